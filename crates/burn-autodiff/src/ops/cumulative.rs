@@ -0,0 +1,140 @@
+use super::{unary, Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    ElementConversion, Shape, TensorMetadata,
+};
+
+#[derive(Debug)]
+pub(crate) struct CumSum;
+
+impl<B: Backend> Backward<B, 1> for CumSum {
+    type State = (usize, bool, bool);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (dim, exclusive, reverse) = ops.state;
+
+        // Each input element contributes to the outputs following it in the scan direction.
+        unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+            B::float_cumsum(grad, dim, exclusive, !reverse)
+        });
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct CumProd;
+
+impl<B: Backend> Backward<B, 1> for CumProd {
+    type State = (FloatTensor<B>, FloatTensor<B>, usize, bool, bool);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (input, output, dim, exclusive, reverse) = ops.state;
+
+        unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+            cumprod_grad::<B>(grad, input, output, dim, exclusive, reverse)
+        });
+    }
+}
+
+/// The gradient of a cumulative product, also defined for zero inputs.
+///
+/// The gradient of a non-zero input is the sum of the gradients of the outputs it contributes to,
+/// weighted by the outputs and divided by the input. The products without the first zero input
+/// of each lane are used for that zero, since dividing by it isn't possible. The gradient of the
+/// inputs following it, including the other zeros, is zero.
+fn cumprod_grad<B: Backend>(
+    grad: FloatTensor<B>,
+    input: FloatTensor<B>,
+    output: FloatTensor<B>,
+    dim: usize,
+    exclusive: bool,
+    reverse: bool,
+) -> FloatTensor<B> {
+    let is_zero = B::float_equal_elem(input.clone(), 0.elem());
+    let zeros_before = B::float_cumsum(B::bool_into_float(is_zero.clone()), dim, true, reverse);
+    let first_zero = B::bool_and(is_zero.clone(), B::float_equal_elem(zeros_before, 0.elem()));
+
+    let grad_nonzero =
+        B::float_cumsum(B::float_mul(grad.clone(), output), dim, exclusive, !reverse);
+    let grad_nonzero = B::float_div(
+        grad_nonzero,
+        B::float_mask_fill(input.clone(), is_zero.clone(), 1.elem()),
+    );
+
+    let output_without_zero = B::float_cumprod(
+        B::float_mask_fill(input, first_zero, 1.elem()),
+        dim,
+        exclusive,
+        reverse,
+    );
+    let grad_zero = B::float_cumsum(
+        B::float_mul(grad, output_without_zero),
+        dim,
+        exclusive,
+        !reverse,
+    );
+
+    B::float_mask_where(grad_nonzero, is_zero, grad_zero)
+}
+
+#[derive(Debug)]
+pub(crate) struct CumMaxMin;
+
+impl<B: Backend> Backward<B, 1> for CumMaxMin {
+    type State = (IntTensor<B>, Shape, usize);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+            let (indices, shape, dim) = ops.state;
+            let device = B::float_device(&grad);
+            let zeros = B::float_zeros(shape, &device);
+
+            B::float_scatter(dim, zeros, indices, grad)
+        });
+    }
+}
+
+/// The indices of the input elements selected by a cumulative maximum or minimum.
+///
+/// An output element comes from the last element, in the scan direction, where the input equals
+/// the output.
+pub(crate) fn cumulative_indices<B: Backend>(
+    input: FloatTensor<B>,
+    output: FloatTensor<B>,
+    dim: usize,
+    reverse: bool,
+) -> IntTensor<B> {
+    let shape = input.shape();
+    let size = shape.dims[dim];
+    let device = B::float_device(&input);
+
+    let mut dims = vec![1; shape.num_dims()];
+    dims[dim] = size;
+    let positions = B::int_arange(0..size as i64, &device);
+    let positions = B::int_expand(B::int_reshape(positions, Shape::from(dims)), shape);
+    let skipped = B::bool_not(B::float_equal(input, output));
+
+    if reverse {
+        let positions = B::int_mask_fill(positions, skipped, (size as i64 - 1).elem());
+        B::int_cummin(positions, dim, true)
+    } else {
+        let positions = B::int_mask_fill(positions, skipped, 0.elem());
+        B::int_cummax(positions, dim, false)
+    }
+}
@@ -329,6 +329,32 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_prod_dim(tensor, dim)
     }
 
+    fn int_cumsum(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        B::int_cumsum(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cumprod(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        B::int_cumprod(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cummax(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        B::int_cummax(tensor, dim, reverse)
+    }
+
+    fn int_cummin(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        B::int_cummin(tensor, dim, reverse)
+    }
+
     fn int_expand(tensor: IntTensor<B>, shape: Shape) -> IntTensor<B> {
         B::int_expand(tensor, shape)
     }
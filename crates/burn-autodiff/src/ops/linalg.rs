@@ -0,0 +1,164 @@
+use super::{unary, Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{backend::Backend, ops::FloatTensor, Bool, Tensor, TensorPrimitive};
+
+/// The eigendecomposition, whose output packs the eigenvalues `[batch_size, 1, n]` on top of the
+/// eigenvectors `[batch_size, n, n]`, so that a single node gets the gradients of both.
+#[derive(Debug)]
+pub(crate) struct Eigh;
+
+impl<B: Backend> Backward<B, 1> for Eigh {
+    type State = (FloatTensor<B>, FloatTensor<B>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (values, vectors) = ops.state;
+
+        unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+            eigh_grad::<B>(values, vectors, grad)
+        });
+    }
+}
+
+/// The singular value decomposition, whose output packs `u` `[batch_size, m, k]`, the singular
+/// values `[batch_size, 1, k]` and `v = vh^T` `[batch_size, n, k]`, so that a single node gets the
+/// gradients of all three.
+#[derive(Debug)]
+pub(crate) struct Svd;
+
+impl<B: Backend> Backward<B, 1> for Svd {
+    type State = (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (u, s, v) = ops.state;
+
+        unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+            svd_grad::<B>(u, s, v, grad)
+        });
+    }
+}
+
+/// The gradient of symmetric matrices from the gradients of their eigenvalues and eigenvectors:
+///
+/// `V (diag(grad_values) + F ∘ (V^T grad_vectors)) V^T`, with `F_ij = 1 / (λ_j - λ_i)`,
+///
+/// made symmetric since only symmetric perturbations keep the input symmetric. The terms of equal
+/// eigenvalues, for which the gradient isn't defined, are zero.
+fn eigh_grad<B: Backend>(
+    values: FloatTensor<B>,
+    vectors: FloatTensor<B>,
+    grad: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let values = tensor::<B>(values);
+    let vectors = tensor::<B>(vectors);
+    let grad = tensor::<B>(grad);
+    let [batch_size, size, _] = vectors.dims();
+
+    let grad_values = grad.clone().slice([0..batch_size, 0..1, 0..size]);
+    let grad_vectors = grad.slice([0..batch_size, 1..size + 1, 0..size]);
+
+    let inner = gaps(values.clone())
+        .mul(vectors.clone().transpose().matmul(grad_vectors))
+        .add(identity::<B>(size, &values.device()).mul(grad_values));
+    let grad = vectors.clone().matmul(inner).matmul(vectors.transpose());
+
+    grad.clone()
+        .add(grad.transpose())
+        .div_scalar(2.0)
+        .into_primitive()
+        .tensor()
+}
+
+/// The gradient of matrices from the gradients of their reduced singular value decomposition
+/// ([Townsend, 2016](https://j-towns.github.io/papers/svd-derivative.pdf)):
+///
+/// `U (J S + diag(grad_s) + S K) V^T + (I - U U^T) grad_u S^-1 V^T + U S^-1 grad_v^T (I - V V^T)`,
+///
+/// with `J = F ∘ (U^T grad_u - grad_u^T U)`, `K = F ∘ (V^T grad_v - grad_v^T V)` and
+/// `F_ij = 1 / (s_j² - s_i²)`. The terms of equal or zero singular values, for which the gradient
+/// isn't defined, are zero.
+fn svd_grad<B: Backend>(
+    u: FloatTensor<B>,
+    s: FloatTensor<B>,
+    v: FloatTensor<B>,
+    grad: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let u = tensor::<B>(u);
+    let s = tensor::<B>(s);
+    let v = tensor::<B>(v);
+    let grad = tensor::<B>(grad);
+    let [batch_size, m, k] = u.dims();
+    let [_, n, _] = v.dims();
+
+    let grad_u = grad.clone().slice([0..batch_size, 0..m, 0..k]);
+    let grad_s = grad.clone().slice([0..batch_size, m..m + 1, 0..k]);
+    let grad_v = grad.slice([0..batch_size, m + 1..m + 1 + n, 0..k]);
+
+    let gaps = gaps(s.clone().powi_scalar(2));
+    let ut_grad_u = u.clone().transpose().matmul(grad_u.clone());
+    let vt_grad_v = v.clone().transpose().matmul(grad_v.clone());
+    let j = gaps
+        .clone()
+        .mul(ut_grad_u.clone().sub(ut_grad_u.transpose()));
+    let k_term = gaps.mul(vt_grad_v.clone().sub(vt_grad_v.transpose()));
+
+    // Multiplying by the diagonal matrix S scales the columns on the right and the rows on the left.
+    let inner = j
+        .mul(s.clone())
+        .add(identity::<B>(k, &s.device()).mul(grad_s))
+        .add(k_term.mul(s.clone().transpose()));
+    let mut grad = u.clone().matmul(inner).matmul(v.clone().transpose());
+
+    let s_inv = safe_recip(s);
+    if m > k {
+        // The component of grad_u orthogonal to the columns of U.
+        let grad_u = grad_u.mul(s_inv.clone());
+        let grad_u = grad_u
+            .clone()
+            .sub(u.clone().matmul(u.clone().transpose().matmul(grad_u)));
+        grad = grad.add(grad_u.matmul(v.clone().transpose()));
+    }
+    if n > k {
+        // The component of grad_v orthogonal to the columns of V.
+        let grad_v = grad_v.mul(s_inv);
+        let grad_v = grad_v
+            .clone()
+            .sub(v.clone().matmul(v.transpose().matmul(grad_v)));
+        grad = grad.add(u.matmul(grad_v.transpose()));
+    }
+
+    grad.into_primitive().tensor()
+}
+
+/// The matrix `F_ij = 1 / (x_j - x_i)` of the row vectors `[batch_size, 1, n]`, zero where the
+/// values are equal, including the diagonal.
+fn gaps<B: Backend>(values: Tensor<B, 3>) -> Tensor<B, 3> {
+    safe_recip(values.clone().sub(values.transpose()))
+}
+
+/// The reciprocal of the values, zero for the zero values.
+fn safe_recip<B: Backend>(values: Tensor<B, 3>) -> Tensor<B, 3> {
+    let is_zero: Tensor<B, 3, Bool> = values.clone().equal_elem(0.0);
+
+    values
+        .mask_fill(is_zero.clone(), 1.0)
+        .recip()
+        .mask_fill(is_zero, 0.0)
+}
+
+fn identity<B: Backend>(size: usize, device: &B::Device) -> Tensor<B, 3> {
+    Tensor::<B, 2>::eye(size, device).unsqueeze()
+}
+
+fn tensor<B: Backend>(tensor: FloatTensor<B>) -> Tensor<B, 3> {
+    Tensor::from_primitive(TensorPrimitive::Float(tensor))
+}
@@ -8,6 +8,8 @@ mod qtensor;
 mod tensor;
 mod transaction;
 
+pub(crate) mod cumulative;
+pub(crate) mod linalg;
 pub(crate) mod maxmin;
 pub(crate) mod sort;
 
@@ -1662,6 +1662,177 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive {
         panic!("Can't differentiate interpolate backward.");
     }
+
+    fn grid_sample_2d(
+        x: AutodiffTensor<B>,
+        grid: AutodiffTensor<B>,
+        options: GridSampleOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct GridSample2d;
+        impl<B: Backend> Backward<B, 2> for GridSample2d {
+            type State = (NodeID, NodeID, GridSampleOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_grid] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, grid_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let grid = checkpointer.retrieve_node_output(grid_state);
+                let backward = B::grid_sample_2d_backward(x, grid, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B>(node.id, backward.x_grad)
+                }
+                if let Some(node) = node_grid {
+                    grads.register::<B>(node.id, backward.grid_grad)
+                }
+            }
+        }
+
+        match GridSample2d
+            .prepare::<C>([x.node.clone(), grid.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let grid_state = prep.checkpoint(&grid);
+                let output =
+                    B::grid_sample_2d(x.primitive.clone(), grid.primitive.clone(), options.clone());
+                prep.finish((x_state, grid_state, options), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::grid_sample_2d(x.primitive, grid.primitive, options))
+            }
+        }
+    }
+
+    fn grid_sample_2d_backward(
+        _x: FloatTensor<Autodiff<B, C>>,
+        _grid: FloatTensor<Autodiff<B, C>>,
+        _output_grad: FloatTensor<Autodiff<B, C>>,
+        _options: GridSampleOptions,
+    ) -> GridSample2dBackward<Autodiff<B, C>> {
+        panic!("Can't differentiate grid sample backward.");
+    }
+
+    fn scaled_dot_product_attention(
+        query: AutodiffTensor<B>,
+        key: AutodiffTensor<B>,
+        value: AutodiffTensor<B>,
+        padding_mask: Option<BoolTensor<B>>,
+        attn_mask: Option<BoolTensor<B>>,
+        options: AttentionOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct ScaledDotProductAttention;
+
+        type AttentionState<B> = (
+            NodeID,
+            NodeID,
+            NodeID,
+            Option<BoolTensor<B>>,
+            Option<BoolTensor<B>>,
+            AttentionOptions,
+        );
+
+        impl<B: Backend> Backward<B, 3> for ScaledDotProductAttention {
+            type State = AttentionState<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (query_state, key_state, value_state, padding_mask, attn_mask, options) =
+                    ops.state;
+                let query = checkpointer.retrieve_node_output(query_state);
+                let key = checkpointer.retrieve_node_output(key_state);
+                let value = checkpointer.retrieve_node_output(value_state);
+                let backward = B::scaled_dot_product_attention_backward(
+                    query,
+                    key,
+                    value,
+                    padding_mask,
+                    attn_mask,
+                    grad,
+                    options,
+                );
+
+                if let Some(node) = node_query {
+                    grads.register::<B>(node.id, backward.query_grad)
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B>(node.id, backward.key_grad)
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B>(node.id, backward.value_grad)
+                }
+            }
+        }
+
+        match ScaledDotProductAttention
+            .prepare::<C>([query.node.clone(), key.node.clone(), value.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let query_state = prep.checkpoint(&query);
+                let key_state = prep.checkpoint(&key);
+                let value_state = prep.checkpoint(&value);
+                let output = B::scaled_dot_product_attention(
+                    query.primitive,
+                    key.primitive,
+                    value.primitive,
+                    padding_mask.clone(),
+                    attn_mask.clone(),
+                    options,
+                );
+                prep.finish(
+                    (
+                        query_state,
+                        key_state,
+                        value_state,
+                        padding_mask,
+                        attn_mask,
+                        options,
+                    ),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::scaled_dot_product_attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                padding_mask,
+                attn_mask,
+                options,
+            )),
+        }
+    }
+
+    fn scaled_dot_product_attention_backward(
+        _query: FloatTensor<Autodiff<B, C>>,
+        _key: FloatTensor<Autodiff<B, C>>,
+        _value: FloatTensor<Autodiff<B, C>>,
+        _padding_mask: Option<BoolTensor<Autodiff<B, C>>>,
+        _attn_mask: Option<BoolTensor<Autodiff<B, C>>>,
+        _output_grad: FloatTensor<Autodiff<B, C>>,
+        _options: AttentionOptions,
+    ) -> AttentionBackward<Autodiff<B, C>> {
+        panic!("Can't differentiate scaled dot-product attention backward.");
+    }
 }
 
 #[derive(Debug)]
@@ -4,10 +4,16 @@ use burn_tensor::{
     backend::Backend,
     ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{QuantizationParametersPrimitive, QuantizationScheme},
-    Device, Shape, TensorData,
+    Device, ElementConversion, Shape, TensorData, TensorMetadata,
 };
 
-use crate::{checkpoint::strategy::CheckpointStrategy, Autodiff};
+use crate::{
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    graph::NodeID,
+    ops::{binary, Backward, Ops, OpsKind},
+    Autodiff,
+};
 
 impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
     fn q_from_data(_data: TensorData, _device: &Device<Self>) -> QuantizedTensor<Self> {
@@ -33,6 +39,119 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
         todo!()
     }
 
+    fn fake_quantize(
+        tensor: FloatTensor<Self>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct FakeQuantize;
+
+        impl<B: Backend> Backward<B, 2> for FakeQuantize {
+            type State = (
+                NodeID,
+                NodeID,
+                Option<B::IntTensorPrimitive>,
+                QuantizationScheme,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (x_state, scale_state, offset, scheme) = ops.state;
+                let x: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(x_state);
+                let scale: B::FloatTensorPrimitive = checkpointer.retrieve_node_output(scale_state);
+                let shape_x = x.shape();
+                let shape_scale = scale.shape();
+
+                let (a, b) = match offset {
+                    Some(_) => scheme.q_type().range(),
+                    None => scheme.q_type().symmetric_range(),
+                };
+                let offset = offset.map(|offset| {
+                    scheme.expand_params_primitive::<B>(B::int_into_float(offset), &shape_x)
+                });
+                let scale = scheme.expand_params_primitive::<B>(scale, &shape_x);
+
+                // Values are quantized as `q = round(x / scale) + offset`
+                let x_scaled = B::float_div(x, scale);
+                let q = B::float_round(x_scaled.clone());
+                let q = match &offset {
+                    Some(offset) => B::float_add(q, offset.clone()),
+                    None => q,
+                };
+                let clipped = B::bool_or(
+                    B::float_lower_elem(q.clone(), (a as f32).elem()),
+                    B::float_greater_elem(q.clone(), (b as f32).elem()),
+                );
+                let clipped_x = clipped.clone();
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        // Straight-through estimator, the gradients of clipped values are zeroed
+                        B::float_mask_fill(grad, clipped_x, 0.elem())
+                    },
+                    |grad| {
+                        // Learned step size: d(fake_quantize(x)) / d(scale) is equal to
+                        // `round(x / scale) - x / scale` when the value is in range, and to the
+                        // clamped quantized value (without the offset) otherwise.
+                        let q = B::float_clamp(q, (a as f32).elem(), (b as f32).elem());
+                        let q = match offset {
+                            Some(offset) => B::float_sub(q, offset),
+                            None => q,
+                        };
+                        let x_scaled = B::float_mask_fill(x_scaled, clipped, 0.elem());
+                        let grad = B::float_mul(grad, B::float_sub(q, x_scaled));
+
+                        // Accumulate the gradients of the values sharing the same scale
+                        let grad = scheme.group_values_primitive::<B>(grad);
+                        let grad = B::float_sum_dim(grad, 1);
+
+                        B::float_reshape(grad, shape_scale)
+                    },
+                );
+            }
+        }
+
+        let scale = qparams.scale;
+        let offset = qparams.offset;
+
+        match FakeQuantize
+            .prepare::<C>([tensor.node.clone(), scale.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&tensor);
+                let scale_state = prep.checkpoint(&scale);
+                let output = B::fake_quantize(
+                    tensor.primitive,
+                    scheme,
+                    QuantizationParametersPrimitive {
+                        scale: scale.primitive,
+                        offset: offset.clone(),
+                    },
+                );
+
+                prep.finish((x_state, scale_state, offset, *scheme), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::fake_quantize(
+                tensor.primitive,
+                scheme,
+                QuantizationParametersPrimitive {
+                    scale: scale.primitive,
+                    offset,
+                },
+            )),
+        }
+    }
+
     fn q_device(tensor: &QuantizedTensor<Self>) -> Device<Self> {
         B::q_device(tensor)
     }
@@ -21,6 +21,7 @@ use burn_tensor::{
     Device, ElementConversion, Shape, TensorData, TensorMetadata,
 };
 
+use super::cumulative::{cumulative_indices, CumMaxMin, CumProd, CumSum};
 use super::maxmin::MaxMinDim;
 
 // Unsqueeze op on primitive.
@@ -1564,6 +1565,176 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_cumsum(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        let output = B::float_cumsum(tensor.primitive, dim, exclusive, reverse);
+
+        match CumSum
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((dim, exclusive, reverse), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn float_cumprod(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        match CumProd
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_cumprod(tensor.primitive.clone(), dim, exclusive, reverse);
+                prep.finish(
+                    (tensor.primitive, output.clone(), dim, exclusive, reverse),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_cumprod(tensor.primitive, dim, exclusive, reverse))
+            }
+        }
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        match CumMaxMin
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = tensor.primitive.shape();
+                let output = B::float_cummax(tensor.primitive.clone(), dim, reverse);
+                let indices =
+                    cumulative_indices::<B>(tensor.primitive, output.clone(), dim, reverse);
+                prep.finish((indices, shape, dim), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_cummax(tensor.primitive, dim, reverse))
+            }
+        }
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        match CumMaxMin
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = tensor.primitive.shape();
+                let output = B::float_cummin(tensor.primitive.clone(), dim, reverse);
+                let indices =
+                    cumulative_indices::<B>(tensor.primitive, output.clone(), dim, reverse);
+                prep.finish((indices, shape, dim), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_cummin(tensor.primitive, dim, reverse))
+            }
+        }
+    }
+
+    fn float_fft(tensor: FloatTensor<Self>, dim: usize, inverse: bool) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Fft;
+
+        impl<B: Backend> Backward<B, 1> for Fft {
+            type State = (usize, bool, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (dim, inverse, size) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // The adjoint of the transform is the opposite transform, which is normalized
+                    // by the size only for the inverse one.
+                    let grad = B::float_fft(grad, dim, !inverse);
+                    let size = (size as f32).elem();
+
+                    if inverse {
+                        B::float_div_scalar(grad, size)
+                    } else {
+                        B::float_mul_scalar(grad, size)
+                    }
+                });
+            }
+        }
+
+        match Fft.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => {
+                let size = tensor.primitive.shape().dims[dim];
+                prep.finish(
+                    (dim, inverse, size),
+                    B::float_fft(tensor.primitive, dim, inverse),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_fft(tensor.primitive, dim, inverse)),
+        }
+    }
+
+    fn float_eigh(tensor: FloatTensor<Self>) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let [batch_size, size, _] = tensor.primitive.shape().dims();
+        let (values, vectors) = B::float_eigh(tensor.primitive);
+        let output = B::float_cat(vec![values.clone(), vectors.clone()], 1);
+
+        // Both outputs are slices of a single node, whose backward pass gets both gradients.
+        let output = match super::linalg::Eigh
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((values, vectors), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        (
+            Self::float_slice(output.clone(), &[0..batch_size, 0..1, 0..size]),
+            Self::float_slice(output, &[0..batch_size, 1..size + 1, 0..size]),
+        )
+    }
+
+    fn float_svd(
+        tensor: FloatTensor<Self>,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>, FloatTensor<Self>) {
+        let [batch_size, m, n] = tensor.primitive.shape().dims();
+        let k = usize::min(m, n);
+        let (u, s, vh) = B::float_svd(tensor.primitive);
+        let v = B::float_swap_dims(vh, 1, 2);
+        let output = B::float_cat(vec![u.clone(), s.clone(), v.clone()], 1);
+
+        // The outputs are slices of a single node, whose backward pass gets all the gradients.
+        let output = match super::linalg::Svd
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((u, s, v), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        };
+
+        let v = Self::float_slice(output.clone(), &[0..batch_size, m + 1..m + 1 + n, 0..k]);
+        (
+            Self::float_slice(output.clone(), &[0..batch_size, 0..m, 0..k]),
+            Self::float_slice(output, &[0..batch_size, m..m + 1, 0..k]),
+            Self::float_swap_dims(v, 1, 2),
+        )
+    }
+
     fn float_argmax(tensor: FloatTensor<Self>, dim: usize) -> IntTensor<B> {
         B::float_argmax(tensor.primitive, dim)
     }
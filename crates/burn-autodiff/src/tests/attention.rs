@@ -0,0 +1,180 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::{attention::AttentionDropout, AttentionOptions};
+    use burn_tensor::{activation, Bool, Tensor, TensorData};
+
+    #[test]
+    fn test_attention_grads_match_reference() {
+        let test = AttentionTestCase::new([2, 2, 5, 4], [2, 2, 6, 4]);
+
+        test.assert_grads(None, AttentionOptions::default());
+    }
+
+    #[test]
+    fn test_attention_causal_grouped_query_grads() {
+        let test = AttentionTestCase::new([1, 4, 3, 4], [1, 2, 5, 4]);
+
+        test.assert_grads(None, AttentionOptions::new(None, true, 0.0));
+    }
+
+    #[test]
+    fn test_attention_padding_mask_grads() {
+        let device = Default::default();
+        let test = AttentionTestCase::new([2, 1, 3, 4], [2, 1, 3, 4]);
+        let padding_mask = Tensor::<TestAutodiffBackend, 2, Bool>::from_data(
+            [[false, false, true], [false, true, true]],
+            &device,
+        );
+
+        test.assert_grads(Some(padding_mask), AttentionOptions::default());
+    }
+
+    #[test]
+    fn test_attention_dropout_grads() {
+        let test = AttentionTestCase::new([2, 4, 3, 4], [2, 2, 5, 4]);
+        let mut options = AttentionOptions::new(None, true, 0.4);
+        options.dropout_seed = Some(11);
+
+        test.assert_grads(None, options);
+    }
+
+    struct AttentionTestCase {
+        query: TestAutodiffTensor<4>,
+        key: TestAutodiffTensor<4>,
+        value: TestAutodiffTensor<4>,
+        output_weights: TestAutodiffTensor<4>,
+    }
+
+    impl AttentionTestCase {
+        fn new(query_shape: [usize; 4], key_shape: [usize; 4]) -> Self {
+            let [batch_size, num_heads, seq_length_q, head_dim] = query_shape;
+
+            Self {
+                query: sequence(query_shape, 0.37),
+                key: sequence(key_shape, 0.53),
+                value: sequence(key_shape, 0.71),
+                output_weights: sequence([batch_size, num_heads, seq_length_q, head_dim], 0.29),
+            }
+        }
+
+        fn assert_grads(
+            &self,
+            padding_mask: Option<Tensor<TestAutodiffBackend, 2, Bool>>,
+            options: AttentionOptions,
+        ) {
+            let query = self.query.clone().require_grad();
+            let key = self.key.clone().require_grad();
+            let value = self.value.clone().require_grad();
+            let output = scaled_dot_product_attention(
+                query.clone(),
+                key.clone(),
+                value.clone(),
+                padding_mask.clone(),
+                None,
+                options,
+            );
+            let grads = output.mul(self.output_weights.clone()).sum().backward();
+
+            let query_ref = self.query.clone().require_grad();
+            let key_ref = self.key.clone().require_grad();
+            let value_ref = self.value.clone().require_grad();
+            let output_ref = reference(
+                query_ref.clone(),
+                key_ref.clone(),
+                value_ref.clone(),
+                padding_mask,
+                &options,
+            );
+            let grads_ref = output_ref.mul(self.output_weights.clone()).sum().backward();
+
+            for (tensor, tensor_ref) in [(query, query_ref), (key, key_ref), (value, value_ref)] {
+                tensor
+                    .grad(&grads)
+                    .unwrap()
+                    .to_data()
+                    .assert_approx_eq(&tensor_ref.grad(&grads_ref).unwrap().to_data(), 3);
+            }
+        }
+    }
+
+    /// The attention computed with differentiable tensor operations.
+    fn reference(
+        query: TestAutodiffTensor<4>,
+        key: TestAutodiffTensor<4>,
+        value: TestAutodiffTensor<4>,
+        padding_mask: Option<Tensor<TestAutodiffBackend, 2, Bool>>,
+        options: &AttentionOptions,
+    ) -> TestAutodiffTensor<4> {
+        let [batch_size, num_heads, seq_length_q, head_dim] = query.dims();
+        let [_, num_kv_heads, seq_length_k, _] = key.dims();
+        let repeat = |tensor: TestAutodiffTensor<4>| {
+            let [_, _, _, dim] = tensor.dims();
+            tensor
+                .unsqueeze_dim::<5>(2)
+                .expand([
+                    batch_size,
+                    num_kv_heads,
+                    num_heads / num_kv_heads,
+                    seq_length_k,
+                    dim,
+                ])
+                .reshape([batch_size, num_heads, seq_length_k, dim])
+        };
+
+        let mut scores = query
+            .matmul(repeat(key).transpose())
+            .div_scalar((head_dim as f32).sqrt());
+        let shape = [batch_size, num_heads, seq_length_q, seq_length_k];
+
+        if let Some(mask) = padding_mask {
+            let mask = mask.reshape([batch_size, 1, 1, seq_length_k]).expand(shape);
+            scores = scores.mask_fill(mask, -1.0e9);
+        }
+        if options.is_causal {
+            let offset = seq_length_k as i64 - seq_length_q as i64;
+            let mask = Tensor::<TestAutodiffBackend, 2, Bool>::tril_mask(
+                [seq_length_q, seq_length_k],
+                offset,
+                &scores.device(),
+            )
+            .unsqueeze::<4>()
+            .expand(shape);
+            scores = scores.mask_fill(mask, -1.0e9);
+        }
+
+        let mut weights = activation::softmax(scores, 3);
+        if let Some(dropout) = AttentionDropout::new(options) {
+            // The rows are numbered across the batch and the heads.
+            let factors = (0..batch_size * num_heads * seq_length_q)
+                .flat_map(|row| {
+                    (0..seq_length_k).map(move |col| match dropout.keep(row, col) {
+                        true => dropout.scale as f32,
+                        false => 0.0,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let factors = TestAutodiffTensor::<4>::from_data(
+                TensorData::new(factors, shape),
+                &weights.device(),
+            );
+            weights = weights.mul(factors);
+        }
+
+        weights.matmul(repeat(value))
+    }
+
+    fn sequence(shape: [usize; 4], factor: f32) -> TestAutodiffTensor<4> {
+        let num_elements = shape.iter().product::<usize>() as i64;
+
+        Tensor::<TestAutodiffBackend, 1, burn_tensor::Int>::arange(
+            0..num_elements,
+            &Default::default(),
+        )
+        .float()
+        .mul_scalar(factor)
+        .sin()
+        .reshape(shape)
+    }
+}
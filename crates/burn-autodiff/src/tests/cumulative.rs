@@ -0,0 +1,151 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+                .require_grad();
+        let tensor_2 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [3.0, 2.0, 1.0]], &device);
+
+        let tensor_3 = tensor_1.clone().cumsum(1).mul(tensor_2);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[6.0, 5.0, 3.0], [6.0, 3.0, 1.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cumsum_exclusive_reverse() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+                .require_grad();
+        let tensor_2 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [3.0, 2.0, 1.0]], &device);
+
+        let tensor_3 = tensor_1
+            .clone()
+            .cumsum_with_options(1, true, true)
+            .mul(tensor_2);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.0, 1.0, 3.0], [0.0, 3.0, 5.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0], [2.0, -1.0, 4.0]], &device)
+                .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[9.0, 4.0, 2.0], [-4.0, 10.0, -2.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_exclusive() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod_with_options(1, true, false);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[3.0, 1.0, 0.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[2.0, 0.0, 3.0, 0.0, 5.0], [1.0, 2.0, 3.0, 4.0, 0.0]],
+            &device,
+        )
+        .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 8.0, 0.0, 0.0, 0.0], [33.0, 16.0, 10.0, 6.0, 24.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_exclusive_reverse_with_zeros() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 0.0, 2.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod_with_options(1, true, true);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.0, 2.0, 1.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cummax() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 3.0, 2.0, 5.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cummax(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 2.0, 0.0, 1.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cummax_reverse() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 3.0, 2.0, 5.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cummax_reverse(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.0, 0.0, 0.0, 4.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cummin() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 1.0], [2.0, 0.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cummin(0);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 1.0], [1.0, 1.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+}
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::quantization::{QuantizationParameters, QuantizationScheme, QuantizationType};
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_diff_fake_quantize_symmetric() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_floats([-1.2, -0.4, 0.3, 1.0, 100.0], &device)
+            .require_grad();
+        let scale = TestAutodiffTensor::<1>::from_floats([0.5], &device).require_grad();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        let qparams = QuantizationParameters {
+            scale: scale.clone(),
+            offset: None,
+        };
+
+        let output = tensor.clone().fake_quantize(&scheme, qparams);
+        let grads = output.clone().sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_scale = scale.grad(&grads).unwrap();
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.0, -0.5, 0.5, 1.0, 63.5]), 3);
+        // The gradient of the clipped value is zeroed
+        grad_tensor
+            .to_data()
+            .assert_eq(&TensorData::from([1.0, 1.0, 1.0, 1.0, 0.0]), false);
+        grad_scale
+            .to_data()
+            .assert_approx_eq(&TensorData::from([127.6]), 3);
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_affine() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_floats([-70.0, 1.2], &device).require_grad();
+        let scale = TestAutodiffTensor::<1>::from_floats([0.5], &device).require_grad();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        let qparams = QuantizationParameters {
+            scale: scale.clone(),
+            offset: Some(Tensor::from_ints([10], &device)),
+        };
+
+        let output = tensor.clone().fake_quantize(&scheme, qparams);
+        let grads = output.sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_scale = scale.grad(&grads).unwrap();
+
+        grad_tensor
+            .to_data()
+            .assert_eq(&TensorData::from([0.0, 1.0]), false);
+        grad_scale
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-138.4]), 3);
+    }
+}
@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+
+    #[test]
+    fn should_diff_rfft() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+
+        let (real, imag) = signal::rfft(tensor.clone(), 0);
+        let grads = real.add(imag).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([3.0, -1.0, 1.0, 1.0]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_ifft() {
+        let device = Default::default();
+        let real =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0, 3.0, 4.0]], &device).require_grad();
+        let imag =
+            TestAutodiffTensor::<2>::from_data([[0.5, -1.0, 2.0, 0.0]], &device).require_grad();
+
+        let (output, _) = signal::ifft(real.clone(), imag.clone(), 1);
+        let grads = output.sum().backward();
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0, 0.0, 0.0]]), 3);
+        grad_imag
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.0, 0.0, 0.0]]), 3);
+    }
+}
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_zeros() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_data([[[[1.0, 2.0], [3.0, 4.0]]]], &device)
+            .require_grad();
+        let grid = TestAutodiffTensor::<4>::from_data([[[[0.0, 0.0], [0.5, -1.0]]]], &device)
+            .require_grad();
+        let options = GridSampleOptions::new(
+            InterpolateMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            true,
+        );
+
+        let output = grid_sample_2d(x.clone(), grid.clone(), options);
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+
+        x_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[[0.5, 1.0], [0.25, 0.25]]]]), 3);
+        grid_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[[0.5, 1.0], [0.5, 1.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_border() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<4>::from_data([[[[1.0, 2.0], [3.0, 4.0]]]], &device)
+            .require_grad();
+        let grid = TestAutodiffTensor::<4>::from_data([[[[1.5, 0.0]]]], &device).require_grad();
+        let options = GridSampleOptions::new(
+            InterpolateMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            true,
+        );
+
+        let output = grid_sample_2d(x.clone(), grid.clone(), options);
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+
+        // The clipped coordinate doesn't depend on the grid anymore.
+        x_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[[0.0, 0.5], [0.0, 0.5]]]]), 3);
+        grid_grad
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[[0.0, 1.0]]]]), 3);
+    }
+}
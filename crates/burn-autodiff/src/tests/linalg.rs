@@ -0,0 +1,112 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn should_diff_det() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+        let output = linalg::det(tensor.clone());
+        let grads = output.backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        // d det(A) / dA = det(A) A^-T
+        let expected = TensorData::from([[6.0, -2.0], [-7.0, 4.0]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+        let output = linalg::inverse(tensor.clone());
+        let grads = output.sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.04, -0.08], [-0.03, 0.06]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[4.0, 2.0], [2.0, 5.0]], &device).require_grad();
+
+        let output = linalg::cholesky(tensor.clone());
+        let grads = output.sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.1875, 0.0], [0.25, 0.25]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [2.0, -1.0]], &device);
+
+        // The eigenvalues and the eigenvectors both contribute: V diag(λ²) V^T = A².
+        let (values, vectors) = linalg::eigh(tensor.clone());
+        let output = vectors
+            .clone()
+            .mul(values.powi_scalar(2))
+            .matmul(vectors.transpose());
+        let grads = output.mul(weights).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        // d sum(W ∘ A²) / dA = W A + A W, for symmetric W and A.
+        let expected = TensorData::from([[8.0, 10.0], [10.0, -2.0]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_svd_tall() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[3.0, 1.0], [1.0, 2.0], [0.0, 1.0]], &device)
+                .require_grad();
+        let weights =
+            TestAutodiffTensor::<2>::from_data([[1.0, 0.5], [-1.0, 2.0], [0.5, 1.0]], &device);
+
+        let grad = svd_cube_grad(tensor, weights);
+
+        // d sum(W ∘ A A^T A) / dA = W A^T A + A W^T A + A A^T W.
+        let expected = TensorData::from([[27.5, 28.0], [10.0, 32.0], [13.0, 19.5]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_svd_wide() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[3.0, 1.0, 0.0], [1.0, 2.0, 1.0]], &device)
+                .require_grad();
+        let weights =
+            TestAutodiffTensor::<2>::from_data([[1.0, -1.0, 0.5], [0.5, 2.0, 1.0]], &device);
+
+        let grad = svd_cube_grad(tensor, weights);
+
+        let expected = TensorData::from([[27.5, 10.0, 13.0], [28.0, 32.0, 19.5]]);
+        grad.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    /// The gradient of `sum(W ∘ U S³ Vh) = sum(W ∘ A A^T A)`, to which all three outputs contribute.
+    fn svd_cube_grad(
+        tensor: TestAutodiffTensor<2>,
+        weights: TestAutodiffTensor<2>,
+    ) -> TestTensor<2> {
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        let output = u.mul(s.powi_scalar(3)).matmul(vh);
+        let grads = output.mul(weights).sum().backward();
+
+        tensor.grad(&grads).unwrap()
+    }
+}
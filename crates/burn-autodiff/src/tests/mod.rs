@@ -5,6 +5,7 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
@@ -22,16 +23,21 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
 mod deform_conv2d;
 mod div;
 mod erf;
 mod exp;
 mod expand;
+mod fake_quantize;
+mod fft;
 mod flip;
 mod floor;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod linalg;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -132,6 +138,8 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_conv3d!();
         #[cfg(not(target_os = "macos"))] // Wgpu on MacOS currently doesn't support atomic compare exchange
         burn_autodiff::testgen_ad_deform_conv2d!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_attention!();
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
         burn_autodiff::testgen_ad_conv_transpose3d!();
@@ -153,6 +161,8 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
+        burn_autodiff::testgen_ad_fft!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_remainder!();
         burn_autodiff::testgen_ad_erf!();
@@ -160,6 +170,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_linalg!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
         burn_autodiff::testgen_ad_mask!();
@@ -176,6 +187,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_tanh!();
         burn_autodiff::testgen_ad_round!();
+        burn_autodiff::testgen_ad_fake_quantize!();
         burn_autodiff::testgen_ad_floor!();
         burn_autodiff::testgen_ad_ceil!();
         burn_autodiff::testgen_ad_sigmoid!();
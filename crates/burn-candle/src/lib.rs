@@ -70,6 +70,7 @@ mod tests {
     burn_tensor::testgen_recip!();
     burn_tensor::testgen_clamp!();
     burn_tensor::testgen_cos!();
+    burn_tensor::testgen_cumulative!();
     burn_tensor::testgen_close!();
     // burn_tensor::testgen_div!();
     burn_tensor::testgen_erf!();
@@ -145,3 +145,111 @@ pub fn mask_where_broadcasted(
 
     CandleTensor::new(mask.tensor.where_cond(&value.tensor, &tensor).unwrap())
 }
+
+pub fn cumsum(tensor: CandleTensor, dim: usize, exclusive: bool, reverse: bool) -> CandleTensor {
+    let size = tensor.tensor.dim(dim).unwrap();
+
+    // Candle computes the cumulative sum with a matmul, which only supports these float types.
+    if size == 0
+        || !matches!(
+            tensor.tensor.dtype(),
+            candle_core::DType::F16 | candle_core::DType::F32 | candle_core::DType::F64
+        )
+    {
+        let identity = exclusive.then_some(candle_core::Tensor::zeros_like as Identity);
+        return cumulative(tensor, dim, identity, reverse, |acc, elem| acc.add(elem));
+    }
+
+    let mut tensor = tensor.tensor;
+    if reverse {
+        // Candle only selects the indices of contiguous tensors.
+        let contiguous = CandleTensor::new(tensor.contiguous().unwrap());
+        tensor = flip(contiguous, &[dim]).tensor;
+    }
+
+    let mut output = tensor.cumsum(dim).unwrap();
+    if exclusive {
+        // Shift the sums by one, the first sum being zero.
+        let first = tensor.narrow(dim, 0, 1).unwrap().zeros_like().unwrap();
+        let sums = output.narrow(dim, 0, size - 1).unwrap();
+        output = candle_core::Tensor::cat(&[first, sums], dim).unwrap();
+    }
+
+    // Candle returns the sums along an inner dimension as a transposed view.
+    let output = CandleTensor::new(output.contiguous().unwrap());
+    match reverse {
+        true => flip(output, &[dim]),
+        false => output,
+    }
+}
+
+pub fn cumprod(tensor: CandleTensor, dim: usize, exclusive: bool, reverse: bool) -> CandleTensor {
+    let identity = exclusive.then_some(candle_core::Tensor::ones_like as Identity);
+    cumulative(tensor, dim, identity, reverse, |acc, elem| acc.mul(elem))
+}
+
+pub fn cummax(tensor: CandleTensor, dim: usize, reverse: bool) -> CandleTensor {
+    cumulative(tensor, dim, None, reverse, |acc, elem| {
+        propagate_nan(elem, acc.maximum(elem)?)
+    })
+}
+
+pub fn cummin(tensor: CandleTensor, dim: usize, reverse: bool) -> CandleTensor {
+    cumulative(tensor, dim, None, reverse, |acc, elem| {
+        propagate_nan(elem, acc.minimum(elem)?)
+    })
+}
+
+/// Candle's maximum and minimum keep the left operand when the right one is NaN, so the NaN
+/// elements are selected explicitly.
+fn propagate_nan(
+    elem: &candle_core::Tensor,
+    output: candle_core::Tensor,
+) -> candle_core::Result<candle_core::Tensor> {
+    elem.ne(elem)?.where_cond(elem, &output)
+}
+
+type Identity = fn(&candle_core::Tensor) -> candle_core::Result<candle_core::Tensor>;
+
+fn cumulative<F>(
+    tensor: CandleTensor,
+    dim: usize,
+    exclusive: Option<Identity>,
+    reverse: bool,
+    op: F,
+) -> CandleTensor
+where
+    F: Fn(&candle_core::Tensor, &candle_core::Tensor) -> candle_core::Result<candle_core::Tensor>,
+{
+    // FIXME: Candle only provides a cumulative sum for float tensors, so the other cumulative ops
+    // and the integer cumulative sum compute one slice of the dimension at a time.
+    let tensor = tensor.tensor;
+    let size = tensor.dim(dim).unwrap();
+    let mut indices: Vec<usize> = (0..size).collect();
+    if reverse {
+        indices.reverse();
+    }
+
+    let mut outputs = Vec::with_capacity(size);
+    let mut acc: Option<candle_core::Tensor> = None;
+    for index in indices {
+        let elem = tensor.narrow(dim, index, 1).unwrap();
+        let next = match &acc {
+            Some(acc) => op(acc, &elem).unwrap(),
+            None => elem,
+        };
+        let output = match (exclusive, acc) {
+            (Some(_), Some(acc)) => acc,
+            (Some(identity), None) => identity(&next).unwrap(),
+            (None, _) => next.clone(),
+        };
+        outputs.push(output);
+        acc = Some(next);
+    }
+
+    if reverse {
+        outputs.reverse();
+    }
+
+    CandleTensor::new(candle_core::Tensor::cat(&outputs, dim).unwrap())
+}
@@ -270,6 +270,32 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         todo!("prod_int is not implemented for Candle IntTensor (see https://github.com/tracel-ai/burn/issues/1454)")
     }
 
+    fn int_cumsum(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        super::base::cumsum(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cumprod(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        super::base::cumprod(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cummax(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        super::base::cummax(tensor, dim, reverse)
+    }
+
+    fn int_cummin(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        super::base::cummin(tensor, dim, reverse)
+    }
+
     fn int_mean_dim(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
         // Candle implements scalar a/b as a * (1/b). With ints 1/b is rounded to 0 so we always obtain 0.
         panic!("Not supported by Candle")
@@ -315,6 +315,32 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         CandleTensor::new(tensor.tensor.mean_keepdim(dim).unwrap())
     }
 
+    fn float_cumsum(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        super::base::cumsum(tensor, dim, exclusive, reverse)
+    }
+
+    fn float_cumprod(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        super::base::cumprod(tensor, dim, exclusive, reverse)
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        super::base::cummax(tensor, dim, reverse)
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        super::base::cummin(tensor, dim, reverse)
+    }
+
     fn float_exp(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         CandleTensor::new(tensor.tensor.exp().unwrap())
     }
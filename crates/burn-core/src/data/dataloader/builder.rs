@@ -1,14 +1,20 @@
-use super::{batcher::DynBatcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
-use burn_dataset::Dataset;
+use super::{
+    batcher::DynBatcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy,
+    IterableDataLoader,
+};
+use burn_dataset::{Dataset, IterableDataset};
 use rand::{rngs::StdRng, SeedableRng};
 use std::sync::Arc;
 
+const DEFAULT_SHUFFLE_BUFFER: usize = 1000;
+
 /// A builder for data loaders.
 pub struct DataLoaderBuilder<I, O> {
     strategy: Option<Box<dyn BatchStrategy<I>>>,
     batcher: Box<dyn DynBatcher<I, O>>,
     num_threads: Option<usize>,
     shuffle: Option<u64>,
+    shuffle_buffer: Option<usize>,
 }
 
 impl<I, O> DataLoaderBuilder<I, O>
@@ -34,6 +40,7 @@ where
             strategy: None,
             num_threads: None,
             shuffle: None,
+            shuffle_buffer: None,
         }
     }
 
@@ -68,6 +75,23 @@ where
         self
     }
 
+    /// Sets the size of the shuffle buffer of each worker, only used by
+    /// [iterable data loaders](IterableDataLoader) when shuffling.
+    ///
+    /// A larger buffer mixes items from further apart in the stream, at the cost of memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of items in the buffer.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn shuffle_buffer(mut self, size: usize) -> Self {
+        self.shuffle_buffer = Some(size);
+        self
+    }
+
     /// Sets the number of workers.
     ///
     /// # Arguments
@@ -114,4 +138,34 @@ where
 
         Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng))
     }
+
+    /// Builds a data loader reading an iterable dataset, with the shards of the dataset
+    /// distributed over the workers.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The iterable dataset.
+    ///
+    /// # Returns
+    ///
+    /// The iterable data loader, whose [position](IterableDataLoader::position) can be saved to
+    /// resume the iteration.
+    pub fn build_iterable<D>(self, dataset: D) -> Arc<IterableDataLoader<I, O>>
+    where
+        D: IterableDataset<I> + 'static,
+    {
+        let strategy = match self.strategy {
+            Some(strategy) => strategy,
+            None => Box::new(FixBatchStrategy::new(1)),
+        };
+
+        Arc::new(IterableDataLoader::new(
+            strategy,
+            Arc::new(dataset),
+            self.batcher,
+            self.num_threads.unwrap_or(1),
+            self.shuffle,
+            self.shuffle_buffer.unwrap_or(DEFAULT_SHUFFLE_BUFFER),
+        ))
+    }
 }
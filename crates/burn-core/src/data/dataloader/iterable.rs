@@ -0,0 +1,422 @@
+use super::{
+    batcher::DynBatcher, multithread::MAX_QUEUED_ITEMS, BatchStrategy, DataLoader,
+    DataLoaderIterator, Message, Progress,
+};
+use burn_dataset::{IterableDataset, ShuffleBuffer};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+/// The position of an [iterable data loader](IterableDataLoader) in its stream of items, which
+/// can be saved with a checkpoint to resume the iteration where it was stopped.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamPosition {
+    /// The number of completed iterations over the dataset.
+    pub epoch: usize,
+
+    /// The number of items yielded by each worker during the current epoch, empty at the start of
+    /// an epoch.
+    pub items: Vec<usize>,
+}
+
+/// A data loader reading an [iterable dataset](IterableDataset) in batches.
+///
+/// The shards of the dataset are distributed over the workers, each worker reading its shards
+/// sequentially. When shuffling, the order of the shards is shuffled at each epoch and the items
+/// of each worker go through a [shuffle buffer](ShuffleBuffer).
+///
+/// The iteration is deterministic for a given seed, so it can be resumed from a
+/// [position](StreamPosition) by skipping the items already yielded by each worker.
+pub struct IterableDataLoader<I, O> {
+    strategy: Box<dyn BatchStrategy<I>>,
+    dataset: Arc<dyn IterableDataset<I>>,
+    batcher: Box<dyn DynBatcher<I, O>>,
+    num_workers: usize,
+    shuffle: Option<u64>,
+    shuffle_buffer: usize,
+    position: Arc<spin::Mutex<StreamPosition>>,
+}
+
+impl<I, O> Clone for IterableDataLoader<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            strategy: self.strategy.clone_dyn(),
+            dataset: self.dataset.clone(),
+            batcher: self.batcher.clone_dyn(),
+            num_workers: self.num_workers,
+            shuffle: self.shuffle,
+            shuffle_buffer: self.shuffle_buffer,
+            position: self.position.clone(),
+        }
+    }
+}
+
+impl<I, O> IterableDataLoader<I, O> {
+    /// Creates a new iterable data loader.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The batch strategy.
+    /// * `dataset` - The iterable dataset.
+    /// * `batcher` - The batcher.
+    /// * `num_workers` - The number of workers, each reading a subset of the shards.
+    /// * `shuffle` - The seed used to shuffle the shards and the items, if they are shuffled.
+    /// * `shuffle_buffer` - The size of the shuffle buffer of each worker.
+    ///
+    /// # Returns
+    ///
+    /// The iterable data loader.
+    pub fn new(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn IterableDataset<I>>,
+        batcher: Box<dyn DynBatcher<I, O>>,
+        num_workers: usize,
+        shuffle: Option<u64>,
+        shuffle_buffer: usize,
+    ) -> Self {
+        Self {
+            strategy,
+            dataset,
+            batcher,
+            num_workers: num_workers.max(1),
+            shuffle,
+            shuffle_buffer,
+            position: Arc::new(spin::Mutex::new(StreamPosition::default())),
+        }
+    }
+
+    /// Returns the current position in the stream, updated each time a batch is yielded.
+    pub fn position(&self) -> StreamPosition {
+        self.position.lock().clone()
+    }
+
+    /// Sets the position from which the next iteration starts.
+    ///
+    /// # Panics
+    ///
+    /// If the position was saved with a different number of workers.
+    pub fn resume(&self, position: StreamPosition) {
+        assert!(
+            position.items.is_empty() || position.items.len() == self.num_workers,
+            "The position was saved with {} workers, but the data loader has {}",
+            position.items.len(),
+            self.num_workers
+        );
+        *self.position.lock() = position;
+    }
+}
+
+impl<I, O> DataLoader<O> for IterableDataLoader<I, O>
+where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let position = self.position();
+        let items = match position.items.is_empty() {
+            true => vec![0; self.num_workers],
+            false => position.items,
+        };
+        let len_hint = self.dataset.len_hint();
+
+        // The shards and the items are shuffled differently at each epoch, but the same way when
+        // an epoch is resumed.
+        let mut rng = self
+            .shuffle
+            .map(|seed| StdRng::seed_from_u64(seed.wrapping_add(position.epoch as u64)));
+        let mut shards = (0..self.dataset.num_shards()).collect::<Vec<_>>();
+        if let Some(rng) = rng.as_mut() {
+            shards.shuffle(rng);
+        }
+
+        let mut workers = (0..self.num_workers)
+            .map(|index| {
+                let shards = shards
+                    .iter()
+                    .copied()
+                    .skip(index)
+                    .step_by(self.num_workers)
+                    .collect::<Vec<_>>();
+                let dataset = self.dataset.clone();
+                let stream = shards
+                    .into_iter()
+                    .flat_map(move |shard| dataset.read_shard(shard));
+                let stream: Box<dyn Iterator<Item = I> + Send> = match rng.as_mut() {
+                    Some(rng) => Box::new(ShuffleBuffer::new(
+                        stream,
+                        self.shuffle_buffer,
+                        rng.random(),
+                    )),
+                    None => Box::new(stream),
+                };
+
+                IterableWorker {
+                    stream: Box::new(stream.skip(items[index])),
+                    strategy: self.strategy.clone_dyn(),
+                    batcher: self.batcher.clone_dyn(),
+                    items_processed: items[index],
+                    len_hint,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let source = match workers.len() {
+            1 => WorkerSource::Inline(workers.remove(0)),
+            _ => WorkerSource::spawn(workers),
+        };
+
+        Box::new(IterableDataLoaderIterator {
+            source,
+            epoch: position.epoch,
+            items,
+            len_hint,
+            position: self.position.clone(),
+        })
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataset.len_hint().unwrap_or(0)
+    }
+}
+
+/// Batches the items of the shards assigned to a worker.
+struct IterableWorker<I, O> {
+    stream: Box<dyn Iterator<Item = I> + Send>,
+    strategy: Box<dyn BatchStrategy<I>>,
+    batcher: Box<dyn DynBatcher<I, O>>,
+    items_processed: usize,
+    len_hint: Option<usize>,
+}
+
+impl<I, O> Iterator for IterableWorker<I, O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        for item in self.stream.by_ref() {
+            self.strategy.add(item);
+
+            if let Some(items) = self.strategy.batch(false) {
+                self.items_processed += items.len();
+                return Some(self.batcher.batch(items));
+            }
+        }
+
+        if let Some(items) = self.strategy.batch(true) {
+            self.items_processed += items.len();
+            return Some(self.batcher.batch(items));
+        }
+
+        None
+    }
+}
+
+impl<I, O> DataLoaderIterator<O> for IterableWorker<I, O> {
+    fn progress(&self) -> Progress {
+        Progress::new(
+            self.items_processed,
+            self.len_hint.unwrap_or(self.items_processed),
+        )
+    }
+}
+
+/// Where the batches come from: a single worker running on the current thread, or one thread per
+/// worker.
+enum WorkerSource<I, O> {
+    Inline(IterableWorker<I, O>),
+    Threads {
+        num_done: usize,
+        handles: Vec<thread::JoinHandle<()>>,
+        receiver: mpsc::Receiver<Message<O>>,
+    },
+}
+
+impl<I, O> WorkerSource<I, O>
+where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    fn spawn(workers: Vec<IterableWorker<I, O>>) -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<Message<O>>(MAX_QUEUED_ITEMS);
+
+        let handles = workers
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut worker)| {
+                let sender = sender.clone();
+
+                thread::spawn(move || {
+                    while let Some(batch) = worker.next() {
+                        let progress = worker.progress();
+
+                        // The receiver is probably gone, just need to stop iterating.
+                        if sender.send(Message::Batch(index, batch, progress)).is_err() {
+                            return;
+                        }
+                    }
+                    sender.send(Message::Done).ok();
+                })
+            })
+            .collect();
+
+        Self::Threads {
+            num_done: 0,
+            handles,
+            receiver,
+        }
+    }
+
+    /// Returns the next batch with the index of its worker and the number of items the worker
+    /// processed.
+    fn next(&mut self) -> Option<(usize, O, usize)> {
+        match self {
+            Self::Inline(worker) => worker
+                .next()
+                .map(|batch| (0, batch, worker.items_processed)),
+            Self::Threads {
+                num_done,
+                handles,
+                receiver,
+            } => loop {
+                if *num_done == handles.len() {
+                    while let Some(handle) = handles.pop() {
+                        handle.join().unwrap();
+                    }
+                    return None;
+                }
+
+                match receiver.recv().ok()? {
+                    Message::Batch(index, batch, progress) => {
+                        return Some((index, batch, progress.items_processed))
+                    }
+                    Message::Done => *num_done += 1,
+                }
+            },
+        }
+    }
+}
+
+struct IterableDataLoaderIterator<I, O> {
+    source: WorkerSource<I, O>,
+    epoch: usize,
+    items: Vec<usize>,
+    len_hint: Option<usize>,
+    position: Arc<spin::Mutex<StreamPosition>>,
+}
+
+impl<I, O> Iterator for IterableDataLoaderIterator<I, O>
+where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        match self.source.next() {
+            Some((index, batch, items_processed)) => {
+                self.items[index] = items_processed;
+                *self.position.lock() = StreamPosition {
+                    epoch: self.epoch,
+                    items: self.items.clone(),
+                };
+                Some(batch)
+            }
+            None => {
+                *self.position.lock() = StreamPosition {
+                    epoch: self.epoch + 1,
+                    items: Vec::new(),
+                };
+                None
+            }
+        }
+    }
+}
+
+impl<I, O> DataLoaderIterator<O> for IterableDataLoaderIterator<I, O>
+where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    fn progress(&self) -> Progress {
+        let items_processed = self.items.iter().sum();
+
+        Progress::new(items_processed, self.len_hint.unwrap_or(items_processed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataloader::FixBatchStrategy;
+    use crate::data::dataset::{InMemDataset, ShardedDataset};
+
+    fn dataloader(
+        num_workers: usize,
+        shuffle: Option<u64>,
+    ) -> IterableDataLoader<usize, Vec<usize>> {
+        let dataset = InMemDataset::<usize>::new((0..27).collect());
+
+        IterableDataLoader::new(
+            Box::new(FixBatchStrategy::new(5)),
+            Arc::new(ShardedDataset::new(dataset, 6)),
+            Box::new(TestBatcher::new()),
+            num_workers,
+            shuffle,
+            8,
+        )
+    }
+
+    #[test]
+    fn test_iterable_dataloader_cover_all_items() {
+        for num_workers in [1, 4] {
+            let dataloader = dataloader(num_workers, Some(42));
+
+            let items = dataloader.iter().flatten().collect::<HashSet<_>>();
+
+            assert_eq!(items, (0..27).collect::<HashSet<_>>());
+            assert_eq!(
+                dataloader.position(),
+                StreamPosition {
+                    epoch: 1,
+                    items: Vec::new()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_iterable_dataloader_shuffle_deterministic() {
+        let first = dataloader(1, Some(42)).iter().flatten().collect::<Vec<_>>();
+        let second = dataloader(1, Some(42)).iter().flatten().collect::<Vec<_>>();
+        let unshuffled = dataloader(1, None).iter().flatten().collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+        assert_ne!(first, unshuffled);
+        assert_eq!(unshuffled, (0..27).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_iterable_dataloader_resume() {
+        let dataloader_stopped = dataloader(2, Some(42));
+        let mut iterator = dataloader_stopped.iter();
+        let mut seen = HashSet::new();
+        for _ in 0..3 {
+            seen.extend(iterator.next().unwrap());
+        }
+        let position = dataloader_stopped.position();
+        assert_eq!(position.items.iter().sum::<usize>(), seen.len());
+        assert_eq!(iterator.progress().items_processed, seen.len());
+
+        let dataloader_resumed = dataloader(2, Some(42));
+        dataloader_resumed.resume(position);
+        let remaining = dataloader_resumed.iter().flatten().collect::<Vec<_>>();
+
+        assert_eq!(remaining.len(), 27 - seen.len());
+        assert!(remaining.iter().all(|item| !seen.contains(item)));
+        seen.extend(remaining);
+        assert_eq!(seen, (0..27).collect::<HashSet<_>>());
+    }
+}
@@ -1,6 +1,7 @@
 mod base;
 mod batch;
 mod builder;
+mod iterable;
 mod multithread;
 mod strategy;
 
@@ -10,5 +11,6 @@ pub mod batcher;
 pub use base::*;
 pub use batch::*;
 pub use builder::*;
+pub use iterable::*;
 pub use multithread::*;
 pub use strategy::*;
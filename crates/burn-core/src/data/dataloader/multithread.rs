@@ -2,7 +2,7 @@ use super::{DataLoader, DataLoaderIterator, DynDataLoader, Progress};
 use std::sync::mpsc;
 use std::thread;
 
-const MAX_QUEUED_ITEMS: usize = 100;
+pub(crate) const MAX_QUEUED_ITEMS: usize = 100;
 
 /// A multi-threaded data loader that can be used to iterate over a dataset.
 pub struct MultiThreadDataLoader<O> {
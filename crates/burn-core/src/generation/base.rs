@@ -0,0 +1,328 @@
+use alloc::{vec, vec::Vec};
+
+use crate as burn;
+
+use super::{Sampler, SamplingConfig};
+use crate::config::Config;
+use crate::tensor::ops::IntElem;
+use crate::tensor::{backend::Backend, ElementConversion, Int, Tensor, TensorData};
+
+/// A model generating sequences of tokens, one position at a time.
+///
+/// The model keeps the sequences it has seen in its state, such as the
+/// [key-value caches](crate::nn::cache::KvCache) of its attention layers, so that each step only
+/// processes the new tokens.
+pub trait GenerationModel<B: Backend> {
+    /// The state of the model for a batch of sequences.
+    type State;
+
+    /// Initializes the state of the model for the given number of sequences.
+    fn init_state(&self, batch_size: usize, device: &B::Device) -> Self::State;
+
+    /// Computes the logits of the next token of each sequence, after processing its new tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The new tokens of each sequence `[batch_size, seq_length]`, which are the
+    ///   prompts at the first step and the last generated tokens afterward.
+    /// * `num_tokens` - The number of new tokens of each sequence, the remaining ones being right
+    ///   padding. Zero for the sequences that are done generating.
+    /// * `state` - The state of the model.
+    ///
+    /// # Returns
+    ///
+    /// The logits `[batch_size, vocab_size]` following the last new token of each sequence.
+    fn next_token_logits(
+        &self,
+        tokens: Tensor<B, 2, Int>,
+        num_tokens: &[usize],
+        state: &mut Self::State,
+    ) -> Tensor<B, 2>;
+
+    /// Reorders the sequences of the state, the sequence at the index `i` of the new state being
+    /// the sequence at the index `indices[i]` of the previous one. Used by the
+    /// [beam search](super::BeamSearch) to follow the selected beams.
+    fn reorder_state(&self, state: Self::State, indices: &[usize]) -> Self::State;
+}
+
+/// Configuration to create a [generator](Generator) using the [init function](GenerationConfig::init).
+#[derive(Config, Debug)]
+pub struct GenerationConfig {
+    /// The maximum number of tokens generated for each sequence.
+    pub max_new_tokens: usize,
+    /// The tokens ending a sequence, which are included in the generated tokens. Default: none
+    #[config(default = "Vec::new()")]
+    pub stop_tokens: Vec<usize>,
+    /// The sampling of the next tokens. Default: multinomial sampling
+    #[config(default = "SamplingConfig::new()")]
+    pub sampling: SamplingConfig,
+}
+
+impl GenerationConfig {
+    /// Initialize a new [generator](Generator).
+    pub fn init(&self) -> Generator {
+        Generator {
+            sampler: self.sampling.init(),
+            max_new_tokens: self.max_new_tokens,
+            stop_tokens: self.stop_tokens.clone(),
+        }
+    }
+}
+
+/// The reason why the generation of a sequence ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FinishReason {
+    /// A stop token was generated.
+    Stop(usize),
+    /// The maximum number of new tokens was generated.
+    Length,
+}
+
+/// A token generated by the [generator](Generator), given to the streaming callback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GeneratedToken {
+    /// The index of the sequence in the batch.
+    pub sequence: usize,
+    /// The generated token.
+    pub token: usize,
+}
+
+/// The sequences generated by the [generator](Generator).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenerationOutput {
+    /// The generated tokens of each sequence, without the prompt.
+    pub tokens: Vec<Vec<usize>>,
+    /// Why the generation of each sequence ended.
+    pub finish_reasons: Vec<FinishReason>,
+}
+
+/// Generates sequences from prompts by sampling one token at a time.
+///
+/// Should be created using [GenerationConfig].
+#[derive(Clone, Debug)]
+pub struct Generator {
+    sampler: Sampler,
+    max_new_tokens: usize,
+    stop_tokens: Vec<usize>,
+}
+
+impl Generator {
+    /// Generates the continuation of each prompt.
+    pub fn generate<B: Backend, M: GenerationModel<B>>(
+        &self,
+        model: &M,
+        prompts: &[Vec<usize>],
+        device: &B::Device,
+    ) -> GenerationOutput {
+        self.generate_stream(model, prompts, device, |_| {})
+    }
+
+    /// Generates the continuation of each prompt, calling the callback with each token as soon
+    /// as it is generated.
+    pub fn generate_stream<B, M, F>(
+        &self,
+        model: &M,
+        prompts: &[Vec<usize>],
+        device: &B::Device,
+        mut callback: F,
+    ) -> GenerationOutput
+    where
+        B: Backend,
+        M: GenerationModel<B>,
+        F: FnMut(GeneratedToken),
+    {
+        assert!(
+            prompts.iter().all(|prompt| !prompt.is_empty()),
+            "The prompts should have at least one token"
+        );
+
+        let batch_size = prompts.len();
+        let mut state = model.init_state(batch_size, device);
+        let mut sequences = prompts.to_vec();
+        let mut generated = vec![Vec::new(); batch_size];
+        let mut finish_reasons = vec![None; batch_size];
+
+        let mut num_tokens = prompts.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut tokens = padded_tokens::<B>(prompts, device);
+
+        for _ in 0..self.max_new_tokens {
+            let logits = model.next_token_logits(tokens, &num_tokens, &mut state);
+            let next = self.sampler.sample(logits, &sequences);
+            let next = to_tokens(next);
+
+            for (sequence, token) in next.iter().copied().enumerate() {
+                if finish_reasons[sequence].is_some() {
+                    num_tokens[sequence] = 0;
+                    continue;
+                }
+
+                sequences[sequence].push(token);
+                generated[sequence].push(token);
+                callback(GeneratedToken { sequence, token });
+
+                if self.stop_tokens.contains(&token) {
+                    finish_reasons[sequence] = Some(FinishReason::Stop(token));
+                    num_tokens[sequence] = 0;
+                } else {
+                    num_tokens[sequence] = 1;
+                }
+            }
+
+            if finish_reasons.iter().all(Option::is_some) {
+                break;
+            }
+
+            let next = next
+                .into_iter()
+                .map(|token| vec![token])
+                .collect::<Vec<_>>();
+            tokens = padded_tokens::<B>(&next, device);
+        }
+
+        GenerationOutput {
+            tokens: generated,
+            finish_reasons: finish_reasons
+                .into_iter()
+                .map(|reason| reason.unwrap_or(FinishReason::Length))
+                .collect(),
+        }
+    }
+}
+
+/// Creates the tensor of the sequences right padded with the token 0.
+pub(crate) fn padded_tokens<B: Backend>(
+    sequences: &[Vec<usize>],
+    device: &B::Device,
+) -> Tensor<B, 2, Int> {
+    let seq_length = sequences.iter().map(Vec::len).max().unwrap_or(0);
+    let tokens = sequences
+        .iter()
+        .flat_map(|sequence| {
+            (0..seq_length).map(|i| (sequence.get(i).copied().unwrap_or(0) as i64).elem())
+        })
+        .collect::<Vec<IntElem<B>>>();
+
+    Tensor::from_data(
+        TensorData::new(tokens, [sequences.len(), seq_length]),
+        device,
+    )
+}
+
+/// Reads the tokens of a tensor.
+pub(crate) fn to_tokens<B: Backend>(tokens: Tensor<B, 1, Int>) -> Vec<usize> {
+    tokens
+        .into_data()
+        .iter::<i64>()
+        .map(|token| token as usize)
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    /// A model whose next token probabilities only depend on the last token of the sequence,
+    /// keeping the tokens of each sequence as its state.
+    pub(crate) struct TableModel {
+        pub(crate) probs: Vec<Vec<f32>>,
+    }
+
+    impl TableModel {
+        /// A model always predicting the token `next[last]`.
+        pub(crate) fn chain(next: &[usize]) -> Self {
+            let probs = next
+                .iter()
+                .map(|token| {
+                    let mut probs = vec![0.0; next.len()];
+                    probs[*token] = 1.0;
+                    probs
+                })
+                .collect();
+            Self { probs }
+        }
+    }
+
+    impl GenerationModel<TestBackend> for TableModel {
+        type State = Vec<Vec<usize>>;
+
+        fn init_state(
+            &self,
+            batch_size: usize,
+            _device: &<TestBackend as Backend>::Device,
+        ) -> Self::State {
+            vec![Vec::new(); batch_size]
+        }
+
+        fn next_token_logits(
+            &self,
+            tokens: Tensor<TestBackend, 2, Int>,
+            num_tokens: &[usize],
+            state: &mut Self::State,
+        ) -> Tensor<TestBackend, 2> {
+            let [batch_size, seq_length] = tokens.dims();
+            let tokens = to_tokens(tokens.reshape([batch_size * seq_length]));
+            let mut logits = Vec::new();
+
+            for (i, sequence) in state.iter_mut().enumerate() {
+                sequence.extend(&tokens[i * seq_length..i * seq_length + num_tokens[i]]);
+                let last = *sequence.last().unwrap();
+                logits.extend(self.probs[last].iter().map(|p| p.max(1e-6).ln()));
+            }
+
+            let vocab_size = self.probs.len();
+            Tensor::from_data(
+                TensorData::new(logits, [batch_size, vocab_size]),
+                &Default::default(),
+            )
+        }
+
+        fn reorder_state(&self, state: Self::State, indices: &[usize]) -> Self::State {
+            indices.iter().map(|i| state[*i].clone()).collect()
+        }
+    }
+
+    #[test]
+    fn test_greedy_generation_with_stop_token() {
+        let model = TableModel::chain(&[1, 2, 3, 4, 0]);
+        let generator = GenerationConfig::new(5)
+            .with_stop_tokens(vec![4])
+            .with_sampling(SamplingConfig::greedy())
+            .init();
+        let mut streamed = Vec::new();
+
+        let output = generator.generate_stream(
+            &model,
+            &[vec![0], vec![3, 2]],
+            &Default::default(),
+            |token| streamed.push(token),
+        );
+
+        assert_eq!(output.tokens, vec![vec![1, 2, 3, 4], vec![3, 4]]);
+        assert_eq!(
+            output.finish_reasons,
+            vec![FinishReason::Stop(4), FinishReason::Stop(4)]
+        );
+        assert_eq!(streamed.len(), 6);
+        assert_eq!(
+            streamed[1],
+            GeneratedToken {
+                sequence: 1,
+                token: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_generation_stops_at_max_new_tokens() {
+        let model = TableModel::chain(&[1, 2, 0]);
+        let generator = GenerationConfig::new(4)
+            .with_sampling(SamplingConfig::greedy())
+            .init();
+
+        let output = generator.generate(&model, &[vec![0]], &Default::default());
+
+        assert_eq!(output.tokens, vec![vec![1, 2, 0, 1]]);
+        assert_eq!(output.finish_reasons, vec![FinishReason::Length]);
+    }
+}
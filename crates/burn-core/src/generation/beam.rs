@@ -0,0 +1,475 @@
+use alloc::{vec, vec::Vec};
+
+use crate as burn;
+
+use super::{padded_tokens, GenerationModel};
+use crate::config::Config;
+use crate::tensor::{activation, backend::Backend, Tensor, TensorData};
+
+/// Configuration to create a [beam search](BeamSearch) using the [init function](BeamSearchConfig::init).
+#[derive(Config, Debug)]
+pub struct BeamSearchConfig {
+    /// The number of sequences kept at each step, and returned for each prompt.
+    pub num_beams: usize,
+    /// The maximum number of tokens generated for each sequence.
+    pub max_new_tokens: usize,
+    /// The exponent of the length dividing the log probability of a sequence. Values above zero
+    /// favor longer sequences, and values below zero favor shorter ones. Default: 1.0
+    #[config(default = 1.0)]
+    pub length_penalty: f64,
+    /// The tokens ending a sequence, which are included in the generated tokens. Default: none
+    #[config(default = "Vec::new()")]
+    pub stop_tokens: Vec<usize>,
+}
+
+impl BeamSearchConfig {
+    /// Initialize a new [beam search](BeamSearch).
+    pub fn init(&self) -> BeamSearch {
+        assert!(self.num_beams > 0, "The number of beams should be positive");
+
+        BeamSearch {
+            config: self.clone(),
+        }
+    }
+}
+
+/// A sequence found by the [beam search](BeamSearch).
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeamHypothesis {
+    /// The generated tokens, without the prompt.
+    pub tokens: Vec<usize>,
+    /// The log probability of the generated tokens divided by their number raised to the length
+    /// penalty.
+    pub score: f64,
+}
+
+/// Generates the most likely continuations of prompts by keeping the best sequences at each
+/// step.
+///
+/// The model state is [reordered](GenerationModel::reorder_state) at each step to follow the
+/// selected beams.
+///
+/// Should be created using [BeamSearchConfig].
+#[derive(Clone, Debug)]
+pub struct BeamSearch {
+    config: BeamSearchConfig,
+}
+
+impl BeamSearch {
+    /// Searches the continuations of each prompt.
+    ///
+    /// # Returns
+    ///
+    /// The hypotheses of each prompt, sorted by decreasing score.
+    pub fn search<B: Backend, M: GenerationModel<B>>(
+        &self,
+        model: &M,
+        prompts: &[Vec<usize>],
+        device: &B::Device,
+    ) -> Vec<Vec<BeamHypothesis>> {
+        assert!(
+            prompts.iter().all(|prompt| !prompt.is_empty()),
+            "The prompts should have at least one token"
+        );
+
+        let num_beams = self.config.num_beams;
+        let num_prompts = prompts.len();
+        let num_rows = num_prompts * num_beams;
+
+        let beam_prompts = prompts
+            .iter()
+            .flat_map(|prompt| vec![prompt.clone(); num_beams])
+            .collect::<Vec<_>>();
+        let mut state = model.init_state(num_rows, device);
+        let mut tokens = padded_tokens::<B>(&beam_prompts, device);
+        let mut num_tokens = beam_prompts.iter().map(Vec::len).collect::<Vec<_>>();
+
+        // Only the first beam is active at first, since all the beams have the same prompt.
+        let mut beams = vec![Vec::new(); num_rows];
+        let mut scores = (0..num_rows)
+            .map(|row| match row % num_beams {
+                0 => 0.0,
+                _ => f64::NEG_INFINITY,
+            })
+            .collect::<Vec<_>>();
+        let mut finished = vec![Vec::new(); num_prompts];
+        let mut done = vec![false; num_prompts];
+
+        for step in 0..self.config.max_new_tokens {
+            let logits = model.next_token_logits(tokens, &num_tokens, &mut state);
+            let [_, vocab_size] = logits.dims();
+            let num_candidates = usize::min(2 * num_beams, num_beams * vocab_size);
+
+            let beam_scores = Tensor::<B, 2>::from_data(
+                TensorData::new(
+                    scores.iter().map(|score| *score as f32).collect(),
+                    [num_rows, 1],
+                ),
+                device,
+            );
+            let (values, indices) = activation::log_softmax(logits, 1)
+                .add(beam_scores.expand([num_rows, vocab_size]))
+                .reshape([num_prompts, num_beams * vocab_size])
+                .topk_with_indices(num_candidates, 1);
+            let values = values.into_data().iter::<f64>().collect::<Vec<_>>();
+            let indices = indices.into_data().iter::<i64>().collect::<Vec<_>>();
+
+            let mut next_rows = Vec::with_capacity(num_rows);
+            let mut next_tokens = Vec::with_capacity(num_rows);
+            let mut next_scores = Vec::with_capacity(num_rows);
+
+            for prompt in 0..num_prompts {
+                let mut num_selected = 0;
+
+                if !done[prompt] {
+                    for candidate in 0..num_candidates {
+                        let offset = prompt * num_candidates + candidate;
+                        let score = values[offset];
+                        if score == f64::NEG_INFINITY {
+                            break;
+                        }
+
+                        let row = prompt * num_beams + indices[offset] as usize / vocab_size;
+                        let token = indices[offset] as usize % vocab_size;
+
+                        if self.config.stop_tokens.contains(&token) {
+                            // Only the stop tokens among the best candidates end a hypothesis.
+                            if candidate < num_beams {
+                                let mut sequence = beams[row].clone();
+                                sequence.push(token);
+                                finished[prompt].push(self.hypothesis(sequence, score));
+                            }
+                            continue;
+                        }
+
+                        next_rows.push(row);
+                        next_tokens.push(token);
+                        next_scores.push(score);
+                        num_selected += 1;
+
+                        if num_selected == num_beams {
+                            break;
+                        }
+                    }
+                }
+
+                // Inactive beams keep generating, but are never selected.
+                for _ in num_selected..num_beams {
+                    next_rows.push(prompt * num_beams);
+                    next_tokens.push(0);
+                    next_scores.push(f64::NEG_INFINITY);
+                }
+
+                if !done[prompt] {
+                    done[prompt] = self.is_done(
+                        &mut finished[prompt],
+                        &next_scores[prompt * num_beams..],
+                        step + 1,
+                    );
+                }
+            }
+
+            beams = next_rows
+                .iter()
+                .zip(&next_tokens)
+                .map(|(row, token)| {
+                    let mut beam = beams[*row].clone();
+                    beam.push(*token);
+                    beam
+                })
+                .collect();
+            scores = next_scores;
+            state = model.reorder_state(state, &next_rows);
+
+            if done.iter().all(|done| *done) {
+                break;
+            }
+
+            let next_tokens = next_tokens
+                .into_iter()
+                .map(|token| vec![token])
+                .collect::<Vec<_>>();
+            tokens = padded_tokens::<B>(&next_tokens, device);
+            num_tokens = (0..num_rows)
+                .map(|row| match done[row / num_beams] {
+                    true => 0,
+                    false => 1,
+                })
+                .collect();
+        }
+
+        // The prompts without enough finished hypotheses complete them with their active beams.
+        for (prompt, finished) in finished.iter_mut().enumerate() {
+            if !done[prompt] {
+                for row in prompt * num_beams..(prompt + 1) * num_beams {
+                    if scores[row] != f64::NEG_INFINITY {
+                        finished.push(self.hypothesis(beams[row].clone(), scores[row]));
+                    }
+                }
+            }
+
+            sort_hypotheses(finished, num_beams);
+        }
+
+        finished
+    }
+
+    fn hypothesis(&self, tokens: Vec<usize>, log_prob: f64) -> BeamHypothesis {
+        let length = tokens.len() as f64;
+
+        BeamHypothesis {
+            tokens,
+            score: log_prob / length.powf(self.config.length_penalty),
+        }
+    }
+
+    /// Whether no active beam can be better than the finished hypotheses, keeping only the best
+    /// finished hypotheses.
+    fn is_done(
+        &self,
+        finished: &mut Vec<BeamHypothesis>,
+        active_scores: &[f64],
+        length: usize,
+    ) -> bool {
+        let num_beams = self.config.num_beams;
+        sort_hypotheses(finished, num_beams);
+
+        if finished.len() < num_beams {
+            return false;
+        }
+
+        let best_active = active_scores
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let best_active = best_active / (length as f64).powf(self.config.length_penalty);
+
+        best_active <= finished[num_beams - 1].score
+    }
+}
+
+fn sort_hypotheses(hypotheses: &mut Vec<BeamHypothesis>, num_beams: usize) {
+    hypotheses.sort_by(|a, b| b.score.total_cmp(&a.score));
+    hypotheses.truncate(num_beams);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::{
+        base::{padded_tokens, tests::TableModel, to_tokens},
+        GenerationConfig, SamplingConfig,
+    };
+    use crate::nn::{
+        attention::{GqaInput, GroupedQueryAttention, GroupedQueryAttentionConfig},
+        cache::{KvCache, KvCacheConfig, KvCacheLayout},
+        Embedding, EmbeddingConfig, Linear, LinearConfig, RotaryEncodingConfig,
+    };
+    use crate::tensor::Int;
+    use crate::TestBackend;
+
+    const VOCAB_SIZE: usize = 8;
+    const MAX_SEQ_LENGTH: usize = 8;
+
+    /// A single attention layer decoding with a key-value cache.
+    #[derive(Clone)]
+    struct AttentionModel {
+        embedding: Embedding<TestBackend>,
+        attention: GroupedQueryAttention<TestBackend>,
+        output: Linear<TestBackend>,
+        layout: KvCacheLayout,
+    }
+
+    impl AttentionModel {
+        fn new(layout: KvCacheLayout) -> Self {
+            let device = Default::default();
+
+            Self {
+                embedding: EmbeddingConfig::new(VOCAB_SIZE, 16).init(&device),
+                attention: GroupedQueryAttentionConfig::new(16, 4, 2)
+                    .with_rotary_encoding(Some(RotaryEncodingConfig::new(16, 4)))
+                    .init(&device),
+                output: LinearConfig::new(16, VOCAB_SIZE).init(&device),
+                layout,
+            }
+        }
+
+        /// The logits following the last of the given number of positions of each sequence.
+        fn logits(
+            &self,
+            x: Tensor<TestBackend, 3>,
+            num_tokens: &[usize],
+        ) -> Tensor<TestBackend, 2> {
+            let [batch_size, _, d_model] = x.dims();
+            let last = num_tokens
+                .iter()
+                .enumerate()
+                .map(|(i, num_tokens)| {
+                    let position = num_tokens.saturating_sub(1);
+                    x.clone()
+                        .slice([i..i + 1, position..position + 1, 0..d_model])
+                })
+                .collect();
+
+            self.output
+                .forward(Tensor::cat(last, 0).reshape([batch_size, d_model]))
+        }
+    }
+
+    impl GenerationModel<TestBackend> for AttentionModel {
+        type State = KvCache<TestBackend>;
+
+        fn init_state(
+            &self,
+            batch_size: usize,
+            device: &<TestBackend as Backend>::Device,
+        ) -> Self::State {
+            KvCacheConfig::new(batch_size, 2, 4, MAX_SEQ_LENGTH)
+                .with_layout(self.layout.clone())
+                .init(device)
+        }
+
+        fn next_token_logits(
+            &self,
+            tokens: Tensor<TestBackend, 2, Int>,
+            num_tokens: &[usize],
+            state: &mut Self::State,
+        ) -> Tensor<TestBackend, 2> {
+            let input =
+                GqaInput::new(self.embedding.forward(tokens)).num_tokens(num_tokens.to_vec());
+            let x = self.attention.forward_cache(input, state);
+
+            self.logits(x, num_tokens)
+        }
+
+        fn reorder_state(&self, mut state: Self::State, indices: &[usize]) -> Self::State {
+            state.reorder(indices);
+            state
+        }
+    }
+
+    /// The same model recomputing the attention over the whole sequences at each step.
+    struct RecomputedModel<'a>(&'a AttentionModel);
+
+    impl GenerationModel<TestBackend> for RecomputedModel<'_> {
+        type State = Vec<Vec<usize>>;
+
+        fn init_state(
+            &self,
+            batch_size: usize,
+            _device: &<TestBackend as Backend>::Device,
+        ) -> Self::State {
+            vec![Vec::new(); batch_size]
+        }
+
+        fn next_token_logits(
+            &self,
+            tokens: Tensor<TestBackend, 2, Int>,
+            num_tokens: &[usize],
+            state: &mut Self::State,
+        ) -> Tensor<TestBackend, 2> {
+            let [batch_size, seq_length] = tokens.dims();
+            let tokens = to_tokens(tokens.reshape([batch_size * seq_length]));
+            for (i, sequence) in state.iter_mut().enumerate() {
+                sequence.extend(&tokens[i * seq_length..i * seq_length + num_tokens[i]]);
+            }
+
+            let model = self.0;
+            let x = model
+                .embedding
+                .forward(padded_tokens::<TestBackend>(state, &Default::default()));
+            let x = model.attention.forward(GqaInput::new(x).causal());
+            let lengths = state.iter().map(Vec::len).collect::<Vec<_>>();
+
+            model.logits(x, &lengths)
+        }
+
+        fn reorder_state(&self, state: Self::State, indices: &[usize]) -> Self::State {
+            indices.iter().map(|i| state[*i].clone()).collect()
+        }
+    }
+
+    fn model() -> TableModel {
+        // The greedy decoding selects the token 1 then the stop token 4, but the sequence starting
+        // with the token 2 is more likely.
+        TableModel {
+            probs: vec![
+                vec![0.0, 0.6, 0.4, 0.0, 0.0],
+                vec![0.3, 0.0, 0.0, 0.3, 0.4],
+                vec![0.05, 0.0, 0.0, 0.05, 0.9],
+                vec![0.5, 0.5, 0.0, 0.0, 0.0],
+                vec![1.0, 0.0, 0.0, 0.0, 0.0],
+            ],
+        }
+    }
+
+    #[test]
+    fn test_beam_search_finds_most_likely_sequence() {
+        let beam_search = BeamSearchConfig::new(2, 5).with_stop_tokens(vec![4]).init();
+
+        let hypotheses = beam_search.search(&model(), &[vec![0]], &Default::default());
+
+        assert_eq!(hypotheses.len(), 1);
+        assert_eq!(hypotheses[0].len(), 2);
+        assert_eq!(hypotheses[0][0].tokens, vec![2, 4]);
+        assert_eq!(hypotheses[0][1].tokens, vec![1, 4]);
+        assert!((hypotheses[0][0].score - 0.36f64.ln() / 2.0).abs() < 1e-4);
+
+        let greedy = GenerationConfig::new(5)
+            .with_stop_tokens(vec![4])
+            .with_sampling(SamplingConfig::greedy())
+            .init()
+            .generate(&model(), &[vec![0]], &Default::default());
+        assert_eq!(greedy.tokens, vec![vec![1, 4]]);
+    }
+
+    #[test]
+    fn test_beam_search_without_stop_token() {
+        let beam_search = BeamSearchConfig::new(2, 2).init();
+
+        let hypotheses = beam_search.search(&model(), &[vec![0], vec![3]], &Default::default());
+
+        assert_eq!(hypotheses[0][0].tokens, vec![2, 4]);
+        assert_eq!(hypotheses[1][0].tokens, vec![0, 1]);
+        assert_eq!(hypotheses[1][1].tokens.len(), 2);
+    }
+
+    #[test]
+    fn test_beam_search_with_cached_attention_should_match_recomputed_attention() {
+        let device = Default::default();
+        let prompts = [vec![1, 2, 3], vec![4]];
+        let beam_search = BeamSearchConfig::new(3, 4).init();
+        let layouts = [
+            KvCacheLayout::Contiguous,
+            // The window holds every position, and the paged cache has enough pages to copy the
+            // sequences of the selected beams.
+            KvCacheLayout::SlidingWindow {
+                window_size: MAX_SEQ_LENGTH,
+            },
+            KvCacheLayout::Paged {
+                page_size: 2,
+                num_pages: 32,
+            },
+        ];
+
+        let model = AttentionModel::new(KvCacheLayout::Contiguous);
+        let expected = beam_search.search(&RecomputedModel(&model), &prompts, &device);
+
+        for layout in layouts {
+            let model = AttentionModel {
+                layout,
+                ..model.clone()
+            };
+            let hypotheses = beam_search.search(&model, &prompts, &device);
+
+            assert_eq!(hypotheses.len(), expected.len());
+            for (hypotheses, expected) in hypotheses.iter().zip(&expected) {
+                assert_eq!(hypotheses.len(), 3);
+                for (hypothesis, expected) in hypotheses.iter().zip(expected) {
+                    assert_eq!(hypothesis.tokens, expected.tokens);
+                    assert!((hypothesis.score - expected.score).abs() < 1e-4);
+                }
+            }
+        }
+    }
+}
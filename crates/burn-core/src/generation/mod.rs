@@ -0,0 +1,7 @@
+mod base;
+mod beam;
+mod sampling;
+
+pub use base::*;
+pub use beam::*;
+pub use sampling::*;
@@ -0,0 +1,359 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::ops::IntElem;
+use crate::tensor::{
+    activation, backend::Backend, Distribution, ElementConversion, Int, Tensor, TensorData,
+};
+
+/// Configuration to create a [sampler](Sampler) using the [init function](SamplingConfig::init).
+///
+/// The logits are processed in the following order: repetition penalty, temperature, top-k,
+/// top-p and min-p, before sampling the next token from the remaining candidates.
+#[derive(Config, Debug)]
+pub struct SamplingConfig {
+    /// The temperature dividing the logits, zero selecting the most likely token (greedy
+    /// decoding). Default: 1.0
+    #[config(default = 1.0)]
+    pub temperature: f64,
+    /// Only keep the `top_k` most likely tokens. Default: None
+    pub top_k: Option<usize>,
+    /// Only keep the smallest set of most likely tokens whose cumulative probability reaches
+    /// `top_p` (nucleus sampling). Default: None
+    pub top_p: Option<f64>,
+    /// Only keep the tokens whose probability is at least `min_p` times the probability of the
+    /// most likely token. Default: None
+    pub min_p: Option<f64>,
+    /// The penalty applied to the tokens already present in the sequence, dividing their positive
+    /// logits and multiplying their negative logits. A value of 1.0 applies no penalty.
+    ///
+    /// Reference: [CTRL: A Conditional Transformer Language Model for Controllable Generation](https://arxiv.org/abs/1909.05858)
+    ///
+    /// Default: 1.0
+    #[config(default = 1.0)]
+    pub repetition_penalty: f64,
+}
+
+impl SamplingConfig {
+    /// Configuration of the greedy decoding, which always selects the most likely token.
+    pub fn greedy() -> Self {
+        Self::new().with_temperature(0.0)
+    }
+
+    /// Initialize a new [sampler](Sampler).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a parameter is out of its range.
+    pub fn init(&self) -> Sampler {
+        assert!(
+            self.temperature >= 0.0,
+            "The temperature should be positive or zero"
+        );
+        assert!(
+            self.repetition_penalty > 0.0,
+            "The repetition penalty should be positive"
+        );
+        if let Some(top_k) = self.top_k {
+            assert!(top_k > 0, "Top-k should keep at least one token");
+        }
+        if let Some(top_p) = self.top_p {
+            assert!(
+                top_p > 0.0 && top_p <= 1.0,
+                "Top-p should be in the range (0, 1]"
+            );
+        }
+        if let Some(min_p) = self.min_p {
+            assert!(
+                (0.0..=1.0).contains(&min_p),
+                "Min-p should be in the range [0, 1]"
+            );
+        }
+
+        Sampler {
+            config: self.clone(),
+        }
+    }
+}
+
+/// Selects the next token of each sequence from the logits of a model.
+///
+/// Should be created using [SamplingConfig].
+#[derive(Clone, Debug)]
+pub struct Sampler {
+    config: SamplingConfig,
+}
+
+impl Sampler {
+    /// Samples the next token of each sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `logits` - The logits of the next token `[batch_size, vocab_size]`.
+    /// * `previous_tokens` - The tokens of each sequence, used by the repetition penalty.
+    ///
+    /// # Returns
+    ///
+    /// The next tokens `[batch_size]`.
+    pub fn sample<B: Backend>(
+        &self,
+        logits: Tensor<B, 2>,
+        previous_tokens: &[Vec<usize>],
+    ) -> Tensor<B, 1, Int> {
+        let [batch_size, _] = logits.dims();
+        let logits = self.process(logits, previous_tokens);
+
+        if self.config.temperature == 0.0 {
+            return logits.argmax(1).reshape([batch_size]);
+        }
+
+        let probs = activation::softmax(logits, 1);
+        let cumulative = probs.cumsum(1);
+        let [_, vocab_size] = cumulative.dims();
+
+        // Inverse transform sampling, scaled by the total to be robust to rounding errors.
+        let total = cumulative.clone().narrow(1, vocab_size - 1, 1);
+        let random = Tensor::random([batch_size, 1], Distribution::Default, &total.device());
+        let threshold = random.mul(total).expand([batch_size, vocab_size]);
+
+        cumulative
+            .lower_equal(threshold)
+            .int()
+            .sum_dim(1)
+            .reshape([batch_size])
+    }
+
+    /// Applies the repetition penalty, the temperature and the filters to the logits, the
+    /// filtered tokens having a logit of `-inf`.
+    pub fn process<B: Backend>(
+        &self,
+        mut logits: Tensor<B, 2>,
+        previous_tokens: &[Vec<usize>],
+    ) -> Tensor<B, 2> {
+        if self.config.repetition_penalty != 1.0 {
+            logits = repetition_penalty(logits, previous_tokens, self.config.repetition_penalty);
+        }
+
+        if self.config.temperature == 0.0 {
+            return logits;
+        }
+        logits = logits.div_scalar(self.config.temperature);
+
+        if let Some(top_k) = self.config.top_k {
+            logits = top_k_filter(logits, top_k);
+        }
+        if let Some(top_p) = self.config.top_p {
+            logits = top_p_filter(logits, top_p);
+        }
+        if let Some(min_p) = self.config.min_p {
+            logits = min_p_filter(logits, min_p);
+        }
+
+        logits
+    }
+}
+
+/// Penalizes the logits of the tokens present in each sequence.
+fn repetition_penalty<B: Backend>(
+    logits: Tensor<B, 2>,
+    previous_tokens: &[Vec<usize>],
+    penalty: f64,
+) -> Tensor<B, 2> {
+    let [batch_size, _] = logits.dims();
+    let num_tokens = previous_tokens.iter().map(Vec::len).max().unwrap_or(0);
+    if num_tokens == 0 {
+        return logits;
+    }
+
+    // Sequences shorter than the longest one are padded with the token 0 and a zero count.
+    let mut indices = Vec::with_capacity(batch_size * num_tokens);
+    let mut counts = Vec::with_capacity(batch_size * num_tokens);
+    for tokens in previous_tokens {
+        for i in 0..num_tokens {
+            indices.push((tokens.get(i).copied().unwrap_or(0) as i64).elem::<IntElem<B>>());
+            counts.push(match i < tokens.len() {
+                true => 1.0f32,
+                false => 0.0,
+            });
+        }
+    }
+
+    let device = logits.device();
+    let indices =
+        Tensor::<B, 2, Int>::from_data(TensorData::new(indices, [batch_size, num_tokens]), &device);
+    let counts =
+        Tensor::<B, 2>::from_data(TensorData::new(counts, [batch_size, num_tokens]), &device);
+    let present = logits
+        .zeros_like()
+        .scatter(1, indices, counts)
+        .greater_elem(0.0);
+
+    let negative = logits.clone().lower_elem(0.0);
+    let penalized = logits
+        .clone()
+        .div_scalar(penalty)
+        .mask_where(negative, logits.clone().mul_scalar(penalty));
+
+    logits.mask_where(present, penalized)
+}
+
+/// Filters the tokens less likely than the `k`-th most likely token.
+fn top_k_filter<B: Backend>(logits: Tensor<B, 2>, k: usize) -> Tensor<B, 2> {
+    let [batch_size, vocab_size] = logits.dims();
+    if k >= vocab_size {
+        return logits;
+    }
+
+    let threshold = logits
+        .clone()
+        .topk(k, 1)
+        .narrow(1, k - 1, 1)
+        .expand([batch_size, vocab_size]);
+    let mask = logits.clone().lower(threshold);
+
+    logits.mask_fill(mask, f32::NEG_INFINITY)
+}
+
+/// Filters the tokens outside of the smallest set of most likely tokens whose cumulative
+/// probability reaches `p`, always keeping the most likely token.
+fn top_p_filter<B: Backend>(logits: Tensor<B, 2>, p: f64) -> Tensor<B, 2> {
+    let probs = activation::softmax(logits.clone(), 1);
+    let (sorted, indices) = probs.sort_descending_with_indices(1);
+
+    // A token is removed when the tokens more likely than itself already reach the probability.
+    let removed = sorted
+        .cumsum_with_options(1, true, false)
+        .greater_equal_elem(p)
+        .float();
+    let mask = removed
+        .zeros_like()
+        .scatter(1, indices, removed)
+        .greater_elem(0.0);
+
+    logits.mask_fill(mask, f32::NEG_INFINITY)
+}
+
+/// Filters the tokens whose probability is lower than `min_p` times the highest probability.
+fn min_p_filter<B: Backend>(logits: Tensor<B, 2>, min_p: f64) -> Tensor<B, 2> {
+    let [batch_size, vocab_size] = logits.dims();
+    let probs = activation::softmax(logits.clone(), 1);
+    let threshold = probs
+        .clone()
+        .max_dim(1)
+        .mul_scalar(min_p)
+        .expand([batch_size, vocab_size]);
+    let mask = probs.lower(threshold);
+
+    logits.mask_fill(mask, f32::NEG_INFINITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec;
+
+    fn logits() -> Tensor<TestBackend, 2> {
+        // Probabilities of [0.1, 0.2, 0.3, 0.4] and [0.7, 0.1, 0.1, 0.1].
+        Tensor::<TestBackend, 2>::from_floats(
+            [[0.1, 0.2, 0.3, 0.4], [0.7, 0.1, 0.1, 0.1]],
+            &Default::default(),
+        )
+        .log()
+    }
+
+    fn kept(sampler: &Sampler, previous_tokens: &[Vec<usize>]) -> TensorData {
+        sampler
+            .process(logits(), previous_tokens)
+            .equal_elem(f32::NEG_INFINITY)
+            .bool_not()
+            .into_data()
+    }
+
+    #[test]
+    fn test_greedy_sampling() {
+        let sampler = SamplingConfig::greedy().init();
+
+        let tokens = sampler.sample(logits(), &[vec![], vec![]]);
+
+        tokens
+            .into_data()
+            .assert_eq(&TensorData::from([3, 0]), false);
+    }
+
+    #[test]
+    fn test_repetition_penalty() {
+        let sampler = SamplingConfig::greedy().with_repetition_penalty(2.0).init();
+
+        let processed = sampler.process(logits(), &[vec![3, 3], vec![1]]);
+
+        let expected = logits().into_data().to_vec::<f32>().unwrap();
+        processed.into_data().assert_approx_eq(
+            &TensorData::new(
+                vec![
+                    expected[0],
+                    expected[1],
+                    expected[2],
+                    expected[3] * 2.0,
+                    expected[4],
+                    expected[5] * 2.0,
+                    expected[6],
+                    expected[7],
+                ],
+                [2, 4],
+            ),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_top_k_filter() {
+        let sampler = SamplingConfig::new().with_top_k(Some(2)).init();
+
+        kept(&sampler, &[]).assert_eq(
+            &TensorData::from([[false, false, true, true], [true, true, true, true]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_top_p_filter() {
+        let sampler = SamplingConfig::new().with_top_p(Some(0.6)).init();
+
+        kept(&sampler, &[]).assert_eq(
+            &TensorData::from([[false, false, true, true], [true, false, false, false]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_min_p_filter() {
+        let sampler = SamplingConfig::new().with_min_p(Some(0.6)).init();
+
+        kept(&sampler, &[]).assert_eq(
+            &TensorData::from([[false, false, true, true], [true, false, false, false]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_sampling_only_selects_kept_tokens() {
+        let sampler = SamplingConfig::new()
+            .with_temperature(0.7)
+            .with_top_k(Some(2))
+            .init();
+
+        for _ in 0..20 {
+            let tokens = sampler
+                .sample(logits(), &[])
+                .into_data()
+                .to_vec::<i64>()
+                .unwrap();
+
+            assert!(tokens[0] == 2 || tokens[0] == 3);
+            assert!(tokens[1] <= 3);
+        }
+    }
+}
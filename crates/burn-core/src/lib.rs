@@ -29,6 +29,9 @@ pub mod lr_scheduler;
 /// Gradient clipping module.
 pub mod grad_clipping;
 
+/// Text generation module.
+pub mod generation;
+
 /// Module for the neural network module.
 pub mod module;
 
@@ -217,6 +217,10 @@ pub trait ModuleVisitor<B: Backend> {
     fn visit_int<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D, Int>) {}
     /// Visit a bool tensor in the module.
     fn visit_bool<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D, Bool>) {}
+    /// Called before visiting a submodule, named after its field or its index in a collection.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after visiting a submodule.
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module mapper trait.
@@ -241,6 +245,10 @@ pub trait ModuleMapper<B: Backend> {
     ) -> Tensor<B, D, Bool> {
         tensor
     }
+    /// Called before mapping a submodule, named after its field or its index in a collection.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after mapping a submodule.
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module with auto-differentiation backend.
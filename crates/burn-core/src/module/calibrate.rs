@@ -0,0 +1,275 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::Any;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
+use burn_common::stub::Mutex;
+use burn_tensor::{
+    backend::Backend,
+    quantization::{Observer, QuantizationParameters, QuantizationScheme},
+    Tensor,
+};
+use hashbrown::HashMap;
+
+use super::{Module, ModuleVisitor, ParamId};
+
+/// Observes the inputs of the modules while a calibration is running, with the id of the weight of
+/// the module receiving them.
+type InputHook = Box<dyn FnMut(ParamId, &dyn Any) + Send>;
+
+/// The hooks of the running calibrations, with their id.
+static INPUT_HOOKS: spin::Mutex<Vec<(usize, InputHook)>> = spin::Mutex::new(Vec::new());
+
+/// The number of running calibrations, to skip locking the hooks when there are none.
+static NUM_CALIBRATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The id of the next calibration.
+static NEXT_CALIBRATION_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Observes the input of the module with the given weight when a [calibration](CalibrationRunner)
+/// is running.
+pub(crate) fn observe_input<B: Backend, const D: usize>(weight: ParamId, input: &Tensor<B, D>) {
+    if NUM_CALIBRATIONS.load(Ordering::Acquire) == 0 {
+        return;
+    }
+
+    let mut hooks = INPUT_HOOKS.lock();
+    if hooks.is_empty() {
+        return;
+    }
+
+    // The observers only track statistics of the whole tensor.
+    let input: Tensor<B, 1> = input.clone().reshape([input.shape().num_elements()]);
+    for (_, hook) in hooks.iter_mut() {
+        hook(weight, &input);
+    }
+}
+
+/// Removes the hook of a running calibration when dropped.
+struct CalibrationGuard {
+    id: usize,
+}
+
+impl CalibrationGuard {
+    fn enter(hook: InputHook) -> Self {
+        let id = NEXT_CALIBRATION_ID.fetch_add(1, Ordering::Relaxed);
+        INPUT_HOOKS.lock().push((id, hook));
+        NUM_CALIBRATIONS.fetch_add(1, Ordering::Release);
+
+        Self { id }
+    }
+}
+
+impl Drop for CalibrationGuard {
+    fn drop(&mut self) {
+        NUM_CALIBRATIONS.fetch_sub(1, Ordering::Release);
+        INPUT_HOOKS.lock().retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// Finds the path of the module owning each parameter (e.g., `encoder.linear` for the parameter
+/// `encoder.linear.weight`).
+struct ModulePaths {
+    path: Vec<String>,
+    modules: HashMap<ParamId, String>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ModulePaths {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        let module = &self.path[..self.path.len().saturating_sub(1)];
+        self.modules.insert(id, module.join("."));
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+/// Collects the statistics of the activations of a model over a representative dataset, to
+/// compute the static quantization parameters of each observed activation.
+///
+/// The inputs of the [linear](crate::nn::Linear) and convolution modules are observed
+/// automatically while [running](Self::run) the model, identified by the path of the module that
+/// receives them (e.g., `encoder.linear1`). Other activations can be [observed](Self::observe)
+/// manually under any name. Each activation gets its own observer cloned from the one provided at
+/// creation.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let runner = CalibrationRunner::new(MinMaxObserver::new());
+/// runner.run(&model, batches, |model, batch| {
+///     model.forward(batch);
+/// });
+///
+/// let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+/// let qparams = runner.q_params::<B>(&scheme, &device);
+/// ```
+#[derive(Clone)]
+pub struct CalibrationRunner<O> {
+    observer: O,
+    observers: Arc<Mutex<BTreeMap<String, O>>>,
+}
+
+impl<O: Observer + Clone + Send + 'static> CalibrationRunner<O> {
+    /// Create a new calibration runner with the observer used for each activation.
+    pub fn new(observer: O) -> Self {
+        Self {
+            observer,
+            observers: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    /// Update the statistics of the activation with the given name.
+    pub fn observe<B: Backend, const D: usize>(&self, name: &str, tensor: &Tensor<B, D>) {
+        let mut observers = self.observers.lock().unwrap();
+
+        observers
+            .entry(name.to_string())
+            .or_insert_with(|| self.observer.clone())
+            .observe(tensor);
+    }
+
+    /// Feed each batch through the model with the `forward` function, observing the inputs of its
+    /// linear and convolution modules.
+    pub fn run<B, M, I, F>(&self, model: &M, batches: impl IntoIterator<Item = I>, mut forward: F)
+    where
+        B: Backend,
+        M: Module<B>,
+        F: FnMut(&M, I),
+    {
+        let mut paths = ModulePaths {
+            path: Vec::new(),
+            modules: HashMap::new(),
+        };
+        model.visit(&mut paths);
+
+        let runner = self.clone();
+        let _guard = CalibrationGuard::enter(Box::new(move |weight, input| {
+            let (Some(path), Some(input)) = (
+                paths.modules.get(&weight),
+                input.downcast_ref::<Tensor<B, 1>>(),
+            ) else {
+                // The module isn't part of the model, or runs on another backend.
+                return;
+            };
+            runner.observe(path, input);
+        }));
+
+        for batch in batches {
+            forward(model, batch);
+        }
+    }
+
+    /// Returns the names of the observed activations.
+    pub fn names(&self) -> Vec<String> {
+        let observers = self.observers.lock().unwrap();
+        observers.keys().cloned().collect()
+    }
+
+    /// Compute the static quantization parameters of each observed activation.
+    ///
+    /// # Panics
+    ///
+    /// If the quantization scheme is not per-tensor, since the observed range applies to the whole
+    /// activation tensor.
+    pub fn q_params<B: Backend>(
+        &self,
+        scheme: &QuantizationScheme,
+        device: &B::Device,
+    ) -> BTreeMap<String, QuantizationParameters<B>> {
+        assert!(
+            scheme.is_per_tensor(),
+            "Static activation quantization only supports per-tensor schemes, got {scheme:?}"
+        );
+        let observers = self.observers.lock().unwrap();
+
+        observers
+            .iter()
+            .map(|(name, observer)| {
+                let range = observer.compute_range(device);
+                (name.clone(), scheme.compute_q_params(range))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::TestBackend;
+    use burn_tensor::quantization::{MinMaxObserver, QuantizationType};
+    use burn_tensor::TensorData;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear1: Linear<B>,
+        linear2: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            self.linear2.forward(self.linear1.forward(input))
+        }
+    }
+
+    #[test]
+    fn should_compute_static_activation_q_params() {
+        let device = Default::default();
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(2, 2).init(&device),
+            linear2: LinearConfig::new(2, 2).init(&device),
+        };
+        let batches = [
+            Tensor::<TestBackend, 2>::from_floats([[-1.0, 0.5]], &device),
+            Tensor::<TestBackend, 2>::from_floats([[0.25, 1.27]], &device),
+        ];
+
+        let runner = CalibrationRunner::new(MinMaxObserver::new());
+        runner.run(&model, batches, |model, input| {
+            model.forward(input);
+        });
+
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        let qparams = runner.q_params::<TestBackend>(&scheme, &device);
+
+        assert_eq!(runner.names(), ["linear1", "linear2"]);
+        qparams["linear1"]
+            .scale
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.01]), 3);
+    }
+
+    #[test]
+    fn should_observe_custom_activations() {
+        let device = Default::default();
+        let linear: Linear<TestBackend> = LinearConfig::new(2, 2).init(&device);
+        let batches = [Tensor::<TestBackend, 2>::from_floats(
+            [[-1.0, 0.5]],
+            &device,
+        )];
+
+        let runner = CalibrationRunner::new(MinMaxObserver::new());
+        runner.run(&linear, batches, |linear, input| {
+            let output = linear.forward(input);
+            runner.observe("output", &output);
+        });
+
+        // The root module has an empty path.
+        assert_eq!(runner.names(), ["", "output"]);
+    }
+}
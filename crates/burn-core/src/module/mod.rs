@@ -1,9 +1,11 @@
 mod base;
+mod calibrate;
 mod display;
 mod param;
 mod quantize;
 
 pub use base::*;
+pub use calibrate::*;
 pub use display::*;
 pub use param::*;
 pub use quantize::*;
@@ -204,6 +204,32 @@ impl<T: Parameter> Param<T> {
         }
     }
 
+    /// Override the device on which the parameter will be initialized, if it isn't initialized yet.
+    ///
+    /// Returns `false` if the parameter is already initialized, in which case the device isn't
+    /// changed.
+    ///
+    /// # Notes
+    ///
+    /// This is a crate-private function, used to initialize lazy records (e.g., memory-mapped)
+    /// directly on the device of the module loading them.
+    pub(crate) fn set_lazy_device(&self, device: T::Device) -> bool {
+        let initialization = match &self.initialization {
+            Some(init) => init,
+            None => return false,
+        };
+
+        let mut init = initialization.write().unwrap();
+
+        match init.as_mut() {
+            Some(value) => {
+                value.device = device;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Override the gradient requirement for the current parameter.
     pub fn set_require_grad(self, require_grad: bool) -> Self {
         let initialization = match &self.initialization {
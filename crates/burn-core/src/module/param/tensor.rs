@@ -102,11 +102,16 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
         let expected_require_grad = self.lazy_is_require_grad();
 
+        // Lazy records are initialized directly on the module device.
+        if record.set_lazy_device(expected_device.clone()) {
+            return record.set_require_grad(expected_require_grad);
+        }
+
+        let (new_id, mut new_value) = record.consume();
+
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
             new_value = new_value.to_device(&expected_device).detach();
@@ -179,10 +184,15 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Int>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
 
+        // Lazy records are initialized directly on the module device.
+        if record.set_lazy_device(expected_device.clone()) {
+            return record;
+        }
+
+        let (new_id, mut new_value) = record.consume();
+
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
             new_value = new_value.to_device(&expected_device);
@@ -243,10 +253,15 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Bool>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
 
+        // Lazy records are initialized directly on the module device.
+        if record.set_lazy_device(expected_device.clone()) {
+            return record;
+        }
+
+        let (new_id, mut new_value) = record.consume();
+
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
             new_value = new_value.to_device(&expected_device);
@@ -16,8 +16,66 @@ pub struct Quantizer<C: Calibration> {
 
 impl<B: Backend, C: Calibration> ModuleMapper<B> for Quantizer<C> {
     fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
-        let range = self.calibration.compute_range(&tensor);
-        let qparams = self.scheme.compute_q_params(range);
-        tensor.quantize(&self.scheme, qparams)
+        // Parameters that cannot be split into channels or blocks (e.g., biases) are quantized
+        // per-tensor instead
+        let scheme = if self.scheme.is_compatible(&tensor.dims()) {
+            self.scheme
+        } else {
+            self.scheme.to_per_tensor()
+        };
+
+        let range = self.calibration.compute_range(&tensor, &scheme);
+        let qparams = scheme.compute_q_params(range);
+        tensor.quantize(&scheme, qparams)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::LinearConfig,
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        TestBackend,
+    };
+    use burn_tensor::{
+        quantization::{MinMaxCalibration, QuantizationType},
+        DType,
+    };
+
+    #[test]
+    // LibTorch does not support sub-byte quantization
+    #[cfg(not(feature = "test-tch"))]
+    fn should_quantize_weights_int4_and_load_record() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt4, 1);
+        let mut quantizer = Quantizer {
+            calibration: MinMaxCalibration {},
+            scheme,
+        };
+        let linear = LinearConfig::new(8, 4)
+            .init::<TestBackend>(&device)
+            .quantize_weights(&mut quantizer);
+
+        let weight = linear.weight.val().into_data();
+        assert_eq!(weight.dtype, DType::QFloat(scheme));
+        // The bias cannot be split into channels along axis 1
+        let bias = linear.bias.as_ref().unwrap().val().into_data();
+        assert_eq!(bias.dtype, DType::QFloat(scheme.to_per_tensor()));
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(linear.into_record(), ()).unwrap();
+        let linear = LinearConfig::new(8, 4)
+            .init::<TestBackend>(&device)
+            .load_record(recorder.load(bytes, &device).unwrap());
+
+        linear.weight.val().into_data().assert_eq(&weight, true);
+        linear
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&bias, true);
     }
 }
@@ -0,0 +1,453 @@
+use alloc::{vec, vec::Vec};
+
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::cache::KvCache;
+use crate::nn::{Initializer, RotaryEncoding, RotaryEncodingConfig};
+use crate::{
+    config::Config,
+    nn,
+    tensor::{backend::Backend, module, ops::AttentionOptions, Bool, Tensor},
+};
+
+/// Configuration to create a [Grouped Query Attention](GroupedQueryAttention) layer using the [init function](GroupedQueryAttentionConfig::init).
+#[derive(Config)]
+pub struct GroupedQueryAttentionConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The number of query heads.
+    pub n_heads: usize,
+    /// The number of key and value heads, each one shared by `n_heads / n_kv_heads` query heads.
+    /// Multi-query attention uses a single key and value head, and multi-head attention uses
+    /// `n_heads`.
+    pub n_kv_heads: usize,
+    /// The size of each head. Default: `d_model / n_heads`
+    pub head_dim: Option<usize>,
+    /// The dropout rate of the attention weights. Default: 0.0
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// If a bias is added to the query, key and value projections. Default: false
+    #[config(default = false)]
+    pub bias: bool,
+    /// The rotary encoding applied to the queries and keys, its `d_model` being the size of each
+    /// head. Default: None
+    pub rotary_encoding: Option<RotaryEncodingConfig>,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The grouped query attention module, introduced in the paper
+/// [GQA: Training Generalized Multi-Query Transformer Models from Multi-Head Checkpoints](https://arxiv.org/abs/2305.13245),
+/// as used by decoder-only language models.
+///
+/// Each key and value head is shared by a group of query heads, which reduces the size of the
+/// [key-value cache](KvCache) used during generation. The attention is computed with the fused
+/// [scaled dot-product attention](crate::tensor::module::scaled_dot_product_attention).
+///
+/// # Params
+///
+/// - query: [Linear](nn::Linear) layer with `d_model` input and `n_heads * head_dim` output features.
+/// - key: [Linear](nn::Linear) layer with `d_model` input and `n_kv_heads * head_dim` output features.
+/// - value: [Linear](nn::Linear) layer with `d_model` input and `n_kv_heads * head_dim` output features.
+/// - output: [Linear](nn::Linear) layer with `n_heads * head_dim` input and `d_model` output features.
+///
+/// Should be created with [GroupedQueryAttentionConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct GroupedQueryAttention<B: Backend> {
+    /// Linear layer to transform the input features into the query space.
+    pub query: nn::Linear<B>,
+    /// Linear layer to transform the input features into the key space.
+    pub key: nn::Linear<B>,
+    /// Linear layer to transform the input features into the value space.
+    pub value: nn::Linear<B>,
+    /// Linear layer to transform the output features back to the original space.
+    pub output: nn::Linear<B>,
+    /// Dropout layer.
+    pub dropout: nn::Dropout,
+    /// Rotary encoding of the queries and keys.
+    pub rotary_encoding: Option<RotaryEncoding<B>>,
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The number of query heads.
+    pub n_heads: usize,
+    /// The number of key and value heads.
+    pub n_kv_heads: usize,
+    /// The size of each head.
+    pub head_dim: usize,
+}
+
+impl<B: Backend> ModuleDisplay for GroupedQueryAttention<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("d_model", &self.d_model)
+            .add("n_heads", &self.n_heads)
+            .add("n_kv_heads", &self.n_kv_heads)
+            .add("head_dim", &self.head_dim)
+            .add("dropout", &self.dropout.prob)
+            .optional()
+    }
+}
+
+impl GroupedQueryAttentionConfig {
+    /// Initialize a new [grouped query attention](GroupedQueryAttention) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of query heads isn't a multiple of the number of key and value heads,
+    /// or if the rotary encoding doesn't match the size of the heads.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GroupedQueryAttention<B> {
+        assert!(
+            self.n_kv_heads > 0 && self.n_heads % self.n_kv_heads == 0,
+            "The number of query heads ({}) should be a multiple of the number of key and value heads ({})",
+            self.n_heads,
+            self.n_kv_heads
+        );
+        let head_dim = self.head_dim.unwrap_or(self.d_model / self.n_heads);
+
+        let linear = |d_input: usize, d_output: usize, bias: bool| {
+            nn::LinearConfig::new(d_input, d_output)
+                .with_bias(bias)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+        let rotary_encoding = self.rotary_encoding.as_ref().map(|config| {
+            assert_eq!(
+                config.d_model, head_dim,
+                "The rotary encoding should have the size of the heads"
+            );
+            config.init(device)
+        });
+
+        GroupedQueryAttention {
+            query: linear(self.d_model, self.n_heads * head_dim, self.bias),
+            key: linear(self.d_model, self.n_kv_heads * head_dim, self.bias),
+            value: linear(self.d_model, self.n_kv_heads * head_dim, self.bias),
+            output: linear(self.n_heads * head_dim, self.d_model, false),
+            dropout: nn::DropoutConfig::new(self.dropout).init(),
+            rotary_encoding,
+            d_model: self.d_model,
+            n_heads: self.n_heads,
+            n_kv_heads: self.n_kv_heads,
+            head_dim,
+        }
+    }
+}
+
+/// [Grouped query attention](GroupedQueryAttention) forward pass input argument.
+#[derive(Debug, Clone)]
+pub struct GqaInput<B: Backend> {
+    /// Shape `[batch_size, seq_length, d_model]`
+    tensor: Tensor<B, 3>,
+    mask_pad: Option<Tensor<B, 2, Bool>>,
+    mask_attn: Option<Tensor<B, 3, Bool>>,
+    is_causal: bool,
+    num_tokens: Option<Vec<usize>>,
+}
+
+impl<B: Backend> GqaInput<B> {
+    /// Create a [grouped query attention](GroupedQueryAttention) input argument, the queries,
+    /// keys and values being computed from the same tensor.
+    ///
+    /// # Shape
+    /// - tensor: `[batch_size, seq_length, d_model]`
+    pub fn new(tensor: Tensor<B, 3>) -> Self {
+        Self {
+            tensor,
+            mask_pad: None,
+            mask_attn: None,
+            is_causal: false,
+            num_tokens: None,
+        }
+    }
+
+    /// Register the padding mask.
+    pub fn mask_pad(mut self, mask_pad: Tensor<B, 2, Bool>) -> Self {
+        self.mask_pad = Some(mask_pad);
+        self
+    }
+
+    /// Register the attention mask.
+    pub fn mask_attn(mut self, mask_attn: Tensor<B, 3, Bool>) -> Self {
+        self.mask_attn = Some(mask_attn);
+        self
+    }
+
+    /// Each position only attends to the previous positions and itself.
+    pub fn causal(mut self) -> Self {
+        self.is_causal = true;
+        self
+    }
+
+    /// Register the number of tokens of each sequence when using a [cache](KvCache), the
+    /// remaining positions being right padding. Default: the sequence length
+    pub fn num_tokens(mut self, num_tokens: Vec<usize>) -> Self {
+        self.num_tokens = Some(num_tokens);
+        self
+    }
+}
+
+impl<B: Backend> GroupedQueryAttention<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [GroupedQueryAttention](GroupedQueryAttention) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - tensor: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: GqaInput<B>) -> Tensor<B, 3> {
+        let query = self.heads(input.tensor.clone(), &self.query, self.n_heads);
+        let key = self.heads(input.tensor.clone(), &self.key, self.n_kv_heads);
+        let value = self.heads(input.tensor, &self.value, self.n_kv_heads);
+
+        let (query, key) = match &self.rotary_encoding {
+            Some(rotary_encoding) => (rotary_encoding.forward(query), rotary_encoding.forward(key)),
+            None => (query, key),
+        };
+
+        let context = module::scaled_dot_product_attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            AttentionOptions::new(None, input.is_causal, self.dropout_prob()),
+        );
+
+        self.output(context)
+    }
+
+    /// Applies the forward pass using a cache, the new positions of each sequence following the
+    /// ones in the cache.
+    ///
+    /// Each position attends to the previous positions of its sequence and itself, so the
+    /// attention is always causal and the masks can't be registered. The sequences can have
+    /// different numbers of tokens with [GqaInput::num_tokens].
+    ///
+    /// # Shapes
+    ///
+    /// - tensor: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward_cache(&self, input: GqaInput<B>, cache: &mut KvCache<B>) -> Tensor<B, 3> {
+        assert!(
+            input.mask_pad.is_none() && input.mask_attn.is_none(),
+            "The cached attention doesn't support masks, the padding is given by the number of tokens"
+        );
+        let [batch_size, seq_length, _] = input.tensor.dims();
+        let num_tokens = input
+            .num_tokens
+            .unwrap_or_else(|| vec![seq_length; batch_size]);
+
+        let query = self.heads(input.tensor.clone(), &self.query, self.n_heads);
+        let key = self.heads(input.tensor.clone(), &self.key, self.n_kv_heads);
+        let value = self.heads(input.tensor, &self.value, self.n_kv_heads);
+
+        let (query, key) = match &self.rotary_encoding {
+            Some(rotary_encoding) => {
+                let starts = cache.lengths();
+                (
+                    rotate(rotary_encoding, query, starts),
+                    rotate(rotary_encoding, key, starts),
+                )
+            }
+            None => (query, key),
+        };
+
+        let cached = cache.append(key, value, &num_tokens);
+        let context = module::scaled_dot_product_attention(
+            query,
+            cached.key,
+            cached.value,
+            None,
+            Some(cached.mask),
+            AttentionOptions::new(None, false, self.dropout_prob()),
+        );
+
+        self.output(context)
+    }
+
+    /// The dropout rate, the weights only being dropped during training like the dropout module.
+    fn dropout_prob(&self) -> f64 {
+        match B::ad_enabled() {
+            true => self.dropout.prob,
+            false => 0.0,
+        }
+    }
+
+    fn heads(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>, n_heads: usize) -> Tensor<B, 4> {
+        let [batch_size, seq_length, _d_model] = x.dims();
+        linear
+            .forward(x)
+            .reshape([batch_size, seq_length, n_heads, self.head_dim])
+            .swap_dims(1, 2)
+    }
+
+    fn output(&self, context: Tensor<B, 4>) -> Tensor<B, 3> {
+        let [batch_size, _, seq_length, _] = context.dims();
+        let context =
+            context
+                .swap_dims(1, 2)
+                .reshape([batch_size, seq_length, self.n_heads * self.head_dim]);
+
+        self.output.forward(context)
+    }
+}
+
+/// Applies the rotary encoding to each sequence from its own start position.
+fn rotate<B: Backend>(
+    rotary_encoding: &RotaryEncoding<B>,
+    tensor: Tensor<B, 4>,
+    starts: &[usize],
+) -> Tensor<B, 4> {
+    if starts.iter().all(|start| *start == starts[0]) {
+        return rotary_encoding.apply(tensor, starts[0]);
+    }
+
+    let sequences = starts
+        .iter()
+        .enumerate()
+        .map(|(i, start)| rotary_encoding.apply(tensor.clone().narrow(0, i, 1), *start))
+        .collect();
+
+    Tensor::cat(sequences, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::cache::{KvCacheConfig, KvCacheLayout};
+    use crate::tensor::{Distribution, Shape};
+    use crate::TestBackend;
+
+    fn config() -> GroupedQueryAttentionConfig {
+        GroupedQueryAttentionConfig::new(16, 4, 2)
+            .with_rotary_encoding(Some(RotaryEncodingConfig::new(16, 4)))
+    }
+
+    #[test]
+    fn test_gqa_shapes() {
+        let [batch_size, seq_length, d_model] = [3, 5, 16];
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(d_model, 4, 1)
+            .with_head_dim(Some(8))
+            .init::<TestBackend>(&device);
+        let input = Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = gqa.forward(GqaInput::new(input).causal());
+
+        assert_eq!(gqa.key.weight.dims(), [d_model, 8]);
+        assert_eq!(gqa.output.weight.dims(), [32, d_model]);
+        assert_eq!(
+            output.shape(),
+            Shape::new([batch_size, seq_length, d_model])
+        );
+    }
+
+    #[test]
+    fn test_causal_attention_should_have_same_output_as_cached_decoding() {
+        let [batch_size, seq_length, d_model] = [2, 4, 16];
+        let device = Default::default();
+        let gqa = config().init::<TestBackend>(&device);
+        let mut cache = KvCacheConfig::new(batch_size, 2, 4, seq_length).init(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let output_1 = gqa.forward(GqaInput::new(tensor.clone()).causal());
+
+        // Prefill the first two positions, then decode one position at a time.
+        let mut output_2 =
+            vec![gqa.forward_cache(GqaInput::new(tensor.clone().narrow(1, 0, 2)), &mut cache)];
+        for i in 2..seq_length {
+            let input = GqaInput::new(tensor.clone().narrow(1, i, 1));
+            output_2.push(gqa.forward_cache(input, &mut cache));
+        }
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&Tensor::cat(output_2, 1).into_data(), 3);
+    }
+
+    #[test]
+    fn test_batched_decoding_with_different_lengths() {
+        let d_model = 16;
+        let device = Default::default();
+        let gqa = config().init::<TestBackend>(&device);
+        let layout = KvCacheLayout::Paged {
+            page_size: 2,
+            num_pages: 4,
+        };
+        let mut cache = KvCacheConfig::new(2, 2, 4, 4)
+            .with_layout(layout)
+            .init(&device);
+
+        let tensor =
+            Tensor::<TestBackend, 3>::random([2, 4, d_model], Distribution::Default, &device);
+
+        // The second sequence has a single token in the prompt, the rest being padding.
+        let prompt = GqaInput::new(tensor.clone().narrow(1, 0, 3)).num_tokens(vec![3, 1]);
+        let prefill = gqa.forward_cache(prompt, &mut cache);
+        let next = gqa.forward_cache(GqaInput::new(tensor.clone().narrow(1, 3, 1)), &mut cache);
+        assert_eq!(cache.lengths(), &[4, 2]);
+
+        let expected_1 = gqa.forward(GqaInput::new(tensor.clone().narrow(0, 0, 1)).causal());
+        let expected_2 = gqa.forward(
+            GqaInput::new(Tensor::cat(
+                vec![
+                    tensor.clone().narrow(0, 1, 1).narrow(1, 0, 1),
+                    tensor.narrow(0, 1, 1).narrow(1, 3, 1),
+                ],
+                1,
+            ))
+            .causal(),
+        );
+
+        Tensor::cat(
+            vec![
+                prefill.clone().narrow(0, 0, 1),
+                next.clone().narrow(0, 0, 1),
+            ],
+            1,
+        )
+        .into_data()
+        .assert_approx_eq(&expected_1.into_data(), 3);
+        Tensor::cat(
+            vec![
+                prefill.narrow(0, 1, 1).narrow(1, 0, 1),
+                next.narrow(0, 1, 1),
+            ],
+            1,
+        )
+        .into_data()
+        .assert_approx_eq(&expected_2.into_data(), 3);
+    }
+
+    #[test]
+    fn display() {
+        let config = GroupedQueryAttentionConfig::new(8, 4, 2);
+        let gqa = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", gqa),
+            "GroupedQueryAttention {d_model: 8, n_heads: 4, n_kv_heads: 2, \
+            head_dim: 2, dropout: 0, params: 192}"
+        );
+    }
+}
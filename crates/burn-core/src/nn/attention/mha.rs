@@ -6,7 +6,7 @@ use crate::nn::Initializer;
 use crate::{
     config::Config,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{activation, backend::Backend, module, ops::AttentionOptions, Bool, Tensor},
 };
 
 #[cfg(not(feature = "std"))]
@@ -23,7 +23,8 @@ pub struct MultiHeadAttentionConfig {
     #[config(default = 0.1)]
     pub dropout: f64,
     /// The minimum value a float can take. Default: -1.0e4
-    /// This is used to mask attention scores before calculating attention weights.
+    /// This is used to mask attention scores before calculating attention weights, when the
+    /// weights are materialized. It should be low enough for the masked keys to get no weight.
     /// A value too low might result in NaN.
     #[config(default = -1.0e4)]
     pub min_float: f64,
@@ -51,6 +52,16 @@ pub struct MultiHeadAttentionConfig {
 /// - value: [Linear](nn::Linear) layer with `d_model` input and output features.
 /// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
 ///
+/// The [forward](MultiHeadAttention::forward) pass returns the attention weights, which requires
+/// materializing them. The [forward_context](MultiHeadAttention::forward_context) pass only
+/// returns the context, computed with the fused
+/// [scaled dot-product attention](crate::tensor::module::scaled_dot_product_attention) unless
+/// using the quiet softmax, so it doesn't materialize the attention weights.
+///
+/// Both follow the same conventions: the masked keys get no weight, a query whose keys are all
+/// masked gets zero weights and a zero context, and the dropout is applied to the attention
+/// weights after the softmax, like PyTorch.
+///
 /// Should be created with [MultiHeadAttentionConfig].
 #[derive(Module, Debug)]
 #[module(custom_display)]
@@ -197,24 +208,38 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
-
         let query = self.attention_linear(input.query, &self.query);
         let key = self.attention_linear(input.key, &self.key);
         let value = self.attention_linear(input.value, &self.value);
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
-
-        let context = weights.clone().matmul(value);
-        let context = context
-            .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
-        let context = self.output.forward(context);
+        let weights = self.attn_weights(query, key, input.mask_pad, input.mask_attn);
+        let context = self.dropout.forward(weights.clone()).matmul(value);
+        let context = self.output.forward(self.merge_heads(context));
 
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass on the input tensors, without materializing the attention
+    /// weights.
+    ///
+    /// See [MultiHeadAttention](MultiHeadAttention) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_context(&self, input: MhaInput<B>) -> Tensor<B, 3> {
+        let query = self.attention_linear(input.query, &self.query);
+        let key = self.attention_linear(input.key, &self.key);
+        let value = self.attention_linear(input.value, &self.value);
+
+        let context = self.attention(query, key, value, input.mask_pad, input.mask_attn);
+
+        self.output.forward(self.merge_heads(context))
+    }
+
     /// Applies the forward pass using a cache.
     ///
     /// # Shapes
@@ -224,63 +249,132 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let (query, key, value) = self.cached_projections(&input, cache);
+
+        let weights = self.attn_weights(query, key, input.mask_pad, input.mask_attn);
+        let context = self.dropout.forward(weights.clone()).matmul(value);
+        let context = cache
+            .output
+            .forward(self.merge_heads(context), |t| self.output.forward(t));
+
+        MhaOutput { weights, context }
+    }
 
-        let query = cache
-            .query
-            .forward(input.query, |t| self.attention_linear(t, &self.query));
+    /// Applies the forward pass using a cache, without materializing the attention weights.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_context_cache(
+        &self,
+        input: MhaInput<B>,
+        cache: &mut MhaCache<B>,
+    ) -> Tensor<B, 3> {
+        let (query, key, value) = self.cached_projections(&input, cache);
+
+        let context = self.attention(query, key, value, input.mask_pad, input.mask_attn);
+
+        cache
+            .output
+            .forward(self.merge_heads(context), |t| self.output.forward(t))
+    }
+
+    fn cached_projections(
+        &self,
+        input: &MhaInput<B>,
+        cache: &mut MhaCache<B>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 4>) {
+        let query = cache.query.forward(input.query.clone(), |t| {
+            self.attention_linear(t, &self.query)
+        });
         let key = cache
             .key
-            .forward(input.key, |t| self.attention_linear(t, &self.key));
-        let value = cache
-            .value
-            .forward(input.value, |t| self.attention_linear(t, &self.value));
+            .forward(input.key.clone(), |t| self.attention_linear(t, &self.key));
+        let value = cache.value.forward(input.value.clone(), |t| {
+            self.attention_linear(t, &self.value)
+        });
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        (query, key, value)
+    }
 
-        let context = weights.clone().matmul(value);
-        let context = context
-            .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
+    /// The attention context, computed with the fused attention unless using the quiet softmax.
+    fn attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+    ) -> Tensor<B, 4> {
+        if self.quiet_softmax {
+            let weights = self.attn_weights(query, key, mask_pad, mask_attn);
+            return self.dropout.forward(weights).matmul(value);
+        }
 
-        let context = cache.output.forward(context, |t| self.output.forward(t));
+        // Like the dropout module, the weights are only dropped during training.
+        let dropout = match B::ad_enabled() {
+            true => self.dropout.prob,
+            false => 0.0,
+        };
 
-        MhaOutput { weights, context }
+        module::scaled_dot_product_attention(
+            query,
+            key,
+            value,
+            mask_pad,
+            mask_attn,
+            AttentionOptions::new(None, false, dropout),
+        )
+    }
+
+    fn merge_heads(&self, context: Tensor<B, 4>) -> Tensor<B, 3> {
+        let [batch_size, _n_heads, seq_length, _d_k] = context.dims();
+
+        context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, self.d_model])
     }
 
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
-        let attn_scores = query
+        query
             .matmul(key.transpose())
-            .div_scalar((self.d_k as f32).sqrt());
-
-        self.dropout.forward(attn_scores)
+            .div_scalar((self.d_k as f32).sqrt())
     }
 
     fn attn_weights(
         &self,
-        mut attn_scores: Tensor<B, 4>,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
         mask_pad: Option<Tensor<B, 2, Bool>>,
         mask_attn: Option<Tensor<B, 3, Bool>>,
     ) -> Tensor<B, 4> {
-        if let Some(mask_pad) = mask_pad {
-            let [batch_size, seq_length] = mask_pad.dims();
+        let attn_scores = self.attn_scores(query, key);
+        let [batch_size, _n_heads, seq_length_1, seq_length_2] = attn_scores.dims();
 
-            attn_scores = attn_scores.mask_fill(
-                mask_pad.reshape([batch_size, 1, 1, seq_length]),
-                self.min_float,
-            );
-        }
+        let shape = [batch_size, 1, seq_length_1, seq_length_2];
 
-        if let Some(mask_attn) = mask_attn {
-            let [batch_size, seq_length_1, seq_length_2] = mask_attn.dims();
+        let mask_pad =
+            mask_pad.map(|mask| mask.reshape([batch_size, 1, 1, seq_length_2]).expand(shape));
+        let mask_attn = mask_attn.map(|mask| mask.reshape(shape));
+        let mask = match (mask_pad, mask_attn) {
+            (Some(mask_pad), Some(mask_attn)) => Some(mask_pad.bool_or(mask_attn)),
+            (mask_pad, mask_attn) => mask_pad.or(mask_attn),
+        };
 
-            attn_scores = attn_scores.mask_fill(
-                mask_attn.reshape([batch_size, 1, seq_length_1, seq_length_2]),
-                self.min_float,
-            );
-        }
+        let Some(mask) = mask else {
+            return self.softmax(attn_scores);
+        };
 
+        let weights = self.softmax(attn_scores.mask_fill(mask.clone(), self.min_float));
+
+        // Like the fused attention, a query whose keys are all masked gets zero weights.
+        weights.mask_fill(mask.all_dim(3), 0.0)
+    }
+
+    fn softmax(&self, attn_scores: Tensor<B, 4>) -> Tensor<B, 4> {
         if self.quiet_softmax {
             activation::quiet_softmax(attn_scores, 3)
         } else {
@@ -354,7 +448,7 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
 mod tests {
     use super::*;
     use crate::tensor::Int;
-    use crate::tensor::{Distribution, Shape};
+    use crate::tensor::{Distribution, Shape, TensorData};
     use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
     use alloc::vec::Vec;
 
@@ -512,6 +606,65 @@ mod tests {
             .assert_approx_eq(&output_2.into_data(), 3);
     }
 
+    #[test]
+    fn test_forward_context_should_match_forward() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 16, 4];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+
+        let output_1 =
+            mha.forward_context(MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn.clone()));
+        let output_2 = mha.forward(MhaInput::self_attn(tensor).mask_attn(mask_attn));
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+    }
+
+    #[test]
+    fn test_forward_context_should_match_forward_with_fully_masked_query() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 4, 16, 4];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        // Every key of the second sequence is padded.
+        let mask_pad = Tensor::<TestBackend, 2, Bool>::from_data(
+            [[false, false, true, true], [true, true, true, true]],
+            &device,
+        );
+
+        let output_1 =
+            mha.forward_context(MhaInput::self_attn(tensor.clone()).mask_pad(mask_pad.clone()));
+        let output_2 = mha.forward(MhaInput::self_attn(tensor).mask_pad(mask_pad));
+
+        output_1
+            .clone()
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+        output_2.weights.narrow(0, 1, 1).into_data().assert_eq(
+            &TensorData::zeros::<f32, _>([1, n_heads, seq_length, seq_length]),
+            false,
+        );
+        // A zero context only keeps the bias of the output layer.
+        let bias = mha.output.bias.as_ref().unwrap().val();
+        output_1.narrow(0, 1, 1).into_data().assert_approx_eq(
+            &bias.unsqueeze::<3>().repeat_dim(1, seq_length).into_data(),
+            3,
+        );
+    }
+
     #[test]
     fn display() {
         let config = MultiHeadAttentionConfig::new(2, 4);
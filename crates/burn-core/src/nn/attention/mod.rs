@@ -1,5 +1,7 @@
+mod gqa;
 mod mask;
 mod mha;
 
+pub use gqa::*;
 pub use mask::*;
 pub use mha::*;
@@ -0,0 +1,749 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::ops::IntElem;
+use crate::tensor::{backend::Backend, Bool, ElementConversion, Int, Tensor, TensorData};
+
+/// Memory layout of a [key-value cache](KvCache).
+#[derive(Config, Debug, PartialEq)]
+pub enum KvCacheLayout {
+    /// Each sequence has a buffer of the maximum sequence length, allocated upfront.
+    Contiguous,
+    /// The keys and values are stored in pages shared by all the sequences, each sequence only
+    /// holding the pages required by its length.
+    Paged {
+        /// The number of positions stored in a page.
+        page_size: usize,
+        /// The number of pages allocated upfront.
+        num_pages: usize,
+    },
+    /// Only the keys and values of the last `window_size` positions are kept, for sliding window
+    /// attention. The sequences can be longer than the maximum sequence length, the positions
+    /// wrapping around the `window_size` slots of each sequence.
+    SlidingWindow {
+        /// The number of positions attended by each query, including its own.
+        window_size: usize,
+    },
+}
+
+/// Configuration to create a [key-value cache](KvCache) using the [init function](KvCacheConfig::init).
+#[derive(Config, Debug)]
+pub struct KvCacheConfig {
+    /// The number of sequences generated at once.
+    pub batch_size: usize,
+    /// The number of key and value heads.
+    pub n_kv_heads: usize,
+    /// The size of each head.
+    pub head_dim: usize,
+    /// The maximum length of a sequence, which doesn't bound the sliding window layout.
+    pub max_seq_length: usize,
+    /// The memory layout of the cache. Default: contiguous
+    #[config(default = "KvCacheLayout::Contiguous")]
+    pub layout: KvCacheLayout,
+}
+
+impl KvCacheConfig {
+    /// Initialize a new empty [key-value cache](KvCache), allocating its memory.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> KvCache<B> {
+        let shape = match self.layout {
+            KvCacheLayout::Contiguous => [
+                self.batch_size,
+                self.n_kv_heads,
+                self.max_seq_length,
+                self.head_dim,
+            ],
+            KvCacheLayout::Paged {
+                page_size,
+                num_pages,
+            } => {
+                assert!(page_size > 0, "The page size should be positive");
+                [num_pages, self.n_kv_heads, page_size, self.head_dim]
+            }
+            KvCacheLayout::SlidingWindow { window_size } => {
+                assert!(window_size > 0, "The window size should be positive");
+                [self.batch_size, self.n_kv_heads, window_size, self.head_dim]
+            }
+        };
+        let free_pages = match self.layout {
+            KvCacheLayout::Paged { num_pages, .. } => (0..num_pages).rev().collect(),
+            _ => Vec::new(),
+        };
+
+        KvCache {
+            key: Tensor::zeros(shape, device),
+            value: Tensor::zeros(shape, device),
+            layout: self.layout.clone(),
+            max_seq_length: self.max_seq_length,
+            lengths: vec![0; self.batch_size],
+            page_tables: vec![Vec::new(); self.batch_size],
+            free_pages,
+        }
+    }
+}
+
+/// Cache of the keys and values of the previous positions of each sequence, used for
+/// autoregressive decoding with the [grouped query attention](crate::nn::attention::GroupedQueryAttention).
+///
+/// The sequences of a batch can have different lengths: each one is appended its own number of
+/// tokens, and the positions are tracked per sequence.
+///
+/// Should be created using [KvCacheConfig].
+pub struct KvCache<B: Backend> {
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    layout: KvCacheLayout,
+    max_seq_length: usize,
+    lengths: Vec<usize>,
+    page_tables: Vec<Vec<usize>>,
+    free_pages: Vec<usize>,
+}
+
+/// The keys and values to attend to after [appending](KvCache::append) new positions.
+#[derive(Debug, Clone)]
+pub struct KvCacheOutput<B: Backend> {
+    /// The keys `[batch_size, n_kv_heads, seq_length_k, head_dim]`.
+    pub key: Tensor<B, 4>,
+    /// The values `[batch_size, n_kv_heads, seq_length_k, head_dim]`.
+    pub value: Tensor<B, 4>,
+    /// The attention mask `[batch_size, seq_length, seq_length_k]` of the appended positions,
+    /// true for the keys that can't be attended.
+    pub mask: Tensor<B, 3, Bool>,
+}
+
+impl<B: Backend> KvCache<B> {
+    /// The number of positions of each sequence in the cache, which is also the position of the
+    /// next token of the sequence.
+    pub fn lengths(&self) -> &[usize] {
+        &self.lengths
+    }
+
+    /// Removes the sequence at the given index of the batch, so that a new sequence can be
+    /// generated in its place.
+    pub fn reset(&mut self, index: usize) {
+        self.lengths[index] = 0;
+        self.free_pages.append(&mut self.page_tables[index]);
+    }
+
+    /// Removes all the sequences.
+    pub fn clear(&mut self) {
+        for index in 0..self.lengths.len() {
+            self.reset(index);
+        }
+    }
+
+    /// Reorders the sequences of the batch, the sequence at the index `i` being replaced by the
+    /// sequence at the index `indices[i]`. A sequence can be selected several times, such as when
+    /// following the beams of a [beam search](crate::generation::BeamSearch), and the sequences
+    /// that aren't selected are removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices doesn't match the batch size, or if the paged cache has no
+    /// free page left to copy a sequence selected several times.
+    pub fn reorder(&mut self, indices: &[usize]) {
+        let batch_size = self.lengths.len();
+        assert_eq!(
+            indices.len(),
+            batch_size,
+            "The number of indices should match the batch size"
+        );
+        assert!(
+            indices.iter().all(|index| *index < batch_size),
+            "The indices should be lower than the batch size"
+        );
+
+        match self.layout {
+            // The slots of a sequence only depend on its length.
+            KvCacheLayout::Contiguous | KvCacheLayout::SlidingWindow { .. } => {
+                let indices = indices
+                    .iter()
+                    .map(|index| (*index as i64).elem())
+                    .collect::<Vec<IntElem<B>>>();
+                let indices = Tensor::<B, 1, Int>::from_data(
+                    TensorData::new(indices, [batch_size]),
+                    &self.key.device(),
+                );
+
+                self.key = self.key.clone().select(0, indices.clone());
+                self.value = self.value.clone().select(0, indices);
+            }
+            KvCacheLayout::Paged { .. } => {
+                let mut page_tables = vec![Vec::new(); batch_size];
+                let mut moved = vec![false; batch_size];
+
+                // The pages of the removed sequences are freed first, so they can hold the copies.
+                for (index, table) in self.page_tables.iter_mut().enumerate() {
+                    if !indices.contains(&index) {
+                        self.free_pages.append(table);
+                    }
+                }
+
+                for (target, source) in indices.iter().copied().enumerate() {
+                    // The pages are moved to the first copy of a sequence, and copied to the
+                    // others since the sequences will diverge.
+                    if !moved[source] {
+                        moved[source] = true;
+                        page_tables[target] = self.page_tables[source].clone();
+                        continue;
+                    }
+
+                    for page in self.page_tables[source].clone() {
+                        let copy = self
+                            .free_pages
+                            .pop()
+                            .expect("The paged key-value cache should have a free page");
+                        self.copy_page(page, copy);
+                        page_tables[target].push(copy);
+                    }
+                }
+
+                self.page_tables = page_tables;
+            }
+        }
+
+        self.lengths = indices.iter().map(|index| self.lengths[*index]).collect();
+    }
+
+    /// Appends the keys and values of new positions to each sequence, and returns the keys and
+    /// values they attend to. Each position attends to the previous positions of its sequence and
+    /// itself, within the window with the sliding window layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The keys of the new positions `[batch_size, n_kv_heads, seq_length, head_dim]`.
+    /// * `value` - The values of the new positions `[batch_size, n_kv_heads, seq_length, head_dim]`.
+    /// * `num_tokens` - The number of new positions of each sequence, the remaining ones being
+    ///   right padding that isn't cached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a sequence exceeds the maximum sequence length without the sliding window layout,
+    /// or if the paged cache has no free page left.
+    pub fn append(
+        &mut self,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        num_tokens: &[usize],
+    ) -> KvCacheOutput<B> {
+        let [batch_size, _, seq_length, _] = key.dims();
+        assert_eq!(
+            batch_size,
+            self.lengths.len(),
+            "The batch size should match the cache"
+        );
+        assert_eq!(
+            num_tokens.len(),
+            batch_size,
+            "The number of tokens should be given for each sequence"
+        );
+        for (length, num_tokens) in self.lengths.iter().zip(num_tokens) {
+            assert!(
+                *num_tokens <= seq_length,
+                "The number of tokens ({num_tokens}) exceeds the sequence length ({seq_length})"
+            );
+            assert!(
+                matches!(self.layout, KvCacheLayout::SlidingWindow { .. })
+                    || length + num_tokens <= self.max_seq_length,
+                "The sequence length exceeds the maximum of the cache ({})",
+                self.max_seq_length
+            );
+        }
+
+        let device = key.device();
+        let (cached, mut cached_positions, cached_length) = self.read();
+
+        // The cached positions are followed by the new ones.
+        let mut positions = Vec::with_capacity(batch_size * (cached_length + seq_length));
+        let mut query_positions = Vec::with_capacity(batch_size * seq_length);
+        for (length, num_tokens) in self.lengths.iter().zip(num_tokens) {
+            positions.extend(cached_positions.drain(..cached_length));
+            positions.extend((0..seq_length).map(|j| match j < *num_tokens {
+                true => (length + j) as i64,
+                false => -1,
+            }));
+            query_positions.extend((0..seq_length).map(|j| (length + j) as i64));
+        }
+
+        let mask = self.mask(
+            positions,
+            query_positions,
+            [batch_size, seq_length, cached_length + seq_length],
+            &device,
+        );
+        let (key_all, value_all) = match cached {
+            Some((key_cached, value_cached)) => (
+                Tensor::cat(vec![key_cached, key.clone()], 2),
+                Tensor::cat(vec![value_cached, value.clone()], 2),
+            ),
+            None => (key.clone(), value.clone()),
+        };
+
+        for (i, num_tokens) in num_tokens.iter().enumerate() {
+            self.write(i, &key, &value, *num_tokens);
+            self.lengths[i] += num_tokens;
+        }
+
+        KvCacheOutput {
+            key: key_all,
+            value: value_all,
+            mask,
+        }
+    }
+
+    /// The cached keys and values of every sequence, with the position of each key or -1 when
+    /// it doesn't hold a position of the sequence.
+    #[allow(clippy::type_complexity)]
+    fn read(&self) -> (Option<(Tensor<B, 4>, Tensor<B, 4>)>, Vec<i64>, usize) {
+        let max_length = self.lengths.iter().copied().max().unwrap_or(0);
+        if max_length == 0 {
+            return (None, Vec::new(), 0);
+        }
+
+        match self.layout {
+            KvCacheLayout::Contiguous => {
+                let key = self.key.clone().narrow(2, 0, max_length);
+                let value = self.value.clone().narrow(2, 0, max_length);
+
+                (
+                    Some((key, value)),
+                    self.linear_positions(max_length),
+                    max_length,
+                )
+            }
+            KvCacheLayout::SlidingWindow { window_size } => {
+                let num_slots = usize::min(max_length, window_size);
+                let key = self.key.clone().narrow(2, 0, num_slots);
+                let value = self.value.clone().narrow(2, 0, num_slots);
+
+                // Each slot holds the last position that maps to it.
+                let positions = self
+                    .lengths
+                    .iter()
+                    .flat_map(|length| {
+                        (0..num_slots).map(move |slot| {
+                            if *length == 0 {
+                                return -1;
+                            }
+                            let last = length - 1;
+                            let distance = (last % window_size + window_size - slot) % window_size;
+                            last as i64 - distance as i64
+                        })
+                    })
+                    .map(|position| position.max(-1))
+                    .collect();
+
+                (Some((key, value)), positions, num_slots)
+            }
+            KvCacheLayout::Paged { page_size, .. } => {
+                let num_pages = max_length.div_ceil(page_size);
+                let [_, n_kv_heads, _, head_dim] = self.key.dims();
+                let batch_size = self.lengths.len();
+
+                // Missing pages are replaced by the first one, their positions being masked.
+                let pages = self
+                    .page_tables
+                    .iter()
+                    .flat_map(|table| {
+                        (0..num_pages).map(|i| (table.get(i).copied().unwrap_or(0) as i64).elem())
+                    })
+                    .collect::<Vec<IntElem<B>>>();
+                let pages = Tensor::<B, 1, Int>::from_data(
+                    TensorData::new(pages, [batch_size * num_pages]),
+                    &self.key.device(),
+                );
+                let gather = |tensor: &Tensor<B, 4>| {
+                    tensor
+                        .clone()
+                        .select(0, pages.clone())
+                        .reshape([batch_size, num_pages, n_kv_heads, page_size, head_dim])
+                        .swap_dims(1, 2)
+                        .reshape([batch_size, n_kv_heads, num_pages * page_size, head_dim])
+                };
+
+                (
+                    Some((gather(&self.key), gather(&self.value))),
+                    self.linear_positions(num_pages * page_size),
+                    num_pages * page_size,
+                )
+            }
+        }
+    }
+
+    /// The positions of slots holding the positions in order.
+    fn linear_positions(&self, num_slots: usize) -> Vec<i64> {
+        self.lengths
+            .iter()
+            .flat_map(|length| {
+                (0..num_slots).map(move |slot| match slot < *length {
+                    true => slot as i64,
+                    false => -1,
+                })
+            })
+            .collect()
+    }
+
+    fn mask(
+        &self,
+        key_positions: Vec<i64>,
+        query_positions: Vec<i64>,
+        shape: [usize; 3],
+        device: &B::Device,
+    ) -> Tensor<B, 3, Bool> {
+        let [batch_size, seq_length, seq_length_k] = shape;
+        let tensor = |positions: Vec<i64>, shape: [usize; 2]| {
+            let positions = positions
+                .into_iter()
+                .map(|p| p.elem::<IntElem<B>>())
+                .collect();
+            Tensor::<B, 2, Int>::from_data(TensorData::new(positions, shape), device)
+        };
+
+        let key_positions = tensor(key_positions, [batch_size, seq_length_k])
+            .unsqueeze_dim::<3>(1)
+            .expand(shape);
+        let query_positions = tensor(query_positions, [batch_size, seq_length])
+            .unsqueeze_dim::<3>(2)
+            .expand(shape);
+
+        let mask = key_positions
+            .clone()
+            .lower_elem(0)
+            .bool_or(key_positions.clone().greater(query_positions.clone()));
+
+        match self.layout {
+            KvCacheLayout::SlidingWindow { window_size } => mask.bool_or(
+                key_positions
+                    .add_scalar(window_size as i64)
+                    .lower_equal(query_positions),
+            ),
+            _ => mask,
+        }
+    }
+
+    /// Writes the first `num_tokens` keys and values of the sequence at the given index.
+    fn write(&mut self, index: usize, key: &Tensor<B, 4>, value: &Tensor<B, 4>, num_tokens: usize) {
+        let start = self.lengths[index];
+
+        match self.layout {
+            KvCacheLayout::Contiguous => {
+                self.assign(index, start, key, value, index, 0..num_tokens);
+            }
+            KvCacheLayout::SlidingWindow { window_size } => {
+                // Only the positions in the window of the next tokens are kept.
+                let mut offset = num_tokens.saturating_sub(window_size);
+
+                while offset < num_tokens {
+                    let slot = (start + offset) % window_size;
+                    let length = usize::min(num_tokens - offset, window_size - slot);
+                    self.assign(index, slot, key, value, index, offset..offset + length);
+                    offset += length;
+                }
+            }
+            KvCacheLayout::Paged { page_size, .. } => {
+                let mut offset = 0;
+
+                while offset < num_tokens {
+                    let position = start + offset;
+                    let page_index = position / page_size;
+
+                    if self.page_tables[index].len() <= page_index {
+                        let page = self
+                            .free_pages
+                            .pop()
+                            .expect("The paged key-value cache should have a free page");
+                        self.page_tables[index].push(page);
+                    }
+
+                    let page = self.page_tables[index][page_index];
+                    let slot = position % page_size;
+                    let length = usize::min(num_tokens - offset, page_size - slot);
+                    self.assign(page, slot, key, value, index, offset..offset + length);
+                    offset += length;
+                }
+            }
+        }
+    }
+
+    /// Copies the keys and values of a page to another page.
+    fn copy_page(&mut self, source: usize, target: usize) {
+        let [_, n_kv_heads, page_size, head_dim] = self.key.dims();
+        let range = |page: usize| [page..page + 1, 0..n_kv_heads, 0..page_size, 0..head_dim];
+
+        self.key = self
+            .key
+            .clone()
+            .slice_assign(range(target), self.key.clone().slice(range(source)));
+        self.value = self
+            .value
+            .clone()
+            .slice_assign(range(target), self.value.clone().slice(range(source)));
+    }
+
+    /// Assigns the keys and values of the range of positions of the sequence at the given index
+    /// to the cache, starting at the given slot of the given buffer.
+    fn assign(
+        &mut self,
+        buffer: usize,
+        slot: usize,
+        key: &Tensor<B, 4>,
+        value: &Tensor<B, 4>,
+        index: usize,
+        range: Range<usize>,
+    ) {
+        let [_, n_kv_heads, _, head_dim] = key.dims();
+        let length = range.len();
+        let source = [index..index + 1, 0..n_kv_heads, range, 0..head_dim];
+        let target = [
+            buffer..buffer + 1,
+            0..n_kv_heads,
+            slot..slot + length,
+            0..head_dim,
+        ];
+
+        self.key = self
+            .key
+            .clone()
+            .slice_assign(target.clone(), key.clone().slice(source.clone()));
+        self.value = self
+            .value
+            .clone()
+            .slice_assign(target, value.clone().slice(source));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{
+        module::scaled_dot_product_attention, ops::AttentionOptions, Distribution,
+    };
+    use crate::TestBackend;
+
+    fn attention(
+        query: Tensor<TestBackend, 4>,
+        output: KvCacheOutput<TestBackend>,
+    ) -> Tensor<TestBackend, 4> {
+        scaled_dot_product_attention(
+            query,
+            output.key,
+            output.value,
+            None,
+            Some(output.mask),
+            AttentionOptions::default(),
+        )
+    }
+
+    #[test]
+    fn test_contiguous_cache_mask() {
+        let device = Default::default();
+        let mut cache = KvCacheConfig::new(2, 1, 2, 8).init::<TestBackend>(&device);
+        let key = Tensor::<TestBackend, 4>::random([2, 1, 3, 2], Distribution::Default, &device);
+
+        let output = cache.append(key.clone(), key.clone(), &[3, 2]);
+
+        assert_eq!(cache.lengths(), &[3, 2]);
+        output.mask.into_data().assert_eq(
+            &TensorData::from([
+                [
+                    [false, true, true],
+                    [false, false, true],
+                    [false, false, false],
+                ],
+                [
+                    [false, true, true],
+                    [false, false, true],
+                    [false, false, true],
+                ],
+            ]),
+            false,
+        );
+
+        let next = Tensor::<TestBackend, 4>::random([2, 1, 1, 2], Distribution::Default, &device);
+        let output = cache.append(next.clone(), next, &[1, 1]);
+
+        assert_eq!(cache.lengths(), &[4, 3]);
+        output.mask.into_data().assert_eq(
+            &TensorData::from([
+                [[false, false, false, false]],
+                [[false, false, true, false]],
+            ]),
+            false,
+        );
+        output
+            .key
+            .narrow(2, 0, 3)
+            .narrow(0, 0, 1)
+            .into_data()
+            .assert_eq(&key.narrow(0, 0, 1).into_data(), false);
+    }
+
+    #[test]
+    fn test_sliding_window_cache_mask() {
+        let device = Default::default();
+        let layout = KvCacheLayout::SlidingWindow { window_size: 2 };
+        let mut cache = KvCacheConfig::new(1, 1, 2, 8)
+            .with_layout(layout)
+            .init::<TestBackend>(&device);
+        let key = Tensor::<TestBackend, 4>::random([1, 1, 3, 2], Distribution::Default, &device);
+
+        let output = cache.append(key.clone(), key.clone(), &[3]);
+        output.mask.into_data().assert_eq(
+            &TensorData::from([[
+                [false, true, true],
+                [false, false, true],
+                [true, false, false],
+            ]]),
+            false,
+        );
+
+        // The slots hold the positions 2 and 1.
+        let next = Tensor::<TestBackend, 4>::random([1, 1, 1, 2], Distribution::Default, &device);
+        let output = cache.append(next.clone(), next, &[1]);
+        output
+            .mask
+            .into_data()
+            .assert_eq(&TensorData::from([[[false, true, false]]]), false);
+        output
+            .key
+            .narrow(2, 0, 1)
+            .into_data()
+            .assert_eq(&key.narrow(2, 2, 1).into_data(), false);
+    }
+
+    #[test]
+    fn test_sliding_window_cache_should_generate_past_max_seq_length() {
+        let device = Default::default();
+        let window_size = 3;
+        let layout = KvCacheLayout::SlidingWindow { window_size };
+        let mut sliding = KvCacheConfig::new(1, 2, 4, 4)
+            .with_layout(layout)
+            .init::<TestBackend>(&device);
+        let mut contiguous = KvCacheConfig::new(1, 2, 4, 16).init::<TestBackend>(&device);
+
+        for length in 1..=10 {
+            let shape = [1, 2, 1, 4];
+            let key = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+            let value = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+            let query = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+
+            let output = sliding.append(key.clone(), value.clone(), &[1]);
+            // Only the slots of the window are allocated.
+            assert_eq!(
+                output.key.dims()[2],
+                usize::min(length - 1, window_size) + 1
+            );
+            let output = attention(query.clone(), output);
+
+            // The last token attends to itself and the previous positions of the window.
+            let all = contiguous.append(key, value, &[1]);
+            let start = length.saturating_sub(window_size);
+            let num_keys = length - start;
+            let expected = scaled_dot_product_attention(
+                query,
+                all.key.narrow(2, start, num_keys),
+                all.value.narrow(2, start, num_keys),
+                None,
+                None,
+                AttentionOptions::default(),
+            );
+
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 4);
+        }
+
+        assert_eq!(sliding.lengths(), &[10]);
+    }
+
+    #[test]
+    fn test_paged_cache_should_match_contiguous_cache() {
+        let device = Default::default();
+        let layout = KvCacheLayout::Paged {
+            page_size: 2,
+            num_pages: 6,
+        };
+        let mut contiguous = KvCacheConfig::new(2, 2, 4, 8).init::<TestBackend>(&device);
+        let mut paged = KvCacheConfig::new(2, 2, 4, 8)
+            .with_layout(layout)
+            .init::<TestBackend>(&device);
+
+        for (seq_length, num_tokens) in [(3, [3, 1]), (1, [1, 1]), (2, [2, 1])] {
+            let shape = [2, 2, seq_length, 4];
+            let key = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+            let value = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+            let query = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+
+            let expected = attention(
+                query.clone(),
+                contiguous.append(key.clone(), value.clone(), &num_tokens),
+            );
+            let output = attention(query, paged.append(key, value, &num_tokens));
+
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 4);
+        }
+
+        assert_eq!(paged.lengths(), &[6, 3]);
+        paged.clear();
+        assert_eq!(paged.free_pages.len(), 6);
+    }
+
+    #[test]
+    fn test_reordered_cache_should_match_cache_of_reordered_sequences() {
+        let device = Default::default();
+        let indices = [2, 0, 2];
+        let select = |tensor: Tensor<TestBackend, 4>| {
+            let indices = Tensor::<TestBackend, 1, Int>::from_data([2, 0, 2], &device);
+            tensor.select(0, indices)
+        };
+        let layouts = [
+            KvCacheLayout::Contiguous,
+            KvCacheLayout::SlidingWindow { window_size: 2 },
+            KvCacheLayout::Paged {
+                page_size: 2,
+                num_pages: 12,
+            },
+        ];
+
+        for layout in layouts {
+            let config = KvCacheConfig::new(3, 2, 4, 8).with_layout(layout);
+            let mut reordered = config.init::<TestBackend>(&device);
+            let mut expected = config.init::<TestBackend>(&device);
+
+            let shape = [3, 2, 3, 4];
+            let key = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+            let value = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+            reordered.append(key.clone(), value.clone(), &[1, 2, 3]);
+            reordered.reorder(&indices);
+            expected.append(select(key), select(value), &[3, 1, 3]);
+
+            assert_eq!(reordered.lengths(), expected.lengths());
+
+            for _ in 0..2 {
+                let shape = [3, 2, 1, 4];
+                let key = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+                let value = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+                let query = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+
+                let output = attention(
+                    query.clone(),
+                    reordered.append(key.clone(), value.clone(), &[1, 1, 1]),
+                );
+                let expected = attention(query, expected.append(key, value, &[1, 1, 1]));
+
+                output
+                    .into_data()
+                    .assert_approx_eq(&expected.into_data(), 4);
+            }
+
+            // The pages of the removed sequence are reused by the copy of the selected one.
+            if let KvCacheLayout::Paged { .. } = config.layout {
+                assert_eq!(reordered.free_pages.len(), expected.free_pages.len());
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 mod autoregressive;
 mod base;
+mod kv;
 
 pub use base::*;
+pub use kv::*;
@@ -4,8 +4,12 @@ use crate as burn;
 
 use crate::{
     config::Config,
-    module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param},
-    nn::{conv::checks, Initializer, PaddingConfig1d},
+    module::{observe_input, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param},
+    nn::{
+        conv::checks,
+        qat::{fake_quantize_input, fake_quantize_weight},
+        Initializer, PaddingConfig1d,
+    },
     tensor::{backend::Backend, module::conv1d, ops::ConvOptions, Tensor},
 };
 
@@ -137,14 +141,15 @@ impl<B: Backend> Conv1d<B> {
     /// - input: `[batch_size, channels_in, length_in]`
     /// - output: `[batch_size, channels_out, length_out]`
     pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        observe_input(self.weight.id, &input);
         let [_batch_size, _channels, length] = input.dims();
         let padding = self
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
         conv1d(
-            input,
-            self.weight.val(),
+            fake_quantize_input(self.weight.id, input),
+            fake_quantize_weight(self.weight.id, self.weight.val()),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new([self.stride], [padding], [self.dilation], self.groups),
         )
@@ -3,7 +3,10 @@ use alloc::format;
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::module::{
+    observe_input, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param,
+};
+use crate::nn::qat::{fake_quantize_input, fake_quantize_weight};
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
@@ -149,13 +152,14 @@ impl<B: Backend> Conv2d<B> {
     /// - input: `[batch_size, channels_in, height_in, width_in]`
     /// - output: `[batch_size, channels_out, height_out, width_out]`
     pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        observe_input(self.weight.id, &input);
         let [_batch_size, _channels_in, height_in, width_in] = input.dims();
         let padding =
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
         conv2d(
-            input,
-            self.weight.val(),
+            fake_quantize_input(self.weight.id, input),
+            fake_quantize_weight(self.weight.id, self.weight.val()),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new(self.stride, padding, self.dilation, self.groups),
         )
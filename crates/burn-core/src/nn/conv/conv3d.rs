@@ -3,7 +3,10 @@ use alloc::format;
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::module::{
+    observe_input, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param,
+};
+use crate::nn::qat::{fake_quantize_input, fake_quantize_weight};
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig3d;
 use crate::tensor::backend::Backend;
@@ -146,6 +149,7 @@ impl<B: Backend> Conv3d<B> {
     /// - input: `[batch_size, channels_in, depth_in, height_in, width_in]`
     /// - output: `[batch_size, channels_out, depth_out, height_out, width_out]`
     pub fn forward(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
+        observe_input(self.weight.id, &input);
         let [_batch_size, _channels_in, depth_in, height_in, width_in] = input.dims();
         let padding = self.padding.calculate_padding_3d(
             depth_in,
@@ -155,8 +159,8 @@ impl<B: Backend> Conv3d<B> {
             &self.stride,
         );
         conv3d(
-            input,
-            self.weight.val(),
+            fake_quantize_input(self.weight.id, input),
+            fake_quantize_weight(self.weight.id, self.weight.val()),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new(self.stride, padding, self.dilation, self.groups),
         )
@@ -9,6 +9,7 @@ use crate::module::Module;
 use crate::module::ModuleDisplay;
 use crate::module::Param;
 use crate::nn::conv::checks;
+use crate::nn::qat::{fake_quantize_input, fake_quantize_weight};
 use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
 use crate::tensor::module::conv_transpose1d;
@@ -141,8 +142,8 @@ impl<B: Backend> ConvTranspose1d<B> {
     /// - output: `[batch_size, channels_out, length_out]`
     pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
         conv_transpose1d(
-            input,
-            self.weight.val(),
+            fake_quantize_input(self.weight.id, input),
+            fake_quantize_weight(self.weight.id, self.weight.val()),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvTransposeOptions::new(
                 [self.stride],
@@ -9,6 +9,7 @@ use crate::module::Module;
 use crate::module::ModuleDisplay;
 use crate::module::Param;
 use crate::nn::conv::checks;
+use crate::nn::qat::{fake_quantize_input, fake_quantize_weight};
 use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
 use crate::tensor::module::conv_transpose2d;
@@ -142,8 +143,8 @@ impl<B: Backend> ConvTranspose2d<B> {
     /// - output: `[batch_size, channels_out, height_out, width_out]`
     pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
         conv_transpose2d(
-            input,
-            self.weight.val(),
+            fake_quantize_input(self.weight.id, input),
+            fake_quantize_weight(self.weight.id, self.weight.val()),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvTransposeOptions::new(
                 self.stride,
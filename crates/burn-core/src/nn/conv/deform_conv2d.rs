@@ -5,6 +5,7 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::nn::qat::{fake_quantize_input, fake_quantize_weight};
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
@@ -168,9 +169,9 @@ impl<B: Backend> DeformConv2d<B> {
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
         deform_conv2d(
-            input,
+            fake_quantize_input(self.weight.id, input),
             offset,
-            self.weight.val(),
+            fake_quantize_weight(self.weight.id, self.weight.val()),
             mask,
             self.bias.as_ref().map(|bias| bias.val()),
             DeformConvOptions::new(
@@ -1,5 +1,6 @@
 use crate as burn;
 
+use super::qat::fake_quantize_weight;
 use super::Initializer;
 use crate::config::Config;
 use crate::module::Module;
@@ -71,7 +72,8 @@ impl<B: Backend> Embedding<B> {
     /// - input: `[batch_size, seq_length]`
     /// - output: `[batch_size, seq_length, d_model]`
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
-        embedding(self.weight.val(), input)
+        let weight = fake_quantize_weight(self.weight.id, self.weight.val());
+        embedding(weight, input)
     }
 }
 
@@ -0,0 +1,172 @@
+use burn_tensor::module::grid_sample_2d;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::ops::{GridSampleOptions, GridSamplePaddingMode};
+use crate::tensor::Tensor;
+
+use super::InterpolateMode;
+
+/// Configuration for the 2D grid sample module.
+///
+/// This struct defines how the input is sampled at the locations of the grid, and how the
+/// locations outside of the input are handled.
+#[derive(Config, Debug)]
+pub struct GridSample2dConfig {
+    /// Interpolation mode used to compute the sampled values.
+    #[config(default = "InterpolateMode::Linear")]
+    pub mode: InterpolateMode,
+
+    /// Padding used for the locations outside of the input.
+    #[config(default = "GridSamplePaddingMode::Zeros")]
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If true, the extrema `-1` and `1` of the grid refer to the center of the corner pixels,
+    /// otherwise they refer to the corner points of the corner pixels.
+    #[config(default = false)]
+    pub align_corners: bool,
+}
+
+/// Grid sample module for sampling tensors with shape [N, C, H, W] at arbitrary locations.
+///
+/// The locations are given by a grid of shape [N, H', W', 2] holding the `(x, y)` coordinates
+/// normalized to `[-1, 1]`, as produced by [affine_grid_2d](burn_tensor::module::affine_grid_2d)
+/// for spatial transformer networks.
+///
+/// The module can be created using the [GridSample2dConfig] struct and the
+/// `init` method, which returns an instance of the [GridSample2d] struct.
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct GridSample2d {
+    /// Interpolation mode used to compute the sampled values
+    pub mode: Ignored<InterpolateMode>,
+
+    /// Padding used for the locations outside of the input
+    pub padding_mode: Ignored<GridSamplePaddingMode>,
+
+    /// Whether the extrema of the grid refer to the center of the corner pixels
+    pub align_corners: bool,
+}
+
+impl GridSample2dConfig {
+    /// Initialize the grid sample module
+    pub fn init(self) -> GridSample2d {
+        GridSample2d {
+            mode: Ignored(self.mode),
+            padding_mode: Ignored(self.padding_mode),
+            align_corners: self.align_corners,
+        }
+    }
+}
+
+impl GridSample2d {
+    /// Performs the forward pass of the grid sample module
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Input tensor with shape [N, C, H, W]
+    /// * `grid` - Sampling locations with shape [N, H', W', 2]
+    ///
+    /// # Returns
+    ///
+    /// Sampled tensor with shape [N, C, H', W']
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let input = Tensor::<Backend, 4>::random([1, 3, 64, 64], Distribution::Uniform(0.0, 1.0), &device);
+    /// let theta = Tensor::<Backend, 3>::from_floats([[[0.5, 0.0, 0.0], [0.0, 0.5, 0.0]]], &device);
+    /// let grid = affine_grid_2d(theta, [1, 3, 32, 32], false);
+    /// let grid_sample = GridSample2dConfig::new().init();
+    /// let output = grid_sample.forward(input, grid);
+    /// assert_eq!(output.dims(), [1, 3, 32, 32]);
+    /// ```
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>, grid: Tensor<B, 4>) -> Tensor<B, 4> {
+        grid_sample_2d(
+            input,
+            grid,
+            GridSampleOptions::new(
+                self.mode.0.clone().into(),
+                self.padding_mode.0,
+                self.align_corners,
+            ),
+        )
+    }
+}
+
+impl ModuleDisplay for GridSample2d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("mode", &self.mode)
+            .add("padding_mode", &self.padding_mode)
+            .add("align_corners", &self.align_corners)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_tensor::{module::affine_grid_2d, Distribution};
+
+    use crate::TestBackend;
+
+    use super::*;
+
+    #[test]
+    fn test_module() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 4>::random(
+            [2, 3, 4, 4],
+            Distribution::Uniform(0.0, 1.0),
+            &device,
+        );
+        let theta = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            ],
+            &device,
+        );
+
+        // The identity transform samples the input at the center of its pixels.
+        let grid = affine_grid_2d(theta.clone(), [2, 3, 4, 4], false);
+        let grid_sample = GridSample2dConfig::new().init();
+        let output = grid_sample.forward(input.clone(), grid);
+        output.into_data().assert_approx_eq(&input.into_data(), 3);
+
+        // The output size follows the grid.
+        let input = Tensor::<TestBackend, 4>::random(
+            [2, 3, 4, 4],
+            Distribution::Uniform(0.0, 1.0),
+            &device,
+        );
+        let grid = affine_grid_2d(theta, [2, 3, 6, 5], true);
+        let grid_sample = GridSample2dConfig::new()
+            .with_mode(InterpolateMode::Cubic)
+            .with_padding_mode(GridSamplePaddingMode::Border)
+            .with_align_corners(true)
+            .init();
+        let output = grid_sample.forward(input, grid);
+        assert_eq!(output.dims(), [2, 3, 6, 5]);
+    }
+
+    #[test]
+    fn display() {
+        let config = GridSample2dConfig::new();
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "GridSample2d {mode: Linear, padding_mode: Zeros, align_corners: false}"
+        );
+    }
+}
@@ -1,6 +1,8 @@
+mod grid_sample2d;
 mod interpolate1d;
 mod interpolate2d;
 
+pub use grid_sample2d::*;
 pub use interpolate1d::*;
 pub use interpolate2d::*;
 
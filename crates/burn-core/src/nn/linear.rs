@@ -1,10 +1,11 @@
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::Param;
+use crate::module::{observe_input, Param};
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
 use crate::tensor::{backend::Backend, Tensor};
 
+use super::qat::{fake_quantize_input, fake_quantize_weight};
 use super::Initializer;
 
 /// Configuration to create a [Linear](Linear) layer using the [init function](LinearConfig::init).
@@ -75,7 +76,9 @@ impl<B: Backend> Linear<B> {
             return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
         }
 
-        let weight = self.weight.val().unsqueeze();
+        observe_input(self.weight.id, &input);
+        let input = fake_quantize_input(self.weight.id, input);
+        let weight = fake_quantize_weight(self.weight.id, self.weight.val()).unsqueeze();
         let bias = self.bias.as_ref().map(|b| b.val().unsqueeze());
         let output = input.matmul(weight);
 
@@ -19,6 +19,9 @@ pub mod transformer;
 /// Interpolate module
 pub mod interpolate;
 
+/// Quantization-aware training module
+pub mod qat;
+
 mod dropout;
 mod embedding;
 mod gelu;
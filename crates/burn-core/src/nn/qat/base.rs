@@ -0,0 +1,71 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::{
+    backend::Backend,
+    quantization::{QuantizationScheme, QuantizationType},
+    Tensor,
+};
+
+use super::{FakeQuantize, FakeQuantizeConfig};
+
+/// Configuration to prepare modules for quantization-aware training with [IntoQat] or
+/// [quantize_aware](super::quantize_aware).
+#[derive(Config, Debug)]
+pub struct QatConfig {
+    /// The fake quantization of the weights.
+    /// Default: per-tensor symmetric int8 quantization
+    #[config(default = "FakeQuantizeConfig::new()")]
+    pub weight: FakeQuantizeConfig,
+    /// The fake quantization of the input activations, which are always quantized per-tensor.
+    /// Default: per-tensor affine int8 quantization
+    #[config(
+        default = "FakeQuantizeConfig::new().with_scheme(QuantizationScheme::PerTensorAffine(QuantizationType::QInt8))"
+    )]
+    pub activation: FakeQuantizeConfig,
+}
+
+impl QatConfig {
+    /// Initializes the fake quantization of the weight, calibrated on its current values.
+    pub(crate) fn init_weight<B: Backend, const D: usize>(
+        &self,
+        weight: &Tensor<B, D>,
+    ) -> FakeQuantize<B> {
+        let shape = weight.dims();
+        // Weights that cannot be split into channels or blocks are quantized per-tensor instead
+        let scheme = if self.weight.scheme.is_compatible(&shape) {
+            self.weight.scheme
+        } else {
+            self.weight.scheme.to_per_tensor()
+        };
+        let num_params = scheme.params_layout(&shape).num_params;
+
+        self.weight
+            .clone()
+            .with_scheme(scheme)
+            .init(num_params, &weight.device())
+            .calibrate(weight)
+    }
+
+    /// Initializes the fake quantization of the input activations.
+    pub(crate) fn init_activation<B: Backend>(&self, device: &B::Device) -> FakeQuantize<B> {
+        self.activation
+            .clone()
+            .with_scheme(self.activation.scheme.to_per_tensor())
+            .init(1, device)
+    }
+}
+
+/// Inserts [fake quantization](FakeQuantize) around the weights and input activations of a module,
+/// so it can be fine-tuned with quantization-aware training before being quantized.
+///
+/// To prepare all the eligible weights of any model instead, see
+/// [quantize_aware](super::quantize_aware).
+pub trait IntoQat<B: Backend> {
+    /// The module prepared for quantization-aware training.
+    type Qat: Module<B>;
+
+    /// Wraps the module for quantization-aware training.
+    fn into_qat(self, config: &QatConfig) -> Self::Qat;
+}
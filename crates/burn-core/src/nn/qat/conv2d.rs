@@ -0,0 +1,107 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::nn::conv::Conv2d;
+use crate::tensor::module::conv2d;
+use crate::tensor::ops::ConvOptions;
+use crate::tensor::{backend::Backend, Tensor};
+
+use super::{FakeQuantize, IntoQat, QatConfig};
+
+/// A [2D convolution](Conv2d) module prepared for quantization-aware training.
+///
+/// Should be created from a [Conv2d] module with [into_qat](IntoQat::into_qat).
+#[derive(Module, Debug)]
+pub struct QatConv2d<B: Backend> {
+    /// The wrapped convolution module.
+    pub conv: Conv2d<B>,
+    /// The fake quantization of the weight.
+    pub weight_quantizer: FakeQuantize<B>,
+    /// The fake quantization of the input activations.
+    pub input_quantizer: FakeQuantize<B>,
+}
+
+impl<B: Backend> IntoQat<B> for Conv2d<B> {
+    type Qat = QatConv2d<B>;
+
+    fn into_qat(self, config: &QatConfig) -> Self::Qat {
+        let weight = self.weight.val();
+
+        QatConv2d {
+            weight_quantizer: config.init_weight(&weight),
+            input_quantizer: config.init_activation(&weight.device()),
+            conv: self,
+        }
+    }
+}
+
+impl<B: Backend> QatConv2d<B> {
+    /// Applies the forward pass on the input tensor, with the input and weight fake quantized.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let conv = &self.conv;
+        let padding =
+            conv.padding
+                .calculate_padding_2d(height_in, width_in, &conv.kernel_size, &conv.stride);
+
+        conv2d(
+            self.input_quantizer.forward(input),
+            self.weight_quantizer.forward(conv.weight.val()),
+            conv.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new(conv.stride, padding, conv.dilation, conv.groups),
+        )
+    }
+
+    /// Returns the [2D convolution](Conv2d) module with its weight quantized using the observed
+    /// quantization parameters.
+    ///
+    /// The module should be converted on a backend without autodiff (e.g., after calling
+    /// [valid](crate::module::AutodiffModule::valid)).
+    pub fn into_quantized(self) -> Conv2d<B> {
+        let scheme = *self.weight_quantizer.scheme;
+        let qparams = self.weight_quantizer.q_params();
+        let weight = self
+            .conv
+            .weight
+            .map(|weight| weight.quantize(&scheme, qparams));
+
+        Conv2d {
+            weight,
+            ..self.conv
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::qat::FakeQuantizeConfig;
+    use crate::tensor::quantization::{QuantizationScheme, QuantizationType};
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn qat_conv2d_should_quantize_weight_per_channel() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0);
+        let config = QatConfig::new().with_weight(FakeQuantizeConfig::new().with_scheme(scheme));
+        let conv = Conv2dConfig::new([2, 3], [3, 3])
+            .init::<TestAutodiffBackend>(&device)
+            .into_qat(&config);
+
+        // One scale for each output channel
+        assert_eq!(conv.weight_quantizer.q_params().scale.dims(), [3]);
+
+        let input = Tensor::<TestAutodiffBackend, 4>::ones([1, 2, 5, 5], &device);
+        let output = conv.forward(input);
+        assert_eq!(output.dims(), [1, 3, 3, 3]);
+
+        let grads = output.sum().backward();
+        assert!(conv.conv.weight.grad(&grads).is_some());
+    }
+}
@@ -0,0 +1,249 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Ignored, Module, Param, RunningState};
+use crate::tensor::{
+    backend::Backend,
+    quantization::{
+        Calibration, CalibrationRange, MinMaxCalibration, QuantizationParameters,
+        QuantizationScheme, QuantizationType,
+    },
+    Tensor,
+};
+
+/// Observer used to compute the quantization parameters of a [fake quantization](FakeQuantize)
+/// module.
+#[derive(Config, Debug, PartialEq)]
+pub enum QuantObserver {
+    /// Tracks the exponential moving average of the minimum and maximum observed values.
+    MovingAverageMinMax {
+        /// Momentum used to update the running range.
+        momentum: f64,
+    },
+    /// The scale is a learnable parameter, optimized with the gradients of the
+    /// [learned step size](https://arxiv.org/abs/1902.08153) estimator.
+    ///
+    /// Only valid for symmetric quantization schemes.
+    Learnable,
+}
+
+/// Configuration to create a [FakeQuantize](FakeQuantize) module using the
+/// [init function](FakeQuantizeConfig::init).
+#[derive(Config, Debug)]
+pub struct FakeQuantizeConfig {
+    /// The quantization scheme to simulate.
+    #[config(default = "QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8)")]
+    pub scheme: QuantizationScheme,
+    /// The observer used to compute the quantization parameters.
+    /// Default: moving average of the min-max range with a momentum of 0.1
+    #[config(default = "QuantObserver::MovingAverageMinMax { momentum: 0.1 }")]
+    pub observer: QuantObserver,
+}
+
+/// Simulates the quantization of the input values in floating point, also known as fake
+/// quantization.
+///
+/// The values are quantized and dequantized with the parameters computed by the observer, so
+/// the quantization error is taken into account during quantization-aware training. The gradients
+/// are computed with the straight-through estimator.
+///
+/// Should be created using [FakeQuantizeConfig].
+#[derive(Module, Debug)]
+pub struct FakeQuantize<B: Backend> {
+    /// The learnable scale, only available with the [learnable](QuantObserver::Learnable) observer.
+    pub scale: Option<Param<Tensor<B, 1>>>,
+    /// The running minimum of the observed values.
+    pub running_min: RunningState<Tensor<B, 1>>,
+    /// The running maximum of the observed values.
+    pub running_max: RunningState<Tensor<B, 1>>,
+    /// The number of observed updates.
+    pub num_updates: RunningState<Tensor<B, 1>>,
+    /// Momentum used to update the running range.
+    pub momentum: f64,
+    /// The quantization scheme.
+    pub scheme: Ignored<QuantizationScheme>,
+}
+
+impl FakeQuantizeConfig {
+    /// Initializes a new [fake quantization](FakeQuantize) module with the given number of
+    /// quantization parameters (see [params_layout](QuantizationScheme::params_layout)).
+    pub fn init<B: Backend>(&self, num_params: usize, device: &B::Device) -> FakeQuantize<B> {
+        let (scale, momentum) = match self.observer {
+            QuantObserver::MovingAverageMinMax { momentum } => (None, momentum),
+            QuantObserver::Learnable => {
+                assert!(
+                    !self.scheme.has_offset(),
+                    "Learnable observer is only supported for symmetric quantization schemes."
+                );
+                let scale = Param::from_tensor(Tensor::ones([num_params], device));
+                (Some(scale), 0.0)
+            }
+        };
+
+        FakeQuantize {
+            scale,
+            running_min: RunningState::new(Tensor::full([num_params], -1.0, device)),
+            running_max: RunningState::new(Tensor::ones([num_params], device)),
+            num_updates: RunningState::new(Tensor::zeros([1], device)),
+            momentum,
+            scheme: Ignored(self.scheme),
+        }
+    }
+}
+
+impl<B: Backend> FakeQuantize<B> {
+    /// Applies the fake quantization on the input tensor.
+    ///
+    /// During training, the running range of the moving average observer is updated with the
+    /// range of the input values, and the updated range is used to compute the quantization
+    /// parameters.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[...]`
+    /// - output: `[...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let qparams = match self.scale.is_none() && B::ad_enabled() {
+            true => self.scheme.compute_q_params(self.observe(&input)),
+            false => self.q_params(),
+        };
+
+        input.fake_quantize(&self.scheme, qparams)
+    }
+
+    /// Returns the quantization parameters computed by the observer.
+    ///
+    /// These are the static parameters used to quantize the values once the module is exported.
+    pub fn q_params(&self) -> QuantizationParameters<B> {
+        match &self.scale {
+            Some(scale) => QuantizationParameters {
+                scale: scale.val(),
+                offset: None,
+            },
+            None => self.scheme.compute_q_params(CalibrationRange {
+                min: self.running_min.value(),
+                max: self.running_max.value(),
+            }),
+        }
+    }
+
+    /// Initializes the observed range, and the learnable scale if any, from the range of the tensor
+    /// values.
+    pub fn calibrate<const D: usize>(mut self, tensor: &Tensor<B, D>) -> Self {
+        let range = MinMaxCalibration {}.compute_range(&tensor.clone().detach(), &self.scheme);
+
+        self.scale = self.scale.map(|scale| {
+            let (id, _) = scale.consume();
+            let qparams = self.scheme.compute_q_params(range.clone());
+            Param::initialized(id, qparams.scale.require_grad())
+        });
+        self.running_min.update(range.min);
+        self.running_max.update(range.max);
+        self.num_updates
+            .update(Tensor::ones([1], &self.num_updates.value().device()));
+
+        self
+    }
+
+    /// Updates the running range with the range of the input values, and returns the updated range.
+    fn observe<const D: usize>(&self, input: &Tensor<B, D>) -> CalibrationRange<B> {
+        let device = input.device();
+        let range = MinMaxCalibration {}.compute_range(&input.clone().detach(), &self.scheme);
+
+        let num_updates = self.num_updates.value_sync().to_device(&device);
+        // The first observed range replaces the initial range, then the moving average is used
+        let momentum = num_updates
+            .clone()
+            .add_scalar(1)
+            .recip()
+            .clamp_min(self.momentum);
+
+        let update = |running: Tensor<B, 1>, value: Tensor<B, 1>| {
+            running.clone() + (value - running) * momentum.clone()
+        };
+        let running_min = update(self.running_min.value_sync().to_device(&device), range.min);
+        let running_max = update(self.running_max.value_sync().to_device(&device), range.max);
+
+        self.running_min.update(running_min.clone().detach());
+        self.running_max.update(running_max.clone().detach());
+        self.num_updates.update(num_updates.add_scalar(1).detach());
+
+        CalibrationRange {
+            min: running_min.detach(),
+            max: running_max.detach(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn moving_average_observer_should_track_range_during_training() {
+        let device = Default::default();
+        let fake_quant = FakeQuantizeConfig::new()
+            .with_observer(QuantObserver::MovingAverageMinMax { momentum: 0.5 })
+            .init::<TestAutodiffBackend>(1, &device);
+
+        // The first observed range is used as is
+        let input = Tensor::<TestAutodiffBackend, 1>::from_floats([-2.0, 1.0, 4.0], &device);
+        fake_quant.forward(input);
+        let input = Tensor::<TestAutodiffBackend, 1>::from_floats([-4.0, 1.0, 8.0], &device);
+        fake_quant.forward(input);
+
+        fake_quant
+            .running_min
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-3.0]), 3);
+        fake_quant
+            .running_max
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([6.0]), 3);
+    }
+
+    #[test]
+    fn observer_should_not_update_range_during_inference() {
+        let device = Default::default();
+        let fake_quant = FakeQuantizeConfig::new().init::<TestBackend>(1, &device);
+
+        let input = Tensor::<TestBackend, 1>::from_floats([-1.0, 0.25, 3.0], &device);
+        let output = fake_quant.forward(input);
+
+        // Quantized with the initial `[-1, 1]` range
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.0, 0.251_968_5, 1.0]), 3);
+        fake_quant
+            .running_max
+            .value()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0]), false);
+    }
+
+    #[test]
+    fn learnable_observer_should_compute_scale_gradients() {
+        let device = Default::default();
+        let weight =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[-1.27, 0.5], [0.3, 1.27]], &device);
+        let fake_quant = FakeQuantizeConfig::new()
+            .with_observer(QuantObserver::Learnable)
+            .init::<TestAutodiffBackend>(1, &device)
+            .calibrate(&weight);
+
+        let scale = fake_quant.scale.as_ref().unwrap();
+        scale
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.01]), 3);
+
+        let output = fake_quant.forward(weight);
+        let grads = output.sum().backward();
+
+        assert!(scale.grad(&grads).is_some());
+    }
+}
@@ -0,0 +1,114 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, Tensor};
+
+use super::{FakeQuantize, IntoQat, QatConfig};
+
+/// A [linear](Linear) module prepared for quantization-aware training.
+///
+/// Should be created from a [Linear] module with [into_qat](IntoQat::into_qat).
+#[derive(Module, Debug)]
+pub struct QatLinear<B: Backend> {
+    /// The wrapped linear module.
+    pub linear: Linear<B>,
+    /// The fake quantization of the weight.
+    pub weight_quantizer: FakeQuantize<B>,
+    /// The fake quantization of the input activations.
+    pub input_quantizer: FakeQuantize<B>,
+}
+
+impl<B: Backend> IntoQat<B> for Linear<B> {
+    type Qat = QatLinear<B>;
+
+    fn into_qat(self, config: &QatConfig) -> Self::Qat {
+        let weight = self.weight.val();
+
+        QatLinear {
+            weight_quantizer: config.init_weight(&weight),
+            input_quantizer: config.init_activation(&weight.device()),
+            linear: self,
+        }
+    }
+}
+
+impl<B: Backend> QatLinear<B> {
+    /// Applies the forward pass on the input tensor, with the input and weight fake quantized.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D == 1 {
+            // Insert and remove an extra batch dimension for the batch matmul to work.
+            return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
+        }
+
+        let input = self.input_quantizer.forward(input);
+        let weight = self
+            .weight_quantizer
+            .forward(self.linear.weight.val())
+            .unsqueeze();
+        let bias = self.linear.bias.as_ref().map(|b| b.val().unsqueeze());
+        let output = input.matmul(weight);
+
+        match bias {
+            Some(bias) => output + bias,
+            None => output,
+        }
+    }
+
+    /// Returns the [linear](Linear) module with its weight quantized using the observed
+    /// quantization parameters.
+    ///
+    /// The module should be converted on a backend without autodiff (e.g., after calling
+    /// [valid](crate::module::AutodiffModule::valid)).
+    pub fn into_quantized(self) -> Linear<B> {
+        let scheme = *self.weight_quantizer.scheme;
+        let qparams = self.weight_quantizer.q_params();
+        let weight = self
+            .linear
+            .weight
+            .map(|weight| weight.quantize(&scheme, qparams));
+
+        Linear {
+            weight,
+            bias: self.linear.bias,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::AutodiffModule;
+    use crate::nn::LinearConfig;
+    use crate::tensor::quantization::{QuantizationScheme, QuantizationType};
+    use crate::tensor::DType;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn qat_linear_should_train_and_quantize_weight() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 2)
+            .init::<TestAutodiffBackend>(&device)
+            .into_qat(&QatConfig::new());
+
+        let input = Tensor::<TestAutodiffBackend, 2>::ones([3, 4], &device);
+        let output = linear.forward(input);
+        assert_eq!(output.dims(), [3, 2]);
+
+        let grads = output.sum().backward();
+        assert!(linear.linear.weight.grad(&grads).is_some());
+
+        let linear = linear.valid().into_quantized();
+        assert_eq!(
+            linear.weight.val().dtype(),
+            DType::QFloat(QuantizationScheme::PerTensorSymmetric(
+                QuantizationType::QInt8
+            ))
+        );
+    }
+}
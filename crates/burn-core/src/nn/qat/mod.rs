@@ -0,0 +1,11 @@
+mod base;
+mod conv2d;
+mod fake_quantize;
+mod linear;
+mod model;
+
+pub use base::*;
+pub use conv2d::*;
+pub use fake_quantize::*;
+pub use linear::*;
+pub use model::*;
@@ -0,0 +1,310 @@
+use crate as burn;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::Any;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use hashbrown::HashMap;
+
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::tensor::{
+    backend::Backend,
+    quantization::{QuantizationParameters, QuantizationScheme},
+    Tensor,
+};
+
+use super::{FakeQuantize, QatConfig};
+
+/// The fake quantization of the weight and of the input activations of each module, keyed by the
+/// id of the weight.
+type Quantizers<B> = HashMap<ParamId, (FakeQuantize<B>, FakeQuantize<B>)>;
+
+/// The quantizers of the running quantization-aware forward passes, with their id.
+static QUANTIZERS: spin::Mutex<Vec<(usize, Box<dyn Any + Send>)>> = spin::Mutex::new(Vec::new());
+
+/// The number of running quantization-aware forward passes, to skip locking the quantizers when
+/// there are none.
+static NUM_FORWARDS: AtomicUsize = AtomicUsize::new(0);
+
+/// The id of the next quantization-aware forward pass.
+static NEXT_FORWARD_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the quantizers of the module with the given weight when a
+/// [quantization-aware](QuantizeAware) forward pass is running.
+fn quantizers<B: Backend>(weight: ParamId) -> Option<(FakeQuantize<B>, FakeQuantize<B>)> {
+    if NUM_FORWARDS.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+
+    QUANTIZERS.lock().iter().find_map(|(_, quantizers)| {
+        // The module isn't part of the model, or runs on another backend.
+        quantizers
+            .downcast_ref::<Quantizers<B>>()?
+            .get(&weight)
+            .cloned()
+    })
+}
+
+/// Fake quantizes the given weight when a [quantization-aware](QuantizeAware) forward pass is
+/// running.
+pub(crate) fn fake_quantize_weight<B: Backend, const D: usize>(
+    weight: ParamId,
+    tensor: Tensor<B, D>,
+) -> Tensor<B, D> {
+    match quantizers::<B>(weight) {
+        Some((quantizer, _)) => quantizer.forward(tensor),
+        None => tensor,
+    }
+}
+
+/// Fake quantizes the input of the module with the given weight when a
+/// [quantization-aware](QuantizeAware) forward pass is running.
+pub(crate) fn fake_quantize_input<B: Backend, const D: usize>(
+    weight: ParamId,
+    input: Tensor<B, D>,
+) -> Tensor<B, D> {
+    match quantizers::<B>(weight) {
+        Some((_, quantizer)) => quantizer.forward(input),
+        None => input,
+    }
+}
+
+/// Removes the quantizers of a running forward pass when dropped.
+struct ForwardGuard {
+    id: usize,
+}
+
+impl ForwardGuard {
+    fn enter<B: Backend>(quantizers: Quantizers<B>) -> Self {
+        let id = NEXT_FORWARD_ID.fetch_add(1, Ordering::Relaxed);
+        QUANTIZERS.lock().push((id, Box::new(quantizers)));
+        NUM_FORWARDS.fetch_add(1, Ordering::Release);
+
+        Self { id }
+    }
+}
+
+impl Drop for ForwardGuard {
+    fn drop(&mut self) {
+        NUM_FORWARDS.fetch_sub(1, Ordering::Release);
+        QUANTIZERS.lock().retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// Finds the weights eligible for quantization-aware training, which are the parameters named
+/// `weight` with at least two dimensions.
+struct EligibleWeights<'a, B: Backend> {
+    path: Vec<String>,
+    ids: Vec<ParamId>,
+    /// Initializes the quantizers of the eligible weights when set.
+    config: Option<&'a QatConfig>,
+    quantizers: Vec<(FakeQuantize<B>, FakeQuantize<B>)>,
+}
+
+impl<'a, B: Backend> EligibleWeights<'a, B> {
+    fn visit<M: Module<B>>(model: &M, config: Option<&'a QatConfig>) -> Self {
+        let mut weights = Self {
+            path: Vec::new(),
+            ids: Vec::new(),
+            config,
+            quantizers: Vec::new(),
+        };
+        model.visit(&mut weights);
+        weights
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for EligibleWeights<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if D < 2 || self.path.last().map(String::as_str) != Some("weight") {
+            return;
+        }
+
+        self.ids.push(id);
+        if let Some(config) = self.config {
+            self.quantizers.push((
+                config.init_weight(tensor),
+                config.init_activation(&tensor.device()),
+            ));
+        }
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+/// Quantizes the eligible weights with the quantization parameters of their observer.
+struct WeightQuantizer<B: Backend> {
+    weights: HashMap<ParamId, (QuantizationScheme, QuantizationParameters<B>)>,
+}
+
+impl<B: Backend> ModuleMapper<B> for WeightQuantizer<B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.weights.remove(&id) {
+            Some((scheme, qparams)) => tensor.quantize(&scheme, qparams),
+            None => tensor,
+        }
+    }
+}
+
+/// A model prepared for quantization-aware training, with the eligible weights and the input
+/// activations of their modules fake quantized during the [forward pass](QuantizeAware::forward).
+///
+/// The eligible weights are the parameters named `weight` with at least two dimensions, which
+/// includes the weights of the linear, convolution and embedding modules. Custom modules with such
+/// a weight should use one of these modules, since their weight is otherwise only fake quantized
+/// once [converted](QuantizeAware::into_quantized).
+///
+/// Should be created with [quantize_aware].
+#[derive(Module, Debug)]
+pub struct QuantizeAware<B: Backend, M> {
+    /// The wrapped model.
+    pub model: M,
+    /// The fake quantization of each eligible weight, in the order they are visited.
+    pub weight_quantizers: Vec<FakeQuantize<B>>,
+    /// The fake quantization of the input activations of the module of each eligible weight.
+    pub input_quantizers: Vec<FakeQuantize<B>>,
+}
+
+/// Prepares any model for quantization-aware training, by wrapping its eligible weights and the
+/// input activations of their modules in [fake quantization](FakeQuantize).
+///
+/// The weight quantizers are calibrated on the current values of the weights.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let model = quantize_aware(model, &QatConfig::new());
+/// let output = model.forward(|model| model.forward(input));
+/// // After training
+/// let model = model.valid().into_quantized();
+/// ```
+pub fn quantize_aware<B: Backend, M: Module<B>>(
+    model: M,
+    config: &QatConfig,
+) -> QuantizeAware<B, M> {
+    let weights = EligibleWeights::visit(&model, Some(config));
+    let (weight_quantizers, input_quantizers) = weights.quantizers.into_iter().unzip();
+
+    QuantizeAware {
+        model,
+        weight_quantizers,
+        input_quantizers,
+    }
+}
+
+impl<B: Backend, M: Module<B>> QuantizeAware<B, M> {
+    /// Runs the forward pass of the wrapped model, with the eligible weights and the input
+    /// activations of their modules fake quantized.
+    pub fn forward<R>(&self, forward: impl FnOnce(&M) -> R) -> R {
+        let quantizers: Quantizers<B> = EligibleWeights::visit(&self.model, None)
+            .ids
+            .into_iter()
+            .zip(
+                self.weight_quantizers
+                    .iter()
+                    .cloned()
+                    .zip(self.input_quantizers.iter().cloned()),
+            )
+            .collect();
+
+        let _guard = ForwardGuard::enter(quantizers);
+        forward(&self.model)
+    }
+
+    /// Returns the wrapped model with its eligible weights quantized using the observed
+    /// quantization parameters.
+    ///
+    /// The model should be converted on a backend without autodiff (e.g., after calling
+    /// [valid](crate::module::AutodiffModule::valid)).
+    pub fn into_quantized(self) -> M {
+        let weights = EligibleWeights::visit(&self.model, None)
+            .ids
+            .into_iter()
+            .zip(self.weight_quantizers)
+            .map(|(id, quantizer)| (id, (*quantizer.scheme, quantizer.q_params())))
+            .collect();
+
+        self.model.map(&mut WeightQuantizer { weights })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::AutodiffModule;
+    use crate::nn::qat::IntoQat;
+    use crate::nn::{LayerNorm, LayerNormConfig, Linear, LinearConfig};
+    use crate::tensor::{DType, TensorData};
+    use crate::TestAutodiffBackend;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        layers: Vec<Linear<B>>,
+        norm: LayerNorm<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.layers[0].forward(input);
+            let x = self.norm.forward(x);
+            self.layers[1].forward(x)
+        }
+    }
+
+    #[test]
+    fn quantize_aware_should_match_qat_modules() {
+        let device = Default::default();
+        let config = QatConfig::new();
+        let model = Model::<TestAutodiffBackend> {
+            layers: vec![
+                LinearConfig::new(4, 3).init(&device),
+                LinearConfig::new(3, 2).init(&device),
+            ],
+            norm: LayerNormConfig::new(3).init(&device),
+        };
+        let (fc1, fc2) = (
+            model.layers[0].clone().into_qat(&config),
+            model.layers[1].clone().into_qat(&config),
+        );
+        let model = quantize_aware(model, &config);
+        // Only the linear weights are eligible, not the layer norm parameters
+        assert_eq!(model.weight_quantizers.len(), 2);
+
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[1.0, -2.0, 0.5, 3.0], [0.2, 0.1, -1.5, 2.0]],
+            &device,
+        );
+        let output = model.forward(|model| model.forward(input.clone()));
+        let expected = fc2.forward(model.model.norm.forward(fc1.forward(input)));
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+
+        // The inputs were observed by the quantizers of the model
+        for quantizer in model.input_quantizers.iter() {
+            quantizer
+                .num_updates
+                .value_sync()
+                .into_data()
+                .assert_eq(&TensorData::from([1.0]), false);
+        }
+
+        let grads = output.sum().backward();
+        assert!(model.model.layers[0].weight.grad(&grads).is_some());
+
+        let model = model.valid().into_quantized();
+        for layer in model.layers {
+            assert!(matches!(layer.weight.val().dtype(), DType::QFloat(_)));
+        }
+        assert_eq!(model.norm.gamma.val().dtype(), DType::F32);
+    }
+}
@@ -277,7 +277,7 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         if let Some(mask_attn) = &input.target_mask_attn {
             self_attn_input = self_attn_input.mask_attn(mask_attn.clone());
         }
-        let residual_path = self.self_attn.forward(self_attn_input).context;
+        let residual_path = self.self_attn.forward_context(self_attn_input);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
@@ -300,7 +300,7 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         if let Some(mask_attn) = &input.memory_mask_attn {
             cross_attn_input = cross_attn_input.mask_attn(mask_attn.clone());
         }
-        let residual_path = self.cross_attn.forward(cross_attn_input).context;
+        let residual_path = self.cross_attn.forward_context(cross_attn_input);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
@@ -354,8 +354,7 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         }
         let residual_path = self
             .self_attn
-            .forward_cache(self_attn_input, &mut cache.self_attn)
-            .context;
+            .forward_context_cache(self_attn_input, &mut cache.self_attn);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
@@ -384,8 +383,7 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         }
         let residual_path = self
             .cross_attn
-            .forward_cache(cross_attn_input, &mut cache.cross_attn)
-            .context;
+            .forward_context_cache(cross_attn_input, &mut cache.cross_attn);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
@@ -262,7 +262,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         if let Some(mask_attn) = mask_attn {
             input_mhs = input_mhs.mask_attn(mask_attn);
         }
-        let residual_path = self.mha.forward(input_mhs).context;
+        let residual_path = self.mha.forward_context(input_mhs);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
@@ -316,7 +316,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         if let Some(mask_attn) = mask_attn {
             input_mhs = input_mhs.mask_attn(mask_attn);
         }
-        let residual_path = self.mha.forward_cache(input_mhs, &mut cache.mha).context;
+        let residual_path = self.mha.forward_context_cache(input_mhs, &mut cache.mha);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
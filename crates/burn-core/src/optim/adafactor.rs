@@ -0,0 +1,278 @@
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use burn_tensor::{backend::Backend, ops::Device};
+
+/// Adafactor configuration.
+#[derive(Config)]
+pub struct AdafactorConfig {
+    /// Regularization constant added to the squared gradients.
+    #[config(default = 1e-30)]
+    epsilon_1: f32,
+    /// Lower bound of the parameter scale when `scale_parameter` is enabled.
+    #[config(default = 1e-3)]
+    epsilon_2: f32,
+    /// Threshold of the root mean square of the update, above which the update is scaled down.
+    #[config(default = 1.0)]
+    clip_threshold: f32,
+    /// Exponent of the step used to compute the decay of the second moment estimate.
+    #[config(default = -0.8)]
+    decay_rate: f32,
+    /// Decay of the first moment estimate. No first moment is kept when `None`, which saves
+    /// memory.
+    beta_1: Option<f32>,
+    /// Decoupled weight decay.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// If true, the learning rate is relative to the root mean square of each parameter.
+    #[config(default = true)]
+    scale_parameter: bool,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Adafactor optimizer as described in the paper [Adafactor: Adaptive Learning Rates with Sublinear Memory Cost](https://arxiv.org/abs/1804.04235).
+///
+/// The second moment estimate of the parameters with at least two dimensions is factored into
+/// the moving averages of the rows and the columns of the squared gradients, over the last two
+/// dimensions. The memory used by the state of a `[n, m]` parameter is then `n + m` instead of
+/// `n * m`.
+#[derive(Clone)]
+pub struct Adafactor {
+    epsilon_1: f32,
+    epsilon_2: f32,
+    clip_threshold: f32,
+    decay_rate: f32,
+    beta_1: Option<f32>,
+    weight_decay: f32,
+    scale_parameter: bool,
+}
+
+/// Adafactor state.
+#[derive(Record, Clone, new)]
+pub struct AdafactorState<B: Backend, const D: usize> {
+    /// The number of iterations aggregated.
+    pub time: usize,
+    /// The moving average of the squared gradients over the last dimension, for factored
+    /// parameters.
+    pub row_variance: Option<Tensor<B, D>>,
+    /// The moving average of the squared gradients over the second to last dimension, for
+    /// factored parameters.
+    pub col_variance: Option<Tensor<B, D>>,
+    /// The moving average of the squared gradients, for parameters with a single dimension.
+    pub variance: Option<Tensor<B, D>>,
+    /// The first moment estimate of the update.
+    pub moment_1: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Adafactor {
+    type State<const D: usize> = AdafactorState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state = state.unwrap_or(AdafactorState::new(0, None, None, None, None));
+        state.time += 1;
+
+        // The weight of the new squared gradients decreases with time, one at the first step.
+        let decay = (state.time as f32).powf(self.decay_rate);
+        let grad_squared = grad.clone().powf_scalar(2.0).add_scalar(self.epsilon_1);
+
+        let mut update = if D >= 2 {
+            let row_mean = grad_squared.clone().mean_dim(D - 1);
+            let col_mean = grad_squared.mean_dim(D - 2);
+            let row_variance = moving_average(state.row_variance.take(), row_mean, decay);
+            let col_variance = moving_average(state.col_variance.take(), col_mean, decay);
+
+            // The row variances are normalized so that their product with the column variances
+            // approximates the second moment estimate.
+            let row_factor = row_variance
+                .clone()
+                .div(row_variance.clone().mean_dim(D - 2))
+                .sqrt()
+                .recip();
+            let col_factor = col_variance.clone().sqrt().recip();
+
+            state.row_variance = Some(row_variance);
+            state.col_variance = Some(col_variance);
+
+            grad.mul(row_factor).mul(col_factor)
+        } else {
+            let variance = moving_average(state.variance.take(), grad_squared, decay);
+            let update = grad.div(variance.clone().sqrt());
+            state.variance = Some(variance);
+            update
+        };
+
+        let clipping = root_mean_square(update.clone())
+            .div_scalar(self.clip_threshold)
+            .clamp_min(1.0);
+        update = update.div(clipping.unsqueeze());
+
+        let lr = match self.scale_parameter {
+            true => root_mean_square(tensor.clone())
+                .clamp_min(self.epsilon_2)
+                .mul_scalar(lr)
+                .unsqueeze(),
+            false => Tensor::<B, 1>::from_floats([lr as f32], &tensor.device()).unsqueeze(),
+        };
+        update = update.mul(lr.clone());
+
+        if let Some(beta_1) = self.beta_1 {
+            let moment_1 = state.moment_1.take().unwrap_or_else(|| update.zeros_like());
+            let moment_1 = moving_average(Some(moment_1), update, 1.0 - beta_1);
+            update = moment_1.clone();
+            state.moment_1 = Some(moment_1);
+        }
+
+        let mut tensor_updated = tensor.clone();
+        if self.weight_decay != 0.0 {
+            tensor_updated = tensor_updated - tensor.mul(lr).mul_scalar(self.weight_decay);
+        }
+
+        (tensor_updated - update, Some(state))
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.row_variance = state.row_variance.map(|tensor| tensor.to_device(device));
+        state.col_variance = state.col_variance.map(|tensor| tensor.to_device(device));
+        state.variance = state.variance.map(|tensor| tensor.to_device(device));
+        state.moment_1 = state.moment_1.map(|tensor| tensor.to_device(device));
+        state
+    }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = penalty;
+        Some(self)
+    }
+}
+
+/// Updates the moving average with a new value weighted by `factor`, starting from the value.
+fn moving_average<B: Backend, const D: usize>(
+    average: Option<Tensor<B, D>>,
+    value: Tensor<B, D>,
+    factor: f32,
+) -> Tensor<B, D> {
+    match average {
+        Some(average) => average
+            .mul_scalar(1.0 - factor)
+            .add(value.mul_scalar(factor)),
+        None => value,
+    }
+}
+
+fn root_mean_square<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+    tensor.powf_scalar(2.0).mean().sqrt()
+}
+
+impl AdafactorConfig {
+    /// Initialize Adafactor optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adafactor, M, B> {
+        let optim = Adafactor {
+            epsilon_1: self.epsilon_1,
+            epsilon_2: self.epsilon_2,
+            clip_threshold: self.clip_threshold,
+            decay_rate: self.decay_rate,
+            beta_1: self.beta_1,
+            weight_decay: self.weight_decay,
+            scale_parameter: self.scale_parameter,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    fn adafactor() -> Adafactor {
+        Adafactor {
+            epsilon_1: 1e-30,
+            epsilon_2: 1e-3,
+            clip_threshold: 1.0,
+            decay_rate: -0.8,
+            beta_1: None,
+            weight_decay: 0.0,
+            scale_parameter: false,
+        }
+    }
+
+    #[test]
+    fn test_adafactor_factored_second_moment() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::zeros([2, 3], &device);
+        // A rank one squared gradient is exactly recovered from its row and column averages.
+        let grad = Tensor::from_floats([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]], &device);
+
+        let (tensor, state) = adafactor().step(LEARNING_RATE, tensor, grad, None);
+
+        tensor.into_data().assert_approx_eq(
+            &TensorData::from([[-0.1, -0.1, -0.1], [-0.1, -0.1, -0.1]]),
+            4,
+        );
+        let state = state.unwrap();
+        assert_eq!(state.row_variance.unwrap().dims(), [2, 1]);
+        assert_eq!(state.col_variance.unwrap().dims(), [1, 3]);
+        assert!(state.variance.is_none());
+    }
+
+    #[test]
+    fn test_adafactor_update_is_clipped() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::zeros([2], &device);
+        let grad = Tensor::from_floats([1.0, 1.0], &device);
+        let (_, state) = adafactor().step(LEARNING_RATE, tensor.clone(), grad, None);
+
+        // The second update is 10 / sqrt(0.426 + 0.574 * 100), which is clipped to one.
+        let grad = Tensor::from_floats([10.0, 10.0], &device);
+        let (updated, _) = adafactor().step(LEARNING_RATE, tensor, grad, state);
+        let updated = updated.into_data().to_vec::<f32>().unwrap();
+        assert!(updated.iter().all(|value| (value + 0.1).abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_adafactor_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = AdafactorConfig::new().with_beta_1(Some(0.9)).init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<Adafactor, nn::Linear<TestAutodiffBackend>, _> =
+            AdafactorConfig::new()
+                .init()
+                .load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), 2);
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+}
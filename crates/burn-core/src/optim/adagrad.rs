@@ -69,6 +69,11 @@ impl<B: Backend> SimpleOptimizer<B> for AdaGrad {
         state.lr_decay = state.lr_decay.to_device(device);
         state
     }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = WeightDecay::from_penalty(penalty);
+        Some(self)
+    }
 }
 
 impl AdaGradConfig {
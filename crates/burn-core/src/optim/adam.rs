@@ -76,6 +76,11 @@ impl<B: Backend> SimpleOptimizer<B> for Adam {
         state.momentum = state.momentum.to_device(device);
         state
     }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = WeightDecay::from_penalty(penalty);
+        Some(self)
+    }
 }
 
 impl AdamConfig {
@@ -114,10 +119,10 @@ pub struct AdaptiveMomentumState<B: Backend, const D: usize> {
 }
 
 #[derive(Clone)]
-struct AdaptiveMomentum {
-    beta_1: f32,
-    beta_2: f32,
-    epsilon: f32,
+pub(crate) struct AdaptiveMomentum {
+    pub(crate) beta_1: f32,
+    pub(crate) beta_2: f32,
+    pub(crate) epsilon: f32,
 }
 
 impl AdaptiveMomentum {
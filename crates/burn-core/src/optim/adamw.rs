@@ -71,6 +71,11 @@ impl<B: Backend> SimpleOptimizer<B> for AdamW {
         state.momentum = state.momentum.to_device(device);
         state
     }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = penalty;
+        Some(self)
+    }
 }
 
 impl AdamWConfig {
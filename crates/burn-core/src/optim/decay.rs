@@ -33,6 +33,12 @@ impl WeightDecay {
         }
     }
 
+    /// Creates a new [weight decay](WeightDecay) with the given penalty, or `None` when the
+    /// penalty is zero.
+    pub(crate) fn from_penalty(penalty: f32) -> Option<Self> {
+        (penalty != 0.0).then_some(Self { penalty })
+    }
+
     /// Transforms a gradient.
     ///
     /// # Arguments
@@ -0,0 +1,422 @@
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt::Debug;
+use hashbrown::HashMap;
+
+use crate::module::{ModuleVisitor, ParamId};
+use crate::tensor::{backend::Backend, Tensor};
+
+type ParamPredicate = Arc<dyn Fn(ParamId, &str) -> bool + Send + Sync>;
+
+/// The parameters of a [parameter group](ParamGroup).
+#[derive(Clone)]
+enum ParamSelector {
+    Paths(Vec<String>),
+    Ids(Vec<ParamId>),
+    Predicate(ParamPredicate),
+}
+
+/// A group of parameters optimized with their own hyperparameters.
+///
+/// The parameters are selected by their path in the module, made of the names of the fields and
+/// the indices in the collections separated by dots, such as `encoder.layers.0.norm.gamma`.
+///
+/// The groups are added to an optimizer with
+/// [with_param_groups](crate::optim::adaptor::OptimizerAdaptor::with_param_groups). A parameter
+/// belongs to the first group selecting it, and the parameters without group use the
+/// hyperparameters of the optimizer.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let optim = AdamWConfig::new()
+///     .init()
+///     .with_param_groups(vec![
+///         // No weight decay on the biases and the layer norm weights.
+///         ParamGroup::paths(&["*.bias", "*.norm.gamma"]).with_weight_decay(0.0),
+///         // 10x lower learning rate on the backbone.
+///         ParamGroup::paths(&["backbone.*"]).with_lr_multiplier(0.1),
+///     ])
+///     .expect("The optimizer should support weight decay");
+/// ```
+#[derive(Clone)]
+pub struct ParamGroup {
+    selector: ParamSelector,
+    lr_multiplier: f64,
+    weight_decay: Option<f32>,
+    frozen: bool,
+}
+
+impl ParamGroup {
+    /// Selects the parameters whose path matches any of the patterns, where `*` matches any
+    /// sequence of characters.
+    pub fn paths(patterns: &[&str]) -> Self {
+        Self::new(ParamSelector::Paths(
+            patterns.iter().map(ToString::to_string).collect(),
+        ))
+    }
+
+    /// Selects the parameters with the given ids.
+    pub fn ids(ids: Vec<ParamId>) -> Self {
+        Self::new(ParamSelector::Ids(ids))
+    }
+
+    /// Selects the parameters for which the predicate, called with the id and the path of each
+    /// parameter, returns true.
+    pub fn predicate<F>(predicate: F) -> Self
+    where
+        F: Fn(ParamId, &str) -> bool + Send + Sync + 'static,
+    {
+        Self::new(ParamSelector::Predicate(Arc::new(predicate)))
+    }
+
+    fn new(selector: ParamSelector) -> Self {
+        Self {
+            selector,
+            lr_multiplier: 1.0,
+            weight_decay: None,
+            frozen: false,
+        }
+    }
+
+    /// Multiplies the learning rate of the group, which follows the
+    /// [learning rate scheduler](crate::lr_scheduler::LrScheduler) of the optimizer. Default: 1.0
+    pub fn with_lr_multiplier(mut self, lr_multiplier: f64) -> Self {
+        self.lr_multiplier = lr_multiplier;
+        self
+    }
+
+    /// Overrides the weight decay penalty of the optimizer for the group, zero disabling the
+    /// weight decay. Default: the weight decay of the optimizer
+    ///
+    /// Adding the group to an optimizer without weight decay fails with
+    /// [ParamGroupError::WeightDecayNotSupported].
+    pub fn with_weight_decay(mut self, penalty: f32) -> Self {
+        self.weight_decay = Some(penalty);
+        self
+    }
+
+    /// If true, the parameters of the group are not updated, and their optimizer state is kept
+    /// as is. Default: false
+    pub fn with_frozen(mut self, frozen: bool) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// The learning rate multiplier of the group.
+    pub fn lr_multiplier(&self) -> f64 {
+        self.lr_multiplier
+    }
+
+    /// The weight decay penalty of the group, if it overrides the one of the optimizer.
+    pub fn weight_decay(&self) -> Option<f32> {
+        self.weight_decay
+    }
+
+    /// If the parameters of the group are frozen.
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// If the group selects the parameter.
+    pub fn contains(&self, id: ParamId, path: &str) -> bool {
+        match &self.selector {
+            ParamSelector::Paths(patterns) => patterns
+                .iter()
+                .any(|pattern| matches_pattern(pattern, path)),
+            ParamSelector::Ids(ids) => ids.contains(&id),
+            ParamSelector::Predicate(predicate) => predicate(id, path),
+        }
+    }
+}
+
+impl Debug for ParamGroup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct("ParamGroup");
+        match &self.selector {
+            ParamSelector::Paths(patterns) => debug.field("paths", patterns),
+            ParamSelector::Ids(ids) => debug.field("ids", ids),
+            ParamSelector::Predicate(_) => debug.field("predicate", &"Fn(ParamId, &str) -> bool"),
+        };
+        debug
+            .field("lr_multiplier", &self.lr_multiplier)
+            .field("weight_decay", &self.weight_decay)
+            .field("frozen", &self.frozen)
+            .finish()
+    }
+}
+
+/// Error that can occur when adding [parameter groups](ParamGroup) to an optimizer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamGroupError {
+    /// The group at the given index overrides the weight decay, but the optimizer has none.
+    WeightDecayNotSupported(usize),
+}
+
+impl core::fmt::Display for ParamGroupError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WeightDecayNotSupported(index) => write!(
+                f,
+                "The parameter group {index} overrides the weight decay of an optimizer without one"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParamGroupError {}
+
+/// Matches a path with a pattern where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // The pattern always has a first part, empty when it starts with `*`.
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts = parts.collect::<Vec<_>>();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` in the pattern.
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+/// Finds the group of each parameter of a module.
+pub(crate) struct ParamGroupVisitor<'a> {
+    groups: Vec<&'a ParamGroup>,
+    path: Vec<String>,
+    /// The index of the group of each parameter with a group.
+    pub(crate) indices: HashMap<ParamId, usize>,
+}
+
+impl<'a> ParamGroupVisitor<'a> {
+    pub(crate) fn new<I: IntoIterator<Item = &'a ParamGroup>>(groups: I) -> Self {
+        Self {
+            groups: groups.into_iter().collect(),
+            path: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamGroupVisitor<'_> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        let path = self.path.join(".");
+
+        if let Some(index) = self
+            .groups
+            .iter()
+            .position(|group| group.contains(id, &path))
+        {
+            self.indices.insert(id, index);
+        }
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::{Module, Param};
+    use crate::optim::{
+        adaptor::OptimizerAdaptor, GradientsParams, LBfgs, LBfgsConfig, Optimizer, SgdConfig,
+    };
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use alloc::vec;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        backbone: Vec<nn::Linear<B>>,
+        norm: nn::LayerNorm<B>,
+        head: nn::Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                backbone: (0..2)
+                    .map(|_| nn::LinearConfig::new(4, 4).init(device))
+                    .collect(),
+                norm: nn::LayerNormConfig::new(4).init(device),
+                head: nn::LinearConfig::new(4, 2).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.backbone[0].forward(input);
+            let x = self.backbone[1].forward(x);
+            self.head.forward(self.norm.forward(x))
+        }
+    }
+
+    fn group_paths(model: &Model<TestBackend>, groups: &[ParamGroup]) -> Vec<Option<usize>> {
+        let mut visitor = ParamGroupVisitor::new(groups);
+        model.visit(&mut visitor);
+        let mut ids = Vec::new();
+        model.visit(&mut IdCollector(&mut ids));
+        ids.iter()
+            .map(|id| visitor.indices.get(id).copied())
+            .collect()
+    }
+
+    struct IdCollector<'a>(&'a mut Vec<ParamId>);
+
+    impl<B: Backend> ModuleVisitor<B> for IdCollector<'_> {
+        fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+            self.0.push(id);
+        }
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        assert!(matches_pattern("head.weight", "head.weight"));
+        assert!(!matches_pattern("head.weight", "head.weights"));
+        assert!(matches_pattern("*.bias", "backbone.0.bias"));
+        assert!(!matches_pattern("*.bias", "bias"));
+        assert!(matches_pattern("backbone.*.weight", "backbone.1.weight"));
+        assert!(matches_pattern("*", "norm.gamma"));
+        assert!(matches_pattern("*norm*", "norm.gamma"));
+        assert!(!matches_pattern("backbone.*.weight", "backbone.1.bias"));
+    }
+
+    #[test]
+    fn test_params_are_selected_by_path() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let groups = [
+            ParamGroup::paths(&["*.bias", "norm.gamma"]),
+            ParamGroup::paths(&["backbone.*"]),
+        ];
+
+        // Parameters in visit order: backbone (weight, bias) x 2, norm (gamma, beta), head
+        // (weight, bias).
+        assert_eq!(
+            group_paths(&model, &groups),
+            vec![
+                Some(1),
+                Some(0),
+                Some(1),
+                Some(0),
+                Some(0),
+                None,
+                None,
+                Some(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_params_are_selected_by_id() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let head_weight = model.head.weight.id;
+        let groups = [
+            ParamGroup::ids(vec![head_weight]),
+            ParamGroup::predicate(|_, path| path.starts_with("norm")),
+        ];
+
+        assert_eq!(
+            group_paths(&model, &groups),
+            vec![None, None, None, None, Some(1), Some(1), Some(0), None]
+        );
+    }
+
+    #[test]
+    fn test_param_groups_lr_multiplier_and_frozen() {
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+        let before = model.clone();
+        let mut optim = SgdConfig::new()
+            .init()
+            .with_param_groups(vec![
+                ParamGroup::paths(&["backbone.*"]).with_frozen(true),
+                ParamGroup::paths(&["head.*"]).with_lr_multiplier(0.0),
+            ])
+            .unwrap();
+
+        let x = Tensor::random([3, 4], Distribution::Default, &device);
+        let grads = model.forward(x).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+        let model = optim.step(0.1, model, grads);
+
+        let data = |param: &Param<Tensor<TestAutodiffBackend, 2>>| param.val().into_data();
+        data(&model.backbone[0].weight).assert_eq(&data(&before.backbone[0].weight), true);
+        data(&model.head.weight).assert_eq(&data(&before.head.weight), true);
+        assert_ne!(
+            model.norm.beta.val().into_data(),
+            before.norm.beta.val().into_data()
+        );
+        // The frozen parameters have no optimizer state.
+        assert_eq!(optim.to_record().len(), 4);
+    }
+
+    #[test]
+    fn test_param_groups_weight_decay() {
+        let device = Default::default();
+        let linear = nn::LinearRecord {
+            weight: Param::from_data(TensorData::from([[1.0], [1.0]]), &device),
+            bias: Some(Param::from_data(TensorData::from([1.0]), &device)),
+        };
+        let linear = nn::LinearConfig::new(2, 1)
+            .init::<TestAutodiffBackend>(&device)
+            .load_record(linear);
+        let mut optim = SgdConfig::new()
+            .init()
+            .with_param_groups(vec![ParamGroup::paths(&["weight"]).with_weight_decay(0.5)])
+            .unwrap();
+
+        // The gradients are zero, so only the weight decay updates the parameters.
+        let x = Tensor::<TestAutodiffBackend, 2>::zeros([1, 2], &device);
+        let grads = linear.forward(x).mul_scalar(0.0).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optim.step(1.0, linear, grads);
+
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.5], [0.5]]), 5);
+        linear
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0]), 5);
+    }
+
+    #[test]
+    fn test_param_groups_weight_decay_not_supported() {
+        let optim: OptimizerAdaptor<LBfgs, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend> =
+            LBfgsConfig::new().init();
+
+        let result = optim.with_param_groups(vec![
+            ParamGroup::paths(&["bias"]).with_frozen(true),
+            ParamGroup::paths(&["weight"]).with_weight_decay(0.5),
+        ]);
+
+        assert_eq!(
+            result.err(),
+            Some(ParamGroupError::WeightDecayNotSupported(1))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use super::{adam::AdaptiveMomentum, AdaptiveMomentumState, SimpleOptimizer};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use burn_tensor::{backend::Backend, ops::Device};
+
+/// Lamb configuration.
+#[derive(Config)]
+pub struct LambConfig {
+    /// Parameter for Lamb.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for Lamb.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// Decoupled weight decay, added to the update before computing the trust ratio.
+    #[config(default = 0.01)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lamb optimizer as described in the paper [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes](https://arxiv.org/abs/1904.00962).
+///
+/// The Adam update of each parameter is scaled by the ratio between the norm of the parameter
+/// and the norm of its update, which keeps the training stable with large batch sizes.
+#[derive(Clone)]
+pub struct Lamb {
+    momentum: AdaptiveMomentum,
+    weight_decay: f32,
+}
+
+/// Lamb state.
+#[derive(Record, Clone, new)]
+pub struct LambState<B: Backend, const D: usize> {
+    /// The current adaptive momentum state.
+    pub momentum: AdaptiveMomentumState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lamb {
+    type State<const D: usize> = LambState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (mut update, momentum) = self.momentum.transform(grad, state.map(|s| s.momentum));
+
+        if self.weight_decay != 0.0 {
+            update = update.add(tensor.clone().mul_scalar(self.weight_decay));
+        }
+
+        // The trust ratio falls back to one when either norm is zero.
+        let param_norm = tensor.clone().powf_scalar(2.0).sum().sqrt();
+        let update_norm = update.clone().powf_scalar(2.0).sum().sqrt();
+        let valid = param_norm
+            .clone()
+            .greater_elem(0.0)
+            .bool_and(update_norm.clone().greater_elem(0.0));
+        let trust_ratio = param_norm
+            .ones_like()
+            .mask_where(valid, param_norm.div(update_norm));
+
+        let delta = update.mul(trust_ratio.unsqueeze()).mul_scalar(lr);
+
+        (tensor - delta, Some(LambState::new(momentum)))
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = penalty;
+        Some(self)
+    }
+}
+
+impl LambConfig {
+    /// Initialize Lamb optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> OptimizerAdaptor<Lamb, M, B> {
+        let optim = Lamb {
+            momentum: AdaptiveMomentum {
+                beta_1: self.beta_1,
+                beta_2: self.beta_2,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    fn lamb() -> Lamb {
+        Lamb {
+            momentum: AdaptiveMomentum {
+                beta_1: 0.9,
+                beta_2: 0.999,
+                epsilon: 1e-6,
+            },
+            weight_decay: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_lamb_step_is_scaled_by_trust_ratio() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+        let grad = Tensor::<TestBackend, 1>::from_floats([1.0, -1.0], &device);
+
+        let (tensor, state) = lamb().step(LEARNING_RATE, tensor, grad, None);
+
+        // The first Adam update is the sign of the gradient, and the trust ratio is sqrt(5 / 2).
+        let ratio = (5.0f32 / 2.0).sqrt();
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0 - 0.1 * ratio, 2.0 + 0.1 * ratio]), 4);
+        assert_eq!(state.unwrap().momentum.time, 1);
+    }
+
+    #[test]
+    fn test_lamb_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = LambConfig::new().init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<Lamb, nn::Linear<TestAutodiffBackend>, _> =
+            LambConfig::new().init().load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), 2);
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+}
@@ -0,0 +1,419 @@
+use alloc::vec::Vec;
+
+use super::{GradientsParams, Optimizer, SimpleOptimizer};
+use crate::config::Config;
+use crate::module::{ModuleVisitor, ParamId};
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use crate::{
+    self as burn,
+    grad_clipping::{GradientClipping, GradientClippingConfig},
+    module::AutodiffModule,
+    record::Record,
+    LearningRate,
+};
+use burn_tensor::{backend::Backend, ops::Device, ElementConversion};
+
+/// The minimum curvature `y · s` of a pair of step and gradient difference kept in the history.
+const CURVATURE_EPSILON: f64 = 1e-10;
+
+/// L-BFGS configuration.
+#[derive(Config)]
+pub struct LBfgsConfig {
+    /// The number of previous steps used to approximate the inverse Hessian.
+    #[config(default = 10)]
+    history_size: usize,
+    /// The fraction of the decrease predicted by the gradient that the line search requires
+    /// (Armijo condition).
+    #[config(default = 1e-4)]
+    sufficient_decrease: f64,
+    /// The factor reducing the step size each time the line search rejects it.
+    #[config(default = 0.5)]
+    backtracking_factor: f64,
+    /// The maximum number of loss evaluations of the line search.
+    #[config(default = 20)]
+    max_line_search_evals: usize,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// L-BFGS optimizer as described in the paper [On the limited memory BFGS method for large scale optimization](https://doi.org/10.1007/BF01589116).
+///
+/// The inverse Hessian of each parameter is approximated from the history of its last steps and
+/// gradient differences. The history is kept per parameter, which approximates the Hessian of
+/// the module as block diagonal.
+///
+/// The step should be performed with [step_closure](OptimizerAdaptor::step_closure), which
+/// searches the step size along the L-BFGS direction. The [step](Optimizer::step) of the
+/// optimizer uses the learning rate as the step size, without line search.
+#[derive(Clone)]
+pub struct LBfgs {
+    history_size: usize,
+    sufficient_decrease: f64,
+    backtracking_factor: f64,
+    max_line_search_evals: usize,
+}
+
+/// L-BFGS state.
+#[derive(Record, Clone, new)]
+pub struct LBfgsState<B: Backend, const D: usize> {
+    /// The previous steps, from the oldest to the newest.
+    pub steps: Vec<Tensor<B, D>>,
+    /// The differences between consecutive gradients, from the oldest to the newest.
+    pub grad_diffs: Vec<Tensor<B, D>>,
+    /// The last step, not yet in the history.
+    pub last_step: Tensor<B, D>,
+    /// The gradient of the last step.
+    pub last_grad: Tensor<B, D>,
+}
+
+impl LBfgs {
+    /// Computes the product of the approximated inverse Hessian and the gradient with the
+    /// two-loop recursion.
+    fn direction<B: Backend, const D: usize>(
+        &self,
+        grad: Tensor<B, D>,
+        steps: &[Tensor<B, D>],
+        grad_diffs: &[Tensor<B, D>],
+    ) -> Tensor<B, D> {
+        let rhos = steps
+            .iter()
+            .zip(grad_diffs)
+            .map(|(step, grad_diff)| 1.0 / dot(grad_diff, step))
+            .collect::<Vec<_>>();
+        let mut alphas = alloc::vec![0.0; steps.len()];
+        let mut direction = grad;
+
+        for i in (0..steps.len()).rev() {
+            alphas[i] = rhos[i] * dot(&steps[i], &direction);
+            direction = direction - grad_diffs[i].clone().mul_scalar(alphas[i]);
+        }
+
+        // The initial inverse Hessian is scaled with the curvature of the newest pair.
+        if let (Some(step), Some(grad_diff)) = (steps.last(), grad_diffs.last()) {
+            direction = direction.mul_scalar(dot(step, grad_diff) / dot(grad_diff, grad_diff));
+        }
+
+        for i in 0..steps.len() {
+            let beta = rhos[i] * dot(&grad_diffs[i], &direction);
+            direction = direction + steps[i].clone().mul_scalar(alphas[i] - beta);
+        }
+
+        direction
+    }
+}
+
+impl<B: Backend> SimpleOptimizer<B> for LBfgs {
+    type State<const D: usize> = LBfgsState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (steps, grad_diffs) = match state {
+            Some(state) => {
+                let mut steps = state.steps;
+                let mut grad_diffs = state.grad_diffs;
+                let grad_diff = grad.clone() - state.last_grad;
+
+                // Pairs with a non-positive curvature would break the positive definiteness of
+                // the approximation.
+                if dot(&grad_diff, &state.last_step) > CURVATURE_EPSILON {
+                    if steps.len() == self.history_size {
+                        steps.remove(0);
+                        grad_diffs.remove(0);
+                    }
+                    steps.push(state.last_step);
+                    grad_diffs.push(grad_diff);
+                }
+
+                (steps, grad_diffs)
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+
+        let step = self
+            .direction(grad.clone(), &steps, &grad_diffs)
+            .mul_scalar(-lr);
+        let state = LBfgsState::new(steps, grad_diffs, step.clone(), grad);
+
+        (tensor + step, Some(state))
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.steps = state
+            .steps
+            .into_iter()
+            .map(|step| step.to_device(device))
+            .collect();
+        state.grad_diffs = state
+            .grad_diffs
+            .into_iter()
+            .map(|grad_diff| grad_diff.to_device(device))
+            .collect();
+        state.last_step = state.last_step.to_device(device);
+        state.last_grad = state.last_grad.to_device(device);
+        state
+    }
+}
+
+impl LBfgsConfig {
+    /// Initialize L-BFGS optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> OptimizerAdaptor<LBfgs, M, B> {
+        assert!(self.history_size > 0, "The history size should be positive");
+        assert!(
+            self.max_line_search_evals > 0,
+            "The line search should evaluate the loss at least once"
+        );
+
+        let optim = LBfgs {
+            history_size: self.history_size,
+            sufficient_decrease: self.sufficient_decrease,
+            backtracking_factor: self.backtracking_factor,
+            max_line_search_evals: self.max_line_search_evals,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+impl<M, B> OptimizerAdaptor<LBfgs, M, B>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// Performs an optimization step with a backtracking line search.
+    ///
+    /// The closure computes the loss of a module, and is called once to compute the gradients
+    /// and once for each step size tried by the line search. The step size starts at the
+    /// learning rate and is reduced until the loss decreases sufficiently, the smallest step
+    /// size being used when none does.
+    ///
+    /// With [gradient clipping](GradientClippingConfig), the clipped gradients are used for both
+    /// the direction and the sufficient decrease of the line search.
+    ///
+    /// # Returns
+    ///
+    /// The updated module.
+    pub fn step_closure<F>(&mut self, lr: LearningRate, module: M, mut closure: F) -> M
+    where
+        F: FnMut(&M) -> Tensor<B, 1>,
+    {
+        let optim = self.optim().clone();
+
+        let loss = closure(&module);
+        let loss_value = loss.clone().into_scalar().elem::<f64>();
+        let grads = GradientsParams::from_grads(loss.backward(), &module);
+        // Clipping the gradients again in each step leaves them unchanged.
+        let grads = copy_grads(&grads, &module, self.grad_clipping());
+        let origin = GradientDot::compute(&grads, &module);
+
+        let mut step_size = lr;
+        let mut result = None;
+
+        for _ in 0..optim.max_line_search_evals {
+            let mut candidate = self.clone();
+            let updated =
+                candidate.step(step_size, module.clone(), copy_grads(&grads, &module, None));
+
+            // The decrease predicted by the gradient is its dot product with the step.
+            let decrease = GradientDot::compute(&grads, &updated) - origin;
+            let updated_loss = closure(&updated).into_scalar().elem::<f64>();
+            let accepted = updated_loss <= loss_value + optim.sufficient_decrease * decrease;
+
+            result = Some((candidate, updated));
+            if accepted {
+                break;
+            }
+            step_size *= optim.backtracking_factor;
+        }
+
+        let (candidate, updated) = result.expect("The line search evaluates the loss");
+        *self = candidate;
+        updated
+    }
+}
+
+fn dot<B: Backend, const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> f64 {
+    lhs.clone().mul(rhs.clone()).sum().into_scalar().elem()
+}
+
+/// Copies the gradients of the parameters of a module, clipped if the clipping is given.
+fn copy_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
+    grads: &GradientsParams,
+    module: &M,
+    clipping: Option<&GradientClipping>,
+) -> GradientsParams {
+    let mut copy = GradientsParams::new();
+    module.visit(&mut GradientsCopy {
+        grads,
+        copy: &mut copy,
+        clipping,
+    });
+    copy
+}
+
+struct GradientsCopy<'a> {
+    grads: &'a GradientsParams,
+    copy: &'a mut GradientsParams,
+    clipping: Option<&'a GradientClipping>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsCopy<'_> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let grad = match self.clipping {
+                Some(clipping) => clipping.clip_gradient(grad),
+                None => grad,
+            };
+            self.copy.register::<B::InnerBackend, D>(id, grad);
+        }
+    }
+}
+
+/// Computes the dot product of the gradients with the parameters of a module.
+struct GradientDot<'a> {
+    grads: &'a GradientsParams,
+    value: f64,
+}
+
+impl<'a> GradientDot<'a> {
+    fn compute<B: AutodiffBackend, M: AutodiffModule<B>>(
+        grads: &'a GradientsParams,
+        module: &M,
+    ) -> f64 {
+        let mut visitor = Self { grads, value: 0.0 };
+        module.visit(&mut visitor);
+        visitor.value
+    }
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientDot<'_> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            self.value += dot(&tensor.clone().inner(), &grad);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend};
+
+    fn least_squares() -> (
+        nn::Linear<TestAutodiffBackend>,
+        Tensor<TestAutodiffBackend, 2>,
+        Tensor<TestAutodiffBackend, 2>,
+    ) {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(TensorData::from([[0.0], [0.0], [0.0]]), &device),
+            bias: None,
+        };
+        let linear = nn::LinearConfig::new(3, 1)
+            .with_bias(false)
+            .init(&device)
+            .load_record(record);
+        let x = Tensor::from_floats(
+            [
+                [1.0, 0.0, 0.5],
+                [0.0, 1.0, 1.0],
+                [1.0, 1.0, 0.0],
+                [0.5, 0.0, 1.0],
+            ],
+            &device,
+        );
+        // The targets of the weights [1.0, -2.0, 0.5].
+        let y = Tensor::from_floats([[1.25], [-1.5], [-1.0], [1.0]], &device);
+
+        (linear, x, y)
+    }
+
+    #[test]
+    fn test_lbfgs_minimizes_least_squares() {
+        let (mut linear, x, y) = least_squares();
+        let mut optimizer = LBfgsConfig::new().init();
+        let loss = |linear: &nn::Linear<TestAutodiffBackend>| {
+            (linear.forward(x.clone()) - y.clone())
+                .powf_scalar(2.0)
+                .mean()
+        };
+
+        for _ in 0..10 {
+            linear = optimizer.step_closure(1.0, linear, loss);
+        }
+
+        let loss = loss(&linear).into_scalar();
+        assert!(loss < 1e-4, "The loss {loss} should be close to zero");
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0], [-2.0], [0.5]]), 2);
+    }
+
+    #[test]
+    fn test_lbfgs_line_search_uses_clipped_gradient() {
+        let (linear, x, y) = least_squares();
+        let mut optimizer = LBfgsConfig::new()
+            .with_sufficient_decrease(0.9)
+            .with_grad_clipping(Some(GradientClippingConfig::Norm(1.0)))
+            .init();
+        let mut num_evals = 0;
+        let loss = |linear: &nn::Linear<TestAutodiffBackend>| {
+            num_evals += 1;
+            (linear.forward(x.clone()) - y.clone())
+                .powf_scalar(2.0)
+                .mean()
+        };
+
+        let linear = optimizer.step_closure(1.0, linear, loss);
+
+        // The full step along the clipped gradient decreases the loss sufficiently.
+        assert_eq!(num_evals, 2);
+        let norm = linear
+            .weight
+            .val()
+            .powf_scalar(2.0)
+            .sum()
+            .sqrt()
+            .into_scalar();
+        assert!(
+            (norm - 1.0).abs() < 1e-4,
+            "The step norm {norm} should be 1"
+        );
+    }
+
+    #[test]
+    fn test_lbfgs_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = LBfgsConfig::new().init();
+        let linear = optimizer.step_closure(0.1, linear, |linear| linear.forward(x.clone()).mean());
+        let _linear =
+            optimizer.step_closure(0.1, linear, |linear| linear.forward(x.clone()).mean());
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<LBfgs, nn::Linear<TestAutodiffBackend>, _> =
+            LBfgsConfig::new().init().load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), 2);
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+}
@@ -0,0 +1,165 @@
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use burn_tensor::{backend::Backend, ops::Device};
+
+/// Lion configuration.
+#[derive(Config)]
+pub struct LionConfig {
+    /// Interpolation factor between the momentum and the gradient used to compute the update.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Decay factor of the momentum.
+    #[config(default = 0.99)]
+    beta_2: f32,
+    /// Decoupled weight decay.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lion optimizer as described in the paper [Symbolic Discovery of Optimization Algorithms](https://arxiv.org/abs/2302.06675).
+///
+/// The update is the sign of an interpolation between the momentum and the gradient, so every
+/// parameter moves by the learning rate. A learning rate 3-10x smaller than with AdamW is
+/// usually used.
+#[derive(Clone)]
+pub struct Lion {
+    beta_1: f32,
+    beta_2: f32,
+    weight_decay: f32,
+}
+
+/// Lion state.
+#[derive(Record, Clone, new)]
+pub struct LionState<B: Backend, const D: usize> {
+    /// The exponential moving average of the gradients.
+    pub momentum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lion {
+    type State<const D: usize> = LionState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let momentum = match state {
+            Some(state) => state.momentum,
+            None => grad.zeros_like(),
+        };
+
+        let update = momentum
+            .clone()
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1))
+            .sign();
+        let momentum = momentum
+            .mul_scalar(self.beta_2)
+            .add(grad.mul_scalar(1.0 - self.beta_2));
+
+        let mut tensor_updated = tensor.clone();
+        if self.weight_decay != 0.0 {
+            tensor_updated = tensor_updated - tensor.mul_scalar(lr).mul_scalar(self.weight_decay);
+        }
+
+        (
+            tensor_updated - update.mul_scalar(lr),
+            Some(LionState::new(momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = penalty;
+        Some(self)
+    }
+}
+
+impl LionConfig {
+    /// Initialize Lion optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> OptimizerAdaptor<Lion, M, B> {
+        let optim = Lion {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            weight_decay: self.weight_decay,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn test_lion_updates_with_sign_of_interpolation() {
+        let device = Default::default();
+        let optim = Lion {
+            beta_1: 0.9,
+            beta_2: 0.99,
+            weight_decay: 0.0,
+        };
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let grad = Tensor::from_floats([0.5, -2.0, 0.0], &device);
+        let (tensor, state) = optim.step(LEARNING_RATE, tensor, grad, None);
+        tensor
+            .clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.9, 2.1, 3.0]), 5);
+
+        // The momentum [0.005, -0.02, 0.0] is outweighed by the new gradient.
+        let grad = Tensor::from_floats([-1.0, 1.0, 1.0], &device);
+        let (tensor, _) = optim.step(LEARNING_RATE, tensor, grad, state);
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0, 2.9]), 5);
+    }
+
+    #[test]
+    fn test_lion_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = LionConfig::new().with_weight_decay(0.1).init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<Lion, nn::Linear<TestAutodiffBackend>, _> =
+            LionConfig::new().init().load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), 2);
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+}
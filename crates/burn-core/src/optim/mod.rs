@@ -4,23 +4,37 @@ pub mod decay;
 /// Momentum module for optimizers.
 pub mod momentum;
 
+mod adafactor;
 mod adagrad;
 mod adam;
 mod adamw;
 mod base;
 mod grad_accum;
 mod grads;
+mod groups;
+mod lamb;
+mod lbfgs;
+mod lion;
+mod nadam;
+mod radam;
 mod rmsprop;
 mod sgd;
 mod simple;
 mod visitor;
 
+pub use adafactor::*;
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use groups::*;
+pub use lamb::*;
+pub use lbfgs::*;
+pub use lion::*;
+pub use nadam::*;
+pub use radam::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
@@ -0,0 +1,214 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig},
+    AdaptiveMomentumState, SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::{backend::Backend, ops::Device};
+
+/// NAdam configuration.
+#[derive(Config)]
+pub struct NAdamConfig {
+    /// Parameter for NAdam.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for NAdam.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    epsilon: f32,
+    /// Decay of the momentum factor over time.
+    #[config(default = 0.004)]
+    momentum_decay: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// NAdam optimizer as described in the paper [Incorporating Nesterov Momentum into Adam](https://openreview.net/forum?id=OM0jvwB8jIp57ZJjtNEZ).
+///
+/// Adam with a Nesterov momentum, which uses the momentum of the next step to compute the
+/// update.
+#[derive(Clone)]
+pub struct NAdam {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+    momentum_decay: f32,
+    weight_decay: Option<WeightDecay>,
+}
+
+/// NAdam state.
+#[derive(Record, Clone, new)]
+pub struct NAdamState<B: Backend, const D: usize> {
+    /// The current adaptive momentum.
+    pub momentum: AdaptiveMomentumState<B, D>,
+    /// The product of the momentum factors of all the previous steps.
+    pub mu_product: f64,
+}
+
+impl NAdam {
+    /// The momentum factor of the given step.
+    fn mu(&self, time: usize) -> f64 {
+        let decay = 0.96f64.powf(time as f64 * self.momentum_decay as f64);
+        self.beta_1 as f64 * (1.0 - 0.5 * decay)
+    }
+}
+
+impl<B: Backend> SimpleOptimizer<B> for NAdam {
+    type State<const D: usize> = NAdamState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone());
+        }
+
+        let (momentum, mu_product) = match state {
+            Some(NAdamState {
+                mut momentum,
+                mu_product,
+            }) => {
+                momentum.moment_1 = momentum
+                    .moment_1
+                    .mul_scalar(self.beta_1)
+                    .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+                momentum.moment_2 = momentum
+                    .moment_2
+                    .mul_scalar(self.beta_2)
+                    .add(grad.clone().powf_scalar(2.0).mul_scalar(1.0 - self.beta_2));
+                momentum.time += 1;
+                (momentum, mu_product)
+            }
+            None => {
+                let moment_1 = grad.clone().mul_scalar(1.0 - self.beta_1);
+                let moment_2 = grad.clone().powf_scalar(2.0).mul_scalar(1.0 - self.beta_2);
+                (AdaptiveMomentumState::new(1, moment_1, moment_2), 1.0)
+            }
+        };
+
+        let time = momentum.time;
+        let mu = self.mu(time);
+        let mu_next = self.mu(time + 1);
+        let mu_product = mu_product * mu;
+        let mu_product_next = mu_product * mu_next;
+
+        let moment_2_corrected = momentum
+            .moment_2
+            .clone()
+            .div_scalar(1.0 - (self.beta_2 as f64).powi(time as i32));
+        let denominator = moment_2_corrected.sqrt().add_scalar(self.epsilon);
+
+        // Nesterov momentum: the momentum of the next step and the current gradient.
+        let update = momentum
+            .moment_1
+            .clone()
+            .mul_scalar(mu_next / (1.0 - mu_product_next))
+            .add(grad.mul_scalar((1.0 - mu) / (1.0 - mu_product)))
+            .div(denominator);
+
+        let state = NAdamState::new(momentum, mu_product);
+
+        (tensor - update.mul_scalar(lr), Some(state))
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = WeightDecay::from_penalty(penalty);
+        Some(self)
+    }
+}
+
+impl NAdamConfig {
+    /// Initialize NAdam optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> OptimizerAdaptor<NAdam, M, B> {
+        let optim = NAdam {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            momentum_decay: self.momentum_decay,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn test_nadam_first_step() {
+        let device = Default::default();
+        let optim = NAdam {
+            beta_1: 0.9,
+            beta_2: 0.999,
+            epsilon: 1e-8,
+            momentum_decay: 0.004,
+            weight_decay: None,
+        };
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+        let grad = Tensor::from_floats([1.0, -1.0], &device);
+
+        let (tensor, state) = optim.step(LEARNING_RATE, tensor, grad, None);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.894355, 2.105645]), 4);
+        let state = state.unwrap();
+        assert_eq!(state.momentum.time, 1);
+        assert!((state.mu_product - 0.450073).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_nadam_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = NAdamConfig::new()
+            .with_weight_decay(Some(WeightDecayConfig::new(0.1)))
+            .init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<NAdam, nn::Linear<TestAutodiffBackend>, _> =
+            NAdamConfig::new().init().load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), 2);
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+}
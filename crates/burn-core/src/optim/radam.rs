@@ -0,0 +1,229 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig},
+    AdaptiveMomentumState, SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::{backend::Backend, ops::Device};
+
+/// RAdam configuration.
+#[derive(Config)]
+pub struct RAdamConfig {
+    /// Parameter for RAdam.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for RAdam.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// RAdam optimizer as described in the paper [On the Variance of the Adaptive Learning Rate and Beyond](https://arxiv.org/abs/1908.03265).
+///
+/// The adaptive learning rate of Adam is only used once the variance of the second moment
+/// estimate is tractable, the first steps being momentum SGD steps. This acts as an automatic
+/// warmup.
+#[derive(Clone)]
+pub struct RAdam {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+    weight_decay: Option<WeightDecay>,
+}
+
+/// RAdam state.
+#[derive(Record, Clone, new)]
+pub struct RAdamState<B: Backend, const D: usize> {
+    /// The current adaptive momentum.
+    pub momentum: AdaptiveMomentumState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for RAdam {
+    type State<const D: usize> = RAdamState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone());
+        }
+
+        let momentum = match state {
+            Some(RAdamState { mut momentum }) => {
+                momentum.moment_1 = momentum
+                    .moment_1
+                    .mul_scalar(self.beta_1)
+                    .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+                momentum.moment_2 = momentum
+                    .moment_2
+                    .mul_scalar(self.beta_2)
+                    .add(grad.powf_scalar(2.0).mul_scalar(1.0 - self.beta_2));
+                momentum.time += 1;
+                momentum
+            }
+            None => {
+                let moment_1 = grad.clone().mul_scalar(1.0 - self.beta_1);
+                let moment_2 = grad.powf_scalar(2.0).mul_scalar(1.0 - self.beta_2);
+                AdaptiveMomentumState::new(1, moment_1, moment_2)
+            }
+        };
+
+        let time = momentum.time as i32;
+        let beta_1 = self.beta_1 as f64;
+        let beta_2 = self.beta_2 as f64;
+        let bias_correction_2 = 1.0 - beta_2.powi(time);
+
+        // Length of the approximated simple moving average.
+        let rho_inf = 2.0 / (1.0 - beta_2) - 1.0;
+        let rho = rho_inf - 2.0 * time as f64 * beta_2.powi(time) / bias_correction_2;
+
+        let mut update = momentum
+            .moment_1
+            .clone()
+            .div_scalar(1.0 - beta_1.powi(time));
+
+        if rho > 5.0 {
+            let rectification = ((rho - 4.0) * (rho - 2.0) * rho_inf
+                / ((rho_inf - 4.0) * (rho_inf - 2.0) * rho))
+                .sqrt();
+            let adaptive_lr = momentum
+                .moment_2
+                .clone()
+                .sqrt()
+                .add_scalar(self.epsilon)
+                .recip()
+                .mul_scalar(bias_correction_2.sqrt());
+            update = update.mul(adaptive_lr).mul_scalar(rectification);
+        }
+
+        (
+            tensor - update.mul_scalar(lr),
+            Some(RAdamState::new(momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = WeightDecay::from_penalty(penalty);
+        Some(self)
+    }
+}
+
+impl RAdamConfig {
+    /// Initialize RAdam optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> OptimizerAdaptor<RAdam, M, B> {
+        let optim = RAdam {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    fn radam() -> RAdam {
+        RAdam {
+            beta_1: 0.9,
+            beta_2: 0.999,
+            epsilon: 1e-8,
+            weight_decay: None,
+        }
+    }
+
+    #[test]
+    fn test_radam_warmup_uses_momentum_only() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+        let grad = Tensor::from_floats([0.5, -2.0], &device);
+
+        let (tensor, _) = radam().step(LEARNING_RATE, tensor, grad, None);
+
+        // The bias corrected momentum of the first step is the gradient.
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.95, 2.2]), 5);
+    }
+
+    #[test]
+    fn test_radam_rectified_steps() {
+        let device = Default::default();
+        let mut tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+        let mut state = None;
+
+        // With a constant gradient, the rectified update is the sign of the gradient times the
+        // rectification term, which reaches its tractable range at the sixth step.
+        for _ in 0..6 {
+            let grad = Tensor::from_floats([0.5, -2.0], &device);
+            (tensor, state) = radam().step(LEARNING_RATE, tensor, grad, state);
+        }
+
+        let expected = 1.0 - 5.0 * 0.1 * 0.5 - 0.1 * rectification(6);
+        let data = tensor.into_data().to_vec::<f32>().unwrap();
+        assert!((data[0] as f64 - expected).abs() < 1e-4);
+    }
+
+    fn rectification(time: i32) -> f64 {
+        let beta_2 = 0.999f64;
+        let rho_inf = 2.0 / (1.0 - beta_2) - 1.0;
+        let rho = rho_inf - 2.0 * time as f64 * beta_2.powi(time) / (1.0 - beta_2.powi(time));
+        ((rho - 4.0) * (rho - 2.0) * rho_inf / ((rho_inf - 4.0) * (rho_inf - 2.0) * rho)).sqrt()
+    }
+
+    #[test]
+    fn test_radam_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = RAdamConfig::new().init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<RAdam, nn::Linear<TestAutodiffBackend>, _> =
+            RAdamConfig::new().init().load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), 2);
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+}
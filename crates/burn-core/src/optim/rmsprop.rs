@@ -131,6 +131,11 @@ impl<B: Backend> SimpleOptimizer<B> for RmsProp {
         state.momentum = state.momentum.map(|momentum| momentum.to_device(device));
         state
     }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = WeightDecay::from_penalty(penalty);
+        Some(self)
+    }
 }
 
 /// State of [RmsProp](RmsProp)
@@ -93,6 +93,11 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         state.momentum = state.momentum.map(|state| state.to_device(device));
         state
     }
+
+    fn with_weight_decay(mut self, penalty: f32) -> Option<Self> {
+        self.weight_decay = WeightDecay::from_penalty(penalty);
+        Some(self)
+    }
 }
 
 #[cfg(test)]
@@ -2,9 +2,10 @@ use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
     module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    optim::{groups::ParamGroupVisitor, GradientsParams, Optimizer, ParamGroup, ParamGroupError},
     LearningRate,
 };
+use alloc::vec::Vec;
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -22,6 +23,7 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    groups: Vec<(ParamGroup, O)>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +38,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            groups: Vec::new(),
         }
     }
 }
@@ -60,6 +63,46 @@ where
         self
     }
 
+    /// Sets the parameter groups, optimized with their own hyperparameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `groups` - The [parameter groups](ParamGroup), a parameter belonging to the first group
+    ///   selecting it.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer, or an error if a group overrides the weight decay of an optimizer without
+    /// weight decay.
+    pub fn with_param_groups(mut self, groups: Vec<ParamGroup>) -> Result<Self, ParamGroupError> {
+        self.groups = groups
+            .into_iter()
+            .enumerate()
+            .map(|(index, group)| {
+                let optim = match group.weight_decay() {
+                    Some(penalty) => <O as SimpleOptimizer<B::InnerBackend>>::with_weight_decay(
+                        self.optim.clone(),
+                        penalty,
+                    )
+                    .ok_or(ParamGroupError::WeightDecayNotSupported(index))?,
+                    None => self.optim.clone(),
+                };
+                Ok((group, optim))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(self)
+    }
+
+    /// The adapted [simple optimizer](SimpleOptimizer).
+    pub(crate) fn optim(&self) -> &O {
+        &self.optim
+    }
+
+    /// The clipping applied to the gradients before each step.
+    pub(crate) fn grad_clipping(&self) -> Option<&GradientClipping> {
+        self.grad_clipping.as_ref()
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -75,8 +118,16 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        // The groups are resolved at each step, since they can depend on the module structure.
+        let mut groups = ParamGroupVisitor::new(self.groups.iter().map(|(group, _)| group));
+        if !self.groups.is_empty() {
+            module.visit(&mut groups);
+        }
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
+            &self.groups,
+            groups.indices,
             &mut self.records,
             &mut grads,
             lr,
@@ -103,6 +154,8 @@ where
     O: SimpleOptimizer<B::InnerBackend>,
 {
     optimizer: &'a O,
+    groups: &'a [(ParamGroup, O)],
+    param_groups: HashMap<ParamId, usize>,
     records: &'a mut HashMap<ParamId, AdaptorRecord<O, B>>,
     grads: &'a mut GradientsParams,
     lr: LearningRate,
@@ -120,6 +173,17 @@ where
         let grad = self.grads.remove(id);
 
         if let Some(grad) = grad {
+            let (optimizer, lr) = match self.param_groups.get(&id) {
+                Some(index) => {
+                    let (group, optimizer) = &self.groups[*index];
+                    if group.is_frozen() {
+                        return tensor;
+                    }
+                    (optimizer, self.lr * group.lr_multiplier())
+                }
+                None => (self.optimizer, self.lr),
+            };
+
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(&id).unzip();
@@ -130,8 +194,8 @@ where
                 grad
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
+            let (tensor, state) = optimizer.step(
+                lr,
                 tensor.inner(),
                 clipped_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
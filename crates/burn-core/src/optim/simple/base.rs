@@ -30,4 +30,13 @@ where
     /// This function will be called accordindly to have the state on the same device as the
     /// gradient and the tensor when the [step](SimpleOptimizer::step) function is called.
     fn to_device<const D: usize>(state: Self::State<D>, device: &B::Device) -> Self::State<D>;
+
+    /// Returns the optimizer with the given weight decay penalty, zero disabling the weight decay,
+    /// or `None` if the optimizer has no weight decay.
+    ///
+    /// This is used by the [parameter groups](crate::optim::ParamGroup) overriding the weight
+    /// decay of their parameters. The default implementation returns `None`.
+    fn with_weight_decay(self, _penalty: f32) -> Option<Self> {
+        None
+    }
 }
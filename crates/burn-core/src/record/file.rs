@@ -6,6 +6,8 @@ use serde::{de::DeserializeOwned, Serialize};
 use std::io::{BufReader, BufWriter};
 use std::{fs::File, path::PathBuf};
 
+use super::mmap::MappedFile;
+
 /// Recorder trait specialized to save and load data to and from files.
 pub trait FileRecorder<B: Backend>:
     Recorder<B, RecordArgs = PathBuf, RecordOutput = (), LoadArgs = PathBuf>
@@ -53,6 +55,27 @@ pub struct NamedMpkFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
 }
 
+/// File recorder using the [named msgpack](rmp_serde) format, which memory-maps the file when
+/// loading a record.
+///
+/// The files are the same as the ones of the [NamedMpkFileRecorder], but the tensors of the
+/// loaded parameters are only read from the file when the parameters are first used, directly on
+/// the device of the module loading the record. This avoids having both the whole file and the
+/// module tensors in memory when loading large models.
+///
+/// # Notes
+///
+/// The file stays memory-mapped while the loaded record or module holds parameters that are not
+/// initialized yet, since they are copied from the mapping when first used. The file must not be
+/// modified or truncated in the meantime.
+///
+/// Only the records of this recorder are loaded lazily: the PyTorch and Safetensors readers of
+/// `burn-import` still read the whole file in memory.
+#[derive(new, Debug, Default, Clone)]
+pub struct NamedMpkMmapFileRecorder<S: PrecisionSettings> {
+    _settings: PhantomData<S>,
+}
+
 impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for BinGzFileRecorder<S> {
     fn file_extension() -> &'static str {
         "bin.gz"
@@ -86,6 +109,12 @@ impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkFileRecorder<
     }
 }
 
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkMmapFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "mpk"
+    }
+}
+
 macro_rules! str2reader {
     (
         $file:expr
@@ -297,6 +326,39 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
     }
 }
 
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkMmapFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let mut writer = str2writer!(file)?;
+
+        rmp_serde::encode::write_named(&mut writer, &item)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let reader = str2reader!(file)?;
+        let mapped = MappedFile::map(file, reader.get_ref())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        // The tensors are deserialized as their location in the file, and only read when needed.
+        let mut deserializer = rmp_serde::Deserializer::from_read_ref(mapped.bytes());
+        let state = I::deserialize(mapped.deserializer(&mut deserializer))
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -352,6 +414,55 @@ mod tests {
         test_can_save_and_load(NamedMpkFileRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[test]
+    fn test_can_save_and_load_mpk_mmap_format() {
+        let file_path = std::env::temp_dir().join("burn_test_file_recorder_mmap");
+        let device = Default::default();
+        let model_before = create_model(&device);
+        let recorder = NamedMpkMmapFileRecorder::<FullPrecisionSettings>::default();
+        recorder
+            .record(model_before.clone().into_record(), file_path.clone())
+            .unwrap();
+
+        let record: ModelRecord<TestBackend> = recorder.load(file_path, &device).unwrap();
+        // The parameters aren't initialized until they are used.
+        assert!(record.linear1.weight.set_lazy_device(Default::default()));
+        let model_after = create_model(&device).load_record(record);
+
+        let byte_recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let model_bytes_before = byte_recorder
+            .record(model_before.into_record(), ())
+            .unwrap();
+        let model_bytes_after = byte_recorder.record(model_after.into_record(), ()).unwrap();
+
+        assert_eq!(model_bytes_after, model_bytes_before);
+    }
+
+    // The mapping outlives the file on Unix, while a mapped file can't be removed on Windows.
+    #[cfg(unix)]
+    #[test]
+    fn test_mpk_mmap_parameters_should_be_read_from_the_mapping() {
+        let file_path = std::env::temp_dir().join("burn_test_file_recorder_mmap_removed");
+        let device = Default::default();
+        let model_before = create_model(&device);
+        let recorder = NamedMpkMmapFileRecorder::<FullPrecisionSettings>::default();
+        recorder
+            .record(model_before.clone().into_record(), file_path.clone())
+            .unwrap();
+
+        let record: ModelRecord<TestBackend> = recorder.load(file_path.clone(), &device).unwrap();
+        std::fs::remove_file(file_path.with_extension("mpk")).unwrap();
+        let model_after = create_model(&device).load_record(record);
+
+        let byte_recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let model_bytes_before = byte_recorder
+            .record(model_before.into_record(), ())
+            .unwrap();
+        let model_bytes_after = byte_recorder.record(model_after.into_record(), ()).unwrap();
+
+        assert_eq!(model_bytes_after, model_bytes_before);
+    }
+
     fn test_can_save_and_load<Recorder>(recorder: Recorder)
     where
         Recorder: FileRecorder<TestBackend>,
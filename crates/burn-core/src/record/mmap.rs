@@ -0,0 +1,467 @@
+use core::fmt;
+use core::ops::Range;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use burn_tensor::{DType, TensorData};
+use memmap2::Mmap;
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::Deserialize;
+
+use super::tensor::MAPPED_BYTES;
+
+/// The memory-mapped files being deserialized, with their id.
+static MAPPED_FILES: Mutex<Vec<(usize, Arc<Mmap>)>> = Mutex::new(Vec::new());
+
+/// The id of the next memory-mapped file.
+static NEXT_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A memory-mapped record file.
+///
+/// The file is registered while it is deserialized, so that the [lazy tensor data](MappedTensorData)
+/// can share its mapping.
+pub(crate) struct MappedFile {
+    id: usize,
+    mmap: Arc<Mmap>,
+}
+
+impl MappedFile {
+    /// Memory-map the file found at the given path.
+    pub(crate) fn map(path: PathBuf, file: &File) -> io::Result<Self> {
+        // SAFETY: The file must not be modified while it is mapped, which lasts as long as the
+        // tensors of the record haven't been read, as documented by the recorder.
+        let mmap = unsafe { Mmap::map(file) }.map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("Should memory-map the file {}: {err}", path.display()),
+            )
+        })?;
+        let mmap = Arc::new(mmap);
+
+        let id = NEXT_FILE_ID.fetch_add(1, Ordering::Relaxed);
+        MAPPED_FILES.lock().unwrap().push((id, mmap.clone()));
+
+        Ok(Self { id, mmap })
+    }
+
+    /// Returns the mapping of the registered file with the given id.
+    fn get(id: usize) -> Option<Arc<Mmap>> {
+        let files = MAPPED_FILES.lock().unwrap();
+        files
+            .iter()
+            .find(|(file, _)| *file == id)
+            .map(|(_, mmap)| mmap.clone())
+    }
+
+    /// The bytes of the file.
+    pub(crate) fn bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// Wrap the deserializer of the [file bytes](Self::bytes), so that the tensors are read lazily.
+    pub(crate) fn deserializer<'de, D>(&self, deserializer: D) -> MappedDeserializer<'_, D>
+    where
+        D: Deserializer<'de>,
+    {
+        MappedDeserializer {
+            inner: deserializer,
+            file: self,
+        }
+    }
+
+    /// Returns the range of the bytes in the file, or `None` if they aren't borrowed from it.
+    fn range_of(&self, bytes: &[u8]) -> Option<Range<usize>> {
+        let file = self.mmap.as_ptr_range();
+        let len = bytes.len();
+        let bytes = bytes.as_ptr_range();
+
+        if bytes.start < file.start || bytes.end > file.end {
+            return None;
+        }
+
+        let start = bytes.start as usize - file.start as usize;
+        Some(start..start + len)
+    }
+}
+
+impl Drop for MappedFile {
+    fn drop(&mut self) {
+        MAPPED_FILES
+            .lock()
+            .unwrap()
+            .retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// The location of tensor bytes in a memory-mapped file, identified by its id.
+#[derive(Deserialize)]
+pub(crate) struct MappedBytes {
+    file: usize,
+    start: usize,
+    end: usize,
+}
+
+/// Tensor data that is only read from a memory-mapped file when needed.
+///
+/// The mapping of the file is shared by all the tensor data of a record, and stays valid until
+/// they are all dropped.
+#[derive(Clone, Debug)]
+pub(crate) struct MappedTensorData {
+    mmap: Arc<Mmap>,
+    range: Range<usize>,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+impl MappedTensorData {
+    /// Create the tensor data from the location of its bytes, or `None` if the file isn't being
+    /// deserialized.
+    pub(crate) fn new(bytes: MappedBytes, shape: Vec<usize>, dtype: DType) -> Option<Self> {
+        Some(Self {
+            mmap: MappedFile::get(bytes.file)?,
+            range: bytes.start..bytes.end,
+            shape,
+            dtype,
+        })
+    }
+
+    /// Read the tensor data from the memory-mapped file.
+    pub(crate) fn read(&self) -> TensorData {
+        let bytes = self.mmap[self.range.clone()].to_vec();
+
+        TensorData::from_bytes(bytes, self.shape.clone(), self.dtype)
+    }
+}
+
+/// Deserializer of a [memory-mapped file](MappedFile), which deserializes the location of the
+/// tensor bytes in the file instead of the bytes themselves.
+pub(crate) struct MappedDeserializer<'a, D> {
+    inner: D,
+    file: &'a MappedFile,
+}
+
+/// Wraps the visitors, seeds and accesses of the inner deserializer, so that the nested values are
+/// also deserialized with a [MappedDeserializer].
+struct Mapped<'a, T> {
+    inner: T,
+    file: &'a MappedFile,
+}
+
+impl<'a, T> Mapped<'a, T> {
+    fn new(inner: T, file: &'a MappedFile) -> Self {
+        Self { inner, file }
+    }
+}
+
+/// The location of the tensor bytes, deserialized as a sequence of `(file, start, end)`.
+struct MappedBytesAccess<E> {
+    file: usize,
+    range: Range<usize>,
+    index: usize,
+    _error: core::marker::PhantomData<E>,
+}
+
+impl<'de, E: de::Error> SeqAccess<'de> for MappedBytesAccess<E> {
+    type Error = E;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, E>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let value = match self.index {
+            0 => seed.deserialize(self.file.into_deserializer()),
+            1 => seed.deserialize(self.range.start.into_deserializer()),
+            2 => seed.deserialize(self.range.end.into_deserializer()),
+            _ => return Ok(None),
+        };
+        self.index += 1;
+
+        value.map(Some)
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner.$method($($arg,)* Mapped::new(visitor, self.file))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for MappedDeserializer<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    );
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if name != MAPPED_BYTES {
+            return self
+                .inner
+                .deserialize_newtype_struct(name, Mapped::new(visitor, self.file));
+        }
+
+        let bytes = <&'de [u8]>::deserialize(self.inner)?;
+        let range = self.file.range_of(bytes).ok_or_else(|| {
+            <D::Error as de::Error>::custom(
+                "The tensor bytes should be borrowed from the memory-mapped file",
+            )
+        })?;
+
+        visitor.visit_seq(MappedBytesAccess {
+            file: self.file.id,
+            range,
+            index: 0,
+            _error: core::marker::PhantomData,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Mapped<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit!(
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    );
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(self.file.deserializer(deserializer))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(self.file.deserializer(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_seq(Mapped::new(seq, self.file))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(Mapped::new(map, self.file))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(Mapped::new(data, self.file))
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Mapped<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.deserialize(self.file.deserializer(deserializer))
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for Mapped<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.inner.next_element_seed(Mapped::new(seed, self.file))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Mapped<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.inner.next_key_seed(Mapped::new(seed, self.file))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.inner.next_value_seed(Mapped::new(seed, self.file))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, 'de, A: EnumAccess<'de>> EnumAccess<'de> for Mapped<'a, A> {
+    type Error = A::Error;
+    type Variant = Mapped<'a, A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (value, variant) = self.inner.variant_seed(Mapped::new(seed, self.file))?;
+
+        Ok((value, Mapped::new(variant, self.file)))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for Mapped<'_, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<S>(self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.inner
+            .newtype_variant_seed(Mapped::new(seed, self.file))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .tuple_variant(len, Mapped::new(visitor, self.file))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .struct_variant(fields, Mapped::new(visitor, self.file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn mapped_file(name: &str, bytes: &[u8]) -> MappedFile {
+        let path = std::env::temp_dir().join(name);
+        File::create(&path).unwrap().write_all(bytes).unwrap();
+
+        MappedFile::map(path.clone(), &File::open(&path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn should_locate_the_bytes_borrowed_from_the_file() {
+        let file = mapped_file("burn_test_mmap_range", &[1, 2, 3, 4, 5]);
+
+        assert_eq!(file.range_of(&file.bytes()[1..4]), Some(1..4));
+        assert_eq!(file.range_of(&file.bytes()[5..]), Some(5..5));
+    }
+
+    #[test]
+    fn should_reject_the_bytes_outside_of_the_file() {
+        let file = mapped_file("burn_test_mmap_outside", &[1, 2, 3, 4, 5]);
+        let other = vec![1, 2, 3];
+
+        assert_eq!(file.range_of(&other), None);
+    }
+}
@@ -16,6 +16,9 @@ mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
+#[cfg(feature = "std")]
+mod mmap;
+
 pub use primitive::ParamSerde;
 
 #[cfg(feature = "record-item-custom-serde")]
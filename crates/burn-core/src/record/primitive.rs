@@ -1,9 +1,9 @@
 use alloc::{string::String, vec, vec::Vec};
 use core::{fmt, marker::PhantomData};
 
-use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
+use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde, RecordData};
 use super::{PrecisionSettings, Record};
-use crate::module::{Param, ParamId};
+use crate::module::{Param, ParamId, Parameter};
 
 use burn_tensor::{backend::Backend, Bool, Int, Tensor};
 
@@ -160,10 +160,15 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
+        param_from_record_data(
             ParamId::deserialize(&item.id),
-            Tensor::from_item(item.param, device).require_grad(), // Same behavior as when we create a new
-                                                                  // Param from a tensor.
+            item.param.into_record_data(),
+            device,
+            true, // Same behavior as when we create a new Param from a tensor.
+            |data, device, require_grad| {
+                Tensor::from_data(data.read::<B::FloatElem>(), device)
+                    .set_require_grad(require_grad)
+            },
         )
     }
 }
@@ -180,9 +185,12 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
+        param_from_record_data(
             ParamId::deserialize(&item.id),
-            Tensor::from_item(item.param, device),
+            item.param.into_record_data(),
+            device,
+            false,
+            |data, device, _| Tensor::from_data(data.read::<B::IntElem>(), device),
         )
     }
 }
@@ -199,13 +207,41 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
+        param_from_record_data(
             ParamId::deserialize(&item.id),
-            Tensor::from_item::<S>(item.param, device),
+            item.param.into_record_data(),
+            device,
+            false,
+            |data, device, _| Tensor::from_data(data.read::<bool>(), device),
         )
     }
 }
 
+/// Create a parameter from the record data, which is only initialized when the parameter is used
+/// if the data is read lazily (e.g., from a memory-mapped file).
+fn param_from_record_data<T, F>(
+    id: ParamId,
+    data: RecordData,
+    device: &T::Device,
+    is_require_grad: bool,
+    init: F,
+) -> Param<T>
+where
+    T: Parameter,
+    F: FnOnce(RecordData, &T::Device, bool) -> T + Send + 'static,
+{
+    if data.is_lazy() {
+        return Param::uninitialized(
+            id,
+            move |device, require_grad| init(data, device, require_grad),
+            device.clone(),
+            is_require_grad,
+        );
+    }
+
+    Param::initialized(id, init(data, device, is_require_grad))
+}
+
 // Type that can be serialized as is without any conversion.
 macro_rules! primitive {
     ($type:ty) => {
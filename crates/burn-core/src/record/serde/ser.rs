@@ -137,11 +137,12 @@ impl SerializerTrait for Serializer {
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        Ok(NestedValue::Default(None))
     }
 
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        // Unit structs (e.g., `PhantomData`) don't hold any value
+        Ok(NestedValue::Default(None))
     }
 
     fn serialize_unit_variant(
@@ -2,54 +2,225 @@ use core::marker::PhantomData;
 
 use super::{PrecisionSettings, Record};
 use burn_tensor::{backend::Backend, Bool, DType, Element, Int, Tensor, TensorData};
+use serde::de::Visitor;
 use serde::{Deserialize, Serialize};
 
 use alloc::format;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use super::mmap::{MappedBytes, MappedTensorData};
+#[cfg(feature = "std")]
+use serde::de::{value::SeqAccessDeserializer, SeqAccess};
+
+/// The name of the newtype struct under which the tensor bytes are deserialized, so that the
+/// deserializer of memory-mapped files can replace them with their location in the file.
+pub(crate) const MAPPED_BYTES: &str = "$burn::record::MappedBytes";
+
+/// Same layout as the serialized [`TensorData`], but the bytes can be read lazily.
+#[derive(Deserialize)]
+struct RecordTensorData {
+    bytes: RecordBytes,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+/// The bytes of a tensor, either loaded in memory or located in a memory-mapped file.
+enum RecordBytes {
+    Loaded(Vec<u8>),
+    #[cfg(feature = "std")]
+    Mapped(MappedBytes),
+}
+
+impl<'de> Deserialize<'de> for RecordBytes {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        struct RecordBytesVisitor;
+
+        impl<'de> Visitor<'de> for RecordBytesVisitor {
+            type Value = RecordBytes;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("tensor bytes")
+            }
+
+            fn visit_newtype_struct<De>(self, deserializer: De) -> Result<Self::Value, De::Error>
+            where
+                De: serde::Deserializer<'de>,
+            {
+                serde_bytes::deserialize(deserializer).map(RecordBytes::Loaded)
+            }
+
+            // Only the deserializer of memory-mapped files provides the location of the bytes.
+            #[cfg(feature = "std")]
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                MappedBytes::deserialize(SeqAccessDeserializer::new(seq)).map(RecordBytes::Mapped)
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(MAPPED_BYTES, RecordBytesVisitor)
+    }
+}
 
 /// Deserialize the value into [`TensorData`].
-fn deserialize_data<'de, E, De>(deserializer: De) -> Result<TensorData, De::Error>
+fn deserialize_data<'de, E, De>(deserializer: De) -> Result<RecordData, De::Error>
 where
     E: Element + Deserialize<'de>,
     De: serde::Deserializer<'de>,
 {
-    let data = TensorData::deserialize(deserializer).map_err(|e| {
+    let data = RecordTensorData::deserialize(deserializer).map_err(|e| {
         serde::de::Error::custom(format!(
             "{:?}\nThe internal data format has changed since version 0.14.0. If you are trying to load a record saved in a previous version, use the `record-backward-compat` feature flag with a previous version (<=0.16.0). Once you have saved the record in the new format, you can upgrade back to the current version.\n",
             e
         ))
     })?;
-    let data = if let DType::QFloat(_) = data.dtype {
+
+    match data.bytes {
+        RecordBytes::Loaded(bytes) => Ok(RecordData::Loaded(convert_data::<E>(
+            TensorData::from_bytes(bytes, data.shape, data.dtype),
+        ))),
+        // The tensors of memory-mapped files are only read when needed.
+        #[cfg(feature = "std")]
+        RecordBytes::Mapped(bytes) => MappedTensorData::new(bytes, data.shape, data.dtype)
+            .map(RecordData::Mapped)
+            .ok_or_else(|| {
+                serde::de::Error::custom("The memory-mapped file should be deserialized")
+            }),
+    }
+}
+
+fn convert_data<E: Element>(data: TensorData) -> TensorData {
+    if let DType::QFloat(_) = data.dtype {
         data // do not convert quantized tensors
     } else {
         data.convert::<E>()
-    };
-    Ok(data)
+    }
+}
+
+/// The tensor data of a record, which is either loaded in memory or read lazily.
+#[derive(Clone, Debug)]
+pub(crate) enum RecordData {
+    /// The tensor data is loaded in memory.
+    Loaded(TensorData),
+    /// The tensor data is read from a memory-mapped file when needed.
+    #[cfg(feature = "std")]
+    Mapped(MappedTensorData),
+}
+
+impl RecordData {
+    /// Whether the tensor data is read lazily.
+    pub(crate) fn is_lazy(&self) -> bool {
+        match self {
+            RecordData::Loaded(_) => false,
+            #[cfg(feature = "std")]
+            RecordData::Mapped(_) => true,
+        }
+    }
+
+    /// Returns the tensor data converted to the given element type, reading it if necessary.
+    pub(crate) fn read<E: Element>(self) -> TensorData {
+        match self {
+            RecordData::Loaded(data) => convert_data::<E>(data),
+            #[cfg(feature = "std")]
+            RecordData::Mapped(data) => convert_data::<E>(data.read()),
+        }
+    }
 }
 
 /// This struct implements serde to lazily serialize and deserialize a float tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct FloatTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: RecordData,
     _e: PhantomData<S::FloatElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an int tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct IntTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: RecordData,
     _e: PhantomData<S::IntElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an bool tensor.
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct BoolTensorSerde {
-    data: TensorData,
+    data: RecordData,
+}
+
+impl<S: PrecisionSettings> FloatTensorSerde<S> {
+    /// Create a new float tensor serde from the tensor data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(RecordData::Loaded(data))
+    }
+
+    fn from_record_data(data: RecordData) -> Self {
+        Self {
+            data,
+            _e: PhantomData,
+        }
+    }
+
+    /// Returns the record data of the tensor.
+    pub(crate) fn into_record_data(self) -> RecordData {
+        self.data
+    }
+}
+
+impl<S: PrecisionSettings> IntTensorSerde<S> {
+    /// Create a new int tensor serde from the tensor data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(RecordData::Loaded(data))
+    }
+
+    fn from_record_data(data: RecordData) -> Self {
+        Self {
+            data,
+            _e: PhantomData,
+        }
+    }
+
+    /// Returns the record data of the tensor.
+    pub(crate) fn into_record_data(self) -> RecordData {
+        self.data
+    }
+}
+
+impl BoolTensorSerde {
+    /// Create a new bool tensor serde from the tensor data.
+    pub fn new(data: TensorData) -> Self {
+        Self {
+            data: RecordData::Loaded(data),
+        }
+    }
+
+    /// Returns the record data of the tensor.
+    pub(crate) fn into_record_data(self) -> RecordData {
+        self.data
+    }
 }
 
 // --- SERDE IMPLEMENTATIONS --- //
 
+impl Serialize for RecordData {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        match self {
+            RecordData::Loaded(data) => data.serialize(serializer),
+            #[cfg(feature = "std")]
+            RecordData::Mapped(data) => data.read().serialize(serializer),
+        }
+    }
+}
+
 impl<S: PrecisionSettings> Serialize for FloatTensorSerde<S> {
     fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
     where
@@ -66,7 +237,7 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatTensorSerde<S> {
     {
         let data = deserialize_data::<S::FloatElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
@@ -86,7 +257,7 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for IntTensorSerde<S> {
     {
         let data = deserialize_data::<S::IntElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
@@ -106,7 +277,7 @@ impl<'de> Deserialize<'de> for BoolTensorSerde {
     {
         let data = deserialize_data::<bool, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self { data })
     }
 }
 
@@ -116,22 +287,11 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
     type Item<S: PrecisionSettings> = FloatTensorSerde<S>;
 
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
-        let data = self.into_data();
-        let data = if let DType::QFloat(_) = data.dtype {
-            data // do not convert quantized tensors
-        } else {
-            data.convert::<S::FloatElem>()
-        };
-        FloatTensorSerde::new(data)
+        FloatTensorSerde::new(convert_data::<S::FloatElem>(self.into_data()))
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        let data = if let DType::QFloat(_) = item.data.dtype {
-            item.data // do not convert quantized tensors
-        } else {
-            item.data.convert::<B::FloatElem>()
-        };
-        Tensor::from_data(data, device)
+        Tensor::from_data(item.data.read::<B::FloatElem>(), device)
     }
 }
 
@@ -143,7 +303,7 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Int> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert::<B::IntElem>(), device)
+        Tensor::from_data(item.data.read::<B::IntElem>(), device)
     }
 }
 
@@ -155,6 +315,6 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data, device)
+        Tensor::from_data(item.data.read::<bool>(), device)
     }
 }
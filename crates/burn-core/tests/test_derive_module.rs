@@ -287,3 +287,89 @@ mod require_grad {
         y.backward()
     }
 }
+
+mod paths {
+    use super::*;
+    use burn::module::{ModuleMapper, ModuleVisitor, ParamId};
+
+    /// Records the path of each float tensor of a module.
+    #[derive(Default)]
+    struct PathRecorder {
+        path: Vec<String>,
+        paths: Vec<String>,
+    }
+
+    impl<B: Backend> ModuleVisitor<B> for PathRecorder {
+        fn visit_float<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D>) {
+            self.paths.push(self.path.join("."));
+        }
+
+        fn enter_module(&mut self, name: &str) {
+            self.path.push(name.to_string());
+        }
+
+        fn exit_module(&mut self, _name: &str) {
+            self.path.pop();
+        }
+    }
+
+    impl<B: Backend> ModuleMapper<B> for PathRecorder {
+        fn map_float<const D: usize>(
+            &mut self,
+            _id: ParamId,
+            tensor: Tensor<B, D>,
+        ) -> Tensor<B, D> {
+            self.paths.push(self.path.join("."));
+            tensor
+        }
+
+        fn enter_module(&mut self, name: &str) {
+            self.path.push(name.to_string());
+        }
+
+        fn exit_module(&mut self, _name: &str) {
+            self.path.pop();
+        }
+    }
+
+    fn assert_paths<M: Module<TestBackend>>(module: M, expected: &[&str]) {
+        let mut visited = PathRecorder::default();
+        module.visit(&mut visited);
+        let mut mapped = PathRecorder::default();
+        module.map(&mut mapped);
+
+        assert_eq!(visited.paths, expected);
+        assert_eq!(mapped.paths, expected);
+    }
+
+    #[test]
+    fn should_visit_and_map_the_same_paths_composed() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module = ModuleComposed::<TestBackend>::new(&device);
+
+        assert_paths(
+            module,
+            &[
+                "weight",
+                "basic.weight_basic",
+                "tuple.0.weight_basic",
+                "tuple.1.weight_basic",
+            ],
+        );
+    }
+
+    #[test]
+    fn should_visit_and_map_the_same_paths_collections() {
+        let device = <TestBackend as Backend>::Device::default();
+        let array = ModuleWithConstGeneric::<TestBackend, 2> {
+            modules: [ModuleBasic::new(&device), ModuleBasic::new(&device)],
+        };
+        let vec = ModuleWithGenericModule::<TestBackend, _> {
+            module: vec![ModuleBasic::new(&device), ModuleBasic::new(&device)],
+            _backend: PhantomData,
+        };
+
+        assert_paths(array, &["modules.0.weight_basic", "modules.1.weight_basic"]);
+        assert_paths(vec, &["module.0.weight_basic", "module.1.weight_basic"]);
+    }
+}
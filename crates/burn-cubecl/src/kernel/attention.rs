@@ -0,0 +1,270 @@
+use crate::{
+    element::{BoolElement, FloatElement},
+    kernel::into_contiguous,
+    ops::numeric::empty_device,
+    tensor::CubeTensor,
+    CubeRuntime,
+};
+use burn_tensor::{
+    ops::{attention::AttentionDropout, AttentionOptions},
+    Shape,
+};
+use cubecl::prelude::*;
+
+/// Number of queries handled by a cube, which is also the number of keys loaded per tile.
+const BLOCK_SIZE: u32 = 32;
+
+/// The largest head dimension supported by the kernel, since every unit keeps its query and
+/// accumulator in registers.
+pub(crate) const ATTENTION_MAX_HEAD_DIM: usize = 128;
+
+/// Each cube computes the output of a block of queries for one head, iterating over tiles of keys
+/// and values loaded in shared memory. Every unit handles one query with an online softmax, so the
+/// attention weights are never materialized.
+///
+/// The dropout mask is generated by each unit from the seed and the position of each weight, as
+/// in [AttentionDropout], so it isn't stored either.
+///
+/// The scores and the accumulation use `f32` so that half precision inputs keep an accurate
+/// result.
+#[cube(launch)]
+fn attention_kernel<F: Float, B: Int>(
+    query: &Tensor<F>,
+    key: &Tensor<F>,
+    value: &Tensor<F>,
+    padding_mask: &Tensor<B>,
+    attn_mask: &Tensor<B>,
+    output: &mut Tensor<F>,
+    scale: f32,
+    num_groups: u32,
+    dropout_seed_0: u32,
+    dropout_seed_1: u32,
+    dropout_threshold: u32,
+    dropout_scale: f32,
+    #[comptime] head_dim: u32,
+    #[comptime] value_dim: u32,
+    #[comptime] is_causal: bool,
+    #[comptime] has_padding_mask: bool,
+    #[comptime] has_attn_mask: bool,
+    #[comptime] has_dropout: bool,
+) {
+    let num_heads = query.shape(1);
+    let seq_length_q = query.shape(2);
+    let seq_length_k = key.shape(2);
+
+    let batch = CUBE_POS_Y / num_heads;
+    let head = CUBE_POS_Y % num_heads;
+    let kv_head = head / num_groups;
+    let row = CUBE_POS_X * BLOCK_SIZE + UNIT_POS_X;
+    // The row of the query across the batch and the heads, numbering the dropout mask.
+    let dropout_row = CUBE_POS_Y * seq_length_q + row;
+    // Units without a query still help loading the tiles, so they can't exit early.
+    let is_valid = row < seq_length_q;
+
+    let mut query_row = Array::<f32>::new(head_dim);
+    let mut acc = Array::<f32>::new(value_dim);
+    let offset_query = batch * query.stride(0) + head * query.stride(1) + row * query.stride(2);
+
+    for d in 0..head_dim {
+        if is_valid {
+            query_row[d] = f32::cast_from(query[offset_query + d * query.stride(3)]) * scale;
+        }
+    }
+    for d in 0..value_dim {
+        acc[d] = 0.0;
+    }
+
+    let mut key_tile = SharedMemory::<f32>::new(BLOCK_SIZE * head_dim);
+    let mut value_tile = SharedMemory::<f32>::new(BLOCK_SIZE * value_dim);
+
+    let offset_key = batch * key.stride(0) + kv_head * key.stride(1);
+    let offset_value = batch * value.stride(0) + kv_head * value.stride(1);
+
+    // Finite so that the first correction factor is zero instead of NaN.
+    let mut max = -3.0e38f32;
+    let mut sum = 0.0f32;
+
+    let num_tiles = (seq_length_k + BLOCK_SIZE - 1) / BLOCK_SIZE;
+
+    for tile in 0..num_tiles {
+        let tile_start = tile * BLOCK_SIZE;
+        let load_index = tile_start + UNIT_POS_X;
+
+        for d in 0..head_dim {
+            let mut val = 0.0f32;
+            if load_index < seq_length_k {
+                let index = offset_key + load_index * key.stride(2) + d * key.stride(3);
+                val = f32::cast_from(key[index]);
+            }
+            key_tile[UNIT_POS_X * head_dim + d] = val;
+        }
+        for d in 0..value_dim {
+            let mut val = 0.0f32;
+            if load_index < seq_length_k {
+                let index = offset_value + load_index * value.stride(2) + d * value.stride(3);
+                val = f32::cast_from(value[index]);
+            }
+            value_tile[UNIT_POS_X * value_dim + d] = val;
+        }
+
+        sync_units();
+
+        if is_valid {
+            for j in 0..BLOCK_SIZE {
+                let col = tile_start + j;
+                let mut attend = col < seq_length_k;
+
+                if comptime![is_causal] {
+                    // The queries are aligned with the last keys.
+                    attend = attend && col + seq_length_q <= row + seq_length_k;
+                }
+                if comptime![has_padding_mask] {
+                    if attend {
+                        let index = batch * padding_mask.stride(0) + col * padding_mask.stride(1);
+                        attend = u32::cast_from(padding_mask[index]) == 0;
+                    }
+                }
+                if comptime![has_attn_mask] {
+                    if attend {
+                        let index = batch * attn_mask.stride(0)
+                            + row * attn_mask.stride(1)
+                            + col * attn_mask.stride(2);
+                        attend = u32::cast_from(attn_mask[index]) == 0;
+                    }
+                }
+
+                if attend {
+                    let mut score = 0.0f32;
+                    for d in 0..head_dim {
+                        score += query_row[d] * key_tile[j * head_dim + d];
+                    }
+
+                    let max_new = f32::max(max, score);
+                    let correction = f32::exp(max - max_new);
+                    let weight = f32::exp(score - max_new);
+
+                    sum = sum * correction + weight;
+
+                    // The dropped weights still count in the softmax normalization.
+                    let mut factor = 1.0f32;
+                    if comptime![has_dropout] {
+                        let hash = dropout_hash(dropout_seed_0, dropout_seed_1, dropout_row, col);
+                        factor = dropout_scale;
+                        if hash >= dropout_threshold {
+                            factor = 0.0;
+                        }
+                    }
+
+                    for d in 0..value_dim {
+                        acc[d] =
+                            acc[d] * correction + factor * weight * value_tile[j * value_dim + d];
+                    }
+                    max = max_new;
+                }
+            }
+        }
+
+        sync_units();
+    }
+
+    if is_valid {
+        // A query attending to no key has a zero output.
+        let mut factor = 0.0f32;
+        if sum > 0.0 {
+            factor = 1.0 / sum;
+        }
+
+        let offset_output =
+            batch * output.stride(0) + head * output.stride(1) + row * output.stride(2);
+        for d in 0..value_dim {
+            output[offset_output + d * output.stride(3)] = F::cast_from(acc[d] * factor);
+        }
+    }
+}
+
+/// Hash of the position of an attention weight, matching [dropout_hash](burn_tensor::ops::attention::dropout_hash).
+#[cube]
+fn dropout_hash(seed_0: u32, seed_1: u32, row: u32, col: u32) -> u32 {
+    let hash = mix(row ^ seed_0);
+    let hash = mix(hash ^ col);
+    mix(hash ^ seed_1)
+}
+
+/// The `lowbias32` integer finalizer, the multiplications wrapping around.
+#[cube]
+fn mix(x: u32) -> u32 {
+    let x = x ^ (x >> 16u32);
+    let x = x * 2_146_121_005u32;
+    let x = x ^ (x >> 15u32);
+    let x = x * 2_221_713_035u32;
+    x ^ (x >> 16u32)
+}
+
+/// Scaled dot-product attention computed with tiles of keys and values.
+pub(crate) fn scaled_dot_product_attention<R: CubeRuntime, E: FloatElement, BT: BoolElement>(
+    query: CubeTensor<R>,
+    key: CubeTensor<R>,
+    value: CubeTensor<R>,
+    padding_mask: Option<CubeTensor<R>>,
+    attn_mask: Option<CubeTensor<R>>,
+    options: AttentionOptions,
+) -> CubeTensor<R> {
+    let query = into_contiguous(query);
+    let [batch_size, num_heads, seq_length_q, head_dim] = query.shape.dims();
+    let [_, num_kv_heads, _, value_dim] = value.shape.dims();
+    assert!(
+        num_kv_heads > 0 && num_heads % num_kv_heads == 0,
+        "The number of query heads ({num_heads}) should be a multiple of the number of key and value heads ({num_kv_heads})"
+    );
+
+    let shape_out = Shape::new([batch_size, num_heads, seq_length_q, value_dim]);
+    let output = empty_device::<R, E>(query.client.clone(), query.device.clone(), shape_out);
+
+    if output.shape.num_elements() == 0 {
+        return output;
+    }
+
+    let scale = options
+        .scale
+        .unwrap_or_else(|| 1.0 / (head_dim as f64).sqrt());
+    let dropout = AttentionDropout::new(&options);
+    let has_padding_mask = padding_mask.is_some();
+    let has_attn_mask = attn_mask.is_some();
+
+    // The masks are unused when absent, the query is passed in their place.
+    let padding_mask = padding_mask.unwrap_or_else(|| query.clone());
+    let attn_mask = attn_mask.unwrap_or_else(|| query.clone());
+
+    let cube_dim = CubeDim::new(BLOCK_SIZE, 1, 1);
+    let cube_count = CubeCount::Static(
+        (seq_length_q as u32).div_ceil(BLOCK_SIZE),
+        (batch_size * num_heads) as u32,
+        1,
+    );
+
+    attention_kernel::launch::<E, BT, R>(
+        &query.client,
+        cube_count,
+        cube_dim,
+        query.as_tensor_arg::<E>(1),
+        key.as_tensor_arg::<E>(1),
+        value.as_tensor_arg::<E>(1),
+        padding_mask.as_tensor_arg::<BT>(1),
+        attn_mask.as_tensor_arg::<BT>(1),
+        output.as_tensor_arg::<E>(1),
+        ScalarArg::new(scale as f32),
+        ScalarArg::new((num_heads / num_kv_heads) as u32),
+        ScalarArg::new(dropout.map_or(0, |dropout| dropout.seeds[0])),
+        ScalarArg::new(dropout.map_or(0, |dropout| dropout.seeds[1])),
+        ScalarArg::new(dropout.map_or(0, |dropout| dropout.threshold)),
+        ScalarArg::new(dropout.map_or(1.0, |dropout| dropout.scale as f32)),
+        head_dim as u32,
+        value_dim as u32,
+        options.is_causal,
+        has_padding_mask,
+        has_attn_mask,
+        dropout.is_some(),
+    );
+
+    output
+}
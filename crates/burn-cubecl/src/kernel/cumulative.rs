@@ -0,0 +1,152 @@
+use crate::{element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// The accumulation of a cumulative operation.
+#[cube]
+pub(crate) trait CumulativeOp<N: Numeric>: 'static + Send + Sync {
+    /// The first output of an exclusive accumulation.
+    fn identity() -> N;
+
+    /// Accumulate the value into the current accumulator.
+    fn combine(acc: N, value: N) -> N;
+}
+
+/// Cumulative sum.
+pub(crate) struct CumSum;
+/// Cumulative product.
+pub(crate) struct CumProd;
+/// Cumulative maximum.
+pub(crate) struct CumMax;
+/// Cumulative minimum.
+pub(crate) struct CumMin;
+
+#[cube]
+impl<N: Numeric> CumulativeOp<N> for CumSum {
+    fn identity() -> N {
+        N::from_int(0)
+    }
+
+    fn combine(acc: N, value: N) -> N {
+        acc + value
+    }
+}
+
+#[cube]
+impl<N: Numeric> CumulativeOp<N> for CumProd {
+    fn identity() -> N {
+        N::from_int(1)
+    }
+
+    fn combine(acc: N, value: N) -> N {
+        acc * value
+    }
+}
+
+#[cube]
+impl<N: Numeric> CumulativeOp<N> for CumMax {
+    fn identity() -> N {
+        N::min_value()
+    }
+
+    fn combine(acc: N, value: N) -> N {
+        // A NaN is never greater than the accumulator, so it is propagated explicitly.
+        select(value > acc || value != value, value, acc)
+    }
+}
+
+#[cube]
+impl<N: Numeric> CumulativeOp<N> for CumMin {
+    fn identity() -> N {
+        N::max_value()
+    }
+
+    fn combine(acc: N, value: N) -> N {
+        // A NaN is never less than the accumulator, so it is propagated explicitly.
+        select(value < acc || value != value, value, acc)
+    }
+}
+
+/// Each unit scans a full lane of the dimension, sequentially.
+#[cube(launch_unchecked)]
+fn cumulative_kernel<N: Numeric, O: CumulativeOp<N>>(
+    input: &Tensor<N>,
+    output: &mut Tensor<N>,
+    dim: u32,
+    #[comptime] exclusive: bool,
+    #[comptime] reverse: bool,
+) {
+    let size = output.shape(dim);
+    let stride_output = output.stride(dim);
+
+    if ABSOLUTE_POS >= output.len() / size {
+        terminate!();
+    }
+
+    // The output is contiguous, so the lane is split around the scanned dimension.
+    let offset_output =
+        ABSOLUTE_POS / stride_output * stride_output * size + ABSOLUTE_POS % stride_output;
+    let mut offset_input = 0;
+
+    for i in 0..output.rank() {
+        let coordinate = offset_output / output.stride(i) % output.shape(i);
+        offset_input += coordinate * input.stride(i);
+    }
+
+    let stride_input = input.stride(dim);
+    let mut acc = O::identity();
+
+    for step in 0..size {
+        let mut index = step;
+        if comptime![reverse] {
+            index = size - 1 - step;
+        }
+
+        let value = input[offset_input + index * stride_input];
+        let next = select(step == 0, value, O::combine(acc, value));
+
+        if comptime![exclusive] {
+            output[offset_output + index * stride_output] = acc;
+        } else {
+            output[offset_output + index * stride_output] = next;
+        }
+
+        acc = next;
+    }
+}
+
+/// Accumulate the elements of the tensor along the dimension with the given operation.
+pub(crate) fn cumulative<R: CubeRuntime, E: CubeElement, O: CumulativeOp<E>>(
+    input: CubeTensor<R>,
+    dim: usize,
+    exclusive: bool,
+    reverse: bool,
+) -> CubeTensor<R> {
+    let output = empty_device::<R, E>(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+
+    let size = output.shape.dims[dim];
+    if size == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements() / size, cube_dim);
+
+    unsafe {
+        cumulative_kernel::launch_unchecked::<E, O, R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+            exclusive,
+            reverse,
+        )
+    };
+
+    output
+}
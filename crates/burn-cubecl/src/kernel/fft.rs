@@ -0,0 +1,341 @@
+use crate::{
+    element::FloatElement,
+    ops::{base::from_data, numeric::empty_device},
+    tensor::CubeTensor,
+    CubeRuntime,
+};
+use burn_tensor::{Shape, TensorData};
+use core::f64::consts::PI;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// The lanes are transformed in contiguous `f32` buffers of shape `[num_lanes, padded, 2]`, so
+/// half precision tensors keep an accurate result.
+///
+/// Each unit loads one complex element of a lane into the buffer, the elements past the size of
+/// the lane being zero. With Bluestein, the element is multiplied by the chirp.
+#[cube(launch_unchecked)]
+fn fft_load_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &Tensor<F>,
+    buffer: &mut Tensor<f32>,
+    chirp: &Tensor<f32>,
+    dim: u32,
+    padded: u32,
+    #[comptime] bluestein: bool,
+) {
+    if ABSOLUTE_POS * 2 >= buffer.len() {
+        terminate!();
+    }
+
+    let lane = ABSOLUTE_POS / padded;
+    let index = ABSOLUTE_POS % padded;
+    let size = output.shape(dim);
+    let offset_buffer = ABSOLUTE_POS * 2;
+
+    if index >= size {
+        buffer[offset_buffer] = 0.0;
+        buffer[offset_buffer + 1] = 0.0;
+    } else {
+        let offset_output = lane_offset(output, lane, dim);
+        let mut offset_input = 0;
+
+        for i in 0..output.rank() - 1 {
+            let coordinate = offset_output / output.stride(i) % output.shape(i);
+            offset_input += coordinate * input.stride(i);
+        }
+
+        let offset = offset_input + index * input.stride(dim);
+        let mut real = f32::cast_from(input[offset]);
+        let mut imag = f32::cast_from(input[offset + input.stride(output.rank() - 1)]);
+
+        if comptime![bluestein] {
+            let cos = chirp[index * 2];
+            let sin = chirp[index * 2 + 1];
+            let chirp_real = real * cos - imag * sin;
+            imag = real * sin + imag * cos;
+            real = chirp_real;
+        }
+
+        buffer[offset_buffer] = real;
+        buffer[offset_buffer + 1] = imag;
+    }
+}
+
+/// One radix-2 Stockham stage, each unit computing one butterfly.
+///
+/// The stage splits the sub-sequences of the given length, interleaved with the given stride,
+/// into their even and odd halves. The output of the last stage is in natural order.
+#[cube(launch_unchecked)]
+fn fft_stage_kernel(
+    input: &Tensor<f32>,
+    output: &mut Tensor<f32>,
+    length: u32,
+    stride: u32,
+    padded: u32,
+    #[comptime] inverse: bool,
+) {
+    if ABSOLUTE_POS * 4 >= output.len() {
+        terminate!();
+    }
+
+    let half_padded = padded / 2;
+    let base = ABSOLUTE_POS / half_padded * padded;
+    let butterfly = ABSOLUTE_POS % half_padded;
+    let p = butterfly / stride;
+    let q = butterfly % stride;
+    let half = length / 2;
+
+    let a = (base + q + stride * p) * 2;
+    let b = (base + q + stride * (p + half)) * 2;
+    let a_real = input[a];
+    let a_imag = input[a + 1];
+    let b_real = input[b];
+    let b_imag = input[b + 1];
+
+    let mut sign = -1.0f32;
+    if comptime![inverse] {
+        sign = 1.0f32;
+    }
+    // The index is smaller than the length, so the angle stays accurate for long lanes.
+    let angle = sign * 6.283_185_5f32 * f32::cast_from(p) / f32::cast_from(length);
+    let cos = f32::cos(angle);
+    let sin = f32::sin(angle);
+
+    let diff_real = a_real - b_real;
+    let diff_imag = a_imag - b_imag;
+
+    let even = (base + q + stride * 2 * p) * 2;
+    let odd = even + stride * 2;
+    output[even] = a_real + b_real;
+    output[even + 1] = a_imag + b_imag;
+    output[odd] = diff_real * cos - diff_imag * sin;
+    output[odd + 1] = diff_real * sin + diff_imag * cos;
+}
+
+/// Multiplies each lane of the buffer by the filter, element-wise.
+#[cube(launch_unchecked)]
+fn fft_multiply_kernel(buffer: &mut Tensor<f32>, filter: &Tensor<f32>, padded: u32) {
+    if ABSOLUTE_POS * 2 >= buffer.len() {
+        terminate!();
+    }
+
+    let offset = ABSOLUTE_POS * 2;
+    let offset_filter = (ABSOLUTE_POS % padded) * 2;
+    let real = buffer[offset];
+    let imag = buffer[offset + 1];
+    let filter_real = filter[offset_filter];
+    let filter_imag = filter[offset_filter + 1];
+
+    buffer[offset] = real * filter_real - imag * filter_imag;
+    buffer[offset + 1] = real * filter_imag + imag * filter_real;
+}
+
+/// Each unit stores one complex element of a lane from the buffer into the output, scaled and,
+/// with Bluestein, multiplied by the chirp.
+#[cube(launch_unchecked)]
+fn fft_store_kernel<F: Float>(
+    buffer: &Tensor<f32>,
+    output: &mut Tensor<F>,
+    chirp: &Tensor<f32>,
+    dim: u32,
+    padded: u32,
+    scale: f32,
+    #[comptime] bluestein: bool,
+) {
+    if ABSOLUTE_POS * 2 >= output.len() {
+        terminate!();
+    }
+
+    let size = output.shape(dim);
+    let lane = ABSOLUTE_POS / size;
+    let index = ABSOLUTE_POS % size;
+
+    let offset_buffer = (lane * padded + index) * 2;
+    let mut real = buffer[offset_buffer] * scale;
+    let mut imag = buffer[offset_buffer + 1] * scale;
+
+    if comptime![bluestein] {
+        let cos = chirp[index * 2];
+        let sin = chirp[index * 2 + 1];
+        let chirp_real = real * cos - imag * sin;
+        imag = real * sin + imag * cos;
+        real = chirp_real;
+    }
+
+    let offset_output = lane_offset(output, lane, dim) + index * output.stride(dim);
+    output[offset_output] = F::cast_from(real);
+    output[offset_output + 1] = F::cast_from(imag);
+}
+
+/// The offset of the first element of a lane in the contiguous output, where the last dimension
+/// holds the real and imaginary parts.
+#[cube]
+fn lane_offset<F: Float>(output: &Tensor<F>, lane: u32, dim: u32) -> u32 {
+    let size = output.shape(dim);
+    let stride = output.stride(dim) / 2;
+
+    (lane / stride * stride * size + lane % stride) * 2
+}
+
+/// Discrete Fourier transform of a complex tensor along the dimension, the last dimension of the
+/// tensor holding the real and imaginary parts.
+///
+/// Lanes with a power of two size use the radix-2 Stockham algorithm, other sizes use the
+/// Bluestein algorithm, so every size runs in `O(n log n)`.
+pub(crate) fn fft<R: CubeRuntime, E: FloatElement>(
+    input: CubeTensor<R>,
+    dim: usize,
+    inverse: bool,
+) -> CubeTensor<R> {
+    let output = empty_device::<R, E>(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+
+    let size = output.shape.dims[dim];
+    let num_elems = output.shape.num_elements() / 2;
+    if num_elems == 0 {
+        return output;
+    }
+
+    let num_lanes = num_elems / size;
+    let bluestein = !size.is_power_of_two();
+    let padded = match bluestein {
+        true => (2 * size - 1).next_power_of_two(),
+        false => size,
+    };
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    // Chirp w_k = exp(sign * i * pi * k^2 / n), with k^2 reduced modulo 2n for accuracy.
+    let chirp = (0..size)
+        .map(|k| {
+            let square = (k as u64 * k as u64) % (2 * size as u64);
+            (sign * PI * square as f64 / size as f64).sin_cos()
+        })
+        .collect::<Vec<_>>();
+    let chirp_tensor = match bluestein {
+        true => {
+            let values = chirp
+                .iter()
+                .flat_map(|(sin, cos)| [*cos as f32, *sin as f32])
+                .collect::<Vec<_>>();
+            from_data::<R>(TensorData::new(values, [size, 2]), &output.device)
+        }
+        // Unused, but the kernels always take a chirp.
+        false => from_data::<R>(TensorData::new(vec![1.0f32, 0.0], [1, 2]), &output.device),
+    };
+
+    let buffer = empty_device::<R, f32>(
+        output.client.clone(),
+        output.device.clone(),
+        Shape::new([num_lanes, padded, 2]),
+    );
+    let cube_dim = CubeDim::default();
+
+    unsafe {
+        fft_load_kernel::launch_unchecked::<E, R>(
+            &output.client,
+            calculate_cube_count_elemwise(num_lanes * padded, cube_dim),
+            cube_dim,
+            input.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            buffer.as_tensor_arg::<f32>(1),
+            chirp_tensor.as_tensor_arg::<f32>(1),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(padded as u32),
+            bluestein,
+        )
+    };
+
+    let mut buffer = radix2(buffer, num_lanes, padded, inverse && !bluestein);
+    let mut scale = if inverse { 1.0 / size as f32 } else { 1.0 };
+
+    if bluestein {
+        // The conjugate chirp, mirrored to make the convolution circular.
+        let mut filter = vec![0.0f32; padded * 2];
+        for (k, (sin, cos)) in chirp.iter().enumerate() {
+            filter[k * 2] = *cos as f32;
+            filter[k * 2 + 1] = -*sin as f32;
+            if k > 0 {
+                filter[(padded - k) * 2] = *cos as f32;
+                filter[(padded - k) * 2 + 1] = -*sin as f32;
+            }
+        }
+        let filter = from_data::<R>(TensorData::new(filter, [1, padded, 2]), &output.device);
+        let filter = radix2(filter, 1, padded, false);
+
+        unsafe {
+            fft_multiply_kernel::launch_unchecked::<R>(
+                &output.client,
+                calculate_cube_count_elemwise(num_lanes * padded, cube_dim),
+                cube_dim,
+                buffer.as_tensor_arg::<f32>(1),
+                filter.as_tensor_arg::<f32>(1),
+                ScalarArg::new(padded as u32),
+            )
+        };
+
+        buffer = radix2(buffer, num_lanes, padded, true);
+        scale /= padded as f32;
+    }
+
+    unsafe {
+        fft_store_kernel::launch_unchecked::<E, R>(
+            &output.client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            buffer.as_tensor_arg::<f32>(1),
+            output.as_tensor_arg::<E>(1),
+            chirp_tensor.as_tensor_arg::<f32>(1),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(padded as u32),
+            ScalarArg::new(scale),
+            bluestein,
+        )
+    };
+
+    output
+}
+
+/// Unnormalized transform of the lanes of a buffer with a power of two size, alternating
+/// between two buffers at each stage.
+fn radix2<R: CubeRuntime>(
+    buffer: CubeTensor<R>,
+    num_lanes: usize,
+    padded: usize,
+    inverse: bool,
+) -> CubeTensor<R> {
+    let mut input = buffer;
+    let mut output = empty_device::<R, f32>(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_lanes * padded / 2, cube_dim);
+    let mut length = padded;
+    let mut stride = 1;
+
+    while length > 1 {
+        unsafe {
+            fft_stage_kernel::launch_unchecked::<R>(
+                &input.client,
+                cube_count.clone(),
+                cube_dim,
+                input.as_tensor_arg::<f32>(1),
+                output.as_tensor_arg::<f32>(1),
+                ScalarArg::new(length as u32),
+                ScalarArg::new(stride as u32),
+                ScalarArg::new(padded as u32),
+                inverse,
+            )
+        };
+
+        core::mem::swap(&mut input, &mut output);
+        length /= 2;
+        stride *= 2;
+    }
+
+    input
+}
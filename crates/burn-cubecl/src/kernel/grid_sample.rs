@@ -0,0 +1,249 @@
+use crate::{
+    element::FloatElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::CubeTensor,
+    CubeRuntime,
+};
+use burn_tensor::{
+    ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode},
+    Shape,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Coefficient of the cubic convolution, as used by PyTorch.
+const CUBIC_A: f32 = -0.75;
+
+const MODE_NEAREST: u32 = 0;
+const MODE_BILINEAR: u32 = 1;
+const MODE_BICUBIC: u32 = 2;
+
+const PADDING_ZEROS: u32 = 0;
+const PADDING_BORDER: u32 = 1;
+const PADDING_REFLECTION: u32 = 2;
+
+/// Each unit computes one output element by sampling the neighbors of its grid location.
+///
+/// The coordinates and the accumulation use `f32` so that half precision grids keep an accurate
+/// location.
+#[cube(launch)]
+fn grid_sample_2d_kernel<F: Float>(
+    input: &Tensor<F>,
+    grid: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] mode: u32,
+    #[comptime] padding_mode: u32,
+    #[comptime] align_corners: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let height = input.shape(2);
+    let width = input.shape(3);
+
+    let grid_offset = batch * grid.stride(0) + y * grid.stride(1) + x * grid.stride(2);
+    let x_coord = unnormalize(f32::cast_from(grid[grid_offset]), width, align_corners);
+    let y_coord = unnormalize(
+        f32::cast_from(grid[grid_offset + grid.stride(3)]),
+        height,
+        align_corners,
+    );
+
+    let offset = batch * input.stride(0) + channel * input.stride(1);
+    let mut value = 0.0f32;
+
+    if comptime![mode == MODE_NEAREST] {
+        let x_coord = pad(x_coord, width, padding_mode, align_corners);
+        let y_coord = pad(y_coord, height, padding_mode, align_corners);
+
+        value = sample(
+            input,
+            offset,
+            round_half_even(x_coord),
+            round_half_even(y_coord),
+        );
+    } else if comptime![mode == MODE_BILINEAR] {
+        let x_coord = pad(x_coord, width, padding_mode, align_corners);
+        let y_coord = pad(y_coord, height, padding_mode, align_corners);
+        let x0 = f32::floor(x_coord);
+        let y0 = f32::floor(y_coord);
+        let tx = x_coord - x0;
+        let ty = y_coord - y0;
+
+        value += sample(input, offset, x0, y0) * (1.0 - tx) * (1.0 - ty);
+        value += sample(input, offset, x0 + 1.0, y0) * tx * (1.0 - ty);
+        value += sample(input, offset, x0, y0 + 1.0) * (1.0 - tx) * ty;
+        value += sample(input, offset, x0 + 1.0, y0 + 1.0) * tx * ty;
+    } else {
+        // Bicubic sampling pads every neighbor instead of the location.
+        let x0 = f32::floor(x_coord);
+        let y0 = f32::floor(y_coord);
+        let tx = x_coord - x0;
+        let ty = y_coord - y0;
+
+        for j in 0..4u32 {
+            let distance_y = ty + 1.0 - f32::cast_from(j);
+            let y_tap = pad(
+                y0 + f32::cast_from(j) - 1.0,
+                height,
+                padding_mode,
+                align_corners,
+            );
+
+            for i in 0..4u32 {
+                let distance_x = tx + 1.0 - f32::cast_from(i);
+                let x_tap = pad(
+                    x0 + f32::cast_from(i) - 1.0,
+                    width,
+                    padding_mode,
+                    align_corners,
+                );
+
+                value += sample(input, offset, x_tap, y_tap)
+                    * cubic_weight(distance_x)
+                    * cubic_weight(distance_y);
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(value);
+}
+
+/// Maps a grid coordinate from `[-1, 1]` to the pixel indices of the input.
+#[cube]
+fn unnormalize(coord: f32, size: u32, #[comptime] align_corners: bool) -> f32 {
+    let size = f32::cast_from(size);
+    let mut result = ((coord + 1.0) * size - 1.0) / 2.0;
+
+    if comptime![align_corners] {
+        result = (coord + 1.0) * (size - 1.0) / 2.0;
+    }
+
+    result
+}
+
+/// Applies the padding to a coordinate outside of the input.
+#[cube]
+fn pad(
+    coord: f32,
+    size: u32,
+    #[comptime] padding_mode: u32,
+    #[comptime] align_corners: bool,
+) -> f32 {
+    let size = f32::cast_from(size);
+    let mut coord = coord;
+
+    if comptime![padding_mode == PADDING_REFLECTION] {
+        if comptime![align_corners] {
+            coord = reflect(coord, 0.0, 2.0 * (size - 1.0));
+        } else {
+            coord = reflect(coord, -1.0, 2.0 * size - 1.0);
+        }
+    }
+
+    if comptime![padding_mode == PADDING_BORDER || padding_mode == PADDING_REFLECTION] {
+        coord = f32::clamp(coord, 0.0, size - 1.0);
+    }
+
+    coord
+}
+
+/// Reflects a coordinate over the bounds, given as twice their value so that they are integers
+/// for both corner alignments.
+#[cube]
+fn reflect(coord: f32, twice_low: f32, twice_high: f32) -> f32 {
+    let min = twice_low / 2.0;
+    let span = (twice_high - twice_low) / 2.0;
+    let coord = f32::abs(coord - min);
+
+    let flips = f32::floor(coord / span);
+    let extra = coord - flips * span;
+    let is_odd = flips - 2.0 * f32::floor(flips / 2.0) > 0.5;
+    let reflected = select(is_odd, span - extra + min, extra + min);
+
+    // A single pixel has no span to reflect over.
+    select(span > 0.0, reflected, 0.0)
+}
+
+/// The value of the input at the location, zero when it is outside of the input.
+#[cube]
+fn sample<F: Float>(input: &Tensor<F>, offset: u32, x: f32, y: f32) -> f32 {
+    let max_x = f32::cast_from(input.shape(3) - 1);
+    let max_y = f32::cast_from(input.shape(2) - 1);
+    let inside = x >= 0.0 && x <= max_x && y >= 0.0 && y <= max_y;
+
+    let x = f32::clamp(x, 0.0, max_x) as u32;
+    let y = f32::clamp(y, 0.0, max_y) as u32;
+    let value = f32::cast_from(input[offset + y * input.stride(2) + x * input.stride(3)]);
+
+    select(inside, value, 0.0)
+}
+
+#[cube]
+fn round_half_even(value: f32) -> f32 {
+    let floor = f32::floor(value);
+    let diff = value - floor;
+    let is_odd = floor - 2.0 * f32::floor(floor / 2.0) > 0.5;
+
+    select(diff > 0.5 || (diff == 0.5 && is_odd), floor + 1.0, floor)
+}
+
+/// The cubic convolution of a neighbor at the distance of the location.
+#[cube]
+fn cubic_weight(distance: f32) -> f32 {
+    let x = f32::abs(distance);
+    let near = ((CUBIC_A + 2.0) * x - (CUBIC_A + 3.0)) * x * x + 1.0;
+    let far = ((CUBIC_A * x - 5.0 * CUBIC_A) * x + 8.0 * CUBIC_A) * x - 4.0 * CUBIC_A;
+
+    select(x <= 1.0, near, select(x < 2.0, far, 0.0))
+}
+
+/// Samples the input at the locations of the grid.
+pub(crate) fn grid_sample_2d<R: CubeRuntime, E: FloatElement>(
+    input: CubeTensor<R>,
+    grid: CubeTensor<R>,
+    options: GridSampleOptions,
+) -> CubeTensor<R> {
+    let input = into_contiguous(input);
+    let [batch_size, channels, _, _] = input.shape.dims();
+    let [_, height, width, _] = grid.shape.dims();
+
+    let shape_out = Shape::new([batch_size, channels, height, width]);
+    let output = empty_device::<R, E>(input.client.clone(), input.device.clone(), shape_out);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let mode = match options.mode {
+        InterpolateMode::Nearest => MODE_NEAREST,
+        InterpolateMode::Bilinear => MODE_BILINEAR,
+        InterpolateMode::Bicubic => MODE_BICUBIC,
+    };
+    let padding_mode = match options.padding_mode {
+        GridSamplePaddingMode::Zeros => PADDING_ZEROS,
+        GridSamplePaddingMode::Border => PADDING_BORDER,
+        GridSamplePaddingMode::Reflection => PADDING_REFLECTION,
+    };
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    grid_sample_2d_kernel::launch::<E, R>(
+        &input.client,
+        cube_count,
+        cube_dim,
+        input.as_tensor_arg::<E>(1),
+        grid.as_tensor_arg::<E>(1),
+        output.as_tensor_arg::<E>(1),
+        mode,
+        padding_mode,
+        options.align_corners,
+    );
+
+    output
+}
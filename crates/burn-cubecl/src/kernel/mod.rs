@@ -1,19 +1,27 @@
+mod attention;
 mod binary;
 mod binary_int;
 mod cast;
 mod clamp;
 mod comparison;
 mod contiguous;
+mod cumulative;
+mod fft;
+mod grid_sample;
 mod index;
 mod mask;
 mod unary_float;
 mod unary_int;
 mod unary_numeric;
 
+pub(crate) use attention::*;
 pub(crate) use binary::*;
 pub(crate) use binary_int::*;
 pub use cast::*;
 pub use contiguous::*;
+pub(crate) use cumulative::*;
+pub(crate) use fft::*;
+pub(crate) use grid_sample::*;
 pub use mask::*;
 pub(crate) use unary_float::*;
 pub(crate) use unary_int::*;
@@ -28,6 +28,25 @@ pub(crate) fn extract_i8(value: u32, offset: u32) -> i32 {
     i32::cast_from(value) - sub
 }
 
+#[cube]
+pub(crate) fn extract_packed(
+    value: u32,
+    offset: u32,
+    #[comptime] bits: u32,
+    #[comptime] signed: bool,
+) -> i32 {
+    // Extract the segment of `bits` bits
+    let mask = comptime!((1u32 << bits) - 1);
+    let value = (value >> offset) & mask;
+    let mut out = i32::cast_from(value);
+    if comptime!(signed) {
+        // Check if the value is negative by inspecting the MSB and subtract 2^bits if it is
+        let sign_mask = comptime!(1u32 << (bits - 1));
+        out -= i32::cast_from(value & sign_mask != 0) * comptime!(1i32 << bits);
+    }
+    out
+}
+
 #[cube]
 pub(crate) fn extract_i8s(value: u32) -> Line<i32> {
     let mut line = Line::empty(4);
@@ -107,6 +126,40 @@ pub(crate) fn dequantize_per_tensor_symmetric_int8_kernel(
     }
 }
 
+#[cube(launch_unchecked)]
+pub(crate) fn dequantize_grouped_int8_kernel(
+    input: &QTensor,
+    output: &mut Tensor<Line<f32>>,
+    num_packed: u32,
+    stride: u32,
+    num_params: u32,
+    #[comptime] scheme: QuantizationScheme,
+) {
+    // The last positions contain the (offset and) scale of each group
+    if ABSOLUTE_POS >= num_packed {
+        terminate!();
+    }
+
+    let qparams = QParams::new(scheme);
+    // Input line size is fixed to 1
+    let packed = input[ABSOLUTE_POS][0];
+
+    // Each value is packed with the number of bits of the quantized type (e.g., four int8 values per u32)
+    let bits = comptime!(scheme.q_type().bits());
+    let signed = comptime!(scheme.q_type().is_signed());
+    let num_packed_values = comptime!(32 / bits);
+    #[unroll]
+    for i in 0..num_packed_values {
+        let index = ABSOLUTE_POS * num_packed_values + i;
+        if index < output.len() {
+            let group = (index / stride) % num_params;
+            let (scale, offset) = qparams.group_values(input, group, num_params);
+            let value = extract_packed(packed, bits * i, bits, signed);
+            output[index] = dequantize_affine_int8::<f32>(Line::new(value), scale, offset);
+        }
+    }
+}
+
 pub(crate) fn dequantize_per_tensor<R, F>(tensor: CubeTensor<R>) -> CubeTensor<R>
 where
     R: CubeRuntime,
@@ -114,8 +167,13 @@ where
 {
     // The actual number of elements is 1/4 (four int8 values packed in a single u32)
     // so we choose a line size to match a valid input binding size.
+    // Sub-byte quantized types pack even more values in a single u32.
     let num_out_elems = tensor.shape.num_elements();
-    let num_elems = usize::div_ceil(num_out_elems, 4);
+    let num_packed = match tensor.dtype {
+        DType::QFloat(scheme) => scheme.q_type().num_packed(),
+        _ => 4,
+    };
+    let num_elems = usize::div_ceil(num_out_elems, num_packed);
     let line_size_in = 1;
     let line_size_out = if num_out_elems < 4 { 1 } else { 4 };
     let cube_dim = CubeDim::default();
@@ -134,6 +192,39 @@ where
 
     if let DType::QFloat(scheme) = tensor.dtype {
         match scheme {
+            QuantizationScheme::PerChannelAffine(..)
+            | QuantizationScheme::PerChannelSymmetric(..)
+            | QuantizationScheme::PerBlockAffine(..)
+            | QuantizationScheme::PerBlockSymmetric(..)
+            // Sub-byte values are unpacked by the grouped kernel, with a single group of parameters
+            | QuantizationScheme::PerTensorAffine(
+                QuantizationType::QInt4
+                | QuantizationType::QUInt4
+                | QuantizationType::QInt2
+                | QuantizationType::QUInt2,
+            )
+            | QuantizationScheme::PerTensorSymmetric(
+                QuantizationType::QInt4
+                | QuantizationType::QUInt4
+                | QuantizationType::QInt2
+                | QuantizationType::QUInt2,
+            ) => {
+                let layout = scheme.params_layout(&tensor.shape.dims);
+
+                unsafe {
+                    dequantize_grouped_int8_kernel::launch_unchecked::<R>(
+                        &client,
+                        cube_count,
+                        cube_dim,
+                        tensor.as_array_arg::<u32>(line_size_in),
+                        output.as_tensor_arg::<F>(1),
+                        ScalarArg::new(num_elems as u32),
+                        ScalarArg::new(layout.stride as u32),
+                        ScalarArg::new(layout.num_params as u32),
+                        scheme,
+                    )
+                };
+            }
             QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => {
                 unsafe {
                     dequantize_per_tensor_affine_int8_kernel::launch_unchecked::<R>(
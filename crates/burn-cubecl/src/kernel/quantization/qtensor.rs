@@ -40,10 +40,24 @@ impl QParams {
                 }
             },
             // Symmetric quantization only contains the scaling factor as the last element
-            QuantizationScheme::PerTensorSymmetric(_) => (
+            // (schemes with multiple groups of parameters should use `group_values` instead)
+            _ => (
                 f32::bitcast_from(tensor[len - 1][tensor.line_size() - 1]),
                 0,
             ),
         }
     }
+
+    /// Get the quantization parameters values of the given group, for schemes with multiple
+    /// groups of quantization parameters (line size of 1).
+    pub fn group_values(&self, tensor: &QTensor, group: u32, num_params: u32) -> (f32, i32) {
+        let len = tensor.len();
+        // Scales are stored last, preceded by the zero-point offsets (if any)
+        let scale = f32::bitcast_from(tensor[len - num_params + group][0]);
+        let mut offset = 0;
+        if comptime!(self.scheme.has_offset()) {
+            offset = i32::cast_from(tensor[len - 2 * num_params + group][0]);
+        }
+        (scale, offset)
+    }
 }
@@ -1,3 +1,4 @@
+use crate::kernel;
 use crate::tensor::CubeTensor;
 use crate::FloatElement;
 use crate::{CubeElement, CubeRuntime, IntElement};
@@ -156,6 +157,173 @@ pub(crate) fn quantize_per_tensor_symmetric_int8_kernel(
     }
 }
 
+#[cube(launch_unchecked)]
+pub(crate) fn quantize_grouped_affine_int8_kernel(
+    input: &Tensor<Line<f32>>,
+    scale: &Tensor<f32>,
+    offset: &Tensor<i32>,
+    range_min: f32,
+    range_max: f32,
+    stride: u32,
+    num_params: u32,
+    output: &mut Array<u32>,
+    #[comptime] bits: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    // The zero-point offsets and scales of each group are stored after the packed values
+    let num_packed = output.len() - 2 * num_params;
+    if ABSOLUTE_POS >= num_packed {
+        let index = ABSOLUTE_POS - num_packed;
+        if index < num_params {
+            output[ABSOLUTE_POS] = u32::bitcast_from(offset[index]);
+        } else {
+            output[ABSOLUTE_POS] = u32::bitcast_from(scale[index - num_params]);
+        }
+        terminate!();
+    }
+
+    let mut v_packed = 0;
+    // Each value is packed with the given number of bits (e.g., four int8 values per u32)
+    let num_packed_values = comptime!(32 / bits);
+    let mask = comptime!((1u32 << bits) - 1);
+    #[unroll]
+    for i in 0..num_packed_values {
+        let index = ABSOLUTE_POS * num_packed_values + i;
+        if index < input.len() {
+            let param = (index / stride) % num_params;
+            let v = quantize_affine_int8::<f32>(
+                input[index],
+                scale[param],
+                offset[param],
+                range_min,
+                range_max,
+            );
+            // Shift and combine into u32
+            v_packed |= (v[0] & mask) << (bits * i);
+        }
+    }
+    output[ABSOLUTE_POS] = v_packed;
+}
+
+#[cube(launch_unchecked)]
+pub(crate) fn quantize_grouped_symmetric_int8_kernel(
+    input: &Tensor<Line<f32>>,
+    scale: &Tensor<f32>,
+    range_min: f32,
+    range_max: f32,
+    stride: u32,
+    num_params: u32,
+    output: &mut Array<u32>,
+    #[comptime] bits: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    // The scales of each group are stored after the packed values
+    let num_packed = output.len() - num_params;
+    if ABSOLUTE_POS >= num_packed {
+        output[ABSOLUTE_POS] = u32::bitcast_from(scale[ABSOLUTE_POS - num_packed]);
+        terminate!();
+    }
+
+    let mut v_packed = 0;
+    // Each value is packed with the given number of bits (e.g., four int8 values per u32)
+    let num_packed_values = comptime!(32 / bits);
+    let mask = comptime!((1u32 << bits) - 1);
+    #[unroll]
+    for i in 0..num_packed_values {
+        let index = ABSOLUTE_POS * num_packed_values + i;
+        if index < input.len() {
+            let param = (index / stride) % num_params;
+            let v =
+                quantize_symmetric_int8::<f32>(input[index], scale[param], range_min, range_max);
+            // Shift and combine into u32
+            v_packed |= (v[0] & mask) << (bits * i);
+        }
+    }
+    output[ABSOLUTE_POS] = v_packed;
+}
+
+pub(crate) fn quantize_grouped<R, F, I>(
+    tensor: CubeTensor<R>,
+    scale: CubeTensor<R>,
+    offset: Option<CubeTensor<R>>,
+    scheme: QuantizationScheme,
+) -> CubeTensor<R>
+where
+    R: CubeRuntime,
+    F: CubeElement,
+    I: IntElement,
+{
+    // Elements are mapped to their quantization parameters based on their row-major position
+    let tensor = kernel::into_contiguous(tensor);
+    let layout = scheme.params_layout(&tensor.shape.dims);
+    let num_elems = tensor.shape.num_elements();
+    let client = tensor.client.clone();
+
+    // Multiple values are packed in a single u32 (e.g., four int8 values), followed by the
+    // (offset and) scale of each group
+    let q_type = scheme.q_type();
+    let num_params = layout.num_params * if offset.is_some() { 2 } else { 1 };
+    let output_len = usize::div_ceil(num_elems, q_type.num_packed()) + num_params;
+    let handle = client.empty(output_len * core::mem::size_of::<u32>());
+    let output = CubeTensor::new_contiguous(
+        client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+        handle,
+        burn_tensor::DType::QFloat(scheme),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output_len, cube_dim);
+    let params_shape = [layout.num_params];
+    let params_strides = [1];
+
+    if let Some(offset) = offset {
+        let (range_min, range_max) = q_type.range();
+        unsafe {
+            quantize_grouped_affine_int8_kernel::launch_unchecked::<R>(
+                &client,
+                cube_count,
+                cube_dim,
+                tensor.as_tensor_arg::<F>(1),
+                TensorArg::from_raw_parts::<F>(&scale.handle, &params_strides, &params_shape, 1),
+                TensorArg::from_raw_parts::<I>(&offset.handle, &params_strides, &params_shape, 1),
+                ScalarArg::new(range_min as f32),
+                ScalarArg::new(range_max as f32),
+                ScalarArg::new(layout.stride as u32),
+                ScalarArg::new(layout.num_params as u32),
+                output.as_array_arg::<u32>(1),
+                q_type.bits(),
+            )
+        };
+    } else {
+        let (range_min, range_max) = q_type.symmetric_range();
+        unsafe {
+            quantize_grouped_symmetric_int8_kernel::launch_unchecked::<R>(
+                &client,
+                cube_count,
+                cube_dim,
+                tensor.as_tensor_arg::<F>(1),
+                TensorArg::from_raw_parts::<F>(&scale.handle, &params_strides, &params_shape, 1),
+                ScalarArg::new(range_min as f32),
+                ScalarArg::new(range_max as f32),
+                ScalarArg::new(layout.stride as u32),
+                ScalarArg::new(layout.num_params as u32),
+                output.as_array_arg::<u32>(1),
+                q_type.bits(),
+            )
+        };
+    }
+
+    output
+}
+
 pub(crate) fn quantize_per_tensor<R, F, I>(
     tensor: CubeTensor<R>,
     scale: CubeTensor<R>,
@@ -253,6 +421,19 @@ where
             QuantizationType::QInt8 => {
                 quantize_per_tensor::<R, F, I>(tensor, scale, offset, *scheme)
             }
+            // Sub-byte values are packed by the grouped kernels, with a single group of parameters
+            QuantizationType::QInt4
+            | QuantizationType::QUInt4
+            | QuantizationType::QInt2
+            | QuantizationType::QUInt2 => {
+                quantize_grouped::<R, F, I>(tensor, scale, offset, *scheme)
+            }
         },
+        QuantizationScheme::PerChannelAffine(dtype, _)
+        | QuantizationScheme::PerChannelSymmetric(dtype, _)
+        | QuantizationScheme::PerBlockAffine(dtype, _)
+        | QuantizationScheme::PerBlockSymmetric(_, _) => {
+            quantize_grouped::<R, F, I>(tensor, scale, offset, *scheme)
+        }
     }
 }
@@ -396,6 +396,56 @@ where
         )
     }
 
+    fn float_cumsum(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cumulative::<R, E, kernel::CumSum>(tensor, dim, exclusive, reverse)
+        )
+    }
+
+    fn float_cumprod(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cumulative::<R, E, kernel::CumProd>(tensor, dim, exclusive, reverse)
+        )
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cumulative::<R, E, kernel::CumMax>(tensor, dim, false, reverse)
+        )
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cumulative::<R, E, kernel::CumMin>(tensor, dim, false, reverse)
+        )
+    }
+
+    fn float_fft(tensor: FloatTensor<Self>, dim: usize, inverse: bool) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::fft::<R, E>(tensor, dim, inverse)
+        )
+    }
+
     fn float_exp(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         unary_basic::launch::<R, _>(tensor, |_| &BasicFloatUnaryKind::Exp)
     }
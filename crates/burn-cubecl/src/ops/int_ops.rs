@@ -219,6 +219,32 @@ where
         reduce::reduce_dim::<R, I, I, reduce::Prod>(tensor, dim, Default::default()).unwrap()
     }
 
+    fn int_cumsum(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        kernel::cumulative::<R, I, kernel::CumSum>(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cumprod(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        kernel::cumulative::<R, I, kernel::CumProd>(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cummax(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        kernel::cumulative::<R, I, kernel::CumMax>(tensor, dim, false, reverse)
+    }
+
+    fn int_cummin(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        kernel::cumulative::<R, I, kernel::CumMin>(tensor, dim, false, reverse)
+    }
+
     fn int_mean_dim(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
         reduce::reduce_dim::<R, I, I, reduce::Mean>(tensor, dim, Default::default()).unwrap()
     }
@@ -7,10 +7,11 @@ use crate::{
     CubeBackend, CubeRuntime, FloatElement, IntElement,
 };
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, InterpolateOptions,
+    attention::attention_with_weights, AttentionOptions, ConvOptions, ConvTransposeOptions,
+    DeformConv2dBackward, DeformConvOptions, GridSampleOptions, InterpolateOptions,
     MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
-use burn_tensor::ops::{FloatTensor, IntTensor};
+use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor};
 
 impl<R, F, I, BT> ModuleOps<Self> for CubeBackend<R, F, I, BT>
 where
@@ -197,4 +198,44 @@ where
     ) -> FloatTensor<Self> {
         kernel::interpolate::interpolate_backward::<R, F>(x, grad, output_size, options)
     }
+
+    fn grid_sample_2d(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        kernel::grid_sample_2d::<R, F>(x, grid, options)
+    }
+
+    fn scaled_dot_product_attention(
+        query: FloatTensor<Self>,
+        key: FloatTensor<Self>,
+        value: FloatTensor<Self>,
+        padding_mask: Option<BoolTensor<Self>>,
+        attn_mask: Option<BoolTensor<Self>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<Self> {
+        let head_dim = query.shape.dims[3];
+        let value_dim = value.shape.dims[3];
+
+        if head_dim > kernel::ATTENTION_MAX_HEAD_DIM || value_dim > kernel::ATTENTION_MAX_HEAD_DIM {
+            return attention_with_weights::<Self>(
+                query,
+                key,
+                value,
+                padding_mask,
+                attn_mask,
+                options,
+            );
+        }
+
+        kernel::scaled_dot_product_attention::<R, F, BT>(
+            query,
+            key,
+            value,
+            padding_mask,
+            attn_mask,
+            options,
+        )
+    }
 }
@@ -1,8 +1,11 @@
 use std::ops::Range;
 
 use burn_tensor::{
-    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
-    quantization::{QuantizationParametersPrimitive, QuantizationScheme, QuantizationType},
+    dequant_op_quant,
+    ops::{FloatTensor, FloatTensorOps, IntTensor, QTensorOps, QuantizedTensor},
+    quantization::{
+        QTensorPrimitive, QuantizationParametersPrimitive, QuantizationScheme, QuantizationType,
+    },
     DType, Device, Shape, TensorData,
 };
 
@@ -39,9 +42,12 @@ where
 {
     fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
         match data.dtype {
-            DType::QFloat(scheme) => match scheme {
-                QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
+            DType::QFloat(scheme) => match scheme.q_type() {
+                QuantizationType::QInt8
+                | QuantizationType::QInt4
+                | QuantizationType::QUInt4
+                | QuantizationType::QInt2
+                | QuantizationType::QUInt2 => {
                     // TensorData quantized representation is the same, with multiple quantized values
                     // packed into u32 and quantization parameters appended to the bytes
                     new_qtensor(data.as_bytes(), data.shape.clone(), scheme, device)
@@ -75,6 +81,22 @@ where
     }
 
     fn q_reshape(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        // The quantization parameters of each channel are no longer valid when the dimensions up to
+        // the channel axis are modified
+        if let DType::QFloat(
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis),
+        ) = tensor.dtype
+        {
+            if shape.num_dims() <= axis || tensor.shape.dims[..=axis] != shape.dims[..=axis] {
+                return dequant_op_quant!(
+                    ty Self,
+                    float_op |tensor| Self::float_reshape(tensor, shape),
+                    tensor
+                );
+            }
+        }
+
         super::reshape(tensor, shape)
     }
 
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, Distribution, Tensor, TensorData};
+
+    #[test]
+    fn fft_power_of_two_should_match_reference() {
+        let real = Tensor::<TestBackend, 3>::random(
+            [3, 64, 5],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let imag = Tensor::<TestBackend, 3>::random(
+            [3, 64, 5],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let real_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(real.to_data(), &Default::default());
+        let imag_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(imag.to_data(), &Default::default());
+
+        let (real, imag) = signal::fft(real, imag, 1);
+        let (real_ref, imag_ref) = signal::fft(real_ref, imag_ref, 1);
+
+        real.into_data().assert_approx_eq(&real_ref.into_data(), 3);
+        imag.into_data().assert_approx_eq(&imag_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn ifft_any_size_should_match_reference() {
+        let real =
+            Tensor::<TestBackend, 2>::random([4, 37], Distribution::Default, &Default::default());
+        let imag =
+            Tensor::<TestBackend, 2>::random([4, 37], Distribution::Default, &Default::default());
+        let real_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(real.to_data(), &Default::default());
+        let imag_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(imag.to_data(), &Default::default());
+
+        let (real, imag) = signal::ifft(real, imag, 1);
+        let (real_ref, imag_ref) = signal::ifft(real_ref, imag_ref, 1);
+
+        real.into_data().assert_approx_eq(&real_ref.into_data(), 3);
+        imag.into_data().assert_approx_eq(&imag_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn fft_long_lanes_should_match_reference() {
+        // Lanes longer than 65535 elements, with a power of two size and with any size.
+        for size in [131_072, 70_001] {
+            let mut values = vec![0.0f32; size];
+            values[1] = 1.0;
+            let real = Tensor::<TestBackend, 1>::from_data(
+                TensorData::new(values.clone(), [size]),
+                &Default::default(),
+            );
+            let imag = Tensor::<TestBackend, 1>::zeros([size], &Default::default());
+            let real_ref = Tensor::<ReferenceBackend, 1>::from_data(
+                TensorData::new(values, [size]),
+                &Default::default(),
+            );
+            let imag_ref = Tensor::<ReferenceBackend, 1>::zeros([size], &Default::default());
+
+            let (real, imag) = signal::fft(real, imag, 0);
+            let (real_ref, imag_ref) = signal::fft(real_ref, imag_ref, 0);
+
+            real.into_data().assert_approx_eq(&real_ref.into_data(), 2);
+            imag.into_data().assert_approx_eq(&imag_ref.into_data(), 2);
+        }
+    }
+}
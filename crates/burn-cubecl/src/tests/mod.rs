@@ -9,6 +9,7 @@ mod conv2d;
 mod conv3d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod fft;
 mod gather;
 mod mask_fill;
 mod mask_where;
@@ -76,6 +77,7 @@ macro_rules! testgen_all {
 
                 burn_cubecl::testgen_cast!();
                 burn_cubecl::testgen_cat!();
+                burn_cubecl::testgen_fft!();
                 burn_cubecl::testgen_clamp!();
                 burn_cubecl::testgen_unary!();
 
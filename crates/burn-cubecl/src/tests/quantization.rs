@@ -79,4 +79,130 @@ mod tests {
 
         output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
     }
+
+    #[test]
+    fn should_quantize_dequantize_per_channel_affine() {
+        let scheme = QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, 1);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 0.0, 3.2], [2.5, -0.3, 1.1]],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_quantize_dequantize_per_block_symmetric() {
+        let scheme = QuantizationScheme::PerBlockSymmetric(QuantizationType::QInt8, 3);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 0.0, 3.2], [2.5, -0.3, 1.1]],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_quantize_dequantize_affine_int4() {
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt4);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 0.0, 3.2], [2.5, -0.3, 1.1]],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_quantize_dequantize_symmetric_int4() {
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 0.0, 3.2], [2.5, -0.3, 1.1]],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_quantize_dequantize_per_channel_affine_uint4() {
+        let scheme = QuantizationScheme::PerChannelAffine(QuantizationType::QUInt4, 0);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 0.0, 3.2], [2.5, -0.3, 1.1]],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_quantize_dequantize_per_block_affine_int2() {
+        let scheme = QuantizationScheme::PerBlockAffine(QuantizationType::QInt2, 3);
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 0.0, 3.2], [2.5, -0.3, 1.1]],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
 }
@@ -0,0 +1,212 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::Dataset;
+
+/// An iterable dataset is read sequentially instead of by index, for data that doesn't fit in
+/// memory or that can't be accessed randomly, such as streamed corpora.
+///
+/// The items are split into shards that are read independently, so that the workers of a data
+/// loader can each read a subset of the shards.
+pub trait IterableDataset<I>: Send + Sync {
+    /// Gets the number of shards.
+    fn num_shards(&self) -> usize;
+
+    /// Returns an iterator over the items of the shard at the given index.
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = I> + Send>;
+
+    /// Gets the number of items in the dataset, if it is known.
+    fn len_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<D, I> IterableDataset<I> for Arc<D>
+where
+    D: IterableDataset<I>,
+{
+    fn num_shards(&self) -> usize {
+        self.as_ref().num_shards()
+    }
+
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = I> + Send> {
+        self.as_ref().read_shard(index)
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        self.as_ref().len_hint()
+    }
+}
+
+impl<I> IterableDataset<I> for Arc<dyn IterableDataset<I>> {
+    fn num_shards(&self) -> usize {
+        self.as_ref().num_shards()
+    }
+
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = I> + Send> {
+        self.as_ref().read_shard(index)
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        self.as_ref().len_hint()
+    }
+}
+
+impl<D, I> IterableDataset<I> for Box<D>
+where
+    D: IterableDataset<I>,
+{
+    fn num_shards(&self) -> usize {
+        self.as_ref().num_shards()
+    }
+
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = I> + Send> {
+        self.as_ref().read_shard(index)
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        self.as_ref().len_hint()
+    }
+}
+
+impl<I> IterableDataset<I> for Box<dyn IterableDataset<I>> {
+    fn num_shards(&self) -> usize {
+        self.as_ref().num_shards()
+    }
+
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = I> + Send> {
+        self.as_ref().read_shard(index)
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        self.as_ref().len_hint()
+    }
+}
+
+/// Reads a [dataset](Dataset) as an [iterable dataset](IterableDataset) split into contiguous
+/// shards.
+pub struct ShardedDataset<D, I> {
+    dataset: Arc<D>,
+    num_shards: usize,
+    input: PhantomData<I>,
+}
+
+impl<D, I> ShardedDataset<D, I>
+where
+    D: Dataset<I>,
+{
+    /// Creates a new iterable dataset splitting the dataset into the given number of shards.
+    pub fn new(dataset: D, num_shards: usize) -> Self {
+        Self {
+            dataset: Arc::new(dataset),
+            num_shards: num_shards.max(1),
+            input: PhantomData,
+        }
+    }
+}
+
+impl<D, I> IterableDataset<I> for ShardedDataset<D, I>
+where
+    D: Dataset<I> + 'static,
+    I: Send + Sync + 'static,
+{
+    fn num_shards(&self) -> usize {
+        self.num_shards
+    }
+
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = I> + Send> {
+        let len = self.dataset.len();
+        let start = len * index / self.num_shards;
+        let end = len * (index + 1) / self.num_shards;
+        let dataset = self.dataset.clone();
+
+        Box::new((start..end).filter_map(move |index| dataset.get(index)))
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        Some(self.dataset.len())
+    }
+}
+
+/// Shuffles the items of an iterator approximately, drawing each item at random from a buffer
+/// filled with the next items of the iterator.
+///
+/// Items are only mixed with the items close to them, so the buffer should be large compared to
+/// the correlation between consecutive items.
+pub struct ShuffleBuffer<T: Iterator> {
+    iter: T,
+    buffer: Vec<T::Item>,
+    size: usize,
+    rng: StdRng,
+}
+
+impl<T: Iterator> ShuffleBuffer<T> {
+    /// Creates a new shuffle buffer of the given size, seeded for reproducibility.
+    pub fn new(iter: T, size: usize, seed: u64) -> Self {
+        let size = size.max(1);
+
+        Self {
+            iter,
+            buffer: Vec::with_capacity(size),
+            size,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl<T: Iterator> Iterator for ShuffleBuffer<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.size {
+            match self.iter.next() {
+                Some(item) => self.buffer.push(item),
+                None => break,
+            }
+        }
+
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        let index = self.rng.random_range(0..self.buffer.len());
+        Some(self.buffer.swap_remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FakeDataset;
+    use std::collections::HashSet;
+
+    #[test]
+    fn sharded_dataset_should_cover_all_items() {
+        let dataset = FakeDataset::<String>::new(27);
+        let items = dataset.iter().collect::<Vec<_>>();
+        let sharded = ShardedDataset::new(dataset, 4);
+
+        let sharded_items = (0..sharded.num_shards())
+            .flat_map(|index| sharded.read_shard(index))
+            .collect::<Vec<_>>();
+
+        assert_eq!(sharded.len_hint(), Some(27));
+        assert_eq!(items, sharded_items);
+    }
+
+    #[test]
+    fn shuffle_buffer_should_keep_all_items() {
+        let items = ShuffleBuffer::new(0..100, 10, 42).collect::<Vec<_>>();
+
+        assert_ne!(items, (0..100).collect::<Vec<_>>());
+        assert_eq!(
+            items.iter().copied().collect::<HashSet<_>>(),
+            (0..100).collect::<HashSet<_>>()
+        );
+        assert_eq!(
+            items,
+            ShuffleBuffer::new(0..100, 10, 42).collect::<Vec<_>>()
+        );
+    }
+}
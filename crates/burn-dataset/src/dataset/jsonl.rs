@@ -0,0 +1,126 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::IterableDataset;
+
+/// Iterable dataset reading [JSON Lines](https://jsonlines.org/) shards, with one item per line.
+///
+/// Each file is a shard, and the items are only deserialized when they are read. Empty lines are
+/// ignored.
+pub struct JsonlDataset<I> {
+    shards: Vec<PathBuf>,
+    input: PhantomData<I>,
+}
+
+impl<I> JsonlDataset<I> {
+    /// Creates a new dataset from the paths of the shards, read in the given order.
+    pub fn new<P: AsRef<Path>>(
+        shards: impl IntoIterator<Item = P>,
+    ) -> Result<Self, std::io::Error> {
+        let shards = shards
+            .into_iter()
+            .map(|path| {
+                let path = path.as_ref();
+                match path.is_file() {
+                    true => Ok(path.to_path_buf()),
+                    false => Err(std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!("Shard {} not found", path.display()),
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            shards,
+            input: PhantomData,
+        })
+    }
+}
+
+impl<I> IterableDataset<I> for JsonlDataset<I>
+where
+    I: DeserializeOwned + Send + Sync + 'static,
+{
+    fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = I> + Send> {
+        let path = self.shards[index].clone();
+        let file = File::open(&path)
+            .unwrap_or_else(|err| panic!("Failed to open shard {}: {err}", path.display()));
+
+        Box::new(
+            BufReader::new(file)
+                .lines()
+                .enumerate()
+                .filter_map(move |(line_number, line)| {
+                    let line = line.unwrap_or_else(|err| {
+                        panic!("Failed to read shard {}: {err}", path.display())
+                    });
+                    if line.trim().is_empty() {
+                        return None;
+                    }
+
+                    let item = serde_json::from_str(&line).unwrap_or_else(|err| {
+                        panic!(
+                            "Failed to parse line {} of shard {}: {err}",
+                            line_number + 1,
+                            path.display()
+                        )
+                    });
+                    Some(item)
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    const SHARDS: [&str; 2] = [
+        "tests/data/jsonl_shards/shard-000.jsonl",
+        "tests/data/jsonl_shards/shard-001.jsonl",
+    ];
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Sample {
+        id: usize,
+        text: String,
+    }
+
+    #[test]
+    fn jsonl_dataset_should_read_shards() {
+        let dataset = JsonlDataset::<Sample>::new(SHARDS).unwrap();
+
+        assert_eq!(dataset.num_shards(), 2);
+
+        let ids = (0..2)
+            .map(|index| dataset.read_shard(index).map(|item| item.id).collect())
+            .collect::<Vec<Vec<_>>>();
+        assert_eq!(ids, vec![vec![0, 1, 2], vec![3, 4]]);
+
+        let first = dataset.read_shard(0).next().unwrap();
+        assert_eq!(
+            first,
+            Sample {
+                id: 0,
+                text: "zero".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn jsonl_dataset_missing_shard() {
+        assert!(JsonlDataset::<Sample>::new(["tests/data/jsonl_shards/missing.jsonl"]).is_err());
+    }
+}
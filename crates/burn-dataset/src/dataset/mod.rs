@@ -1,10 +1,16 @@
 mod base;
 mod in_memory;
+mod iterable;
 mod iterator;
+mod jsonl;
+mod webdataset;
 
 pub use base::*;
 pub use in_memory::*;
+pub use iterable::*;
 pub use iterator::*;
+pub use jsonl::*;
+pub use webdataset::*;
 
 #[cfg(any(test, feature = "fake"))]
 mod fake;
@@ -0,0 +1,275 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::IterableDataset;
+
+const BLOCK_SIZE: usize = 512;
+
+/// A sample of a [WebDataset](WebDataset), made of the consecutive files of a shard sharing the
+/// same key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebDatasetSample {
+    /// Path of the files up to the first dot of their name, for example `images/000042` for the
+    /// files `images/000042.jpg` and `images/000042.json`.
+    pub key: String,
+
+    /// Content of each file of the sample, by extension (the part of the name after the first
+    /// dot, for example `jpg` or `seg.png`).
+    pub files: HashMap<String, Vec<u8>>,
+}
+
+/// Iterable dataset reading [WebDataset](https://github.com/webdataset/webdataset)-style tar
+/// shards.
+///
+/// Each tar archive is a shard, where the files sharing the same key are grouped into a
+/// [sample](WebDatasetSample). Files of a sample must be stored consecutively in the archive.
+/// Only regular files are read; directories, links and extended headers are skipped.
+pub struct WebDataset {
+    shards: Vec<PathBuf>,
+}
+
+impl WebDataset {
+    /// Creates a new dataset from the paths of the tar shards, read in the given order.
+    pub fn new<P: AsRef<Path>>(shards: impl IntoIterator<Item = P>) -> Result<Self, io::Error> {
+        let shards = shards
+            .into_iter()
+            .map(|path| {
+                let path = path.as_ref();
+                match path.is_file() {
+                    true => Ok(path.to_path_buf()),
+                    false => Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("Shard {} not found", path.display()),
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { shards })
+    }
+}
+
+impl IterableDataset<WebDatasetSample> for WebDataset {
+    fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn read_shard(&self, index: usize) -> Box<dyn Iterator<Item = WebDatasetSample> + Send> {
+        let path = self.shards[index].clone();
+        let file = File::open(&path)
+            .unwrap_or_else(|err| panic!("Failed to open shard {}: {err}", path.display()));
+
+        Box::new(TarSamples {
+            entries: TarEntries {
+                reader: BufReader::new(file),
+            },
+            pending: None,
+            path,
+        })
+    }
+}
+
+/// Iterator over the regular files `(path, content)` of a tar archive.
+struct TarEntries<R> {
+    reader: R,
+}
+
+impl<R: Read> TarEntries<R> {
+    fn next_entry(&mut self) -> io::Result<Option<(String, Vec<u8>)>> {
+        let mut long_name = None;
+
+        loop {
+            let mut header = [0u8; BLOCK_SIZE];
+            if !self.read_block(&mut header)? || header.iter().all(|&byte| byte == 0) {
+                // End of the archive
+                return Ok(None);
+            }
+
+            let size = parse_size(&header[124..136])?;
+            let padding = (BLOCK_SIZE - size % BLOCK_SIZE) % BLOCK_SIZE;
+
+            match header[156] {
+                // Regular file
+                b'0' | 0 => {
+                    let mut content = vec![0; size];
+                    self.reader.read_exact(&mut content)?;
+                    self.skip(padding)?;
+
+                    let name = long_name.take().unwrap_or_else(|| entry_name(&header));
+                    return Ok(Some((name, content)));
+                }
+                // GNU long name of the next entry
+                b'L' => {
+                    let mut name = vec![0; size];
+                    self.reader.read_exact(&mut name)?;
+                    self.skip(padding)?;
+                    long_name = Some(null_terminated(&name));
+                }
+                _ => self.skip(size + padding)?,
+            }
+        }
+    }
+
+    /// Reads a block, returning false at the end of the reader.
+    fn read_block(&mut self, block: &mut [u8; BLOCK_SIZE]) -> io::Result<bool> {
+        match self.reader.read_exact(block) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn skip(&mut self, num_bytes: usize) -> io::Result<()> {
+        io::copy(
+            &mut (&mut self.reader).take(num_bytes as u64),
+            &mut io::sink(),
+        )?;
+        Ok(())
+    }
+}
+
+/// Name of the entry, including the prefix of the ustar format.
+fn entry_name(header: &[u8; BLOCK_SIZE]) -> String {
+    let name = null_terminated(&header[0..100]);
+
+    if &header[257..262] == b"ustar" {
+        let prefix = null_terminated(&header[345..500]);
+        if !prefix.is_empty() {
+            return format!("{prefix}/{name}");
+        }
+    }
+
+    name
+}
+
+fn null_terminated(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+/// Parses the size of an entry, stored in octal or in base-256 for large files.
+fn parse_size(field: &[u8]) -> io::Result<usize> {
+    if field[0] & 0x80 != 0 {
+        let size = field[1..]
+            .iter()
+            .fold((field[0] & 0x7f) as u64, |size, &byte| {
+                (size << 8) | byte as u64
+            });
+        return Ok(size as usize);
+    }
+
+    let digits = null_terminated(field);
+    let digits = digits.trim_matches(|c: char| c == ' ' || c == '\0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+
+    usize::from_str_radix(digits, 8).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid tar entry size: {err}"),
+        )
+    })
+}
+
+/// Splits the path of a file into its key and its extension, at the first dot of its name.
+fn split_key(path: &str) -> (&str, &str) {
+    let name_start = path.rfind('/').map(|i| i + 1).unwrap_or(0);
+
+    match path[name_start..].find('.') {
+        Some(dot) => (&path[..name_start + dot], &path[name_start + dot + 1..]),
+        None => (path, ""),
+    }
+}
+
+/// Iterator grouping the consecutive files of a tar archive by key.
+struct TarSamples<R> {
+    entries: TarEntries<R>,
+    pending: Option<(String, Vec<u8>)>,
+    path: PathBuf,
+}
+
+impl<R: Read> TarSamples<R> {
+    fn next_entry(&mut self) -> Option<(String, Vec<u8>)> {
+        self.entries
+            .next_entry()
+            .unwrap_or_else(|err| panic!("Failed to read shard {}: {err}", self.path.display()))
+    }
+}
+
+impl<R: Read> Iterator for TarSamples<R> {
+    type Item = WebDatasetSample;
+
+    fn next(&mut self) -> Option<WebDatasetSample> {
+        let (name, content) = self.pending.take().or_else(|| self.next_entry())?;
+        let (key, extension) = split_key(&name);
+        let mut sample = WebDatasetSample {
+            key: key.to_string(),
+            files: HashMap::from([(extension.to_string(), content)]),
+        };
+
+        while let Some((name, content)) = self.next_entry() {
+            let (key, extension) = split_key(&name);
+            if key != sample.key {
+                self.pending = Some((name, content));
+                break;
+            }
+            sample.files.insert(extension.to_string(), content);
+        }
+
+        Some(sample)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHARDS: [&str; 2] = [
+        "tests/data/webdataset_shards/shard-000.tar",
+        "tests/data/webdataset_shards/shard-001.tar",
+    ];
+
+    #[test]
+    fn webdataset_should_group_files_by_key() {
+        let dataset = WebDataset::new(SHARDS).unwrap();
+        assert_eq!(dataset.num_shards(), 2);
+
+        let samples = dataset.read_shard(0).collect::<Vec<_>>();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].key, "samples/000000");
+        assert_eq!(samples[0].files["txt"], b"zero".to_vec());
+        assert_eq!(samples[0].files["cls"], b"0".to_vec());
+        assert_eq!(samples[1].key, "samples/000001");
+        assert_eq!(samples[1].files["txt"], b"one".to_vec());
+
+        // Shard with GNU long names
+        let samples = dataset.read_shard(1).collect::<Vec<_>>();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].key.len(), 127);
+        assert!(samples[0].key.ends_with("nested_/000002"));
+        assert_eq!(samples[0].files.len(), 2);
+        assert_eq!(samples[0].files["seg.txt"], b"two".to_vec());
+    }
+
+    #[test]
+    fn split_key_at_first_dot_of_name() {
+        assert_eq!(split_key("a.b/000.seg.png"), ("a.b/000", "seg.png"));
+        assert_eq!(split_key("000.jpg"), ("000", "jpg"));
+        assert_eq!(split_key("readme"), ("readme", ""));
+    }
+
+    #[test]
+    fn parse_octal_and_base256_sizes() {
+        assert_eq!(parse_size(b"00000001750\0").unwrap(), 1000);
+        let mut field = [0u8; 12];
+        field[0] = 0x80;
+        field[10] = 0x01;
+        field[11] = 0x00;
+        assert_eq!(parse_size(&field).unwrap(), 256);
+    }
+}
@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::ImageLoaderError;
+
+/// Segmentation of a COCO object, decoded into a binary mask when the item is loaded.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub(crate) enum CocoSegmentation {
+    /// Polygons `[x1, y1, x2, y2, ...]` in pixel coordinates, for an image `[height, width]`.
+    Polygons {
+        size: [usize; 2],
+        polygons: Vec<Vec<f32>>,
+    },
+    /// Run-length encoding of a mask `[height, width]` in column-major order, starting with a
+    /// run of background pixels.
+    Rle {
+        size: [usize; 2],
+        counts: Vec<usize>,
+    },
+}
+
+impl CocoSegmentation {
+    /// Parse the `segmentation` field of an annotation, either polygons or a run-length encoding
+    /// (uncompressed or compressed), for an image of size `[height, width]`.
+    pub(crate) fn parse(value: &Value, size: [usize; 2]) -> Result<Self, ImageLoaderError> {
+        if let Some(polygons) = value.as_array() {
+            let polygons = polygons
+                .iter()
+                .map(|polygon| {
+                    polygon
+                        .as_array()
+                        .ok_or_else(|| {
+                            ImageLoaderError::ParsingError("invalid segmentation polygon".into())
+                        })?
+                        .iter()
+                        .map(|v| {
+                            v.as_f64().map(|v| v as f32).ok_or_else(|| {
+                                ImageLoaderError::ParsingError("invalid polygon value".into())
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            return Ok(Self::Polygons { size, polygons });
+        }
+
+        let rle_size = value["size"]
+            .as_array()
+            .filter(|size| size.len() == 2)
+            .and_then(|size| Some([size[0].as_u64()? as usize, size[1].as_u64()? as usize]))
+            .ok_or_else(|| ImageLoaderError::ParsingError("invalid RLE size".into()))?;
+
+        let counts = match &value["counts"] {
+            Value::Array(counts) => counts
+                .iter()
+                .map(|v| {
+                    v.as_u64()
+                        .map(|v| v as usize)
+                        .ok_or_else(|| ImageLoaderError::ParsingError("invalid RLE count".into()))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Value::String(counts) => rle_from_string(counts)?,
+            _ => {
+                return Err(ImageLoaderError::ParsingError(
+                    "invalid segmentation".to_string(),
+                ))
+            }
+        };
+
+        if counts.iter().sum::<usize>() != rle_size[0] * rle_size[1] {
+            return Err(ImageLoaderError::ParsingError(
+                "RLE counts do not match the mask size".to_string(),
+            ));
+        }
+
+        Ok(Self::Rle {
+            size: rle_size,
+            counts,
+        })
+    }
+
+    /// Decode the segmentation into a binary mask in row-major order, with 1 for the pixels of
+    /// the object.
+    pub(crate) fn to_mask(&self) -> Vec<usize> {
+        match self {
+            Self::Polygons { size, polygons } => rasterize_polygons(polygons, *size),
+            Self::Rle { size, counts } => decode_rle(counts, *size),
+        }
+    }
+}
+
+/// Rasterize the polygons with the even-odd rule, a pixel being inside a polygon when its center
+/// is. The mask is the union of the polygons.
+fn rasterize_polygons(polygons: &[Vec<f32>], [height, width]: [usize; 2]) -> Vec<usize> {
+    let mut mask = vec![0; height * width];
+    let mut crossings = Vec::new();
+
+    for polygon in polygons {
+        let points = polygon
+            .chunks_exact(2)
+            .map(|p| (p[0], p[1]))
+            .collect::<Vec<_>>();
+        if points.len() < 3 {
+            continue;
+        }
+
+        for y in 0..height {
+            let center = y as f32 + 0.5;
+
+            // Abscissas where the edges of the polygon cross the row of pixel centers
+            crossings.clear();
+            for (i, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(i + 1) % points.len()];
+                if (y0 <= center) != (y1 <= center) {
+                    crossings.push(x0 + (center - y0) * (x1 - x0) / (y1 - y0));
+                }
+            }
+            crossings.sort_by(|a, b| a.total_cmp(b));
+
+            for span in crossings.chunks_exact(2) {
+                // Pixels whose center lies in [start, end)
+                let start = (span[0] - 0.5).ceil().clamp(0.0, width as f32) as usize;
+                let end = (span[1] - 0.5).ceil().clamp(0.0, width as f32) as usize;
+                mask[y * width + start..y * width + end].fill(1);
+            }
+        }
+    }
+
+    mask
+}
+
+/// Decode the column-major run-length encoding into a row-major mask.
+fn decode_rle(counts: &[usize], [height, width]: [usize; 2]) -> Vec<usize> {
+    let mut mask = vec![0; height * width];
+    let mut position = 0;
+
+    for (i, &count) in counts.iter().enumerate() {
+        // Runs alternate between background and object, starting with background
+        if i % 2 == 1 {
+            for p in position..position + count {
+                mask[(p % height) * width + p / height] = 1;
+            }
+        }
+        position += count;
+    }
+
+    mask
+}
+
+/// Decode the compressed counts of a COCO run-length encoding.
+///
+/// Each count is stored as 5-bit groups offset in the printable ASCII range, with a
+/// continuation bit and a sign bit on the last group. From the third count onward, the values are
+/// stored as the difference with the count two positions before.
+fn rle_from_string(counts: &str) -> Result<Vec<usize>, ImageLoaderError> {
+    let bytes = counts.as_bytes();
+    let mut decoded: Vec<i64> = Vec::new();
+    let mut p = 0;
+
+    while p < bytes.len() {
+        let mut value = 0i64;
+        let mut k = 0;
+        loop {
+            let c = bytes[p]
+                .checked_sub(48)
+                .filter(|&c| c < 64)
+                .ok_or_else(|| ImageLoaderError::ParsingError("invalid RLE string".into()))?
+                as i64;
+            value |= (c & 0x1f) << (5 * k);
+            p += 1;
+            k += 1;
+
+            if c & 0x20 == 0 {
+                if c & 0x10 != 0 {
+                    value |= -1 << (5 * k);
+                }
+                break;
+            }
+            if p >= bytes.len() {
+                return Err(ImageLoaderError::ParsingError(
+                    "truncated RLE string".to_string(),
+                ));
+            }
+        }
+
+        if decoded.len() > 2 {
+            value += decoded[decoded.len() - 2];
+        }
+        decoded.push(value);
+    }
+
+    decoded
+        .into_iter()
+        .map(|count| {
+            usize::try_from(count)
+                .map_err(|_| ImageLoaderError::ParsingError("negative RLE count".to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn rasterize_square_polygon() {
+        // Square covering the centers of the pixels (1, 1) to (2, 2)
+        let polygons = vec![vec![1.0, 1.0, 3.0, 1.0, 3.0, 3.0, 1.0, 3.0]];
+
+        #[rustfmt::skip]
+        let expected = vec![
+            0, 0, 0, 0,
+            0, 1, 1, 0,
+            0, 1, 1, 0,
+            0, 0, 0, 0,
+        ];
+        assert_eq!(rasterize_polygons(&polygons, [4, 4]), expected);
+    }
+
+    #[test]
+    pub fn rasterize_triangle_polygon() {
+        let polygons = vec![vec![0.0, 0.0, 4.0, 0.0, 0.0, 4.0]];
+
+        #[rustfmt::skip]
+        let expected = vec![
+            1, 1, 1, 0,
+            1, 1, 0, 0,
+            1, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        assert_eq!(rasterize_polygons(&polygons, [4, 4]), expected);
+    }
+
+    #[test]
+    pub fn decode_uncompressed_rle() {
+        // Column-major: the second column is the object
+        let counts = vec![3, 3, 3];
+
+        #[rustfmt::skip]
+        let expected = vec![
+            0, 1, 0,
+            0, 1, 0,
+            0, 1, 0,
+        ];
+        assert_eq!(decode_rle(&counts, [3, 3]), expected);
+    }
+
+    #[test]
+    pub fn decode_compressed_rle() {
+        assert_eq!(rle_from_string("23112").unwrap(), vec![2, 3, 1, 4, 3]);
+        assert_eq!(rle_from_string("k1;").unwrap(), vec![59, 11]);
+    }
+
+    #[test]
+    pub fn parse_rle_segmentation_size_mismatch() {
+        let value = serde_json::json!({"size": [2, 2], "counts": [1, 2]});
+        assert!(CocoSegmentation::parse(&value, [2, 2]).is_err());
+    }
+}
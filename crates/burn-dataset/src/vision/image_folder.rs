@@ -1,3 +1,7 @@
+use super::coco::CocoSegmentation;
+use super::voc::{
+    parse_voc_annotation, parse_voc_split, voc_image_path, voc_mask_to_vec_usize, VOC_CLASSES,
+};
 use crate::transform::{Mapper, MapperDataset};
 use crate::{Dataset, InMemDataset};
 
@@ -71,6 +75,8 @@ pub enum Annotation {
     BoundingBoxes(Vec<BoundingBox>),
     /// Segmentation mask.
     SegmentationMask(SegmentationMask),
+    /// Object instances with their segmentation masks.
+    Instances(Vec<InstanceAnnotation>),
 }
 
 /// Segmentation mask annotation.
@@ -92,6 +98,19 @@ pub struct BoundingBox {
     pub label: usize,
 }
 
+/// Object instance annotation, for instance segmentation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceAnnotation {
+    /// Object bounding box and class label.
+    pub bbox: BoundingBox,
+
+    /// Binary mask of the object with the size of the image, set to 1 for the object pixels.
+    pub mask: SegmentationMask,
+
+    /// Whether the annotation covers a crowd of objects.
+    pub is_crowd: bool,
+}
+
 /// Image dataset item.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ImageDatasetItem {
@@ -112,6 +131,16 @@ enum AnnotationRaw {
     MultiLabel(Vec<String>),
     BoundingBoxes(Vec<BoundingBox>),
     SegmentationMask(PathBuf),
+    Instances(Vec<InstanceRaw>),
+    VocSegmentationMask(PathBuf),
+}
+
+/// Raw object instance, the segmentation being decoded when the item is loaded.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+struct InstanceRaw {
+    bbox: BoundingBox,
+    segmentation: CocoSegmentation,
+    is_crowd: bool,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -188,6 +217,22 @@ fn parse_image_annotation(
             })
         }
         AnnotationRaw::BoundingBoxes(v) => Annotation::BoundingBoxes(v.clone()),
+        AnnotationRaw::Instances(v) => Annotation::Instances(
+            v.iter()
+                .map(|instance| InstanceAnnotation {
+                    bbox: instance.bbox.clone(),
+                    mask: SegmentationMask {
+                        mask: instance.segmentation.to_mask(),
+                    },
+                    is_crowd: instance.is_crowd,
+                })
+                .collect(),
+        ),
+        AnnotationRaw::VocSegmentationMask(mask_path) => {
+            Annotation::SegmentationMask(SegmentationMask {
+                mask: voc_mask_to_vec_usize(mask_path),
+            })
+        }
     }
 }
 
@@ -227,11 +272,88 @@ fn parse_coco_classes(
     Ok(classes)
 }
 
-/// Retrieve annotations from COCO JSON
-fn parse_coco_bbox_annotations(
+/// Mapping of the COCO category IDs to class labels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CocoCategoryMapping {
+    /// Use the category IDs of the annotations as labels.
+    #[default]
+    CategoryId,
+    /// Map the categories, sorted by ID, to contiguous labels starting at 0. For example, the 80
+    /// categories of COCO 2017 have IDs ranging from 1 to 90.
+    Contiguous,
+}
+
+impl CocoCategoryMapping {
+    /// Label of each category ID, `None` when the IDs are used as labels.
+    fn labels(&self, classes: &HashMap<String, usize>) -> Option<HashMap<usize, usize>> {
+        match self {
+            CocoCategoryMapping::CategoryId => None,
+            CocoCategoryMapping::Contiguous => {
+                let mut ids = classes.values().copied().collect::<Vec<_>>();
+                ids.sort();
+                Some(
+                    ids.into_iter()
+                        .enumerate()
+                        .map(|(label, id)| (id, label))
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+/// Options to load a COCO dataset.
+#[derive(Debug, Clone, Default)]
+pub struct CocoOptions {
+    /// Mapping of the category IDs to class labels.
+    pub category_mapping: CocoCategoryMapping,
+
+    /// Skip the crowd annotations (`iscrowd` set to 1), which cover groups of objects.
+    pub skip_crowd: bool,
+}
+
+/// Retrieve the size `[height, width]` of the images from the COCO JSON
+fn parse_coco_image_sizes(
+    json: &serde_json::Value,
+) -> Result<HashMap<u64, [usize; 2]>, ImageLoaderError> {
+    let mut sizes = HashMap::new();
+
+    if let Some(json_images) = json["images"].as_array() {
+        for image in json_images {
+            let image_id = image["id"].as_u64().ok_or_else(|| {
+                ImageLoaderError::ParsingError("Invalid image ID in image list".to_string())
+            })?;
+
+            let [height, width] = ["height", "width"].map(|key| image[key].as_u64());
+            let (Some(height), Some(width)) = (height, width) else {
+                return Err(ImageLoaderError::ParsingError(format!(
+                    "Invalid size for image {}",
+                    image_id
+                )));
+            };
+
+            sizes.insert(image_id, [height as usize, width as usize]);
+        }
+    }
+
+    Ok(sizes)
+}
+
+/// Retrieve annotations from COCO JSON, as bounding boxes or as object instances with their
+/// segmentation.
+fn parse_coco_annotations(
     json: &serde_json::Value,
+    classes: &HashMap<String, usize>,
+    options: &CocoOptions,
+    instances: bool,
 ) -> Result<HashMap<u64, AnnotationRaw>, ImageLoaderError> {
     let mut annotations = HashMap::new();
+    let labels = options.category_mapping.labels(classes);
+    let sizes = if instances {
+        parse_coco_image_sizes(json)?
+    } else {
+        HashMap::new()
+    };
 
     if let Some(json_annotations) = json["annotations"].as_array() {
         for annotation in json_annotations {
@@ -239,6 +361,11 @@ fn parse_coco_bbox_annotations(
                 ImageLoaderError::ParsingError("Invalid image ID in annotation".into())
             })?;
 
+            let is_crowd = annotation["iscrowd"].as_u64().unwrap_or(0) == 1;
+            if is_crowd && options.skip_crowd {
+                continue;
+            }
+
             let class_id = annotation["category_id"]
                 .as_u64()
                 .ok_or_else(|| {
@@ -252,6 +379,16 @@ fn parse_coco_bbox_annotations(
                     })
                 })?;
 
+            let label = match &labels {
+                Some(labels) => *labels.get(&class_id).ok_or_else(|| {
+                    ImageLoaderError::ParsingError(format!(
+                        "Unknown class ID {} in annotations",
+                        class_id
+                    ))
+                })?,
+                None => class_id,
+            };
+
             let bbox_coords = annotation["bbox"]
                 .as_array()
                 .ok_or_else(|| ImageLoaderError::ParsingError("missing bbox array".to_string()))?
@@ -279,17 +416,40 @@ fn parse_coco_bbox_annotations(
                     bbox_coords[2],
                     bbox_coords[3],
                 ],
-                label: class_id,
+                label,
             };
 
-            annotations
-                .entry(image_id)
-                .and_modify(|entry| {
-                    if let AnnotationRaw::BoundingBoxes(ref mut bboxes) = entry {
-                        bboxes.push(bbox.clone());
-                    }
-                })
-                .or_insert_with(|| AnnotationRaw::BoundingBoxes(vec![bbox]));
+            if instances {
+                let size = sizes.get(&image_id).ok_or_else(|| {
+                    ImageLoaderError::ParsingError(format!(
+                        "annotation for unknown image {}",
+                        image_id
+                    ))
+                })?;
+                let instance = InstanceRaw {
+                    bbox,
+                    segmentation: CocoSegmentation::parse(&annotation["segmentation"], *size)?,
+                    is_crowd,
+                };
+
+                annotations
+                    .entry(image_id)
+                    .and_modify(|entry| {
+                        if let AnnotationRaw::Instances(ref mut instances) = entry {
+                            instances.push(instance.clone());
+                        }
+                    })
+                    .or_insert_with(|| AnnotationRaw::Instances(vec![instance]));
+            } else {
+                annotations
+                    .entry(image_id)
+                    .and_modify(|entry| {
+                        if let AnnotationRaw::BoundingBoxes(ref mut bboxes) = entry {
+                            bboxes.push(bbox.clone());
+                        }
+                    })
+                    .or_insert_with(|| AnnotationRaw::BoundingBoxes(vec![bbox]));
+            }
         }
     }
 
@@ -307,6 +467,7 @@ fn parse_coco_images<P: AsRef<Path>>(
     images_path: &P,
     mut annotations: HashMap<u64, AnnotationRaw>,
     json: &serde_json::Value,
+    empty_annotation: AnnotationRaw,
 ) -> Result<Vec<ImageDatasetItemRaw>, ImageLoaderError> {
     let mut images = Vec::new();
     if let Some(json_images) = json["images"].as_array() {
@@ -332,7 +493,7 @@ fn parse_coco_images<P: AsRef<Path>>(
 
             let annotation = annotations
                 .remove(&image_id)
-                .unwrap_or_else(|| AnnotationRaw::BoundingBoxes(Vec::new()));
+                .unwrap_or_else(|| empty_annotation.clone());
 
             images.push(ImageDatasetItemRaw {
                 annotation,
@@ -649,6 +810,140 @@ impl ImageFolderDataset {
     pub fn new_coco_detection<A: AsRef<Path>, I: AsRef<Path>>(
         annotations_json: A,
         images_path: I,
+    ) -> Result<Self, ImageLoaderError> {
+        Self::new_coco_detection_with(annotations_json, images_path, CocoOptions::default())
+    }
+
+    /// Create a COCO detection dataset based on the annotations JSON and image directory, with
+    /// the category mapping and crowd handling of the options.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotations_json` - Path to the JSON file containing annotations in COCO format (for
+    ///   example instances_train2017.json).
+    ///
+    /// * `images_path` - Path containing the images matching the annotations JSON.
+    ///
+    /// * `options` - Options to load the annotations.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_coco_detection_with<A: AsRef<Path>, I: AsRef<Path>>(
+        annotations_json: A,
+        images_path: I,
+        options: CocoOptions,
+    ) -> Result<Self, ImageLoaderError> {
+        Self::new_coco(annotations_json, images_path, options, false)
+    }
+
+    /// Create a COCO instance segmentation dataset based on the annotations JSON and image
+    /// directory.
+    ///
+    /// Each item is annotated with its [instances](Annotation::Instances). The segmentation
+    /// polygons are rasterized and the run-length encoded masks are decoded when the item is
+    /// loaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotations_json` - Path to the JSON file containing annotations in COCO format (for
+    ///   example instances_train2017.json).
+    ///
+    /// * `images_path` - Path containing the images matching the annotations JSON.
+    ///
+    /// * `options` - Options to load the annotations.
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_coco_instance_segmentation<A: AsRef<Path>, I: AsRef<Path>>(
+        annotations_json: A,
+        images_path: I,
+        options: CocoOptions,
+    ) -> Result<Self, ImageLoaderError> {
+        Self::new_coco(annotations_json, images_path, options, true)
+    }
+
+    /// Create a Pascal VOC detection dataset from the folder of a VOC release.
+    ///
+    /// Labels are indices of [VOC_CLASSES](VOC_CLASSES), and boxes are converted to 0-based
+    /// coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Folder containing the `Annotations`, `ImageSets` and `JPEGImages` folders (for
+    ///   example VOCdevkit/VOC2012).
+    ///
+    /// * `split` - Image set listed in `ImageSets/Main` (for example train or val).
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_voc_detection<P: AsRef<Path>>(
+        root: P,
+        split: &str,
+    ) -> Result<Self, ImageLoaderError> {
+        let root = root.as_ref();
+        let items = parse_voc_split(root, "Main", split)?
+            .iter()
+            .map(|id| {
+                let annotation_path = root.join("Annotations").join(format!("{id}.xml"));
+                let annotation =
+                    AnnotationRaw::BoundingBoxes(parse_voc_annotation(annotation_path)?);
+
+                Ok(ImageDatasetItemRaw::new(
+                    voc_image_path(root, id)?,
+                    annotation,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::with_items(items, &VOC_CLASSES)
+    }
+
+    /// Create a Pascal VOC semantic segmentation dataset from the folder of a VOC release.
+    ///
+    /// The colors of the masks are mapped back to the labels of [VOC_CLASSES](VOC_CLASSES), with
+    /// 255 for object boundaries and ambiguous pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Folder containing the `ImageSets`, `JPEGImages` and `SegmentationClass`
+    ///   folders (for example VOCdevkit/VOC2012).
+    ///
+    /// * `split` - Image set listed in `ImageSets/Segmentation` (for example train or val).
+    ///
+    /// # Returns
+    /// A new dataset instance.
+    pub fn new_voc_segmentation<P: AsRef<Path>>(
+        root: P,
+        split: &str,
+    ) -> Result<Self, ImageLoaderError> {
+        let root = root.as_ref();
+        let items = parse_voc_split(root, "Segmentation", split)?
+            .iter()
+            .map(|id| {
+                let mask_path = root.join("SegmentationClass").join(format!("{id}.png"));
+                if !mask_path.exists() {
+                    return Err(ImageLoaderError::IOError(format!(
+                        "Mask {} not found",
+                        mask_path.display()
+                    )));
+                }
+
+                Ok(ImageDatasetItemRaw::new(
+                    voc_image_path(root, id)?,
+                    AnnotationRaw::VocSegmentationMask(mask_path),
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::with_items(items, &VOC_CLASSES)
+    }
+
+    /// Create a COCO dataset annotated with bounding boxes or with object instances.
+    fn new_coco<A: AsRef<Path>, I: AsRef<Path>>(
+        annotations_json: A,
+        images_path: I,
+        options: CocoOptions,
+        instances: bool,
     ) -> Result<Self, ImageLoaderError> {
         let file = fs::File::open(annotations_json)
             .map_err(|e| ImageLoaderError::IOError(format!("Failed to open annotations: {}", e)))?;
@@ -657,8 +952,13 @@ impl ImageFolderDataset {
         })?;
 
         let classes = parse_coco_classes(&json)?;
-        let annotations = parse_coco_bbox_annotations(&json)?;
-        let items = parse_coco_images(&images_path, annotations, &json)?;
+        let annotations = parse_coco_annotations(&json, &classes, &options, instances)?;
+        let empty_annotation = if instances {
+            AnnotationRaw::Instances(Vec::new())
+        } else {
+            AnnotationRaw::BoundingBoxes(Vec::new())
+        };
+        let items = parse_coco_images(&images_path, annotations, &json, empty_annotation)?;
         let dataset = InMemDataset::new(items);
         let mapper = PathToImageDatasetItem { classes };
         let dataset = MapperDataset::new(dataset, mapper);
@@ -719,6 +1019,8 @@ mod tests {
     const SEGMASK_ROOT: &str = "tests/data/segmask_folder";
     const COCO_JSON: &str = "tests/data/dataset_coco.json";
     const COCO_IMAGES: &str = "tests/data/image_folder_coco";
+    const COCO_SEGMENTATION_JSON: &str = "tests/data/dataset_coco_segmentation.json";
+    const VOC_ROOT: &str = "tests/data/voc_folder";
 
     #[test]
     pub fn image_folder_dataset() {
@@ -1071,4 +1373,157 @@ mod tests {
             }
         }
     }
+
+    fn coco_instances(options: CocoOptions) -> HashMap<String, Vec<InstanceAnnotation>> {
+        let dataset = ImageFolderDataset::new_coco_instance_segmentation(
+            COCO_SEGMENTATION_JSON,
+            COCO_IMAGES,
+            options,
+        )
+        .unwrap();
+        assert_eq!(dataset.len(), 3);
+
+        dataset
+            .iter()
+            .map(|item| {
+                let file_name = Path::new(&item.image_path)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned();
+                match item.annotation {
+                    Annotation::Instances(instances) => (file_name, instances),
+                    _ => panic!("unexpected annotation"),
+                }
+            })
+            .collect()
+    }
+
+    fn mask_area(instance: &InstanceAnnotation) -> usize {
+        assert_eq!(instance.mask.mask.len(), 32 * 32);
+        instance.mask.mask.iter().sum()
+    }
+
+    #[test]
+    pub fn coco_instance_segmentation_dataset() {
+        let items = coco_instances(CocoOptions {
+            category_mapping: CocoCategoryMapping::Contiguous,
+            skip_crowd: false,
+        });
+
+        // Polygon and compressed RLE crowd annotation
+        let instances = &items["two_dots_and_triangle.jpg"];
+        assert_eq!(instances.len(), 2);
+        assert_eq!(instances[0].bbox.label, 0);
+        assert!(!instances[0].is_crowd);
+        assert_eq!(mask_area(&instances[0]), 64);
+        assert_eq!(instances[0].mask.mask[4 * 32 + 4], 1);
+        assert_eq!(instances[0].mask.mask[12 * 32 + 12], 0);
+        assert_eq!(instances[1].bbox.label, 1);
+        assert!(instances[1].is_crowd);
+        assert_eq!(mask_area(&instances[1]), 20);
+        // Column-major runs: the object starts at row 4 of column 3
+        assert_eq!(instances[1].mask.mask[4 * 32 + 3], 1);
+
+        // Uncompressed RLE annotation covering the third column
+        let instances = &items["dot_triangle.jpg"];
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].bbox.label, 1);
+        assert_eq!(mask_area(&instances[0]), 32);
+        assert!((0..32).all(|row| instances[0].mask.mask[row * 32 + 2] == 1));
+
+        // Image without annotations
+        assert!(items["one_dot.jpg"].is_empty());
+    }
+
+    #[test]
+    pub fn coco_instance_segmentation_dataset_skip_crowd() {
+        let items = coco_instances(CocoOptions {
+            skip_crowd: true,
+            ..Default::default()
+        });
+
+        let instances = &items["two_dots_and_triangle.jpg"];
+        assert_eq!(instances.len(), 1);
+        assert!(!instances[0].is_crowd);
+        // Category IDs are kept as labels
+        assert_eq!(instances[0].bbox.label, 1);
+    }
+
+    #[test]
+    pub fn coco_detection_dataset_contiguous_categories() {
+        let dataset = ImageFolderDataset::new_coco_detection_with(
+            COCO_SEGMENTATION_JSON,
+            COCO_IMAGES,
+            CocoOptions {
+                category_mapping: CocoCategoryMapping::Contiguous,
+                skip_crowd: true,
+            },
+        )
+        .unwrap();
+
+        let labels = dataset
+            .iter()
+            .flat_map(|item| match item.annotation {
+                Annotation::BoundingBoxes(v) => v.into_iter().map(|bbox| bbox.label),
+                _ => panic!("unexpected annotation"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec![0, 1]);
+    }
+
+    #[test]
+    pub fn voc_detection_dataset() {
+        let dataset = ImageFolderDataset::new_voc_detection(VOC_ROOT, "train").unwrap();
+        assert_eq!(dataset.len(), 2);
+        assert_eq!(dataset.get(2), None);
+
+        // Items follow the order of the image set
+        assert_eq!(
+            dataset.get(0).unwrap().annotation,
+            Annotation::BoundingBoxes(vec![
+                BoundingBox {
+                    coords: [3.0, 17.0, 11.0, 12.0],
+                    label: 15, // person
+                },
+                BoundingBox {
+                    coords: [15.0, 3.0, 13.0, 12.0],
+                    label: 7, // car
+                },
+            ])
+        );
+        assert_eq!(
+            dataset.get(1).unwrap().annotation,
+            Annotation::BoundingBoxes(vec![BoundingBox {
+                coords: [10.0, 9.0, 11.0, 12.0],
+                label: 12, // dog
+            }])
+        );
+    }
+
+    #[test]
+    pub fn voc_segmentation_dataset() {
+        let dataset = ImageFolderDataset::new_voc_segmentation(VOC_ROOT, "train").unwrap();
+        assert_eq!(dataset.len(), 1);
+
+        let item = dataset.get(0).unwrap();
+        assert!(item.image_path.ends_with("one_dot.jpg"));
+
+        let mut expected = vec![0; 32 * 32];
+        for x in 10..20 {
+            expected[9 * 32 + x] = 255; // boundary
+            for y in 10..20 {
+                expected[y * 32 + x] = 12; // dog
+            }
+        }
+        assert_eq!(
+            item.annotation,
+            Annotation::SegmentationMask(SegmentationMask { mask: expected })
+        );
+    }
+
+    #[test]
+    pub fn voc_dataset_missing_split() {
+        assert!(ImageFolderDataset::new_voc_detection(VOC_ROOT, "val").is_err());
+    }
 }
@@ -1,5 +1,8 @@
+mod coco;
 mod image_folder;
 mod mnist;
+mod voc;
 
 pub use image_folder::*;
 pub use mnist::*;
+pub use voc::VOC_CLASSES;
@@ -0,0 +1,198 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{BoundingBox, ImageLoaderError};
+
+/// Pascal VOC class names, indexed by label.
+///
+/// The background is label 0, so the object classes are labeled from 1 to 20 for both detection
+/// and segmentation. Segmentation masks also use the label 255 for object boundaries and
+/// ambiguous pixels.
+pub const VOC_CLASSES: [&str; 21] = [
+    "background",
+    "aeroplane",
+    "bicycle",
+    "bird",
+    "boat",
+    "bottle",
+    "bus",
+    "car",
+    "cat",
+    "chair",
+    "cow",
+    "diningtable",
+    "dog",
+    "horse",
+    "motorbike",
+    "person",
+    "pottedplant",
+    "sheep",
+    "sofa",
+    "train",
+    "tvmonitor",
+];
+
+/// Label of the boundaries and ambiguous pixels of the segmentation masks.
+const VOC_VOID_LABEL: usize = 255;
+
+#[derive(Deserialize, Debug)]
+struct VocAnnotation {
+    #[serde(default, rename = "object")]
+    objects: Vec<VocObject>,
+}
+
+#[derive(Deserialize, Debug)]
+struct VocObject {
+    name: String,
+    bndbox: VocBox,
+}
+
+#[derive(Deserialize, Debug)]
+struct VocBox {
+    xmin: f32,
+    ymin: f32,
+    xmax: f32,
+    ymax: f32,
+}
+
+/// Read the image IDs of a split listed in `ImageSets/<task>/<split>.txt`.
+pub(crate) fn parse_voc_split<P: AsRef<Path>>(
+    root: P,
+    task: &str,
+    split: &str,
+) -> Result<Vec<String>, ImageLoaderError> {
+    let path = root
+        .as_ref()
+        .join("ImageSets")
+        .join(task)
+        .join(format!("{split}.txt"));
+    let content = fs::read_to_string(&path).map_err(|e| {
+        ImageLoaderError::IOError(format!("Failed to read {}: {}", path.display(), e))
+    })?;
+
+    let ids = content
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        return Err(ImageLoaderError::ParsingError(format!(
+            "No images found in {}",
+            path.display()
+        )));
+    }
+
+    Ok(ids)
+}
+
+/// Path of the JPEG image with the given ID.
+pub(crate) fn voc_image_path<P: AsRef<Path>>(
+    root: P,
+    id: &str,
+) -> Result<PathBuf, ImageLoaderError> {
+    let path = root.as_ref().join("JPEGImages").join(format!("{id}.jpg"));
+
+    if !path.exists() {
+        return Err(ImageLoaderError::IOError(format!(
+            "Image {} not found",
+            path.display()
+        )));
+    }
+
+    Ok(path)
+}
+
+/// Retrieve the bounding boxes of the objects from the XML annotation of an image.
+///
+/// VOC boxes use inclusive 1-based pixel coordinates, they are converted to 0-based
+/// `[x_min, y_min, width, height]` boxes covering the annotated pixels.
+pub(crate) fn parse_voc_annotation<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<BoundingBox>, ImageLoaderError> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path).map_err(|e| {
+        ImageLoaderError::IOError(format!("Failed to read {}: {}", path.display(), e))
+    })?;
+    let annotation: VocAnnotation = quick_xml::de::from_str(&content).map_err(|e| {
+        ImageLoaderError::ParsingError(format!("Failed to parse {}: {}", path.display(), e))
+    })?;
+
+    annotation
+        .objects
+        .into_iter()
+        .map(|object| {
+            let label = VOC_CLASSES
+                .iter()
+                .position(|&name| name == object.name.trim())
+                .filter(|&label| label != 0)
+                .ok_or_else(|| {
+                    ImageLoaderError::ParsingError(format!("Unknown VOC class {}", object.name))
+                })?;
+            let VocBox {
+                xmin,
+                ymin,
+                xmax,
+                ymax,
+            } = object.bndbox;
+
+            Ok(BoundingBox {
+                coords: [xmin - 1.0, ymin - 1.0, xmax - xmin + 1.0, ymax - ymin + 1.0],
+                label,
+            })
+        })
+        .collect()
+}
+
+/// The color of each label in the palette of the VOC segmentation masks.
+fn voc_palette() -> HashMap<[u8; 3], usize> {
+    (0..256)
+        .map(|label| {
+            // The bits of the label are spread over the channels, from the most significant bit
+            let mut color = [0u8; 3];
+            let mut bits = label;
+            for shift in (0..8).rev() {
+                for (channel, value) in color.iter_mut().enumerate() {
+                    *value |= (((bits >> channel) & 1) << shift) as u8;
+                }
+                bits >>= 3;
+            }
+            (color, label)
+        })
+        .collect()
+}
+
+/// Load a VOC segmentation mask, mapping the colors of its palette back to the labels.
+pub(crate) fn voc_mask_to_vec_usize(mask_path: &Path) -> Vec<usize> {
+    let palette = voc_palette();
+
+    // Palette images are expanded to RGB
+    image::open(mask_path)
+        .unwrap()
+        .into_rgb8()
+        .pixels()
+        .map(|pixel| match palette.get(&pixel.0) {
+            Some(&label) if label < VOC_CLASSES.len() || label == VOC_VOID_LABEL => label,
+            _ => panic!("Unrecognized VOC mask color {:?}", pixel.0),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn voc_palette_colors() {
+        let palette = voc_palette();
+
+        assert_eq!(palette[&[0, 0, 0]], 0);
+        assert_eq!(palette[&[128, 0, 0]], 1);
+        assert_eq!(palette[&[0, 128, 0]], 2);
+        assert_eq!(palette[&[192, 128, 128]], 15);
+        assert_eq!(palette[&[0, 64, 128]], 20);
+        assert_eq!(palette[&[224, 224, 192]], VOC_VOID_LABEL);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     binary_float_cmp_ops, binary_float_ops,
     client::FusionClient,
-    get_client,
+    cumulative_float_ops, get_client,
     ops::binary::check_binary_op_types,
     scalar_float2int_ops, scalar_float_cmp_ops, scalar_float_ops,
     stream::{execution::Operation, StreamId},
@@ -1315,6 +1315,152 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_cumsum(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        cumulative_float_ops!(CumSumOps, B::float_cumsum, exclusive);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumSum(desc.clone())),
+            CumSumOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cumprod(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        cumulative_float_ops!(CumProdOps, B::float_cumprod, exclusive);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumProd(desc.clone())),
+            CumProdOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        cumulative_float_ops!(CumMaxOps, B::float_cummax);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive: false,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumMax(desc.clone())),
+            CumMaxOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        cumulative_float_ops!(CumMinOps, B::float_cummin);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive: false,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumMin(desc.clone())),
+            CumMinOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_fft(tensor: FloatTensor<Self>, dim: usize, inverse: bool) -> FloatTensor<Self> {
+        #[derive(new)]
+        struct FftOps<B: FusionBackend> {
+            desc: FftOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for FftOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_float_tensor::<B>(&self.desc.input);
+                let output = B::float_fft(input, self.desc.dim, self.desc.inverse);
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = FftOpIr {
+            input: tensor.into_ir(),
+            dim,
+            inverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::Float(dtype, FloatOperationIr::Fft(desc.clone())),
+            FftOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_mean(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         unary_float_ops!(MeanOps, B::float_mean, reduce);
 
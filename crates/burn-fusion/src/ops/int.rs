@@ -1,7 +1,7 @@
 use crate::{
     binary_int_cmp_ops, binary_int_ops,
     client::FusionClient,
-    get_client, scalar_int_cmp_ops, scalar_int_ops,
+    cumulative_int_ops, get_client, scalar_int_cmp_ops, scalar_int_ops,
     stream::{execution::Operation, StreamId},
     unary_int_ops, Fusion, FusionBackend,
 };
@@ -1222,6 +1222,116 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn int_cumsum(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        cumulative_int_ops!(CumSumOps, B::int_cumsum, exclusive);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericInt(dtype, NumericOperationIr::CumSum(desc.clone())),
+            CumSumOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_cumprod(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        cumulative_int_ops!(CumProdOps, B::int_cumprod, exclusive);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericInt(dtype, NumericOperationIr::CumProd(desc.clone())),
+            CumProdOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_cummax(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        cumulative_int_ops!(CumMaxOps, B::int_cummax);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive: false,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericInt(dtype, NumericOperationIr::CumMax(desc.clone())),
+            CumMaxOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_cummin(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        cumulative_int_ops!(CumMinOps, B::int_cummin);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive: false,
+            reverse,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericInt(dtype, NumericOperationIr::CumMin(desc.clone())),
+            CumMinOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn int_mean(tensor: IntTensor<Self>) -> IntTensor<Self> {
         unary_int_ops!(MeanOps, B::int_mean, reduce);
 
@@ -6,9 +6,10 @@ use burn_tensor::{
             calculate_conv_output_size, calculate_conv_transpose_output_size,
             calculate_pool_output_size,
         },
-        ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, FloatTensor,
-        IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        AttentionBackward, AttentionOptions, BoolTensor, ConvOptions, ConvTransposeOptions,
+        DeformConv2dBackward, DeformConvOptions, FloatTensor, GridSample2dBackward,
+        GridSampleOptions, IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices,
+        MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
     },
     Element,
 };
@@ -1371,4 +1372,233 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         );
         out
     }
+
+    fn grid_sample_2d(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            GridSample2dOps,
+            GridSample2dOpIr,
+            |args: GridSample2dOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grid = handles.get_float_tensor::<B>(&args.grid);
+                let output = B::grid_sample_2d(x, grid, args.options.clone().into());
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let stream_1 = x.stream;
+        let stream_2 = grid.stream;
+        let shape = vec![x.shape[0], x.shape[1], grid.shape[1], grid.shape[2]];
+        let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = GridSample2dOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            options: options.into(),
+            out: out.to_ir_out(),
+        };
+
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationIr::Module(ModuleOperationIr::GridSample2d(desc.clone())),
+            GridSample2dOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn grid_sample_2d_backward(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> GridSample2dBackward<Self> {
+        make_ops!(
+            GridSample2dBackwardOps,
+            GridSample2dBackwardOpIr,
+            |args: GridSample2dBackwardOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grid = handles.get_float_tensor::<B>(&args.grid);
+                let out_grad = handles.get_float_tensor::<B>(&args.out_grad);
+                let output =
+                    B::grid_sample_2d_backward(x, grid, out_grad, args.options.clone().into());
+
+                handles.register_float_tensor::<B>(&args.x_grad.id, output.x_grad);
+                handles.register_float_tensor::<B>(&args.grid_grad.id, output.grid_grad);
+            }
+        );
+
+        let stream_1 = x.stream;
+        let stream_2 = grid.stream;
+        let stream_3 = output_grad.stream;
+        let x_grad = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+        let grid_grad = x
+            .client
+            .tensor_uninitialized(grid.shape.clone(), B::FloatElem::dtype());
+
+        let desc = GridSample2dBackwardOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            out_grad: output_grad.into_ir(),
+            options: options.into(),
+            x_grad: x_grad.to_ir_out(),
+            grid_grad: grid_grad.to_ir_out(),
+        };
+        x_grad.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationIr::Module(ModuleOperationIr::GridSample2dBackward(desc.clone())),
+            GridSample2dBackwardOps::<B>::new(desc),
+        );
+
+        GridSample2dBackward::new(x_grad, grid_grad)
+    }
+
+    fn scaled_dot_product_attention(
+        query: FloatTensor<Self>,
+        key: FloatTensor<Self>,
+        value: FloatTensor<Self>,
+        padding_mask: Option<BoolTensor<Self>>,
+        attn_mask: Option<BoolTensor<Self>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            ScaledDotProductAttentionOps,
+            ScaledDotProductAttentionOpIr,
+            |args: ScaledDotProductAttentionOpIr, handles: &mut HandleContainer<B::Handle>| {
+                let query = handles.get_float_tensor::<B>(&args.query);
+                let key = handles.get_float_tensor::<B>(&args.key);
+                let value = handles.get_float_tensor::<B>(&args.value);
+                let padding_mask = args
+                    .padding_mask
+                    .as_ref()
+                    .map(|mask| handles.get_bool_tensor::<B>(mask));
+                let attn_mask = args
+                    .attn_mask
+                    .as_ref()
+                    .map(|mask| handles.get_bool_tensor::<B>(mask));
+                let output = B::scaled_dot_product_attention(
+                    query,
+                    key,
+                    value,
+                    padding_mask,
+                    attn_mask,
+                    args.options.clone().into(),
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let mut streams = vec![query.stream, key.stream, value.stream];
+        streams.extend(padding_mask.as_ref().map(|mask| mask.stream));
+        streams.extend(attn_mask.as_ref().map(|mask| mask.stream));
+        let shape = vec![
+            query.shape[0],
+            query.shape[1],
+            query.shape[2],
+            value.shape[3],
+        ];
+        let out = query
+            .client
+            .tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = ScaledDotProductAttentionOpIr {
+            query: query.into_ir(),
+            key: key.into_ir(),
+            value: value.into_ir(),
+            padding_mask: padding_mask.map(|mask| mask.into_ir()),
+            attn_mask: attn_mask.map(|mask| mask.into_ir()),
+            options: options.into(),
+            out: out.to_ir_out(),
+        };
+
+        out.client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::ScaledDotProductAttention(Box::new(
+                desc.clone(),
+            ))),
+            ScaledDotProductAttentionOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn scaled_dot_product_attention_backward(
+        query: FloatTensor<Self>,
+        key: FloatTensor<Self>,
+        value: FloatTensor<Self>,
+        padding_mask: Option<BoolTensor<Self>>,
+        attn_mask: Option<BoolTensor<Self>>,
+        output_grad: FloatTensor<Self>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<Self> {
+        make_ops!(
+            ScaledDotProductAttentionBackwardOps,
+            ScaledDotProductAttentionBackwardOpIr,
+            |args: ScaledDotProductAttentionBackwardOpIr,
+             handles: &mut HandleContainer<B::Handle>| {
+                let query = handles.get_float_tensor::<B>(&args.query);
+                let key = handles.get_float_tensor::<B>(&args.key);
+                let value = handles.get_float_tensor::<B>(&args.value);
+                let padding_mask = args
+                    .padding_mask
+                    .as_ref()
+                    .map(|mask| handles.get_bool_tensor::<B>(mask));
+                let attn_mask = args
+                    .attn_mask
+                    .as_ref()
+                    .map(|mask| handles.get_bool_tensor::<B>(mask));
+                let out_grad = handles.get_float_tensor::<B>(&args.out_grad);
+                let output = B::scaled_dot_product_attention_backward(
+                    query,
+                    key,
+                    value,
+                    padding_mask,
+                    attn_mask,
+                    out_grad,
+                    args.options.clone().into(),
+                );
+
+                handles.register_float_tensor::<B>(&args.query_grad.id, output.query_grad);
+                handles.register_float_tensor::<B>(&args.key_grad.id, output.key_grad);
+                handles.register_float_tensor::<B>(&args.value_grad.id, output.value_grad);
+            }
+        );
+
+        let mut streams = vec![query.stream, key.stream, value.stream, output_grad.stream];
+        streams.extend(padding_mask.as_ref().map(|mask| mask.stream));
+        streams.extend(attn_mask.as_ref().map(|mask| mask.stream));
+        let client = query.client.clone();
+        let query_grad = client.tensor_uninitialized(query.shape.clone(), B::FloatElem::dtype());
+        let key_grad = client.tensor_uninitialized(key.shape.clone(), B::FloatElem::dtype());
+        let value_grad = client.tensor_uninitialized(value.shape.clone(), B::FloatElem::dtype());
+
+        let desc = ScaledDotProductAttentionBackwardOpIr {
+            query: query.into_ir(),
+            key: key.into_ir(),
+            value: value.into_ir(),
+            padding_mask: padding_mask.map(|mask| mask.into_ir()),
+            attn_mask: attn_mask.map(|mask| mask.into_ir()),
+            out_grad: output_grad.into_ir(),
+            options: options.into(),
+            query_grad: query_grad.to_ir_out(),
+            key_grad: key_grad.to_ir_out(),
+            value_grad: value_grad.to_ir_out(),
+        };
+        client.register(
+            streams,
+            OperationIr::Module(ModuleOperationIr::ScaledDotProductAttentionBackward(
+                Box::new(desc.clone()),
+            )),
+            ScaledDotProductAttentionBackwardOps::<B>::new(desc),
+        );
+
+        AttentionBackward::new(query_grad, key_grad, value_grad)
+    }
 }
@@ -262,3 +262,91 @@ macro_rules! scalar_int_ops {
         }
     };
 }
+
+#[allow(missing_docs)]
+#[macro_export(local_inner_macros)]
+macro_rules! cumulative_float_ops {
+    (
+        $name:ident,
+        $ops:expr
+    ) => {
+        #[derive(new)]
+        struct $name<B: FusionBackend> {
+            desc: CumulativeOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for $name<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_float_tensor::<B>(&self.desc.input);
+                let output = $ops(input, self.desc.dim, self.desc.reverse);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+    };
+    (
+        $name:ident,
+        $ops:expr,
+        exclusive
+    ) => {
+        #[derive(new)]
+        struct $name<B: FusionBackend> {
+            desc: CumulativeOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for $name<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_float_tensor::<B>(&self.desc.input);
+                let output = $ops(input, self.desc.dim, self.desc.exclusive, self.desc.reverse);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+    };
+}
+
+#[allow(missing_docs)]
+#[macro_export(local_inner_macros)]
+macro_rules! cumulative_int_ops {
+    (
+        $name:ident,
+        $ops:expr
+    ) => {
+        #[derive(new)]
+        struct $name<B: FusionBackend> {
+            desc: CumulativeOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for $name<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_int_tensor::<B>(&self.desc.input);
+                let output = $ops(input, self.desc.dim, self.desc.reverse);
+
+                handles.register_int_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+    };
+    (
+        $name:ident,
+        $ops:expr,
+        exclusive
+    ) => {
+        #[derive(new)]
+        struct $name<B: FusionBackend> {
+            desc: CumulativeOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for $name<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_int_tensor::<B>(&self.desc.input);
+                let output = $ops(input, self.desc.dim, self.desc.exclusive, self.desc.reverse);
+
+                handles.register_int_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+    };
+}
@@ -518,6 +518,65 @@ impl RelativeOps for ModuleOperationIr {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationIr::GridSample2d(desc) => {
+                ModuleOperationIr::GridSample2d(GridSample2dOpIr {
+                    x: desc.x.to_relative(converter),
+                    grid: desc.grid.to_relative(converter),
+                    options: desc.options.clone(),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationIr::GridSample2dBackward(desc) => {
+                ModuleOperationIr::GridSample2dBackward(GridSample2dBackwardOpIr {
+                    x: desc.x.to_relative(converter),
+                    grid: desc.grid.to_relative(converter),
+                    out_grad: desc.out_grad.to_relative(converter),
+                    options: desc.options.clone(),
+                    x_grad: desc.x_grad.to_relative(converter),
+                    grid_grad: desc.grid_grad.to_relative(converter),
+                })
+            }
+            ModuleOperationIr::ScaledDotProductAttention(desc) => {
+                ModuleOperationIr::ScaledDotProductAttention(Box::new(
+                    ScaledDotProductAttentionOpIr {
+                        query: desc.query.to_relative(converter),
+                        key: desc.key.to_relative(converter),
+                        value: desc.value.to_relative(converter),
+                        padding_mask: desc
+                            .padding_mask
+                            .as_ref()
+                            .map(|mask| mask.to_relative(converter)),
+                        attn_mask: desc
+                            .attn_mask
+                            .as_ref()
+                            .map(|mask| mask.to_relative(converter)),
+                        options: desc.options.clone(),
+                        out: desc.out.to_relative(converter),
+                    },
+                ))
+            }
+            ModuleOperationIr::ScaledDotProductAttentionBackward(desc) => {
+                ModuleOperationIr::ScaledDotProductAttentionBackward(Box::new(
+                    ScaledDotProductAttentionBackwardOpIr {
+                        query: desc.query.to_relative(converter),
+                        key: desc.key.to_relative(converter),
+                        value: desc.value.to_relative(converter),
+                        padding_mask: desc
+                            .padding_mask
+                            .as_ref()
+                            .map(|mask| mask.to_relative(converter)),
+                        attn_mask: desc
+                            .attn_mask
+                            .as_ref()
+                            .map(|mask| mask.to_relative(converter)),
+                        out_grad: desc.out_grad.to_relative(converter),
+                        options: desc.options.clone(),
+                        query_grad: desc.query_grad.to_relative(converter),
+                        key_grad: desc.key_grad.to_relative(converter),
+                        value_grad: desc.value_grad.to_relative(converter),
+                    },
+                ))
+            }
         }
     }
 }
@@ -582,6 +641,12 @@ impl RelativeOpsScalar<f32> for FloatOperationIr {
                 input: desc.input.to_relative(converter),
                 out: desc.out.to_relative(converter),
             }),
+            FloatOperationIr::Fft(desc) => FloatOperationIr::Fft(FftOpIr {
+                input: desc.input.to_relative(converter),
+                dim: desc.dim,
+                inverse: desc.inverse,
+                out: desc.out.to_relative(converter),
+            }),
             FloatOperationIr::Quantize(desc) => FloatOperationIr::Quantize(QuantizeOpIr {
                 tensor: desc.tensor.to_relative(converter),
                 qparams: QuantizationParametersIr {
@@ -882,6 +947,34 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationIr<E> {
                     out: desc.out.to_relative(converter),
                 })
             }
+            NumericOperationIr::CumSum(desc) => NumericOperationIr::CumSum(CumulativeOpIr {
+                input: desc.input.to_relative(converter),
+                dim: desc.dim,
+                exclusive: desc.exclusive,
+                reverse: desc.reverse,
+                out: desc.out.to_relative(converter),
+            }),
+            NumericOperationIr::CumProd(desc) => NumericOperationIr::CumProd(CumulativeOpIr {
+                input: desc.input.to_relative(converter),
+                dim: desc.dim,
+                exclusive: desc.exclusive,
+                reverse: desc.reverse,
+                out: desc.out.to_relative(converter),
+            }),
+            NumericOperationIr::CumMax(desc) => NumericOperationIr::CumMax(CumulativeOpIr {
+                input: desc.input.to_relative(converter),
+                dim: desc.dim,
+                exclusive: desc.exclusive,
+                reverse: desc.reverse,
+                out: desc.out.to_relative(converter),
+            }),
+            NumericOperationIr::CumMin(desc) => NumericOperationIr::CumMin(CumulativeOpIr {
+                input: desc.input.to_relative(converter),
+                dim: desc.dim,
+                exclusive: desc.exclusive,
+                reverse: desc.reverse,
+                out: desc.out.to_relative(converter),
+            }),
             NumericOperationIr::EqualElem(desc) => NumericOperationIr::EqualElem(ScalarOpIr {
                 lhs: desc.lhs.to_relative(converter),
                 rhs: local_elem(converter, &desc.rhs),
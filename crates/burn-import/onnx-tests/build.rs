@@ -47,7 +47,10 @@ fn main() {
         .input("tests/greater/greater_scalar.onnx")
         .input("tests/greater_or_equal/greater_or_equal.onnx")
         .input("tests/greater_or_equal/greater_or_equal_scalar.onnx")
+        .input("tests/gru/gru.onnx")
+        .input("tests/gru/gru_bidirectional.onnx")
         .input("tests/hard_sigmoid/hard_sigmoid.onnx")
+        .input("tests/if/if_else.onnx")
         .input("tests/layer_norm/layer_norm.onnx")
         .input("tests/leaky_relu/leaky_relu.onnx")
         .input("tests/less/less.onnx")
@@ -57,6 +60,9 @@ fn main() {
         .input("tests/linear/linear.onnx")
         .input("tests/log/log.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
+        .input("tests/loop/loop_trip_count.onnx")
+        .input("tests/lstm/lstm.onnx")
+        .input("tests/lstm/lstm_bidirectional.onnx")
         .input("tests/mask_where/mask_where.onnx")
         .input("tests/mask_where/mask_where_broadcast.onnx")
         .input("tests/mask_where/mask_where_scalar_x.onnx")
@@ -76,6 +82,9 @@ fn main() {
         .input("tests/pow/pow.onnx")
         .input("tests/pow/pow_int.onnx")
         .input("tests/prelu/prelu.onnx")
+        .input("tests/qdq/qdq.onnx")
+        .input("tests/qlinear_conv/qlinear_conv.onnx")
+        .input("tests/qlinear_matmul/qlinear_matmul.onnx")
         .input("tests/random_normal/random_normal.onnx")
         .input("tests/random_normal_like/random_normal_like.onnx")
         .input("tests/random_uniform/random_uniform.onnx")
@@ -90,12 +99,15 @@ fn main() {
         .input("tests/reduce_sum/reduce_sum_opset13.onnx")
         .input("tests/relu/relu.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/rnn/rnn.onnx")
+        .input("tests/rnn/rnn_bidirectional.onnx")
         .input("tests/resize/resize_with_sizes.onnx")
         .input("tests/resize/resize_1d_linear_scale.onnx")
         .input("tests/resize/resize_1d_nearest_scale.onnx")
         .input("tests/resize/resize_2d_bicubic_scale.onnx")
         .input("tests/resize/resize_2d_bilinear_scale.onnx")
         .input("tests/resize/resize_2d_nearest_scale.onnx")
+        .input("tests/scan/scan.onnx")
         .input("tests/shape/shape.onnx")
         .input("tests/sigmoid/sigmoid.onnx")
         .input("tests/sign/sign.onnx")
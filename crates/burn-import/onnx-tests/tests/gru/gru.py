@@ -0,0 +1,109 @@
+#!/usr/bin/env python3
+
+# used to generate models: onnx-tests/tests/gru/gru.onnx and
+# onnx-tests/tests/gru/gru_bidirectional.onnx
+
+import math
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper, numpy_helper
+
+SEQ_LENGTH = 3
+BATCH_SIZE = 2
+INPUT_SIZE = 2
+HIDDEN_SIZE = 3
+
+
+def values(shape, offset):
+    """Deterministic values in [-0.5, 0.5], which are easy to reproduce in the Rust tests."""
+    size = math.prod(shape)
+    return (np.sin(np.arange(size) * 0.7 + offset) * 0.5).astype(np.float32).reshape(shape)
+
+
+def build_model(direction, initial_states, linear_before_reset):
+    num_directions = 2 if direction == "bidirectional" else 1
+    state_shape = [num_directions, BATCH_SIZE, HIDDEN_SIZE]
+
+    # The gates are packed in the order update, reset and hidden
+    initializers = [
+        numpy_helper.from_array(values([num_directions, 3 * HIDDEN_SIZE, INPUT_SIZE], 0.0), "W"),
+        numpy_helper.from_array(values([num_directions, 3 * HIDDEN_SIZE, HIDDEN_SIZE], 1.0), "R"),
+        numpy_helper.from_array(values([num_directions, 6 * HIDDEN_SIZE], 2.0), "B"),
+    ]
+
+    inputs = [
+        helper.make_tensor_value_info(
+            "X", TensorProto.FLOAT, [SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE]
+        )
+    ]
+    # The sequence lengths are skipped with an empty name
+    node_inputs = ["X", "W", "R", "B"]
+    if initial_states:
+        inputs += [
+            helper.make_tensor_value_info("initial_h", TensorProto.FLOAT, state_shape),
+        ]
+        node_inputs += ["", "initial_h"]
+
+    outputs = [
+        helper.make_tensor_value_info(
+            "Y", TensorProto.FLOAT, [SEQ_LENGTH, num_directions, BATCH_SIZE, HIDDEN_SIZE]
+        ),
+        helper.make_tensor_value_info("Y_h", TensorProto.FLOAT, state_shape),
+    ]
+
+    node = helper.make_node(
+        "GRU",
+        inputs=node_inputs,
+        outputs=["Y", "Y_h"],
+        direction=direction,
+        hidden_size=HIDDEN_SIZE,
+        linear_before_reset=linear_before_reset,
+    )
+
+    graph = helper.make_graph(
+        nodes=[node],
+        name="GruGraph",
+        inputs=inputs,
+        outputs=outputs,
+        initializer=initializers,
+    )
+
+    return helper.make_model(
+        graph,
+        ir_version=8,
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+
+def export(file_name, direction, initial_states, linear_before_reset):
+    model = build_model(direction, initial_states, linear_before_reset)
+    onnx.checker.check_model(model)
+    onnx.save(model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    num_directions = 2 if direction == "bidirectional" else 1
+    state_shape = [num_directions, BATCH_SIZE, HIDDEN_SIZE]
+    feeds = {"X": values([SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE], 3.0)}
+    if initial_states:
+        feeds["initial_h"] = values(state_shape, 4.0)
+
+    # Output some test data for use in the test
+    session = onnxruntime.InferenceSession(file_name)
+    output, output_h = session.run(None, feeds)
+    for name, value in feeds.items():
+        print(f"Test input {name}: {value}")
+    print(f"Test output Y: {output}")
+    print(f"Test output Y_h: {output_h}")
+
+
+def main():
+    np.set_printoptions(precision=8)
+
+    export("gru.onnx", "forward", initial_states=True, linear_before_reset=1)
+    export("gru_bidirectional.onnx", "bidirectional", initial_states=True, linear_before_reset=0)
+
+
+if __name__ == "__main__":
+    main()
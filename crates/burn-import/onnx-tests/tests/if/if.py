@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/if/if_else.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+SHAPE = [2, 3]
+
+
+def build_model():
+    # The branches don't have inputs, they use the values of the enclosing graph
+    then_branch = helper.make_graph(
+        nodes=[
+            helper.make_node("Add", ["x", "y"], ["then_sum"]),
+            helper.make_node("Mul", ["x", "y"], ["then_product"]),
+        ],
+        name="ThenBranch",
+        inputs=[],
+        outputs=[
+            helper.make_tensor_value_info("then_sum", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("then_product", TensorProto.FLOAT, SHAPE),
+        ],
+    )
+    else_branch = helper.make_graph(
+        nodes=[
+            helper.make_node("Sub", ["x", "y"], ["else_difference"]),
+            helper.make_node("Neg", ["x"], ["else_negation"]),
+        ],
+        name="ElseBranch",
+        inputs=[],
+        outputs=[
+            helper.make_tensor_value_info("else_difference", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("else_negation", TensorProto.FLOAT, SHAPE),
+        ],
+    )
+
+    node = helper.make_node(
+        "If",
+        inputs=["condition"],
+        outputs=["output1", "output2"],
+        then_branch=then_branch,
+        else_branch=else_branch,
+    )
+
+    graph = helper.make_graph(
+        nodes=[node],
+        name="IfGraph",
+        inputs=[
+            helper.make_tensor_value_info("condition", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("y", TensorProto.FLOAT, SHAPE),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, SHAPE),
+        ],
+    )
+
+    return helper.make_model(
+        graph,
+        ir_version=8,
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+
+def main():
+    model = build_model()
+    onnx.checker.check_model(model)
+
+    file_name = "if_else.onnx"
+    onnx.save(model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    x = np.arange(6, dtype=np.float32).reshape(SHAPE) * 0.5 - 1.0
+    y = np.arange(6, dtype=np.float32).reshape(SHAPE) * -0.25 + 2.0
+
+    # Output some test data for use in the test, taking both branches
+    session = onnxruntime.InferenceSession(file_name)
+    print(f"Test input x: {x}")
+    print(f"Test input y: {y}")
+    for condition in [True, False]:
+        feeds = {"condition": np.array(condition, dtype=np.bool_), "x": x, "y": y}
+        output1, output2 = session.run(None, feeds)
+        print(f"Test output with condition={condition}: {output1}, {output2}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/loop/loop_trip_count.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+TRIP_COUNT = 3
+SHAPE = [2]
+
+
+def build_model():
+    # The body adds `x` of the enclosing graph to the loop carried accumulator, and outputs the
+    # product of the accumulator and `x` as a scan output at each iteration
+    body = helper.make_graph(
+        nodes=[
+            helper.make_node("Identity", ["condition_in"], ["condition_out"]),
+            helper.make_node("Add", ["accumulator_in", "x"], ["accumulator_out"]),
+            helper.make_node("Mul", ["accumulator_out", "x"], ["product"]),
+        ],
+        name="LoopBody",
+        inputs=[
+            helper.make_tensor_value_info("iteration", TensorProto.INT64, []),
+            helper.make_tensor_value_info("condition_in", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("accumulator_in", TensorProto.FLOAT, SHAPE),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("condition_out", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("accumulator_out", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("product", TensorProto.FLOAT, SHAPE),
+        ],
+    )
+
+    # The condition is skipped with an empty name, so the loop runs for the trip count
+    node = helper.make_node(
+        "Loop",
+        inputs=["trip_count", "", "accumulator"],
+        outputs=["output_accumulator", "output_products"],
+        body=body,
+    )
+
+    graph = helper.make_graph(
+        nodes=[node],
+        name="LoopGraph",
+        inputs=[
+            helper.make_tensor_value_info("trip_count", TensorProto.INT64, []),
+            helper.make_tensor_value_info("accumulator", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, SHAPE),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output_accumulator", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info(
+                "output_products", TensorProto.FLOAT, [TRIP_COUNT] + SHAPE
+            ),
+        ],
+    )
+
+    return helper.make_model(
+        graph,
+        ir_version=8,
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+
+def main():
+    model = build_model()
+    onnx.checker.check_model(model)
+
+    file_name = "loop_trip_count.onnx"
+    onnx.save(model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    feeds = {
+        "trip_count": np.array(TRIP_COUNT, dtype=np.int64),
+        "accumulator": np.array([1.0, -2.0], dtype=np.float32),
+        "x": np.array([0.5, 1.5], dtype=np.float32),
+    }
+
+    # Output some test data for use in the test
+    session = onnxruntime.InferenceSession(file_name)
+    output_accumulator, output_products = session.run(None, feeds)
+    for name, value in feeds.items():
+        print(f"Test input {name}: {value}")
+    print(f"Test output accumulator: {output_accumulator}")
+    print(f"Test output products: {output_products}")
+
+
+if __name__ == "__main__":
+    main()
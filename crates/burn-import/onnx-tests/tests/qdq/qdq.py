@@ -0,0 +1,96 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/qdq/qdq.onnx
+
+import math
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper, numpy_helper
+
+INPUT_SHAPE = [2, 3]
+WEIGHTS_SHAPE = [3, 2]
+
+
+def values(shape, offset):
+    """Deterministic values in [-1, 1], which are easy to reproduce in the Rust tests."""
+    size = math.prod(shape)
+    return np.sin(np.arange(size) * 0.9 + offset).astype(np.float32).reshape(shape)
+
+
+def build_model():
+    initializers = [
+        # The input is quantized per tensor to uint8
+        numpy_helper.from_array(np.array(0.05, dtype=np.float32), "input_scale"),
+        numpy_helper.from_array(np.array(128, dtype=np.uint8), "input_zero_point"),
+        # The constant int8 weights are quantized per column
+        numpy_helper.from_array(
+            (values(WEIGHTS_SHAPE, 1.0) * 100).astype(np.int8), "weights_quantized"
+        ),
+        numpy_helper.from_array(np.array([0.01, 0.02], dtype=np.float32), "weights_scale"),
+        numpy_helper.from_array(np.array([3, -5], dtype=np.int8), "weights_zero_point"),
+    ]
+
+    nodes = [
+        helper.make_node(
+            "QuantizeLinear",
+            ["input", "input_scale", "input_zero_point"],
+            ["input_quantized"],
+        ),
+        helper.make_node(
+            "DequantizeLinear",
+            ["input_quantized", "input_scale", "input_zero_point"],
+            ["output1"],
+        ),
+        helper.make_node(
+            "DequantizeLinear",
+            ["weights_quantized", "weights_scale", "weights_zero_point"],
+            ["weights"],
+            axis=1,
+        ),
+        helper.make_node("MatMul", ["output1", "weights"], ["output2"]),
+    ]
+
+    graph = helper.make_graph(
+        nodes=nodes,
+        name="QdqGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, INPUT_SHAPE)],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, INPUT_SHAPE),
+            helper.make_tensor_value_info(
+                "output2", TensorProto.FLOAT, [INPUT_SHAPE[0], WEIGHTS_SHAPE[1]]
+            ),
+        ],
+        initializer=initializers,
+    )
+
+    return helper.make_model(
+        graph,
+        ir_version=8,
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+
+def main():
+    np.set_printoptions(precision=8)
+
+    model = build_model()
+    onnx.checker.check_model(model)
+
+    file_name = "qdq.onnx"
+    onnx.save(model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    # The values are chosen so that none of them is rounded from a tie when quantized
+    test_input = values(INPUT_SHAPE, 0.0)
+
+    # Output some test data for use in the test
+    session = onnxruntime.InferenceSession(file_name)
+    output1, output2 = session.run(None, {"input": test_input})
+    print(f"Test input data: {test_input}")
+    print(f"Test output data: {output1}, {output2}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,89 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/scan/scan.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+SEQ_LENGTH = 3
+SHAPE = [2]
+
+
+def build_model():
+    # The body accumulates the slices of the sequence scaled by `weight` of the enclosing graph,
+    # and outputs the difference between the state and the slice at each iteration
+    body = helper.make_graph(
+        nodes=[
+            helper.make_node("Mul", ["sequence_slice", "weight"], ["scaled"]),
+            helper.make_node("Add", ["state_in", "scaled"], ["state_out"]),
+            helper.make_node("Sub", ["state_out", "sequence_slice"], ["difference"]),
+        ],
+        name="ScanBody",
+        inputs=[
+            helper.make_tensor_value_info("state_in", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("sequence_slice", TensorProto.FLOAT, SHAPE),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("state_out", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("difference", TensorProto.FLOAT, SHAPE),
+        ],
+    )
+
+    node = helper.make_node(
+        "Scan",
+        inputs=["state", "sequence"],
+        outputs=["output_state", "output_differences"],
+        body=body,
+        num_scan_inputs=1,
+    )
+
+    graph = helper.make_graph(
+        nodes=[node],
+        name="ScanGraph",
+        inputs=[
+            helper.make_tensor_value_info("state", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info("sequence", TensorProto.FLOAT, [SEQ_LENGTH] + SHAPE),
+            helper.make_tensor_value_info("weight", TensorProto.FLOAT, SHAPE),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output_state", TensorProto.FLOAT, SHAPE),
+            helper.make_tensor_value_info(
+                "output_differences", TensorProto.FLOAT, [SEQ_LENGTH] + SHAPE
+            ),
+        ],
+    )
+
+    return helper.make_model(
+        graph,
+        ir_version=8,
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+
+def main():
+    model = build_model()
+    onnx.checker.check_model(model)
+
+    file_name = "scan.onnx"
+    onnx.save(model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    feeds = {
+        "state": np.array([0.5, -1.0], dtype=np.float32),
+        "sequence": np.arange(6, dtype=np.float32).reshape([SEQ_LENGTH] + SHAPE) * 0.5 - 1.0,
+        "weight": np.array([2.0, -0.5], dtype=np.float32),
+    }
+
+    # Output some test data for use in the test
+    session = onnxruntime.InferenceSession(file_name)
+    output_state, output_differences = session.run(None, feeds)
+    for name, value in feeds.items():
+        print(f"Test input {name}: {value}")
+    print(f"Test output state: {output_state}")
+    print(f"Test output differences: {output_differences}")
+
+
+if __name__ == "__main__":
+    main()
@@ -56,7 +56,10 @@ include_models!(
     greater_scalar,
     greater_or_equal,
     greater_or_equal_scalar,
+    gru,
+    gru_bidirectional,
     hard_sigmoid,
+    if_else,
     layer_norm,
     leaky_relu,
     less,
@@ -66,6 +69,9 @@ include_models!(
     linear,
     log,
     log_softmax,
+    loop_trip_count,
+    lstm,
+    lstm_bidirectional,
     mask_where,
     mask_where_broadcast,
     mask_where_scalar_x,
@@ -85,6 +91,9 @@ include_models!(
     pow,
     pow_int,
     prelu,
+    qdq,
+    qlinear_conv,
+    qlinear_matmul,
     random_normal,
     random_normal_like,
     random_uniform,
@@ -99,12 +108,15 @@ include_models!(
     reduce_sum_opset13,
     relu,
     reshape,
+    rnn,
+    rnn_bidirectional,
     resize_with_sizes,
     resize_1d_linear_scale,
     resize_1d_nearest_scale,
     resize_2d_bicubic_scale,
     resize_2d_bilinear_scale,
     resize_2d_nearest_scale,
+    scan,
     shape,
     sigmoid,
     sign,
@@ -1471,6 +1483,486 @@ mod tests {
         assert!(expected_sum3.approx_eq(output_sum3, (1.0e-6, 2)));
     }
 
+    #[test]
+    fn lstm() {
+        let device = Default::default();
+        let model: lstm::Model<Backend> = lstm::Model::default();
+
+        // Forward LSTM with initial hidden and cell states
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.07056, -0.26491806], [-0.47580105, -0.46290734]],
+                [[-0.23230109, 0.10755999], [0.39683393, 0.49947068]],
+                [[0.36719856, 0.06222721], [-0.27201056, -0.4783175]],
+            ],
+            &device,
+        );
+        let initial_h = Tensor::<Backend, 3>::from_floats(
+            [[
+                [-0.37840125, -0.49996161, -0.38638225],
+                [-0.09108125, 0.24705668, 0.46899998],
+            ]],
+            &device,
+        );
+        let initial_c = Tensor::<Backend, 3>::from_floats(
+            [[
+                [-0.47946215, -0.27534276, 0.0582746],
+                [0.36448452, 0.49927166, 0.39924356],
+            ]],
+            &device,
+        );
+        let (output, output_h, output_c) = model.forward(input, initial_h, initial_c);
+
+        // Expected values printed by the generator script
+        let expected = TensorData::from([
+            [[
+                [-0.11453f32, -0.10637, -0.03766],
+                [0.14636, 0.04179, 0.05923],
+            ]],
+            [[[-0.09270, -0.11987, -0.11326], [0.10889, 0.03830, -0.07562]]],
+            [[
+                [-0.05928, -0.06657, -0.15201],
+                [0.07594, -0.06038, -0.10713],
+            ]],
+        ]);
+        let expected_h = TensorData::from([[
+            [-0.05928f32, -0.06657, -0.15201],
+            [0.07594, -0.06038, -0.10713],
+        ]]);
+        let expected_c = TensorData::from([[
+            [-0.18942f32, -0.15112, -0.26772],
+            [0.15693, -0.12738, -0.25113],
+        ]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+        output_c.to_data().assert_approx_eq(&expected_c, 4);
+    }
+
+    #[test]
+    fn lstm_bidirectional() {
+        let device = Default::default();
+        let model: lstm_bidirectional::Model<Backend> = lstm_bidirectional::Model::default();
+
+        // Bidirectional LSTM starting from zero states
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.07056, -0.26491806], [-0.47580105, -0.46290734]],
+                [[-0.23230109, 0.10755999], [0.39683393, 0.49947068]],
+                [[0.36719856, 0.06222721], [-0.27201056, -0.4783175]],
+            ],
+            &device,
+        );
+        let (output, output_h, output_c) = model.forward(input);
+
+        // Expected values printed by the generator script
+        let expected = TensorData::from([
+            [
+                [
+                    [-0.01114f32, -0.06355, -0.07843],
+                    [-0.03052, -0.09716, -0.07160],
+                ],
+                [[0.27948, 0.13940, 0.08678], [0.28300, 0.18647, 0.05392]],
+            ],
+            [
+                [
+                    [-0.00520, -0.10253, -0.14163],
+                    [0.00708, -0.00632, -0.15935],
+                ],
+                [[0.18439, 0.14216, 0.11415], [0.17252, 0.11679, 0.09557]],
+            ],
+            [
+                [
+                    [-0.00877, -0.05475, -0.17130],
+                    [-0.03213, -0.09340, -0.14250],
+                ],
+                [[0.06610, 0.08497, 0.09111], [0.19094, 0.14966, -0.00281]],
+            ],
+        ]);
+        let expected_h = TensorData::from([
+            [
+                [-0.00877f32, -0.05475, -0.17130],
+                [-0.03213, -0.09340, -0.14250],
+            ],
+            [[0.27948, 0.13940, 0.08678], [0.28300, 0.18647, 0.05392]],
+        ]);
+        let expected_c = TensorData::from([
+            [
+                [-0.02703f32, -0.12333, -0.31006],
+                [-0.06927, -0.19296, -0.33181],
+            ],
+            [[0.56249, 0.34168, 0.23592], [0.68029, 0.41291, 0.11923]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+        output_c.to_data().assert_approx_eq(&expected_c, 4);
+    }
+
+    #[test]
+    fn gru() {
+        let device = Default::default();
+        let model: gru::Model<Backend> = gru::Model::default();
+
+        // Forward GRU with an initial hidden state, applying the reset gate after the linear transformation
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.07056, -0.26491806], [-0.47580105, -0.46290734]],
+                [[-0.23230109, 0.10755999], [0.39683393, 0.49947068]],
+                [[0.36719856, 0.06222721], [-0.27201056, -0.4783175]],
+            ],
+            &device,
+        );
+        let initial_h = Tensor::<Backend, 3>::from_floats(
+            [[
+                [-0.37840125, -0.49996161, -0.38638225],
+                [-0.09108125, 0.24705668, 0.46899998],
+            ]],
+            &device,
+        );
+        let (output, output_h) = model.forward(input, initial_h);
+
+        // Expected values printed by the generator script
+        let expected = TensorData::from([
+            [[
+                [-0.29197f32, -0.14760, 0.13042],
+                [-0.12617, 0.40541, 0.63186],
+            ]],
+            [[[-0.25552, 0.05974, 0.41841], [-0.04530, 0.23082, 0.48873]]],
+            [[[-0.15080, 0.11851, 0.46140], [-0.06917, 0.37922, 0.61661]]],
+        ]);
+        let expected_h = TensorData::from([[
+            [-0.15080f32, 0.11851, 0.46140],
+            [-0.06917, 0.37922, 0.61661],
+        ]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+    }
+
+    #[test]
+    fn gru_bidirectional() {
+        let device = Default::default();
+        let model: gru_bidirectional::Model<Backend> = gru_bidirectional::Model::default();
+
+        // Bidirectional GRU with an initial hidden state, applying the reset gate before the linear transformation
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.07056, -0.26491806], [-0.47580105, -0.46290734]],
+                [[-0.23230109, 0.10755999], [0.39683393, 0.49947068]],
+                [[0.36719856, 0.06222721], [-0.27201056, -0.4783175]],
+            ],
+            &device,
+        );
+        let initial_h = Tensor::<Backend, 3>::from_floats(
+            [
+                [
+                    [-0.37840125, -0.49996161, -0.38638225],
+                    [-0.09108125, 0.24705668, 0.46899998],
+                ],
+                [
+                    [0.47036529, 0.25051042, -0.08716339],
+                    [-0.38384292, -0.49999511, -0.38099179],
+                ],
+            ],
+            &device,
+        );
+        let (output, output_h) = model.forward(input, initial_h);
+
+        // Expected values printed by the generator script
+        let expected = TensorData::from([
+            [
+                [
+                    [-0.32097f32, -0.10535, 0.20092],
+                    [-0.14501, 0.48729, 0.69446],
+                ],
+                [[0.28495, 0.47477, 0.69220], [-0.24310, 0.43039, 0.67729]],
+            ],
+            [
+                [[-0.27927, 0.15643, 0.52157], [-0.06498, 0.33251, 0.63748]],
+                [[0.33146, 0.40513, 0.60548], [-0.22234, 0.08607, 0.44134]],
+            ],
+            [
+                [[-0.17143, 0.24166, 0.60464], [-0.09514, 0.50400, 0.75609]],
+                [[0.41020, 0.32799, 0.34594], [-0.37916, 0.00512, 0.26116]],
+            ],
+        ]);
+        let expected_h = TensorData::from([
+            [
+                [-0.17143f32, 0.24166, 0.60464],
+                [-0.09514, 0.50400, 0.75609],
+            ],
+            [[0.28495, 0.47477, 0.69220], [-0.24310, 0.43039, 0.67729]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+    }
+
+    #[test]
+    fn rnn() {
+        let device = Default::default();
+        let model: rnn::Model<Backend> = rnn::Model::default();
+
+        // Forward RNN with an initial hidden state
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.07056, -0.26491806], [-0.47580105, -0.46290734]],
+                [[-0.23230109, 0.10755999], [0.39683393, 0.49947068]],
+                [[0.36719856, 0.06222721], [-0.27201056, -0.4783175]],
+            ],
+            &device,
+        );
+        let initial_h = Tensor::<Backend, 3>::from_floats(
+            [[
+                [-0.37840125, -0.49996161, -0.38638225],
+                [-0.09108125, 0.24705668, 0.46899998],
+            ]],
+            &device,
+        );
+        let (output, output_h) = model.forward(input, initial_h);
+
+        // Expected values printed by the generator script
+        let expected = TensorData::from([
+            [[
+                [-0.52079f32, -0.03002, -0.21826],
+                [0.13809, -0.77203, -0.39012],
+            ]],
+            [[
+                [-0.22371, -0.24247, -0.32449],
+                [-0.24497, 0.50533, -0.44554],
+            ]],
+            [[
+                [-0.25278, 0.15037, -0.32422],
+                [-0.11108, -0.51300, -0.46044],
+            ]],
+        ]);
+        let expected_h = TensorData::from([[
+            [-0.25278f32, 0.15037, -0.32422],
+            [-0.11108, -0.51300, -0.46044],
+        ]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+    }
+
+    #[test]
+    fn rnn_bidirectional() {
+        let device = Default::default();
+        let model: rnn_bidirectional::Model<Backend> = rnn_bidirectional::Model::default();
+
+        // Bidirectional RNN starting from a zero state
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.07056, -0.26491806], [-0.47580105, -0.46290734]],
+                [[-0.23230109, 0.10755999], [0.39683393, 0.49947068]],
+                [[0.36719856, 0.06222721], [-0.27201056, -0.4783175]],
+            ],
+            &device,
+        );
+        let (output, output_h) = model.forward(input);
+
+        // Expected values printed by the generator script
+        let expected = TensorData::from([
+            [
+                [
+                    [-0.03980f32, -0.34870, -0.39883],
+                    [-0.10323, -0.61606, -0.44548],
+                ],
+                [[0.76206, 0.28409, -0.07131], [0.85242, 0.39399, -0.22638]],
+            ],
+            [
+                [
+                    [-0.23945, -0.05160, -0.47590],
+                    [-0.28485, 0.48597, -0.38873],
+                ],
+                [[0.70742, 0.22831, 0.23665], [0.48503, 0.20554, 0.34013]],
+            ],
+            [
+                [
+                    [-0.21795, 0.16527, -0.37021],
+                    [-0.11818, -0.52951, -0.43641],
+                ],
+                [[0.21552, 0.36262, 0.46168], [0.64287, 0.52056, 0.02239]],
+            ],
+        ]);
+        let expected_h = TensorData::from([
+            [
+                [-0.21795f32, 0.16527, -0.37021],
+                [-0.11818, -0.52951, -0.43641],
+            ],
+            [[0.76206, 0.28409, -0.07131], [0.85242, 0.39399, -0.22638]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+    }
+
+    #[test]
+    fn if_else() {
+        let device = Default::default();
+        let model: if_else::Model<Backend> = if_else::Model::new(&device);
+
+        let x = Tensor::<Backend, 2>::from_floats([[-1.0, -0.5, 0.0], [0.5, 1.0, 1.5]], &device);
+        let y = Tensor::<Backend, 2>::from_floats([[2.0, 1.75, 1.5], [1.25, 1.0, 0.75]], &device);
+
+        // The then branch computes the sum and the product of the inputs
+        let (output1, output2) = model.forward(true, x.clone(), y.clone());
+        let expected1 = TensorData::from([[1.0f32, 1.25, 1.5], [1.75, 2.0, 2.25]]);
+        let expected2 = TensorData::from([[-2.0f32, -0.875, 0.0], [0.625, 1.0, 1.125]]);
+
+        output1.to_data().assert_eq(&expected1, true);
+        output2.to_data().assert_eq(&expected2, true);
+
+        // The else branch computes the difference of the inputs and the negation of the first one
+        let (output1, output2) = model.forward(false, x, y);
+        let expected1 = TensorData::from([[-3.0f32, -2.25, -1.5], [-0.75, 0.0, 0.75]]);
+        let expected2 = TensorData::from([[1.0f32, 0.5, -0.0], [-0.5, -1.0, -1.5]]);
+
+        output1.to_data().assert_eq(&expected1, true);
+        output2.to_data().assert_eq(&expected2, true);
+    }
+
+    #[test]
+    fn loop_trip_count() {
+        let device = Default::default();
+        let model: loop_trip_count::Model<Backend> = loop_trip_count::Model::new(&device);
+
+        let accumulator = Tensor::<Backend, 1>::from_floats([1.0, -2.0], &device);
+        let x = Tensor::<Backend, 1>::from_floats([0.5, 1.5], &device);
+
+        // The loop carried accumulator and the scan output of the 3 iterations
+        let (output_accumulator, output_products) = model.forward(3, accumulator, x);
+        let expected_accumulator = TensorData::from([2.5f32, 2.5]);
+        let expected_products = TensorData::from([[0.75f32, -0.75], [1.0, 1.5], [1.25, 3.75]]);
+
+        output_accumulator
+            .to_data()
+            .assert_eq(&expected_accumulator, true);
+        output_products
+            .to_data()
+            .assert_eq(&expected_products, true);
+    }
+
+    #[test]
+    fn scan() {
+        let device = Default::default();
+        let model: scan::Model<Backend> = scan::Model::new(&device);
+
+        let state = Tensor::<Backend, 1>::from_floats([0.5, -1.0], &device);
+        let sequence =
+            Tensor::<Backend, 2>::from_floats([[-1.0, -0.5], [0.0, 0.5], [1.0, 1.5]], &device);
+        let weight = Tensor::<Backend, 1>::from_floats([2.0, -0.5], &device);
+
+        // The final state and the scan output of each slice of the sequence
+        let (output_state, output_differences) = model.forward(state, sequence, weight);
+        let expected_state = TensorData::from([0.5f32, -1.75]);
+        let expected_differences =
+            TensorData::from([[-0.5f32, -0.25], [-1.5, -1.5], [-0.5, -3.25]]);
+
+        output_state.to_data().assert_eq(&expected_state, true);
+        output_differences
+            .to_data()
+            .assert_eq(&expected_differences, true);
+    }
+
+    #[test]
+    fn qdq() {
+        let device = Default::default();
+        let model: qdq::Model<Backend> = qdq::Model::default();
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [
+                [0.0, 0.78332692, 0.97384763],
+                [0.42737988, -0.44252044, -0.97753012],
+            ],
+            &device,
+        );
+        let (output1, output2) = model.forward(input);
+
+        // The input rounded to the quantization steps, and its product with the dequantized weights
+        let expected1 = TensorData::from([
+            [0.00000f32, 0.80000, 0.95000],
+            [0.45000, -0.45000, -1.00000],
+        ]);
+        let expected2 = TensorData::from([[-0.72900f32, -1.98700], [1.24950, 2.61400]]);
+
+        output1.to_data().assert_approx_eq(&expected1, 4);
+        output2.to_data().assert_approx_eq(&expected2, 4);
+    }
+
+    #[test]
+    fn qlinear_conv() {
+        let device = Default::default();
+        let model: qlinear_conv::Model<Backend> = qlinear_conv::Model::default();
+
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[
+                [
+                    [0.0, 0.78332692, 0.97384763, 0.42737988],
+                    [-0.44252044, -0.97753012, -0.7727645, 0.0168139],
+                    [0.79366785, 0.96988982, 0.41211849, -0.45753589],
+                    [-0.98093623, -0.76198357, 0.03362305, 0.80378443],
+                ],
+                [
+                    [0.96565777, 0.39674059, -0.47242197, -0.984065],
+                    [-0.75098723, 0.05042269, 0.81367373, 0.96115273],
+                    [0.3812505, -0.48717451, -0.98691553, -0.73977858],
+                    [0.06720807, 0.82333302, 0.95637596, 0.36565262],
+                ],
+            ]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // The dequantized output, which is rounded to the output quantization steps
+        let expected = TensorData::from([[
+            [
+                [-1.55000f32, -5.15000, -3.70000, 0.35000],
+                [2.50000, 6.35000, 5.00000, 0.15000],
+                [-4.10000, -6.30000, -2.30000, 1.70000],
+                [3.65000, 5.30000, 1.90000, -1.30000],
+            ],
+            [
+                [0.00000, 2.10000, 2.55000, 0.75000],
+                [-0.50000, -3.00000, -3.50000, -1.35000],
+                [1.40000, 3.35000, 2.45000, 0.10000],
+                [-1.35000, -2.90000, -2.10000, -0.15000],
+            ],
+            [
+                [0.95000, -2.10000, -4.40000, -2.60000],
+                [-0.75000, 2.85000, 5.65000, 3.45000],
+                [-0.85000, -4.65000, -5.35000, -1.85000],
+                [0.70000, 3.75000, 4.20000, 1.30000],
+            ],
+        ]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn qlinear_matmul() {
+        let device = Default::default();
+        let model: qlinear_matmul::Model<Backend> = qlinear_matmul::Model::default();
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [
+                [0.0, 0.78332692, 0.97384763],
+                [0.42737988, -0.44252044, -0.97753012],
+            ],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // The dequantized output, which is rounded to the output quantization steps
+        let expected = TensorData::from([
+            [0.15000f32, -0.21000, -0.72000, -0.15000],
+            [-0.12000, 0.66000, 0.96000, 0.18000],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
     #[test]
     fn tanh() {
         // Initialize the model
@@ -4,6 +4,8 @@ use quote::quote;
 use burn::nn::PaddingConfig1d;
 use burn::nn::PaddingConfig2d;
 use burn::nn::PaddingConfig3d;
+use burn::tensor::quantization::QuantizationScheme;
+use burn::tensor::quantization::QuantizationType;
 
 fn convert_primitive<T: core::fmt::Debug>(primitive: T) -> TokenStream {
     let value = format!("{:?}", primitive);
@@ -116,3 +118,52 @@ impl ToTokens for PaddingConfig3d {
         }
     }
 }
+
+/// Quantization data type
+impl ToTokens for QuantizationType {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::QInt8 => quote! { QuantizationType::QInt8 },
+            Self::QInt4 => quote! { QuantizationType::QInt4 },
+            Self::QUInt4 => quote! { QuantizationType::QUInt4 },
+            Self::QInt2 => quote! { QuantizationType::QInt2 },
+            Self::QUInt2 => quote! { QuantizationType::QUInt2 },
+        }
+    }
+}
+
+/// Quantization scheme
+impl ToTokens for QuantizationScheme {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::PerTensorAffine(q_type) => {
+                let q_type = q_type.to_tokens();
+                quote! { QuantizationScheme::PerTensorAffine(#q_type) }
+            }
+            Self::PerTensorSymmetric(q_type) => {
+                let q_type = q_type.to_tokens();
+                quote! { QuantizationScheme::PerTensorSymmetric(#q_type) }
+            }
+            Self::PerChannelAffine(q_type, axis) => {
+                let q_type = q_type.to_tokens();
+                let axis = axis.to_tokens();
+                quote! { QuantizationScheme::PerChannelAffine(#q_type, #axis) }
+            }
+            Self::PerChannelSymmetric(q_type, axis) => {
+                let q_type = q_type.to_tokens();
+                let axis = axis.to_tokens();
+                quote! { QuantizationScheme::PerChannelSymmetric(#q_type, #axis) }
+            }
+            Self::PerBlockAffine(q_type, block_size) => {
+                let q_type = q_type.to_tokens();
+                let block_size = block_size.to_tokens();
+                quote! { QuantizationScheme::PerBlockAffine(#q_type, #block_size) }
+            }
+            Self::PerBlockSymmetric(q_type, block_size) => {
+                let q_type = q_type.to_tokens();
+                let block_size = block_size.to_tokens();
+                quote! { QuantizationScheme::PerBlockSymmetric(#q_type, #block_size) }
+            }
+        }
+    }
+}
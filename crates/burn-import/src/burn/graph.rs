@@ -7,7 +7,7 @@ use burn::record::{
     BinFileRecorder, BurnRecord, FileRecorder, NamedMpkFileRecorder, NamedMpkGzFileRecorder,
     PrecisionSettings, PrettyJsonFileRecorder, Recorder,
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use serde::{
     ser::{SerializeMap, SerializeTuple},
@@ -36,7 +36,7 @@ pub enum RecordType {
 }
 
 /// Burn graph intermediate representation of modules and tensor operations.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct BurnGraph<PS: PrecisionSettings> {
     nodes: Vec<Node<PS>>,
     scope: Scope,
@@ -96,7 +96,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<PrettyJsonFileRecorder<PS>>(StructMap(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
@@ -118,7 +118,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<NamedMpkGzFileRecorder<PS>>(StructMap(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
@@ -140,7 +140,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<NamedMpkGzFileRecorder<PS>>(StructMap(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
@@ -163,7 +163,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<BinFileRecorder<PS>>(StructTuple(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
@@ -254,8 +254,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
     fn register_imports(&mut self) {
         // Register imports from nodes
-        self.nodes
-            .iter()
+        all_nodes(&self.nodes)
+            .into_iter()
             .for_each(|node| node.register_imports(&mut self.imports));
 
         // Combine input and output types into a single vector
@@ -285,16 +285,15 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     }
     /// Build the scope state to make sure tensor clones are added where needed.
     fn build_scope(&mut self) {
+        self.scope = self.new_scope(&[]);
+    }
+
+    /// Create the scope state of the graph, with the values captured from the enclosing graph
+    /// when it is a subgraph.
+    fn new_scope(&self, captures: &[Type]) -> Scope {
         log::debug!("Building the scope nodes len => '{}'", self.nodes.len());
 
-        fn to_tensor(ty: Type) -> Option<TensorType> {
-            match ty {
-                Type::Tensor(tensor) => Some(tensor),
-                Type::Scalar(_) => None,
-                Type::Other(_) => None,
-                Type::Shape(_) => None,
-            }
-        }
+        let mut scope = Scope::default();
 
         // Register graph tensor input with 0 as node position
         self.graph_input_types
@@ -302,7 +301,17 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             .into_iter()
             .flat_map(to_tensor)
             .for_each(|tensor| {
-                self.scope.tensor_register_variable(&tensor, 0);
+                scope.tensor_register_variable(&tensor, 0);
+            });
+
+        // Captured values are never moved, since they are still owned by the enclosing graph
+        captures
+            .iter()
+            .cloned()
+            .flat_map(to_tensor)
+            .for_each(|tensor| {
+                scope.tensor_register_variable(&tensor, 0);
+                scope.tensor_register_future_use(&tensor, self.nodes.len() + 1);
             });
 
         self.nodes
@@ -313,19 +322,25 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                     .into_iter()
                     .flat_map(to_tensor)
                     .for_each(|tensor| {
-                        self.scope
-                            .tensor_register_variable(&tensor, node_position + 1);
+                        scope.tensor_register_variable(&tensor, node_position + 1);
                     });
                 // Since the graph is guaranteed to be a DAG, we can safely register future uses
                 // of the inputs (which are the previous nodes' outputs)
                 node.input_types()
                     .into_iter()
                     .flat_map(to_tensor)
-                    .for_each(|tensor| {
-                        self.scope
-                            .tensor_register_future_use(&tensor, node_position)
-                    });
+                    .for_each(|tensor| scope.tensor_register_future_use(&tensor, node_position));
             });
+
+        // The outputs are used after the last node, so they must be cloned when also used by
+        // another node
+        self.graph_output_types
+            .clone()
+            .into_iter()
+            .flat_map(to_tensor)
+            .for_each(|tensor| scope.tensor_register_future_use(&tensor, self.nodes.len()));
+
+        scope
     }
 
     fn register_record_file(&mut self, file: PathBuf, recorder_str: &str) {
@@ -390,8 +405,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
     fn codegen_struct(&self) -> TokenStream {
         let mut body = quote! {};
-        self.nodes
-            .iter()
+        all_nodes(&self.nodes)
+            .into_iter()
             .filter_map(|node| node.field_type())
             .map(|field| {
                 let name = field.name();
@@ -426,14 +441,13 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     fn codegen_new(&self) -> TokenStream {
         let mut body = quote! {};
 
-        self.nodes
-            .iter()
+        all_nodes(&self.nodes)
+            .into_iter()
             .map(|node| node.field_init())
             .for_each(|code| body.extend(code));
 
-        let fields = self
-            .nodes
-            .iter()
+        let fields = all_nodes(&self.nodes)
+            .into_iter()
             .flat_map(|node| node.field_type())
             .map(|field| field.name().clone())
             .collect::<Vec<_>>();
@@ -519,6 +533,76 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         }
     }
 
+    /// Generate the forward pass of a subgraph (e.g., a branch of an `If` node) as statements
+    /// evaluating to its outputs, to be inlined in the forward pass of the enclosing graph.
+    ///
+    /// The captured values of the enclosing graph are always cloned, since they can still be used
+    /// afterward (e.g., by the next iteration of a loop).
+    pub(crate) fn codegen_subgraph(&self, captures: &[Type]) -> TokenStream {
+        let mut scope = self.new_scope(captures);
+        let output_position = self.nodes.len();
+
+        let mut body = quote! {};
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| node.forward(&mut scope, index))
+            .for_each(|code| body.extend(code));
+
+        let outputs = self
+            .graph_output_types
+            .iter()
+            .map(|output| match output {
+                Type::Tensor(tensor) => scope.tensor_use_owned(tensor, output_position),
+                _ => {
+                    let name = output.name();
+                    quote! { #name }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match outputs.len() {
+            1 => quote! {
+                #body
+                #(#outputs)*
+            },
+            _ => quote! {
+                #body
+                (#(#outputs),*)
+            },
+        }
+    }
+
+    /// Register the input and output types of a subgraph, which can directly be inputs of the
+    /// subgraph or values of the enclosing graph.
+    pub(crate) fn register_subgraph_input_output(
+        &mut self,
+        input_types: Vec<Type>,
+        output_types: Vec<Type>,
+    ) {
+        self.graph_input_types = input_types;
+        self.graph_output_types = output_types;
+    }
+
+    /// The input types of the graph.
+    pub(crate) fn input_types(&self) -> &[Type] {
+        &self.graph_input_types
+    }
+
+    /// The output types of the graph.
+    pub(crate) fn output_types(&self) -> &[Type] {
+        &self.graph_output_types
+    }
+
+    /// Whether the value is used by the nodes of the graph or returned by the graph.
+    pub(crate) fn uses(&self, name: &Ident) -> bool {
+        self.nodes
+            .iter()
+            .flat_map(|node| node.input_types())
+            .chain(self.graph_output_types.iter().cloned())
+            .any(|ty| ty.name() == name)
+    }
+
     /// Register the input and output types of the graph using the passed in names.
     /// The names must be unique and match the names of the inputs and outputs of the nodes.
     /// The order will be preserved.
@@ -572,7 +656,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
 #[derive(new, Debug)]
 struct BurnGraphState<'a, PS: PrecisionSettings> {
-    nodes: &'a Vec<Node<PS>>,
+    nodes: Vec<&'a Node<PS>>,
 }
 
 /// Represents a custom serialization strategy for the graph state in the module struct.
@@ -644,6 +728,32 @@ impl<PS: PrecisionSettings> Serialize for StructTuple<'_, PS> {
     }
 }
 
+/// All the nodes, including the nodes of the subgraphs, whose fields are part of the generated
+/// module.
+fn all_nodes<PS: PrecisionSettings>(nodes: &[Node<PS>]) -> Vec<&Node<PS>> {
+    nodes
+        .iter()
+        .flat_map(|node| {
+            let subgraph_nodes = node
+                .subgraphs()
+                .into_iter()
+                .flat_map(|subgraph| all_nodes(&subgraph.nodes));
+            core::iter::once(node)
+                .chain(subgraph_nodes)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn to_tensor(ty: Type) -> Option<TensorType> {
+    match ty {
+        Type::Tensor(tensor) => Some(tensor),
+        Type::Scalar(_) => None,
+        Type::Other(_) => None,
+        Type::Shape(_) => None,
+    }
+}
+
 fn extract_type_name_by_type<T: ?Sized>() -> String {
     let full_type_name = type_name::<T>();
     full_type_name
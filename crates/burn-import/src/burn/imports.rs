@@ -3,7 +3,7 @@ use quote::quote;
 use std::collections::HashSet;
 
 /// Keep track of imported modules.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct BurnImports {
     imports: HashSet<String>,
 }
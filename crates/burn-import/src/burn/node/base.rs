@@ -3,20 +3,25 @@ use std::marker::PhantomData;
 use super::{
     argmax::ArgMaxNode, avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode,
     batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
-    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
-    conv2d::Conv2dNode, conv3d::Conv3dNode, conv_transpose_1d::ConvTranspose1dNode,
+    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, control_flow::IfNode,
+    control_flow::LoopNode, control_flow::ScanNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
+    conv3d::Conv3dNode, conv_transpose_1d::ConvTranspose1dNode,
     conv_transpose_2d::ConvTranspose2dNode, conv_transpose_3d::ConvTranspose3dNode,
-    dropout::DropoutNode, expand::ExpandNode, floor::FloorNode, gather::GatherNode,
-    gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
-    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode,
-    pad::PadNode, prelu::PReluNode, random_normal::RandomNormalNode,
-    random_normal_like::RandomNormalLikeNode, random_uniform::RandomUniformNode,
-    random_uniform_like::RandomUniformLikeNode, range::RangeNode, reshape::ReshapeNode,
-    resize::ResizeNode, slice::SliceNode, squeeze::SqueezeNode, sum::SumNode, tile::TileNode,
-    top_k::TopKNode, trilu::TriluNode, unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    dropout::DropoutNode, einsum::EinsumNode, expand::ExpandNode, floor::FloorNode,
+    gather::GatherNode, gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
+    grid_sample::GridSampleNode, gru::GruNode, layer_norm::LayerNormNode, linear::LinearNode,
+    lstm::LstmNode, mask_where::WhereNode, matmul::MatmulNode, max_pool1d::MaxPool1dNode,
+    max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode, pad::PadNode, prelu::PReluNode,
+    qlinear_conv::ConvIntegerNode, qlinear_conv::QLinearConvNode,
+    qlinear_matmul::MatMulIntegerNode, qlinear_matmul::QLinearMatMulNode,
+    quantization::DequantizeLinearNode, quantization::QuantizeLinearNode,
+    random_normal::RandomNormalNode, random_normal_like::RandomNormalLikeNode,
+    random_uniform::RandomUniformNode, random_uniform_like::RandomUniformLikeNode,
+    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, rnn::RnnNode, slice::SliceNode,
+    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode,
+    unary::UnaryNode, unsqueeze::UnsqueezeNode,
 };
-use crate::burn::{BurnImports, Scope, Type};
+use crate::burn::{graph::BurnGraph, BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use serde::Serialize;
@@ -94,27 +99,41 @@ pub enum Node<PS: PrecisionSettings> {
     Conv1d(Conv1dNode),
     Conv2d(Conv2dNode),
     Conv3d(Conv3dNode),
+    ConvInteger(ConvIntegerNode),
     ConvTranspose1d(ConvTranspose1dNode),
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
     PRelu(PReluNode),
+    DequantizeLinear(DequantizeLinearNode),
     Dropout(DropoutNode),
+    Einsum(EinsumNode),
     Expand(ExpandNode),
     Floor(FloorNode),
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GridSample(GridSampleNode),
+    Gru(GruNode),
+    If(IfNode<PS>),
     LayerNorm(LayerNormNode),
     Linear(LinearNode),
+    Loop(LoopNode<PS>),
+    Lstm(LstmNode),
     Matmul(MatmulNode),
+    MatMulInteger(MatMulIntegerNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
     Mean(MeanNode),
     OneHot(OneHotNode),
     Pad(PadNode),
+    QLinearConv(QLinearConvNode),
+    QLinearMatMul(QLinearMatMulNode),
+    QuantizeLinear(QuantizeLinearNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
+    Rnn(RnnNode),
+    Scan(ScanNode<PS>),
     Slice(SliceNode),
     Squeeze(SqueezeNode),
     Sum(SumNode),
@@ -149,27 +168,41 @@ macro_rules! match_all {
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
             Node::Conv3d(node) => $func(node),
+            Node::ConvInteger(node) => $func(node),
             Node::ConvTranspose1d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
             Node::PRelu(node) => $func(node),
+            Node::DequantizeLinear(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Einsum(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Floor(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GridSample(node) => $func(node),
+            Node::Gru(node) => $func(node),
+            Node::If(node) => $func(node),
             Node::LayerNorm(node) => $func(node),
             Node::Linear(node) => $func(node),
+            Node::Loop(node) => $func(node),
+            Node::Lstm(node) => $func(node),
             Node::Matmul(node) => $func(node),
+            Node::MatMulInteger(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Mean(node) => $func(node),
             Node::OneHot(node) => $func(node),
             Node::Pad(node) => $func(node),
+            Node::QLinearConv(node) => $func(node),
+            Node::QLinearMatMul(node) => $func(node),
+            Node::QuantizeLinear(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
+            Node::Rnn(node) => $func(node),
+            Node::Scan(node) => $func(node),
             Node::Slice(node) => $func(node),
             Node::Squeeze(node) => $func(node),
             Node::Sum(node) => $func(node),
@@ -212,27 +245,41 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv1d(_) => "conv1d",
             Node::Conv2d(_) => "conv2d",
             Node::Conv3d(_) => "conv3d",
+            Node::ConvInteger(_) => "conv_integer",
             Node::ConvTranspose1d(_) => "conv_transpose1d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
             Node::PRelu(_) => "prelu",
+            Node::DequantizeLinear(_) => "dequantize_linear",
             Node::Dropout(_) => "dropout",
+            Node::Einsum(_) => "einsum",
             Node::Expand(_) => "expand",
             Node::Floor(_) => "floor",
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GridSample(_) => "grid_sample",
+            Node::Gru(_) => "gru",
+            Node::If(_) => "if",
             Node::LayerNorm(_) => "layer_norm",
             Node::Linear(_) => "linear",
+            Node::Loop(_) => "loop",
+            Node::Lstm(_) => "lstm",
             Node::Matmul(_) => "matmul",
+            Node::MatMulInteger(_) => "matmul_integer",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Mean(_) => "mean",
             Node::OneHot(_) => "one_hot",
             Node::Pad(_) => "pad",
+            Node::QLinearConv(_) => "qlinear_conv",
+            Node::QLinearMatMul(_) => "qlinear_matmul",
+            Node::QuantizeLinear(_) => "quantize_linear",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
+            Node::Rnn(_) => "rnn",
+            Node::Scan(_) => "scan",
             Node::Slice(_) => "slice",
             Node::Squeeze(_) => "squeeze",
             Node::Sum(_) => "add",
@@ -250,6 +297,16 @@ impl<PS: PrecisionSettings> Node<PS> {
             _ => unimplemented!(),
         }
     }
+
+    /// The subgraphs of the control flow nodes.
+    pub fn subgraphs(&self) -> Vec<&BurnGraph<PS>> {
+        match self {
+            Node::If(node) => vec![&node.then_branch, &node.else_branch],
+            Node::Loop(node) => vec![&node.body],
+            Node::Scan(node) => vec![&node.body],
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for Node<PS> {
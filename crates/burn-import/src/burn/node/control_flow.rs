@@ -0,0 +1,590 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{graph::BurnGraph, BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Node for the ONNX If operator.
+///
+/// Both branches are generated as the blocks of an `if` expression.
+#[derive(Debug, Clone, new)]
+pub struct IfNode<PS: PrecisionSettings> {
+    pub condition: Type,
+    pub then_branch: BurnGraph<PS>,
+    pub else_branch: BurnGraph<PS>,
+    /// The values of the enclosing graph used by the branches.
+    pub captures: Vec<Type>,
+    pub outputs: Vec<Type>,
+}
+
+/// Node for the ONNX Loop operator.
+///
+/// The body is generated as the block of a `while` loop, updating the loop carried values and
+/// collecting the scan outputs of each iteration.
+#[derive(Debug, Clone, new)]
+pub struct LoopNode<PS: PrecisionSettings> {
+    pub max_trip_count: Option<Type>,
+    pub condition: Option<Type>,
+    pub initial_values: Vec<Type>,
+    pub body: BurnGraph<PS>,
+    /// The values of the enclosing graph used by the body.
+    pub captures: Vec<Type>,
+    pub outputs: Vec<Type>,
+}
+
+/// Node for the ONNX Scan operator.
+///
+/// The body is generated as the block of a `for` loop over the slices of the scan inputs, updating
+/// the state values and collecting the scan outputs of each iteration.
+#[derive(Debug, Clone, new)]
+pub struct ScanNode<PS: PrecisionSettings> {
+    pub initial_states: Vec<Type>,
+    pub scan_inputs: Vec<TensorType>,
+    pub body: BurnGraph<PS>,
+    /// The values of the enclosing graph used by the body.
+    pub captures: Vec<Type>,
+    pub outputs: Vec<Type>,
+    pub config: ScanConfig,
+}
+
+/// The axes and directions of the scan inputs and outputs.
+#[derive(Debug, Clone, new)]
+pub struct ScanConfig {
+    pub input_axes: Vec<usize>,
+    pub input_reversed: Vec<bool>,
+    pub output_axes: Vec<usize>,
+    pub output_reversed: Vec<bool>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for IfNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![self.condition.clone()];
+        inputs.extend(self.captures.iter().cloned());
+        inputs
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let condition = use_value(scope, &self.condition, node_position);
+        let condition = bool_value(&self.condition, condition);
+        use_captures(scope, &self.captures, node_position);
+
+        let then_branch = self.then_branch.codegen_subgraph(&self.captures);
+        let else_branch = self.else_branch.codegen_subgraph(&self.captures);
+        let outputs = tuple(self.outputs.iter().map(|output| output.name()));
+
+        quote! {
+            let #outputs = if #condition {
+                #then_branch
+            } else {
+                #else_branch
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::If(self)
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LoopNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = Vec::new();
+        inputs.extend(self.max_trip_count.iter().cloned());
+        inputs.extend(self.condition.iter().cloned());
+        inputs.extend(self.initial_values.iter().cloned());
+        inputs.extend(self.captures.iter().cloned());
+        inputs
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let max_trip_count = self.max_trip_count.as_ref().map(|max_trip_count| {
+            let value = use_value(scope, max_trip_count, node_position);
+            match max_trip_count {
+                Type::Tensor(_) => quote! { #value.into_scalar().elem::<i64>() },
+                _ => value,
+            }
+        });
+        let condition = match &self.condition {
+            Some(condition) => bool_value(condition, use_value(scope, condition, node_position)),
+            None => quote! { true },
+        };
+        let initial_values = self
+            .initial_values
+            .iter()
+            .map(|value| use_value(scope, value, node_position))
+            .collect::<Vec<_>>();
+        use_captures(scope, &self.captures, node_position);
+
+        // The body inputs are the iteration number, the condition and the loop carried values,
+        // while the body outputs are the condition, the loop carried values and the scan outputs
+        let body_inputs = self.body.input_types();
+        let iteration_name = body_inputs[0].name();
+        let condition_name = body_inputs[1].name();
+        let carried = body_inputs[2..].iter().map(Type::name).collect::<Vec<_>>();
+        let next_carried = carried
+            .iter()
+            .map(|name| Ident::new(&format!("next_{name}"), Span::call_site()))
+            .collect::<Vec<_>>();
+        let scan_outputs = &self.outputs[carried.len()..];
+        let scan_output_names = scan_outputs.iter().map(Type::name).collect::<Vec<_>>();
+        let scan_steps = step_names(scan_outputs);
+        let next_condition = bool_value(&self.body.output_types()[0], quote! { next_condition });
+
+        let body = self.body.codegen_subgraph(&self.captures);
+        let body_outputs = tuple(
+            [quote! { next_condition }]
+                .into_iter()
+                .chain(next_carried.iter().map(|name| quote! { #name }))
+                .chain(scan_steps.iter().map(|name| quote! { #name })),
+        );
+
+        // Only keep track of the iteration number when it is needed
+        let uses_iteration = self.body.uses(iteration_name);
+        let (iteration_init, iteration_increment) = match max_trip_count.is_some() || uses_iteration
+        {
+            true => (
+                quote! { let mut iteration = 0i64; },
+                quote! { iteration += 1; },
+            ),
+            false => (quote! {}, quote! {}),
+        };
+        let (max_trip_count_init, loop_condition) = match max_trip_count {
+            Some(max_trip_count) => (
+                quote! { let max_trip_count = #max_trip_count; },
+                quote! { condition && iteration < max_trip_count },
+            ),
+            None => (quote! {}, quote! { condition }),
+        };
+        let iteration_binding = match uses_iteration {
+            true => quote! { let #iteration_name = iteration; },
+            false => quote! {},
+        };
+        let condition_binding = match self.body.uses(condition_name) {
+            true => quote! { let #condition_name = condition; },
+            false => quote! {},
+        };
+
+        // The scan outputs are collected in vectors created from empty slices, since `Vec` isn't
+        // in scope of the `no_std` models
+        let stacked_outputs = stacked_outputs(scan_outputs, |_| 0, |_| false);
+        let outputs = tuple(self.outputs.iter().map(|output| output.name()));
+        let results = tuple(
+            carried
+                .iter()
+                .map(|name| quote! { #name })
+                .chain(stacked_outputs),
+        );
+
+        quote! {
+            let #outputs = {
+                #(let mut #carried = #initial_values;)*
+                #(let mut #scan_output_names = [].to_vec();)*
+                let mut condition = #condition;
+                #max_trip_count_init
+                #iteration_init
+
+                while #loop_condition {
+                    #iteration_binding
+                    #condition_binding
+                    let #body_outputs = {
+                        #body
+                    };
+                    condition = #next_condition;
+                    #(#carried = #next_carried;)*
+                    #(#scan_output_names.push(#scan_steps);)*
+                    #iteration_increment
+                }
+
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let Some(Type::Tensor(_)) = self.max_trip_count {
+            imports.register("burn::tensor::ElementConversion");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Loop(self)
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScanNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = self.initial_states.clone();
+        inputs.extend(self.scan_inputs.iter().cloned().map(Type::Tensor));
+        inputs.extend(self.captures.iter().cloned());
+        inputs
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let initial_states = self
+            .initial_states
+            .iter()
+            .map(|state| use_value(scope, state, node_position))
+            .collect::<Vec<_>>();
+        let scan_inputs = self
+            .scan_inputs
+            .iter()
+            .map(|input| scope.tensor_use_owned(input, node_position))
+            .collect::<Vec<_>>();
+        use_captures(scope, &self.captures, node_position);
+
+        // The body inputs are the state values and the slices of the scan inputs, while the body
+        // outputs are the state values and the slices of the scan outputs
+        let body_inputs = self.body.input_types();
+        let states = body_inputs[..initial_states.len()]
+            .iter()
+            .map(Type::name)
+            .collect::<Vec<_>>();
+        let next_states = states
+            .iter()
+            .map(|name| Ident::new(&format!("next_{name}"), Span::call_site()))
+            .collect::<Vec<_>>();
+        let sequences = (0..scan_inputs.len())
+            .map(|i| Ident::new(&format!("scan_input{}", i + 1), Span::call_site()))
+            .collect::<Vec<_>>();
+        let slices = body_inputs[states.len()..]
+            .iter()
+            .zip(self.scan_inputs.iter().zip(sequences.iter()))
+            .enumerate()
+            .map(|(i, (slice, (input, sequence)))| {
+                let name = slice.name();
+                let axis = self.config.input_axes[i].to_tokens();
+                let dim = (input.dim - 1).to_tokens();
+                let index = match self.config.input_reversed[i] {
+                    true => quote! { sequence_length - i - 1 },
+                    false => quote! { i },
+                };
+
+                quote! {
+                    let #name = #sequence.clone().narrow(#axis, #index, 1).squeeze::<#dim>(#axis);
+                }
+            })
+            .collect::<Vec<_>>();
+        let first_axis = self.config.input_axes[0].to_tokens();
+
+        let scan_outputs = &self.outputs[states.len()..];
+        let scan_output_names = scan_outputs.iter().map(Type::name).collect::<Vec<_>>();
+        let scan_steps = step_names(scan_outputs);
+
+        let body = self.body.codegen_subgraph(&self.captures);
+        let body_outputs = tuple(
+            next_states
+                .iter()
+                .chain(scan_steps.iter())
+                .map(|name| quote! { #name }),
+        );
+
+        let stacked_outputs = stacked_outputs(
+            scan_outputs,
+            |i| self.config.output_axes[i],
+            |i| self.config.output_reversed[i],
+        );
+        let outputs = tuple(self.outputs.iter().map(|output| output.name()));
+        let results = tuple(
+            states
+                .iter()
+                .map(|name| quote! { #name })
+                .chain(stacked_outputs),
+        );
+
+        quote! {
+            let #outputs = {
+                #(let mut #states = #initial_states;)*
+                #(let #sequences = #scan_inputs;)*
+                #(let mut #scan_output_names = [].to_vec();)*
+                let sequence_length = scan_input1.dims()[#first_axis];
+
+                for i in 0..sequence_length {
+                    #(#slices)*
+                    let #body_outputs = {
+                        #body
+                    };
+                    #(#states = #next_states;)*
+                    #(#scan_output_names.push(#scan_steps);)*
+                }
+
+                #results
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Scan(self)
+    }
+}
+
+/// Use a value of the enclosing graph.
+fn use_value(scope: &mut Scope, value: &Type, node_position: usize) -> TokenStream {
+    match value {
+        Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+        _ => {
+            let name = value.name();
+            quote! { #name }
+        }
+    }
+}
+
+/// Use the values of the enclosing graph captured by a subgraph, which clones them when needed.
+fn use_captures(scope: &mut Scope, captures: &[Type], node_position: usize) {
+    for capture in captures {
+        use_value(scope, capture, node_position);
+    }
+}
+
+/// Convert a condition to a `bool`, since it can be a tensor with a single element.
+fn bool_value(ty: &Type, value: TokenStream) -> TokenStream {
+    match ty {
+        Type::Tensor(_) => quote! { #value.into_scalar() },
+        _ => value,
+    }
+}
+
+/// The names of the values of the scan outputs computed at each iteration.
+fn step_names(scan_outputs: &[Type]) -> Vec<Ident> {
+    scan_outputs
+        .iter()
+        .map(|output| Ident::new(&format!("{}_step", output.name()), Span::call_site()))
+        .collect()
+}
+
+/// Stack the values of the scan outputs computed at each iteration.
+fn stacked_outputs(
+    scan_outputs: &[Type],
+    axis: impl Fn(usize) -> usize,
+    reversed: impl Fn(usize) -> bool,
+) -> Vec<TokenStream> {
+    scan_outputs
+        .iter()
+        .enumerate()
+        .map(|(i, output)| {
+            let Type::Tensor(tensor) = output else {
+                panic!("Scan outputs should be tensors, got {:?}", output);
+            };
+            let name = &tensor.name;
+            let dim = tensor.dim.to_tokens();
+            let axis = axis(i).to_tokens();
+
+            match reversed(i) {
+                true => quote! { Tensor::stack::<#dim>(#name, #axis).flip([#axis]) },
+                false => quote! { Tensor::stack::<#dim>(#name, #axis) },
+            }
+        })
+        .collect()
+}
+
+/// A tuple of values, or the value itself when there is only one.
+fn tuple<T: quote::ToTokens>(values: impl Iterator<Item = T>) -> TokenStream {
+    let values = values.collect::<Vec<_>>();
+
+    match values.len() {
+        1 => quote! { #(#values)* },
+        _ => quote! { (#(#values),*) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::node::{binary::BinaryNode, tests::one_node_graph, unary::UnaryNode};
+    use crate::burn::{ScalarKind, ScalarType};
+    use burn::record::FullPrecisionSettings;
+
+    fn subgraph<N: NodeCodegen<FullPrecisionSettings> + 'static>(
+        node: N,
+        inputs: Vec<Type>,
+        outputs: Vec<Type>,
+    ) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::default();
+        graph.register(node);
+        graph.register_subgraph_input_output(inputs, outputs);
+        graph
+    }
+
+    fn tensor(name: &str, dim: usize) -> Type {
+        Type::Tensor(TensorType::new_float(name, dim))
+    }
+
+    #[test]
+    fn test_codegen_if() {
+        let then_branch = subgraph(
+            UnaryNode::relu(tensor("input", 2), tensor("relu_out", 2)),
+            vec![],
+            vec![tensor("relu_out", 2)],
+        );
+        let else_branch = subgraph(
+            UnaryNode::exp(tensor("input", 2), tensor("exp_out", 2)),
+            vec![],
+            vec![tensor("exp_out", 2)],
+        );
+
+        one_node_graph(
+            IfNode::new(
+                Type::Scalar(ScalarType::new("cond", ScalarKind::Bool)),
+                then_branch,
+                else_branch,
+                vec![tensor("input", 2)],
+                vec![tensor("output", 2)],
+            ),
+            quote! {
+                pub fn forward(&self, cond: bool, input: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let output = if cond {
+                        let relu_out = burn::tensor::activation::relu(input.clone());
+                        relu_out
+                    } else {
+                        let exp_out = input.clone().exp();
+                        exp_out
+                    };
+
+                    output
+                }
+            },
+            vec!["cond".to_string(), "input".to_string()],
+            vec!["output".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_codegen_loop() {
+        let body = subgraph(
+            BinaryNode::add(
+                tensor("loop_body_input3", 2),
+                tensor("bias", 2),
+                tensor("add_out", 2),
+            ),
+            vec![
+                Type::Scalar(ScalarType::new("loop_body_input1", ScalarKind::Int64)),
+                Type::Scalar(ScalarType::new("loop_body_input2", ScalarKind::Bool)),
+                tensor("loop_body_input3", 2),
+            ],
+            vec![
+                Type::Scalar(ScalarType::new("loop_body_input2", ScalarKind::Bool)),
+                tensor("add_out", 2),
+                tensor("add_out", 2),
+            ],
+        );
+
+        one_node_graph(
+            LoopNode::new(
+                Some(Type::Scalar(ScalarType::new(
+                    "trip_count",
+                    ScalarKind::Int64,
+                ))),
+                Some(Type::Scalar(ScalarType::new("cond", ScalarKind::Bool))),
+                vec![tensor("input", 2)],
+                body,
+                vec![tensor("bias", 2)],
+                vec![tensor("output", 2), tensor("scan_output", 3)],
+            ),
+            quote! {
+                pub fn forward(
+                    &self,
+                    trip_count: i64,
+                    cond: bool,
+                    input: Tensor<B, 2>,
+                    bias: Tensor<B, 2>
+                ) -> (Tensor<B, 2>, Tensor<B, 3>) {
+                    let (output, scan_output) = {
+                        let mut loop_body_input3 = input;
+                        let mut scan_output = [].to_vec();
+                        let mut condition = cond;
+                        let max_trip_count = trip_count;
+                        let mut iteration = 0i64;
+
+                        while condition && iteration < max_trip_count {
+                            let loop_body_input2 = condition;
+                            let (next_condition, next_loop_body_input3, scan_output_step) = {
+                                let add_out = loop_body_input3.add(bias.clone());
+                                (loop_body_input2, add_out.clone(), add_out)
+                            };
+                            condition = next_condition;
+                            loop_body_input3 = next_loop_body_input3;
+                            scan_output.push(scan_output_step);
+                            iteration += 1;
+                        }
+
+                        (loop_body_input3, Tensor::stack::<3>(scan_output, 0))
+                    };
+
+                    (output, scan_output)
+                }
+            },
+            vec![
+                "trip_count".to_string(),
+                "cond".to_string(),
+                "input".to_string(),
+                "bias".to_string(),
+            ],
+            vec!["output".to_string(), "scan_output".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_codegen_scan() {
+        let body = subgraph(
+            BinaryNode::add(
+                tensor("scan_body_input1", 2),
+                tensor("scan_body_input2", 2),
+                tensor("add_out", 2),
+            ),
+            vec![tensor("scan_body_input1", 2), tensor("scan_body_input2", 2)],
+            vec![tensor("add_out", 2), tensor("add_out", 2)],
+        );
+
+        one_node_graph(
+            ScanNode::new(
+                vec![tensor("state", 2)],
+                vec![TensorType::new_float("input", 3)],
+                body,
+                vec![],
+                vec![tensor("output", 2), tensor("scan_output", 3)],
+                ScanConfig::new(vec![0], vec![false], vec![1], vec![true]),
+            ),
+            quote! {
+                pub fn forward(
+                    &self,
+                    state: Tensor<B, 2>,
+                    input: Tensor<B, 3>
+                ) -> (Tensor<B, 2>, Tensor<B, 3>) {
+                    let (output, scan_output) = {
+                        let mut scan_body_input1 = state;
+                        let scan_input1 = input;
+                        let mut scan_output = [].to_vec();
+                        let sequence_length = scan_input1.dims()[0];
+
+                        for i in 0..sequence_length {
+                            let scan_body_input2 = scan_input1.clone().narrow(0, i, 1).squeeze::<2>(0);
+                            let (next_scan_body_input1, scan_output_step) = {
+                                let add_out = scan_body_input1.add(scan_body_input2);
+                                (add_out.clone(), add_out)
+                            };
+                            scan_body_input1 = next_scan_body_input1;
+                            scan_output.push(scan_output_step);
+                        }
+
+                        (scan_body_input1, Tensor::stack::<3>(scan_output, 1).flip([1]))
+                    };
+
+                    (output, scan_output)
+                }
+            },
+            vec!["state".to_string(), "input".to_string()],
+            vec!["output".to_string(), "scan_output".to_string()],
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{OtherType, Scope, TensorType, Type};
+use burn::{
+    nn::interpolate::{GridSample2dConfig, InterpolateMode},
+    record::PrecisionSettings,
+    tensor::ops::GridSamplePaddingMode,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone)]
+pub struct GridSampleNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub grid: TensorType,
+    pub output: TensorType,
+    pub config: GridSample2dConfig,
+}
+
+impl GridSampleNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        grid: TensorType,
+        output: TensorType,
+        config: GridSample2dConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    GridSample2d
+                },
+            ),
+            input,
+            grid,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GridSampleNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.grid.clone()),
+        ]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let mode = match self.config.mode {
+            InterpolateMode::Nearest => quote! { InterpolateMode::Nearest },
+            InterpolateMode::Linear => quote! { InterpolateMode::Linear },
+            InterpolateMode::Cubic => quote! { InterpolateMode::Cubic },
+        };
+        let padding_mode = match self.config.padding_mode {
+            GridSamplePaddingMode::Zeros => quote! { GridSamplePaddingMode::Zeros },
+            GridSamplePaddingMode::Border => quote! { GridSamplePaddingMode::Border },
+            GridSamplePaddingMode::Reflection => quote! { GridSamplePaddingMode::Reflection },
+        };
+        let align_corners = self.config.align_corners;
+
+        let tokens = quote! {
+            let #name = GridSample2dConfig::new()
+                .with_mode(#mode)
+                .with_padding_mode(#padding_mode)
+                .with_align_corners(#align_corners)
+                .init();
+        };
+
+        Some(tokens)
+    }
+
+    fn register_imports(&self, imports: &mut crate::burn::BurnImports) {
+        imports.register("burn::nn::interpolate::GridSample2d");
+        imports.register("burn::nn::interpolate::GridSample2dConfig");
+        imports.register("burn::nn::interpolate::InterpolateMode");
+        imports.register("burn::tensor::ops::GridSamplePaddingMode");
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        S::serialize_none(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let grid = scope.tensor_use_owned(&self.grid, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        quote! {
+            let #output = self.#field.forward(#input, #grid);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GridSample(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{grid_sample::GridSampleNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_grid_sample() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GridSampleNode::new(
+            "grid_sample",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("grid", 4),
+            TensorType::new_float("output", 4),
+            GridSample2dConfig::new()
+                .with_mode(InterpolateMode::Cubic)
+                .with_padding_mode(GridSamplePaddingMode::Border)
+                .with_align_corners(true),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "grid".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::interpolate::GridSample2d;
+            use burn::nn::interpolate::GridSample2dConfig;
+            use burn::nn::interpolate::InterpolateMode;
+            use burn::tensor::ops::GridSamplePaddingMode;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                grid_sample: GridSample2d,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let grid_sample = GridSample2dConfig::new()
+                        .with_mode(InterpolateMode::Cubic)
+                        .with_padding_mode(GridSamplePaddingMode::Border)
+                        .with_align_corners(true)
+                        .init();
+                    Self {
+                        grid_sample,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>, grid: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.grid_sample.forward(input, grid);
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
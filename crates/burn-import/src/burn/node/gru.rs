@@ -0,0 +1,255 @@
+use super::{
+    rnn::{sequence_forward, GateWeights, RecurrentConfig, RnnDirection},
+    Node, NodeCodegen, SerializationBackend,
+};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::gru::GruRecord,
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Node for the ONNX GRU operator.
+///
+/// Each direction maps to a [Gru](burn::nn::gru::Gru) module.
+#[derive(Debug, Clone)]
+pub struct GruNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_h: Option<TensorType>,
+    pub output: Option<TensorType>,
+    pub output_h: Option<TensorType>,
+    /// The weights of the update, reset and new gates of each direction.
+    pub weights: Vec<[GateWeights; 3]>,
+    pub config: RecurrentConfig,
+    /// The reset gate is applied after the hidden linear transformation (i.e.,
+    /// `linear_before_reset` in ONNX).
+    pub reset_after: bool,
+}
+
+impl GruNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_h: Option<TensorType>,
+        output: Option<TensorType>,
+        output_h: Option<TensorType>,
+        weights: Vec<[GateWeights; 3]>,
+        config: RecurrentConfig,
+        reset_after: bool,
+    ) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { [Gru<B>; 2] },
+            _ => quote! { Gru<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_h,
+            output,
+            output_h,
+            weights,
+            config,
+            reset_after,
+        }
+    }
+
+    fn gru_record<PS: PrecisionSettings>(
+        weights: &[GateWeights; 3],
+    ) -> GruRecord<SerializationBackend> {
+        let [update_gate, reset_gate, new_gate] = weights;
+
+        GruRecord {
+            update_gate: update_gate.to_record::<PS>(),
+            reset_gate: reset_gate.to_record::<PS>(),
+            new_gate: new_gate.to_record::<PS>(),
+            d_hidden: ConstantRecord::new(),
+            reset_after: ConstantRecord::new(),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GruNode {
+    fn input_types(&self) -> Vec<Type> {
+        [Some(&self.input), self.initial_h.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [self.output.as_ref(), self.output_h.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+        let reset_after = self.reset_after;
+
+        let gru = quote! {
+            GruConfig::new(#d_input, #d_hidden, #bias)
+                .with_reset_after(#reset_after)
+                .init(device)
+        };
+
+        let tokens = match self.config.direction {
+            RnnDirection::Bidirectional => quote! {
+                let #name = [#gru, #gru];
+            },
+            _ => quote! {
+                let #name = #gru;
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let record: [GruRecord<SerializationBackend>; 2] = [
+                    Self::gru_record::<PS>(&self.weights[0]),
+                    Self::gru_record::<PS>(&self.weights[1]),
+                ];
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+            _ => {
+                let record = Self::gru_record::<PS>(&self.weights[0]);
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let initial_h = self
+            .initial_h
+            .as_ref()
+            .map(|initial_h| scope.tensor_use_owned(initial_h, node_position));
+        let field = &self.field.name;
+
+        sequence_forward(
+            &self.config,
+            quote! { self.#field },
+            input,
+            initial_h,
+            &self.output,
+            &self.output_h,
+            |gru, input, state| quote! { #gru.forward(#input, #state) },
+        )
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::gru::Gru");
+        imports.register("burn::nn::gru::GruConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gru(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{gru::GruNode, test::assert_tokens},
+        TensorType,
+    };
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    fn gate_weights() -> GateWeights {
+        GateWeights::new(
+            TensorData::from([[1f32, 2f32]]),
+            None,
+            TensorData::from([[1f32, 2f32], [3f32, 4f32]]),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            TensorType::new_float("input", 3),
+            Some(TensorType::new_float("initial_h", 3)),
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("output_h", 3)),
+            vec![core::array::from_fn(|_| gate_weights())],
+            RecurrentConfig::new(1, 2, false, RnnDirection::Forward, true),
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "initial_h".to_string()],
+            vec!["output".to_string(), "output_h".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                gru: Gru<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let gru = GruConfig::new(1, 2, false)
+                        .with_reset_after(false)
+                        .init(device);
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    initial_h: Tensor<B, 3>,
+                ) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_h) = {
+                        let output = self.gru.forward(input, Some(initial_h.squeeze::<2>(1)));
+                        let seq_length = output.dims()[1];
+                        let output_h = output.clone().narrow(1, seq_length - 1, 1).squeeze::<2>(1);
+                        (output.unsqueeze_dim::<4>(2), output_h.unsqueeze_dim::<3>(1))
+                    };
+
+                    (output, output_h)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,334 @@
+use super::{
+    rnn::{bind_outputs, GateWeights, RecurrentConfig, RnnDirection},
+    Node, NodeCodegen, SerializationBackend,
+};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::{BiLstmRecord, LstmRecord},
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Node for the ONNX LSTM operator.
+///
+/// A single direction maps to [Lstm](burn::nn::Lstm), while both directions map to
+/// [BiLstm](burn::nn::BiLstm).
+#[derive(Debug, Clone)]
+pub struct LstmNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_h: Option<TensorType>,
+    pub initial_c: Option<TensorType>,
+    pub output: Option<TensorType>,
+    pub output_h: Option<TensorType>,
+    pub output_c: Option<TensorType>,
+    /// The weights of the input, forget, output and cell gates of each direction.
+    pub weights: Vec<[GateWeights; 4]>,
+    pub config: RecurrentConfig,
+}
+
+impl LstmNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_h: Option<TensorType>,
+        initial_c: Option<TensorType>,
+        output: Option<TensorType>,
+        output_h: Option<TensorType>,
+        output_c: Option<TensorType>,
+        weights: Vec<[GateWeights; 4]>,
+        config: RecurrentConfig,
+    ) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { BiLstm<B> },
+            _ => quote! { Lstm<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_h,
+            initial_c,
+            output,
+            output_h,
+            output_c,
+            weights,
+            config,
+        }
+    }
+
+    fn lstm_record<PS: PrecisionSettings>(
+        weights: &[GateWeights; 4],
+    ) -> LstmRecord<SerializationBackend> {
+        let [input_gate, forget_gate, output_gate, cell_gate] = weights;
+
+        LstmRecord {
+            input_gate: input_gate.to_record::<PS>(),
+            forget_gate: forget_gate.to_record::<PS>(),
+            output_gate: output_gate.to_record::<PS>(),
+            cell_gate: cell_gate.to_record::<PS>(),
+            d_hidden: ConstantRecord::new(),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LstmNode {
+    fn input_types(&self) -> Vec<Type> {
+        [
+            Some(&self.input),
+            self.initial_h.as_ref(),
+            self.initial_c.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|tensor| Type::Tensor(tensor.clone()))
+        .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [
+            self.output.as_ref(),
+            self.output_h.as_ref(),
+            self.output_c.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|tensor| Type::Tensor(tensor.clone()))
+        .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+
+        let tokens = match self.config.direction {
+            RnnDirection::Bidirectional => quote! {
+                let #name = BiLstmConfig::new(#d_input, #d_hidden, #bias).init(device);
+            },
+            _ => quote! {
+                let #name = LstmConfig::new(#d_input, #d_hidden, #bias).init(device);
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let record = BiLstmRecord::<SerializationBackend> {
+                    forward: Self::lstm_record::<PS>(&self.weights[0]),
+                    reverse: Self::lstm_record::<PS>(&self.weights[1]),
+                    d_hidden: ConstantRecord::new(),
+                };
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+            _ => {
+                let record = Self::lstm_record::<PS>(&self.weights[0]);
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let initial_h = self
+            .initial_h
+            .as_ref()
+            .map(|initial_h| scope.tensor_use_owned(initial_h, node_position));
+        let initial_c = self
+            .initial_c
+            .as_ref()
+            .map(|initial_c| scope.tensor_use_owned(initial_c, node_position));
+        let field = &self.field.name;
+        let state_input = |state| self.config.state_input_tokens(state);
+
+        // ONNX allows providing a single initial state, the other one defaulting to zeros
+        let state = match (initial_h.map(state_input), initial_c.map(state_input)) {
+            (Some(initial_h), Some(initial_c)) => quote! {
+                Some(LstmState::new(#initial_c, #initial_h))
+            },
+            (Some(initial_h), None) => quote! {{
+                let initial_h = #initial_h;
+                Some(LstmState::new(initial_h.zeros_like(), initial_h))
+            }},
+            (None, Some(initial_c)) => quote! {{
+                let initial_c = #initial_c;
+                Some(LstmState::new(initial_c.clone(), initial_c.zeros_like()))
+            }},
+            (None, None) => quote! { None },
+        };
+
+        let input = self.config.input_tokens(input);
+        let (input, output_value) = match self.config.direction {
+            RnnDirection::Reverse => (quote! { #input.flip([1]) }, quote! { output.flip([1]) }),
+            _ => (input, quote! { output }),
+        };
+        let output_value = self.config.output_tokens(output_value);
+        let output_h = self.config.state_output_tokens(quote! { state.hidden });
+        let output_c = self.config.state_output_tokens(quote! { state.cell });
+
+        // Only bind the output sequence and the final states when they are used
+        let output_name = match self.output {
+            Some(_) => quote! { output },
+            None => quote! { _ },
+        };
+        let state_name = match self.output_h.is_some() || self.output_c.is_some() {
+            true => quote! { state },
+            false => quote! { _ },
+        };
+
+        bind_outputs(
+            &[
+                (&self.output, output_value),
+                (&self.output_h, output_h),
+                (&self.output_c, output_c),
+            ],
+            quote! {
+                let (#output_name, #state_name) = self.#field.forward(#input, #state);
+            },
+        )
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                imports.register("burn::nn::BiLstm");
+                imports.register("burn::nn::BiLstmConfig");
+            }
+            _ => {
+                imports.register("burn::nn::Lstm");
+                imports.register("burn::nn::LstmConfig");
+            }
+        }
+
+        if self.initial_h.is_some() || self.initial_c.is_some() {
+            imports.register("burn::nn::LstmState");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Lstm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{lstm::LstmNode, test::assert_tokens},
+        TensorType,
+    };
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    fn gate_weights() -> GateWeights {
+        GateWeights::new(
+            TensorData::from([[1f32, 2f32]]),
+            Some(TensorData::from([1f32, 2f32])),
+            TensorData::from([[1f32, 2f32], [3f32, 4f32]]),
+            Some(TensorData::from([1f32, 2f32])),
+        )
+    }
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            Some(TensorType::new_float("initial_h", 3)),
+            Some(TensorType::new_float("initial_c", 3)),
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("output_h", 3)),
+            Some(TensorType::new_float("output_c", 3)),
+            vec![
+                core::array::from_fn(|_| gate_weights()),
+                core::array::from_fn(|_| gate_weights()),
+            ],
+            RecurrentConfig::new(1, 2, true, RnnDirection::Bidirectional, false),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "input".to_string(),
+                "initial_h".to_string(),
+                "initial_c".to_string(),
+            ],
+            vec![
+                "output".to_string(),
+                "output_h".to_string(),
+                "output_c".to_string(),
+            ],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::BiLstm;
+            use burn::nn::BiLstmConfig;
+            use burn::nn::LstmState;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: BiLstm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let lstm = BiLstmConfig::new(1, 2, true).init(device);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    initial_h: Tensor<B, 3>,
+                    initial_c: Tensor<B, 3>,
+                ) -> (Tensor<B, 4>, Tensor<B, 3>, Tensor<B, 3>) {
+                    let (output, output_h, output_c) = {
+                        let (output, state) = self.lstm.forward(
+                            input.swap_dims(0, 1),
+                            Some(LstmState::new(initial_c, initial_h)),
+                        );
+                        (
+                            {
+                                let [batch_size, seq_length, _] = output.dims();
+                                output
+                                    .reshape([batch_size, seq_length, 2, 2])
+                                    .permute([1, 2, 0, 3])
+                            },
+                            state.hidden,
+                            state.cell,
+                        )
+                    };
+
+                    (output, output_h, output_c)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
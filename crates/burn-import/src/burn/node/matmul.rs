@@ -38,50 +38,10 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulNode {
         let lhs = scope.tensor_use_owned(&self.lhs, node_position);
         let rhs = scope.tensor_use_owned(&self.rhs, node_position);
         let output = &self.output.name;
+        let matmul = matmul_tokens(lhs, self.lhs.dim, rhs, self.rhs.dim);
 
-        let lhs_dim = self.lhs.dim;
-        let rhs_dim = self.rhs.dim;
-
-        // Support broadcasting for missing dimensions
-        match lhs_dim.cmp(&rhs_dim) {
-            Ordering::Greater => {
-                // Alternate unsqueeze(0) -> unsqueeze(-1) -> unsqueeze(0) -> ...
-                let axes = (0..lhs_dim - rhs_dim)
-                    .map(|i| if i % 2 == 0 { 0 } else { -1 })
-                    .collect::<Vec<i64>>();
-                let axes = axes.to_tokens();
-
-                if rhs_dim == 1 {
-                    // Matrix-vector product: squeeze(-1)
-                    let squeeze_dim = lhs_dim - 1;
-                    quote! {
-                        let #output = #lhs.matmul(#rhs.unsqueeze_dims(&#axes)).squeeze(#squeeze_dim);
-                    }
-                } else {
-                    quote! {
-                        let #output = #lhs.matmul(#rhs.unsqueeze_dims(&#axes));
-                    }
-                }
-            }
-            Ordering::Less => {
-                // Always unsqueeze(0)
-                let axes = [0i64].repeat(rhs_dim - lhs_dim).to_tokens();
-
-                if lhs_dim == 1 {
-                    // Vector-matrix product: squeeze(-2)
-                    let squeeze_dim = rhs_dim - 2;
-                    quote! {
-                        let #output = #lhs.unsqueeze_dims(&#axes).matmul(#rhs).squeeze(#squeeze_dim);
-                    }
-                } else {
-                    quote! {
-                        let #output = #lhs.unsqueeze_dims(&#axes).matmul(#rhs);
-                    }
-                }
-            }
-            Ordering::Equal => quote! {
-                let #output = #lhs.matmul(#rhs);
-            },
+        quote! {
+            let #output = #matmul;
         }
     }
 
@@ -90,6 +50,57 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulNode {
     }
 }
 
+/// Returns the tokens of the matrix product between the given tensors, broadcasting the missing
+/// dimensions.
+pub(crate) fn matmul_tokens(
+    lhs: TokenStream,
+    lhs_dim: usize,
+    rhs: TokenStream,
+    rhs_dim: usize,
+) -> TokenStream {
+    // Support broadcasting for missing dimensions
+    match lhs_dim.cmp(&rhs_dim) {
+        Ordering::Greater => {
+            // Alternate unsqueeze(0) -> unsqueeze(-1) -> unsqueeze(0) -> ...
+            let axes = (0..lhs_dim - rhs_dim)
+                .map(|i| if i % 2 == 0 { 0 } else { -1 })
+                .collect::<Vec<i64>>();
+            let axes = axes.to_tokens();
+
+            if rhs_dim == 1 {
+                // Matrix-vector product: squeeze(-1)
+                let squeeze_dim = lhs_dim - 1;
+                quote! {
+                    #lhs.matmul(#rhs.unsqueeze_dims(&#axes)).squeeze(#squeeze_dim)
+                }
+            } else {
+                quote! {
+                    #lhs.matmul(#rhs.unsqueeze_dims(&#axes))
+                }
+            }
+        }
+        Ordering::Less => {
+            // Always unsqueeze(0)
+            let axes = [0i64].repeat(rhs_dim - lhs_dim).to_tokens();
+
+            if lhs_dim == 1 {
+                // Vector-matrix product: squeeze(-2)
+                let squeeze_dim = rhs_dim - 2;
+                quote! {
+                    #lhs.unsqueeze_dims(&#axes).matmul(#rhs).squeeze(#squeeze_dim)
+                }
+            } else {
+                quote! {
+                    #lhs.unsqueeze_dims(&#axes).matmul(#rhs)
+                }
+            }
+        }
+        Ordering::Equal => quote! {
+            #lhs.matmul(#rhs)
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -9,6 +9,7 @@ pub(crate) mod clip;
 pub(crate) mod concat;
 pub(crate) mod constant;
 pub(crate) mod constant_of_shape;
+pub(crate) mod control_flow;
 pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv3d;
@@ -16,13 +17,17 @@ pub(crate) mod conv_transpose_1d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
 pub(crate) mod expand;
 pub(crate) mod floor;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
 pub(crate) mod global_avg_pool;
+pub(crate) mod grid_sample;
+pub(crate) mod gru;
 pub(crate) mod layer_norm;
 pub(crate) mod linear;
+pub(crate) mod lstm;
 pub(crate) mod mask_where;
 pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
@@ -31,6 +36,9 @@ pub(crate) mod mean;
 pub(crate) mod one_hot;
 pub(crate) mod pad;
 pub(crate) mod prelu;
+pub(crate) mod qlinear_conv;
+pub(crate) mod qlinear_matmul;
+pub(crate) mod quantization;
 pub(crate) mod random_normal;
 pub(crate) mod random_normal_like;
 pub(crate) mod random_uniform;
@@ -38,6 +46,7 @@ pub(crate) mod random_uniform_like;
 pub(crate) mod range;
 pub(crate) mod reshape;
 pub(crate) mod resize;
+pub(crate) mod rnn;
 pub(crate) mod slice;
 pub(crate) mod squeeze;
 pub(crate) mod sum;
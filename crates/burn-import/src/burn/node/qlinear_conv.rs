@@ -0,0 +1,381 @@
+use super::{quantization::QuantizationConfig, Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{ConstantRecord, Param, ParamId},
+    nn::conv::{Conv2dConfig, Conv2dRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{Tensor, TensorData},
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use serde::Serialize;
+
+/// Node for the ONNX QLinearConv operator.
+///
+/// Maps to a [Conv2d](burn::nn::conv::Conv2d) module with quantized weights. The input is
+/// dequantized before the convolution and the result is quantized with the output quantization.
+#[derive(Debug, Clone)]
+pub struct QLinearConvNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    /// The quantized weights.
+    pub data_weights: TensorData,
+    /// The bias, dequantized with the product of the input and weights scales.
+    pub data_bias: Option<TensorData>,
+    pub config: Conv2dConfig,
+    pub output_config: QuantizationConfig,
+}
+
+impl QLinearConvNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        data_weights: TensorData,
+        data_bias: Option<TensorData>,
+        config: Conv2dConfig,
+        output_config: QuantizationConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(name, quote! { Conv2d<B> }),
+            input,
+            output,
+            data_weights,
+            data_bias,
+            config,
+            output_config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QLinearConvNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        Some(conv2d_init(&self.field.name, &self.config))
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = conv2d_record::<PS>(&self.data_weights, self.data_bias.as_ref());
+        Record::into_item::<PS>(record).serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+        let quantized = self
+            .output_config
+            .quantize_tokens(quote! { self.#field.forward(#input.dequantize()) });
+
+        quote! {
+            let #output = #quantized;
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::PaddingConfig2d");
+        imports.register("burn::nn::conv::Conv2d");
+        imports.register("burn::nn::conv::Conv2dConfig");
+        self.output_config.register_imports(imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QLinearConv(self)
+    }
+}
+
+/// Node for the ONNX ConvInteger operator.
+///
+/// Maps to a [Conv2d](burn::nn::conv::Conv2d) module whose weights are quantized with a unit scale
+/// and their zero point, so that their dequantized values are the integer differences convolved by
+/// the operator. The convolution is computed in floating point and converted back to integers.
+#[derive(Debug, Clone)]
+pub struct ConvIntegerNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub input_zero_point: i64,
+    pub output: TensorType,
+    /// The quantized weights.
+    pub data_weights: TensorData,
+    pub config: Conv2dConfig,
+}
+
+impl ConvIntegerNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        input_zero_point: i64,
+        output: TensorType,
+        data_weights: TensorData,
+        config: Conv2dConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(name, quote! { Conv2d<B> }),
+            input,
+            input_zero_point,
+            output,
+            data_weights,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConvIntegerNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        Some(conv2d_init(&self.field.name, &self.config))
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = conv2d_record::<PS>(&self.data_weights, None);
+        Record::into_item::<PS>(record).serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        let input = match self.input_zero_point {
+            0 => quote! { #input.float() },
+            zero_point => quote! { #input.sub_scalar(#zero_point).float() },
+        };
+
+        quote! {
+            let #output = self.#field.forward(#input).int();
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::PaddingConfig2d");
+        imports.register("burn::nn::conv::Conv2d");
+        imports.register("burn::nn::conv::Conv2dConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ConvInteger(self)
+    }
+}
+
+fn conv2d_init(name: &Ident, config: &Conv2dConfig) -> TokenStream {
+    let channels = config.channels.to_tokens();
+    let kernel_size = config.kernel_size.to_tokens();
+    let stride = config.stride.to_tokens();
+    let dilation = config.dilation.to_tokens();
+    let groups = config.groups.to_tokens();
+    let padding = config.padding.to_tokens();
+    let bias = config.bias;
+
+    quote! {
+        let #name = Conv2dConfig::new(#channels, #kernel_size)
+            .with_stride(#stride)
+            .with_padding(#padding)
+            .with_dilation(#dilation)
+            .with_groups(#groups)
+            .with_bias(#bias)
+            .init(device);
+    }
+}
+
+/// The record of the convolution, where the quantized weights are kept as-is regardless of the
+/// precision settings.
+fn conv2d_record<PS: PrecisionSettings>(
+    weights: &TensorData,
+    bias: Option<&TensorData>,
+) -> Conv2dRecord<SerializationBackend> {
+    let device = Default::default();
+
+    Conv2dRecord {
+        weight: Param::initialized(ParamId::new(), Tensor::from_data(weights.clone(), &device)),
+        bias: bias.map(|bias| {
+            Param::initialized(
+                ParamId::new(),
+                Tensor::from_data(bias.clone().convert::<PS::FloatElem>(), &device),
+            )
+        }),
+        stride: [ConstantRecord::new(); 2],
+        kernel_size: [ConstantRecord::new(); 2],
+        dilation: [ConstantRecord::new(); 2],
+        groups: ConstantRecord::new(),
+        padding: ConstantRecord::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::{
+        nn::PaddingConfig2d,
+        record::FullPrecisionSettings,
+        tensor::quantization::{QuantizationScheme, QuantizationType},
+    };
+
+    #[test]
+    fn test_codegen_qlinear_conv() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = QuantizationConfig::new(
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+            vec![0.5],
+            vec![0],
+        );
+
+        graph.register(QLinearConvNode::new(
+            "qlinearconv",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            config.quantized_data(vec![2], vec![1, 1, 1, 1]),
+            None,
+            Conv2dConfig::new([1, 1], [1, 1])
+                .with_padding(PaddingConfig2d::Valid)
+                .with_bias(false),
+            config,
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::PaddingConfig2d;
+            use burn::nn::conv::Conv2d;
+            use burn::nn::conv::Conv2dConfig;
+            use burn::tensor::quantization::QuantizationParameters;
+            use burn::tensor::quantization::QuantizationScheme;
+            use burn::tensor::quantization::QuantizationType;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                qlinearconv: Conv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let qlinearconv = Conv2dConfig::new([1, 1], [1, 1])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_groups(1)
+                        .with_bias(false)
+                        .init(device);
+
+                    Self {
+                        qlinearconv,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.qlinearconv.forward(input.dequantize()).quantize(
+                        &QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+                        QuantizationParameters {
+                            scale: Tensor::from_floats([0.5], &*self.device),
+                            offset: Some(Tensor::from_ints([0], &*self.device)),
+                        },
+                    );
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_conv_integer() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = QuantizationConfig::new(
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+            vec![1.0],
+            vec![0],
+        );
+
+        graph.register(ConvIntegerNode::new(
+            "convinteger",
+            TensorType::new_int("input", 4),
+            0,
+            TensorType::new_int("output", 4),
+            config.quantized_data(vec![2], vec![1, 1, 1, 1]),
+            Conv2dConfig::new([1, 1], [1, 1])
+                .with_padding(PaddingConfig2d::Valid)
+                .with_bias(false),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::PaddingConfig2d;
+            use burn::nn::conv::Conv2d;
+            use burn::nn::conv::Conv2dConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                convinteger: Conv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let convinteger = Conv2dConfig::new([1, 1], [1, 1])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_groups(1)
+                        .with_bias(false)
+                        .init(device);
+
+                    Self {
+                        convinteger,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4, Int>) -> Tensor<B, 4, Int> {
+                    let output = self.convinteger.forward(input.float()).int();
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,240 @@
+use super::{
+    matmul::matmul_tokens,
+    quantization::{QuantizationConfig, QuantizedInput, QuantizedWeights},
+    Node, NodeCodegen,
+};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX QLinearMatMul operator.
+///
+/// The quantized inputs are multiplied in floating point and the product is quantized with the
+/// output quantization.
+#[derive(Debug, Clone, new)]
+pub struct QLinearMatMulNode {
+    pub lhs: TensorType,
+    pub rhs: QuantizedInput,
+    pub output: TensorType,
+    pub config: QuantizationConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QLinearMatMulNode {
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.lhs.clone())];
+        inputs.extend(self.rhs.input_types());
+        inputs
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        self.rhs.field_type()
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        self.rhs.field_init()
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.rhs.field_serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let lhs = scope.tensor_use_owned(&self.lhs, node_position);
+        let rhs = self.rhs.dequantize_tokens(scope, node_position);
+        let output = &self.output.name;
+
+        let matmul = matmul_tokens(
+            quote! { #lhs.dequantize() },
+            self.lhs.dim,
+            rhs,
+            self.rhs.dim(),
+        );
+        let quantized = self.config.quantize_tokens(matmul);
+
+        quote! {
+            let #output = #quantized;
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.config.register_imports(imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QLinearMatMul(self)
+    }
+}
+
+/// Node for the ONNX MatMulInteger operator.
+///
+/// The weights are quantized with a unit scale and their zero point, so that their dequantized
+/// values are the integer differences multiplied by the operator. The product is computed in
+/// floating point and converted back to integers.
+#[derive(Debug, Clone, new)]
+pub struct MatMulIntegerNode {
+    pub lhs: TensorType,
+    pub lhs_zero_point: i64,
+    pub rhs: QuantizedWeights,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for MatMulIntegerNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.lhs.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Tensor(self.rhs.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        Some(self.rhs.field_init())
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.rhs.field_serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let lhs = scope.tensor_use_owned(&self.lhs, node_position);
+        let output = &self.output.name;
+
+        let lhs = match self.lhs_zero_point {
+            0 => quote! { #lhs.float() },
+            zero_point => quote! { #lhs.sub_scalar(#zero_point).float() },
+        };
+        let matmul = matmul_tokens(
+            lhs,
+            self.lhs.dim,
+            self.rhs.dequantize_tokens(),
+            self.rhs.field.dim,
+        );
+
+        quote! {
+            let #output = #matmul.int();
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::MatMulInteger(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, tests::one_node_graph},
+    };
+    use burn::{
+        record::FullPrecisionSettings,
+        tensor::quantization::{QuantizationScheme, QuantizationType},
+    };
+
+    #[test]
+    fn test_codegen_qlinear_matmul() {
+        one_node_graph(
+            QLinearMatMulNode::new(
+                TensorType::new_float("tensor1", 3),
+                QuantizedInput::Tensor(TensorType::new_float("tensor2", 2)),
+                TensorType::new_float("tensor3", 3),
+                QuantizationConfig::new(
+                    QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+                    vec![0.5],
+                    vec![0],
+                ),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3>, tensor2: Tensor<B, 2>) -> Tensor<B, 3> {
+                    let tensor3 = tensor1
+                        .dequantize()
+                        .matmul(tensor2.dequantize().unsqueeze_dims(&[0]))
+                        .quantize(
+                            &QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+                            QuantizationParameters {
+                                scale: Tensor::from_floats([0.5], &*self.device),
+                                offset: Some(Tensor::from_ints([0], &*self.device)),
+                            },
+                        );
+
+                    tensor3
+                }
+            },
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_codegen_matmul_integer() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = QuantizationConfig::new(
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+            vec![1.0],
+            vec![-128],
+        );
+
+        graph.register(MatMulIntegerNode::new(
+            TensorType::new_int("tensor1", 2),
+            -128,
+            QuantizedWeights::new(
+                "matmulinteger1",
+                config.quantized_data(vec![1, 2, 3, 4], vec![2, 2]),
+            ),
+            TensorType::new_int("tensor2", 2),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                matmulinteger1: burn::module::Param<Tensor<B, 2>>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let matmulinteger1: burn::module::Param<Tensor<B, 2>> = burn::nn::Initializer::Zeros.init([2, 2], device).set_require_grad(false);
+
+                    Self {
+                        matmulinteger1,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2, Int>) -> Tensor<B, 2, Int> {
+                    let tensor2 = tensor1
+                        .sub_scalar(-128i64)
+                        .float()
+                        .matmul(self.matmulinteger1.val().dequantize())
+                        .int();
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
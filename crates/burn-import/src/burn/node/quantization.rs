@@ -0,0 +1,338 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ParamId,
+    record::{ParamSerde, PrecisionSettings},
+    tensor::{
+        quantization::{QParams, QuantizationScheme, QuantizationStrategy},
+        TensorData,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// The quantization of a tensor.
+///
+/// The 8-bit integers of ONNX map to the affine `QInt8` schemes. Unsigned values are shifted to the
+/// signed range along with their zero point, which leaves the dequantized values unchanged.
+#[derive(Debug, Clone, new)]
+pub struct QuantizationConfig {
+    pub scheme: QuantizationScheme,
+    /// The scale of each group of values sharing the same quantization parameters.
+    pub scale: Vec<f32>,
+    /// The zero-point offset of each group of values sharing the same quantization parameters.
+    pub offset: Vec<i8>,
+}
+
+impl QuantizationConfig {
+    /// Returns the quantized tensor data of the given values.
+    pub fn quantized_data(&self, values: Vec<i8>, shape: Vec<usize>) -> TensorData {
+        let qparams = QParams {
+            scale: self.scale.clone(),
+            offset: Some(self.offset.clone()),
+        };
+        let strategy = QuantizationStrategy::from_qparams(self.scheme, qparams);
+
+        TensorData::quantized(values, shape, strategy)
+    }
+
+    /// Returns the tokens quantizing the given float tensor.
+    pub fn quantize_tokens(&self, tensor: TokenStream) -> TokenStream {
+        let scheme = self.scheme.to_tokens();
+        let scale = self.scale.to_tokens();
+        let offset = self
+            .offset
+            .iter()
+            .map(|offset| *offset as i64)
+            .collect::<Vec<_>>()
+            .to_tokens();
+
+        quote! {
+            #tensor.quantize(
+                &#scheme,
+                QuantizationParameters {
+                    scale: Tensor::from_floats(#scale, &*self.device),
+                    offset: Some(Tensor::from_ints(#offset, &*self.device)),
+                },
+            )
+        }
+    }
+
+    pub fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::quantization::QuantizationParameters");
+        imports.register("burn::tensor::quantization::QuantizationScheme");
+        imports.register("burn::tensor::quantization::QuantizationType");
+    }
+}
+
+/// Constant weights, stored as a quantized parameter of the model.
+#[derive(Debug, Clone)]
+pub struct QuantizedWeights {
+    pub field: TensorType,
+    /// The quantized data of the weights.
+    pub data: TensorData,
+}
+
+impl QuantizedWeights {
+    pub fn new<S: AsRef<str>>(name: S, data: TensorData) -> Self {
+        let field =
+            TensorType::new_float_with_shape(name, data.shape.len(), Some(data.shape.clone()));
+
+        Self { field, data }
+    }
+
+    pub fn field_init(&self) -> TokenStream {
+        let name = &self.field.name;
+        let ty = self.field.ty();
+        let shape = self.data.shape.to_tokens();
+
+        // The quantized values are loaded from the record
+        quote! {
+            let #name: burn::module::Param<#ty> = burn::nn::Initializer::Zeros.init(#shape, device).set_require_grad(false);
+        }
+    }
+
+    pub fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Quantized data is kept as-is regardless of the precision settings
+        ParamSerde::new(ParamId::new().to_string(), self.data.clone()).serialize(serializer)
+    }
+
+    /// Returns the tokens of the dequantized weights.
+    pub fn dequantize_tokens(&self) -> TokenStream {
+        let name = &self.field.name;
+
+        quote! { self.#name.val().dequantize() }
+    }
+}
+
+/// A quantized input of a node.
+#[derive(Debug, Clone)]
+pub enum QuantizedInput {
+    /// A quantized tensor computed during the forward pass.
+    Tensor(TensorType),
+    /// Constant quantized weights.
+    Weights(QuantizedWeights),
+}
+
+impl QuantizedInput {
+    pub fn dim(&self) -> usize {
+        match self {
+            Self::Tensor(tensor) => tensor.dim,
+            Self::Weights(weights) => weights.field.dim,
+        }
+    }
+
+    pub fn input_types(&self) -> Vec<Type> {
+        match self {
+            Self::Tensor(tensor) => vec![Type::Tensor(tensor.clone())],
+            Self::Weights(_) => vec![],
+        }
+    }
+
+    pub fn field_type(&self) -> Option<Type> {
+        match self {
+            Self::Tensor(_) => None,
+            Self::Weights(weights) => Some(Type::Tensor(weights.field.clone())),
+        }
+    }
+
+    pub fn field_init(&self) -> Option<TokenStream> {
+        match self {
+            Self::Tensor(_) => None,
+            Self::Weights(weights) => Some(weights.field_init()),
+        }
+    }
+
+    pub fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Tensor(_) => S::serialize_none(serializer),
+            Self::Weights(weights) => weights.field_serialize(serializer),
+        }
+    }
+
+    /// Returns the tokens of the dequantized input.
+    pub fn dequantize_tokens(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        match self {
+            Self::Tensor(tensor) => {
+                let tensor = scope.tensor_use_owned(tensor, node_position);
+                quote! { #tensor.dequantize() }
+            }
+            Self::Weights(weights) => weights.dequantize_tokens(),
+        }
+    }
+}
+
+/// Node for the ONNX QuantizeLinear operator.
+#[derive(Debug, Clone, new)]
+pub struct QuantizeLinearNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: QuantizationConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QuantizeLinearNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let quantized = self.config.quantize_tokens(input);
+
+        quote! {
+            let #output = #quantized;
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.config.register_imports(imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QuantizeLinear(self)
+    }
+}
+
+/// Node for the ONNX DequantizeLinear operator.
+#[derive(Debug, Clone, new)]
+pub struct DequantizeLinearNode {
+    pub input: QuantizedInput,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DequantizeLinearNode {
+    fn input_types(&self) -> Vec<Type> {
+        self.input.input_types()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        self.input.field_type()
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        self.input.field_init()
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.input.field_serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = self.input.dequantize_tokens(scope, node_position);
+        let output = &self.output.name;
+
+        quote! {
+            let #output = #input;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DequantizeLinear(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, tests::one_node_graph},
+    };
+    use burn::{record::FullPrecisionSettings, tensor::quantization::QuantizationType};
+
+    #[test]
+    fn test_codegen_quantize_linear() {
+        one_node_graph(
+            QuantizeLinearNode::new(
+                TensorType::new_float("tensor1", 4),
+                TensorType::new_float("tensor2", 4),
+                QuantizationConfig::new(
+                    QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+                    vec![0.5],
+                    vec![-128],
+                ),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.quantize(
+                        &QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+                        QuantizationParameters {
+                            scale: Tensor::from_floats([0.5], &*self.device),
+                            offset: Some(Tensor::from_ints([-128], &*self.device)),
+                        },
+                    );
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_codegen_dequantize_linear_weights() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = QuantizationConfig::new(
+            QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, 0),
+            vec![0.5, 0.25],
+            vec![0, 0],
+        );
+
+        graph.register(DequantizeLinearNode::new(
+            QuantizedInput::Weights(QuantizedWeights::new(
+                "weights",
+                config.quantized_data(vec![1, 2, 3, 4], vec![2, 2]),
+            )),
+            TensorType::new_float("tensor1", 2),
+        ));
+
+        graph.register_input_output(vec![], vec!["tensor1".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                weights: burn::module::Param<Tensor<B, 2>>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let weights: burn::module::Param<Tensor<B, 2>> = burn::nn::Initializer::Zeros.init([2, 2], device).set_require_grad(false);
+
+                    Self {
+                        weights,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self) -> Tensor<B, 2> {
+                    let tensor1 = self.weights.val().dequantize();
+
+                    tensor1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
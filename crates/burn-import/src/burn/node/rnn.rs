@@ -0,0 +1,510 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{Param, ParamId},
+    nn::{GateControllerRecord, LinearRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{Tensor, TensorData},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Direction in which a recurrent node processes the input sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RnnDirection {
+    Forward,
+    Reverse,
+    Bidirectional,
+}
+
+/// Configuration shared by the recurrent nodes (LSTM, GRU and RNN).
+#[derive(Debug, Clone, new)]
+pub struct RecurrentConfig {
+    pub d_input: usize,
+    pub d_hidden: usize,
+    pub bias: bool,
+    pub direction: RnnDirection,
+    /// The input and output sequences are `[batch_size, seq_length, ...]` instead of
+    /// `[seq_length, batch_size, ...]`.
+    pub batch_first: bool,
+}
+
+impl RecurrentConfig {
+    /// Converts the input sequence to the `[batch_size, seq_length, d_input]` layout of the Burn
+    /// recurrent modules.
+    pub(crate) fn input_tokens(&self, input: TokenStream) -> TokenStream {
+        match self.batch_first {
+            true => input,
+            false => quote! { #input.swap_dims(0, 1) },
+        }
+    }
+
+    /// Converts the output sequence of the Burn recurrent modules, with the outputs of both
+    /// directions concatenated on the last dimension, to the ONNX layout
+    /// `[seq_length, num_directions, batch_size, d_hidden]`.
+    pub(crate) fn output_tokens(&self, output: TokenStream) -> TokenStream {
+        let d_hidden = self.d_hidden.to_tokens();
+
+        match (self.direction, self.batch_first) {
+            (RnnDirection::Bidirectional, true) => quote! {{
+                let [batch_size, seq_length, _] = #output.dims();
+                #output.reshape([batch_size, seq_length, 2, #d_hidden])
+            }},
+            (RnnDirection::Bidirectional, false) => quote! {{
+                let [batch_size, seq_length, _] = #output.dims();
+                #output
+                    .reshape([batch_size, seq_length, 2, #d_hidden])
+                    .permute([1, 2, 0, 3])
+            }},
+            (_, true) => quote! { #output.unsqueeze_dim::<4>(2) },
+            (_, false) => quote! { #output.swap_dims(0, 1).unsqueeze_dim::<4>(1) },
+        }
+    }
+
+    /// Converts an initial state to the layout of the Burn recurrent modules, which is
+    /// `[batch_size, d_hidden]` for a single direction and `[2, batch_size, d_hidden]` for both.
+    pub(crate) fn state_input_tokens(&self, state: TokenStream) -> TokenStream {
+        match (self.direction, self.batch_first) {
+            (RnnDirection::Bidirectional, true) => quote! { #state.swap_dims(0, 1) },
+            (RnnDirection::Bidirectional, false) => state,
+            (_, true) => quote! { #state.squeeze::<2>(1) },
+            (_, false) => quote! { #state.squeeze::<2>(0) },
+        }
+    }
+
+    /// Converts a final state of the Burn recurrent modules to the ONNX layout
+    /// `[num_directions, batch_size, d_hidden]`.
+    pub(crate) fn state_output_tokens(&self, state: TokenStream) -> TokenStream {
+        match (self.direction, self.batch_first) {
+            (RnnDirection::Bidirectional, true) => quote! { #state.swap_dims(0, 1) },
+            (RnnDirection::Bidirectional, false) => state,
+            (_, true) => quote! { #state.unsqueeze_dim::<3>(1) },
+            (_, false) => quote! { #state.unsqueeze_dim::<3>(0) },
+        }
+    }
+}
+
+/// Weights of a recurrent gate, already transposed to the `[d_input, d_output]` layout of the
+/// Burn linear layers.
+#[derive(Debug, Clone, new)]
+pub struct GateWeights {
+    pub input_weight: TensorData,
+    pub input_bias: Option<TensorData>,
+    pub hidden_weight: TensorData,
+    pub hidden_bias: Option<TensorData>,
+}
+
+impl GateWeights {
+    /// Creates the record of the gate controller.
+    pub fn to_record<PS: PrecisionSettings>(&self) -> GateControllerRecord<SerializationBackend> {
+        GateControllerRecord {
+            input_transform: linear_record::<PS>(&self.input_weight, &self.input_bias),
+            hidden_transform: linear_record::<PS>(&self.hidden_weight, &self.hidden_bias),
+        }
+    }
+}
+
+fn linear_record<PS: PrecisionSettings>(
+    weight: &TensorData,
+    bias: &Option<TensorData>,
+) -> LinearRecord<SerializationBackend> {
+    let device = Default::default();
+
+    LinearRecord {
+        weight: Param::initialized(
+            ParamId::new(),
+            Tensor::from_data(weight.clone().convert::<PS::FloatElem>(), &device),
+        ),
+        bias: bias.as_ref().map(|bias| {
+            Param::initialized(
+                ParamId::new(),
+                Tensor::from_data(bias.clone().convert::<PS::FloatElem>(), &device),
+            )
+        }),
+    }
+}
+
+/// Binds the values of the present outputs of a recurrent node, computed by the body.
+pub(crate) fn bind_outputs(
+    outputs: &[(&Option<TensorType>, TokenStream)],
+    body: TokenStream,
+) -> TokenStream {
+    let (names, values): (Vec<_>, Vec<_>) = outputs
+        .iter()
+        .filter_map(|(output, value)| output.as_ref().map(|output| (&output.name, value)))
+        .unzip();
+
+    match names.len() {
+        1 => quote! {
+            let #(#names)* = {
+                #body
+                #(#values)*
+            };
+        },
+        _ => quote! {
+            let (#(#names),*) = {
+                #body
+                (#(#values),*)
+            };
+        },
+    }
+}
+
+/// Generates the forward pass of the recurrent modules that only return their output sequence,
+/// from which the final hidden state is extracted.
+///
+/// The `call` function generates the forward pass of the module of a single direction, from the
+/// module, the `[batch_size, seq_length, d_input]` input and the optional initial hidden state.
+pub(crate) fn sequence_forward(
+    config: &RecurrentConfig,
+    field: TokenStream,
+    input: TokenStream,
+    initial_h: Option<TokenStream>,
+    output: &Option<TensorType>,
+    output_h: &Option<TensorType>,
+    call: impl Fn(TokenStream, TokenStream, TokenStream) -> TokenStream,
+) -> TokenStream {
+    let input = config.input_tokens(input);
+    let initial_h = initial_h.map(|initial_h| config.state_input_tokens(initial_h));
+
+    let (body, output_value) = match config.direction {
+        RnnDirection::Bidirectional => {
+            let (initial_h, state_forward, state_reverse) = match initial_h {
+                Some(initial_h) => (
+                    quote! { let initial_h = #initial_h; },
+                    quote! { Some(initial_h.clone().narrow(0, 0, 1).squeeze::<2>(0)) },
+                    quote! { Some(initial_h.narrow(0, 1, 1).squeeze::<2>(0)) },
+                ),
+                None => (quote! {}, quote! { None }, quote! { None }),
+            };
+            let forward = call(
+                quote! { #field[0] },
+                quote! { input.clone() },
+                state_forward,
+            );
+            let reverse = call(
+                quote! { #field[1] },
+                quote! { input.flip([1]) },
+                state_reverse,
+            );
+            let output_h_stmt = output_h.as_ref().map(|_| {
+                quote! {
+                    let seq_length = output_forward.dims()[1];
+                    let output_h: Tensor<B, 3> = Tensor::stack(
+                        [
+                            output_forward.clone().narrow(1, seq_length - 1, 1).squeeze::<2>(1),
+                            output_reverse.clone().narrow(1, seq_length - 1, 1).squeeze::<2>(1),
+                        ]
+                        .into(),
+                        0,
+                    );
+                }
+            });
+            let output_stmt = output.as_ref().map(|_| {
+                quote! {
+                    let output = Tensor::cat([output_forward, output_reverse.flip([1])].into(), 2);
+                }
+            });
+            let body = quote! {
+                #initial_h
+                let input = #input;
+                let output_forward = #forward;
+                let output_reverse = #reverse;
+                #output_h_stmt
+                #output_stmt
+            };
+
+            (body, quote! { output })
+        }
+        direction => {
+            let state = match initial_h {
+                Some(initial_h) => quote! { Some(#initial_h) },
+                None => quote! { None },
+            };
+            let (input, output_value) = match direction {
+                RnnDirection::Reverse => (quote! { #input.flip([1]) }, quote! { output.flip([1]) }),
+                _ => (input, quote! { output }),
+            };
+            let forward = call(field, input, state);
+            let output_h_stmt = output_h.as_ref().map(|_| {
+                quote! {
+                    let seq_length = output.dims()[1];
+                    let output_h = output.clone().narrow(1, seq_length - 1, 1).squeeze::<2>(1);
+                }
+            });
+            let body = quote! {
+                let output = #forward;
+                #output_h_stmt
+            };
+
+            (body, output_value)
+        }
+    };
+
+    let output_value = config.output_tokens(output_value);
+    let output_h_value = config.state_output_tokens(quote! { output_h });
+    bind_outputs(&[(output, output_value), (output_h, output_h_value)], body)
+}
+
+/// Node for the ONNX RNN operator, which applies a simple recurrent layer with a tanh activation
+/// to the input sequence.
+///
+/// Each direction is a [gate controller](burn::nn::GateController) computing the next hidden
+/// state from the current input and hidden state.
+#[derive(Debug, Clone)]
+pub struct RnnNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_h: Option<TensorType>,
+    pub output: Option<TensorType>,
+    pub output_h: Option<TensorType>,
+    /// The weights of the gate of each direction.
+    pub weights: Vec<GateWeights>,
+    pub config: RecurrentConfig,
+}
+
+impl RnnNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_h: Option<TensorType>,
+        output: Option<TensorType>,
+        output_h: Option<TensorType>,
+        weights: Vec<GateWeights>,
+        config: RecurrentConfig,
+    ) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { [GateController<B>; 2] },
+            _ => quote! { GateController<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_h,
+            output,
+            output_h,
+            weights,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for RnnNode {
+    fn input_types(&self) -> Vec<Type> {
+        [Some(&self.input), self.initial_h.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [self.output.as_ref(), self.output_h.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+
+        let gate = quote! {
+            GateController::new(
+                #d_input,
+                #d_hidden,
+                #bias,
+                Initializer::XavierNormal { gain: 1.0 },
+                device,
+            )
+        };
+
+        let tokens = match self.config.direction {
+            RnnDirection::Bidirectional => quote! {
+                let #name = [#gate, #gate];
+            },
+            _ => quote! {
+                let #name = #gate;
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let record: [GateControllerRecord<SerializationBackend>; 2] = [
+                    self.weights[0].to_record::<PS>(),
+                    self.weights[1].to_record::<PS>(),
+                ];
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+            _ => {
+                let record = self.weights[0].to_record::<PS>();
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let initial_h = self
+            .initial_h
+            .as_ref()
+            .map(|initial_h| scope.tensor_use_owned(initial_h, node_position));
+        let field = &self.field.name;
+        let d_hidden = self.config.d_hidden.to_tokens();
+
+        let forward = sequence_forward(
+            &self.config,
+            quote! { self.#field },
+            input,
+            initial_h,
+            &self.output,
+            &self.output_h,
+            |gate, input, state| quote! { rnn(&#gate, #input, #state) },
+        );
+
+        quote! {
+            let rnn = |gate: &GateController<B>, input: Tensor<B, 3>, state: Option<Tensor<B, 2>>| {
+                let [batch_size, _, _] = input.dims();
+                let mut hidden = state
+                    .unwrap_or_else(|| Tensor::zeros([batch_size, #d_hidden], &input.device()));
+                let outputs = input
+                    .iter_dim(1)
+                    .map(|input_t| {
+                        hidden = gate.gate_product(input_t.squeeze::<2>(1), hidden.clone()).tanh();
+                        hidden.clone().unsqueeze_dim::<3>(1)
+                    })
+                    .collect();
+
+                Tensor::cat(outputs, 1)
+            };
+            #forward
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::GateController");
+        imports.register("burn::nn::Initializer");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Rnn(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{rnn::RnnNode, test::assert_tokens},
+        TensorType,
+    };
+    use burn::record::FullPrecisionSettings;
+
+    fn gate_weights() -> GateWeights {
+        GateWeights::new(
+            TensorData::from([[1f32, 2f32]]),
+            None,
+            TensorData::from([[1f32, 2f32], [3f32, 4f32]]),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(RnnNode::new(
+            "rnn",
+            TensorType::new_float("input", 3),
+            None,
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("output_h", 3)),
+            vec![gate_weights()],
+            RecurrentConfig::new(1, 2, false, RnnDirection::Reverse, false),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "output_h".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::GateController;
+            use burn::nn::Initializer;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                rnn: GateController<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let rnn = GateController::new(
+                        1,
+                        2,
+                        false,
+                        Initializer::XavierNormal { gain: 1.0 },
+                        device,
+                    );
+
+                    Self {
+                        rnn,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let rnn = |gate: &GateController<B>, input: Tensor<B, 3>, state: Option<Tensor<B, 2>>| {
+                        let [batch_size, _, _] = input.dims();
+                        let mut hidden = state
+                            .unwrap_or_else(|| Tensor::zeros([batch_size, 2], &input.device()));
+                        let outputs = input
+                            .iter_dim(1)
+                            .map(|input_t| {
+                                hidden = gate.gate_product(input_t.squeeze::<2>(1), hidden.clone()).tanh();
+                                hidden.clone().unsqueeze_dim::<3>(1)
+                            })
+                            .collect();
+
+                        Tensor::cat(outputs, 1)
+                    };
+                    let (output, output_h) = {
+                        let output = rnn(&self.rnn, input.swap_dims(0, 1).flip([1]), None);
+                        let seq_length = output.dims()[1];
+                        let output_h = output.clone().narrow(1, seq_length - 1, 1).squeeze::<2>(1);
+                        (
+                            output.flip([1]).swap_dims(0, 1).unsqueeze_dim::<4>(1),
+                            output_h.unsqueeze_dim::<3>(0),
+                        )
+                    };
+
+                    (output, output_h)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod adapter;
+pub(crate) mod candle;
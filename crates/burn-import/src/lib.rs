@@ -10,7 +10,7 @@
 //! aligns the imported model with Burn's model and converts tensor data into a format compatible with
 //! Burn.
 
-#[cfg(any(feature = "pytorch", feature = "onnx"))]
+#[cfg(any(feature = "pytorch", feature = "safetensors", feature = "onnx"))]
 #[macro_use]
 extern crate derive_new;
 
@@ -18,6 +18,10 @@ extern crate derive_new;
 #[cfg(any(feature = "pytorch", feature = "onnx"))]
 mod logger;
 
+// Shared by the PyTorch and safetensors recorders.
+#[cfg(any(feature = "pytorch", feature = "safetensors"))]
+mod common;
+
 /// The onnx module.
 #[cfg(feature = "onnx")]
 pub mod onnx;
@@ -30,5 +34,9 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
 mod formatter;
 pub use formatter::*;
@@ -1,3 +1,5 @@
 mod op_configuration;
 mod to_burn;
 pub use to_burn::*;
+
+pub mod runtime;
@@ -3,15 +3,25 @@ use burn::nn::{
         Conv1dConfig, Conv2dConfig, Conv3dConfig, ConvTranspose1dConfig, ConvTranspose2dConfig,
         ConvTranspose3dConfig,
     },
+    interpolate::{GridSample2dConfig, InterpolateMode},
     pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig},
     BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
     PaddingConfig2d, PaddingConfig3d,
 };
 
 use crate::burn::node::{
-    expand::ExpandShape, pad::PadConfig, tile::TileConfig, top_k::TopKConfig, trilu::TriluConfig,
+    control_flow::ScanConfig,
+    expand::ExpandShape,
+    pad::PadConfig,
+    quantization::QuantizationConfig,
+    rnn::{RecurrentConfig, RnnDirection},
+    tile::TileConfig,
+    top_k::TopKConfig,
+    trilu::TriluConfig,
 };
-use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node};
+use burn::tensor::ops::GridSamplePaddingMode;
+use burn::tensor::quantization::{QuantizationScheme, QuantizationType};
+use onnx_ir::ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType};
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -817,6 +827,14 @@ pub fn concat_config(node: &Node) -> usize {
     axis as usize
 }
 
+/// Extract the equation of an einsum node
+pub fn einsum_config(node: &Node) -> String {
+    match node.attrs.get("equation") {
+        Some(equation) => equation.clone().into_string(),
+        None => panic!("Einsum: equation attribute not found"),
+    }
+}
+
 /// Create a BatchNormConfig from the attributes of the node
 pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
     // extract the shape of the weight tensor
@@ -1219,6 +1237,46 @@ pub fn leaky_relu_config(node: &Node) -> f64 {
     alpha
 }
 
+/// Create a GridSample2dConfig from the attributes of the node
+pub fn grid_sample_config(node: &Node) -> GridSample2dConfig {
+    let mut mode = InterpolateMode::Linear;
+    let mut padding_mode = GridSamplePaddingMode::Zeros;
+    let mut align_corners = false;
+
+    if let ArgType::Tensor(tensor) = &node.inputs[0].ty {
+        assert_eq!(tensor.dim, 4, "GridSample: only 2D inputs are supported");
+    }
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            // The modes were renamed from opset 20.
+            "mode" => {
+                mode = match value.clone().into_string().to_lowercase().as_str() {
+                    "nearest" => InterpolateMode::Nearest,
+                    "linear" | "bilinear" => InterpolateMode::Linear,
+                    "cubic" | "bicubic" => InterpolateMode::Cubic,
+                    mode => panic!("GridSample: unsupported mode {mode}"),
+                }
+            }
+            "padding_mode" => {
+                padding_mode = match value.clone().into_string().to_lowercase().as_str() {
+                    "zeros" => GridSamplePaddingMode::Zeros,
+                    "border" => GridSamplePaddingMode::Border,
+                    "reflection" => GridSamplePaddingMode::Reflection,
+                    padding_mode => panic!("GridSample: unsupported padding mode {padding_mode}"),
+                }
+            }
+            "align_corners" => align_corners = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    GridSample2dConfig::new()
+        .with_mode(mode)
+        .with_padding_mode(padding_mode)
+        .with_align_corners(align_corners)
+}
+
 // Create a HardSigmoidConfig from the alpha and beta attributes of the node
 pub fn hard_sigmoid_config(node: &Node) -> (f64, f64) {
     let mut alpha = 0.2;
@@ -1884,3 +1942,292 @@ pub fn one_hot_config(curr: &Node) -> (usize, [f32; 2], i64) {
         .unwrap_or(-1);
     (depth as usize, values.try_into().unwrap(), axis)
 }
+
+/// Create a RecurrentConfig from the attributes of an LSTM, GRU or RNN node
+///
+/// Only the default activation functions of the operator are supported.
+fn recurrent_config(node: &Node, default_activations: &[&str]) -> RecurrentConfig {
+    let name = format!("{:?}", node.node_type);
+
+    // the weight tensor's shape is [num_directions, num_gates * hidden_size, input_size]
+    let weight_shape = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.shape.clone(),
+        _ => None,
+    }
+    .unwrap_or_else(|| panic!("{name}: the weight tensor must be constant"));
+
+    let mut direction = RnnDirection::Forward;
+    let mut hidden_size = None;
+    let mut batch_first = false;
+    let mut activations = Vec::new();
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "direction" => {
+                direction = match value.clone().into_string().as_str() {
+                    "forward" => RnnDirection::Forward,
+                    "reverse" => RnnDirection::Reverse,
+                    "bidirectional" => RnnDirection::Bidirectional,
+                    direction => panic!("{name}: invalid direction {direction}"),
+                }
+            }
+            "hidden_size" => hidden_size = Some(value.clone().into_i64() as usize),
+            "layout" => batch_first = value.clone().into_i64() == 1,
+            "activations" => activations = value.clone().into_strings(),
+            "clip" => panic!("{name}: cell clipping is not supported"),
+            _ => {}
+        }
+    }
+
+    let is_default_activations = activations.chunks(default_activations.len()).all(|chunk| {
+        chunk
+            .iter()
+            .zip(default_activations)
+            .all(|(activation, default)| activation.eq_ignore_ascii_case(default))
+    });
+    if !is_default_activations {
+        panic!(
+            "{name}: only the default activations {default_activations:?} are supported, got \
+             {activations:?}"
+        );
+    }
+
+    // optional inputs can be skipped with an empty name
+    let has_input = |index: usize| {
+        node.inputs
+            .get(index)
+            .is_some_and(|input| !input.name.is_empty())
+    };
+    if has_input(4) {
+        panic!("{name}: sequence lengths are not supported");
+    }
+
+    // the hidden size can also be inferred from the recurrence weight tensor's shape
+    let hidden_size = hidden_size.unwrap_or_else(|| match &node.inputs[2].ty {
+        ArgType::Tensor(tensor) => tensor.shape.as_ref().unwrap()[2],
+        _ => panic!("{name}: the recurrence weight tensor must be constant"),
+    });
+
+    RecurrentConfig::new(
+        weight_shape[2],
+        hidden_size,
+        has_input(3),
+        direction,
+        batch_first,
+    )
+}
+
+/// Create a RecurrentConfig from the attributes of the LSTM node
+pub fn lstm_config(node: &Node) -> RecurrentConfig {
+    let input_forget = node
+        .attrs
+        .get("input_forget")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(0);
+    if input_forget != 0 {
+        panic!("LSTM: coupling the input and forget gates is not supported");
+    }
+
+    let has_peepholes = node
+        .inputs
+        .get(7)
+        .is_some_and(|input| !input.name.is_empty());
+    if has_peepholes {
+        panic!("LSTM: peephole connections are not supported");
+    }
+
+    recurrent_config(node, &["Sigmoid", "Tanh", "Tanh"])
+}
+
+/// Create a RecurrentConfig from the attributes of the GRU node, and whether the reset gate is
+/// applied after the linear transformation of the hidden state
+pub fn gru_config(node: &Node) -> (RecurrentConfig, bool) {
+    let linear_before_reset = node
+        .attrs
+        .get("linear_before_reset")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(0);
+
+    (
+        recurrent_config(node, &["Sigmoid", "Tanh"]),
+        linear_before_reset != 0,
+    )
+}
+
+/// Create a RecurrentConfig from the attributes of the RNN node
+pub fn rnn_config(node: &Node) -> RecurrentConfig {
+    recurrent_config(node, &["Tanh"])
+}
+
+/// Create a ScanConfig from the attributes of the node
+pub fn scan_config(node: &Node) -> ScanConfig {
+    let num_scan_inputs = node
+        .attrs
+        .get("num_scan_inputs")
+        .map(|value| value.clone().into_i64() as usize)
+        .expect("Scan: num_scan_inputs attribute is required");
+    let num_states = match node.attrs.get("body") {
+        Some(AttributeValue::Graph(body)) => body.inputs.len() - num_scan_inputs,
+        _ => panic!("Scan: body subgraph is required"),
+    };
+    let num_scan_outputs = node.outputs.len() - num_states;
+
+    let rank = |arg: &Argument| match &arg.ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => panic!("Scan: scan inputs and outputs must be tensors"),
+    };
+    let attr_or_zeros = |name: &str, len: usize| {
+        node.attrs
+            .get(name)
+            .map(|value| value.clone().into_i64s())
+            .unwrap_or_else(|| vec![0; len])
+    };
+
+    // Negative axes count from the last dimension
+    let scan_inputs = &node.inputs[num_states..num_states + num_scan_inputs];
+    let input_axes = attr_or_zeros("scan_input_axes", num_scan_inputs)
+        .into_iter()
+        .zip(scan_inputs)
+        .map(|(axis, input)| axis.rem_euclid(rank(input)) as usize)
+        .collect();
+    let scan_outputs = &node.outputs[num_states..];
+    let output_axes = attr_or_zeros("scan_output_axes", num_scan_outputs)
+        .into_iter()
+        .zip(scan_outputs)
+        .map(|(axis, output)| axis.rem_euclid(rank(output)) as usize)
+        .collect();
+
+    let input_reversed = attr_or_zeros("scan_input_directions", num_scan_inputs)
+        .into_iter()
+        .map(|direction| direction == 1)
+        .collect();
+    let output_reversed = attr_or_zeros("scan_output_directions", num_scan_outputs)
+        .into_iter()
+        .map(|direction| direction == 1)
+        .collect();
+
+    ScanConfig::new(input_axes, input_reversed, output_axes, output_reversed)
+}
+
+/// Returns the element type of the quantized values of a node, which is the type of its zero point
+/// input when present.
+pub fn quantized_elem_type(node: &Node, zero_point: usize, default: ElementType) -> ElementType {
+    match node
+        .inputs
+        .get(zero_point)
+        .filter(|input| !input.name.is_empty())
+        .map(|input| &input.ty)
+    {
+        Some(ArgType::Tensor(tensor)) => tensor.elem_type.clone(),
+        Some(ArgType::Scalar(elem_type)) => elem_type.clone(),
+        _ => default,
+    }
+}
+
+/// Create a QuantizationConfig from the scale and zero point inputs of a quantized node
+///
+/// # Arguments
+///
+/// * `node` - The quantized node.
+/// * `scale` - The index of the scale input, or `None` for a unit scale.
+/// * `zero_point` - The index of the optional zero point input.
+/// * `axis` - The axis of the per-axis quantization, used when there is more than one scale.
+/// * `elem_type` - The element type of the quantized values.
+pub fn quantization_config(
+    node: &Node,
+    scale: Option<usize>,
+    zero_point: usize,
+    axis: usize,
+    elem_type: &ElementType,
+) -> QuantizationConfig {
+    let name = &node.name;
+    let scale = scale.map(|index| match node.inputs[index].value.clone() {
+        Some(Data::Float32(scale)) => vec![scale],
+        Some(data) => data.into_f32s(),
+        None => panic!("{name}: the quantization scale must be constant"),
+    });
+    let offset = node
+        .inputs
+        .get(zero_point)
+        .filter(|input| !input.name.is_empty())
+        .map(|input| match input.value.clone() {
+            Some(data) => integer_values(data),
+            None => panic!("{name}: the quantization zero point must be constant"),
+        });
+
+    let (scale, offset) = match (scale, offset) {
+        (Some(scale), Some(offset)) => (scale, offset),
+        (Some(scale), None) => {
+            let offset = vec![0; scale.len()];
+            (scale, offset)
+        }
+        (None, Some(offset)) => (vec![1.0; offset.len()], offset),
+        (None, None) => (vec![1.0], vec![0]),
+    };
+
+    // Unsigned values are shifted to the signed range of QInt8
+    let shift = match elem_type {
+        ElementType::Int8 => 0,
+        ElementType::Uint8 => 128,
+        _ => panic!("{name}: only 8-bit integer quantization is supported, got {elem_type:?}"),
+    };
+    let offset = offset
+        .into_iter()
+        .map(|offset| (offset - shift) as i8)
+        .collect();
+
+    let scheme = match scale.len() {
+        1 => QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        _ => QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, axis),
+    };
+
+    QuantizationConfig::new(scheme, scale, offset)
+}
+
+/// Create a QuantizationConfig from the attributes and inputs of a QuantizeLinear or
+/// DequantizeLinear node
+pub fn quantize_linear_config(node: &Node) -> QuantizationConfig {
+    let (rank, input_type) = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => (tensor.dim as i64, tensor.elem_type.clone()),
+        ArgType::Scalar(elem_type) => (0, elem_type.clone()),
+        _ => panic!("{}: the input must be a tensor", node.name),
+    };
+
+    let mut axis = 1;
+    let mut output_dtype = 0;
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "output_dtype" => output_dtype = value.clone().into_i64(),
+            "block_size" if value.clone().into_i64() != 0 => {
+                panic!("{}: blocked quantization is not supported", node.name)
+            }
+            _ => {}
+        }
+    }
+
+    // The quantized values are the output of QuantizeLinear, which defaults to uint8 (i.e.,
+    // `output_dtype` 2 while int8 is 3), and the input of DequantizeLinear
+    let default_type = match node.node_type {
+        NodeType::QuantizeLinear if output_dtype == 3 => ElementType::Int8,
+        NodeType::QuantizeLinear => ElementType::Uint8,
+        _ => input_type,
+    };
+    let elem_type = quantized_elem_type(node, 2, default_type);
+
+    // Negative axes count from the last dimension
+    let axis = match rank {
+        0 => 0,
+        rank => axis.rem_euclid(rank) as usize,
+    };
+
+    quantization_config(node, Some(1), 2, axis, &elem_type)
+}
+
+fn integer_values(data: Data) -> Vec<i64> {
+    match data {
+        Data::Int32(value) => vec![value as i64],
+        Data::Int64(value) => vec![value],
+        data => data.into_i64s(),
+    }
+}
@@ -0,0 +1,39 @@
+use onnx_ir::{ir::NodeType, ParseError};
+
+/// Error of the ONNX interpreter.
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// The file can't be parsed into an ONNX graph.
+    #[error("Unable to parse the ONNX file: {0}")]
+    Parse(#[from] ParseError),
+
+    /// The graph contains operators that can't be interpreted.
+    #[error("Unsupported ops: {0:?}")]
+    UnsupportedOps(Vec<NodeType>),
+
+    /// An argument has neither been computed nor provided.
+    #[error("Missing value for argument: {0}")]
+    MissingValue(String),
+
+    /// The inputs of the model are invalid.
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    /// A node can't be executed with its inputs or attributes.
+    #[error("{node}: {message}")]
+    InvalidNode {
+        /// The name of the node.
+        node: String,
+        /// The reason of the failure.
+        message: String,
+    },
+}
+
+impl Error {
+    pub(crate) fn invalid_node(node: &str, message: impl Into<String>) -> Self {
+        Self::InvalidNode {
+            node: node.to_string(),
+            message: message.into(),
+        }
+    }
+}
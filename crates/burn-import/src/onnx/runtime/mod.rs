@@ -0,0 +1,15 @@
+//! Runtime interpreter of ONNX graphs.
+//!
+//! Unlike [ModelGen](crate::onnx::ModelGen), which generates the source code of a model at build
+//! time, the interpreter loads an `.onnx` file at runtime and executes its graph directly on any
+//! backend.
+
+mod error;
+mod model;
+mod module;
+mod ops;
+mod value;
+
+pub use error::Error;
+pub use model::OnnxModel;
+pub use value::{Value, ValueKind};
@@ -0,0 +1,461 @@
+use std::{collections::HashMap, path::Path};
+
+use burn::tensor::backend::Backend;
+use onnx_ir::{
+    convert_constant_value,
+    ir::{Argument, Node, NodeType, OnnxGraph},
+    try_parse_onnx,
+};
+
+use super::{
+    module::NodeModule,
+    ops::{is_supported, run, NodeInputs},
+    value::argument_data,
+    Error, Value,
+};
+
+/// An ONNX model executed at runtime on any backend, without code generation.
+///
+/// The weights of the graph are loaded as tensors once, when the model is created, and each node is
+/// dispatched to the tensor operations of the backend during the forward pass.
+///
+/// # Example
+///
+/// ```ignore
+/// let model = OnnxModel::<NdArray>::from_file("model.onnx", &device)?;
+/// let outputs = model.forward(vec![Value::from(input)])?;
+/// let output: Tensor<NdArray, 2> = outputs[0].clone().into_float();
+/// ```
+#[derive(Debug)]
+pub struct OnnxModel<B: Backend> {
+    nodes: Vec<Node>,
+    inputs: Vec<Argument>,
+    outputs: Vec<Argument>,
+    /// The initializers and constants of the graph.
+    constants: HashMap<String, Value<B>>,
+    /// The modules of the nodes, by node index.
+    modules: HashMap<usize, NodeModule<B>>,
+    /// The index of the last node using each computed value, after which it is dropped.
+    last_uses: HashMap<String, usize>,
+    device: B::Device,
+}
+
+impl<B: Backend> OnnxModel<B> {
+    /// Load the model from an `.onnx` file.
+    pub fn from_file<P: AsRef<Path>>(path: P, device: &B::Device) -> Result<Self, Error> {
+        Self::from_graph(try_parse_onnx(path.as_ref())?, device)
+    }
+
+    /// Load the model from a parsed ONNX graph.
+    pub fn from_graph(graph: OnnxGraph, device: &B::Device) -> Result<Self, Error> {
+        let mut unsupported_ops = vec![];
+        for node in graph.nodes.iter() {
+            if !is_supported(&node.node_type) && !unsupported_ops.contains(&node.node_type) {
+                unsupported_ops.push(node.node_type.clone());
+            }
+        }
+
+        if !unsupported_ops.is_empty() {
+            return Err(Error::UnsupportedOps(unsupported_ops));
+        }
+
+        let mut constants = HashMap::new();
+        for node in graph.nodes.iter() {
+            if node.node_type == NodeType::Constant {
+                let data = argument_data(&convert_constant_value(node))?;
+                constants.insert(node.outputs[0].name.clone(), Value::from_data(data, device));
+            }
+
+            for input in node.inputs.iter().filter(|input| input.value.is_some()) {
+                if !constants.contains_key(&input.name) {
+                    let data = argument_data(input)?;
+                    constants.insert(input.name.clone(), Value::from_data(data, device));
+                }
+            }
+        }
+
+        let mut modules = HashMap::new();
+        for (index, node) in graph.nodes.iter().enumerate() {
+            if let Some(module) = NodeModule::init(node, &constants, device)? {
+                modules.insert(index, module);
+            }
+        }
+
+        let mut last_uses = HashMap::new();
+        for (index, node) in graph.nodes.iter().enumerate() {
+            for input in node.inputs.iter() {
+                last_uses.insert(input.name.clone(), index);
+            }
+        }
+        for output in graph.outputs.iter() {
+            last_uses.remove(&output.name);
+        }
+
+        Ok(Self {
+            nodes: graph.nodes,
+            inputs: graph.inputs,
+            outputs: graph.outputs,
+            constants,
+            modules,
+            last_uses,
+            device: device.clone(),
+        })
+    }
+
+    /// The names of the inputs of the model.
+    pub fn input_names(&self) -> Vec<&str> {
+        self.inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect()
+    }
+
+    /// The names of the outputs of the model.
+    pub fn output_names(&self) -> Vec<&str> {
+        self.outputs
+            .iter()
+            .map(|output| output.name.as_str())
+            .collect()
+    }
+
+    /// Execute the model with the given inputs, in the order of the graph inputs.
+    pub fn forward(&self, inputs: Vec<Value<B>>) -> Result<Vec<Value<B>>, Error> {
+        if inputs.len() != self.inputs.len() {
+            return Err(Error::InvalidInput(format!(
+                "expected {} inputs, got {}",
+                self.inputs.len(),
+                inputs.len()
+            )));
+        }
+
+        let mut values = self
+            .inputs
+            .iter()
+            .map(|input| input.name.clone())
+            .zip(inputs)
+            .collect::<HashMap<_, _>>();
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            if node.node_type == NodeType::Constant {
+                continue;
+            }
+
+            let inputs = node
+                .inputs
+                .iter()
+                .map(|input| match input.name.is_empty() {
+                    // Skipped optional input
+                    true => Ok(None),
+                    false => self.value(&values, &input.name).map(Some),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let outputs = run(
+                NodeInputs::new(node, inputs, &self.device),
+                self.modules.get(&index),
+            )?;
+
+            // Drop the values that are no longer used
+            for input in node.inputs.iter() {
+                if self.last_uses.get(&input.name) == Some(&index) {
+                    values.remove(&input.name);
+                }
+            }
+
+            for (output, value) in node.outputs.iter().zip(outputs) {
+                values.insert(output.name.clone(), value);
+            }
+        }
+
+        self.outputs
+            .iter()
+            .map(|output| self.value(&values, &output.name))
+            .collect()
+    }
+
+    fn value(&self, values: &HashMap<String, Value<B>>, name: &str) -> Result<Value<B>, Error> {
+        values
+            .get(name)
+            .or_else(|| self.constants.get(name))
+            .cloned()
+            .ok_or_else(|| Error::MissingValue(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+    use onnx_ir::ir::{ArgType, AttributeValue, Attributes, Data, ElementType, TensorType};
+    use onnx_ir::ParseError;
+
+    type TestBackend = burn_ndarray::NdArray<f32>;
+
+    fn tensor_arg(name: &str, dim: usize) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorType {
+                elem_type: ElementType::Float32,
+                dim,
+                shape: None,
+            }),
+            value: None,
+            passed: true,
+        }
+    }
+
+    fn constant_arg(name: &str, elem_type: ElementType, data: Data, shape: Vec<usize>) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorType {
+                elem_type,
+                dim: shape.len(),
+                shape: Some(shape),
+            }),
+            value: Some(data),
+            passed: false,
+        }
+    }
+
+    fn node(
+        node_type: NodeType,
+        inputs: Vec<Argument>,
+        outputs: Vec<Argument>,
+        attrs: Attributes,
+    ) -> Node {
+        Node {
+            name: node_type.to_string().to_lowercase(),
+            node_type,
+            inputs,
+            outputs,
+            attrs,
+        }
+    }
+
+    fn model(
+        nodes: Vec<Node>,
+        inputs: Vec<Argument>,
+        outputs: Vec<Argument>,
+    ) -> OnnxModel<TestBackend> {
+        let graph = OnnxGraph {
+            nodes,
+            inputs,
+            outputs,
+        };
+
+        OnnxModel::from_graph(graph, &Default::default()).unwrap()
+    }
+
+    #[test]
+    fn test_forward_binary_and_unary() {
+        let model = model(
+            vec![
+                node(
+                    NodeType::Add,
+                    vec![tensor_arg("input1", 2), tensor_arg("input2", 1)],
+                    vec![tensor_arg("add1_out1", 2)],
+                    Attributes::new(),
+                ),
+                node(
+                    NodeType::Relu,
+                    vec![tensor_arg("add1_out1", 2)],
+                    vec![tensor_arg("relu1_out1", 2)],
+                    Attributes::new(),
+                ),
+            ],
+            vec![tensor_arg("input1", 2), tensor_arg("input2", 1)],
+            vec![tensor_arg("relu1_out1", 2)],
+        );
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0], [-3.0, 4.0]], &device);
+        let rhs = Tensor::<TestBackend, 1>::from_floats([1.0, 1.0], &device);
+
+        let outputs = model.forward(vec![lhs.into(), rhs.into()]).unwrap();
+
+        assert_eq!(model.output_names(), vec!["relu1_out1"]);
+        outputs[0]
+            .clone()
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0f32, 0.0], [0.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn test_forward_constants() {
+        let weight = constant_arg(
+            "weight",
+            ElementType::Float32,
+            Data::Float32s(vec![1.0, 2.0, 3.0, 4.0]),
+            vec![2, 2],
+        );
+        let shape = constant_arg("shape", ElementType::Int64, Data::Int64s(vec![-1]), vec![1]);
+        let model = model(
+            vec![
+                node(
+                    NodeType::Linear,
+                    vec![tensor_arg("input1", 2), weight],
+                    vec![tensor_arg("linear1_out1", 2)],
+                    Attributes::new(),
+                ),
+                node(
+                    NodeType::Reshape,
+                    vec![tensor_arg("linear1_out1", 2), shape],
+                    vec![tensor_arg("reshape1_out1", 1)],
+                    Attributes::new(),
+                ),
+            ],
+            vec![tensor_arg("input1", 2)],
+            vec![tensor_arg("reshape1_out1", 1)],
+        );
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0]], &Default::default());
+
+        let outputs = model.forward(vec![input.into()]).unwrap();
+
+        outputs[0]
+            .clone()
+            .into_data()
+            .assert_eq(&TensorData::from([4.0f32, 6.0]), false);
+    }
+
+    #[test]
+    fn test_forward_softmax_and_argmax() {
+        let mut attrs = Attributes::new();
+        attrs.insert("axis".to_string(), AttributeValue::Int64(1));
+        attrs.insert("keepdims".to_string(), AttributeValue::Int64(0));
+        let model = model(
+            vec![
+                node(
+                    NodeType::Softmax,
+                    vec![tensor_arg("input1", 2)],
+                    vec![tensor_arg("softmax1_out1", 2)],
+                    Attributes::new(),
+                ),
+                node(
+                    NodeType::ArgMax,
+                    vec![tensor_arg("softmax1_out1", 2)],
+                    vec![tensor_arg("argmax1_out1", 1)],
+                    attrs,
+                ),
+            ],
+            vec![tensor_arg("input1", 2)],
+            vec![
+                tensor_arg("softmax1_out1", 2),
+                tensor_arg("argmax1_out1", 1),
+            ],
+        );
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[0.0, 0.0], [2.0_f32.ln(), 0.0]],
+            &Default::default(),
+        );
+
+        let outputs = model.forward(vec![input.into()]).unwrap();
+
+        outputs[0].clone().into_data().assert_approx_eq(
+            &TensorData::from([[0.5f32, 0.5], [2.0 / 3.0, 1.0 / 3.0]]),
+            3,
+        );
+        outputs[1]
+            .clone()
+            .into_data()
+            .assert_eq(&TensorData::from([0i64, 0]), false);
+    }
+
+    #[test]
+    fn test_forward_conv2d() {
+        let weight = constant_arg(
+            "weight",
+            ElementType::Float32,
+            Data::Float32s(vec![1.0; 4]),
+            vec![1, 1, 2, 2],
+        );
+        let bias = constant_arg(
+            "bias",
+            ElementType::Float32,
+            Data::Float32s(vec![1.0]),
+            vec![1],
+        );
+        let mut attrs = Attributes::new();
+        attrs.insert(
+            "kernel_shape".to_string(),
+            AttributeValue::Int64s(vec![2, 2]),
+        );
+        let model = model(
+            vec![node(
+                NodeType::Conv2d,
+                vec![tensor_arg("input1", 4), weight, bias],
+                vec![tensor_arg("conv2d1_out1", 4)],
+                attrs,
+            )],
+            vec![tensor_arg("input1", 4)],
+            vec![tensor_arg("conv2d1_out1", 4)],
+        );
+        let input = Tensor::<TestBackend, 4>::from_floats(
+            [[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]],
+            &Default::default(),
+        );
+
+        let output: Tensor<TestBackend, 4> = model.forward(vec![input.into()]).unwrap()[0]
+            .clone()
+            .into_float();
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[[13.0f32, 17.0], [25.0, 29.0]]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_unsupported_ops() {
+        let graph = OnnxGraph {
+            nodes: vec![node(
+                NodeType::NonZero,
+                vec![tensor_arg("input1", 1)],
+                vec![tensor_arg("nonzero1_out1", 2)],
+                Attributes::new(),
+            )],
+            inputs: vec![tensor_arg("input1", 1)],
+            outputs: vec![tensor_arg("nonzero1_out1", 2)],
+        };
+
+        let result = OnnxModel::<TestBackend>::from_graph(graph, &Default::default());
+
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedOps(ops)) if ops == vec![NodeType::NonZero]
+        ));
+    }
+
+    #[test]
+    fn test_invalid_input_count() {
+        let model = model(
+            vec![node(
+                NodeType::Relu,
+                vec![tensor_arg("input1", 1)],
+                vec![tensor_arg("relu1_out1", 1)],
+                Attributes::new(),
+            )],
+            vec![tensor_arg("input1", 1)],
+            vec![tensor_arg("relu1_out1", 1)],
+        );
+
+        assert!(matches!(model.forward(vec![]), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let path = std::env::temp_dir().join("burn_import_runtime_invalid.onnx");
+        std::fs::write(&path, b"not an onnx model").unwrap();
+
+        let result = OnnxModel::<TestBackend>::from_file(&path, &Default::default());
+
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let path = std::env::temp_dir().join("burn_import_runtime_missing.onnx");
+
+        let result = OnnxModel::<TestBackend>::from_file(&path, &Default::default());
+
+        assert!(matches!(result, Err(Error::Parse(ParseError::Io(_)))));
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use burn::{
+    module::Param,
+    nn::{
+        conv::{Conv1d, Conv2d, Conv3d, ConvTranspose1d, ConvTranspose2d, ConvTranspose3d},
+        pool::{AvgPool1d, AvgPool2d, MaxPool1d, MaxPool2d},
+    },
+    tensor::{backend::Backend, ops::FloatTensor, Tensor, TensorPrimitive},
+};
+use onnx_ir::ir::{Node, NodeType};
+
+use super::{Error, Value};
+use crate::onnx::op_configuration::{
+    avg_pool1d_config, avg_pool2d_config, conv1d_config, conv2d_config, conv3d_config,
+    conv_transpose1d_config, conv_transpose2d_config, conv_transpose3d_config, max_pool1d_config,
+    max_pool2d_config,
+};
+
+/// A Burn module executing a node of the graph, initialized once with the constant weights of the
+/// node when the model is loaded.
+#[derive(Debug)]
+pub(crate) enum NodeModule<B: Backend> {
+    Conv1d(Conv1d<B>),
+    Conv2d(Conv2d<B>),
+    Conv3d(Conv3d<B>),
+    ConvTranspose1d(ConvTranspose1d<B>),
+    ConvTranspose2d(ConvTranspose2d<B>),
+    ConvTranspose3d(ConvTranspose3d<B>),
+    MaxPool1d(MaxPool1d),
+    MaxPool2d(MaxPool2d),
+    AvgPool1d(AvgPool1d),
+    AvgPool2d(AvgPool2d),
+}
+
+impl<B: Backend> NodeModule<B> {
+    /// Initialize the module of the node, if it maps to one.
+    ///
+    /// The weights of the module are taken from the constant values of the graph.
+    pub(crate) fn init(
+        node: &Node,
+        constants: &HashMap<String, Value<B>>,
+        device: &B::Device,
+    ) -> Result<Option<Self>, Error> {
+        let module = match node.node_type {
+            NodeType::Conv1d => {
+                let (weight, bias) = weights::<B, 3>(node, constants)?;
+                let conv = conv1d_config(node).init(device);
+                Self::Conv1d(Conv1d {
+                    weight,
+                    bias,
+                    ..conv
+                })
+            }
+            NodeType::Conv2d => {
+                let (weight, bias) = weights::<B, 4>(node, constants)?;
+                let conv = conv2d_config(node).init(device);
+                Self::Conv2d(Conv2d {
+                    weight,
+                    bias,
+                    ..conv
+                })
+            }
+            NodeType::Conv3d => {
+                let (weight, bias) = weights::<B, 5>(node, constants)?;
+                let conv = conv3d_config(node).init(device);
+                Self::Conv3d(Conv3d {
+                    weight,
+                    bias,
+                    ..conv
+                })
+            }
+            NodeType::ConvTranspose1d => {
+                let (weight, bias) = weights::<B, 3>(node, constants)?;
+                let conv = conv_transpose1d_config(node).init(device);
+                Self::ConvTranspose1d(ConvTranspose1d {
+                    weight,
+                    bias,
+                    ..conv
+                })
+            }
+            NodeType::ConvTranspose2d => {
+                let (weight, bias) = weights::<B, 4>(node, constants)?;
+                let conv = conv_transpose2d_config(node).init(device);
+                Self::ConvTranspose2d(ConvTranspose2d {
+                    weight,
+                    bias,
+                    ..conv
+                })
+            }
+            NodeType::ConvTranspose3d => {
+                let (weight, bias) = weights::<B, 5>(node, constants)?;
+                let conv = conv_transpose3d_config(node).init(device);
+                Self::ConvTranspose3d(ConvTranspose3d {
+                    weight,
+                    bias,
+                    ..conv
+                })
+            }
+            NodeType::MaxPool1d => Self::MaxPool1d(max_pool1d_config(node).init()),
+            NodeType::MaxPool2d => Self::MaxPool2d(max_pool2d_config(node).init()),
+            NodeType::AveragePool1d => Self::AvgPool1d(avg_pool1d_config(node).init()),
+            NodeType::AveragePool2d => Self::AvgPool2d(avg_pool2d_config(node).init()),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(module))
+    }
+
+    pub(crate) fn forward(&self, input: FloatTensor<B>) -> FloatTensor<B> {
+        match self {
+            Self::Conv1d(conv) => primitive(conv.forward(tensor(input))),
+            Self::Conv2d(conv) => primitive(conv.forward(tensor(input))),
+            Self::Conv3d(conv) => primitive(conv.forward(tensor(input))),
+            Self::ConvTranspose1d(conv) => primitive(conv.forward(tensor(input))),
+            Self::ConvTranspose2d(conv) => primitive(conv.forward(tensor(input))),
+            Self::ConvTranspose3d(conv) => primitive(conv.forward(tensor(input))),
+            Self::MaxPool1d(pool) => primitive(pool.forward::<B>(tensor(input))),
+            Self::MaxPool2d(pool) => primitive(pool.forward::<B>(tensor(input))),
+            Self::AvgPool1d(pool) => primitive(pool.forward::<B>(tensor(input))),
+            Self::AvgPool2d(pool) => primitive(pool.forward::<B>(tensor(input))),
+        }
+    }
+}
+
+type Weights<B, const D: usize> = (Param<Tensor<B, D>>, Option<Param<Tensor<B, 1>>>);
+
+/// The constant weight and optional bias of a convolution.
+fn weights<B: Backend, const D: usize>(
+    node: &Node,
+    constants: &HashMap<String, Value<B>>,
+) -> Result<Weights<B, D>, Error> {
+    let constant = |index: usize| {
+        let input = &node.inputs[index];
+        constants.get(&input.name).cloned().ok_or_else(|| {
+            Error::invalid_node(&node.name, format!("{} must be constant", input.name))
+        })
+    };
+
+    let weight = Param::from_tensor(constant(1)?.into_float());
+    let bias = match node.inputs.get(2) {
+        Some(input) if !input.name.is_empty() => {
+            Some(Param::from_tensor(constant(2)?.into_float()))
+        }
+        _ => None,
+    };
+
+    Ok((weight, bias))
+}
+
+fn tensor<B: Backend, const D: usize>(tensor: FloatTensor<B>) -> Tensor<B, D> {
+    Tensor::from_primitive(TensorPrimitive::Float(tensor))
+}
+
+fn primitive<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> FloatTensor<B> {
+    tensor.into_primitive().tensor()
+}
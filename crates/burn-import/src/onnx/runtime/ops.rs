@@ -0,0 +1,922 @@
+use burn::tensor::{
+    backend::Backend,
+    einsum_primitive,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+    ElementConversion, Shape, TensorData, TensorMetadata,
+};
+use onnx_ir::ir::{AttributeValue, Data, Node, NodeType};
+
+use super::{
+    module::NodeModule,
+    value::{broadcast_shape, cat, ValueKind},
+    Error, Value,
+};
+use crate::onnx::op_configuration::{
+    einsum_config, grid_sample_config, hard_sigmoid_config, leaky_relu_config,
+};
+
+/// Returns true if the interpreter can execute the nodes of the given type.
+pub(crate) fn is_supported(node_type: &NodeType) -> bool {
+    matches!(
+        node_type,
+        NodeType::Abs
+            | NodeType::Add
+            | NodeType::And
+            | NodeType::ArgMax
+            | NodeType::ArgMin
+            | NodeType::AveragePool1d
+            | NodeType::AveragePool2d
+            | NodeType::BatchNormalization
+            | NodeType::Cast
+            | NodeType::Ceil
+            | NodeType::Clip
+            | NodeType::Concat
+            | NodeType::Constant
+            | NodeType::ConstantOfShape
+            | NodeType::Conv1d
+            | NodeType::Conv2d
+            | NodeType::Conv3d
+            | NodeType::ConvTranspose1d
+            | NodeType::ConvTranspose2d
+            | NodeType::ConvTranspose3d
+            | NodeType::Cos
+            | NodeType::CumSum
+            | NodeType::Div
+            | NodeType::Dropout
+            | NodeType::Einsum
+            | NodeType::Equal
+            | NodeType::Erf
+            | NodeType::Exp
+            | NodeType::Expand
+            | NodeType::Flatten
+            | NodeType::Floor
+            | NodeType::Gather
+            | NodeType::GatherElements
+            | NodeType::Gelu
+            | NodeType::GlobalAveragePool
+            | NodeType::Greater
+            | NodeType::GreaterOrEqual
+            | NodeType::GridSample
+            | NodeType::HardSigmoid
+            | NodeType::Identity
+            | NodeType::LayerNormalization
+            | NodeType::LeakyRelu
+            | NodeType::Less
+            | NodeType::LessOrEqual
+            | NodeType::Linear
+            | NodeType::Log
+            | NodeType::LogSoftmax
+            | NodeType::MatMul
+            | NodeType::Max
+            | NodeType::MaxPool1d
+            | NodeType::MaxPool2d
+            | NodeType::Mean
+            | NodeType::Min
+            | NodeType::Mul
+            | NodeType::Neg
+            | NodeType::Not
+            | NodeType::Or
+            | NodeType::Pow
+            | NodeType::PRelu
+            | NodeType::Range
+            | NodeType::Reciprocal
+            | NodeType::ReduceMax
+            | NodeType::ReduceMean
+            | NodeType::ReduceMin
+            | NodeType::ReduceProd
+            | NodeType::ReduceSum
+            | NodeType::Relu
+            | NodeType::Reshape
+            | NodeType::Round
+            | NodeType::Shape
+            | NodeType::Sigmoid
+            | NodeType::Sign
+            | NodeType::Sin
+            | NodeType::Slice
+            | NodeType::Softmax
+            | NodeType::Sqrt
+            | NodeType::Squeeze
+            | NodeType::Sub
+            | NodeType::Sum
+            | NodeType::Tanh
+            | NodeType::Tile
+            | NodeType::Transpose
+            | NodeType::Unsqueeze
+            | NodeType::Where
+            | NodeType::Xor
+    )
+}
+
+/// The inputs of a node being executed.
+pub(crate) struct NodeInputs<'a, B: Backend> {
+    node: &'a Node,
+    values: Vec<Option<Value<B>>>,
+    device: &'a B::Device,
+}
+
+impl<'a, B: Backend> NodeInputs<'a, B> {
+    /// Create the inputs of the node, where skipped optional inputs are `None`.
+    pub(crate) fn new(
+        node: &'a Node,
+        values: Vec<Option<Value<B>>>,
+        device: &'a B::Device,
+    ) -> Self {
+        Self {
+            node,
+            values,
+            device,
+        }
+    }
+
+    fn input(&mut self, index: usize) -> Result<Value<B>, Error> {
+        self.optional(index)
+            .ok_or_else(|| self.error(format!("missing input {index}")))
+    }
+
+    fn optional(&mut self, index: usize) -> Option<Value<B>> {
+        self.values.get_mut(index).and_then(Option::take)
+    }
+
+    /// All the remaining inputs, for variadic operators.
+    fn all(&mut self) -> Vec<Value<B>> {
+        self.values.drain(..).flatten().collect()
+    }
+
+    fn attr(&self, name: &str) -> Option<AttributeValue> {
+        self.node.attrs.get(name).cloned()
+    }
+
+    fn attr_i64(&self, name: &str, default: i64) -> i64 {
+        self.attr(name).map_or(default, AttributeValue::into_i64)
+    }
+
+    fn attr_f32(&self, name: &str, default: f32) -> f32 {
+        self.attr(name).map_or(default, AttributeValue::into_f32)
+    }
+
+    /// The integers of the attribute, or of the input at the given index when they are provided
+    /// as an input by newer opsets.
+    fn ints(&mut self, name: &str, index: usize) -> Option<Vec<i64>> {
+        match self.attr(name) {
+            Some(value) => Some(value.into_i64s()),
+            None => self.optional(index).map(Value::into_i64s),
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> Error {
+        Error::invalid_node(&self.node.name, message)
+    }
+}
+
+/// Execute the node with its inputs, returning its outputs.
+pub(crate) fn run<B: Backend>(
+    mut inputs: NodeInputs<B>,
+    module: Option<&NodeModule<B>>,
+) -> Result<Vec<Value<B>>, Error> {
+    let node = inputs.node;
+
+    let output = match node.node_type {
+        NodeType::Add => arithmetic(inputs.input(0)?, inputs.input(1)?, B::float_add, B::int_add),
+        NodeType::Sub => arithmetic(inputs.input(0)?, inputs.input(1)?, B::float_sub, B::int_sub),
+        NodeType::Mul => arithmetic(inputs.input(0)?, inputs.input(1)?, B::float_mul, B::int_mul),
+        NodeType::Div => arithmetic(inputs.input(0)?, inputs.input(1)?, B::float_div, B::int_div),
+        NodeType::Pow => arithmetic(
+            inputs.input(0)?,
+            inputs.input(1)?,
+            B::float_powf,
+            B::int_powi,
+        ),
+        NodeType::Sum => inputs
+            .all()
+            .into_iter()
+            .reduce(|lhs, rhs| arithmetic(lhs, rhs, B::float_add, B::int_add))
+            .ok_or_else(|| inputs.error("missing inputs"))?,
+        NodeType::Mean => {
+            let values = inputs.all();
+            let count = values.len();
+            let sum = values
+                .into_iter()
+                .reduce(|lhs, rhs| arithmetic(lhs, rhs, B::float_add, B::int_add))
+                .ok_or_else(|| inputs.error("missing inputs"))?;
+            Value::Float(B::float_div_scalar(sum.float(), (count as f64).elem()))
+        }
+        NodeType::Max => extremum(inputs.all(), B::float_lower, B::int_lower)?,
+        NodeType::Min => extremum(inputs.all(), B::float_greater, B::int_greater)?,
+        NodeType::Equal => comparison(
+            inputs.input(0)?,
+            inputs.input(1)?,
+            B::float_equal,
+            B::int_equal,
+        ),
+        NodeType::Greater => comparison(
+            inputs.input(0)?,
+            inputs.input(1)?,
+            B::float_greater,
+            B::int_greater,
+        ),
+        NodeType::GreaterOrEqual => comparison(
+            inputs.input(0)?,
+            inputs.input(1)?,
+            B::float_greater_equal,
+            B::int_greater_equal,
+        ),
+        NodeType::Less => comparison(
+            inputs.input(0)?,
+            inputs.input(1)?,
+            B::float_lower,
+            B::int_lower,
+        ),
+        NodeType::LessOrEqual => comparison(
+            inputs.input(0)?,
+            inputs.input(1)?,
+            B::float_lower_equal,
+            B::int_lower_equal,
+        ),
+        NodeType::And => logical(inputs.input(0)?, inputs.input(1)?, B::bool_and),
+        NodeType::Or => logical(inputs.input(0)?, inputs.input(1)?, B::bool_or),
+        NodeType::Xor => logical(inputs.input(0)?, inputs.input(1)?, B::bool_not_equal),
+        NodeType::Not => Value::Bool(B::bool_not(inputs.input(0)?.bool())),
+        NodeType::Abs => inputs
+            .input(0)?
+            .map(B::float_abs, B::int_abs, |tensor| tensor),
+        NodeType::Neg => inputs.input(0)?.map(B::float_neg, B::int_neg, B::bool_not),
+        NodeType::Sign => inputs
+            .input(0)?
+            .map(B::float_sign, B::int_sign, |tensor| tensor),
+        NodeType::Ceil => unary(inputs.input(0)?, B::float_ceil),
+        NodeType::Cos => unary(inputs.input(0)?, B::float_cos),
+        NodeType::Erf => unary(inputs.input(0)?, B::float_erf),
+        NodeType::Exp => unary(inputs.input(0)?, B::float_exp),
+        NodeType::Floor => unary(inputs.input(0)?, B::float_floor),
+        NodeType::Gelu => unary(inputs.input(0)?, B::gelu),
+        NodeType::Log => unary(inputs.input(0)?, B::float_log),
+        NodeType::Reciprocal => unary(inputs.input(0)?, B::float_recip),
+        NodeType::Relu => unary(inputs.input(0)?, B::relu),
+        NodeType::Round => unary(inputs.input(0)?, B::float_round),
+        NodeType::Sigmoid => unary(inputs.input(0)?, B::sigmoid),
+        NodeType::Sin => unary(inputs.input(0)?, B::float_sin),
+        NodeType::Sqrt => unary(inputs.input(0)?, B::float_sqrt),
+        NodeType::Tanh => unary(inputs.input(0)?, B::float_tanh),
+        NodeType::LeakyRelu => {
+            let alpha = leaky_relu_config(node);
+            Value::Float(B::leaky_relu(inputs.input(0)?.float(), alpha.elem()))
+        }
+        NodeType::HardSigmoid => {
+            let (alpha, beta) = hard_sigmoid_config(node);
+            Value::Float(B::hard_sigmoid(
+                inputs.input(0)?.float(),
+                alpha.elem(),
+                beta.elem(),
+            ))
+        }
+        NodeType::PRelu => {
+            let input = inputs.input(0)?.float();
+            let slope = inputs.input(1)?;
+            let positive = Value::Float(B::float_clamp_min(input.clone(), 0.elem()));
+            let negative = Value::Float(B::float_clamp_max(input, 0.elem()));
+            let negative = arithmetic(negative, slope, B::float_mul, B::int_mul);
+            arithmetic(positive, negative, B::float_add, B::int_add)
+        }
+        NodeType::Softmax => {
+            let input = inputs.input(0)?;
+            let dim = axis(inputs.attr_i64("axis", -1), input.rank());
+            let input = input.float();
+            let (exp, sum) = exp_sum::<B>(input, dim);
+            Value::Float(B::float_div(exp, sum))
+        }
+        NodeType::LogSoftmax => {
+            let input = inputs.input(0)?;
+            let dim = axis(inputs.attr_i64("axis", -1), input.rank());
+            let input = input.float();
+            let max = B::float_max_dim(input.clone(), dim);
+            let shifted = B::float_sub(input, max);
+            let sum = B::float_sum_dim(B::float_exp(shifted.clone()), dim);
+            Value::Float(B::float_sub(shifted, B::float_log(sum)))
+        }
+        NodeType::Clip => {
+            let input = inputs.input(0)?;
+            let min = inputs
+                .attr("min")
+                .map(|min| min.into_f32() as f64)
+                .or_else(|| inputs.optional(1).map(Value::into_f64));
+            let max = inputs
+                .attr("max")
+                .map(|max| max.into_f32() as f64)
+                .or_else(|| inputs.optional(2).map(Value::into_f64));
+            clip(input, min, max)
+        }
+        NodeType::MatMul => matmul(inputs.input(0)?, inputs.input(1)?),
+        NodeType::Einsum => {
+            let equation = einsum_config(node);
+            let operands = inputs.all().into_iter().map(Value::float).collect();
+            Value::Float(einsum_primitive::<B>(&equation, operands))
+        }
+        NodeType::GridSample => {
+            let grid_sample = grid_sample_config(node).init();
+            let output = grid_sample.forward(
+                inputs.input(0)?.into_float::<4>(),
+                inputs.input(1)?.into_float(),
+            );
+            Value::Float(output.into_primitive().tensor())
+        }
+        NodeType::Linear => {
+            let output = matmul(inputs.input(0)?, inputs.input(1)?);
+            match inputs.optional(2) {
+                Some(bias) => arithmetic(output, bias, B::float_add, B::int_add),
+                None => output,
+            }
+        }
+        NodeType::Conv1d
+        | NodeType::Conv2d
+        | NodeType::Conv3d
+        | NodeType::ConvTranspose1d
+        | NodeType::ConvTranspose2d
+        | NodeType::ConvTranspose3d
+        | NodeType::MaxPool1d
+        | NodeType::MaxPool2d
+        | NodeType::AveragePool1d
+        | NodeType::AveragePool2d => {
+            let module = module.ok_or_else(|| inputs.error("the module isn't initialized"))?;
+            Value::Float(module.forward(inputs.input(0)?.float()))
+        }
+        NodeType::GlobalAveragePool => {
+            let input = inputs.input(0)?;
+            let rank = input.rank();
+            let output = (2..rank).fold(input.float(), B::float_mean_dim);
+            Value::Float(output)
+        }
+        NodeType::BatchNormalization => {
+            let input = inputs.input(0)?;
+            let epsilon = inputs.attr_f32("epsilon", 1e-5);
+            let rank = input.rank();
+
+            // The parameters are broadcast along the channels
+            let mut shape = vec![1; rank];
+            shape[1] = input.shape().dims[1];
+            let mut param = |index| {
+                inputs
+                    .input(index)
+                    .map(|value| value.reshape(Shape::from(shape.clone())).float())
+            };
+            let (scale, bias, mean, var) = (param(1)?, param(2)?, param(3)?, param(4)?);
+
+            let std = B::float_sqrt(B::float_add_scalar(var, epsilon.elem()));
+            let output = B::float_div(B::float_sub(input.float(), mean), std);
+            Value::Float(B::float_add(B::float_mul(output, scale), bias))
+        }
+        NodeType::LayerNormalization => {
+            let input = inputs.input(0)?;
+            let epsilon = inputs.attr_f32("epsilon", 1e-5);
+            let rank = input.rank();
+            let first = axis(inputs.attr_i64("axis", -1), rank);
+            let input = input.float();
+
+            let mean_dims = |tensor: FloatTensor<B>| (first..rank).fold(tensor, B::float_mean_dim);
+            let centered = B::float_sub(input.clone(), mean_dims(input));
+            let var = mean_dims(B::float_powf_scalar(centered.clone(), 2.0));
+            let std = B::float_sqrt(B::float_add_scalar(var, epsilon.elem()));
+            let output = Value::Float(B::float_div(centered, std));
+
+            let output = arithmetic(output, inputs.input(1)?, B::float_mul, B::int_mul);
+            match inputs.optional(2) {
+                Some(bias) => arithmetic(output, bias, B::float_add, B::int_add),
+                None => output,
+            }
+        }
+        NodeType::Dropout | NodeType::Identity => inputs.input(0)?,
+        NodeType::Cast => inputs.input(0)?.cast(ValueKind::from(&node.outputs[0].ty)),
+        NodeType::Reshape => {
+            let input = inputs.input(0)?;
+            let shape = inputs.input(1)?.into_i64s();
+            let allow_zero = inputs.attr_i64("allowzero", 0) != 0;
+            let shape = reshape_shape(&input.shape(), &shape, allow_zero)
+                .ok_or_else(|| inputs.error(format!("invalid shape {shape:?}")))?;
+            input.reshape(shape)
+        }
+        NodeType::Flatten => {
+            let input = inputs.input(0)?;
+            let dims = input.shape().dims;
+            let dim = axis(inputs.attr_i64("axis", 1), dims.len());
+            let rows = dims[..dim].iter().product::<usize>();
+            let cols = dims[dim..].iter().product::<usize>();
+            input.reshape(Shape::new([rows, cols]))
+        }
+        NodeType::Transpose => {
+            let input = inputs.input(0)?;
+            let rank = input.rank();
+            let axes = match inputs.attr("perm") {
+                Some(perm) => perm
+                    .into_i64s()
+                    .into_iter()
+                    .map(|dim| axis(dim, rank))
+                    .collect(),
+                None => (0..rank).rev().collect::<Vec<_>>(),
+            };
+            input.permute(&axes)
+        }
+        NodeType::Squeeze => {
+            let input = inputs.input(0)?;
+            let dims = input.shape().dims;
+            let axes = match inputs.ints("axes", 1) {
+                Some(axes) => axes.into_iter().map(|dim| axis(dim, dims.len())).collect(),
+                None => (0..dims.len())
+                    .filter(|&i| dims[i] == 1)
+                    .collect::<Vec<_>>(),
+            };
+            let shape = (0..dims.len())
+                .filter(|i| !axes.contains(i))
+                .map(|i| dims[i])
+                .collect::<Vec<_>>();
+            input.reshape(Shape::from(shape))
+        }
+        NodeType::Unsqueeze => {
+            let input = inputs.input(0)?;
+            let axes = inputs
+                .ints("axes", 1)
+                .ok_or_else(|| inputs.error("missing axes"))?;
+            // Scalars are unsqueezed from their rank 0
+            let dims = match node.inputs[0].ty.rank() {
+                0 => vec![],
+                _ => input.shape().dims,
+            };
+            let rank = dims.len() + axes.len();
+            let axes = axes
+                .into_iter()
+                .map(|dim| axis(dim, rank))
+                .collect::<Vec<_>>();
+            let mut dims = dims.into_iter();
+            let shape = (0..rank)
+                .map(|i| match axes.contains(&i) {
+                    true => 1,
+                    false => dims.next().unwrap_or(1),
+                })
+                .collect::<Vec<_>>();
+            input.reshape(Shape::from(shape))
+        }
+        NodeType::Concat => {
+            let values = inputs.all();
+            let rank = values.first().map_or(0, Value::rank);
+            let dim = axis(inputs.attr_i64("axis", 0), rank);
+            cat(values, dim)
+        }
+        NodeType::CumSum => {
+            let input = inputs.input(0)?;
+            let dim = axis(inputs.input(1)?.into_i64s()[0], input.rank());
+            let exclusive = inputs.attr_i64("exclusive", 0) != 0;
+            let reverse = inputs.attr_i64("reverse", 0) != 0;
+            input.map(
+                |tensor| B::float_cumsum(tensor, dim, exclusive, reverse),
+                |tensor| B::int_cumsum(tensor, dim, exclusive, reverse),
+                |tensor| tensor,
+            )
+        }
+        NodeType::Gather => {
+            let input = inputs.input(0)?;
+            let indices = inputs.input(1)?;
+            let dims = input.shape().dims;
+            let dim = axis(inputs.attr_i64("axis", 0), dims.len());
+
+            // Scalar indices remove the gathered dimension
+            let indices_dims = match node.inputs[1].ty.rank() {
+                0 => vec![],
+                _ => indices.shape().dims,
+            };
+            let num_indices = indices.shape().num_elements();
+            let indices = B::int_reshape(indices.int(), Shape::new([num_indices]));
+            let indices = positive_indices::<B>(indices, dims[dim]);
+
+            let mut shape = dims[..dim].to_vec();
+            shape.extend(indices_dims);
+            shape.extend_from_slice(&dims[dim + 1..]);
+            input.select(dim, indices).reshape(Shape::from(shape))
+        }
+        NodeType::GatherElements => {
+            let input = inputs.input(0)?;
+            let indices = inputs.input(1)?;
+            let dims = input.shape().dims;
+            let dim = axis(inputs.attr_i64("axis", 0), dims.len());
+            let indices = positive_indices::<B>(indices.int(), dims[dim]);
+
+            match input {
+                Value::Float(tensor) => Value::Float(B::float_gather(dim, tensor, indices)),
+                input => {
+                    let kind = input.kind();
+                    Value::Int(B::int_gather(dim, input.int(), indices)).cast(kind)
+                }
+            }
+        }
+        NodeType::Slice => {
+            let input = inputs.input(0)?;
+            let dims = input.shape().dims;
+            let starts = inputs
+                .ints("starts", 1)
+                .ok_or_else(|| inputs.error("missing starts"))?;
+            let ends = inputs
+                .ints("ends", 2)
+                .ok_or_else(|| inputs.error("missing ends"))?;
+            let axes = inputs
+                .ints("axes", 3)
+                .unwrap_or_else(|| (0..starts.len() as i64).collect());
+            if let Some(steps) = inputs.optional(4) {
+                if steps.into_i64s().iter().any(|&step| step != 1) {
+                    return Err(inputs.error("only unit steps are supported"));
+                }
+            }
+
+            let mut ranges = dims.iter().map(|&dim| 0..dim).collect::<Vec<_>>();
+            for ((start, end), dim) in starts.into_iter().zip(ends).zip(axes) {
+                let dim = axis(dim, dims.len());
+                let start = clamp_index(start, dims[dim]);
+                let end = clamp_index(end, dims[dim]).max(start);
+                ranges[dim] = start..end;
+            }
+            input.slice(&ranges)
+        }
+        NodeType::Shape => {
+            let dims = inputs.input(0)?.shape().dims;
+            let start = clamp_index(inputs.attr_i64("start", 0), dims.len());
+            let end = clamp_index(inputs.attr_i64("end", dims.len() as i64), dims.len());
+            let dims = dims[start..end.max(start)]
+                .iter()
+                .map(|&dim| dim as i64)
+                .collect::<Vec<_>>();
+            int_value(dims, inputs.device)
+        }
+        NodeType::Expand => {
+            let input = inputs.input(0)?;
+            let shape = Shape::from(inputs.input(1)?.into_i64s());
+            let shape = broadcast_shape(&[input.shape(), shape])?;
+            input.unsqueeze_to(shape.num_dims()).expand(shape)
+        }
+        NodeType::Tile => {
+            let input = inputs.input(0)?;
+            let repeats = inputs.input(1)?.into_i64s();
+            repeats
+                .into_iter()
+                .enumerate()
+                .filter(|(_, times)| *times != 1)
+                .fold(input, |input, (dim, times)| {
+                    input.repeat_dim(dim, times as usize)
+                })
+        }
+        NodeType::Where => {
+            let condition = inputs.input(0)?;
+            let (lhs, rhs) = (inputs.input(1)?, inputs.input(2)?);
+            let shape = broadcast_shape(&[condition.shape(), lhs.shape(), rhs.shape()])?;
+            let expand =
+                |value: Value<B>| value.unsqueeze_to(shape.num_dims()).expand(shape.clone());
+            let mask = expand(condition).bool();
+
+            match (lhs.kind(), rhs.kind()) {
+                (ValueKind::Float, _) | (_, ValueKind::Float) => Value::Float(B::float_mask_where(
+                    expand(rhs).float(),
+                    mask,
+                    expand(lhs).float(),
+                )),
+                (kind, _) => Value::Int(B::int_mask_where(
+                    expand(rhs).int(),
+                    mask,
+                    expand(lhs).int(),
+                ))
+                .cast(kind),
+            }
+        }
+        NodeType::ArgMax | NodeType::ArgMin => {
+            let input = inputs.input(0)?;
+            let dims = input.shape().dims;
+            let dim = axis(inputs.attr_i64("axis", 0), dims.len());
+            let output = match (&node.node_type, input) {
+                (NodeType::ArgMax, Value::Float(tensor)) => B::float_argmax(tensor, dim),
+                (NodeType::ArgMax, input) => B::int_argmax(input.int(), dim),
+                (_, Value::Float(tensor)) => B::float_argmin(tensor, dim),
+                (_, input) => B::int_argmin(input.int(), dim),
+            };
+            let output = Value::Int(output);
+
+            match inputs.attr_i64("keepdims", 1) {
+                0 => output.reshape(Shape::from(remove_dims(&dims, &[dim]))),
+                _ => output,
+            }
+        }
+        NodeType::ReduceMax => reduce(&mut inputs, B::float_max_dim, B::int_max_dim)?,
+        NodeType::ReduceMin => reduce(&mut inputs, B::float_min_dim, B::int_min_dim)?,
+        NodeType::ReduceMean => reduce(&mut inputs, B::float_mean_dim, B::int_mean_dim)?,
+        NodeType::ReduceProd => reduce(&mut inputs, B::float_prod_dim, B::int_prod_dim)?,
+        NodeType::ReduceSum => reduce(&mut inputs, B::float_sum_dim, B::int_sum_dim)?,
+        NodeType::ConstantOfShape => {
+            let shape = Shape::from(inputs.input(0)?.into_i64s());
+            let value = match inputs.attr("value") {
+                Some(AttributeValue::Tensor(tensor)) => tensor.data,
+                _ => None,
+            };
+            constant_of_shape(value, shape, inputs.device)
+                .ok_or_else(|| inputs.error("unsupported value"))?
+        }
+        NodeType::Range => {
+            let start = inputs.input(0)?;
+            let (limit, delta) = (inputs.input(1)?, inputs.input(2)?);
+            match start.kind() {
+                ValueKind::Float => {
+                    let (start, limit, delta) =
+                        (start.into_f64(), limit.into_f64(), delta.into_f64());
+                    let len = ((limit - start) / delta).ceil().max(0.0) as usize;
+                    let values = (0..len)
+                        .map(|i| start + i as f64 * delta)
+                        .collect::<Vec<_>>();
+                    Value::from_data(TensorData::new(values, [len]), inputs.device)
+                }
+                _ => {
+                    let start = start.into_i64s()[0];
+                    let (limit, delta) = (limit.into_i64s()[0], delta.into_i64s()[0]);
+                    if delta == 0 {
+                        return Err(inputs.error("the delta must be nonzero"));
+                    }
+                    let len = ((limit - start) as f64 / delta as f64).ceil().max(0.0) as usize;
+                    let values = (0..len as i64).map(|i| start + i * delta).collect();
+                    int_value(values, inputs.device)
+                }
+            }
+        }
+        _ => return Err(Error::UnsupportedOps(vec![node.node_type.clone()])),
+    };
+
+    Ok(vec![output])
+}
+
+/// Normalize a possibly negative axis of a tensor of the given rank.
+fn axis(axis: i64, rank: usize) -> usize {
+    match axis < 0 {
+        true => (axis + rank as i64) as usize,
+        false => axis as usize,
+    }
+}
+
+/// Normalize a possibly negative index along a dimension, clamped to the dimension size.
+fn clamp_index(index: i64, size: usize) -> usize {
+    let size = size as i64;
+    let index = match index < 0 {
+        true => index + size,
+        false => index,
+    };
+
+    index.clamp(0, size) as usize
+}
+
+fn remove_dims(dims: &[usize], removed: &[usize]) -> Vec<usize> {
+    dims.iter()
+        .enumerate()
+        .filter(|(i, _)| !removed.contains(i))
+        .map(|(_, &dim)| dim)
+        .collect()
+}
+
+/// Offset the negative indices by the dimension size.
+fn positive_indices<B: Backend>(indices: IntTensor<B>, size: usize) -> IntTensor<B> {
+    let negative = B::int_lower_elem(indices.clone(), 0.elem());
+    let offset = B::int_add_scalar(indices.clone(), (size as i64).elem());
+    B::int_mask_where(indices, negative, offset)
+}
+
+fn int_value<B: Backend>(values: Vec<i64>, device: &B::Device) -> Value<B> {
+    let len = values.len();
+    Value::from_data(TensorData::new(values, [len]), device)
+}
+
+/// The shape of a reshape operator, where a dimension of 0 copies the input dimension (unless
+/// `allow_zero` is set) and a dimension of -1 is inferred from the number of elements.
+fn reshape_shape(input: &Shape, shape: &[i64], allow_zero: bool) -> Option<Shape> {
+    let mut dims = shape
+        .iter()
+        .enumerate()
+        .map(|(i, &dim)| match dim {
+            0 if !allow_zero => input.dims.get(i).copied(),
+            -1 => Some(1),
+            dim => usize::try_from(dim).ok(),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    if let Some(inferred) = shape.iter().position(|&dim| dim == -1) {
+        let known = dims.iter().product::<usize>();
+        if known == 0 {
+            return None;
+        }
+        dims[inferred] = input.num_elements() / known;
+    }
+
+    Some(Shape::from(dims))
+}
+
+fn unary<B: Backend>(input: Value<B>, op: fn(FloatTensor<B>) -> FloatTensor<B>) -> Value<B> {
+    Value::Float(op(input.float()))
+}
+
+/// Broadcast the values to the same rank, as expected by the backends.
+fn same_rank<B: Backend>(lhs: Value<B>, rhs: Value<B>) -> (Value<B>, Value<B>) {
+    let rank = usize::max(lhs.rank(), rhs.rank());
+    (lhs.unsqueeze_to(rank), rhs.unsqueeze_to(rank))
+}
+
+/// An arithmetic operator, computed on integers when both values are integers.
+fn arithmetic<B: Backend>(
+    lhs: Value<B>,
+    rhs: Value<B>,
+    float_op: fn(FloatTensor<B>, FloatTensor<B>) -> FloatTensor<B>,
+    int_op: fn(IntTensor<B>, IntTensor<B>) -> IntTensor<B>,
+) -> Value<B> {
+    let (lhs, rhs) = same_rank(lhs, rhs);
+
+    match (lhs.kind(), rhs.kind()) {
+        (ValueKind::Float, _) | (_, ValueKind::Float) => {
+            Value::Float(float_op(lhs.float(), rhs.float()))
+        }
+        _ => Value::Int(int_op(lhs.int(), rhs.int())),
+    }
+}
+
+/// A comparison operator, computed on integers when both values are integers.
+fn comparison<B: Backend>(
+    lhs: Value<B>,
+    rhs: Value<B>,
+    float_op: fn(FloatTensor<B>, FloatTensor<B>) -> BoolTensor<B>,
+    int_op: fn(IntTensor<B>, IntTensor<B>) -> BoolTensor<B>,
+) -> Value<B> {
+    let (lhs, rhs) = same_rank(lhs, rhs);
+
+    match (lhs.kind(), rhs.kind()) {
+        (ValueKind::Float, _) | (_, ValueKind::Float) => {
+            Value::Bool(float_op(lhs.float(), rhs.float()))
+        }
+        _ => Value::Bool(int_op(lhs.int(), rhs.int())),
+    }
+}
+
+fn logical<B: Backend>(
+    lhs: Value<B>,
+    rhs: Value<B>,
+    op: fn(BoolTensor<B>, BoolTensor<B>) -> BoolTensor<B>,
+) -> Value<B> {
+    let (lhs, rhs) = same_rank(lhs, rhs);
+    Value::Bool(op(lhs.bool(), rhs.bool()))
+}
+
+/// The element-wise maximum or minimum of the values, where an element is replaced by the next
+/// value when the comparison with it is true.
+fn extremum<B: Backend>(
+    values: Vec<Value<B>>,
+    float_op: fn(FloatTensor<B>, FloatTensor<B>) -> BoolTensor<B>,
+    int_op: fn(IntTensor<B>, IntTensor<B>) -> BoolTensor<B>,
+) -> Result<Value<B>, Error> {
+    let shapes = values.iter().map(Value::shape).collect::<Vec<_>>();
+    let shape = broadcast_shape(&shapes)?;
+    let is_float = values.iter().any(|value| value.kind() == ValueKind::Float);
+    let mut values = values
+        .into_iter()
+        .map(|value| value.unsqueeze_to(shape.num_dims()).expand(shape.clone()));
+
+    let first = values
+        .next()
+        .ok_or_else(|| Error::InvalidInput("missing inputs".to_string()))?;
+    let output = match is_float {
+        true => Value::Float(values.fold(first.float(), |acc, value| {
+            let value = value.float();
+            let mask = float_op(acc.clone(), value.clone());
+            B::float_mask_where(acc, mask, value)
+        })),
+        false => Value::Int(values.fold(first.int(), |acc, value| {
+            let value = value.int();
+            let mask = int_op(acc.clone(), value.clone());
+            B::int_mask_where(acc, mask, value)
+        })),
+    };
+
+    Ok(output)
+}
+
+fn clip<B: Backend>(input: Value<B>, min: Option<f64>, max: Option<f64>) -> Value<B> {
+    match input {
+        Value::Int(tensor) => {
+            let tensor = match min {
+                Some(min) => B::int_clamp_min(tensor, (min as i64).elem()),
+                None => tensor,
+            };
+            let tensor = match max {
+                Some(max) => B::int_clamp_max(tensor, (max as i64).elem()),
+                None => tensor,
+            };
+            Value::Int(tensor)
+        }
+        input => {
+            let tensor = input.float();
+            let tensor = match min {
+                Some(min) => B::float_clamp_min(tensor, min.elem()),
+                None => tensor,
+            };
+            let tensor = match max {
+                Some(max) => B::float_clamp_max(tensor, max.elem()),
+                None => tensor,
+            };
+            Value::Float(tensor)
+        }
+    }
+}
+
+/// The exponential of the shifted input along the dimension and its sum, for a softmax.
+fn exp_sum<B: Backend>(input: FloatTensor<B>, dim: usize) -> (FloatTensor<B>, FloatTensor<B>) {
+    let max = B::float_max_dim(input.clone(), dim);
+    let exp = B::float_exp(B::float_sub(input, max));
+    let sum = B::float_sum_dim(exp.clone(), dim);
+
+    (exp, sum)
+}
+
+/// A matrix product with the semantics of `numpy.matmul`, where the 1D operands are promoted to
+/// matrices and the batch dimensions are broadcast.
+fn matmul<B: Backend>(lhs: Value<B>, rhs: Value<B>) -> Value<B> {
+    let (lhs_rank, rhs_rank) = (lhs.rank(), rhs.rank());
+    let is_int = lhs.kind() == ValueKind::Int && rhs.kind() == ValueKind::Int;
+
+    let lhs = lhs.unsqueeze_to(2);
+    let rhs = match rhs_rank {
+        1 => {
+            let size = rhs.shape().dims[0];
+            rhs.reshape(Shape::new([size, 1]))
+        }
+        _ => rhs,
+    };
+    let (lhs, rhs) = same_rank(lhs, rhs);
+    let output = B::float_matmul(lhs.float(), rhs.float());
+
+    // Remove the promoted dimensions
+    let mut dims = output.shape().dims;
+    if rhs_rank == 1 {
+        dims.pop();
+    }
+    if lhs_rank == 1 {
+        let dim = dims.len() - if rhs_rank == 1 { 1 } else { 2 };
+        dims.remove(dim);
+    }
+
+    let output = Value::Float(output).reshape(Shape::from(dims));
+    match is_int {
+        true => output.cast(ValueKind::Int),
+        false => output,
+    }
+}
+
+/// A reduce operator along the axes, given as an attribute or an input, or along all the
+/// dimensions when no axes are given.
+fn reduce<B: Backend>(
+    inputs: &mut NodeInputs<B>,
+    float_op: fn(FloatTensor<B>, usize) -> FloatTensor<B>,
+    int_op: fn(IntTensor<B>, usize) -> IntTensor<B>,
+) -> Result<Value<B>, Error> {
+    let input = inputs.input(0)?;
+    let dims = input.shape().dims;
+    let keep_dims = inputs.attr_i64("keepdims", 1) != 0;
+    let axes = inputs.ints("axes", 1).unwrap_or_default();
+
+    if axes.is_empty() && inputs.attr_i64("noop_with_empty_axes", 0) != 0 {
+        return Ok(input);
+    }
+
+    let axes = match axes.is_empty() {
+        true => (0..dims.len()).collect::<Vec<_>>(),
+        false => axes.into_iter().map(|dim| axis(dim, dims.len())).collect(),
+    };
+
+    let output = match input {
+        Value::Float(tensor) => Value::Float(axes.iter().copied().fold(tensor, float_op)),
+        input => Value::Int(axes.iter().copied().fold(input.int(), int_op)),
+    };
+
+    Ok(match keep_dims {
+        true => output,
+        false => output.reshape(Shape::from(remove_dims(&dims, &axes))),
+    })
+}
+
+fn constant_of_shape<B: Backend>(
+    value: Option<Data>,
+    shape: Shape,
+    device: &B::Device,
+) -> Option<Value<B>> {
+    let shape = match shape.num_dims() {
+        0 => Shape::new([1]),
+        _ => shape,
+    };
+
+    let value = match value.map(Data::into_scalar) {
+        None => Value::Float(B::float_zeros(shape, device)),
+        Some(Data::Float16(value)) => Value::Float(B::float_full(shape, value.elem(), device)),
+        Some(Data::Float32(value)) => Value::Float(B::float_full(shape, value.elem(), device)),
+        Some(Data::Float64(value)) => Value::Float(B::float_full(shape, value.elem(), device)),
+        Some(Data::Int32(value)) => Value::Int(B::int_full(shape, value.elem(), device)),
+        Some(Data::Int64(value)) => Value::Int(B::int_full(shape, value.elem(), device)),
+        Some(Data::Bool(value)) => {
+            Value::Int(B::int_full(shape, (value as i64).elem(), device)).cast(ValueKind::Bool)
+        }
+        Some(_) => return None,
+    };
+
+    Some(value)
+}
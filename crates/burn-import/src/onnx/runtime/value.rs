@@ -0,0 +1,349 @@
+use burn::tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+    try_read_sync, Bool, DType, Element, ElementConversion, Int, Shape, Tensor, TensorData,
+    TensorMetadata, TensorPrimitive,
+};
+use onnx_ir::ir::{ArgType, Argument, Data, ElementType};
+
+use super::Error;
+
+/// A value of the graph, i.e. a tensor of any rank computed or loaded by the interpreter.
+///
+/// Scalars are represented as tensors of shape `[1]`.
+#[derive(Debug, Clone)]
+pub enum Value<B: Backend> {
+    /// A float tensor.
+    Float(FloatTensor<B>),
+    /// An int tensor.
+    Int(IntTensor<B>),
+    /// A bool tensor.
+    Bool(BoolTensor<B>),
+}
+
+/// The kind of the elements of a [value](Value).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// Float elements.
+    Float,
+    /// Int elements.
+    Int,
+    /// Bool elements.
+    Bool,
+}
+
+impl<B: Backend> Value<B> {
+    /// Create a value from the given data, whose kind is inferred from the data type.
+    pub fn from_data(data: TensorData, device: &B::Device) -> Self {
+        match data.dtype {
+            DType::Bool => Self::Bool(B::bool_from_data(data, device)),
+            dtype if dtype.is_float() => {
+                Self::Float(B::float_from_data(data.convert::<B::FloatElem>(), device))
+            }
+            _ => Self::Int(B::int_from_data(data.convert::<B::IntElem>(), device)),
+        }
+    }
+
+    /// Read the data of the value.
+    pub fn into_data(self) -> TensorData {
+        let data = match self {
+            Self::Float(tensor) => try_read_sync(B::float_into_data(tensor)),
+            Self::Int(tensor) => try_read_sync(B::int_into_data(tensor)),
+            Self::Bool(tensor) => try_read_sync(B::bool_into_data(tensor)),
+        };
+
+        data.expect("Failed to read tensor data synchronously.")
+    }
+
+    /// Convert the value into a float tensor.
+    pub fn into_float<const D: usize>(self) -> Tensor<B, D> {
+        Tensor::from_primitive(TensorPrimitive::Float(self.float()))
+    }
+
+    /// Convert the value into an int tensor.
+    pub fn into_int<const D: usize>(self) -> Tensor<B, D, Int> {
+        Tensor::from_primitive(self.int())
+    }
+
+    /// Convert the value into a bool tensor.
+    pub fn into_bool<const D: usize>(self) -> Tensor<B, D, Bool> {
+        Tensor::from_primitive(self.bool())
+    }
+
+    /// The kind of the elements of the value.
+    pub fn kind(&self) -> ValueKind {
+        match self {
+            Self::Float(_) => ValueKind::Float,
+            Self::Int(_) => ValueKind::Int,
+            Self::Bool(_) => ValueKind::Bool,
+        }
+    }
+
+    /// The shape of the value.
+    pub fn shape(&self) -> Shape {
+        match self {
+            Self::Float(tensor) => tensor.shape(),
+            Self::Int(tensor) => tensor.shape(),
+            Self::Bool(tensor) => tensor.shape(),
+        }
+    }
+
+    /// The rank of the value.
+    pub fn rank(&self) -> usize {
+        self.shape().num_dims()
+    }
+
+    /// The float primitive of the value, casting int and bool elements.
+    pub(crate) fn float(self) -> FloatTensor<B> {
+        match self {
+            Self::Float(tensor) => tensor,
+            Self::Int(tensor) => B::int_into_float(tensor),
+            Self::Bool(tensor) => B::bool_into_float(tensor),
+        }
+    }
+
+    /// The int primitive of the value, casting float and bool elements.
+    pub(crate) fn int(self) -> IntTensor<B> {
+        match self {
+            Self::Float(tensor) => B::float_into_int(tensor),
+            Self::Int(tensor) => tensor,
+            Self::Bool(tensor) => B::bool_into_int(tensor),
+        }
+    }
+
+    /// The bool primitive of the value, where nonzero elements are true.
+    pub(crate) fn bool(self) -> BoolTensor<B> {
+        match self {
+            Self::Float(tensor) => B::float_not_equal_elem(tensor, 0.elem()),
+            Self::Int(tensor) => B::int_not_equal_elem(tensor, 0.elem()),
+            Self::Bool(tensor) => tensor,
+        }
+    }
+
+    /// Cast the value to the given kind.
+    pub(crate) fn cast(self, kind: ValueKind) -> Self {
+        match kind {
+            ValueKind::Float => Self::Float(self.float()),
+            ValueKind::Int => Self::Int(self.int()),
+            ValueKind::Bool => Self::Bool(self.bool()),
+        }
+    }
+
+    /// Read the elements of the value as integers.
+    pub(crate) fn into_i64s(self) -> Vec<i64> {
+        self.into_data().iter::<i64>().collect()
+    }
+
+    /// Read the elements of the value as floats.
+    pub(crate) fn into_f64s(self) -> Vec<f64> {
+        self.into_data().iter::<f64>().collect()
+    }
+
+    /// Read the single element of a scalar value.
+    pub(crate) fn into_f64(self) -> f64 {
+        self.into_f64s()[0]
+    }
+
+    /// Apply the given operation to the primitive of the value, regardless of its kind.
+    pub(crate) fn map(
+        self,
+        float_op: impl FnOnce(FloatTensor<B>) -> FloatTensor<B>,
+        int_op: impl FnOnce(IntTensor<B>) -> IntTensor<B>,
+        bool_op: impl FnOnce(BoolTensor<B>) -> BoolTensor<B>,
+    ) -> Self {
+        match self {
+            Self::Float(tensor) => Self::Float(float_op(tensor)),
+            Self::Int(tensor) => Self::Int(int_op(tensor)),
+            Self::Bool(tensor) => Self::Bool(bool_op(tensor)),
+        }
+    }
+
+    pub(crate) fn reshape(self, shape: Shape) -> Self {
+        // Scalars are represented with a single dimension
+        let shape = match shape.num_dims() {
+            0 => Shape::new([1]),
+            _ => shape,
+        };
+
+        self.map(
+            |tensor| B::float_reshape(tensor, shape.clone()),
+            |tensor| B::int_reshape(tensor, shape.clone()),
+            |tensor| B::bool_reshape(tensor, shape.clone()),
+        )
+    }
+
+    pub(crate) fn expand(self, shape: Shape) -> Self {
+        self.map(
+            |tensor| B::float_expand(tensor, shape.clone()),
+            |tensor| B::int_expand(tensor, shape.clone()),
+            |tensor| B::bool_expand(tensor, shape.clone()),
+        )
+    }
+
+    pub(crate) fn permute(self, axes: &[usize]) -> Self {
+        self.map(
+            |tensor| B::float_permute(tensor, axes),
+            |tensor| B::int_permute(tensor, axes),
+            |tensor| B::bool_permute(tensor, axes),
+        )
+    }
+
+    pub(crate) fn slice(self, ranges: &[core::ops::Range<usize>]) -> Self {
+        self.map(
+            |tensor| B::float_slice(tensor, ranges),
+            |tensor| B::int_slice(tensor, ranges),
+            |tensor| B::bool_slice(tensor, ranges),
+        )
+    }
+
+    pub(crate) fn repeat_dim(self, dim: usize, times: usize) -> Self {
+        self.map(
+            |tensor| B::float_repeat_dim(tensor, dim, times),
+            |tensor| B::int_repeat_dim(tensor, dim, times),
+            |tensor| B::bool_repeat_dim(tensor, dim, times),
+        )
+    }
+
+    /// Select the given indices along the dimension.
+    pub(crate) fn select(self, dim: usize, indices: IntTensor<B>) -> Self {
+        match self {
+            Self::Float(tensor) => Self::Float(B::float_select(tensor, dim, indices)),
+            Self::Int(tensor) => Self::Int(B::int_select(tensor, dim, indices)),
+            Self::Bool(tensor) => {
+                let selected = B::int_select(B::bool_into_int(tensor), dim, indices);
+                Self::Bool(B::int_not_equal_elem(selected, 0.elem()))
+            }
+        }
+    }
+
+    /// Prepend dimensions of size 1 up to the given rank.
+    pub(crate) fn unsqueeze_to(self, rank: usize) -> Self {
+        let mut dims = self.shape().dims;
+        if dims.len() >= rank {
+            return self;
+        }
+
+        let mut shape = vec![1; rank - dims.len()];
+        shape.append(&mut dims);
+        self.reshape(Shape::from(shape))
+    }
+}
+
+/// Concatenate the values along the given dimension, casting them to the kind of the first one.
+pub(crate) fn cat<B: Backend>(values: Vec<Value<B>>, dim: usize) -> Value<B> {
+    match values[0].kind() {
+        ValueKind::Float => Value::Float(B::float_cat(
+            values.into_iter().map(Value::float).collect(),
+            dim,
+        )),
+        ValueKind::Int => Value::Int(B::int_cat(
+            values.into_iter().map(Value::int).collect(),
+            dim,
+        )),
+        ValueKind::Bool => Value::Bool(B::bool_cat(
+            values.into_iter().map(Value::bool).collect(),
+            dim,
+        )),
+    }
+}
+
+/// The multidirectional broadcast shape of the given shapes.
+pub(crate) fn broadcast_shape(shapes: &[Shape]) -> Result<Shape, Error> {
+    let rank = shapes.iter().map(Shape::num_dims).max().unwrap_or(0);
+    let mut dims = vec![1; rank];
+
+    for shape in shapes {
+        let offset = rank - shape.num_dims();
+        for (i, &dim) in shape.dims.iter().enumerate() {
+            let out = &mut dims[offset + i];
+            if *out == 1 {
+                *out = dim;
+            } else if dim != 1 && dim != *out {
+                return Err(Error::InvalidInput(format!(
+                    "shapes {:?} can't be broadcast together",
+                    shapes.iter().map(|shape| &shape.dims).collect::<Vec<_>>()
+                )));
+            }
+        }
+    }
+
+    Ok(Shape::from(dims))
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for Value<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        Self::Float(tensor.into_primitive().tensor())
+    }
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D, Int>> for Value<B> {
+    fn from(tensor: Tensor<B, D, Int>) -> Self {
+        Self::Int(tensor.into_primitive())
+    }
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D, Bool>> for Value<B> {
+    fn from(tensor: Tensor<B, D, Bool>) -> Self {
+        Self::Bool(tensor.into_primitive())
+    }
+}
+
+impl From<&ElementType> for ValueKind {
+    fn from(elem_type: &ElementType) -> Self {
+        match elem_type {
+            ElementType::Float16 | ElementType::Float32 | ElementType::Float64 => Self::Float,
+            ElementType::Bool => Self::Bool,
+            _ => Self::Int,
+        }
+    }
+}
+
+impl From<&ArgType> for ValueKind {
+    fn from(ty: &ArgType) -> Self {
+        match ty {
+            ArgType::Shape(_) => Self::Int,
+            ArgType::Scalar(elem_type) => elem_type.into(),
+            ArgType::Tensor(tensor) => (&tensor.elem_type).into(),
+        }
+    }
+}
+
+/// Convert the constant data of an argument to tensor data.
+pub(crate) fn argument_data(arg: &Argument) -> Result<TensorData, Error> {
+    let Some(value) = arg.value.clone() else {
+        return Err(Error::MissingValue(arg.name.clone()));
+    };
+
+    let data = match value {
+        Data::Bool(value) => TensorData::new(vec![value], [1]),
+        Data::Bools(values) => tensor_data(values, arg),
+        Data::Float16(value) => TensorData::new(vec![value], [1]),
+        Data::Float16s(values) => tensor_data(values, arg),
+        Data::Float32(value) => TensorData::new(vec![value], [1]),
+        Data::Float32s(values) => tensor_data(values, arg),
+        Data::Float64(value) => TensorData::new(vec![value], [1]),
+        Data::Float64s(values) => tensor_data(values, arg),
+        Data::Int32(value) => TensorData::new(vec![value], [1]),
+        Data::Int32s(values) => tensor_data(values, arg),
+        Data::Int64(value) => TensorData::new(vec![value], [1]),
+        Data::Int64s(values) => tensor_data(values, arg),
+        Data::String(_) | Data::Strings(_) => {
+            return Err(Error::InvalidInput(format!(
+                "{}: string tensors are not supported",
+                arg.name
+            )))
+        }
+    };
+
+    Ok(data)
+}
+
+fn tensor_data<E: Element>(values: Vec<E>, arg: &Argument) -> TensorData {
+    let shape = match &arg.ty {
+        ArgType::Tensor(tensor) if tensor.dim > 0 => tensor.shape.clone(),
+        _ => None,
+    };
+    let shape = shape.unwrap_or(vec![values.len()]);
+
+    TensorData::new(values, shape)
+}
@@ -24,6 +24,7 @@ use crate::{
             concat::ConcatNode,
             constant::{ConstantNode, ConstantValue},
             constant_of_shape::ConstantOfShapeNode,
+            control_flow::{IfNode, LoopNode, ScanNode},
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv3d::Conv3dNode,
@@ -31,13 +32,17 @@ use crate::{
             conv_transpose_2d::ConvTranspose2dNode,
             conv_transpose_3d::ConvTranspose3dNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
             expand::{ExpandNode, ExpandShape},
             floor::FloorNode,
             gather::GatherNode,
             gather_elements::GatherElementsNode,
             global_avg_pool::GlobalAvgPoolNode,
+            grid_sample::GridSampleNode,
+            gru::GruNode,
             layer_norm::LayerNormNode,
             linear::LinearNode,
+            lstm::LstmNode,
             mask_where::WhereNode,
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
@@ -45,6 +50,12 @@ use crate::{
             one_hot::OneHotNode,
             pad::PadNode,
             prelu::PReluNode,
+            qlinear_conv::{ConvIntegerNode, QLinearConvNode},
+            qlinear_matmul::{MatMulIntegerNode, QLinearMatMulNode},
+            quantization::{
+                DequantizeLinearNode, QuantizationConfig, QuantizeLinearNode, QuantizedInput,
+                QuantizedWeights,
+            },
             random_normal::RandomNormalNode,
             random_normal_like::RandomNormalLikeNode,
             random_uniform::RandomUniformNode,
@@ -52,6 +63,7 @@ use crate::{
             range::RangeNode,
             reshape::ReshapeNode,
             resize::ResizeNode,
+            rnn::{GateWeights, RnnNode},
             slice::SliceNode,
             squeeze::SqueezeNode,
             sum::SumNode,
@@ -70,12 +82,14 @@ use crate::{
 use super::op_configuration::{
     argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
     concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose1d_config,
-    conv_transpose2d_config, conv_transpose3d_config, dropout_config, expand_config,
-    flatten_config, gather_config, hard_sigmoid_config, layer_norm_config, leaky_relu_config,
-    linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config, one_hot_config,
-    pad_config, reduce_max_config, reduce_mean_config, reduce_min_config, reduce_prod_config,
-    reduce_sum_config, reshape_config, resize_config, shape_config, slice_config, softmax_config,
-    squeeze_config, tile_config, top_k_config, transpose_config, trilu_config, unsqueeze_config,
+    conv_transpose2d_config, conv_transpose3d_config, dropout_config, einsum_config, expand_config,
+    flatten_config, gather_config, grid_sample_config, gru_config, hard_sigmoid_config,
+    layer_norm_config, leaky_relu_config, linear_config, log_softmax_config, lstm_config,
+    max_pool1d_config, max_pool2d_config, one_hot_config, pad_config, quantization_config,
+    quantize_linear_config, quantized_elem_type, reduce_max_config, reduce_mean_config,
+    reduce_min_config, reduce_prod_config, reduce_sum_config, reshape_config, resize_config,
+    rnn_config, scan_config, shape_config, slice_config, softmax_config, squeeze_config,
+    tile_config, top_k_config, transpose_config, trilu_config, unsqueeze_config,
 };
 use onnx_ir::{
     convert_constant_value,
@@ -257,9 +271,37 @@ impl ParsedOnnxGraph {
     pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
         let mut graph = BurnGraph::<PS>::default();
 
+        Self::register_nodes(&mut graph, self.0.nodes);
+
+        // Get input and output names
+        let input_names = self
+            .0
+            .inputs
+            .iter()
+            .map(|input| input.name.clone())
+            .collect::<Vec<_>>();
+
+        let output_names = self
+            .0
+            .outputs
+            .iter()
+            .map(|output| output.name.clone())
+            .collect::<Vec<_>>();
+
+        // Register inputs and outputs with the graph
+        graph.register_input_output(input_names, output_names);
+
+        graph
+    }
+
+    /// Converts the ONNX nodes and registers them with the graph.
+    fn register_nodes<PS: PrecisionSettings + 'static>(
+        graph: &mut BurnGraph<PS>,
+        nodes: Vec<Node>,
+    ) {
         let mut unsupported_ops = vec![];
 
-        for node in self.0.nodes {
+        for node in nodes {
             match node.node_type {
                 NodeType::Add => graph.register(Self::add_conversion(node)),
                 NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
@@ -295,6 +337,20 @@ impl ParsedOnnxGraph {
                     graph.register(Self::layer_norm_conversion::<PS>(node))
                 }
                 NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
+                NodeType::If => graph.register(Self::if_conversion::<PS>(node)),
+                NodeType::Loop => graph.register(Self::loop_conversion::<PS>(node)),
+                NodeType::Scan => graph.register(Self::scan_conversion::<PS>(node)),
+                NodeType::LSTM => graph.register(Self::lstm_conversion::<PS>(node)),
+                NodeType::GRU => graph.register(Self::gru_conversion::<PS>(node)),
+                NodeType::RNN => graph.register(Self::rnn_conversion::<PS>(node)),
+                NodeType::QuantizeLinear => graph.register(Self::quantize_linear_conversion(node)),
+                NodeType::DequantizeLinear => {
+                    graph.register(Self::dequantize_linear_conversion(node))
+                }
+                NodeType::QLinearMatMul => graph.register(Self::qlinear_matmul_conversion(node)),
+                NodeType::QLinearConv => graph.register(Self::qlinear_conv_conversion(node)),
+                NodeType::MatMulInteger => graph.register(Self::matmul_integer_conversion(node)),
+                NodeType::ConvInteger => graph.register(Self::conv_integer_conversion(node)),
                 NodeType::BatchNormalization => {
                     graph.register(Self::batch_norm_conversion::<PS>(node))
                 }
@@ -303,6 +359,7 @@ impl ParsedOnnxGraph {
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
                 NodeType::Gather => graph.register(Self::gather_conversion(node)),
                 NodeType::GatherElements => graph.register(Self::gather_elements_conversion(node)),
+                NodeType::GridSample => graph.register(Self::grid_sample_conversion(node)),
                 NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
@@ -330,6 +387,7 @@ impl ParsedOnnxGraph {
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
                 NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::Einsum => graph.register(Self::einsum_conversion(node)),
                 NodeType::GlobalAveragePool => {
                     graph.register(Self::global_avg_pool_conversion(node))
                 }
@@ -369,26 +427,18 @@ impl ParsedOnnxGraph {
         if !unsupported_ops.is_empty() {
             panic!("Unsupported ops: {:?}", unsupported_ops);
         }
+    }
 
-        // Get input and output names
-        let input_names = self
-            .0
-            .inputs
-            .iter()
-            .map(|input| input.name.clone())
-            .collect::<Vec<_>>();
+    /// Converts an ONNX subgraph (e.g., the body of a loop) to a Burn graph.
+    fn subgraph_conversion<PS: PrecisionSettings + 'static>(graph: OnnxGraph) -> BurnGraph<PS> {
+        let mut burn_graph = BurnGraph::<PS>::default();
+        let input_types = graph.inputs.iter().map(Type::from).collect();
+        let output_types = graph.outputs.iter().map(Type::from).collect();
 
-        let output_names = self
-            .0
-            .outputs
-            .iter()
-            .map(|output| output.name.clone())
-            .collect::<Vec<_>>();
+        Self::register_nodes(&mut burn_graph, graph.nodes);
+        burn_graph.register_subgraph_input_output(input_types, output_types);
 
-        // Register inputs and outputs with the graph
-        graph.register_input_output(input_names, output_names);
-
-        graph
+        burn_graph
     }
 
     fn constant_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode {
@@ -418,7 +468,10 @@ impl ParsedOnnxGraph {
                                 tensor.shape.unwrap(),
                             )
                         }
-                        ElementType::Int32 | ElementType::Int64 => serialize_data::<PS::IntElem>(
+                        ElementType::Int8
+                        | ElementType::Uint8
+                        | ElementType::Int32
+                        | ElementType::Int64 => serialize_data::<PS::IntElem>(
                             attr.value.unwrap(),
                             tensor.shape.unwrap(),
                         ),
@@ -722,6 +775,16 @@ impl ParsedOnnxGraph {
         ResizeNode::new(name, input, output, mode, scales, sizes)
     }
 
+    fn grid_sample_conversion(node: Node) -> GridSampleNode {
+        let name = &node.name;
+        let input = TensorType::from(&node.inputs[0]);
+        let grid = TensorType::from(&node.inputs[1]);
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = grid_sample_config(&node);
+
+        GridSampleNode::new(name, input, grid, output, config)
+    }
+
     fn min_conversion(node: Node) -> BinaryNode {
         let lhs = Type::from(node.inputs.first().unwrap());
         let rhs = Type::from(node.inputs.get(1).unwrap());
@@ -909,6 +972,14 @@ impl ParsedOnnxGraph {
         ConcatNode::new(inputs, output, dim)
     }
 
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let equation = einsum_config(&node);
+
+        EinsumNode::new(inputs, output, equation)
+    }
+
     fn linear_conversion<PS: PrecisionSettings>(node: Node) -> LinearNode {
         let name = &node.name;
         let input = TensorType::from(node.inputs.first().unwrap());
@@ -922,6 +993,138 @@ impl ParsedOnnxGraph {
         LinearNode::new(name, input, output, weight, bias, config)
     }
 
+    fn if_conversion<PS: PrecisionSettings + 'static>(mut node: Node) -> IfNode<PS> {
+        let then_branch = node.attrs.remove("then_branch").unwrap().into_graph();
+        let else_branch = node.attrs.remove("else_branch").unwrap().into_graph();
+        let condition = Type::from(node.inputs.first().unwrap());
+        // The values of the enclosing graph used by the branches follow the condition
+        let captures = node.inputs.iter().skip(1).map(Type::from).collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+
+        IfNode::new(
+            condition,
+            Self::subgraph_conversion(then_branch),
+            Self::subgraph_conversion(else_branch),
+            captures,
+            outputs,
+        )
+    }
+
+    fn loop_conversion<PS: PrecisionSettings + 'static>(mut node: Node) -> LoopNode<PS> {
+        let body = node.attrs.remove("body").unwrap().into_graph();
+        let max_trip_count = optional_input(&node, 0).map(Type::from);
+        let condition = optional_input(&node, 1).map(Type::from);
+        // The body inputs are the iteration number, the condition and the loop carried values,
+        // and the values of the enclosing graph used by the body follow the initial values
+        let num_carried = body.inputs.len() - 2;
+        let initial_values = node.inputs[2..2 + num_carried]
+            .iter()
+            .map(Type::from)
+            .collect();
+        let captures = node.inputs[2 + num_carried..]
+            .iter()
+            .map(Type::from)
+            .collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+
+        LoopNode::new(
+            max_trip_count,
+            condition,
+            initial_values,
+            Self::subgraph_conversion(body),
+            captures,
+            outputs,
+        )
+    }
+
+    fn scan_conversion<PS: PrecisionSettings + 'static>(mut node: Node) -> ScanNode<PS> {
+        let config = scan_config(&node);
+        let body = node.attrs.remove("body").unwrap().into_graph();
+        // The body inputs are the state values and the slices of the scan inputs, and the values
+        // of the enclosing graph used by the body follow the scan inputs
+        let num_inputs = body.inputs.len();
+        let num_states = num_inputs - config.input_axes.len();
+        let initial_states = node.inputs[..num_states].iter().map(Type::from).collect();
+        let scan_inputs = node.inputs[num_states..num_inputs]
+            .iter()
+            .map(TensorType::from)
+            .collect();
+        let captures = node.inputs[num_inputs..].iter().map(Type::from).collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+
+        ScanNode::new(
+            initial_states,
+            scan_inputs,
+            Self::subgraph_conversion(body),
+            captures,
+            outputs,
+            config,
+        )
+    }
+
+    fn lstm_conversion<PS: PrecisionSettings>(node: Node) -> LstmNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let initial_h = optional_input(&node, 5).map(TensorType::from);
+        let initial_c = optional_input(&node, 6).map(TensorType::from);
+        let output = node.outputs.first().map(TensorType::from);
+        let output_h = node.outputs.get(1).map(TensorType::from);
+        let output_c = node.outputs.get(2).map(TensorType::from);
+        let config = lstm_config(&node);
+
+        // ONNX gates are ordered as input, output, forget and cell
+        let weights = extract_gate_weights::<PS::FloatElem>(&node, &[0, 2, 1, 3])
+            .into_iter()
+            .map(|gates| gates.try_into().unwrap())
+            .collect();
+
+        LstmNode::new(
+            name, input, initial_h, initial_c, output, output_h, output_c, weights, config,
+        )
+    }
+
+    fn gru_conversion<PS: PrecisionSettings>(node: Node) -> GruNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let initial_h = optional_input(&node, 5).map(TensorType::from);
+        let output = node.outputs.first().map(TensorType::from);
+        let output_h = node.outputs.get(1).map(TensorType::from);
+        let (config, reset_after) = gru_config(&node);
+
+        // ONNX gates are ordered as update, reset and new (hidden)
+        let weights = extract_gate_weights::<PS::FloatElem>(&node, &[0, 1, 2])
+            .into_iter()
+            .map(|gates| gates.try_into().unwrap())
+            .collect();
+
+        GruNode::new(
+            name,
+            input,
+            initial_h,
+            output,
+            output_h,
+            weights,
+            config,
+            reset_after,
+        )
+    }
+
+    fn rnn_conversion<PS: PrecisionSettings>(node: Node) -> RnnNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let initial_h = optional_input(&node, 5).map(TensorType::from);
+        let output = node.outputs.first().map(TensorType::from);
+        let output_h = node.outputs.get(1).map(TensorType::from);
+        let config = rnn_config(&node);
+
+        let weights = extract_gate_weights::<PS::FloatElem>(&node, &[0])
+            .into_iter()
+            .map(|mut gates| gates.remove(0))
+            .collect();
+
+        RnnNode::new(name, input, initial_h, output, output_h, weights, config)
+    }
+
     fn dropout_conversion(node: Node) -> DropoutNode {
         let name = &node.name;
         let input = TensorType::from(node.inputs.first().unwrap());
@@ -1022,6 +1225,149 @@ impl ParsedOnnxGraph {
         Conv3dNode::new(name, input, output, weight, bias, config)
     }
 
+    fn quantize_linear_conversion(node: Node) -> QuantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = quantize_linear_config(&node);
+
+        QuantizeLinearNode::new(input, output, config)
+    }
+
+    fn dequantize_linear_conversion(node: Node) -> DequantizeLinearNode {
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        // Constant inputs are stored as quantized weights, while the quantized tensors computed
+        // by the model already carry their quantization parameters
+        let input = match node.inputs[0].value {
+            Some(_) => {
+                let config = quantize_linear_config(&node);
+                let data = extract_quantized_data(&node, 0, &config);
+                QuantizedInput::Weights(QuantizedWeights::new(&node.name, data))
+            }
+            None => QuantizedInput::Tensor(quantized_tensor(&node, 0)),
+        };
+
+        DequantizeLinearNode::new(input, output)
+    }
+
+    fn qlinear_matmul_conversion(node: Node) -> QLinearMatMulNode {
+        // The quantization parameters of the quantized tensors computed by the model are carried
+        // by the tensors, so only the parameters of constant inputs and the output are needed
+        let lhs = quantized_tensor(&node, 0);
+        let rhs = match node.inputs[3].value {
+            Some(_) => {
+                // The scales of the weights are per column
+                let rank = tensor_rank(&node.inputs[3]);
+                let elem_type = quantized_elem_type(&node, 5, tensor_elem_type(&node.inputs[3]));
+                let config = quantization_config(&node, Some(4), 5, rank - 1, &elem_type);
+                let data = extract_quantized_data(&node, 3, &config);
+                QuantizedInput::Weights(QuantizedWeights::new(&node.name, data))
+            }
+            None => QuantizedInput::Tensor(quantized_tensor(&node, 3)),
+        };
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let rank = output.dim;
+        let elem_type = quantized_elem_type(&node, 7, ElementType::Uint8);
+        let config = quantization_config(&node, Some(6), 7, rank - 1, &elem_type);
+
+        QLinearMatMulNode::new(lhs, rhs, output, config)
+    }
+
+    fn qlinear_conv_conversion(node: Node) -> QLinearConvNode {
+        let name = &node.name;
+        let input = quantized_tensor(&node, 0);
+        let output = TensorType::from(node.outputs.first().unwrap());
+        if tensor_rank(&node.inputs[3]) != 4 {
+            panic!("{name}: only 2D convolutions are supported");
+        }
+
+        // The scales of the weights are per output channel
+        let elem_type = quantized_elem_type(&node, 5, tensor_elem_type(&node.inputs[3]));
+        let weights_config = quantization_config(&node, Some(4), 5, 0, &elem_type);
+        let weights = extract_quantized_data(&node, 3, &weights_config);
+
+        // The int32 bias is quantized with the product of the input and weights scales
+        let bias = optional_input(&node, 8).map(|bias| {
+            let values = bias
+                .value
+                .clone()
+                .unwrap_or_else(|| panic!("{name}: the bias must be constant"))
+                .into_f32s();
+            let input_scale = match node.inputs[1].value.clone() {
+                Some(Data::Float32(scale)) => scale,
+                Some(data) => data.into_f32s()[0],
+                None => panic!("{name}: the quantization scale must be constant"),
+            };
+            let scales = &weights_config.scale;
+            let values = values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| value * input_scale * scales[i % scales.len()])
+                .collect::<Vec<_>>();
+            let shape = vec![values.len()];
+
+            TensorData::new(values, shape)
+        });
+
+        // Same configuration as the convolution of the dequantized weights and bias
+        let mut conv = node.clone();
+        conv.inputs = [
+            Some(&node.inputs[0]),
+            Some(&node.inputs[3]),
+            optional_input(&node, 8),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+        let config = conv2d_config(&conv);
+
+        let elem_type = quantized_elem_type(&node, 7, ElementType::Uint8);
+        let output_config = quantization_config(&node, Some(6), 7, 1, &elem_type);
+
+        QLinearConvNode::new(name, input, output, weights, bias, config, output_config)
+    }
+
+    fn matmul_integer_conversion(node: Node) -> MatMulIntegerNode {
+        let name = &node.name;
+        let lhs = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let lhs_zero_point = input_zero_point(&node, 2);
+
+        // The weights are quantized with a unit scale and their zero point, per column
+        if node.inputs[1].value.is_none() {
+            panic!("{name}: only constant weights are supported");
+        }
+        let rank = tensor_rank(&node.inputs[1]);
+        let elem_type = quantized_elem_type(&node, 3, tensor_elem_type(&node.inputs[1]));
+        let config = quantization_config(&node, None, 3, rank - 1, &elem_type);
+        let rhs = QuantizedWeights::new(name, extract_quantized_data(&node, 1, &config));
+
+        MatMulIntegerNode::new(lhs, lhs_zero_point, rhs, output)
+    }
+
+    fn conv_integer_conversion(node: Node) -> ConvIntegerNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let input_zero_point = input_zero_point(&node, 2);
+        if tensor_rank(&node.inputs[1]) != 4 {
+            panic!("{name}: only 2D convolutions are supported");
+        }
+
+        // The weights are quantized with a unit scale and their zero point, per output channel
+        let elem_type = quantized_elem_type(&node, 3, tensor_elem_type(&node.inputs[1]));
+        let weights_config = quantization_config(&node, None, 3, 0, &elem_type);
+        let weights = extract_quantized_data(&node, 1, &weights_config);
+
+        // Same configuration as the convolution of the dequantized weights, without bias
+        let mut conv = node.clone();
+        conv.inputs.truncate(2);
+        let config = conv2d_config(&conv);
+
+        ConvIntegerNode::new(name, input, input_zero_point, output, weights, config)
+    }
+
     fn max_pool1d_conversion(node: Node) -> MaxPool1dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1331,6 +1677,150 @@ fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option
     }
 }
 
+/// Returns the optional input of the node at the given index, which is absent when skipped with an
+/// empty name.
+fn optional_input(node: &Node, index: usize) -> Option<&OnnxArgument> {
+    node.inputs
+        .get(index)
+        .filter(|input| !input.name.is_empty())
+}
+
+/// Returns the quantized tensor input of the node at the given index, which must be computed by
+/// the model (e.g., by QuantizeLinear).
+fn quantized_tensor(node: &Node, index: usize) -> TensorType {
+    let tensor = TensorType::from(&node.inputs[index]);
+    if tensor.kind != TensorKind::Float {
+        panic!(
+            "{}: only quantized tensors and constant inputs are supported",
+            node.name
+        );
+    }
+    tensor
+}
+
+/// Returns the constant scalar zero point of an integer input of the node, which defaults to zero.
+fn input_zero_point(node: &Node, index: usize) -> i64 {
+    let name = &node.name;
+    optional_input(node, index).map_or(0, |input| match input.value.clone() {
+        Some(Data::Int32(zero_point)) => zero_point as i64,
+        Some(data) => match data.into_i64s()[..] {
+            [zero_point] => zero_point,
+            _ => panic!("{name}: only a single input zero point is supported"),
+        },
+        None => panic!("{name}: the input zero point must be constant"),
+    })
+}
+
+fn tensor_rank(arg: &OnnxArgument) -> usize {
+    match &arg.ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("{}: expected a tensor", arg.name),
+    }
+}
+
+fn tensor_elem_type(arg: &OnnxArgument) -> ElementType {
+    match &arg.ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("{}: expected a tensor", arg.name),
+    }
+}
+
+/// Extract the constant 8-bit integer tensor of the node at the given index as quantized data.
+///
+/// Unsigned values are shifted to the signed range, like the zero point of the quantization.
+fn extract_quantized_data(node: &Node, index: usize, config: &QuantizationConfig) -> TensorData {
+    let input = &node.inputs[index];
+    let (ArgType::Tensor(tensor), Some(value)) = (&input.ty, &input.value) else {
+        panic!(
+            "{}: the quantized weights must be a constant tensor",
+            node.name
+        );
+    };
+
+    let shift = match tensor.elem_type {
+        ElementType::Uint8 => 128,
+        _ => 0,
+    };
+    let values = value
+        .clone()
+        .into_i32s()
+        .into_iter()
+        .map(|value| (value - shift) as i8)
+        .collect();
+
+    config.quantized_data(values, tensor.shape.clone().unwrap())
+}
+
+/// Extract the weights of each gate of a recurrent node (LSTM, GRU or RNN), for each direction.
+///
+/// The weights of all gates are packed in the ONNX tensors `W` of shape
+/// `[num_directions, num_gates * hidden_size, input_size]`, `R` of shape
+/// `[num_directions, num_gates * hidden_size, hidden_size]` and the optional `B` of shape
+/// `[num_directions, 2 * num_gates * hidden_size]`, with the input biases followed by the
+/// recurrence biases.
+///
+/// # Arguments
+///
+/// * `node` - The recurrent node.
+/// * `gate_order` - The index in the ONNX packed tensors of each gate of the Burn module.
+fn extract_gate_weights<E: Element>(node: &Node, gate_order: &[usize]) -> Vec<Vec<GateWeights>> {
+    let name = &node.name;
+    let weight = extract_data_serialize::<E>(1, node)
+        .unwrap_or_else(|| panic!("{name}: the weight tensor must be constant"));
+    let recurrence = extract_data_serialize::<E>(2, node)
+        .unwrap_or_else(|| panic!("{name}: the recurrence weight tensor must be constant"));
+    let bias = optional_input(node, 3).map(|_| {
+        extract_data_serialize::<E>(3, node)
+            .unwrap_or_else(|| panic!("{name}: the bias tensor must be constant"))
+    });
+
+    let [num_directions, num_gates_hidden, d_input] = weight.shape[..] else {
+        panic!("{name}: the weight tensor must have 3 dimensions");
+    };
+    let d_hidden = recurrence.shape[2];
+    let num_gates = num_gates_hidden / d_hidden;
+
+    let weight = weight.to_vec::<E>().unwrap();
+    let recurrence = recurrence.to_vec::<E>().unwrap();
+    let bias = bias.map(|bias| bias.to_vec::<E>().unwrap());
+
+    // Transpose the rows of the gate to the [d_input, d_output] layout of the linear layers
+    let gate_weight = |values: &[E], gate: usize, cols: usize| {
+        let rows = &values[gate * d_hidden * cols..(gate + 1) * d_hidden * cols];
+        let data = (0..cols)
+            .flat_map(|col| (0..d_hidden).map(move |row| rows[row * cols + col]))
+            .collect::<Vec<_>>();
+
+        TensorData::new(data, [cols, d_hidden])
+    };
+    let gate_bias = |values: &[E], gate: usize| {
+        TensorData::new(
+            values[gate * d_hidden..(gate + 1) * d_hidden].to_vec(),
+            [d_hidden],
+        )
+    };
+
+    (0..num_directions)
+        .map(|direction| {
+            gate_order
+                .iter()
+                .map(|&gate| {
+                    let index = direction * num_gates + gate;
+                    let bias_index = direction * 2 * num_gates + gate;
+
+                    GateWeights::new(
+                        gate_weight(&weight, index, d_input),
+                        bias.as_ref().map(|bias| gate_bias(bias, bias_index)),
+                        gate_weight(&recurrence, index, d_hidden),
+                        bias.as_ref()
+                            .map(|bias| gate_bias(bias, bias_index + num_gates)),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
 /// Convert data to `TensorData`.
 fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> TensorData {
     match data {
@@ -1354,7 +1844,8 @@ impl From<&OnnxArgument> for TensorType {
                 ..
             }) => TensorType::new_float_with_shape(arg.name.clone(), *dim, shape.clone()),
             ArgType::Tensor(OnnxTensorType {
-                elem_type: ElementType::Int32 | ElementType::Int64,
+                elem_type:
+                    ElementType::Int8 | ElementType::Uint8 | ElementType::Int32 | ElementType::Int64,
                 dim,
                 shape,
                 ..
@@ -1401,7 +1892,7 @@ impl From<&ElementType> for ScalarKind {
         match elem_type {
             ElementType::Float32 => ScalarKind::Float32,
             ElementType::Float64 => ScalarKind::Float64,
-            ElementType::Int32 => ScalarKind::Int32,
+            ElementType::Int8 | ElementType::Uint8 | ElementType::Int32 => ScalarKind::Int32,
             ElementType::Int64 => ScalarKind::Int64,
             ElementType::Bool => ScalarKind::Bool,
             ElementType::String => panic!("String tensor unsupported"),
@@ -1415,6 +1906,8 @@ impl From<ElementType> for TensorKind {
         match elem_type {
             ElementType::Float32 => TensorKind::Float,
             ElementType::Float64 => TensorKind::Float,
+            ElementType::Int8 => TensorKind::Int,
+            ElementType::Uint8 => TensorKind::Int,
             ElementType::Int32 => TensorKind::Int,
             ElementType::Int64 => TensorKind::Int,
             ElementType::Bool => TensorKind::Bool,
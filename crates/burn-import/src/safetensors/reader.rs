@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{
+    error::Error,
+    recorder::AdapterType,
+    writer::{BURN_FORMAT, FORMAT_KEY},
+};
+use crate::common::{
+    adapter::PyTorchAdapter,
+    candle::{print_debug_info, CandleTensor},
+};
+
+use burn::{
+    record::{
+        serde::{
+            adapter::DefaultAdapter,
+            data::{remap, unflatten},
+            de::Deserializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::backend::Backend,
+};
+
+use candle_core::{safetensors, Device};
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+/// Deserializes a safetensors file.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `top_level_key` - An optional top-level key to only load the tensors nested under it.
+/// * `adapter_type` - The adapter used to convert the tensors to the Burn module layout. It is
+///                    ignored for the files saved from Burn, which already follow that layout.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    top_level_key: Option<&str>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let buffer = std::fs::read(path)?;
+
+    // Files saved from Burn are marked in the metadata and already follow the module layout
+    let (_, metadata) = ::safetensors::SafeTensors::read_metadata(&buffer)?;
+    let adapter_type = match metadata.metadata() {
+        Some(metadata) if metadata.get(FORMAT_KEY).map(String::as_str) == Some(BURN_FORMAT) => {
+            AdapterType::NoAdapter
+        }
+        _ => adapter_type,
+    };
+
+    // Read the safetensors file and return a vector of Candle tensors
+    let tensors: HashMap<String, CandleTensor> = safetensors::load_buffer(&buffer, &Device::Cpu)?
+        .into_iter()
+        .filter_map(|(key, tensor)| {
+            // Safetensors files are flat, so the top-level key is a prefix of the keys
+            let key = match top_level_key {
+                Some(top_level_key) => key
+                    .strip_prefix(top_level_key)
+                    .and_then(|key| key.strip_prefix('.'))?
+                    .to_string(),
+                None => key,
+            };
+            Some((key, CandleTensor(tensor)))
+        })
+        .collect();
+
+    // Remap the keys (replace the keys in the map with the new keys)
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        print_debug_info(&tensors, remapped_keys);
+    }
+
+    // Convert the vector of Candle tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Deserialize the nested value into a record type with the selected adapter
+    let value = match adapter_type {
+        AdapterType::PyTorch => D::deserialize(Deserializer::<PyTorchAdapter<PS, B>>::new(
+            nested_value,
+            true,
+        ))?,
+        AdapterType::NoAdapter => {
+            D::deserialize(Deserializer::<DefaultAdapter>::new(nested_value, true))?
+        }
+    };
+    Ok(value)
+}
@@ -0,0 +1,456 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::{backend::Backend, DType},
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader::from_file, writer::to_file};
+
+/// A recorder that loads and saves safetensors files (`.safetensors`) into and from Burn modules.
+///
+/// LoadArgs can be used to remap keys, select a top-level key or the adapter.
+/// See [LoadArgs](struct.LoadArgs.html) for more information.
+///
+/// SaveArgs can be used to select the data type of the saved float tensors.
+/// See [SaveArgs](struct.SaveArgs.html) for more information.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = SaveArgs;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        args: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        to_file(item, &args.file, args.float_dtype)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        let item = from_file::<PS, I, B>(
+            &args.file,
+            args.key_remap,
+            args.top_level_key.as_deref(),
+            args.adapter_type,
+            args.debug,
+        )?;
+        Ok(item)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = from_file::<PS, R::Item<Self::Settings>, B>(
+            &args.file,
+            args.key_remap,
+            args.top_level_key.as_deref(), // Convert Option<String> to Option<&str>
+            args.adapter_type,
+            args.debug,
+        )?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// The adapter used to convert the tensors of the file to the layout of the Burn modules.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AdapterType {
+    /// The tensors follow the PyTorch module layout (e.g., Hugging Face checkpoints), so the
+    /// linear weights are transposed and the normalization parameters are renamed.
+    ///
+    /// The files saved with the [SafetensorsFileRecorder] are marked as such in their metadata and
+    /// are always loaded without adapter.
+    #[default]
+    PyTorch,
+
+    /// The tensors already follow the Burn module layout (e.g., files saved from Burn without the
+    /// [SafetensorsFileRecorder] format marker).
+    NoAdapter,
+}
+
+/// Arguments for loading a safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///                See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///                for more information.
+///
+/// # Notes
+///
+/// Use [Netron](https://github.com/lutzroeder/netron) to inspect the keys of the safetensors file
+/// (.safetensors extension).
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::{AdapterType, LoadArgs, SafetensorsFileRecorder};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let args = LoadArgs::new("model.safetensors".into())
+///    .with_key_remap("conv\\.(.*)", "$1") // Remove "conv" prefix, e.g. "conv.conv1" -> "conv1"
+///    .with_adapter_type(AdapterType::PyTorch);
+///
+/// let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+///   .load(args, &device)
+///   .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// Top-level key to load the tensors from.
+    /// Only the tensors with a key prefixed by the top-level key are loaded, without the prefix.
+    pub top_level_key: Option<String>,
+
+    /// The adapter used to convert the tensors to the Burn module layout.
+    pub adapter_type: AdapterType,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            top_level_key: None,
+            adapter_type: AdapterType::default(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the top-level key to load the tensors from (e.g., `model` for `model.linear.weight`).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The top-level key to load the tensors from.
+    pub fn with_top_level_key(mut self, key: &str) -> Self {
+        self.top_level_key = Some(key.into());
+        self
+    }
+
+    /// Sets the adapter used to convert the tensors to the Burn module layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `adapter_type` - The adapter type.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+/// Arguments for saving a safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to save.
+/// * `float_dtype` - The data type of the saved float tensors. By default, the float tensors are
+///                   saved with the precision of the recorder settings (e.g., `F16` with
+///                   [HalfPrecisionSettings](burn::record::HalfPrecisionSettings)).
+#[derive(Debug, Clone)]
+pub struct SaveArgs {
+    /// The path to the file to save.
+    pub file: PathBuf,
+
+    /// The data type of the saved float tensors.
+    pub float_dtype: Option<DType>,
+}
+
+impl SaveArgs {
+    /// Creates a new `SaveArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to save.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            float_dtype: None,
+        }
+    }
+
+    /// Sets the data type of the saved float tensors (e.g., `DType::BF16`).
+    ///
+    /// # Panics
+    ///
+    /// If the data type is not a float type.
+    pub fn with_float_dtype(mut self, dtype: DType) -> Self {
+        assert!(
+            matches!(dtype, DType::F64 | DType::F32 | DType::F16 | DType::BF16),
+            "Expected a float data type, got {dtype:?}"
+        );
+        self.float_dtype = Some(dtype);
+        self
+    }
+}
+
+impl From<PathBuf> for SaveArgs {
+    fn from(val: PathBuf) -> Self {
+        SaveArgs::new(val)
+    }
+}
+
+impl From<String> for SaveArgs {
+    fn from(val: String) -> Self {
+        SaveArgs::new(val.into())
+    }
+}
+
+impl From<&str> for SaveArgs {
+    fn from(val: &str) -> Self {
+        SaveArgs::new(val.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::{
+        module::Module,
+        nn::{Linear, LinearConfig, LinearRecord},
+        record::{FullPrecisionSettings, HalfPrecisionSettings},
+        tensor::Tensor,
+    };
+    use burn_ndarray::NdArray;
+
+    type TestBackend = NdArray<f32>;
+
+    fn file_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("burn_import_safetensors_{name}.safetensors"))
+    }
+
+    fn load_dtype(file: &std::path::Path, key: &str) -> candle_core::DType {
+        let tensors = candle_core::safetensors::load(file, &candle_core::Device::Cpu).unwrap();
+        tensors[key].dtype()
+    }
+
+    #[test]
+    fn should_save_and_load_module() {
+        let device = Default::default();
+        let file = file_path("full");
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            linear.clone().into_record(),
+            file.clone().into(),
+        )
+        .unwrap();
+        let args = LoadArgs::new(file.clone()).with_adapter_type(AdapterType::NoAdapter);
+        let record = Recorder::<TestBackend>::load(&recorder, args, &device).unwrap();
+        let loaded: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device).load_record(record);
+
+        assert_eq!(load_dtype(&file, "weight"), candle_core::DType::F32);
+        linear
+            .weight
+            .to_data()
+            .assert_eq(&loaded.weight.to_data(), true);
+        linear
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_eq(&loaded.bias.unwrap().to_data(), true);
+    }
+
+    #[test]
+    fn should_save_and_load_module_with_default_args() {
+        let device = Default::default();
+        let file = file_path("default_args");
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            linear.clone().into_record(),
+            file.clone().into(),
+        )
+        .unwrap();
+        let record = Recorder::<TestBackend>::load(&recorder, file.into(), &device).unwrap();
+        let loaded: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device).load_record(record);
+
+        linear
+            .weight
+            .to_data()
+            .assert_eq(&loaded.weight.to_data(), true);
+        linear
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_eq(&loaded.bias.unwrap().to_data(), true);
+    }
+
+    #[test]
+    fn should_load_saved_item() {
+        let device = Default::default();
+        let file = file_path("item");
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            linear.clone().into_record(),
+            file.clone().into(),
+        )
+        .unwrap();
+        let item: <LinearRecord<TestBackend> as Record<TestBackend>>::Item<FullPrecisionSettings> =
+            Recorder::<TestBackend>::load_item(&recorder, file.into()).unwrap();
+        let record = LinearRecord::<TestBackend>::from_item(item, &device);
+
+        linear
+            .weight
+            .to_data()
+            .assert_eq(&record.weight.to_data(), true);
+    }
+
+    #[test]
+    fn should_save_with_recorder_precision() {
+        let device = Default::default();
+        let file = file_path("half");
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let recorder = SafetensorsFileRecorder::<HalfPrecisionSettings>::default();
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            linear.clone().into_record(),
+            file.clone().into(),
+        )
+        .unwrap();
+        let args = LoadArgs::new(file.clone()).with_adapter_type(AdapterType::NoAdapter);
+        let record = Recorder::<TestBackend>::load(&recorder, args, &device).unwrap();
+        let loaded: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device).load_record(record);
+
+        assert_eq!(load_dtype(&file, "weight"), candle_core::DType::F16);
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&loaded.weight.to_data(), 2);
+    }
+
+    #[test]
+    fn should_save_with_float_dtype() {
+        let device = Default::default();
+        let file = file_path("bf16");
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            linear.clone().into_record(),
+            SaveArgs::new(file.clone()).with_float_dtype(DType::BF16),
+        )
+        .unwrap();
+        let args = LoadArgs::new(file.clone()).with_adapter_type(AdapterType::NoAdapter);
+        let record = Recorder::<TestBackend>::load(&recorder, args, &device).unwrap();
+        let loaded: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device).load_record(record);
+
+        assert_eq!(load_dtype(&file, "weight"), candle_core::DType::BF16);
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&loaded.weight.to_data(), 1);
+    }
+
+    #[test]
+    fn should_load_pytorch_layout_with_top_level_key_and_key_remap() {
+        let device = Default::default();
+        let file = file_path("pytorch");
+
+        // PyTorch linear weights are stored as [d_output, d_input]
+        let weight = candle_core::Tensor::new(
+            &[[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            &candle_core::Device::Cpu,
+        )
+        .unwrap();
+        let bias = candle_core::Tensor::new(&[7.0f32, 8.0], &candle_core::Device::Cpu).unwrap();
+        let tensors = std::collections::HashMap::from([
+            ("model.fc.weight".to_string(), weight),
+            ("model.fc.bias".to_string(), bias),
+        ]);
+        candle_core::safetensors::save(&tensors, &file).unwrap();
+
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+        let args = LoadArgs::new(file)
+            .with_top_level_key("model")
+            .with_key_remap("fc\\.(.*)", "$1");
+        let record = Recorder::<TestBackend>::load(&recorder, args, &device).unwrap();
+        let linear: Linear<TestBackend> = LinearConfig::new(3, 2).init(&device).load_record(record);
+
+        let output = linear.forward(Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 1.0, 1.0]],
+            &device,
+        ));
+
+        output
+            .to_data()
+            .assert_eq(&burn::tensor::TensorData::from([[13.0f32, 23.0]]), false);
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::error::Error;
+
+use burn::{
+    record::serde::{
+        adapter::DefaultAdapter, data::NestedValue, de::Deserializer, ser::Serializer,
+    },
+    tensor::{DType, TensorData},
+};
+
+use candle_core::Device;
+use serde::{Deserialize, Serialize};
+
+/// Metadata key of the format marker added to the saved files.
+pub(crate) const FORMAT_KEY: &str = "format";
+
+/// Format marker of the files saved in the Burn module layout, which are loaded without adapter.
+pub(crate) const BURN_FORMAT: &str = "burn";
+
+/// Serializes an item to a safetensors file.
+///
+/// Each parameter of the item is saved as a tensor, with the key corresponding to its path in
+/// the module (e.g., `linear.weight`). The float tensors are saved with the `float_dtype` if
+/// provided, otherwise with their recorded data type. The file metadata marks the Burn module
+/// layout, so the tensors are loaded back without adapter.
+pub fn to_file<I: Serialize>(
+    item: I,
+    path: &Path,
+    float_dtype: Option<DType>,
+) -> Result<(), Error> {
+    let value = item.serialize(Serializer::new())?;
+
+    // Records are saved along with their metadata, which cannot be stored as tensors
+    let value = match value {
+        NestedValue::Map(mut map) if map.contains_key("item") => map.remove("item").unwrap(),
+        value => value,
+    };
+
+    let mut tensors = HashMap::new();
+    flatten(value, String::new(), float_dtype, &mut tensors)?;
+
+    let metadata = HashMap::from([(FORMAT_KEY.to_string(), BURN_FORMAT.to_string())]);
+    ::safetensors::serialize_to_file(&tensors, &Some(metadata), path)?;
+    Ok(())
+}
+
+/// Collects the tensors of the nested value with their flattened key.
+fn flatten(
+    value: NestedValue,
+    key: String,
+    float_dtype: Option<DType>,
+    tensors: &mut HashMap<String, candle_core::Tensor>,
+) -> Result<(), Error> {
+    let join = |name: &str| match key.is_empty() {
+        true => name.to_string(),
+        false => format!("{key}.{name}"),
+    };
+
+    match value {
+        NestedValue::Map(mut map) => {
+            // Parameters are serialized as a map with their id and tensor data
+            if map.contains_key("id") && map.contains_key("param") {
+                let param = map.remove("param").unwrap();
+                let data =
+                    TensorData::deserialize(Deserializer::<DefaultAdapter>::new(param, false))?;
+                tensors.insert(key, to_candle_tensor(data, float_dtype)?);
+                return Ok(());
+            }
+
+            for (name, value) in map {
+                flatten(value, join(&name), float_dtype, tensors)?;
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (i, value) in values.into_iter().enumerate() {
+                flatten(value, join(&i.to_string()), float_dtype, tensors)?;
+            }
+        }
+        // Other values (e.g., constants) are not saved
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Converts the tensor data to a candle tensor, with a data type supported by safetensors.
+fn to_candle_tensor(
+    data: TensorData,
+    float_dtype: Option<DType>,
+) -> Result<candle_core::Tensor, Error> {
+    let data = match (data.dtype, float_dtype) {
+        (DType::F64 | DType::F32 | DType::F16 | DType::BF16, Some(dtype)) => {
+            data.convert_dtype(dtype)
+        }
+        (DType::I32 | DType::I16 | DType::I8 | DType::U64 | DType::U16, _) => data.convert::<i64>(),
+        (DType::Bool, _) => {
+            let values = data.iter::<bool>().map(u8::from).collect::<Vec<_>>();
+            TensorData::new(values, data.shape)
+        }
+        (DType::QFloat(_), _) => {
+            return Err(Error::Other(
+                "Quantized tensors cannot be saved to safetensors".into(),
+            ))
+        }
+        _ => data,
+    };
+
+    let dtype = match data.dtype {
+        DType::F64 => candle_core::DType::F64,
+        DType::F32 => candle_core::DType::F32,
+        DType::F16 => candle_core::DType::F16,
+        DType::BF16 => candle_core::DType::BF16,
+        DType::I64 => candle_core::DType::I64,
+        DType::U32 => candle_core::DType::U32,
+        DType::U8 => candle_core::DType::U8,
+        dtype => {
+            return Err(Error::Other(format!(
+                "Unsupported data type {dtype:?} for safetensors"
+            )))
+        }
+    };
+
+    let tensor =
+        candle_core::Tensor::from_raw_buffer(data.as_bytes(), dtype, &data.shape, &Device::Cpu)?;
+    Ok(tensor)
+}
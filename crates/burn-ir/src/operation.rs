@@ -8,7 +8,8 @@ use alloc::{string::String, vec, vec::Vec};
 
 use burn_tensor::{
     ops::{
-        ConvOptions, ConvTransposeOptions, DeformConvOptions, InterpolateMode, InterpolateOptions,
+        AttentionOptions, ConvOptions, ConvTransposeOptions, DeformConvOptions, GridSampleOptions,
+        GridSamplePaddingMode, InterpolateMode, InterpolateOptions,
     },
     quantization::QuantizationScheme,
     DType, Distribution, Element,
@@ -118,6 +119,8 @@ pub enum FloatOperationIr {
     Random(RandomOpIr),
     /// Operation corresponding to [recip](burn_tensor::ops::FloatTensorOps::float_recip).
     Recip(UnaryOpIr),
+    /// Operation corresponding to [fft](burn_tensor::ops::FloatTensorOps::float_fft).
+    Fft(FftOpIr),
     /// Operation corresponding to [quantize](burn_tensor::ops::QTensorOps::quantize).
     Quantize(QuantizeOpIr),
     /// Operation corresponding to [dequantize](burn_tensor::ops::QTensorOps::dequantize).
@@ -191,6 +194,14 @@ pub enum ModuleOperationIr {
     Interpolate(InterpolateOpIr),
     /// Operation corresponding to [interpolate backward](burn_tensor::ops::ModuleOps::interpolate_backward).
     InterpolateBackward(InterpolateBackwardOpIr),
+    /// Operation corresponding to [grid sample 2d](burn_tensor::ops::ModuleOps::grid_sample_2d).
+    GridSample2d(GridSample2dOpIr),
+    /// Operation corresponding to [grid sample 2d backward](burn_tensor::ops::ModuleOps::grid_sample_2d_backward).
+    GridSample2dBackward(GridSample2dBackwardOpIr),
+    /// Operation corresponding to [scaled dot-product attention](burn_tensor::ops::ModuleOps::scaled_dot_product_attention).
+    ScaledDotProductAttention(Box<ScaledDotProductAttentionOpIr>),
+    /// Operation corresponding to [scaled dot-product attention backward](burn_tensor::ops::ModuleOps::scaled_dot_product_attention_backward).
+    ScaledDotProductAttentionBackward(Box<ScaledDotProductAttentionBackwardOpIr>),
 }
 
 /// Basic operations that can be done on any tensor type.
@@ -413,6 +424,30 @@ pub enum NumericOperationIr<E> {
     /// Int => [prod dim](burn_tensor::ops::IntTensorOps::int_prod_dim).
     ProdDim(ScalarOpIr<usize>),
 
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumsum](burn_tensor::ops::FloatTensorOps::float_cumsum).
+    /// Int => [cumsum](burn_tensor::ops::IntTensorOps::int_cumsum).
+    CumSum(CumulativeOpIr),
+
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumprod](burn_tensor::ops::FloatTensorOps::float_cumprod).
+    /// Int => [cumprod](burn_tensor::ops::IntTensorOps::int_cumprod).
+    CumProd(CumulativeOpIr),
+
+    /// Operation corresponding to:
+    ///
+    /// Float => [cummax](burn_tensor::ops::FloatTensorOps::float_cummax).
+    /// Int => [cummax](burn_tensor::ops::IntTensorOps::int_cummax).
+    CumMax(CumulativeOpIr),
+
+    /// Operation corresponding to:
+    ///
+    /// Float => [cummin](burn_tensor::ops::FloatTensorOps::float_cummin).
+    /// Int => [cummin](burn_tensor::ops::IntTensorOps::int_cummin).
+    CumMin(CumulativeOpIr),
+
     /// Operation corresponding to:
     ///
     /// Float => [equal elem](burn_tensor::ops::FloatTensorOps::float_equal_elem).
@@ -773,6 +808,25 @@ pub struct ReduceDimWithIndicesOpIr {
     pub out_indices: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct FftOpIr {
+    pub input: TensorIr,
+    pub dim: usize,
+    pub inverse: bool,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct CumulativeOpIr {
+    pub input: TensorIr,
+    pub dim: usize,
+    pub exclusive: bool,
+    pub reverse: bool,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct EmbeddingOpIr {
@@ -1352,6 +1406,140 @@ pub struct InterpolateBackwardOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum GridSamplePaddingModeIr {
+    Zeros,
+    Border,
+    Reflection,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GridSampleOptionsIr {
+    pub mode: InterpolateModeIr,
+    pub padding_mode: GridSamplePaddingModeIr,
+    pub align_corners: bool,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GridSample2dOpIr {
+    pub x: TensorIr,
+    pub grid: TensorIr,
+    pub options: GridSampleOptionsIr,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct GridSample2dBackwardOpIr {
+    pub x: TensorIr,
+    pub grid: TensorIr,
+    pub out_grad: TensorIr,
+    pub options: GridSampleOptionsIr,
+    pub x_grad: TensorIr,
+    pub grid_grad: TensorIr,
+}
+
+impl From<GridSamplePaddingModeIr> for GridSamplePaddingMode {
+    fn from(val: GridSamplePaddingModeIr) -> Self {
+        match val {
+            GridSamplePaddingModeIr::Zeros => Self::Zeros,
+            GridSamplePaddingModeIr::Border => Self::Border,
+            GridSamplePaddingModeIr::Reflection => Self::Reflection,
+        }
+    }
+}
+
+impl From<GridSampleOptionsIr> for GridSampleOptions {
+    fn from(val: GridSampleOptionsIr) -> Self {
+        Self {
+            mode: val.mode.into(),
+            padding_mode: val.padding_mode.into(),
+            align_corners: val.align_corners,
+        }
+    }
+}
+
+impl From<GridSamplePaddingMode> for GridSamplePaddingModeIr {
+    fn from(val: GridSamplePaddingMode) -> Self {
+        match val {
+            GridSamplePaddingMode::Zeros => Self::Zeros,
+            GridSamplePaddingMode::Border => Self::Border,
+            GridSamplePaddingMode::Reflection => Self::Reflection,
+        }
+    }
+}
+
+impl From<GridSampleOptions> for GridSampleOptionsIr {
+    fn from(val: GridSampleOptions) -> Self {
+        Self {
+            mode: val.mode.into(),
+            padding_mode: val.padding_mode.into(),
+            align_corners: val.align_corners,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AttentionOptionsIr {
+    pub scale: Option<f64>,
+    pub is_causal: bool,
+    pub dropout: f64,
+    pub dropout_seed: Option<u64>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct ScaledDotProductAttentionOpIr {
+    pub query: TensorIr,
+    pub key: TensorIr,
+    pub value: TensorIr,
+    pub padding_mask: Option<TensorIr>,
+    pub attn_mask: Option<TensorIr>,
+    pub options: AttentionOptionsIr,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct ScaledDotProductAttentionBackwardOpIr {
+    pub query: TensorIr,
+    pub key: TensorIr,
+    pub value: TensorIr,
+    pub padding_mask: Option<TensorIr>,
+    pub attn_mask: Option<TensorIr>,
+    pub out_grad: TensorIr,
+    pub options: AttentionOptionsIr,
+    pub query_grad: TensorIr,
+    pub key_grad: TensorIr,
+    pub value_grad: TensorIr,
+}
+
+impl From<AttentionOptionsIr> for AttentionOptions {
+    fn from(val: AttentionOptionsIr) -> Self {
+        Self {
+            scale: val.scale,
+            is_causal: val.is_causal,
+            dropout: val.dropout,
+            dropout_seed: val.dropout_seed,
+        }
+    }
+}
+
+impl From<AttentionOptions> for AttentionOptionsIr {
+    fn from(val: AttentionOptions) -> Self {
+        Self {
+            scale: val.scale,
+            is_causal: val.is_causal,
+            dropout: val.dropout,
+            dropout_seed: val.dropout_seed,
+        }
+    }
+}
+
 impl OperationIr {
     /// Cleanup the remaining tensor handles that have not been used.
     pub fn nodes(&self) -> Vec<&TensorIr> {
@@ -1522,6 +1710,18 @@ impl<E: Element> NumericOperationIr<E> {
             NumericOperationIr::ProdDim(repr) => {
                 vec![&repr.lhs, &repr.out]
             }
+            NumericOperationIr::CumSum(repr) => {
+                vec![&repr.input, &repr.out]
+            }
+            NumericOperationIr::CumProd(repr) => {
+                vec![&repr.input, &repr.out]
+            }
+            NumericOperationIr::CumMax(repr) => {
+                vec![&repr.input, &repr.out]
+            }
+            NumericOperationIr::CumMin(repr) => {
+                vec![&repr.input, &repr.out]
+            }
             NumericOperationIr::Max(repr) => {
                 vec![&repr.input, &repr.out]
             }
@@ -1562,6 +1762,7 @@ impl FloatOperationIr {
             FloatOperationIr::Log1p(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Erf(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Recip(repr) => vec![&repr.input, &repr.out],
+            FloatOperationIr::Fft(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::PowfScalar(repr) => vec![&repr.lhs, &repr.out],
             FloatOperationIr::Sqrt(repr) => vec![&repr.input, &repr.out],
             FloatOperationIr::Cos(repr) => vec![&repr.input, &repr.out],
@@ -1749,6 +1950,37 @@ impl ModuleOperationIr {
             ModuleOperationIr::InterpolateBackward(repr) => {
                 vec![&repr.x, &repr.out, &repr.grad]
             }
+            ModuleOperationIr::GridSample2d(repr) => {
+                vec![&repr.x, &repr.grid, &repr.out]
+            }
+            ModuleOperationIr::GridSample2dBackward(repr) => {
+                vec![
+                    &repr.x,
+                    &repr.grid,
+                    &repr.out_grad,
+                    &repr.x_grad,
+                    &repr.grid_grad,
+                ]
+            }
+            ModuleOperationIr::ScaledDotProductAttention(repr) => {
+                let mut nodes = vec![&repr.query, &repr.key, &repr.value];
+                nodes.extend(repr.padding_mask.iter());
+                nodes.extend(repr.attn_mask.iter());
+                nodes.push(&repr.out);
+                nodes
+            }
+            ModuleOperationIr::ScaledDotProductAttentionBackward(repr) => {
+                let mut nodes = vec![&repr.query, &repr.key, &repr.value];
+                nodes.extend(repr.padding_mask.iter());
+                nodes.extend(repr.attn_mask.iter());
+                nodes.extend([
+                    &repr.out_grad,
+                    &repr.query_grad,
+                    &repr.key_grad,
+                    &repr.value_grad,
+                ]);
+                nodes
+            }
         }
     }
 }
@@ -1778,6 +2010,13 @@ impl core::hash::Hash for RandomOpIr {
     }
 }
 
+impl core::hash::Hash for AttentionOptionsIr {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.scale.is_some().hash(state);
+        self.is_causal.hash(state);
+    }
+}
+
 impl<E> core::hash::Hash for ScalarOpIr<E> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.lhs.hash(state);
@@ -1829,6 +2068,10 @@ impl<E> core::hash::Hash for NumericOperationIr<E> {
             NumericOperationIr::SumDim(repr) => repr.hash(state),
             NumericOperationIr::Prod(repr) => repr.hash(state),
             NumericOperationIr::ProdDim(repr) => repr.hash(state),
+            NumericOperationIr::CumSum(repr) => repr.hash(state),
+            NumericOperationIr::CumProd(repr) => repr.hash(state),
+            NumericOperationIr::CumMax(repr) => repr.hash(state),
+            NumericOperationIr::CumMin(repr) => repr.hash(state),
             NumericOperationIr::EqualElem(repr) => repr.hash(state),
             NumericOperationIr::Greater(repr) => repr.hash(state),
             NumericOperationIr::GreaterElem(repr) => repr.hash(state),
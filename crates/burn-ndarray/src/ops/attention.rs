@@ -0,0 +1,110 @@
+use alloc::vec;
+use burn_common::{iter_range_par, run_par};
+use burn_tensor::ops::{attention::AttentionDropout, AttentionOptions};
+use burn_tensor::ElementConversion;
+use ndarray::{Array4, Ix2, Ix3, Ix4};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{FloatNdArrayElement, NdArrayTensor, UnsafeSharedRef};
+
+/// Computes the attention of each query with an online softmax over the keys, so that the
+/// attention weights and the dropout mask are never materialized.
+pub(crate) fn scaled_dot_product_attention<E: FloatNdArrayElement>(
+    query: NdArrayTensor<E>,
+    key: NdArrayTensor<E>,
+    value: NdArrayTensor<E>,
+    padding_mask: Option<NdArrayTensor<bool>>,
+    attn_mask: Option<NdArrayTensor<bool>>,
+    options: AttentionOptions,
+) -> NdArrayTensor<E> {
+    let query = query.array.into_dimensionality::<Ix4>().unwrap();
+    let key = key.array.into_dimensionality::<Ix4>().unwrap();
+    let value = value.array.into_dimensionality::<Ix4>().unwrap();
+    let padding_mask = padding_mask.map(|mask| mask.array.into_dimensionality::<Ix2>().unwrap());
+    let attn_mask = attn_mask.map(|mask| mask.array.into_dimensionality::<Ix3>().unwrap());
+
+    let (batch_size, num_heads, seq_length_q, head_dim) = query.dim();
+    let (_, num_kv_heads, seq_length_k, _) = key.dim();
+    let value_dim = value.dim().3;
+    assert!(
+        num_kv_heads > 0 && num_heads % num_kv_heads == 0,
+        "The number of query heads ({num_heads}) should be a multiple of the number of key and value heads ({num_kv_heads})"
+    );
+    let num_groups = num_heads / num_kv_heads;
+    let scale = options
+        .scale
+        .unwrap_or_else(|| 1.0 / (head_dim as f64).sqrt());
+    let dropout = AttentionDropout::new(&options);
+    // The queries are aligned with the last keys.
+    let causal_offset = seq_length_k as isize - seq_length_q as isize;
+
+    let mut output = Array4::zeros((batch_size, num_heads, seq_length_q, value_dim));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * num_heads * seq_length_q).for_each(|id| {
+            let (b, h, i) = (
+                id / (num_heads * seq_length_q),
+                id / seq_length_q % num_heads,
+                id % seq_length_q,
+            );
+            let kv_head = h / num_groups;
+
+            // Running maximum of the scores, sum of the weights and weighted sum of the values.
+            let mut max = f64::NEG_INFINITY;
+            let mut sum = 0.0;
+            let mut acc = vec![0.0; value_dim];
+
+            for j in 0..seq_length_k {
+                if options.is_causal && j as isize > i as isize + causal_offset {
+                    break;
+                }
+                let is_padding = padding_mask.as_ref().is_some_and(|mask| mask[(b, j)]);
+                let is_masked = attn_mask.as_ref().is_some_and(|mask| mask[(b, i, j)]);
+                if is_padding || is_masked {
+                    continue;
+                }
+
+                let score = (0..head_dim)
+                    .map(|d| {
+                        query[(b, h, i, d)].elem::<f64>() * key[(b, kv_head, j, d)].elem::<f64>()
+                    })
+                    .sum::<f64>()
+                    * scale;
+
+                if score > max {
+                    // Rescale what was accumulated with the previous maximum.
+                    let correction = (max - score).exp();
+                    sum *= correction;
+                    acc.iter_mut().for_each(|value| *value *= correction);
+                    max = score;
+                }
+
+                let weight = (score - max).exp();
+                sum += weight;
+
+                // The dropped weights still count in the softmax normalization.
+                let weight = match dropout {
+                    Some(dropout) if !dropout.keep(id, j) => continue,
+                    Some(dropout) => weight * dropout.scale,
+                    None => weight,
+                };
+                for (d, acc) in acc.iter_mut().enumerate() {
+                    *acc += weight * value[(b, kv_head, j, d)].elem::<f64>();
+                }
+            }
+
+            unsafe {
+                let output = unsafe_shared_out.get();
+                for (d, acc) in acc.into_iter().enumerate() {
+                    // A query attending to no key has a zero output.
+                    let value = if sum > 0.0 { acc / sum } else { 0.0 };
+                    output[(b, h, i, d)] = value.elem();
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
@@ -286,6 +286,98 @@ where
         }
     }
 
+    pub fn cumsum(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> NdArrayTensor<E> {
+        Self::cumulative(
+            tensor,
+            dim,
+            exclusive.then(|| 0.elem()),
+            reverse,
+            |acc, x| acc + x,
+        )
+    }
+
+    pub fn cumprod(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> NdArrayTensor<E> {
+        Self::cumulative(
+            tensor,
+            dim,
+            exclusive.then(|| 1.elem()),
+            reverse,
+            |acc, x| acc * x,
+        )
+    }
+
+    pub fn cummax(tensor: NdArrayTensor<E>, dim: usize, reverse: bool) -> NdArrayTensor<E> {
+        // A NaN is never greater than the accumulator, so it is propagated explicitly.
+        Self::cumulative(tensor, dim, None, reverse, |acc, x| {
+            if x > acc || x.partial_cmp(&x).is_none() {
+                x
+            } else {
+                acc
+            }
+        })
+    }
+
+    pub fn cummin(tensor: NdArrayTensor<E>, dim: usize, reverse: bool) -> NdArrayTensor<E> {
+        // A NaN is never less than the accumulator, so it is propagated explicitly.
+        Self::cumulative(tensor, dim, None, reverse, |acc, x| {
+            if x < acc || x.partial_cmp(&x).is_none() {
+                x
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Scan every lane of the given dimension with the accumulation function.
+    ///
+    /// When `exclusive` holds the identity of the accumulation, each output element only includes
+    /// the elements preceding it.
+    fn cumulative<F>(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+        exclusive: Option<E>,
+        reverse: bool,
+        op: F,
+    ) -> NdArrayTensor<E>
+    where
+        F: Fn(E, E) -> E,
+    {
+        let mut array = tensor.array.into_owned();
+
+        for mut lane in array.lanes_mut(Axis(dim)) {
+            let mut acc: Option<E> = None;
+            let step = |elem: &mut E| {
+                let next = match acc {
+                    Some(acc) => op(acc, *elem),
+                    None => *elem,
+                };
+                *elem = match exclusive {
+                    Some(identity) => acc.unwrap_or(identity),
+                    None => next,
+                };
+                acc = Some(next);
+            };
+
+            if reverse {
+                lane.iter_mut().rev().for_each(step);
+            } else {
+                lane.iter_mut().for_each(step);
+            }
+        }
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     pub fn gather<I: NdArrayElement>(
         dim: usize,
         mut tensor: NdArrayTensor<E>,
@@ -0,0 +1,169 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::ElementConversion;
+use core::f64::consts::PI;
+use ndarray::{ArrayD, IxDyn};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{FloatNdArrayElement, NdArrayTensor};
+
+/// Discrete Fourier transform of a complex tensor along the dimension, the last dimension of the
+/// tensor holding the real and imaginary parts.
+///
+/// Lanes with a power of two size use the radix-2 Cooley-Tukey algorithm, other sizes use the
+/// Bluestein algorithm, so every size runs in `O(n log n)`.
+pub(crate) fn fft<E: FloatNdArrayElement>(
+    tensor: NdArrayTensor<E>,
+    dim: usize,
+    inverse: bool,
+) -> NdArrayTensor<E> {
+    let mut array = tensor.array.into_owned();
+    let rank = array.ndim();
+
+    // Move the transformed dimension right before the complex one, so lanes are contiguous.
+    array.swap_axes(dim, rank - 2);
+    let shape = array.shape().to_vec();
+    let size = shape[rank - 2];
+    let mut values = array
+        .as_standard_layout()
+        .iter()
+        .copied()
+        .collect::<Vec<E>>();
+
+    if size > 0 {
+        let mut real = vec![0.0; size];
+        let mut imag = vec![0.0; size];
+
+        for lane in values.chunks_mut(2 * size) {
+            for (i, value) in lane.chunks(2).enumerate() {
+                real[i] = value[0].elem();
+                imag[i] = value[1].elem();
+            }
+
+            transform(&mut real, &mut imag, inverse);
+
+            let scale = if inverse { 1.0 / size as f64 } else { 1.0 };
+            for (i, value) in lane.chunks_mut(2).enumerate() {
+                value[0] = (real[i] * scale).elem();
+                value[1] = (imag[i] * scale).elem();
+            }
+        }
+    }
+
+    let mut array = ArrayD::from_shape_vec(IxDyn(&shape), values).unwrap();
+    array.swap_axes(dim, rank - 2);
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+/// Unnormalized in-place transform of a complex sequence of any size.
+fn transform(real: &mut [f64], imag: &mut [f64], inverse: bool) {
+    let size = real.len();
+
+    if size.is_power_of_two() {
+        radix2(real, imag, inverse);
+    } else {
+        bluestein(real, imag, inverse);
+    }
+}
+
+/// Iterative radix-2 Cooley-Tukey transform, the size must be a power of two.
+fn radix2(real: &mut [f64], imag: &mut [f64], inverse: bool) {
+    let size = real.len();
+    if size <= 1 {
+        return;
+    }
+
+    // Bit reversal permutation.
+    let bits = size.trailing_zeros();
+    for i in 0..size {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            real.swap(i, j);
+            imag.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut length = 2;
+
+    while length <= size {
+        let angle = sign * 2.0 * PI / length as f64;
+        let half = length / 2;
+
+        for start in (0..size).step_by(length) {
+            for k in 0..half {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let a = start + k;
+                let b = a + half;
+
+                let re = real[b] * cos - imag[b] * sin;
+                let im = real[b] * sin + imag[b] * cos;
+
+                real[b] = real[a] - re;
+                imag[b] = imag[a] - im;
+                real[a] += re;
+                imag[a] += im;
+            }
+        }
+
+        length *= 2;
+    }
+}
+
+/// Bluestein transform, expressing a transform of any size as a convolution computed with
+/// power of two transforms.
+fn bluestein(real: &mut [f64], imag: &mut [f64], inverse: bool) {
+    let size = real.len();
+    let padded = (2 * size - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    // Chirp w_k = exp(sign * i * pi * k^2 / n), with k^2 reduced modulo 2n for accuracy.
+    let chirp = (0..size)
+        .map(|k| {
+            let angle = sign * PI * ((k * k) % (2 * size)) as f64 / size as f64;
+            angle.sin_cos()
+        })
+        .collect::<Vec<_>>();
+
+    let mut a_real = vec![0.0; padded];
+    let mut a_imag = vec![0.0; padded];
+    let mut b_real = vec![0.0; padded];
+    let mut b_imag = vec![0.0; padded];
+
+    for (k, (sin, cos)) in chirp.iter().enumerate() {
+        a_real[k] = real[k] * cos - imag[k] * sin;
+        a_imag[k] = real[k] * sin + imag[k] * cos;
+
+        // The conjugate chirp, mirrored to make the convolution circular.
+        b_real[k] = *cos;
+        b_imag[k] = -sin;
+        if k > 0 {
+            b_real[padded - k] = *cos;
+            b_imag[padded - k] = -sin;
+        }
+    }
+
+    radix2(&mut a_real, &mut a_imag, false);
+    radix2(&mut b_real, &mut b_imag, false);
+
+    let a = a_real.iter_mut().zip(a_imag.iter_mut());
+    let b = b_real.iter().zip(b_imag.iter());
+    for ((a_real, a_imag), (b_real, b_imag)) in a.zip(b) {
+        let re = *a_real * b_real - *a_imag * b_imag;
+        let im = *a_real * b_imag + *a_imag * b_real;
+        *a_real = re;
+        *a_imag = im;
+    }
+
+    radix2(&mut a_real, &mut a_imag, true);
+
+    for (k, (sin, cos)) in chirp.iter().enumerate() {
+        let re = a_real[k] / padded as f64;
+        let im = a_imag[k] / padded as f64;
+
+        real[k] = re * cos - im * sin;
+        imag[k] = re * sin + im * cos;
+    }
+}
@@ -0,0 +1,328 @@
+use alloc::vec::Vec;
+use burn_common::{iter_range_par, run_par};
+use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+use burn_tensor::ElementConversion;
+use ndarray::{Array4, Ix4};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{FloatNdArrayElement, NdArrayTensor, UnsafeSharedRef};
+
+/// Coefficient of the cubic convolution, as used by PyTorch.
+const CUBIC_A: f64 = -0.75;
+
+pub(crate) fn grid_sample_2d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E>,
+    grid: NdArrayTensor<E>,
+    options: GridSampleOptions,
+) -> NdArrayTensor<E> {
+    let x = x.array.into_dimensionality::<Ix4>().unwrap();
+    let grid = grid.array.into_dimensionality::<Ix4>().unwrap();
+
+    let (batch_size, channels, height_in, width_in) = x.dim();
+    let (_, height, width, _) = grid.dim();
+
+    let mut output = Array4::zeros((batch_size, channels, height, width));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * height * width).for_each(|id| {
+            let (b, h, w) = (
+                id / (height * width),
+                id % (height * width) / width,
+                id % width,
+            );
+            let location = Location::new(
+                grid[(b, h, w, 0)].elem(),
+                grid[(b, h, w, 1)].elem(),
+                [height_in, width_in],
+                &options,
+            );
+
+            for c in 0..channels {
+                let value = location
+                    .taps
+                    .iter()
+                    .filter_map(|tap| {
+                        tap.index
+                            .map(|(iy, ix)| x[(b, c, iy, ix)].elem::<f64>() * tap.weight)
+                    })
+                    .sum::<f64>();
+
+                unsafe {
+                    let output = unsafe_shared_out.get();
+                    output[(b, c, h, w)] = value.elem();
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn grid_sample_2d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E>,
+    grid: NdArrayTensor<E>,
+    output_grad: NdArrayTensor<E>,
+    options: GridSampleOptions,
+) -> (NdArrayTensor<E>, NdArrayTensor<E>) {
+    let x = x.array.into_dimensionality::<Ix4>().unwrap();
+    let grid = grid.array.into_dimensionality::<Ix4>().unwrap();
+    let output_grad = output_grad.array.into_dimensionality::<Ix4>().unwrap();
+
+    let (batch_size, channels, height_in, width_in) = x.dim();
+    let (_, height, width, _) = grid.dim();
+
+    let mut x_grad = Array4::from_elem(x.dim(), 0.elem());
+    let mut grid_grad = Array4::from_elem(grid.dim(), 0.elem());
+    let unsafe_shared_x_grad = UnsafeSharedRef::new(&mut x_grad);
+    let unsafe_shared_grid_grad = UnsafeSharedRef::new(&mut grid_grad);
+
+    // Locations of the same batch can share neighbors, so each batch is accumulated by one thread.
+    run_par!(|| {
+        iter_range_par!(0, batch_size).for_each(|b| unsafe {
+            let x_grad = unsafe_shared_x_grad.get();
+            let grid_grad = unsafe_shared_grid_grad.get();
+
+            for h in 0..height {
+                for w in 0..width {
+                    let location = Location::new(
+                        grid[(b, h, w, 0)].elem(),
+                        grid[(b, h, w, 1)].elem(),
+                        [height_in, width_in],
+                        &options,
+                    );
+                    let mut grad_x = 0.0;
+                    let mut grad_y = 0.0;
+
+                    for c in 0..channels {
+                        let grad = output_grad[(b, c, h, w)].elem::<f64>();
+
+                        for tap in location.taps.iter() {
+                            if let Some((iy, ix)) = tap.index {
+                                x_grad[(b, c, iy, ix)] += (grad * tap.weight).elem();
+
+                                let value = grad * x[(b, c, iy, ix)].elem::<f64>();
+                                grad_x += value * tap.weight_grad_x;
+                                grad_y += value * tap.weight_grad_y;
+                            }
+                        }
+                    }
+
+                    grid_grad[(b, h, w, 0)] = (grad_x * location.grad_x).elem();
+                    grid_grad[(b, h, w, 1)] = (grad_y * location.grad_y).elem();
+                }
+            }
+        });
+    });
+
+    (
+        NdArrayTensor::new(x_grad.into_dyn().into_shared()),
+        NdArrayTensor::new(grid_grad.into_dyn().into_shared()),
+    )
+}
+
+/// A neighbor contributing to a sampled value, with its weight and the gradient of the weight
+/// with respect to the unnormalized location.
+struct Tap {
+    /// The `(y, x)` index of the neighbor, `None` when it is outside of the input.
+    index: Option<(usize, usize)>,
+    weight: f64,
+    weight_grad_x: f64,
+    weight_grad_y: f64,
+}
+
+/// A sampled location, with the gradient of its unnormalized coordinates with respect to the
+/// grid.
+struct Location {
+    taps: Vec<Tap>,
+    grad_x: f64,
+    grad_y: f64,
+}
+
+impl Location {
+    fn new(x: f64, y: f64, [height, width]: [usize; 2], options: &GridSampleOptions) -> Self {
+        let (x, grad_x) = unnormalize(x, width, options.align_corners);
+        let (y, grad_y) = unnormalize(y, height, options.align_corners);
+
+        let index = |x: f64, y: f64| {
+            let inside =
+                x >= 0.0 && x <= width as f64 - 1.0 && y >= 0.0 && y <= height as f64 - 1.0;
+            inside.then_some((y as usize, x as usize))
+        };
+
+        match options.mode {
+            InterpolateMode::Nearest => {
+                let (x, grad_x) = pad(x, grad_x, width, options);
+                let (y, grad_y) = pad(y, grad_y, height, options);
+                let taps = alloc::vec![Tap {
+                    index: index(round_half_even(x), round_half_even(y)),
+                    weight: 1.0,
+                    weight_grad_x: 0.0,
+                    weight_grad_y: 0.0,
+                }];
+
+                Self {
+                    taps,
+                    grad_x,
+                    grad_y,
+                }
+            }
+            InterpolateMode::Bilinear => {
+                let (x, grad_x) = pad(x, grad_x, width, options);
+                let (y, grad_y) = pad(y, grad_y, height, options);
+                let (x0, y0) = (x.floor(), y.floor());
+                let (tx, ty) = (x - x0, y - y0);
+                // The weight along a dimension and its gradient, for the lower and upper neighbors.
+                let x_weights = [(1.0 - tx, -1.0), (tx, 1.0)];
+                let y_weights = [(1.0 - ty, -1.0), (ty, 1.0)];
+
+                let mut taps = Vec::with_capacity(4);
+                for (j, (wy, dy)) in y_weights.iter().enumerate() {
+                    for (i, (wx, dx)) in x_weights.iter().enumerate() {
+                        taps.push(Tap {
+                            index: index(x0 + i as f64, y0 + j as f64),
+                            weight: wx * wy,
+                            weight_grad_x: dx * wy,
+                            weight_grad_y: wx * dy,
+                        });
+                    }
+                }
+
+                Self {
+                    taps,
+                    grad_x,
+                    grad_y,
+                }
+            }
+            InterpolateMode::Bicubic => {
+                // Bicubic sampling pads every neighbor instead of the location.
+                let (x0, y0) = (x.floor(), y.floor());
+                let x_weights = cubic_weights(x - x0);
+                let y_weights = cubic_weights(y - y0);
+                let neighbor = |start: f64, offset: usize, size: usize| {
+                    pad(start + offset as f64 - 1.0, 0.0, size, options).0
+                };
+
+                let mut taps = Vec::with_capacity(16);
+                for (j, (wy, dy)) in y_weights.iter().enumerate() {
+                    for (i, (wx, dx)) in x_weights.iter().enumerate() {
+                        taps.push(Tap {
+                            index: index(neighbor(x0, i, width), neighbor(y0, j, height)),
+                            weight: wx * wy,
+                            weight_grad_x: dx * wy,
+                            weight_grad_y: wx * dy,
+                        });
+                    }
+                }
+
+                Self {
+                    taps,
+                    grad_x,
+                    grad_y,
+                }
+            }
+        }
+    }
+}
+
+/// Maps a grid coordinate from `[-1, 1]` to the pixel indices of the input, returning the
+/// coordinate and its gradient.
+fn unnormalize(coord: f64, size: usize, align_corners: bool) -> (f64, f64) {
+    if align_corners {
+        let scale = (size as f64 - 1.0) / 2.0;
+        ((coord + 1.0) * scale, scale)
+    } else {
+        let scale = size as f64 / 2.0;
+        ((coord + 1.0) * scale - 0.5, scale)
+    }
+}
+
+/// Applies the padding to a coordinate outside of the input.
+fn pad(coord: f64, grad: f64, size: usize, options: &GridSampleOptions) -> (f64, f64) {
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => (coord, grad),
+        GridSamplePaddingMode::Border => clip(coord, grad, size),
+        GridSamplePaddingMode::Reflection => {
+            let (coord, grad) = match options.align_corners {
+                true => reflect(coord, grad, 0.0, 2.0 * (size as f64 - 1.0)),
+                false => reflect(coord, grad, -1.0, 2.0 * size as f64 - 1.0),
+            };
+            clip(coord, grad, size)
+        }
+    }
+}
+
+fn clip(coord: f64, grad: f64, size: usize) -> (f64, f64) {
+    let max = size as f64 - 1.0;
+
+    if coord <= 0.0 {
+        (0.0, 0.0)
+    } else if coord >= max {
+        (max, 0.0)
+    } else {
+        (coord, grad)
+    }
+}
+
+/// Reflects a coordinate over the bounds, given as twice their value so that they are integers
+/// for both corner alignments.
+fn reflect(coord: f64, grad: f64, twice_low: f64, twice_high: f64) -> (f64, f64) {
+    if twice_low == twice_high {
+        return (0.0, 0.0);
+    }
+
+    let min = twice_low / 2.0;
+    let span = (twice_high - twice_low) / 2.0;
+    let coord = coord - min;
+    let (coord, grad) = match coord < 0.0 {
+        true => (-coord, -grad),
+        false => (coord, grad),
+    };
+
+    let extra = coord % span;
+    let flips = (coord / span).floor();
+
+    if flips % 2.0 == 0.0 {
+        (extra + min, grad)
+    } else {
+        (span - extra + min, -grad)
+    }
+}
+
+fn round_half_even(value: f64) -> f64 {
+    let floor = value.floor();
+    let diff = value - floor;
+
+    if diff > 0.5 || (diff == 0.5 && floor % 2.0 != 0.0) {
+        floor + 1.0
+    } else {
+        floor
+    }
+}
+
+/// The weights of the 4 neighbors of a location at distance `t` from the second neighbor, and
+/// their gradient with respect to `t`.
+fn cubic_weights(t: f64) -> [(f64, f64); 4] {
+    // Cubic convolution for |x| <= 1 and its derivative.
+    let near = |x: f64| {
+        (
+            ((CUBIC_A + 2.0) * x - (CUBIC_A + 3.0)) * x * x + 1.0,
+            (3.0 * (CUBIC_A + 2.0) * x - 2.0 * (CUBIC_A + 3.0)) * x,
+        )
+    };
+    // Cubic convolution for 1 < |x| < 2 and its derivative.
+    let far = |x: f64| {
+        (
+            ((CUBIC_A * x - 5.0 * CUBIC_A) * x + 8.0 * CUBIC_A) * x - 4.0 * CUBIC_A,
+            (3.0 * CUBIC_A * x - 10.0 * CUBIC_A) * x + 8.0 * CUBIC_A,
+        )
+    };
+
+    let (w0, d0) = far(t + 1.0);
+    let (w1, d1) = near(t);
+    let (w2, d2) = near(1.0 - t);
+    let (w3, d3) = far(2.0 - t);
+
+    [(w0, d0), (w1, d1), (w2, -d2), (w3, -d3)]
+}
@@ -215,6 +215,32 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         NdArrayMathOps::prod_dim(tensor, dim)
     }
 
+    fn int_cumsum(
+        tensor: NdArrayTensor<I>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> NdArrayTensor<I> {
+        NdArrayMathOps::cumsum(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cumprod(
+        tensor: NdArrayTensor<I>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> NdArrayTensor<I> {
+        NdArrayMathOps::cumprod(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cummax(tensor: NdArrayTensor<I>, dim: usize, reverse: bool) -> NdArrayTensor<I> {
+        NdArrayMathOps::cummax(tensor, dim, reverse)
+    }
+
+    fn int_cummin(tensor: NdArrayTensor<I>, dim: usize, reverse: bool) -> NdArrayTensor<I> {
+        NdArrayMathOps::cummin(tensor, dim, reverse)
+    }
+
     fn int_mean(tensor: NdArrayTensor<I>) -> NdArrayTensor<I> {
         NdArrayMathOps::mean(tensor)
     }
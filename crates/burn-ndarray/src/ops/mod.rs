@@ -8,9 +8,12 @@ mod tensor;
 mod transaction;
 
 pub(crate) mod adaptive_avgpool;
+pub(crate) mod attention;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
 pub(crate) mod deform_conv;
+pub(crate) mod fft;
+pub(crate) mod grid_sample;
 pub(crate) mod interpolate;
 pub(crate) mod macros;
 pub(crate) mod matmul;
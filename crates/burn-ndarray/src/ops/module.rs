@@ -1,8 +1,10 @@
 use super::{
     adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
+    attention::scaled_dot_product_attention,
     avgpool::{avg_pool2d, avg_pool2d_backward},
     conv::{conv2d, conv3d, conv_transpose2d, conv_transpose3d},
     deform_conv::{backward::deform_conv2d_backward, deform_conv2d},
+    grid_sample::{grid_sample_2d, grid_sample_2d_backward},
     interpolate::{bicubic_interpolate, bilinear_interpolate, nearest_interpolate},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
 };
@@ -272,6 +274,49 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> ModuleOps<Se
         }
     }
 
+    fn grid_sample_2d(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        module_op!(inp(x, grid), opt(), E, |x, grid| grid_sample_2d::<E>(
+            x, grid, options
+        )
+        .into())
+    }
+
+    fn grid_sample_2d_backward(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> GridSample2dBackward<Self> {
+        module_op!(
+            inp(x, grid, output_grad),
+            opt(),
+            E,
+            |x, grid, output_grad| {
+                let (x_grad, grid_grad) =
+                    grid_sample_2d_backward::<E>(x, grid, output_grad, options);
+                GridSample2dBackward::new(x_grad.into(), grid_grad.into())
+            }
+        )
+    }
+
+    fn scaled_dot_product_attention(
+        query: FloatTensor<Self>,
+        key: FloatTensor<Self>,
+        value: FloatTensor<Self>,
+        padding_mask: Option<BoolTensor<Self>>,
+        attn_mask: Option<BoolTensor<Self>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<Self> {
+        module_op!(inp(query, key, value), opt(), E, |query, key, value| {
+            scaled_dot_product_attention::<E>(query, key, value, padding_mask, attn_mask, options)
+                .into()
+        })
+    }
+
     fn conv3d(
         x: FloatTensor<Self>,
         weight: FloatTensor<Self>,
@@ -1,10 +1,12 @@
+use alloc::vec::Vec;
 use core::ops::Range;
 
 use burn_tensor::{
-    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
+    dequant_op_quant,
+    ops::{FloatTensor, FloatTensorOps, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        AffineQuantization, QParams, QuantizationParametersPrimitive, QuantizationScheme,
-        QuantizationStrategy, QuantizationType, QuantizedBytes, SymmetricQuantization,
+        QParams, QTensorPrimitive, QuantizationParametersPrimitive, QuantizationScheme,
+        QuantizationStrategy, QuantizationType, QuantizedBytes,
     },
     DType, ElementConversion, Shape, TensorData, TensorMetadata,
 };
@@ -44,15 +46,21 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
                     num_elements,
                 };
 
-                match scheme {
-                    QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                    | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
+                match scheme.q_type() {
+                    // Sub-byte quantized values are unpacked to a single value per element
+                    QuantizationType::QInt8
+                    | QuantizationType::QInt4
+                    | QuantizationType::QUInt4
+                    | QuantizationType::QInt2
+                    | QuantizationType::QUInt2 => {
                         let (values, qparams) = q_bytes.into_vec_i8();
 
                         let data = TensorData::new(values, shape).convert::<Q>();
                         let qparams = QParams {
                             scale: qparams.scale,
-                            offset: qparams.offset.map(|x| x.elem::<Q>()),
+                            offset: qparams
+                                .offset
+                                .map(|x| x.into_iter().map(|x| x.elem::<Q>()).collect()),
                         };
 
                         NdArrayQTensor {
@@ -75,41 +83,22 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         scheme: &QuantizationScheme,
         qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self> {
-        let (strategy, qparams) = match scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = into_data_f(qparams.scale).iter().next().unwrap();
-                    let offset = into_data(qparams.offset.unwrap())
-                        .iter::<Q>()
-                        .next()
-                        .unwrap();
-                    (
-                        QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                            scale,
-                            offset.elem(),
-                        )),
-                        QParams {
-                            scale,
-                            offset: Some(offset),
-                        },
-                    )
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = into_data_f(qparams.scale).iter().next().unwrap();
-                    (
-                        QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                            scale,
-                        )),
-                        QParams {
-                            scale,
-                            offset: None,
-                        },
-                    )
-                }
-            },
+        let qparams = QParams {
+            scale: into_data_f(qparams.scale).iter().collect::<Vec<f32>>(),
+            offset: qparams
+                .offset
+                .map(|offset| into_data(offset).iter::<Q>().collect::<Vec<_>>()),
         };
+        let strategy = QuantizationStrategy::from_qparams(
+            *scheme,
+            QParams {
+                scale: qparams.scale.clone(),
+                offset: qparams
+                    .offset
+                    .as_ref()
+                    .map(|offset| offset.iter().map(|x| x.elem::<i8>()).collect()),
+            },
+        );
 
         let shape = tensor.shape();
         let data = into_data_f(tensor).with_quantization(strategy);
@@ -149,6 +138,25 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
     }
 
     fn q_reshape(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        // Reshaping preserves the row-major order of the elements, so the quantization parameters
+        // remain valid unless the dimensions up to the quantized channel are modified.
+        let preserves_qparams = match tensor.scheme {
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => {
+                shape.num_dims() > axis
+                    && tensor.qtensor.array.shape()[..=axis] == shape.dims[..=axis]
+            }
+            _ => true,
+        };
+
+        if !preserves_qparams {
+            return dequant_op_quant!(
+                ty Self,
+                float_op |tensor| Self::float_reshape(tensor, shape),
+                tensor
+            );
+        }
+
         NdArrayQTensor {
             qtensor: NdArrayOps::reshape(tensor.qtensor, shape),
             scheme: tensor.scheme,
@@ -168,26 +176,90 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         dim1: usize,
         dim2: usize,
     ) -> QuantizedTensor<Self> {
+        let scheme = match tensor.scheme {
+            QuantizationScheme::PerChannelAffine(dtype, axis) => {
+                QuantizationScheme::PerChannelAffine(dtype, swap_axis(axis, dim1, dim2))
+            }
+            QuantizationScheme::PerChannelSymmetric(dtype, axis) => {
+                QuantizationScheme::PerChannelSymmetric(dtype, swap_axis(axis, dim1, dim2))
+            }
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..)
+                if dim1 != dim2 =>
+            {
+                return dequant_op_quant!(
+                    ty Self,
+                    float_op |tensor| Self::float_swap_dims(tensor, dim1, dim2),
+                    tensor
+                );
+            }
+            scheme => scheme,
+        };
+
         NdArrayQTensor {
             qtensor: NdArrayOps::swap_dims(tensor.qtensor, dim1, dim2),
-            scheme: tensor.scheme,
+            scheme,
             qparams: tensor.qparams,
         }
     }
 
     fn q_permute(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        let permuted_axis = |axis: usize| axes.iter().position(|d| *d == axis).unwrap();
+        let scheme = match tensor.scheme {
+            QuantizationScheme::PerChannelAffine(dtype, axis) => {
+                QuantizationScheme::PerChannelAffine(dtype, permuted_axis(axis))
+            }
+            QuantizationScheme::PerChannelSymmetric(dtype, axis) => {
+                QuantizationScheme::PerChannelSymmetric(dtype, permuted_axis(axis))
+            }
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+                return dequant_op_quant!(
+                    ty Self,
+                    float_op |tensor| Self::float_permute(tensor, axes),
+                    tensor
+                );
+            }
+            scheme => scheme,
+        };
+
         NdArrayQTensor {
             qtensor: NdArrayOps::permute(tensor.qtensor, axes),
-            scheme: tensor.scheme,
+            scheme,
             qparams: tensor.qparams,
         }
     }
 
     fn q_flip(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        let mut qparams = tensor.qparams;
+        match tensor.scheme {
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => {
+                // Flipping the channel axis reverses the order of the quantization parameters
+                if axes.contains(&axis) {
+                    qparams.scale.reverse();
+                    if let Some(offset) = qparams.offset.as_mut() {
+                        offset.reverse();
+                    }
+                }
+            }
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+                let tensor = NdArrayQTensor {
+                    qtensor: tensor.qtensor,
+                    scheme: tensor.scheme,
+                    qparams,
+                };
+                return dequant_op_quant!(
+                    ty Self,
+                    float_op |tensor| Self::float_flip(tensor, axes),
+                    tensor
+                );
+            }
+            _ => {}
+        };
+
         NdArrayQTensor {
             qtensor: NdArrayOps::flip(tensor.qtensor, axes),
             scheme: tensor.scheme,
-            qparams: tensor.qparams,
+            qparams,
         }
     }
 
@@ -196,6 +268,14 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         tensor: QuantizedTensor<Self>,
         indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
+        if !tensor.scheme.is_per_tensor() {
+            return dequant_op_quant!(
+                ty Self,
+                float_op |tensor| Self::float_gather(dim, tensor, indices),
+                tensor
+            );
+        }
+
         NdArrayQTensor {
             qtensor: NdArrayMathOps::gather(dim, tensor.qtensor, indices),
             scheme: tensor.scheme,
@@ -208,6 +288,20 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         dim: usize,
         indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
+        let preserves_qparams = match tensor.scheme {
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => axis != dim,
+            scheme => scheme.is_per_tensor(),
+        };
+
+        if !preserves_qparams {
+            return dequant_op_quant!(
+                ty Self,
+                float_op |tensor| Self::float_select(tensor, dim, indices),
+                tensor
+            );
+        }
+
         NdArrayQTensor {
             qtensor: NdArrayMathOps::select(tensor.qtensor, dim, indices),
             scheme: tensor.scheme,
@@ -216,22 +310,61 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
     }
 
     fn q_slice(tensor: QuantizedTensor<Self>, ranges: &[Range<usize>]) -> QuantizedTensor<Self> {
+        let mut qparams = tensor.qparams;
+        match tensor.scheme {
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => {
+                // Keep only the quantization parameters of the sliced channels
+                if let Some(range) = ranges.get(axis) {
+                    qparams.scale = qparams.scale[range.clone()].to_vec();
+                    qparams.offset = qparams.offset.map(|offset| offset[range.clone()].to_vec());
+                }
+            }
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+                let tensor = NdArrayQTensor {
+                    qtensor: tensor.qtensor,
+                    scheme: tensor.scheme,
+                    qparams,
+                };
+                return dequant_op_quant!(
+                    ty Self,
+                    float_op |tensor| Self::float_slice(tensor, ranges),
+                    tensor
+                );
+            }
+            _ => {}
+        }
+
         NdArrayQTensor {
             qtensor: NdArrayOps::slice(tensor.qtensor, ranges),
             scheme: tensor.scheme,
-            qparams: tensor.qparams,
+            qparams,
         }
     }
 
     fn q_argmax(tensor: QuantizedTensor<Self>, dim: usize) -> IntTensor<Self> {
+        if !compares_same_qparams(&tensor.scheme, dim) {
+            return Self::float_argmax(Self::dequantize(tensor), dim);
+        }
         NdArrayMathOps::argmax(tensor.qtensor, dim)
     }
 
     fn q_argmin(tensor: QuantizedTensor<Self>, dim: usize) -> IntTensor<Self> {
+        if !compares_same_qparams(&tensor.scheme, dim) {
+            return Self::float_argmin(Self::dequantize(tensor), dim);
+        }
         NdArrayMathOps::argmin(tensor.qtensor, dim)
     }
 
     fn q_expand(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        if !tensor.scheme.is_per_tensor() {
+            return dequant_op_quant!(
+                ty Self,
+                float_op |tensor| Self::float_expand(tensor, shape),
+                tensor
+            );
+        }
+
         NdArrayQTensor {
             qtensor: NdArrayOps::expand(tensor.qtensor, shape),
             scheme: tensor.scheme,
@@ -239,3 +372,24 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         }
     }
 }
+
+/// Returns the position of the quantized channel axis after swapping two dimensions.
+fn swap_axis(axis: usize, dim1: usize, dim2: usize) -> usize {
+    if axis == dim1 {
+        dim2
+    } else if axis == dim2 {
+        dim1
+    } else {
+        axis
+    }
+}
+
+/// Returns true if all the values reduced along the given dimension share the same quantization
+/// parameters, in which case the quantized values can be compared directly.
+fn compares_same_qparams(scheme: &QuantizationScheme, dim: usize) -> bool {
+    match scheme {
+        QuantizationScheme::PerChannelAffine(_, axis)
+        | QuantizationScheme::PerChannelSymmetric(_, axis) => *axis != dim,
+        scheme => scheme.is_per_tensor(),
+    }
+}
@@ -6,7 +6,7 @@ use core::ops::Range;
 use ndarray::Zip;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+use super::{fft::fft, matmul::matmul, NdArrayMathOps, NdArrayOps};
 use crate::element::{ExpElement, FloatNdArrayElement, IntNdArrayElement, QuantElement};
 use crate::{execute_with_float_dtype, NdArrayDevice, NdArrayTensorFloat, SEED};
 use crate::{tensor::NdArrayTensor, NdArray};
@@ -351,6 +351,44 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::prod_dim(tensor, dim))
     }
 
+    fn float_cumsum(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| {
+            NdArrayMathOps::cumsum(tensor, dim, exclusive, reverse)
+        })
+    }
+
+    fn float_cumprod(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| {
+            NdArrayMathOps::cumprod(tensor, dim, exclusive, reverse)
+        })
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| {
+            NdArrayMathOps::cummax(tensor, dim, reverse)
+        })
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize, reverse: bool) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| {
+            NdArrayMathOps::cummin(tensor, dim, reverse)
+        })
+    }
+
+    fn float_fft(tensor: FloatTensor<Self>, dim: usize, inverse: bool) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| fft(tensor, dim, inverse))
+    }
+
     fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
             let array = tensor.array.mapv_into(|a| a.log1p_elem()).into_shared();
@@ -1,8 +1,6 @@
+use alloc::vec::Vec;
 use burn_tensor::{
-    quantization::{
-        AffineQuantization, QParams, QTensorPrimitive, QuantizationScheme, QuantizationStrategy,
-        QuantizationType, SymmetricQuantization,
-    },
+    quantization::{QParams, QTensorPrimitive, QuantizationScheme, QuantizationStrategy},
     DType, Element, Shape, TensorData, TensorMetadata,
 };
 
@@ -342,26 +340,24 @@ pub struct NdArrayQTensor<Q: QuantElement> {
     pub qtensor: NdArrayTensor<Q>,
     /// The quantization scheme.
     pub scheme: QuantizationScheme,
-    /// The quantization parameters.
-    pub qparams: QParams<f32, Q>,
+    /// The quantization parameters, with one scale (and offset) for each group of values.
+    pub qparams: QParams<Vec<f32>, Vec<Q>>,
 }
 
 impl<Q: QuantElement> NdArrayQTensor<Q> {
     /// Returns the quantization strategy, including quantization parameters, for the given tensor.
     pub fn strategy(&self) -> QuantizationStrategy {
-        match self.scheme {
-            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => {
-                QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                    self.qparams.scale,
-                    self.qparams.offset.unwrap().elem(),
-                ))
-            }
-            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                    self.qparams.scale,
-                ))
-            }
-        }
+        QuantizationStrategy::from_qparams(
+            self.scheme,
+            QParams {
+                scale: self.qparams.scale.clone(),
+                offset: self
+                    .qparams
+                    .offset
+                    .as_ref()
+                    .map(|offset| offset.iter().map(|x| x.elem()).collect()),
+            },
+        )
     }
 }
 
@@ -470,4 +466,34 @@ mod tests {
             QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(scale, offset))
         );
     }
+
+    #[test]
+    fn should_support_qtensor_per_channel_permute() {
+        type B = NdArray<f32, i64, i8>;
+        let device = Default::default();
+
+        let tensor = B::float_from_data(
+            TensorData::from([[-1.8f32, -1.0, 0.0], [0.5, 2.0, -3.0]]),
+            &device,
+        );
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 1);
+        let qparams = QuantizationParametersPrimitive {
+            scale: B::float_from_data(TensorData::from([0.1f32, 0.2, 0.3]), &device),
+            offset: None,
+        };
+        let qtensor: NdArrayQTensor<i8> = B::quantize(tensor, &scheme, qparams);
+        let qtensor = B::q_permute(qtensor, &[1, 0]);
+
+        assert_eq!(
+            qtensor.scheme(),
+            &QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0)
+        );
+        let NdArrayTensorFloat::F32(output) = B::dequantize(qtensor) else {
+            unreachable!()
+        };
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[-1.8f32, 0.5], [-1.0, 2.0], [0.0, -3.0]]),
+            1,
+        );
+    }
 }
@@ -3,10 +3,10 @@ use burn_tensor::backend::Backend;
 use core::ops::Range;
 
 use burn_ir::{
-    BaseOperationIr, BinaryOpIr, CatOpIr, ClampOpIr, ExpandOpIr, FlipOpIr, GatherOpIr,
-    InitOperationIr, IntOperationIr, MaskFillOpIr, MaskWhereOpIr, NumericOperationIr, OperationIr,
-    PermuteOpIr, RandomOpIr, ReduceDimWithIndicesOpIr, RepeatDimOpIr, ScalarOpIr, ScatterOpIr,
-    SelectAssignOpIr, SelectOpIr, SliceAssignOpIr, SliceOpIr, SwapDimsOpIr, UnaryOpIr,
+    BaseOperationIr, BinaryOpIr, CatOpIr, ClampOpIr, CumulativeOpIr, ExpandOpIr, FlipOpIr,
+    GatherOpIr, InitOperationIr, IntOperationIr, MaskFillOpIr, MaskWhereOpIr, NumericOperationIr,
+    OperationIr, PermuteOpIr, RandomOpIr, ReduceDimWithIndicesOpIr, RepeatDimOpIr, ScalarOpIr,
+    ScatterOpIr, SelectAssignOpIr, SelectOpIr, SliceAssignOpIr, SliceOpIr, SwapDimsOpIr, UnaryOpIr,
 };
 use burn_tensor::ops::{
     binary_ops_shape, BoolTensor, FloatElem, FloatTensor, IntElem, IntTensor, IntTensorOps,
@@ -781,6 +781,100 @@ impl<R: RunnerChannel> IntTensorOps<Self> for BackendRouter<R> {
         out
     }
 
+    fn int_cumsum(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive,
+            reverse,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumSum(desc),
+        ));
+
+        out
+    }
+
+    fn int_cumprod(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive,
+            reverse,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumProd(desc),
+        ));
+
+        out
+    }
+
+    fn int_cummax(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive: false,
+            reverse,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumMax(desc),
+        ));
+
+        out
+    }
+
+    fn int_cummin(tensor: IntTensor<Self>, dim: usize, reverse: bool) -> IntTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = CumulativeOpIr {
+            input: tensor.into_ir(),
+            dim,
+            exclusive: false,
+            reverse,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumMin(desc),
+        ));
+
+        out
+    }
+
     fn int_mean(tensor: IntTensor<Self>) -> IntTensor<Self> {
         let client = tensor.client.clone();
         let dtype = tensor.dtype;
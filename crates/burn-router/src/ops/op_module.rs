@@ -4,16 +4,18 @@ use burn_ir::{
     AdaptiveAvgPool1dBackwardOpIr, AdaptiveAvgPool1dOpIr, AdaptiveAvgPool2dBackwardOpIr,
     AdaptiveAvgPool2dOpIr, AvgPool1dBackwardOpIr, AvgPool1dOpIr, AvgPool2dBackwardOpIr,
     AvgPool2dOpIr, Conv1dOpIr, Conv2dOpIr, Conv3dOpIr, ConvTranspose1dOpIr, ConvTranspose2dOpIr,
-    ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr, InterpolateBackwardOpIr,
-    InterpolateOpIr, MaxPool1dOpIr, MaxPool1dWithIndicesBackwardOpIr, MaxPool1dWithIndicesOpIr,
-    MaxPool2dOpIr, MaxPool2dWithIndicesBackwardOpIr, MaxPool2dWithIndicesOpIr, ModuleOperationIr,
-    OperationIr,
+    ConvTranspose3dOpIr, DeformConv2dBackwardOpIr, DeformConv2dOpIr, GridSample2dBackwardOpIr,
+    GridSample2dOpIr, InterpolateBackwardOpIr, InterpolateOpIr, MaxPool1dOpIr,
+    MaxPool1dWithIndicesBackwardOpIr, MaxPool1dWithIndicesOpIr, MaxPool2dOpIr,
+    MaxPool2dWithIndicesBackwardOpIr, MaxPool2dWithIndicesOpIr, ModuleOperationIr, OperationIr,
+    ScaledDotProductAttentionBackwardOpIr, ScaledDotProductAttentionOpIr,
 };
 use burn_tensor::ops::conv::{
     calculate_conv_output_size, calculate_conv_transpose_output_size, calculate_pool_output_size,
 };
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, FloatTensor,
+    AttentionBackward, AttentionOptions, BoolTensor, ConvOptions, ConvTransposeOptions,
+    DeformConv2dBackward, DeformConvOptions, FloatTensor, GridSample2dBackward, GridSampleOptions,
     IntElem, ModuleOps,
 };
 use burn_tensor::ops::{
@@ -796,4 +798,121 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
 
         DeformConv2dBackward::new(input_grad, offset_grad, weight_grad, mask_grad, bias_grad)
     }
+
+    fn grid_sample_2d(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        let shape = vec![x.shape[0], x.shape[1], grid.shape[1], grid.shape[2]];
+
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape, x.dtype);
+
+        let desc = GridSample2dOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            options: options.into(),
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(ModuleOperationIr::GridSample2d(desc)));
+
+        out
+    }
+
+    fn grid_sample_2d_backward(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> GridSample2dBackward<Self> {
+        let client = x.client.clone();
+        let x_grad = client.register_empty_tensor(x.shape.clone(), x.dtype);
+        let grid_grad = client.register_empty_tensor(grid.shape.clone(), grid.dtype);
+
+        let desc = GridSample2dBackwardOpIr {
+            x: x.into_ir(),
+            grid: grid.into_ir(),
+            out_grad: output_grad.into_ir(),
+            options: options.into(),
+            x_grad: x_grad.to_ir_out(),
+            grid_grad: grid_grad.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(
+            ModuleOperationIr::GridSample2dBackward(desc),
+        ));
+
+        GridSample2dBackward::new(x_grad, grid_grad)
+    }
+
+    fn scaled_dot_product_attention(
+        query: FloatTensor<Self>,
+        key: FloatTensor<Self>,
+        value: FloatTensor<Self>,
+        padding_mask: Option<BoolTensor<Self>>,
+        attn_mask: Option<BoolTensor<Self>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<Self> {
+        let shape = vec![
+            query.shape[0],
+            query.shape[1],
+            query.shape[2],
+            value.shape[3],
+        ];
+
+        let client = query.client.clone();
+        let out = client.register_empty_tensor(shape, query.dtype);
+
+        let desc = ScaledDotProductAttentionOpIr {
+            query: query.into_ir(),
+            key: key.into_ir(),
+            value: value.into_ir(),
+            padding_mask: padding_mask.map(|mask| mask.into_ir()),
+            attn_mask: attn_mask.map(|mask| mask.into_ir()),
+            options: options.into(),
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(
+            ModuleOperationIr::ScaledDotProductAttention(Box::new(desc)),
+        ));
+
+        out
+    }
+
+    fn scaled_dot_product_attention_backward(
+        query: FloatTensor<Self>,
+        key: FloatTensor<Self>,
+        value: FloatTensor<Self>,
+        padding_mask: Option<BoolTensor<Self>>,
+        attn_mask: Option<BoolTensor<Self>>,
+        output_grad: FloatTensor<Self>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<Self> {
+        let client = query.client.clone();
+        let query_grad = client.register_empty_tensor(query.shape.clone(), query.dtype);
+        let key_grad = client.register_empty_tensor(key.shape.clone(), key.dtype);
+        let value_grad = client.register_empty_tensor(value.shape.clone(), value.dtype);
+
+        let desc = ScaledDotProductAttentionBackwardOpIr {
+            query: query.into_ir(),
+            key: key.into_ir(),
+            value: value.into_ir(),
+            padding_mask: padding_mask.map(|mask| mask.into_ir()),
+            attn_mask: attn_mask.map(|mask| mask.into_ir()),
+            out_grad: output_grad.into_ir(),
+            options: options.into(),
+            query_grad: query_grad.to_ir_out(),
+            key_grad: key_grad.to_ir_out(),
+            value_grad: value_grad.to_ir_out(),
+        };
+
+        client.register(OperationIr::Module(
+            ModuleOperationIr::ScaledDotProductAttentionBackward(Box::new(desc)),
+        ));
+
+        AttentionBackward::new(query_grad, key_grad, value_grad)
+    }
 }
@@ -499,6 +499,30 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 NumericOperationIr::ProdDim(desc) => {
                     scalar_float_dim_ops!(handles, desc, B::float_prod_dim)
                 }
+                NumericOperationIr::CumSum(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_cumsum(tensor, desc.dim, desc.exclusive, desc.reverse);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                NumericOperationIr::CumProd(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_cumprod(tensor, desc.dim, desc.exclusive, desc.reverse);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                NumericOperationIr::CumMax(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_cummax(tensor, desc.dim, desc.reverse);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                NumericOperationIr::CumMin(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_cummin(tensor, desc.dim, desc.reverse);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
                 NumericOperationIr::EqualElem(desc) => {
                     scalar_float_cmp_ops!(handles, desc, B::float_equal_elem)
                 }
@@ -681,6 +705,30 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 NumericOperationIr::ProdDim(desc) => {
                     scalar_int_dim_ops!(handles, desc, B::int_prod_dim)
                 }
+                NumericOperationIr::CumSum(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.input);
+
+                    let output = B::int_cumsum(tensor, desc.dim, desc.exclusive, desc.reverse);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
+                NumericOperationIr::CumProd(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.input);
+
+                    let output = B::int_cumprod(tensor, desc.dim, desc.exclusive, desc.reverse);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
+                NumericOperationIr::CumMax(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.input);
+
+                    let output = B::int_cummax(tensor, desc.dim, desc.reverse);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
+                NumericOperationIr::CumMin(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.input);
+
+                    let output = B::int_cummin(tensor, desc.dim, desc.reverse);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
                 NumericOperationIr::EqualElem(desc) => {
                     scalar_int_cmp_ops!(handles, desc, B::int_equal_elem)
                 }
@@ -882,6 +930,12 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 FloatOperationIr::Recip(desc) => {
                     unary_float_ops!(handles, desc, B::float_recip)
                 }
+                FloatOperationIr::Fft(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.input);
+
+                    let output = B::float_fft(tensor, desc.dim, desc.inverse);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
                 FloatOperationIr::Quantize(_) => todo!(),
                 FloatOperationIr::Dequantize(_) => todo!(),
             },
@@ -1204,6 +1258,77 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     );
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                ModuleOperationIr::GridSample2d(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let grid = handles.get_float_tensor::<B>(&desc.grid);
+
+                    let output = B::grid_sample_2d(x, grid, desc.options.clone().into());
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationIr::GridSample2dBackward(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let grid = handles.get_float_tensor::<B>(&desc.grid);
+                    let output_grad = handles.get_float_tensor::<B>(&desc.out_grad);
+
+                    let output = B::grid_sample_2d_backward(
+                        x,
+                        grid,
+                        output_grad,
+                        desc.options.clone().into(),
+                    );
+                    handles.register_float_tensor::<B>(&desc.x_grad.id, output.x_grad);
+                    handles.register_float_tensor::<B>(&desc.grid_grad.id, output.grid_grad);
+                }
+                ModuleOperationIr::ScaledDotProductAttention(desc) => {
+                    let query = handles.get_float_tensor::<B>(&desc.query);
+                    let key = handles.get_float_tensor::<B>(&desc.key);
+                    let value = handles.get_float_tensor::<B>(&desc.value);
+                    let padding_mask = desc
+                        .padding_mask
+                        .as_ref()
+                        .map(|mask| handles.get_bool_tensor::<B>(mask));
+                    let attn_mask = desc
+                        .attn_mask
+                        .as_ref()
+                        .map(|mask| handles.get_bool_tensor::<B>(mask));
+
+                    let output = B::scaled_dot_product_attention(
+                        query,
+                        key,
+                        value,
+                        padding_mask,
+                        attn_mask,
+                        desc.options.clone().into(),
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationIr::ScaledDotProductAttentionBackward(desc) => {
+                    let query = handles.get_float_tensor::<B>(&desc.query);
+                    let key = handles.get_float_tensor::<B>(&desc.key);
+                    let value = handles.get_float_tensor::<B>(&desc.value);
+                    let padding_mask = desc
+                        .padding_mask
+                        .as_ref()
+                        .map(|mask| handles.get_bool_tensor::<B>(mask));
+                    let attn_mask = desc
+                        .attn_mask
+                        .as_ref()
+                        .map(|mask| handles.get_bool_tensor::<B>(mask));
+                    let output_grad = handles.get_float_tensor::<B>(&desc.out_grad);
+
+                    let output = B::scaled_dot_product_attention_backward(
+                        query,
+                        key,
+                        value,
+                        padding_mask,
+                        attn_mask,
+                        output_grad,
+                        desc.options.clone().into(),
+                    );
+                    handles.register_float_tensor::<B>(&desc.query_grad.id, output.query_grad);
+                    handles.register_float_tensor::<B>(&desc.key_grad.id, output.key_grad);
+                    handles.register_float_tensor::<B>(&desc.value_grad.id, output.value_grad);
+                }
             },
             OperationIr::Custom(_) => {
                 panic!("Can't execute custom operation here")
@@ -308,6 +308,70 @@ impl TchOps {
         )
     }
 
+    pub fn cumsum(tensor: TchTensor, dim: usize, exclusive: bool, reverse: bool) -> TchTensor {
+        Self::cumulative(
+            tensor,
+            dim,
+            exclusive.then_some(0),
+            reverse,
+            |tensor, dim| tensor.cumsum(dim, tensor.kind()),
+        )
+    }
+
+    pub fn cumprod(tensor: TchTensor, dim: usize, exclusive: bool, reverse: bool) -> TchTensor {
+        Self::cumulative(
+            tensor,
+            dim,
+            exclusive.then_some(1),
+            reverse,
+            |tensor, dim| tensor.cumprod(dim, tensor.kind()),
+        )
+    }
+
+    pub fn cummax(tensor: TchTensor, dim: usize, reverse: bool) -> TchTensor {
+        Self::cumulative(tensor, dim, None, reverse, |tensor, dim| {
+            tensor.cummax(dim).0
+        })
+    }
+
+    pub fn cummin(tensor: TchTensor, dim: usize, reverse: bool) -> TchTensor {
+        Self::cumulative(tensor, dim, None, reverse, |tensor, dim| {
+            tensor.cummin(dim).0
+        })
+    }
+
+    /// Applies the inclusive scan of LibTorch, flipping the dimension for a reverse scan and
+    /// shifting the result by one element, starting with the identity, for an exclusive one.
+    fn cumulative<F>(
+        tensor: TchTensor,
+        dim: usize,
+        exclusive: Option<i64>,
+        reverse: bool,
+        op: F,
+    ) -> TchTensor
+    where
+        F: Fn(&tch::Tensor, i64) -> tch::Tensor,
+    {
+        let dim = dim as i64;
+        let mut tensor = tensor.tensor;
+        if reverse {
+            tensor = tensor.flip([dim]);
+        }
+
+        let mut output = op(&tensor, dim);
+        if let Some(identity) = exclusive {
+            let size = output.size()[dim as usize];
+            let first = output.narrow(dim, 0, 1).full_like(identity);
+            output = tch::Tensor::cat(&[first, output.narrow(dim, 0, size - 1)], dim);
+        }
+
+        if reverse {
+            output = output.flip([dim]);
+        }
+
+        TchTensor::new(output)
+    }
+
     pub fn argmax(tensor: TchTensor, dim: usize) -> TchTensor {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.argmax(dim as i64, true);
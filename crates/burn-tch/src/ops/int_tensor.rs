@@ -231,6 +231,22 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
         TchOps::prod_dim(tensor, dim)
     }
 
+    fn int_cumsum(tensor: TchTensor, dim: usize, exclusive: bool, reverse: bool) -> TchTensor {
+        TchOps::cumsum(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cumprod(tensor: TchTensor, dim: usize, exclusive: bool, reverse: bool) -> TchTensor {
+        TchOps::cumprod(tensor, dim, exclusive, reverse)
+    }
+
+    fn int_cummax(tensor: TchTensor, dim: usize, reverse: bool) -> TchTensor {
+        TchOps::cummax(tensor, dim, reverse)
+    }
+
+    fn int_cummin(tensor: TchTensor, dim: usize, reverse: bool) -> TchTensor {
+        TchOps::cummin(tensor, dim, reverse)
+    }
+
     fn int_mean(tensor: TchTensor) -> TchTensor {
         let tensor: TchTensor =
             TchTensor::new(tensor.tensor.to_dtype(tch::Kind::Float, true, false));
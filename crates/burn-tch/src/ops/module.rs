@@ -2,8 +2,9 @@ use crate::{element::TchElement, LibTorch, QuantElement, TchTensor};
 use burn_tensor::{
     ops::{
         ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions,
-        InterpolateMode, InterpolateOptions, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        GridSample2dBackward, GridSampleOptions, GridSamplePaddingMode, InterpolateMode,
+        InterpolateOptions, MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices,
+        ModuleOps,
     },
     TensorMetadata,
 };
@@ -406,4 +407,53 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
 
         TchTensor::new(tensor)
     }
+
+    fn grid_sample_2d(x: TchTensor, grid: TchTensor, options: GridSampleOptions) -> TchTensor {
+        let (mode, padding_mode) = grid_sampler_modes(&options);
+        let tensor = tch::Tensor::grid_sampler_2d(
+            &x.tensor,
+            &grid.tensor,
+            mode,
+            padding_mode,
+            options.align_corners,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn grid_sample_2d_backward(
+        x: TchTensor,
+        grid: TchTensor,
+        output_grad: TchTensor,
+        options: GridSampleOptions,
+    ) -> GridSample2dBackward<Self> {
+        let (mode, padding_mode) = grid_sampler_modes(&options);
+        let (x_grad, grid_grad) = tch::Tensor::grid_sampler_2d_backward(
+            &output_grad.tensor,
+            &x.tensor,
+            &grid.tensor,
+            mode,
+            padding_mode,
+            options.align_corners,
+            &[true, true],
+        );
+
+        GridSample2dBackward::new(TchTensor::new(x_grad), TchTensor::new(grid_grad))
+    }
+}
+
+/// The interpolation and padding modes of the torch grid sampler.
+fn grid_sampler_modes(options: &GridSampleOptions) -> (i64, i64) {
+    let mode = match options.mode {
+        InterpolateMode::Bilinear => 0,
+        InterpolateMode::Nearest => 1,
+        InterpolateMode::Bicubic => 2,
+    };
+    let padding_mode = match options.padding_mode {
+        GridSamplePaddingMode::Zeros => 0,
+        GridSamplePaddingMode::Border => 1,
+        GridSamplePaddingMode::Reflection => 2,
+    };
+
+    (mode, padding_mode)
 }
@@ -3,20 +3,20 @@ use std::ops::Range;
 use burn_tensor::{
     ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        QParams, QuantizationParametersPrimitive, QuantizationScheme, QuantizationType,
-        QuantizedBytes,
+        CalibrationRange, QParams, QuantizationParametersPrimitive, QuantizationScheme,
+        QuantizationType, QuantizedBytes,
     },
-    DType, Shape, TensorData, TensorMetadata,
+    DType, Shape, Tensor, TensorData, TensorMetadata, TensorPrimitive,
 };
 
 use crate::{LibTorch, LibTorchDevice, QuantElement, TchElement, TchQTensor, TchShape, TchTensor};
 
 use super::TchOps;
 
-fn quantize<E: TchElement, Q: QuantElement>(
+fn quantize(
     tensor: tch::Tensor,
     scheme: &QuantizationScheme,
-    qparams: &QParams<E, Q>,
+    qparams: &QParams<Vec<f32>, Vec<i8>>,
 ) -> tch::Tensor {
     let mut tensor = tensor;
     // Quantize only works on Float Tensor
@@ -24,18 +24,65 @@ fn quantize<E: TchElement, Q: QuantElement>(
         tensor = tensor.to_kind(tch::Kind::Float);
     }
 
+    let offset = |qparams: &QParams<Vec<f32>, Vec<i8>>| -> Vec<i64> {
+        match &qparams.offset {
+            Some(offset) => offset.iter().map(|x| *x as i64).collect(),
+            None => vec![0; qparams.scale.len()],
+        }
+    };
+
     match scheme {
-        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => tensor.quantize_per_tensor(
-            qparams.scale.elem(),
-            qparams.offset.unwrap().elem(),
-            tch::Kind::QInt8,
-        ),
-        QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-            tensor.quantize_per_tensor(qparams.scale.elem(), 0, tch::Kind::QInt8)
+        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
+        | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => tensor
+            .quantize_per_tensor(
+                qparams.scale[0] as f64,
+                offset(qparams)[0],
+                tch::Kind::QInt8,
+            ),
+        QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, axis)
+        | QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, axis) => {
+            let device = tensor.device();
+            tensor.quantize_per_channel(
+                &tch::Tensor::from_slice(&qparams.scale).to(device),
+                &tch::Tensor::from_slice(&offset(qparams)).to(device),
+                *axis as i64,
+                tch::Kind::QInt8,
+            )
         }
+        QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+            unsupported_block_quantization()
+        }
+        _ => unsupported_quantization_type(scheme.q_type()),
     }
 }
 
+fn unsupported_block_quantization() -> ! {
+    panic!("LibTorch backend does not support block-wise quantization")
+}
+
+fn unsupported_quantization_type(dtype: QuantizationType) -> ! {
+    panic!("LibTorch backend does not support {dtype:?} quantization")
+}
+
+/// Updates the quantization scheme to reflect the channel axis of the quantized tensor, which
+/// can be moved by layout operations.
+fn with_channel_axis(mut tensor: TchQTensor) -> TchQTensor {
+    tensor.scheme = match tensor.scheme {
+        QuantizationScheme::PerChannelAffine(dtype, _) => QuantizationScheme::PerChannelAffine(
+            dtype,
+            tensor.qtensor.tensor.q_per_channel_axis() as usize,
+        ),
+        QuantizationScheme::PerChannelSymmetric(dtype, _) => {
+            QuantizationScheme::PerChannelSymmetric(
+                dtype,
+                tensor.qtensor.tensor.q_per_channel_axis() as usize,
+            )
+        }
+        scheme => scheme,
+    };
+    tensor
+}
+
 impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
     fn q_from_data(data: TensorData, device: &LibTorchDevice) -> QuantizedTensor<Self> {
         let shape_tch = TchShape::from(data.shape.as_slice());
@@ -48,13 +95,14 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         match data.dtype {
             DType::QFloat(scheme) => {
                 let num_elements = data.num_elements();
+                let data_shape = data.shape.clone();
                 let q_bytes = QuantizedBytes {
                     bytes: data.into_bytes(),
                     scheme,
                     num_elements,
                 };
 
-                let (values, qparams) = q_bytes.dequantize();
+                let (values, qparams) = q_bytes.dequantize(&data_shape);
                 let tensor = tch::Tensor::from_slice(&values).to(device);
                 let tensor = quantize(tensor.reshape(shape_tch.dims), &scheme, &qparams);
 
@@ -81,20 +129,33 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
             tensor.tensor = tensor.tensor.to_kind(tch::Kind::Float);
         }
 
+        let offset = match qparams.offset {
+            Some(offset) => offset.tensor,
+            None => tch::Tensor::zeros_like(&qparams.scale.tensor).to_kind(tch::Kind::Int64),
+        };
+
         let qtensor = match scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
                 QuantizationType::QInt8 => tensor.tensor.quantize_per_tensor_tensor_qparams(
                     &qparams.scale.tensor,
-                    &qparams.offset.unwrap().tensor,
+                    &offset,
                     tch::Kind::QInt8,
                 ),
+                dtype => unsupported_quantization_type(*dtype),
             },
-            QuantizationScheme::PerTensorSymmetric(_) => {
-                tensor.tensor.quantize_per_tensor_tensor_qparams(
-                    &qparams.scale.tensor,
-                    &tch::Tensor::zeros_like(&qparams.scale.tensor),
+            QuantizationScheme::PerChannelAffine(dtype, axis)
+            | QuantizationScheme::PerChannelSymmetric(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => tensor.tensor.quantize_per_channel(
+                    &qparams.scale.tensor.to_kind(tch::Kind::Double),
+                    &offset,
+                    *axis as i64,
                     tch::Kind::QInt8,
-                )
+                ),
+                dtype => unsupported_quantization_type(*dtype),
+            },
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+                unsupported_block_quantization()
             }
         };
 
@@ -116,6 +177,7 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                 QuantizationType::QInt8 => tensor
                     .tensor
                     .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
+                dtype => unsupported_quantization_type(*dtype),
             },
             QuantizationScheme::PerTensorSymmetric(dtype) => {
                 log::warn!("LibTorch backend does not support symmetric per-tensor scheme for dynamic quantization, reverting to the default per-tensor affine quantization");
@@ -123,8 +185,34 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                     QuantizationType::QInt8 => tensor
                         .tensor
                         .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
+                    dtype => unsupported_quantization_type(*dtype),
                 }
             }
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => {
+                // LibTorch has no dynamic per-channel quantization, so we compute the range of each
+                // channel before quantizing
+                let channels = tensor.tensor.size()[*axis];
+                let values = tensor
+                    .tensor
+                    .transpose(0, *axis as i64)
+                    .reshape([channels, -1]);
+                let (min, _) = values.min_dim(1, false);
+                let (max, _) = values.max_dim(1, false);
+                let range = CalibrationRange {
+                    min: Tensor::<Self, 1>::from_primitive(TensorPrimitive::Float(TchTensor::new(
+                        min,
+                    ))),
+                    max: Tensor::<Self, 1>::from_primitive(TensorPrimitive::Float(TchTensor::new(
+                        max,
+                    ))),
+                };
+                let qparams = scheme.compute_q_params(range);
+                return Self::quantize(tensor, scheme, qparams.into());
+            }
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+                unsupported_block_quantization()
+            }
         };
 
         TchQTensor {
@@ -159,11 +247,15 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
 
     async fn q_into_data(tensor: QuantizedTensor<Self>) -> TensorData {
         let shape = tensor.shape();
-        let tensor = Self::q_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
         let strategy = tensor.strategy();
 
         // To get the integer values we have to call `int_repr()`
-        let values: Result<Vec<i8>, tch::TchError> = tensor.qtensor.tensor.int_repr().try_into();
+        let values: Result<Vec<i8>, tch::TchError> = tensor
+            .qtensor
+            .tensor
+            .int_repr()
+            .reshape([shape.num_elements() as i64])
+            .try_into();
 
         TensorData::quantized(values.unwrap(), shape, strategy)
     }
@@ -173,17 +265,15 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         dim1: usize,
         dim2: usize,
     ) -> QuantizedTensor<Self> {
-        // NOTE: with per-channel quantization (future), the channel axis could be impacted by this op
         let mut tensor = tensor;
         tensor.qtensor = TchOps::swap_dims(tensor.qtensor, dim1, dim2);
-        tensor
+        with_channel_axis(tensor)
     }
 
     fn q_permute(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
-        // NOTE: with per-channel quantization (future), the channel axis could be impacted by this op
         let mut tensor = tensor;
         tensor.qtensor = TchOps::permute(tensor.qtensor, axes);
-        tensor
+        with_channel_axis(tensor)
     }
 
     fn q_flip(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
@@ -281,11 +371,10 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
     }
 
     fn q_expand(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
-        // NOTE: with per-channel quantization (future), the channel axis could be impacted by this op
-        TchQTensor {
+        with_channel_axis(TchQTensor {
             qtensor: TchOps::expand(tensor.qtensor, shape),
             scheme: tensor.scheme,
-        }
+        })
     }
 
     fn q_sort(
@@ -307,6 +307,33 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         TchOps::prod_dim(tensor, dim)
     }
 
+    fn float_cumsum(tensor: TchTensor, dim: usize, exclusive: bool, reverse: bool) -> TchTensor {
+        TchOps::cumsum(tensor, dim, exclusive, reverse)
+    }
+
+    fn float_cumprod(tensor: TchTensor, dim: usize, exclusive: bool, reverse: bool) -> TchTensor {
+        TchOps::cumprod(tensor, dim, exclusive, reverse)
+    }
+
+    fn float_cummax(tensor: TchTensor, dim: usize, reverse: bool) -> TchTensor {
+        TchOps::cummax(tensor, dim, reverse)
+    }
+
+    fn float_cummin(tensor: TchTensor, dim: usize, reverse: bool) -> TchTensor {
+        TchOps::cummin(tensor, dim, reverse)
+    }
+
+    fn float_fft(tensor: TchTensor, dim: usize, inverse: bool) -> TchTensor {
+        let complex = tensor.tensor.contiguous().view_as_complex();
+        let output = if inverse {
+            complex.fft_ifft(None, dim as i64, "backward")
+        } else {
+            complex.fft_fft(None, dim as i64, "backward")
+        };
+
+        TchTensor::new(output.view_as_real())
+    }
+
     fn float_argmax(tensor: TchTensor, dim: usize) -> TchTensor {
         TchOps::argmax(tensor, dim)
     }
@@ -330,25 +330,64 @@ pub struct TchQTensor {
 impl TchQTensor {
     /// Returns the quantization strategy, including quantization parameters, for the given tensor.
     pub fn strategy(&self) -> QuantizationStrategy {
+        let tensor = &self.qtensor.tensor;
+        let per_channel_qparams = || {
+            let scale: Vec<f64> = tensor.q_per_channel_scales().try_into().unwrap();
+            let offset: Vec<i64> = tensor.q_per_channel_zero_points().try_into().unwrap();
+            (
+                scale.into_iter().map(|x| x as f32).collect::<Vec<_>>(),
+                offset.into_iter().map(|x| x as i8).collect::<Vec<_>>(),
+            )
+        };
+
         match &self.scheme {
             QuantizationScheme::PerTensorAffine(dtype) => match dtype {
                 QuantizationType::QInt8 => {
-                    let scale = self.qtensor.tensor.q_scale();
-                    let offset = self.qtensor.tensor.q_zero_point();
+                    let scale = tensor.q_scale();
+                    let offset = tensor.q_zero_point();
                     QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
                         scale as f32,
                         offset as i8,
                     ))
                 }
+                dtype => panic!("LibTorch backend does not support {dtype:?} quantization"),
             },
             QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
                 QuantizationType::QInt8 => {
-                    let scale = self.qtensor.tensor.q_scale();
+                    let scale = tensor.q_scale();
                     QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
                         scale as f32,
                     ))
                 }
+                dtype => panic!("LibTorch backend does not support {dtype:?} quantization"),
+            },
+            QuantizationScheme::PerChannelAffine(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => {
+                    let (scale, offset) = per_channel_qparams();
+                    QuantizationStrategy::PerChannelAffineInt8(
+                        scale
+                            .into_iter()
+                            .zip(offset)
+                            .map(|(scale, offset)| AffineQuantization::init(scale, offset))
+                            .collect(),
+                        *axis,
+                    )
+                }
+                dtype => panic!("LibTorch backend does not support {dtype:?} quantization"),
             },
+            QuantizationScheme::PerChannelSymmetric(dtype, axis) => match dtype {
+                QuantizationType::QInt8 => {
+                    let (scale, _) = per_channel_qparams();
+                    QuantizationStrategy::PerChannelSymmetricInt8(
+                        scale.into_iter().map(SymmetricQuantization::init).collect(),
+                        *axis,
+                    )
+                }
+                dtype => panic!("LibTorch backend does not support {dtype:?} quantization"),
+            },
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+                panic!("LibTorch backend does not support block-wise quantization")
+            }
         }
     }
 }
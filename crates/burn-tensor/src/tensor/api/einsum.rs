@@ -0,0 +1,567 @@
+use crate::{
+    backend::Backend, ops::FloatTensor, Shape, Tensor, TensorData, TensorMetadata, TensorPrimitive,
+};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// An operand of [einsum], which can be a float tensor of any rank.
+#[derive(Clone, Debug)]
+pub struct EinsumOperand<B: Backend> {
+    tensor: FloatTensor<B>,
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for EinsumOperand<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        Self {
+            tensor: tensor.into_primitive().tensor(),
+        }
+    }
+}
+
+/// Evaluates the Einstein summation convention on the operands.
+///
+/// The equation lists the subscripts of every operand separated by commas, optionally followed by
+/// `->` and the subscripts of the output, e.g. `"bhqd,bhkd->bhqk"`. Subscripts are ASCII letters,
+/// and `...` stands for the broadcast dimensions. Without an explicit output, the output
+/// subscripts are the broadcast dimensions followed by the subscripts appearing exactly once, in
+/// alphabetical order.
+///
+/// Subscripts missing from the output are summed over, and a subscript repeated within an operand
+/// takes its diagonal. Operands are contracted two at a time with matrix multiplications, in the
+/// order that greedily minimizes the size of the intermediate results.
+///
+/// # Arguments
+///
+/// * `equation` - The einsum equation.
+/// * `operands` - The operands, one per term of the equation.
+///
+/// # Returns
+///
+/// The output tensor. An output without subscripts has a shape of `[1]`.
+///
+/// # Panics
+///
+/// If the equation is invalid, doesn't match the rank of the operands or of the output, or if a
+/// subscript has incompatible sizes.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let query = Tensor::<B, 4>::ones([2, 8, 16, 64], &device);
+///     let key = Tensor::<B, 4>::ones([2, 8, 32, 64], &device);
+///
+///     // Attention scores of shape [2, 8, 16, 32].
+///     let scores = Tensor::<B, 4>::einsum("bhqd,bhkd->bhqk", vec![query.into(), key.into()]);
+/// }
+/// ```
+pub fn einsum<B: Backend, const D: usize>(
+    equation: &str,
+    operands: Vec<EinsumOperand<B>>,
+) -> Tensor<B, D> {
+    let tensors = operands
+        .into_iter()
+        .map(|operand| operand.tensor)
+        .collect::<Vec<_>>();
+    let equation = Equation::parse(equation, &ranks::<B>(&tensors));
+    assert_eq!(
+        usize::max(equation.output.len(), 1),
+        D,
+        "Invalid einsum equation: the output has {} dimensions, but the rank {D} was requested",
+        equation.output.len()
+    );
+
+    Tensor::new(TensorPrimitive::Float(evaluate::<B>(equation, tensors)))
+}
+
+/// Evaluates the Einstein summation convention on float tensor primitives, for when the rank of
+/// the output is only known at runtime.
+///
+/// See [einsum] for the syntax of the equation.
+pub fn einsum_primitive<B: Backend>(
+    equation: &str,
+    operands: Vec<FloatTensor<B>>,
+) -> FloatTensor<B> {
+    let equation = Equation::parse(equation, &ranks::<B>(&operands));
+    evaluate::<B>(equation, operands)
+}
+
+fn ranks<B: Backend>(tensors: &[FloatTensor<B>]) -> Vec<usize> {
+    tensors
+        .iter()
+        .map(|tensor| tensor.shape().num_dims())
+        .collect()
+}
+
+fn evaluate<B: Backend>(equation: Equation, operands: Vec<FloatTensor<B>>) -> FloatTensor<B> {
+    let sizes = equation.sizes::<B>(&operands);
+    let terms = operands
+        .into_iter()
+        .zip(equation.inputs)
+        .map(|(tensor, labels)| Term::<B>::new(tensor, labels).broadcast(&sizes))
+        .map(Term::diagonal)
+        .collect::<Vec<_>>();
+
+    // Labels appearing in a single operand and not in the output can be summed right away.
+    let needed = (0..terms.len())
+        .map(|i| needed_labels(&terms, &equation.output, &[i]))
+        .collect::<Vec<_>>();
+    let mut terms = terms
+        .into_iter()
+        .zip(needed)
+        .map(|(term, needed)| term.keep(&needed))
+        .collect::<Vec<_>>();
+
+    while terms.len() > 1 {
+        let (i, j) = next_contraction(&terms, &equation.output, &sizes);
+        let needed = needed_labels(&terms, &equation.output, &[i, j]);
+        let rhs = terms.remove(j);
+        let lhs = terms.remove(i);
+        terms.push(lhs.contract(rhs, &needed));
+    }
+
+    let term = terms.pop().expect("Einsum requires at least one operand");
+    term.keep(&equation.output).permute(&equation.output).tensor
+}
+
+/// The labels of every dimension of the operands and of the output, with the broadcast dimensions
+/// replaced by generated labels.
+#[derive(Debug, PartialEq)]
+struct Equation {
+    inputs: Vec<Vec<char>>,
+    output: Vec<char>,
+}
+
+/// A parsed term, with the labels before and after the ellipsis if there is one.
+struct ParsedTerm {
+    before: Vec<char>,
+    after: Option<Vec<char>>,
+}
+
+/// Label of a broadcast dimension, taken from a private use area so it can't clash with letters.
+fn broadcast_label(index: usize) -> char {
+    char::from_u32(0xE000 + index as u32).unwrap()
+}
+
+impl ParsedTerm {
+    fn parse(term: &str) -> Self {
+        let parse_labels = |labels: &str| {
+            labels
+                .chars()
+                .inspect(|label| {
+                    assert!(
+                        label.is_ascii_alphabetic(),
+                        "Invalid einsum equation: unexpected character '{label}' in '{term}'"
+                    );
+                })
+                .collect::<Vec<_>>()
+        };
+
+        match term.split_once("...") {
+            Some((before, after)) => Self {
+                before: parse_labels(before),
+                after: Some(parse_labels(after)),
+            },
+            None => Self {
+                before: parse_labels(term),
+                after: None,
+            },
+        }
+    }
+
+    fn num_labels(&self) -> usize {
+        self.before.len() + self.after.as_ref().map(Vec::len).unwrap_or(0)
+    }
+
+    /// The labels of the term with `num_broadcast` broadcast dimensions, aligned to the right of
+    /// the `total_broadcast` broadcast dimensions of the equation.
+    fn labels(&self, num_broadcast: usize, total_broadcast: usize) -> Vec<char> {
+        let mut labels = self.before.clone();
+
+        if let Some(after) = &self.after {
+            labels.extend((total_broadcast - num_broadcast..total_broadcast).map(broadcast_label));
+            labels.extend(after);
+        }
+
+        labels
+    }
+}
+
+impl Equation {
+    fn parse(equation: &str, ranks: &[usize]) -> Self {
+        let equation = equation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<alloc::string::String>();
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(ParsedTerm::parse(output))),
+            None => (equation.as_str(), None),
+        };
+
+        let inputs = inputs.split(',').map(ParsedTerm::parse).collect::<Vec<_>>();
+        assert_eq!(
+            inputs.len(),
+            ranks.len(),
+            "Invalid einsum equation: {} terms for {} operands",
+            inputs.len(),
+            ranks.len()
+        );
+
+        let num_broadcasts = inputs
+            .iter()
+            .zip(ranks)
+            .map(|(term, rank)| {
+                let num_labels = term.num_labels();
+                let num_broadcast = match term.after {
+                    Some(_) => rank.checked_sub(num_labels),
+                    None => (*rank == num_labels).then_some(0),
+                };
+
+                num_broadcast.unwrap_or_else(|| {
+                    panic!(
+                        "Invalid einsum equation: {num_labels} subscripts for an operand of rank {rank}"
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        let total_broadcast = num_broadcasts.iter().copied().max().unwrap_or(0);
+
+        let inputs = inputs
+            .iter()
+            .zip(num_broadcasts)
+            .map(|(term, num_broadcast)| term.labels(num_broadcast, total_broadcast))
+            .collect::<Vec<_>>();
+
+        let output = match output {
+            Some(output) => output.labels(total_broadcast, total_broadcast),
+            None => {
+                let mut counts = BTreeMap::<char, usize>::new();
+                for label in inputs.iter().flatten() {
+                    *counts.entry(*label).or_default() += 1;
+                }
+
+                // Broadcast labels sort after letters, so they're put first explicitly.
+                (0..total_broadcast)
+                    .map(broadcast_label)
+                    .chain(
+                        counts
+                            .into_iter()
+                            .filter(|(label, count)| *count == 1 && label.is_ascii_alphabetic())
+                            .map(|(label, _)| label),
+                    )
+                    .collect()
+            }
+        };
+
+        for (i, label) in output.iter().enumerate() {
+            assert!(
+                !output[..i].contains(label),
+                "Invalid einsum equation: the output subscript '{label}' is repeated"
+            );
+            assert!(
+                inputs.iter().any(|labels| labels.contains(label)),
+                "Invalid einsum equation: the output subscript '{label}' is not in any operand"
+            );
+        }
+
+        Self { inputs, output }
+    }
+
+    /// The size of every label, where sizes of 1 are broadcast.
+    fn sizes<B: Backend>(&self, operands: &[FloatTensor<B>]) -> BTreeMap<char, usize> {
+        let mut sizes = BTreeMap::new();
+
+        for (labels, operand) in self.inputs.iter().zip(operands) {
+            for (label, size) in labels.iter().zip(operand.shape().dims) {
+                let current = sizes.entry(*label).or_insert(size);
+
+                if *current == 1 {
+                    *current = size;
+                } else if size != 1 && size != *current {
+                    panic!(
+                        "Invalid einsum operands: the subscript '{label}' has sizes {current} and {size}"
+                    );
+                }
+            }
+        }
+
+        sizes
+    }
+}
+
+/// The labels of the terms, except the ones at `excluded`, and of the output.
+fn needed_labels<B: Backend>(terms: &[Term<B>], output: &[char], excluded: &[usize]) -> Vec<char> {
+    let mut labels = output.to_vec();
+
+    for (i, term) in terms.iter().enumerate() {
+        if !excluded.contains(&i) {
+            labels.extend(&term.labels);
+        }
+    }
+
+    labels
+}
+
+/// Selects the pair of terms whose contraction has the smallest output.
+fn next_contraction<B: Backend>(
+    terms: &[Term<B>],
+    output: &[char],
+    sizes: &BTreeMap<char, usize>,
+) -> (usize, usize) {
+    let mut best = (0, 1);
+    let mut best_size = usize::MAX;
+
+    for i in 0..terms.len() {
+        for j in i + 1..terms.len() {
+            let needed = needed_labels(terms, output, &[i, j]);
+            let mut labels = terms[i].labels.clone();
+            for label in &terms[j].labels {
+                if !labels.contains(label) {
+                    labels.push(*label);
+                }
+            }
+
+            let size = labels
+                .iter()
+                .filter(|label| needed.contains(label))
+                .map(|label| sizes[label])
+                .product::<usize>();
+
+            if size < best_size {
+                best = (i, j);
+                best_size = size;
+            }
+        }
+    }
+
+    best
+}
+
+/// The shape of a term with the given dimensions.
+fn term_shape(dims: Vec<usize>) -> Shape {
+    match dims.is_empty() {
+        true => Shape::new([1]),
+        false => Shape::from(dims),
+    }
+}
+
+/// An operand with the label of each of its dimensions. A term without labels holds a single
+/// value with a shape of `[1]`, since tensors can't have zero dimensions.
+struct Term<B: Backend> {
+    tensor: FloatTensor<B>,
+    labels: Vec<char>,
+}
+
+impl<B: Backend> Term<B> {
+    fn new(tensor: FloatTensor<B>, labels: Vec<char>) -> Self {
+        Self { tensor, labels }
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        self.tensor.shape().dims
+    }
+
+    /// Expands the dimensions of size 1 that are broadcast to a larger size.
+    fn broadcast(self, sizes: &BTreeMap<char, usize>) -> Self {
+        let dims = self.dims();
+        let target = self
+            .labels
+            .iter()
+            .map(|label| sizes[label])
+            .collect::<Vec<_>>();
+
+        if dims == target {
+            return self;
+        }
+
+        let tensor = B::float_expand(self.tensor, Shape::from(target));
+        Self::new(tensor, self.labels)
+    }
+
+    /// Takes the diagonal of the dimensions sharing the same label.
+    fn diagonal(mut self) -> Self {
+        while let Some((first, second)) = self.repeated_label() {
+            let dims = self.dims();
+            let size = dims[first];
+            let dtype = self.tensor.dtype();
+            let device = B::float_device(&self.tensor);
+
+            let mut eye = vec![0.0f32; size * size];
+            for i in 0..size {
+                eye[i * size + i] = 1.0;
+            }
+            let mut eye_shape = vec![1; dims.len()];
+            eye_shape[first] = size;
+            eye_shape[second] = size;
+            let eye = B::float_from_data(
+                TensorData::new(eye, eye_shape).convert_dtype(dtype),
+                &device,
+            );
+
+            let tensor = B::float_sum_dim(B::float_mul(self.tensor, eye), second);
+            self = Self::new(tensor, self.labels).remove_dim(second);
+        }
+
+        self
+    }
+
+    fn repeated_label(&self) -> Option<(usize, usize)> {
+        self.labels.iter().enumerate().find_map(|(first, label)| {
+            self.labels[first + 1..]
+                .iter()
+                .position(|other| other == label)
+                .map(|offset| (first, first + 1 + offset))
+        })
+    }
+
+    /// Removes a dimension of size 1.
+    fn remove_dim(mut self, dim: usize) -> Self {
+        let mut dims = self.dims();
+        dims.remove(dim);
+        self.labels.remove(dim);
+
+        Self::new(B::float_reshape(self.tensor, term_shape(dims)), self.labels)
+    }
+
+    /// Sums the dimensions whose labels aren't needed.
+    fn keep(mut self, needed: &[char]) -> Self {
+        for dim in (0..self.labels.len()).rev() {
+            if !needed.contains(&self.labels[dim]) {
+                self.tensor = B::float_sum_dim(self.tensor, dim);
+                self = self.remove_dim(dim);
+            }
+        }
+
+        self
+    }
+
+    fn permute(self, labels: &[char]) -> Self {
+        let axes = labels
+            .iter()
+            .map(|label| self.labels.iter().position(|other| other == label).unwrap())
+            .collect::<Vec<_>>();
+
+        if axes.iter().enumerate().all(|(i, axis)| i == *axis) {
+            return Self::new(self.tensor, labels.to_vec());
+        }
+
+        Self::new(B::float_permute(self.tensor, &axes), labels.to_vec())
+    }
+
+    /// Permutes the dimensions to the groups of labels, and merges each group into a single
+    /// dimension.
+    fn grouped(self, groups: [&[char]; 3]) -> FloatTensor<B> {
+        let labels = groups.concat();
+        let term = self.permute(&labels);
+        let dims = term.dims();
+
+        let mut start = 0;
+        let shape = groups.map(|group| {
+            let size = dims[start..start + group.len()].iter().product::<usize>();
+            start += group.len();
+            size
+        });
+
+        B::float_reshape(term.tensor, Shape::new(shape))
+    }
+
+    /// Contracts two terms with a batched matrix multiplication, keeping the needed labels.
+    fn contract(self, rhs: Self, needed: &[char]) -> Self {
+        let lhs = self.keep(&[needed, rhs.labels.as_slice()].concat());
+        let rhs = rhs.keep(&[needed, lhs.labels.as_slice()].concat());
+
+        let mut batch = Vec::new();
+        let mut contracted = Vec::new();
+        let mut left = Vec::new();
+        for label in &lhs.labels {
+            if !rhs.labels.contains(label) {
+                left.push(*label);
+            } else if needed.contains(label) {
+                batch.push(*label);
+            } else {
+                contracted.push(*label);
+            }
+        }
+        let right = rhs
+            .labels
+            .iter()
+            .filter(|label| !lhs.labels.contains(label))
+            .copied()
+            .collect::<Vec<_>>();
+
+        let label_size = |term: &Self, label: &char| {
+            let dim = term.labels.iter().position(|other| other == label).unwrap();
+            term.dims()[dim]
+        };
+        let mut dims = batch
+            .iter()
+            .chain(&left)
+            .map(|label| label_size(&lhs, label))
+            .collect::<Vec<_>>();
+        dims.extend(right.iter().map(|label| label_size(&rhs, label)));
+
+        let lhs = lhs.grouped([&batch, &left, &contracted]);
+        let rhs = rhs.grouped([&batch, &contracted, &right]);
+        let tensor = B::float_reshape(B::float_matmul(lhs, rhs), term_shape(dims));
+
+        Self::new(tensor, [batch, left, right].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_explicit_output() {
+        let equation = Equation::parse("bhqd, bhkd -> bhqk", &[4, 4]);
+
+        assert_eq!(
+            equation,
+            Equation {
+                inputs: vec![vec!['b', 'h', 'q', 'd'], vec!['b', 'h', 'k', 'd']],
+                output: vec!['b', 'h', 'q', 'k'],
+            }
+        );
+    }
+
+    #[test]
+    fn should_parse_implicit_output() {
+        let equation = Equation::parse("ij,jk", &[2, 2]);
+
+        assert_eq!(equation.output, vec!['i', 'k']);
+    }
+
+    #[test]
+    fn should_parse_ellipsis() {
+        let equation = Equation::parse("...ij,j...->...i", &[4, 2]);
+
+        assert_eq!(
+            equation,
+            Equation {
+                inputs: vec![
+                    vec![broadcast_label(0), broadcast_label(1), 'i', 'j'],
+                    vec!['j', broadcast_label(1)],
+                ],
+                output: vec![broadcast_label(0), broadcast_label(1), 'i'],
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_rank_mismatch() {
+        Equation::parse("ij,jk->ik", &[2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_output_label_missing() {
+        Equation::parse("ij,jk->il", &[2, 2]);
+    }
+}
@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
+
 use crate::check::TensorCheck;
 use crate::quantization::{QuantizationParameters, QuantizationScheme};
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
-use crate::tensor::{Distribution, TensorData};
+use crate::tensor::{einsum, Distribution, EinsumOperand, TensorData};
 use crate::Tensor;
 use crate::{check, FloatDType};
 use crate::{Int, TensorPrimitive};
@@ -186,6 +188,29 @@ where
         )))
     }
 
+    /// Evaluates the Einstein summation convention on the operands.
+    ///
+    /// See [einsum](crate::einsum) for the syntax of the equation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let matrix = Tensor::<B, 2>::ones([3, 4], &device);
+    ///     let vector = Tensor::<B, 1>::ones([4], &device);
+    ///
+    ///     // Matrix-vector product of shape [3].
+    ///     let output = Tensor::<B, 1>::einsum("ij,j->i", vec![matrix.into(), vector.into()]);
+    /// }
+    /// ```
+    pub fn einsum(equation: &str, operands: Vec<EinsumOperand<B>>) -> Self {
+        einsum(equation, operands)
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
@@ -323,6 +348,33 @@ where
         )))
     }
 
+    /// Convert the tensor to a lower precision data type based on the quantization scheme and
+    /// parameters, and back to the floating point data type (also known as fake quantization).
+    ///
+    /// With an autodiff backend, the gradients of the values within the quantized range are passed
+    /// straight through, and the gradients of the scale are computed so it can be learned. This
+    /// is used to simulate the quantization error during quantization-aware training.
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - The quantization scheme.
+    /// * `qparams` - The pre-computed quantization parameters.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values rounded to the quantized grid.
+    pub fn fake_quantize(
+        self,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParameters<B>,
+    ) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(B::fake_quantize(
+            self.primitive.tensor(),
+            scheme,
+            qparams.into(),
+        )))
+    }
+
     /// Convert the tensor back to a higher precision data type.
     ///
     /// If the tensor is not quantized, its value is simply returned.
@@ -6,6 +6,7 @@ mod base;
 mod bool;
 mod cartesian_grid;
 mod chunk;
+mod einsum;
 mod float;
 mod int;
 mod kind;
@@ -20,6 +21,7 @@ pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use chunk::chunk;
+pub use einsum::{einsum, einsum_primitive, EinsumOperand};
 pub use kind::*;
 pub use narrow::narrow;
 pub use numeric::*;
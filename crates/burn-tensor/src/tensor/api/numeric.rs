@@ -594,6 +594,216 @@ where
         Self::new(K::prod_dim(self.primitive, dim))
     }
 
+    /// Accumulate the elements along the given *dimension* or *axis* in the tensor with the sum
+    /// operation, keeping the intermediate results.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cumsum(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -1.0, 2.0], [5.0, 14.0, 20.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        self.cumsum_with_options(dim, false, false)
+    }
+
+    /// Accumulate the elements along the given *dimension* or *axis* in the tensor with the sum
+    /// operation, keeping the intermediate results.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element,
+    ///   the accumulation starting at zero.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1>::from_data([1.0, 2.0, 3.0], &device);
+    ///    let tensor = tensor.cumsum_with_options(0, true, true);
+    ///    println!("{tensor}");
+    ///    // [5.0, 3.0, 0.0]
+    /// }
+    /// ```
+    pub fn cumsum_with_options(self, dim: usize, exclusive: bool, reverse: bool) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumSum", dim));
+        Self::new(K::cumsum(self.primitive, dim, exclusive, reverse))
+    }
+
+    /// Accumulate the elements along the given *dimension* or *axis* in the tensor with the
+    /// product operation, keeping the intermediate results.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cumprod(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -2.0, -6.0], [5.0, 45.0, 270.0]]
+    /// }
+    /// ```
+    pub fn cumprod(self, dim: usize) -> Self {
+        self.cumprod_with_options(dim, false, false)
+    }
+
+    /// Accumulate the elements along the given *dimension* or *axis* in the tensor with the
+    /// product operation, keeping the intermediate results.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element,
+    ///   the accumulation starting at one.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1>::from_data([2.0, 3.0, 4.0], &device);
+    ///    let tensor = tensor.cumprod_with_options(0, true, false);
+    ///    println!("{tensor}");
+    ///    // [1.0, 2.0, 6.0]
+    /// }
+    /// ```
+    pub fn cumprod_with_options(self, dim: usize, exclusive: bool, reverse: bool) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumProd", dim));
+        Self::new(K::cumprod(self.primitive, dim, exclusive, reverse))
+    }
+
+    /// Computes the running maximum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummax(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, 1.0, 3.0], [5.0, 9.0, 9.0]]
+    /// }
+    /// ```
+    pub fn cummax(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumMax", dim));
+        Self::new(K::cummax(self.primitive, dim, /*reverse*/ false))
+    }
+
+    /// Computes the running maximum of the elements along the given *dimension* or *axis*,
+    /// starting from the last element.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummax_reverse(1);
+    ///    println!("{tensor}");
+    ///    // [[3.0, 3.0, 3.0], [9.0, 9.0, 6.0]]
+    /// }
+    /// ```
+    pub fn cummax_reverse(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumMax", dim));
+        Self::new(K::cummax(self.primitive, dim, /*reverse*/ true))
+    }
+
+    /// Computes the running minimum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummin(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -2.0, -2.0], [5.0, 5.0, 5.0]]
+    /// }
+    /// ```
+    pub fn cummin(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumMin", dim));
+        Self::new(K::cummin(self.primitive, dim, /*reverse*/ false))
+    }
+
+    /// Computes the running minimum of the elements along the given *dimension* or *axis*,
+    /// starting from the last element.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummin_reverse(1);
+    ///    println!("{tensor}");
+    ///    // [[-2.0, -2.0, 3.0], [5.0, 6.0, 6.0]]
+    /// }
+    /// ```
+    pub fn cummin_reverse(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("CumMin", dim));
+        Self::new(K::cummin(self.primitive, dim, /*reverse*/ true))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Arguments
@@ -2628,6 +2838,110 @@ where
     ///
     fn prod_dim(tensor: Self::Primitive, dim: usize) -> Self::Primitive;
 
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative sum of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative sum of the elements of a tensor along a dimension, users should
+    /// prefer the [Tensor::cumsum_with_options](Tensor::cumsum_with_options) function,
+    /// which is more high-level and designed for public use.
+    fn cumsum(
+        tensor: Self::Primitive,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> Self::Primitive;
+
+    /// Computes the cumulative product of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative product of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative product of the elements of a tensor along a dimension, users should
+    /// prefer the [Tensor::cumprod_with_options](Tensor::cumprod_with_options) function,
+    /// which is more high-level and designed for public use.
+    fn cumprod(
+        tensor: Self::Primitive,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> Self::Primitive;
+
+    /// Computes the cumulative maximum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// The running maximum of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the running maximum of the elements of a tensor along a dimension, users should
+    /// prefer the [Tensor::cummax](Tensor::cummax) function,
+    /// which is more high-level and designed for public use.
+    fn cummax(tensor: Self::Primitive, dim: usize, reverse: bool) -> Self::Primitive;
+
+    /// Computes the cumulative minimum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// The running minimum of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the running minimum of the elements of a tensor along a dimension, users should
+    /// prefer the [Tensor::cummin](Tensor::cummin) function,
+    /// which is more high-level and designed for public use.
+    fn cummin(tensor: Self::Primitive, dim: usize, reverse: bool) -> Self::Primitive;
+
     /// Computes the mean of all the elements of the tensor.
     ///
     /// # Arguments
@@ -3519,6 +3833,32 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_prod_dim(tensor, dim)
     }
 
+    fn cumsum(
+        tensor: Self::Primitive,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> Self::Primitive {
+        B::int_cumsum(tensor, dim, exclusive, reverse)
+    }
+
+    fn cumprod(
+        tensor: Self::Primitive,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> Self::Primitive {
+        B::int_cumprod(tensor, dim, exclusive, reverse)
+    }
+
+    fn cummax(tensor: Self::Primitive, dim: usize, reverse: bool) -> Self::Primitive {
+        B::int_cummax(tensor, dim, reverse)
+    }
+
+    fn cummin(tensor: Self::Primitive, dim: usize, reverse: bool) -> Self::Primitive {
+        B::int_cummin(tensor, dim, reverse)
+    }
+
     fn mean(tensor: Self::Primitive) -> Self::Primitive {
         B::int_mean(tensor)
     }
@@ -3871,6 +4211,60 @@ impl<B: Backend> Numeric<B> for Float {
         }
     }
 
+    fn cumsum(
+        tensor: Self::Primitive,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_cumsum(tensor, dim, exclusive, reverse))
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                TensorPrimitive::QFloat(B::q_cumsum(tensor, dim, exclusive, reverse))
+            }
+        }
+    }
+
+    fn cumprod(
+        tensor: Self::Primitive,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_cumprod(tensor, dim, exclusive, reverse))
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                TensorPrimitive::QFloat(B::q_cumprod(tensor, dim, exclusive, reverse))
+            }
+        }
+    }
+
+    fn cummax(tensor: Self::Primitive, dim: usize, reverse: bool) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_cummax(tensor, dim, reverse))
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                TensorPrimitive::QFloat(B::q_cummax(tensor, dim, reverse))
+            }
+        }
+    }
+
+    fn cummin(tensor: Self::Primitive, dim: usize, reverse: bool) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_cummin(tensor, dim, reverse))
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                TensorPrimitive::QFloat(B::q_cummin(tensor, dim, reverse))
+            }
+        }
+    }
+
     fn mean(tensor: Self::Primitive) -> Self::Primitive {
         match tensor {
             TensorPrimitive::Float(tensor) => TensorPrimitive::Float(B::float_mean(tensor)),
@@ -8,9 +8,7 @@ use bytemuck::{checked::CheckedCastError, AnyBitPattern};
 use half::{bf16, f16};
 
 use crate::{
-    quantization::{
-        Quantization, QuantizationScheme, QuantizationStrategy, QuantizationType, QuantizedBytes,
-    },
+    quantization::{QuantizationStrategy, QuantizationType, QuantizedBytes},
     tensor::bytes::Bytes,
     DType, Distribution, Element, ElementConversion,
 };
@@ -225,10 +223,13 @@ impl TensorData {
                 ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool => Box::new(self.bytes.iter().map(|e| e.elem::<E>())),
-                DType::QFloat(scheme) => match scheme {
-                    QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                    | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                        // Quantized int8 values
+                DType::QFloat(scheme) => match scheme.q_type() {
+                    QuantizationType::QInt8
+                    | QuantizationType::QInt4
+                    | QuantizationType::QUInt4
+                    | QuantizationType::QInt2
+                    | QuantizationType::QUInt2 => {
+                        // Quantized values (sub-byte values are unpacked to a single value per `i8`)
                         let q_bytes = QuantizedBytes {
                             bytes: self.bytes.clone(),
                             scheme,
@@ -418,18 +419,8 @@ impl TensorData {
             DType::F32,
             "Only f32 data type can be quantized"
         );
-        match &quantization {
-            QuantizationStrategy::PerTensorAffineInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-            QuantizationStrategy::PerTensorSymmetricInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-        }
+        let values = quantization.quantize(self.as_slice().unwrap(), &self.shape);
+        TensorData::quantized(values, self.shape, quantization)
     }
 
     /// Dequantizes the data according to its quantization scheme.
@@ -442,7 +433,7 @@ impl TensorData {
                 num_elements,
             };
 
-            let values = q_bytes.dequantize().0;
+            let values = q_bytes.dequantize(&self.shape).0;
             Ok(Self::new(values, self.shape))
         } else {
             Err(DataError::TypeMismatch(format!(
@@ -511,16 +502,16 @@ impl TensorData {
                 } else {
                     panic!("Quantized data differs from other not quantized data")
                 };
-                match (q, q_other) {
-                    (
-                        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
-                        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
-                    )
-                    | (
-                        QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
-                        QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
-                    ) => self.assert_eq_elem::<i8>(other),
-                    _ => panic!("Quantization schemes differ ({:?} != {:?})", q, q_other),
+                if q != q_other {
+                    panic!("Quantization schemes differ ({:?} != {:?})", q, q_other)
+                }
+                match q.q_type() {
+                    // Compare the packed values and quantization parameters
+                    QuantizationType::QInt8
+                    | QuantizationType::QInt4
+                    | QuantizationType::QUInt4
+                    | QuantizationType::QInt2
+                    | QuantizationType::QUInt2 => self.assert_eq_elem::<i8>(other),
                 }
             }
         }
@@ -801,9 +792,12 @@ impl core::fmt::Display for TensorData {
             DType::U16 => format!("{:?}", self.as_slice::<u16>().unwrap()),
             DType::U8 => format!("{:?}", self.as_slice::<u8>().unwrap()),
             DType::Bool => format!("{:?}", self.as_slice::<bool>().unwrap()),
-            DType::QFloat(scheme) => match scheme {
-                QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
+            DType::QFloat(scheme) => match scheme.q_type() {
+                QuantizationType::QInt8
+                | QuantizationType::QInt4
+                | QuantizationType::QUInt4
+                | QuantizationType::QInt2
+                | QuantizationType::QUInt2 => {
                     format!("{:?} {scheme:?}", self.try_as_slice::<i8>().unwrap())
                 }
             },
@@ -838,7 +832,10 @@ fn compare_floats(value: f64, other: f64, ty: DType, tolerance: f64) -> Option<(
 
 #[cfg(test)]
 mod tests {
-    use crate::{quantization::AffineQuantization, Shape};
+    use crate::{
+        quantization::{AffineQuantization, QuantizationScheme, SymmetricQuantization},
+        Shape,
+    };
 
     use super::*;
     use alloc::vec;
@@ -977,4 +974,44 @@ mod tests {
 
         output.assert_approx_eq(&TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]), 4);
     }
+
+    #[test]
+    fn should_support_dequantize_per_channel() {
+        let data = TensorData::quantized(
+            vec![10i8, 2, 20, -4],
+            [2, 2],
+            QuantizationStrategy::PerChannelSymmetricInt8(
+                vec![
+                    SymmetricQuantization::init(0.1),
+                    SymmetricQuantization::init(0.5),
+                ],
+                1,
+            ),
+        );
+
+        let output = data.dequantize().unwrap();
+
+        output.assert_approx_eq(&TensorData::from([[1.0, 1.0], [2.0, -2.0]]), 4);
+    }
+
+    #[test]
+    fn should_support_quantize_dequantize_int4() {
+        let data = TensorData::from([[-1.0f32, 0.5], [0.25, 1.75]]);
+        let strategy = QuantizationStrategy::PerTensorSymmetricInt8(
+            SymmetricQuantization::init(0.25).with_q_type(QuantizationType::QInt4),
+        );
+
+        let q_data = data.with_quantization(strategy);
+        assert_eq!(
+            q_data.dtype,
+            DType::QFloat(QuantizationScheme::PerTensorSymmetric(
+                QuantizationType::QInt4
+            ))
+        );
+        assert_eq!(q_data.iter::<i8>().collect::<Vec<_>>(), vec![-4, 2, 1, 7]);
+
+        let output = q_data.dequantize().unwrap();
+
+        output.assert_approx_eq(&TensorData::from([[-1.0, 0.5], [0.25, 1.75]]), 4);
+    }
 }
@@ -303,11 +303,13 @@ impl DType {
             DType::U16 => core::mem::size_of::<u16>(),
             DType::U8 => core::mem::size_of::<u8>(),
             DType::Bool => core::mem::size_of::<bool>(),
-            DType::QFloat(scheme) => match scheme {
-                QuantizationScheme::PerTensorAffine(qtype)
-                | QuantizationScheme::PerTensorSymmetric(qtype) => match qtype {
-                    QuantizationType::QInt8 => core::mem::size_of::<i8>(),
-                },
+            DType::QFloat(scheme) => match scheme.q_type() {
+                // Quantized values are stored as `i8` (sub-byte values are only packed for storage)
+                QuantizationType::QInt8
+                | QuantizationType::QInt4
+                | QuantizationType::QUInt4
+                | QuantizationType::QInt2
+                | QuantizationType::QUInt2 => core::mem::size_of::<i8>(),
             },
         }
     }
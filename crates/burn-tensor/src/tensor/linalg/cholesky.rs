@@ -0,0 +1,46 @@
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::Tensor;
+
+use super::utils::{check_square, row_indices, slice_matrix};
+
+/// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`. Only the lower triangular part is read.
+///
+/// # Returns
+///
+/// The lower triangular matrices `L` of shape `[..., n, n]` such that `A = L L^T`.
+///
+/// # Notes
+///
+/// The factorization is computed column by column on top of the other tensor operations, so it
+/// runs on every backend and supports autodiff. Matrices that are not positive-definite produce
+/// non-finite values.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("Cholesky", &tensor);
+    let size = tensor.dims()[D - 1];
+    let rows = row_indices::<B, D>(size, &tensor.device());
+
+    let mut columns: Vec<Tensor<B, D>> = Vec::with_capacity(size);
+
+    for j in 0..size {
+        let mut column = slice_matrix(tensor.clone(), 0..size, j..j + 1);
+
+        if j > 0 {
+            let lower = Tensor::cat(columns.clone(), D - 1);
+            let row = slice_matrix(lower.clone(), j..j + 1, 0..j);
+            column = column.sub(lower.matmul(row.transpose()));
+        }
+
+        let diagonal = slice_matrix(column.clone(), j..j + 1, 0..1).sqrt();
+        let below = rows.clone().greater_equal_elem(j as f32).float();
+
+        columns.push(column.div(diagonal).mul(below));
+    }
+
+    Tensor::cat(columns, D - 1)
+}
@@ -0,0 +1,101 @@
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{Tensor, TensorPrimitive};
+
+use super::utils::{
+    batch_size, check_square, diagonal, identity, jacobi_rotation, off_diagonal_ratio,
+    rotation_matrix, slice_matrix,
+};
+
+/// The maximum number of Jacobi sweeps before giving up on convergence.
+pub(crate) const MAX_SWEEPS: usize = 32;
+
+/// The number of Jacobi sweeps between two convergence checks, each check reading a single value
+/// back from the device.
+pub(crate) const SWEEPS_PER_CHECK: usize = 4;
+
+/// Computes the eigenvalues and eigenvectors of symmetric matrices.
+///
+/// # Arguments
+///
+/// * `tensor` - The symmetric matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tuple `(values, vectors)` where `values` of shape `[..., 1, n]` holds the eigenvalues in
+/// ascending order and the columns of `vectors` of shape `[..., n, n]` are the matching
+/// orthonormal eigenvectors, so that `A = (vectors * values) vectors^T`.
+///
+/// # Notes
+///
+/// The decomposition is the [eigh](crate::ops::FloatTensorOps::float_eigh) backend operation,
+/// whose default implementation uses the cyclic Jacobi eigenvalue algorithm. The gradient is
+/// computed in closed form from the eigenvalues and eigenvectors, and is only defined for
+/// distinct eigenvalues.
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_square("Eigh", &tensor);
+    let dims = tensor.dims();
+    let size = dims[D - 1];
+
+    let matrices = tensor.reshape([batch_size(&dims), size, size]);
+    let (values, vectors) = B::float_eigh(matrices.into_primitive().tensor());
+
+    let mut values_dims = dims;
+    values_dims[D - 2] = 1;
+    let values = Tensor::<B, 3>::from_primitive(TensorPrimitive::Float(values));
+    let vectors = Tensor::<B, 3>::from_primitive(TensorPrimitive::Float(vectors));
+
+    (values.reshape(values_dims), vectors.reshape(dims))
+}
+
+/// Eigendecomposition of the symmetric matrices `[batch_size, n, n]` with the cyclic Jacobi
+/// eigenvalue algorithm, built on top of the other tensor operations so that it runs on every
+/// backend.
+pub(crate) fn eigh_with_jacobi<B: Backend>(
+    tensor: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let tensor = Tensor::<B, 3>::from_primitive(TensorPrimitive::Float(tensor));
+    let [_, size, _] = tensor.dims();
+
+    let mut matrix = tensor;
+    let mut vectors = identity::<B, 3>(size, &matrix.device()).expand(matrix.shape());
+    let mut previous = f64::INFINITY;
+
+    for sweep in 0..MAX_SWEEPS {
+        if sweep % SWEEPS_PER_CHECK == 0 {
+            let ratio = off_diagonal_ratio(matrix.clone());
+            // Stop once the matrix is diagonal or the rotations stop making progress.
+            if ratio == 0.0 || ratio >= previous {
+                break;
+            }
+            previous = ratio;
+        }
+
+        for p in 0..size {
+            for q in p + 1..size {
+                let (cosine, sine) = jacobi_rotation(
+                    slice_matrix(matrix.clone(), p..p + 1, p..p + 1),
+                    slice_matrix(matrix.clone(), q..q + 1, q..q + 1),
+                    slice_matrix(matrix.clone(), p..p + 1, q..q + 1),
+                );
+                let rotation = rotation_matrix(cosine, sine, p, q, size);
+
+                matrix = rotation
+                    .clone()
+                    .transpose()
+                    .matmul(matrix)
+                    .matmul(rotation.clone());
+                vectors = vectors.matmul(rotation);
+            }
+        }
+    }
+
+    let (values, indices) = diagonal(matrix).sort_with_indices(2);
+    let indices = indices.expand(vectors.shape());
+    let vectors = vectors.gather(2, indices);
+
+    (
+        values.into_primitive().tensor(),
+        vectors.into_primitive().tensor(),
+    )
+}
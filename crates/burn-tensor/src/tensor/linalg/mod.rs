@@ -0,0 +1,14 @@
+mod cholesky;
+mod eigh;
+mod norm;
+mod qr;
+mod solve;
+mod svd;
+mod utils;
+
+pub use cholesky::*;
+pub use eigh::*;
+pub use norm::*;
+pub use qr::*;
+pub use solve::*;
+pub use svd::*;
@@ -0,0 +1,92 @@
+use crate::backend::Backend;
+use crate::Tensor;
+
+use super::svd::svd;
+use super::utils::check_matrix;
+
+/// The order of a [vector norm](vector_norm).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VectorNorm {
+    /// The number of non-zero elements.
+    L0,
+    /// The sum of absolute values.
+    L1,
+    /// The euclidean norm.
+    L2,
+    /// The largest absolute value.
+    LInf,
+    /// The smallest absolute value.
+    LNegInf,
+    /// The p-norm `(sum |x|^p)^(1/p)`.
+    Lp(f64),
+}
+
+/// The kind of [matrix norm](matrix_norm).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixNorm {
+    /// The square root of the sum of squared elements.
+    Frobenius,
+    /// The sum of the singular values.
+    Nuclear,
+    /// The largest singular value.
+    Spectral,
+    /// The largest sum of absolute values over the columns.
+    L1,
+    /// The largest sum of absolute values over the rows.
+    LInf,
+}
+
+/// Computes the vector norm of a tensor along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `norm` - The order of the norm.
+/// * `dim` - The dimension along which the norm is computed.
+///
+/// # Returns
+///
+/// The norms, with the reduced dimension kept with a size of 1.
+pub fn vector_norm<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    norm: VectorNorm,
+    dim: usize,
+) -> Tensor<B, D> {
+    match norm {
+        VectorNorm::L0 => tensor.not_equal_elem(0.0).float().sum_dim(dim),
+        VectorNorm::L1 => tensor.abs().sum_dim(dim),
+        VectorNorm::L2 => tensor.powi_scalar(2).sum_dim(dim).sqrt(),
+        VectorNorm::LInf => tensor.abs().max_dim(dim),
+        VectorNorm::LNegInf => tensor.abs().min_dim(dim),
+        VectorNorm::Lp(p) => tensor
+            .abs()
+            .powf_scalar(p)
+            .sum_dim(dim)
+            .powf_scalar(1.0 / p),
+    }
+}
+
+/// Computes the matrix norm over the last two dimensions of a tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., m, n]`.
+/// * `norm` - The kind of norm.
+///
+/// # Returns
+///
+/// The norms of shape `[..., 1, 1]`.
+pub fn matrix_norm<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    norm: MatrixNorm,
+) -> Tensor<B, D> {
+    check_matrix::<D>("MatrixNorm");
+
+    match norm {
+        MatrixNorm::Frobenius => tensor.powi_scalar(2).sum_dim(D - 1).sum_dim(D - 2).sqrt(),
+        MatrixNorm::Nuclear => svd(tensor).1.sum_dim(D - 1),
+        MatrixNorm::Spectral => svd(tensor).1.max_dim(D - 1),
+        MatrixNorm::L1 => tensor.abs().sum_dim(D - 2).max_dim(D - 1),
+        MatrixNorm::LInf => tensor.abs().sum_dim(D - 1).max_dim(D - 2),
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::Tensor;
+
+use super::utils::{check_matrix, slice_matrix};
+
+/// Computes the reduced QR decomposition of matrices.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(q, r)` where `q` of shape `[..., m, k]` has orthonormal columns and `r` of shape
+/// `[..., k, n]` is upper triangular with a positive diagonal, with `k = min(m, n)` and
+/// `A = Q R`.
+///
+/// # Notes
+///
+/// The decomposition uses Gram-Schmidt orthogonalization with one reorthogonalization pass, built
+/// on top of the other tensor operations so that it runs on every backend and supports autodiff.
+/// The first `k` columns of the matrices must be linearly independent.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("QR");
+    let [m, n] = [tensor.dims()[D - 2], tensor.dims()[D - 1]];
+    let k = usize::min(m, n);
+
+    let mut q_columns: Vec<Tensor<B, D>> = Vec::with_capacity(k);
+    let mut r_columns: Vec<Tensor<B, D>> = Vec::with_capacity(n);
+
+    for j in 0..k {
+        let mut column = slice_matrix(tensor.clone(), 0..m, j..j + 1);
+        let mut r_column = Vec::with_capacity(2);
+
+        if j > 0 {
+            let q = Tensor::cat(q_columns.clone(), D - 1);
+            let mut projections = None;
+
+            // Orthogonalizing twice keeps the columns orthogonal to working precision.
+            for _ in 0..2 {
+                let projection = q.clone().transpose().matmul(column.clone());
+                column = column.sub(q.clone().matmul(projection.clone()));
+                projections = Some(match projections {
+                    Some(projections) => projection.add(projections),
+                    None => projection,
+                });
+            }
+
+            r_column.push(projections.unwrap());
+        }
+
+        let norm = column.clone().powi_scalar(2).sum_dim(D - 2).sqrt();
+        q_columns.push(column.div(norm.clone()));
+        r_column.push(norm.clone());
+
+        if j + 1 < k {
+            let mut shape = norm.dims();
+            shape[D - 2] = k - j - 1;
+            r_column.push(Tensor::zeros(shape, &norm.device()));
+        }
+
+        r_columns.push(Tensor::cat(r_column, D - 2));
+    }
+
+    let q = Tensor::cat(q_columns, D - 1);
+
+    if n > k {
+        let remaining = slice_matrix(tensor, 0..m, k..n);
+        r_columns.push(q.clone().transpose().matmul(remaining));
+    }
+
+    (q, Tensor::cat(r_columns, D - 1))
+}
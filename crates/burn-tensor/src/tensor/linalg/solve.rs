@@ -0,0 +1,206 @@
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::Tensor;
+
+use super::utils::{check_square, identity, one_hot_row, row_indices, slice_matrix};
+
+/// Result of the Gauss-Jordan elimination of a (batch of) square matrix.
+struct Elimination<B: Backend, const D: usize> {
+    /// The right-hand side after elimination, if one was given.
+    rhs: Option<Tensor<B, D>>,
+    /// The pivot used at each step, with shape `[..., 1, 1]`.
+    pivots: Vec<Tensor<B, D>>,
+    /// Whether rows were swapped at each step (1.0) or not (0.0), with shape `[..., 1, 1]`.
+    swaps: Vec<Tensor<B, D>>,
+}
+
+/// Gauss-Jordan elimination with partial pivoting.
+///
+/// Every step is expressed with differentiable tensor operations so that no data has to be read
+/// back from the device and gradients flow through the whole elimination.
+fn eliminate<B: Backend, const D: usize>(
+    matrix: Tensor<B, D>,
+    rhs: Option<Tensor<B, D>>,
+) -> Elimination<B, D> {
+    let size = matrix.dims()[D - 1];
+    let device = matrix.device();
+    let rows = row_indices::<B, D>(size, &device);
+
+    let mut matrix = matrix;
+    let mut rhs = rhs;
+    let mut pivots = Vec::with_capacity(size);
+    let mut swaps = Vec::with_capacity(size);
+
+    for k in 0..size {
+        let row_k = one_hot_row::<B, D>(k, size, &device);
+
+        // Select the row with the largest absolute value in the current column as pivot.
+        let pivot_index = slice_matrix(matrix.clone(), k..size, k..k + 1)
+            .abs()
+            .argmax(D - 2)
+            .float()
+            .add_scalar(k as f32);
+        let row_pivot = rows
+            .clone()
+            .sub(pivot_index.clone())
+            .equal_elem(0.0)
+            .float();
+
+        matrix = swap_rows(matrix, row_k.clone(), row_pivot.clone(), k);
+        rhs = rhs.map(|rhs| swap_rows(rhs, row_k.clone(), row_pivot.clone(), k));
+        swaps.push(pivot_index.sub_scalar(k as f32).clamp_max(1.0));
+
+        // Normalize the pivot row and eliminate the current column from every other row.
+        let pivot = slice_matrix(matrix.clone(), k..k + 1, k..k + 1);
+        let factors = slice_matrix(matrix.clone(), 0..size, k..k + 1).sub(row_k);
+
+        let normalized = select_row(matrix.clone(), k).div(pivot.clone());
+        matrix = matrix.sub(factors.clone().mul(normalized));
+
+        rhs = rhs.map(|rhs| {
+            let normalized = select_row(rhs.clone(), k).div(pivot.clone());
+            rhs.sub(factors.mul(normalized))
+        });
+
+        pivots.push(pivot);
+    }
+
+    Elimination { rhs, pivots, swaps }
+}
+
+fn select_row<B: Backend, const D: usize>(tensor: Tensor<B, D>, row: usize) -> Tensor<B, D> {
+    let cols = tensor.dims()[D - 1];
+    slice_matrix(tensor, row..row + 1, 0..cols)
+}
+
+/// Swaps row `k` with the row selected by the one-hot `row_pivot` mask.
+fn swap_rows<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    row_k: Tensor<B, D>,
+    row_pivot: Tensor<B, D>,
+    k: usize,
+) -> Tensor<B, D> {
+    let values_k = select_row(tensor.clone(), k);
+    let values_pivot = tensor.clone().mul(row_pivot.clone()).sum_dim(D - 2);
+
+    tensor
+        .sub(row_pivot.clone().mul(values_pivot.clone()))
+        .sub(row_k.clone().mul(values_k.clone()))
+        .add(row_pivot.mul(values_k))
+        .add(row_k.mul(values_pivot))
+}
+
+/// Solves the linear system `A X = B` for `X`.
+///
+/// # Arguments
+///
+/// * `a` - The coefficient matrices of shape `[..., n, n]`.
+/// * `b` - The right-hand sides of shape `[..., n, k]`.
+///
+/// # Returns
+///
+/// The solutions of shape `[..., n, k]`.
+///
+/// # Notes
+///
+/// The system is solved with Gauss-Jordan elimination and partial pivoting, built on top of the
+/// other tensor operations so that it runs on every backend and supports autodiff. Singular
+/// matrices produce non-finite values.
+pub fn solve<B: Backend, const D: usize>(a: Tensor<B, D>, b: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("Solve", &a);
+    assert_eq!(
+        a.dims()[D - 1],
+        b.dims()[D - 2],
+        "Solve: the right-hand side must have as many rows as the matrix"
+    );
+
+    eliminate(a, Some(b)).rhs.unwrap()
+}
+
+/// Computes the inverse of square matrices.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// The inverse matrices of shape `[..., n, n]`.
+///
+/// # Notes
+///
+/// Prefer [solve] when the inverse is only used to solve a linear system, since it is both faster
+/// and more accurate.
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("Inverse", &tensor);
+    let size = tensor.dims()[D - 1];
+    let identity = identity::<B, D>(size, &tensor.device());
+
+    eliminate(tensor, Some(identity)).rhs.unwrap()
+}
+
+/// Computes the determinant of square matrices.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// The determinants of shape `[..., 1, 1]`.
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check_square("Det", &tensor);
+    let elimination = eliminate(tensor, None);
+
+    elimination
+        .pivots
+        .into_iter()
+        .zip(elimination.swaps)
+        .map(|(pivot, swap)| pivot.mul(permutation_sign(swap)))
+        .reduce(|acc, value| acc.mul(value))
+        .unwrap()
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of square
+/// matrices.
+///
+/// This is more stable than [det] for large matrices, whose determinant easily overflows or
+/// underflows.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tuple `(sign, logabsdet)` of tensors of shape `[..., 1, 1]`, where the sign is `-1`, `0` or
+/// `1` and the determinant equals `sign * exp(logabsdet)`.
+pub fn slogdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check_square("Slogdet", &tensor);
+    let elimination = eliminate(tensor, None);
+
+    let mut sign = None;
+    let mut logabsdet = None;
+
+    for (pivot, swap) in elimination.pivots.into_iter().zip(elimination.swaps) {
+        let pivot_sign = pivot.clone().sign().mul(permutation_sign(swap));
+        let pivot_log = pivot.abs().log();
+
+        sign = Some(match sign {
+            Some(sign) => pivot_sign.mul(sign),
+            None => pivot_sign,
+        });
+        logabsdet = Some(match logabsdet {
+            Some(logabsdet) => pivot_log.add(logabsdet),
+            None => pivot_log,
+        });
+    }
+
+    (sign.unwrap(), logabsdet.unwrap())
+}
+
+/// Maps a row swap indicator (0 or 1) to the sign of the corresponding permutation (1 or -1).
+fn permutation_sign<B: Backend, const D: usize>(swap: Tensor<B, D>) -> Tensor<B, D> {
+    swap.mul_scalar(-2.0).add_scalar(1.0)
+}
@@ -0,0 +1,119 @@
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{Tensor, TensorPrimitive};
+
+use super::eigh::{MAX_SWEEPS, SWEEPS_PER_CHECK};
+use super::utils::{
+    batch_size, check_matrix, identity, jacobi_rotation, off_diagonal_ratio, rotation_matrix,
+    slice_matrix,
+};
+
+/// Computes the reduced singular value decomposition of matrices.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices of shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(u, s, vh)` where `u` of shape `[..., m, k]` and the transpose of `vh` of shape
+/// `[..., k, n]` have orthonormal columns, and `s` of shape `[..., 1, k]` holds the singular values
+/// in descending order, with `k = min(m, n)` and `A = (u * s) vh`.
+///
+/// # Notes
+///
+/// The decomposition is the [svd](crate::ops::FloatTensorOps::float_svd) backend operation, whose
+/// default implementation uses the one-sided Jacobi algorithm. The gradient is computed in closed
+/// form from the decomposition, and is only defined for distinct and non-zero singular values.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check_matrix::<D>("SVD");
+    let dims = tensor.dims();
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+    let k = usize::min(m, n);
+
+    let matrices = tensor.reshape([batch_size(&dims), m, n]);
+    let (u, s, vh) = B::float_svd(matrices.into_primitive().tensor());
+
+    let reshape = |tensor: FloatTensor<B>, rows: usize, cols: usize| {
+        let mut dims = dims;
+        dims[D - 2] = rows;
+        dims[D - 1] = cols;
+        Tensor::<B, 3>::from_primitive(TensorPrimitive::Float(tensor)).reshape(dims)
+    };
+
+    (reshape(u, m, k), reshape(s, 1, k), reshape(vh, k, n))
+}
+
+/// Reduced singular value decomposition of the matrices `[batch_size, m, n]` with the one-sided
+/// Jacobi algorithm, built on top of the other tensor operations so that it runs on every
+/// backend. The columns of `u` matching a singular value of exactly zero are zero.
+pub(crate) fn svd_with_jacobi<B: Backend>(
+    tensor: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>) {
+    let tensor = Tensor::<B, 3>::from_primitive(TensorPrimitive::Float(tensor));
+    let [_, m, n] = tensor.dims();
+
+    let (u, s, vh) = if m < n {
+        // A^T = U S V^T, so A = V S U^T.
+        let (u, s, vh) = svd_tall(tensor.transpose());
+        (vh.transpose(), s, u.transpose())
+    } else {
+        svd_tall(tensor)
+    };
+
+    (
+        u.into_primitive().tensor(),
+        s.into_primitive().tensor(),
+        vh.into_primitive().tensor(),
+    )
+}
+
+/// One-sided Jacobi SVD of matrices with at least as many rows as columns.
+fn svd_tall<B: Backend>(tensor: Tensor<B, 3>) -> (Tensor<B, 3>, Tensor<B, 3>, Tensor<B, 3>) {
+    let [batch_size, m, n] = tensor.dims();
+
+    let mut matrix = tensor;
+    let mut vectors = identity::<B, 3>(n, &matrix.device()).expand([batch_size, n, n]);
+    let mut previous = f64::INFINITY;
+
+    for sweep in 0..MAX_SWEEPS {
+        if sweep % SWEEPS_PER_CHECK == 0 {
+            // The columns are orthogonal once their Gram matrix is diagonal.
+            let gram = matrix.clone().transpose().matmul(matrix.clone());
+            let ratio = off_diagonal_ratio(gram);
+            if ratio == 0.0 || ratio >= previous {
+                break;
+            }
+            previous = ratio;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let column_p = slice_matrix(matrix.clone(), 0..m, p..p + 1);
+                let column_q = slice_matrix(matrix.clone(), 0..m, q..q + 1);
+
+                let (cosine, sine) = jacobi_rotation(
+                    column_p.clone().powi_scalar(2).sum_dim(1),
+                    column_q.clone().powi_scalar(2).sum_dim(1),
+                    column_p.mul(column_q).sum_dim(1),
+                );
+                let rotation = rotation_matrix(cosine, sine, p, q, n);
+
+                matrix = matrix.matmul(rotation.clone());
+                vectors = vectors.matmul(rotation);
+            }
+        }
+    }
+
+    let values = matrix.clone().powi_scalar(2).sum_dim(1).sqrt();
+    let is_zero = values.clone().equal_elem(0.0).float();
+    let u = matrix.div(values.clone().add(is_zero));
+
+    let (values, indices) = values.sort_descending_with_indices(2);
+    let u = u.gather(2, indices.clone().expand([batch_size, m, n]));
+    let vectors = vectors.gather(2, indices.expand([batch_size, n, n]));
+
+    (u, values, vectors.transpose())
+}
@@ -0,0 +1,170 @@
+use core::ops::Range;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::{Int, Tensor};
+
+/// Panics when the tensor is not a (batch of) matrix.
+pub(crate) fn check_matrix<const D: usize>(ops: &str) {
+    assert!(
+        D >= 2,
+        "{ops}: expected a tensor with at least 2 dimensions, got {D}"
+    );
+}
+
+/// Panics when the tensor is not a (batch of) square matrix.
+pub(crate) fn check_square<B: Backend, const D: usize>(ops: &str, tensor: &Tensor<B, D>) {
+    check_matrix::<D>(ops);
+    let dims = tensor.dims();
+    assert_eq!(
+        dims[D - 2],
+        dims[D - 1],
+        "{ops}: expected square matrices, got shape {dims:?}"
+    );
+}
+
+/// The number of matrices of a tensor with the given dimensions.
+pub(crate) fn batch_size<const D: usize>(dims: &[usize; D]) -> usize {
+    dims[..D - 2].iter().product()
+}
+
+/// Slices the rows and columns of the matrices, keeping every batch dimension.
+pub(crate) fn slice_matrix<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> Tensor<B, D> {
+    let mut ranges = tensor.dims().map(|dim| 0..dim);
+    ranges[D - 2] = rows;
+    ranges[D - 1] = cols;
+
+    tensor.slice(ranges)
+}
+
+/// Row indices `[0, size)` as a float tensor of shape `[1, ..., size, 1]`.
+pub(crate) fn row_indices<B: Backend, const D: usize>(
+    size: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let mut shape = [1; D];
+    shape[D - 2] = size;
+
+    Tensor::<B, 1, Int>::arange(0..size as i64, device)
+        .float()
+        .reshape(shape)
+}
+
+/// One-hot column vector of shape `[1, ..., size, 1]` selecting the given row.
+pub(crate) fn one_hot_row<B: Backend, const D: usize>(
+    index: usize,
+    size: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    row_indices::<B, D>(size, device)
+        .equal_elem(index as f32)
+        .float()
+}
+
+/// Identity matrix of shape `[1, ..., size, size]`, broadcastable over any batch.
+pub(crate) fn identity<B: Backend, const D: usize>(
+    size: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    Tensor::<B, 2>::eye(size, device).unsqueeze()
+}
+
+/// Extracts the diagonal of the matrices as row vectors of shape `[..., 1, n]`.
+pub(crate) fn diagonal<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let size = tensor.dims()[D - 1];
+    let identity = identity::<B, D>(size, &tensor.device());
+
+    tensor.mul(identity).sum_dim(D - 2)
+}
+
+/// Sum of the squared off-diagonal entries relative to the sum of all squared entries, over the
+/// whole batch. Both sums are read back from the device at once.
+pub(crate) fn off_diagonal_ratio<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> f64 {
+    let size = tensor.dims()[D - 1];
+    let identity = identity::<B, D>(size, &tensor.device());
+
+    let squared = tensor.powi_scalar(2);
+    let total = squared.clone().sum();
+    let off = squared.mul(identity.neg().add_scalar(1.0)).sum();
+    let sums = Tensor::cat(vec![off, total], 0)
+        .into_data()
+        .iter::<f64>()
+        .collect::<Vec<_>>();
+
+    if sums[1] == 0.0 {
+        0.0
+    } else {
+        sums[0] / sums[1]
+    }
+}
+
+/// Computes the cosine and sine of the Jacobi rotation zeroing the `(p, q)` entry of the symmetric
+/// matrix `[[a_pp, a_pq], [a_pq, a_qq]]`.
+///
+/// Every argument has the shape `[..., 1, 1]`.
+pub(crate) fn jacobi_rotation<B: Backend, const D: usize>(
+    a_pp: Tensor<B, D>,
+    a_qq: Tensor<B, D>,
+    a_pq: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let diff = a_qq.sub(a_pp);
+    // The sign of zero is one, so that equal diagonal entries still rotate by 45 degrees.
+    let sign = diff
+        .clone()
+        .greater_equal_elem(0.0)
+        .float()
+        .mul_scalar(2.0)
+        .sub_scalar(1.0);
+    let denominator = diff.clone().abs().add(
+        diff.powi_scalar(2)
+            .add(a_pq.clone().powi_scalar(2).mul_scalar(4.0))
+            .sqrt(),
+    );
+    // The denominator is only zero when the off-diagonal entry is zero, no rotation is needed.
+    let denominator_is_zero = denominator.clone().equal_elem(0.0).float();
+    let tangent = sign
+        .mul(a_pq)
+        .mul_scalar(2.0)
+        .div(denominator.add(denominator_is_zero));
+
+    let cosine = tangent
+        .clone()
+        .powi_scalar(2)
+        .add_scalar(1.0)
+        .sqrt()
+        .recip();
+    let sine = tangent.mul(cosine.clone());
+
+    (cosine, sine)
+}
+
+/// Builds the `[..., size, size]` Jacobi rotation matrix acting on rows and columns `p` and `q`.
+pub(crate) fn rotation_matrix<B: Backend, const D: usize>(
+    cosine: Tensor<B, D>,
+    sine: Tensor<B, D>,
+    p: usize,
+    q: usize,
+    size: usize,
+) -> Tensor<B, D> {
+    let device = cosine.device();
+    let row_p = one_hot_row::<B, D>(p, size, &device);
+    let row_q = one_hot_row::<B, D>(q, size, &device);
+    let col_p = row_p.clone().transpose();
+    let col_q = row_q.clone().transpose();
+
+    let diagonal = row_p
+        .clone()
+        .mul(col_p.clone())
+        .add(row_q.clone().mul(col_q.clone()));
+    let skew = row_p.mul(col_q).sub(row_q.mul(col_p));
+
+    identity::<B, D>(size, &device)
+        .add(diagonal.mul(cosine.sub_scalar(1.0)))
+        .add(skew.mul(sine))
+}
@@ -1,8 +1,12 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
-    Int, Tensor, TensorPrimitive,
+    ops::{
+        AttentionOptions, ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions,
+        UnfoldOptions,
+    },
+    Bool, Distribution, Int, Tensor, TensorData, TensorPrimitive,
 };
+use alloc::vec::Vec;
 
 use super::ops::DeformConvOptions;
 
@@ -316,3 +320,124 @@ where
         options,
     )))
 }
+
+/// Applies a [2D grid sampling](crate::ops::ModuleOps::grid_sample_2d).
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::grid_sample_2d(
+        x.primitive.tensor(),
+        grid.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Applies the [scaled dot-product attention](crate::ops::ModuleOps::scaled_dot_product_attention).
+///
+/// # Shapes
+///
+/// - query: `[batch_size, num_heads, seq_length_q, head_dim]`
+/// - key: `[batch_size, num_kv_heads, seq_length_k, head_dim]`
+/// - value: `[batch_size, num_kv_heads, seq_length_k, value_dim]`
+/// - padding_mask: `[batch_size, seq_length_k]`, true for the padded keys
+/// - attn_mask: `[batch_size, seq_length_q, seq_length_k]`, true for the masked keys
+/// - output: `[batch_size, num_heads, seq_length_q, value_dim]`
+///
+/// With dropout, a new [seed](AttentionOptions::dropout_seed) is drawn from the backend random
+/// number generator (see [Backend::seed]) for each call when the options don't set one.
+pub fn scaled_dot_product_attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    padding_mask: Option<Tensor<B, 2, Bool>>,
+    attn_mask: Option<Tensor<B, 3, Bool>>,
+    options: AttentionOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let options = match options.dropout > 0.0 && options.dropout_seed.is_none() {
+        true => AttentionOptions {
+            dropout_seed: Some(dropout_seed::<B>(&query.device())),
+            ..options
+        },
+        false => options,
+    };
+
+    Tensor::new(TensorPrimitive::Float(B::scaled_dot_product_attention(
+        query.primitive.tensor(),
+        key.primitive.tensor(),
+        value.primitive.tensor(),
+        padding_mask.map(|mask| mask.primitive),
+        attn_mask.map(|mask| mask.primitive),
+        options,
+    )))
+}
+
+/// Draws a dropout seed from the backend random number generator.
+fn dropout_seed<B: Backend>(device: &B::Device) -> u64 {
+    // Two 31-bit halves, which fit the integer elements of every backend.
+    let bound = (1u64 << 31) as f64;
+    let halves = Tensor::<B, 1, Int>::random([2], Distribution::Uniform(0.0, bound), device)
+        .into_data()
+        .iter::<i64>()
+        .map(|half| half as u64)
+        .collect::<Vec<_>>();
+
+    (halves[0] << 31) | halves[1]
+}
+
+/// Generates the sampling grid of the 2D affine transformations, to be used with
+/// [grid_sample_2d].
+///
+/// # Arguments
+///
+/// * `theta` - The affine matrices of shape `[batch_size, 2, 3]`, mapping the normalized output
+///   locations to the normalized input locations.
+/// * `size` - The size of the output `[batch_size, channels, height, width]`.
+/// * `align_corners` - If true, the locations -1 and 1 refer to the center of the corner pixels,
+///   otherwise they refer to the outer edges of the corner pixels.
+///
+/// # Returns
+///
+/// The grid of shape `[batch_size, height, width, 2]`.
+pub fn affine_grid_2d<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [batch_size, _, height, width] = size;
+    let xs = normalized_locations(width, align_corners);
+    let ys = normalized_locations(height, align_corners);
+
+    // The homogeneous coordinates (x, y, 1) of every output location.
+    let base = ys
+        .iter()
+        .flat_map(|y| xs.iter().flat_map(move |x| [*x, *y, 1.0]))
+        .collect::<Vec<_>>();
+    let base =
+        Tensor::<B, 2>::from_data(TensorData::new(base, [height * width, 3]), &theta.device())
+            .unsqueeze::<3>()
+            .expand([batch_size, height * width, 3]);
+
+    base.matmul(theta.swap_dims(1, 2))
+        .reshape([batch_size, height, width, 2])
+}
+
+/// The normalized locations of the pixel centers along a dimension.
+fn normalized_locations(size: usize, align_corners: bool) -> Vec<f32> {
+    if size <= 1 {
+        return alloc::vec![0.0; size];
+    }
+
+    (0..size)
+        .map(|i| match align_corners {
+            true => 2.0 * i as f32 / (size - 1) as f32 - 1.0,
+            false => (2 * i + 1) as f32 / size as f32 - 1.0,
+        })
+        .collect()
+}
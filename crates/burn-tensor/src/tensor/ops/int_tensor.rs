@@ -706,6 +706,68 @@ pub trait IntTensorOps<B: Backend> {
     /// The product of all elements in the tensor along the dimension.
     fn int_prod_dim(tensor: IntTensor<B>, dim: usize) -> IntTensor<B>;
 
+    /// Cumulative sum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element,
+    ///   starting the accumulation at zero.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the cumulative sum along `dim`.
+    fn int_cumsum(tensor: IntTensor<B>, dim: usize, exclusive: bool, reverse: bool)
+        -> IntTensor<B>;
+
+    /// Cumulative product of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element,
+    ///   starting the accumulation at one.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the cumulative product along `dim`.
+    fn int_cumprod(
+        tensor: IntTensor<B>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<B>;
+
+    /// Cumulative maximum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the running maximum along `dim`.
+    fn int_cummax(tensor: IntTensor<B>, dim: usize, reverse: bool) -> IntTensor<B>;
+
+    /// Cumulative minimum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the running minimum along `dim`.
+    fn int_cummin(tensor: IntTensor<B>, dim: usize, reverse: bool) -> IntTensor<B>;
+
     /// Computes the mean of all elements in the tensor.
     ///
     /// # Arguments
@@ -0,0 +1,315 @@
+use crate::{
+    backend::Backend,
+    ops::{AttentionBackward, AttentionOptions, BoolTensor, FloatTensor},
+    Bool, Tensor, TensorData, TensorPrimitive,
+};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Number of queries whose attention weights are materialized at once during the backward pass.
+const BACKWARD_BLOCK_SIZE: usize = 256;
+
+/// The dropout of the attention weights.
+///
+/// Whether a weight is kept is a hash of the seed and of the position of the weight, so that each
+/// unit of a kernel generates the mask of its own weights, and the backward pass recomputes it
+/// instead of storing it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AttentionDropout {
+    /// The low and high halves of the seed.
+    pub seeds: [u32; 2],
+    /// A weight is kept when its hash is lower than the threshold.
+    pub threshold: u32,
+    /// The factor of the kept weights, `1 / (1 - dropout)`.
+    pub scale: f64,
+}
+
+impl AttentionDropout {
+    /// The dropout of the options, none when the dropout probability is zero.
+    pub fn new(options: &AttentionOptions) -> Option<Self> {
+        if options.dropout == 0.0 {
+            return None;
+        }
+        assert!(
+            (0.0..1.0).contains(&options.dropout),
+            "The dropout probability should be in [0, 1)"
+        );
+
+        let seed = options.dropout_seed.unwrap_or_default();
+        let prob_keep = 1.0 - options.dropout;
+
+        Some(Self {
+            seeds: [seed as u32, (seed >> 32) as u32],
+            threshold: (prob_keep * 4_294_967_296.0).min(u32::MAX as f64) as u32,
+            scale: 1.0 / prob_keep,
+        })
+    }
+
+    /// Whether the weight of the key `col` for the query `row` is kept, the rows being numbered
+    /// across the batch and the heads as `(batch * num_heads + head) * seq_length_q + query`.
+    pub fn keep(&self, row: usize, col: usize) -> bool {
+        dropout_hash(self.seeds, row as u32, col as u32) < self.threshold
+    }
+
+    /// The factors of the weights of the queries from `start` to `end`, zero for the dropped
+    /// weights.
+    fn factors<B: Backend>(
+        &self,
+        shape: [usize; 4],
+        start: usize,
+        end: usize,
+        device: &B::Device,
+    ) -> Tensor<B, 4> {
+        let [batch_size, num_heads, seq_length_q, seq_length_k] = shape;
+        let scale = self.scale as f32;
+        let factors = (0..batch_size * num_heads)
+            .flat_map(|head| {
+                (start..end).flat_map(move |query| {
+                    let row = head * seq_length_q + query;
+                    (0..seq_length_k).map(move |col| match self.keep(row, col) {
+                        true => scale,
+                        false => 0.0,
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Tensor::from_data(
+            TensorData::new(factors, [batch_size, num_heads, end - start, seq_length_k]),
+            device,
+        )
+    }
+}
+
+/// Hash of the position of an attention weight, chaining the seed and the position through the
+/// `lowbias32` integer finalizer.
+pub fn dropout_hash(seeds: [u32; 2], row: u32, col: u32) -> u32 {
+    let mix = |mut x: u32| {
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x7feb_352d);
+        x ^= x >> 15;
+        x = x.wrapping_mul(0x846c_a68b);
+        x ^ (x >> 16)
+    };
+
+    mix(mix(mix(row ^ seeds[0]) ^ col) ^ seeds[1])
+}
+
+/// Computes the attention by materializing the attention weights of all the queries.
+pub fn attention_with_weights<B: Backend>(
+    query: FloatTensor<B>,
+    key: FloatTensor<B>,
+    value: FloatTensor<B>,
+    padding_mask: Option<BoolTensor<B>>,
+    attn_mask: Option<BoolTensor<B>>,
+    options: AttentionOptions,
+) -> FloatTensor<B> {
+    let attention = Attention::<B>::new(query, key, value, padding_mask, attn_mask, &options);
+    let [_, _, seq_length_q, _] = attention.query.dims();
+
+    let mut weights = attention.weights(attention.query.clone(), 0, seq_length_q);
+
+    if let Some(dropout) = AttentionDropout::new(&options) {
+        let factors = dropout.factors::<B>(weights.dims(), 0, seq_length_q, &weights.device());
+        weights = weights.mul(factors);
+    }
+
+    weights.matmul(attention.value).into_primitive().tensor()
+}
+
+/// Backward pass of the attention, recomputing the attention weights and the dropout mask for
+/// blocks of queries so that only `BACKWARD_BLOCK_SIZE` rows of weights are materialized at once.
+pub fn attention_backward_with_blocks<B: Backend>(
+    query: FloatTensor<B>,
+    key: FloatTensor<B>,
+    value: FloatTensor<B>,
+    padding_mask: Option<BoolTensor<B>>,
+    attn_mask: Option<BoolTensor<B>>,
+    output_grad: FloatTensor<B>,
+    options: AttentionOptions,
+) -> AttentionBackward<B> {
+    let dropout = AttentionDropout::new(&options);
+    let attention = Attention::<B>::new(query, key, value, padding_mask, attn_mask, &options);
+    let output_grad = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(output_grad));
+    let [batch_size, num_heads, seq_length_q, _] = attention.query.dims();
+    let [_, _, seq_length_k, _] = attention.key.dims();
+
+    let mut query_grads = Vec::new();
+    let mut key_grad = attention.key.zeros_like();
+    let mut value_grad = attention.value.zeros_like();
+
+    for start in (0..seq_length_q).step_by(BACKWARD_BLOCK_SIZE) {
+        let end = usize::min(start + BACKWARD_BLOCK_SIZE, seq_length_q);
+        let query = attention.query.clone().narrow(2, start, end - start);
+        let output_grad = output_grad.clone().narrow(2, start, end - start);
+
+        let weights = attention.weights(query.clone(), start, end);
+        let factors = dropout.map(|dropout| {
+            let shape = [batch_size, num_heads, seq_length_q, seq_length_k];
+            dropout.factors::<B>(shape, start, end, &weights.device())
+        });
+        let dropped = match &factors {
+            Some(factors) => weights.clone().mul(factors.clone()),
+            None => weights.clone(),
+        };
+        value_grad = value_grad.add(dropped.transpose().matmul(output_grad.clone()));
+
+        // Gradient of the dropout and of the softmax
+        let mut weights_grad = output_grad.matmul(attention.value.clone().transpose());
+        if let Some(factors) = factors {
+            weights_grad = weights_grad.mul(factors);
+        }
+        let dot = weights_grad.clone().mul(weights.clone()).sum_dim(3);
+        let scores_grad = weights
+            .mul(weights_grad.sub(dot))
+            .mul_scalar(attention.scale);
+
+        query_grads.push(scores_grad.clone().matmul(attention.key.clone()));
+        key_grad = key_grad.add(scores_grad.transpose().matmul(query));
+    }
+
+    AttentionBackward::new(
+        Tensor::cat(query_grads, 2).into_primitive().tensor(),
+        attention.reduce_groups(key_grad).into_primitive().tensor(),
+        attention
+            .reduce_groups(value_grad)
+            .into_primitive()
+            .tensor(),
+    )
+}
+
+/// The inputs of the attention, with the key and value heads repeated for every query head of
+/// their group.
+struct Attention<B: Backend> {
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    padding_mask: Option<Tensor<B, 2, Bool>>,
+    attn_mask: Option<Tensor<B, 3, Bool>>,
+    scale: f64,
+    is_causal: bool,
+    num_groups: usize,
+}
+
+impl<B: Backend> Attention<B> {
+    fn new(
+        query: FloatTensor<B>,
+        key: FloatTensor<B>,
+        value: FloatTensor<B>,
+        padding_mask: Option<BoolTensor<B>>,
+        attn_mask: Option<BoolTensor<B>>,
+        options: &AttentionOptions,
+    ) -> Self {
+        let query = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(query));
+        let key = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(key));
+        let value = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(value));
+
+        let [_, num_heads, _, head_dim] = query.dims();
+        let [_, num_kv_heads, _, _] = key.dims();
+        assert!(
+            num_kv_heads > 0 && num_heads % num_kv_heads == 0,
+            "The number of query heads ({num_heads}) should be a multiple of the number of key and value heads ({num_kv_heads})"
+        );
+        let num_groups = num_heads / num_kv_heads;
+
+        Self {
+            query,
+            key: repeat_groups(key, num_groups),
+            value: repeat_groups(value, num_groups),
+            padding_mask: padding_mask.map(Tensor::from_primitive),
+            attn_mask: attn_mask.map(Tensor::from_primitive),
+            scale: options
+                .scale
+                .unwrap_or_else(|| 1.0 / (head_dim as f64).sqrt()),
+            is_causal: options.is_causal,
+            num_groups,
+        }
+    }
+
+    /// The attention weights of the queries from `start` to `end`.
+    fn weights(&self, query: Tensor<B, 4>, start: usize, end: usize) -> Tensor<B, 4> {
+        let mut scores = query
+            .matmul(self.key.clone().transpose())
+            .mul_scalar(self.scale);
+        let shape = scores.shape();
+        let [batch_size, _, _, seq_length_k] = scores.dims();
+        let [_, _, seq_length_q, _] = self.query.dims();
+
+        if let Some(mask) = &self.padding_mask {
+            let mask = mask
+                .clone()
+                .reshape([batch_size, 1, 1, seq_length_k])
+                .expand(shape.clone());
+            scores = scores.mask_fill(mask, f64::NEG_INFINITY);
+        }
+
+        if let Some(mask) = &self.attn_mask {
+            let mask = mask
+                .clone()
+                .narrow(1, start, end - start)
+                .unsqueeze_dim::<4>(1)
+                .expand(shape.clone());
+            scores = scores.mask_fill(mask, f64::NEG_INFINITY);
+        }
+
+        if self.is_causal {
+            // The queries are aligned with the last keys.
+            let offset = (start + seq_length_k) as i64 - seq_length_q as i64;
+            let mask = Tensor::<B, 2, Bool>::tril_mask(
+                [end - start, seq_length_k],
+                offset,
+                &scores.device(),
+            )
+            .unsqueeze::<4>()
+            .expand(shape);
+            scores = scores.mask_fill(mask, f64::NEG_INFINITY);
+        }
+
+        masked_softmax(scores)
+    }
+
+    /// Sums the gradients of the repeated key or value heads of each group.
+    fn reduce_groups(&self, grad: Tensor<B, 4>) -> Tensor<B, 4> {
+        if self.num_groups == 1 {
+            return grad;
+        }
+
+        let [batch_size, num_heads, seq_length, dim] = grad.dims();
+        let num_kv_heads = num_heads / self.num_groups;
+
+        grad.reshape([batch_size, num_kv_heads, self.num_groups, seq_length, dim])
+            .sum_dim(2)
+            .reshape([batch_size, num_kv_heads, seq_length, dim])
+    }
+}
+
+/// Repeats each head for the query heads of its group.
+fn repeat_groups<B: Backend>(tensor: Tensor<B, 4>, num_groups: usize) -> Tensor<B, 4> {
+    if num_groups == 1 {
+        return tensor;
+    }
+
+    let [batch_size, num_heads, seq_length, dim] = tensor.dims();
+
+    tensor
+        .unsqueeze_dim::<5>(2)
+        .expand([batch_size, num_heads, num_groups, seq_length, dim])
+        .reshape([batch_size, num_heads * num_groups, seq_length, dim])
+}
+
+/// Softmax over the keys where masked scores are `-inf`, the weights of a query attending to no
+/// key being zero.
+fn masked_softmax<B: Backend>(scores: Tensor<B, 4>) -> Tensor<B, 4> {
+    let max = scores.clone().max_dim(3).detach();
+    let max = max
+        .clone()
+        .mask_fill(max.equal_elem(f64::NEG_INFINITY), 0.0);
+
+    let weights = scores.sub(max).exp();
+    let sum = weights.clone().sum_dim(3);
+    let sum = sum.clone().mask_fill(sum.equal_elem(0.0), 1.0);
+
+    weights.div(sum)
+}
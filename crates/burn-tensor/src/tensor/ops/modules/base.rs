@@ -1,9 +1,9 @@
 use core::num::NonZeroUsize;
 
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{attention, conv, grid_sample, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     backend::Backend,
-    ops::{FloatTensor, IntTensor},
+    ops::{BoolTensor, FloatTensor, IntTensor},
     Shape, TensorMetadata,
 };
 
@@ -257,6 +257,77 @@ pub struct InterpolateBackward<B: Backend> {
     pub x_grad: FloatTensor<B>,
 }
 
+/// Padding used by [grid_sample_2d](ModuleOps::grid_sample_2d) for the locations outside of the
+/// input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum GridSamplePaddingMode {
+    /// The locations outside of the input are zeros.
+    Zeros,
+
+    /// The locations outside of the input take the value of the closest border.
+    Border,
+
+    /// The locations outside of the input are reflected by the borders.
+    Reflection,
+}
+
+/// Grid sampling options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// Algorithm used to sample the input.
+    pub mode: InterpolateMode,
+
+    /// Padding for the locations outside of the input.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If true, the locations -1 and 1 refer to the center of the corner pixels, otherwise they
+    /// refer to the outer edges of the corner pixels.
+    pub align_corners: bool,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [grid_sample_2d](ModuleOps::grid_sample_2d).
+#[derive(new)]
+pub struct GridSample2dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B>,
+
+    /// Grid gradient.
+    pub grid_grad: FloatTensor<B>,
+}
+
+/// Options of the [scaled dot-product attention](ModuleOps::scaled_dot_product_attention).
+#[derive(new, Debug, Clone, Copy, PartialEq, Default)]
+pub struct AttentionOptions {
+    /// Factor applied to the attention scores, `1 / sqrt(head_dim)` when not set.
+    pub scale: Option<f64>,
+
+    /// If true, each query only attends to the keys up to its own position. When there are more
+    /// keys than queries, such as with cached keys, the queries are aligned with the last keys.
+    pub is_causal: bool,
+
+    /// Probability of dropping each attention weight.
+    pub dropout: f64,
+
+    /// Seed of the dropout mask, which is a function of the seed and the position of each weight
+    /// so that it is recomputed instead of stored. The [tensor function](crate::module::scaled_dot_product_attention)
+    /// draws a new seed for each call when not set.
+    #[new(default)]
+    pub dropout_seed: Option<u64>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [scaled_dot_product_attention](ModuleOps::scaled_dot_product_attention).
+#[derive(new)]
+pub struct AttentionBackward<B: Backend> {
+    /// Query gradient.
+    pub query_grad: FloatTensor<B>,
+
+    /// Key gradient.
+    pub key_grad: FloatTensor<B>,
+
+    /// Value gradient.
+    pub value_grad: FloatTensor<B>,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -764,6 +835,92 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B>;
+
+    /// Samples the input at the locations of the grid.
+    ///
+    /// The grid holds the `(x, y)` locations normalized to `[-1, 1]`, where `(-1, -1)` is the
+    /// top-left corner of the input and `(1, 1)` its bottom-right corner.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels, height_in, width_in]`,
+    /// grid:   `[batch_size, height_out, width_out, 2]`,
+    /// output: `[batch_size, channels, height_out, width_out]`
+    fn grid_sample_2d(
+        x: FloatTensor<B>,
+        grid: FloatTensor<B>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B> {
+        grid_sample::grid_sample_2d_with_gather::<B>(x, grid, options)
+    }
+
+    /// Backward pass for the [grid_sample_2d](ModuleOps::grid_sample_2d) operation.
+    fn grid_sample_2d_backward(
+        x: FloatTensor<B>,
+        grid: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        options: GridSampleOptions,
+    ) -> GridSample2dBackward<B> {
+        grid_sample::grid_sample_2d_backward_with_scatter::<B>(x, grid, output_grad, options)
+    }
+
+    /// Computes the scaled dot-product attention `softmax(query · keyᵀ · scale) · value`.
+    ///
+    /// The keys hidden by the padding mask, the attention mask or the causal option are ignored,
+    /// and a query attending to no key has a zero output. The query heads are split into groups
+    /// sharing the same key and value head (grouped-query attention), with a single key and value
+    /// head being multi-query attention.
+    ///
+    /// The dropped weights are given by [AttentionDropout](attention::AttentionDropout), so that
+    /// backends computing the softmax over blocks of keys generate the same mask without storing
+    /// it.
+    ///
+    /// The default implementation materializes the attention weights of all the queries, backends
+    /// should compute the softmax over blocks of keys to avoid it.
+    ///
+    /// # Shapes
+    ///
+    /// query:        `[batch_size, num_heads, seq_length_q, head_dim]`,
+    /// key:          `[batch_size, num_kv_heads, seq_length_k, head_dim]`,
+    /// value:        `[batch_size, num_kv_heads, seq_length_k, value_dim]`,
+    /// padding_mask: `[batch_size, seq_length_k]`, true for the padded keys,
+    /// attn_mask:    `[batch_size, seq_length_q, seq_length_k]`, true for the masked keys,
+    /// output:       `[batch_size, num_heads, seq_length_q, value_dim]`
+    fn scaled_dot_product_attention(
+        query: FloatTensor<B>,
+        key: FloatTensor<B>,
+        value: FloatTensor<B>,
+        padding_mask: Option<BoolTensor<B>>,
+        attn_mask: Option<BoolTensor<B>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<B> {
+        attention::attention_with_weights::<B>(query, key, value, padding_mask, attn_mask, options)
+    }
+
+    /// Backward pass for the [scaled_dot_product_attention](ModuleOps::scaled_dot_product_attention)
+    /// operation.
+    ///
+    /// The default implementation recomputes the attention weights and the dropout mask for blocks
+    /// of queries.
+    fn scaled_dot_product_attention_backward(
+        query: FloatTensor<B>,
+        key: FloatTensor<B>,
+        value: FloatTensor<B>,
+        padding_mask: Option<BoolTensor<B>>,
+        attn_mask: Option<BoolTensor<B>>,
+        output_grad: FloatTensor<B>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<B> {
+        attention::attention_backward_with_blocks::<B>(
+            query,
+            key,
+            value,
+            padding_mask,
+            attn_mask,
+            output_grad,
+            options,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,67 @@
+use crate::{backend::Backend, ops::FloatTensor, Shape, TensorData, TensorMetadata};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Discrete Fourier transform computed as a matrix multiplication with the DFT matrix.
+///
+/// The last dimension of the tensor holds the real and imaginary parts.
+pub(crate) fn fft_with_dft_matrix<B: Backend>(
+    tensor: FloatTensor<B>,
+    dim: usize,
+    inverse: bool,
+) -> FloatTensor<B> {
+    let shape = tensor.shape();
+    let rank = shape.num_dims();
+    let size = shape.dims[dim];
+    let device = B::float_device(&tensor);
+    let dtype = tensor.dtype();
+
+    // Move the transformed dimension right before the complex one: [..., size, 1].
+    let tensor = B::float_swap_dims(tensor, dim, rank - 2);
+    let mut ranges = tensor
+        .shape()
+        .dims
+        .iter()
+        .map(|dim| 0..*dim)
+        .collect::<Vec<_>>();
+    ranges[rank - 1] = 0..1;
+    let real = B::float_slice(tensor.clone(), &ranges);
+    ranges[rank - 1] = 1..2;
+    let imag = B::float_slice(tensor, &ranges);
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let scale = if inverse { 1.0 / size as f64 } else { 1.0 };
+    let mut cos = Vec::with_capacity(size * size);
+    let mut sin = Vec::with_capacity(size * size);
+
+    for k in 0..size {
+        for j in 0..size {
+            let angle = sign * 2.0 * PI * ((j * k) % size) as f64 / size as f64;
+            cos.push(angle.cos() * scale);
+            sin.push(angle.sin() * scale);
+        }
+    }
+
+    let mut matrix_shape = vec![1; rank];
+    matrix_shape[rank - 2] = size;
+    matrix_shape[rank - 1] = size;
+    let matrix = |values: Vec<f64>| {
+        let data = TensorData::new(values, Shape::from(matrix_shape.clone())).convert_dtype(dtype);
+        B::float_from_data(data, &device)
+    };
+    let cos = matrix(cos);
+    let sin = matrix(sin);
+
+    // (cos + i sin) (real + i imag)
+    let out_real = B::float_sub(
+        B::float_matmul(cos.clone(), real.clone()),
+        B::float_matmul(sin.clone(), imag.clone()),
+    );
+    let out_imag = B::float_add(B::float_matmul(cos, imag), B::float_matmul(sin, real));
+
+    let output = B::float_cat(vec![out_real, out_imag], rank - 1);
+    B::float_swap_dims(output, dim, rank - 2)
+}
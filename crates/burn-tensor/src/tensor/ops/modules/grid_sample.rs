@@ -0,0 +1,395 @@
+use crate::{
+    backend::Backend,
+    ops::{
+        FloatTensor, GridSample2dBackward, GridSampleOptions, GridSamplePaddingMode,
+        InterpolateMode,
+    },
+    Int, Tensor, TensorPrimitive,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Coefficient of the cubic convolution, as used by PyTorch.
+const CUBIC_A: f64 = -0.75;
+
+/// Samples the input at the locations of the grid by gathering the neighbors of every location.
+pub(crate) fn grid_sample_2d_with_gather<B: Backend>(
+    x: FloatTensor<B>,
+    grid: FloatTensor<B>,
+    options: GridSampleOptions,
+) -> FloatTensor<B> {
+    let sampler = Sampler::<B>::new(x, grid, &options);
+    let [batch_size, channels, height, width] = sampler.output_shape;
+
+    let output = sampler
+        .taps()
+        .into_iter()
+        .map(|tap| sampler.gather(&tap).mul(tap.weight.unsqueeze_dim(1)))
+        .reduce(|acc, value| acc.add(value))
+        .unwrap();
+
+    output
+        .reshape([batch_size, channels, height, width])
+        .into_primitive()
+        .tensor()
+}
+
+/// Backward pass of [grid_sample_2d_with_gather], scattering the output gradient to the neighbors
+/// of every location.
+pub(crate) fn grid_sample_2d_backward_with_scatter<B: Backend>(
+    x: FloatTensor<B>,
+    grid: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    options: GridSampleOptions,
+) -> GridSample2dBackward<B> {
+    let sampler = Sampler::<B>::new(x, grid, &options);
+    let [batch_size, channels, height, width] = sampler.output_shape;
+    let output_grad = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(output_grad))
+        .reshape([batch_size, channels, height * width]);
+
+    let mut x_grad = sampler.x.zeros_like();
+    let mut grad_x = Tensor::<B, 2>::zeros([batch_size, height * width], &sampler.x.device());
+    let mut grad_y = grad_x.clone();
+
+    for tap in sampler.taps() {
+        let values = sampler.gather(&tap);
+        x_grad = x_grad.add(sampler.scatter(
+            &tap,
+            output_grad.clone().mul(tap.weight.clone().unsqueeze_dim(1)),
+        ));
+
+        // Derivative of the output with respect to the sampled location, summed over channels.
+        let grad = output_grad
+            .clone()
+            .mul(values)
+            .sum_dim(1)
+            .reshape([batch_size, height * width]);
+        grad_x = grad_x.add(grad.clone().mul(tap.weight_grad_x));
+        grad_y = grad_y.add(grad.mul(tap.weight_grad_y));
+    }
+
+    let grad_x = grad_x.mul(sampler.x_coords.grad);
+    let grad_y = grad_y.mul(sampler.y_coords.grad);
+    let grid_grad = Tensor::cat(
+        vec![
+            grad_x.reshape([batch_size, height, width, 1]),
+            grad_y.reshape([batch_size, height, width, 1]),
+        ],
+        3,
+    );
+
+    let [_, _, height_in, width_in] = sampler.input_shape;
+    GridSample2dBackward::new(
+        x_grad
+            .reshape([batch_size, channels, height_in, width_in])
+            .into_primitive()
+            .tensor(),
+        grid_grad.into_primitive().tensor(),
+    )
+}
+
+/// Unnormalized coordinates along one dimension, with their gradient with respect to the grid.
+struct Coordinates<B: Backend> {
+    values: Tensor<B, 2>,
+    grad: Tensor<B, 2>,
+}
+
+impl<B: Backend> Coordinates<B> {
+    /// Maps the grid coordinates from `[-1, 1]` to the pixel indices of the input.
+    fn unnormalize(coords: Tensor<B, 2>, size: usize, align_corners: bool) -> Self {
+        let scale = match align_corners {
+            true => (size as f64 - 1.0) / 2.0,
+            false => size as f64 / 2.0,
+        };
+        let values = match align_corners {
+            true => coords.add_scalar(1.0).mul_scalar(scale),
+            false => coords.add_scalar(1.0).mul_scalar(scale).sub_scalar(0.5),
+        };
+        let grad = values.ones_like().mul_scalar(scale);
+
+        Self { values, grad }
+    }
+
+    /// Applies the padding to the locations outside of the input.
+    fn pad(self, size: usize, padding_mode: GridSamplePaddingMode, align_corners: bool) -> Self {
+        match padding_mode {
+            GridSamplePaddingMode::Zeros => self,
+            GridSamplePaddingMode::Border => self.clip(size),
+            GridSamplePaddingMode::Reflection => match align_corners {
+                true => self.reflect(0.0, 2.0 * (size as f64 - 1.0)).clip(size),
+                false => self.reflect(-1.0, 2.0 * size as f64 - 1.0).clip(size),
+            },
+        }
+    }
+
+    fn clip(self, size: usize) -> Self {
+        let max = size as f64 - 1.0;
+        let inside = self
+            .values
+            .clone()
+            .greater_elem(0.0)
+            .bool_and(self.values.clone().lower_elem(max))
+            .float();
+
+        Self {
+            values: self.values.clamp(0.0, max),
+            grad: self.grad.mul(inside),
+        }
+    }
+
+    /// Reflects the coordinates over the bounds, given as twice their value so that they are
+    /// integers for both corner alignments.
+    fn reflect(self, twice_low: f64, twice_high: f64) -> Self {
+        if twice_low == twice_high {
+            return Self {
+                values: self.values.zeros_like(),
+                grad: self.grad.zeros_like(),
+            };
+        }
+
+        let min = twice_low / 2.0;
+        let span = (twice_high - twice_low) / 2.0;
+        let values = self.values.sub_scalar(min);
+        // 1 for the coordinates below the lower bound, 0 otherwise.
+        let negative = values.clone().lower_elem(0.0).float();
+        let values = values.abs();
+
+        let extra = values.clone().remainder_scalar(span);
+        // 1 when the number of reflections is odd, 0 otherwise.
+        let odd = values.div_scalar(span).floor().remainder_scalar(2.0);
+
+        // Even: extra + min, odd: span - extra + min.
+        let values = odd
+            .clone()
+            .mul(extra.clone().mul_scalar(-2.0).add_scalar(span))
+            .add(extra)
+            .add_scalar(min);
+        let sign = negative
+            .mul_scalar(-2.0)
+            .add_scalar(1.0)
+            .mul(odd.mul_scalar(-2.0).add_scalar(1.0));
+
+        Self {
+            values,
+            grad: self.grad.mul(sign),
+        }
+    }
+}
+
+/// A neighbor contributing to the sampled values, with its weight and the gradient of the weight
+/// with respect to the unnormalized location.
+struct Tap<B: Backend> {
+    x: Tensor<B, 2>,
+    y: Tensor<B, 2>,
+    weight: Tensor<B, 2>,
+    weight_grad_x: Tensor<B, 2>,
+    weight_grad_y: Tensor<B, 2>,
+}
+
+struct Sampler<B: Backend> {
+    /// The input flattened to `[batch_size, channels, height * width]`.
+    x: Tensor<B, 3>,
+    x_coords: Coordinates<B>,
+    y_coords: Coordinates<B>,
+    input_shape: [usize; 4],
+    output_shape: [usize; 4],
+    options: GridSampleOptions,
+}
+
+impl<B: Backend> Sampler<B> {
+    fn new(x: FloatTensor<B>, grid: FloatTensor<B>, options: &GridSampleOptions) -> Self {
+        let x = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(x));
+        let grid = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(grid));
+        let [batch_size, channels, height_in, width_in] = x.dims();
+        let [_, height, width, _] = grid.dims();
+        let num_points = height * width;
+
+        let coords = |dim: usize, size: usize| {
+            let coords = grid
+                .clone()
+                .narrow(3, dim, 1)
+                .reshape([batch_size, num_points]);
+            let coords = Coordinates::unnormalize(coords, size, options.align_corners);
+
+            // Bicubic sampling pads every neighbor instead of the location.
+            match options.mode {
+                InterpolateMode::Bicubic => coords,
+                _ => coords.pad(size, options.padding_mode, options.align_corners),
+            }
+        };
+
+        Self {
+            x_coords: coords(0, width_in),
+            y_coords: coords(1, height_in),
+            x: x.reshape([batch_size, channels, height_in * width_in]),
+            input_shape: [batch_size, channels, height_in, width_in],
+            output_shape: [batch_size, channels, height, width],
+            options: options.clone(),
+        }
+    }
+
+    fn taps(&self) -> Vec<Tap<B>> {
+        match self.options.mode {
+            InterpolateMode::Nearest => self.nearest_taps(),
+            InterpolateMode::Bilinear => self.bilinear_taps(),
+            InterpolateMode::Bicubic => self.bicubic_taps(),
+        }
+    }
+
+    fn nearest_taps(&self) -> Vec<Tap<B>> {
+        let zeros = self.x_coords.values.zeros_like();
+
+        vec![Tap {
+            x: self.x_coords.values.clone().round(),
+            y: self.y_coords.values.clone().round(),
+            weight: zeros.ones_like(),
+            weight_grad_x: zeros.clone(),
+            weight_grad_y: zeros,
+        }]
+    }
+
+    fn bilinear_taps(&self) -> Vec<Tap<B>> {
+        let x0 = self.x_coords.values.clone().floor();
+        let y0 = self.y_coords.values.clone().floor();
+        let tx = self.x_coords.values.clone().sub(x0.clone());
+        let ty = self.y_coords.values.clone().sub(y0.clone());
+        // The weight along a dimension and its gradient, for the lower and upper neighbors.
+        let x_weights = [(tx.clone().neg().add_scalar(1.0), -1.0), (tx, 1.0)];
+        let y_weights = [(ty.clone().neg().add_scalar(1.0), -1.0), (ty, 1.0)];
+
+        let mut taps = Vec::with_capacity(4);
+        for (j, (wy, dy)) in y_weights.iter().enumerate() {
+            for (i, (wx, dx)) in x_weights.iter().enumerate() {
+                taps.push(Tap {
+                    x: x0.clone().add_scalar(i as f64),
+                    y: y0.clone().add_scalar(j as f64),
+                    weight: wx.clone().mul(wy.clone()),
+                    weight_grad_x: wy.clone().mul_scalar(*dx),
+                    weight_grad_y: wx.clone().mul_scalar(*dy),
+                });
+            }
+        }
+
+        taps
+    }
+
+    fn bicubic_taps(&self) -> Vec<Tap<B>> {
+        let [_, _, height_in, width_in] = self.input_shape;
+        let x0 = self.x_coords.values.clone().floor();
+        let y0 = self.y_coords.values.clone().floor();
+        let x_weights = cubic_weights(self.x_coords.values.clone().sub(x0.clone()));
+        let y_weights = cubic_weights(self.y_coords.values.clone().sub(y0.clone()));
+
+        let neighbor = |start: &Tensor<B, 2>, offset: usize, size: usize| {
+            let coords = Coordinates {
+                values: start.clone().add_scalar(offset as f64 - 1.0),
+                grad: start.zeros_like(),
+            };
+            coords
+                .pad(size, self.options.padding_mode, self.options.align_corners)
+                .values
+        };
+
+        let mut taps = Vec::with_capacity(16);
+        for (j, (wy, dy)) in y_weights.iter().enumerate() {
+            for (i, (wx, dx)) in x_weights.iter().enumerate() {
+                taps.push(Tap {
+                    x: neighbor(&x0, i, width_in),
+                    y: neighbor(&y0, j, height_in),
+                    weight: wx.clone().mul(wy.clone()),
+                    weight_grad_x: dx.clone().mul(wy.clone()),
+                    weight_grad_y: wx.clone().mul(dy.clone()),
+                });
+            }
+        }
+
+        taps
+    }
+
+    /// Flat indices of the neighbor in the input, along with whether it is inside the input.
+    fn indices(&self, tap: &Tap<B>) -> (Tensor<B, 3, Int>, Tensor<B, 3>) {
+        let [batch_size, channels, height_in, width_in] = self.input_shape;
+        let [_, _, height, width] = self.output_shape;
+        let max_x = width_in as f64 - 1.0;
+        let max_y = height_in as f64 - 1.0;
+
+        let inside = tap
+            .x
+            .clone()
+            .greater_equal_elem(0.0)
+            .bool_and(tap.x.clone().lower_equal_elem(max_x))
+            .bool_and(tap.y.clone().greater_equal_elem(0.0))
+            .bool_and(tap.y.clone().lower_equal_elem(max_y))
+            .float()
+            .unsqueeze_dim(1);
+        let indices = tap
+            .y
+            .clone()
+            .clamp(0.0, max_y)
+            .mul_scalar(width_in as f64)
+            .add(tap.x.clone().clamp(0.0, max_x))
+            .int()
+            .unsqueeze_dim::<3>(1)
+            .expand([batch_size, channels, height * width]);
+
+        (indices, inside)
+    }
+
+    /// The values of the neighbor, zero when it is outside of the input.
+    fn gather(&self, tap: &Tap<B>) -> Tensor<B, 3> {
+        let (indices, inside) = self.indices(tap);
+        self.x.clone().gather(2, indices).mul(inside)
+    }
+
+    /// Accumulates the values to the neighbor, ignoring the ones outside of the input.
+    fn scatter(&self, tap: &Tap<B>, values: Tensor<B, 3>) -> Tensor<B, 3> {
+        let (indices, inside) = self.indices(tap);
+        self.x.zeros_like().scatter(2, indices, values.mul(inside))
+    }
+}
+
+/// The weights of the 4 neighbors of a location at distance `t` from the second neighbor, and
+/// their gradient with respect to `t`.
+fn cubic_weights<B: Backend>(t: Tensor<B, 2>) -> [(Tensor<B, 2>, Tensor<B, 2>); 4] {
+    // Cubic convolution for |x| <= 1 and its derivative.
+    let near = |x: Tensor<B, 2>| {
+        let value = x
+            .clone()
+            .mul_scalar(CUBIC_A + 2.0)
+            .sub_scalar(CUBIC_A + 3.0)
+            .mul(x.clone())
+            .mul(x.clone())
+            .add_scalar(1.0);
+        let grad = x
+            .clone()
+            .mul_scalar(3.0 * (CUBIC_A + 2.0))
+            .sub_scalar(2.0 * (CUBIC_A + 3.0))
+            .mul(x);
+        (value, grad)
+    };
+    // Cubic convolution for 1 < |x| < 2 and its derivative.
+    let far = |x: Tensor<B, 2>| {
+        let value = x
+            .clone()
+            .mul_scalar(CUBIC_A)
+            .sub_scalar(5.0 * CUBIC_A)
+            .mul(x.clone())
+            .add_scalar(8.0 * CUBIC_A)
+            .mul(x.clone())
+            .sub_scalar(4.0 * CUBIC_A);
+        let grad = x
+            .clone()
+            .mul_scalar(3.0 * CUBIC_A)
+            .sub_scalar(10.0 * CUBIC_A)
+            .mul(x)
+            .add_scalar(8.0 * CUBIC_A);
+        (value, grad)
+    };
+
+    let (w0, d0) = far(t.clone().add_scalar(1.0));
+    let (w1, d1) = near(t.clone());
+    let (w2, d2) = near(t.clone().neg().add_scalar(1.0));
+    let (w3, d3) = far(t.neg().add_scalar(2.0));
+
+    [(w0, d0), (w1, d1), (w2, d2.neg()), (w3, d3.neg())]
+}
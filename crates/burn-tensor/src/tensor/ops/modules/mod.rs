@@ -1,8 +1,14 @@
 /// Module with convolution operations.
 pub mod conv;
 
+/// Module with attention operations.
+pub mod attention;
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with fft operation
+pub(crate) mod fft;
+/// Module with grid sample operations.
+pub(crate) mod grid_sample;
 /// Module with repeat operation
 pub(crate) mod repeat_dim;
 /// Module with unfold operations.
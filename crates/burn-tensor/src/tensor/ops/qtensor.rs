@@ -4,7 +4,7 @@ use core::{future::Future, ops::Range};
 use crate::{
     backend::Backend,
     quantization::{QTensorPrimitive, QuantizationParametersPrimitive, QuantizationScheme},
-    Device, Shape, TensorData, TensorMetadata,
+    Device, ElementConversion, Shape, TensorData, TensorMetadata,
 };
 
 use super::{BoolTensor, FloatElem, FloatTensor, IntElem, IntTensor, QuantizedTensor};
@@ -65,8 +65,7 @@ pub trait QTensorOps<B: Backend> {
     /// Dynamically convert the tensor to a lower precision data type based on the quantization scheme.
     fn quantize_dynamic(tensor: FloatTensor<B>, scheme: &QuantizationScheme) -> QuantizedTensor<B> {
         // Dynamically compute min/max tensor range and qparams before quantizing
-        let min = B::float_min(tensor.clone());
-        let max = B::float_max(tensor.clone());
+        let (min, max) = scheme.compute_range_primitive::<B>(tensor.clone());
         let qparams = scheme.compute_q_params_primitive(min, max);
         Self::quantize(tensor, scheme, qparams)
     }
@@ -74,6 +73,41 @@ pub trait QTensorOps<B: Backend> {
     /// Convert the tensor back to a higher precision data type.
     fn dequantize(tensor: QuantizedTensor<B>) -> FloatTensor<B>;
 
+    /// Convert the tensor to a lower precision data type based on the quantization scheme and
+    /// parameters, and back to a higher precision data type (also known as fake quantization).
+    ///
+    /// This simulates the quantization error in floating point, which is used for
+    /// quantization-aware training.
+    fn fake_quantize(
+        tensor: FloatTensor<B>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> FloatTensor<B> {
+        let shape = tensor.shape();
+        let (a, b) = match qparams.offset {
+            Some(_) => scheme.q_type().range(),
+            None => scheme.q_type().symmetric_range(),
+        };
+        let scale = scheme.expand_params_primitive::<B>(qparams.scale, &shape);
+        let offset = qparams
+            .offset
+            .map(|offset| scheme.expand_params_primitive::<B>(B::int_into_float(offset), &shape));
+
+        // Simulated with float operations, so it is supported even without quantized tensors
+        let q = B::float_round(B::float_div(tensor, scale.clone()));
+        let q = match &offset {
+            Some(offset) => B::float_add(q, offset.clone()),
+            None => q,
+        };
+        let q = B::float_clamp(q, (a as f32).elem(), (b as f32).elem());
+        let q = match offset {
+            Some(offset) => B::float_sub(q, offset),
+            None => q,
+        };
+
+        B::float_mul(q, scale)
+    }
+
     /// Gets the device of the tensor.
     ///
     /// # Arguments
@@ -733,6 +767,94 @@ pub trait QTensorOps<B: Backend> {
         )
     }
 
+    /// Cumulative sum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the cumulative sum along `dim`.
+    fn q_cumsum(
+        tensor: QuantizedTensor<B>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> QuantizedTensor<B> {
+        dequant_op_quant!(
+            ty Self,
+            float_op |tensor| B::float_cumsum(tensor, dim, exclusive, reverse),
+            tensor
+        )
+    }
+
+    /// Cumulative product of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the cumulative product along `dim`.
+    fn q_cumprod(
+        tensor: QuantizedTensor<B>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> QuantizedTensor<B> {
+        dequant_op_quant!(
+            ty Self,
+            float_op |tensor| B::float_cumprod(tensor, dim, exclusive, reverse),
+            tensor
+        )
+    }
+
+    /// Cumulative maximum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the running maximum along `dim`.
+    fn q_cummax(tensor: QuantizedTensor<B>, dim: usize, reverse: bool) -> QuantizedTensor<B> {
+        dequant_op_quant!(
+            ty Self,
+            float_op |tensor| B::float_cummax(tensor, dim, reverse),
+            tensor
+        )
+    }
+
+    /// Cumulative minimum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the running minimum along `dim`.
+    fn q_cummin(tensor: QuantizedTensor<B>, dim: usize, reverse: bool) -> QuantizedTensor<B> {
+        dequant_op_quant!(
+            ty Self,
+            float_op |tensor| B::float_cummin(tensor, dim, reverse),
+            tensor
+        )
+    }
+
     /// Mean of all elements in a tensor.
     ///
     /// # Arguments
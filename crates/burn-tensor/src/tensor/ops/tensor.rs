@@ -1,7 +1,9 @@
 use super::cat::cat_with_slice_assign;
+use super::fft::fft_with_dft_matrix;
 use super::repeat_dim::repeat_with_slice_assign;
 use super::{BoolTensor, Device, FloatElem, FloatTensor, IntElem, IntTensor};
 use crate::tensor::cast::ToElement;
+use crate::tensor::linalg::{eigh_with_jacobi, svd_with_jacobi};
 use crate::{backend::Backend, tensor::Shape, Distribution, ElementConversion, Float, TensorData};
 use crate::{
     tensor::api::chunk, tensor::api::narrow, tensor::api::split, tensor::api::split_with_sizes,
@@ -742,6 +744,121 @@ pub trait FloatTensorOps<B: Backend> {
         B::float_exp(B::float_sum_dim(B::float_log(tensor), dim))
     }
 
+    /// Cumulative sum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element,
+    ///   starting the accumulation at zero.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the cumulative sum along `dim`.
+    fn float_cumsum(
+        tensor: FloatTensor<B>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<B>;
+
+    /// Cumulative product of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element of the output excludes the matching input element,
+    ///   starting the accumulation at one.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the cumulative product along `dim`.
+    fn float_cumprod(
+        tensor: FloatTensor<B>,
+        dim: usize,
+        exclusive: bool,
+        reverse: bool,
+    ) -> FloatTensor<B>;
+
+    /// Cumulative maximum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the running maximum along `dim`.
+    fn float_cummax(tensor: FloatTensor<B>, dim: usize, reverse: bool) -> FloatTensor<B>;
+
+    /// Cumulative minimum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `reverse` - Whether to accumulate from the last element of the dimension to the first.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the running minimum along `dim`.
+    fn float_cummin(tensor: FloatTensor<B>, dim: usize, reverse: bool) -> FloatTensor<B>;
+
+    /// Discrete Fourier transform of a complex tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The complex tensor, whose last dimension of size 2 holds the real and
+    ///   imaginary parts.
+    /// * `dim` - The dimension along which to compute the transform, which can't be the last one.
+    /// * `inverse` - Whether to compute the inverse transform, normalized by the size of `dim`.
+    ///
+    /// # Returns
+    ///
+    /// A complex tensor with the same shape as `tensor`, holding the transform along `dim`.
+    fn float_fft(tensor: FloatTensor<B>, dim: usize, inverse: bool) -> FloatTensor<B> {
+        fft_with_dft_matrix::<B>(tensor, dim, inverse)
+    }
+
+    /// Eigendecomposition of symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The symmetric matrices of shape `[batch_size, n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues of shape `[batch_size, 1, n]` in ascending order, and the matching
+    /// orthonormal eigenvectors as the columns of a tensor of shape `[batch_size, n, n]`.
+    ///
+    /// The default implementation uses the cyclic Jacobi eigenvalue algorithm.
+    fn float_eigh(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>) {
+        eigh_with_jacobi::<B>(tensor)
+    }
+
+    /// Reduced singular value decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[batch_size, m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The left singular vectors `u` of shape `[batch_size, m, k]`, the singular values of shape
+    /// `[batch_size, 1, k]` in descending order and the transposed right singular vectors `vh` of
+    /// shape `[batch_size, k, n]`, with `k = min(m, n)`.
+    ///
+    /// The default implementation uses the one-sided Jacobi algorithm.
+    fn float_svd(tensor: FloatTensor<B>) -> (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>) {
+        svd_with_jacobi::<B>(tensor)
+    }
+
     /// Mean of all elements in a tensor.
     ///
     /// # Arguments
@@ -4,19 +4,22 @@ use crate::{Bytes, Element};
 use alloc::vec::Vec;
 
 use super::{
-    pack_i8s_to_u32s, unpack_u32s_to_i8s, AffineQuantization, QParams, Quantization,
-    QuantizationScheme, QuantizationStrategy, QuantizationType, SymmetricQuantization,
+    pack_i8s_to_u32s, pack_sub_bytes_to_u32s, unpack_u32s_to_i8s, unpack_u32s_to_sub_bytes,
+    QParams, QuantizationScheme, QuantizationStrategy, QuantizationType,
 };
 
 /// Quantized data bytes representation.
 ///
 /// # Notes
 /// 1) The quantized values are packed into 32-bit unsigned integers. For example, int8
-///    quantized values pack 4 grouped values into a single `u32`. When unpacking these values,
-///    we make sure to retrieve only the meaningful values (and ignore the alignment padding).
+///    quantized values pack 4 grouped values into a single `u32`, and int4 quantized values
+///    pack 8 grouped values. When unpacking these values, we make sure to retrieve only the
+///    meaningful values (and ignore the alignment padding).
 /// 2) Quantization parameters are appended to the tensor data.
-///    As such, the last bytes always correspond to the scale parameter.
-///    If the quantization scheme includes an offset (zero-point) parameter, it is next to last.
+///    As such, the last bytes always correspond to the scale parameters (one for each group of
+///    values, see [params_layout](QuantizationScheme::params_layout)).
+///    If the quantization scheme includes offset (zero-point) parameters, they are stored right
+///    before the scales.
 pub struct QuantizedBytes {
     /// The quantized values and quantization parameters represented as bytes.
     pub bytes: Bytes,
@@ -31,69 +34,70 @@ impl QuantizedBytes {
     pub fn new<E: Element>(value: Vec<E>, strategy: QuantizationStrategy) -> Self {
         let mut bytes: Bytes;
         let num_elements = value.len();
+        let scheme = strategy.scheme();
 
-        match strategy {
-            QuantizationStrategy::PerTensorAffineInt8(q) => {
-                if TypeId::of::<E>() == TypeId::of::<i8>() {
-                    // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
-                    let u32s = pack_i8s_to_u32s(bytemuck::allocation::cast_vec(value));
-                    bytes = Bytes::from_elems(u32s);
-                } else {
-                    panic!("Invalid quantized type");
-                }
-                // Scale is always stored as f32 and zero-point offset as i32
-                let offset = q.offset as i32;
-                let scale_bytes = bytemuck::bytes_of(&q.scale);
-                let offset_bytes = bytemuck::bytes_of(&offset);
-                bytes.extend_from_byte_slice_aligned(offset_bytes, align_of::<i32>());
-                bytes.extend_from_byte_slice_aligned(scale_bytes, align_of::<f32>());
+        if TypeId::of::<E>() != TypeId::of::<i8>() {
+            panic!("Invalid quantized type");
+        }
+        // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
+        let value: Vec<i8> = bytemuck::allocation::cast_vec(value);
+        match scheme.q_type() {
+            QuantizationType::QInt8 => {
+                bytes = Bytes::from_elems(pack_i8s_to_u32s(value));
             }
-            QuantizationStrategy::PerTensorSymmetricInt8(q) => {
-                if TypeId::of::<E>() == TypeId::of::<i8>() {
-                    // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
-                    let u32s = pack_i8s_to_u32s(bytemuck::allocation::cast_vec(value));
-                    bytes = Bytes::from_elems(u32s);
-                } else {
-                    panic!("Invalid quantized type");
-                }
-                let scale_bytes = bytemuck::bytes_of(&q.scale);
-                bytes.extend_from_byte_slice_aligned(scale_bytes, align_of::<f32>());
+            q_type @ (QuantizationType::QInt4
+            | QuantizationType::QUInt4
+            | QuantizationType::QInt2
+            | QuantizationType::QUInt2) => {
+                bytes = Bytes::from_elems(pack_sub_bytes_to_u32s(&value, q_type.bits()));
             }
         }
 
+        // Scales are always stored as f32 and zero-point offsets as i32
+        let qparams = strategy.qparams();
+        if let Some(offset) = qparams.offset {
+            let offset = offset.into_iter().map(|x| x as i32).collect::<Vec<_>>();
+            bytes.extend_from_byte_slice_aligned(bytemuck::cast_slice(&offset), align_of::<i32>());
+        }
+        bytes.extend_from_byte_slice_aligned(
+            bytemuck::cast_slice(&qparams.scale),
+            align_of::<f32>(),
+        );
+
         Self {
             bytes,
-            scheme: strategy.scheme(),
+            scheme,
             num_elements,
         }
     }
 
-    /// Returns the int8 quantized values with the quantization parameters.
-    pub fn into_vec_i8(self) -> (Vec<i8>, QParams<f32, i8>) {
+    /// Returns the quantized values with the quantization parameters.
+    ///
+    /// Sub-byte quantized values (e.g., int4) are unpacked to a single value per `i8`.
+    pub fn into_vec_i8(self) -> (Vec<i8>, QParams<Vec<f32>, Vec<i8>>) {
         let numel = self.num_elements;
         let scheme = self.scheme;
         let (values, qparams) = self.split_values_off();
 
-        let values = unpack_u32s_to_i8s(values, numel);
+        let values = match scheme.q_type() {
+            QuantizationType::QInt8 => unpack_u32s_to_i8s(values, numel),
+            q_type => unpack_u32s_to_sub_bytes(&values, numel, q_type.bits(), q_type.is_signed()),
+        };
 
         // Quantization parameters are added at the end of the tensor data.
-        // As such, the last bytes always correspond to the scale parameter.
-        // If the quantization scheme includes an offset (zero-point) parameter, it is next to last.
-        let scale_size = core::mem::size_of::<f32>(); // scale is stored as f32
-        let qparams_bytes = bytemuck::cast_slice(&qparams);
-        let total_bytes = qparams_bytes.len();
-        let scale = *bytemuck::checked::from_bytes(&qparams_bytes[total_bytes - scale_size..]);
-
-        let offset = match scheme {
-            QuantizationScheme::PerTensorAffine(_) => {
-                let offset_size = core::mem::size_of::<i32>(); // zero-point offset is stored as i32
-                Some(*bytemuck::checked::from_bytes::<i32>(
-                    &qparams_bytes
-                        [total_bytes - scale_size - offset_size..total_bytes - scale_size],
-                ) as i8)
-            }
-            QuantizationScheme::PerTensorSymmetric(_) => None,
+        // As such, the last bytes always correspond to the scale parameters.
+        // If the quantization scheme includes offset (zero-point) parameters, they are next to last.
+        let num_params = if scheme.has_offset() {
+            qparams.len() / 2
+        } else {
+            qparams.len()
         };
+        let (offset, scale) = qparams.split_at(qparams.len() - num_params);
+
+        let scale = scale.iter().map(|x| f32::from_bits(*x)).collect();
+        let offset = scheme
+            .has_offset()
+            .then(|| offset.iter().map(|x| *x as i32 as i8).collect());
 
         (values, QParams { scale, offset })
     }
@@ -120,35 +124,23 @@ impl QuantizedBytes {
             _ => unreachable!(),
         };
 
-        let scale_size = 1; // f32 scale is the same number of bytes as u32
-        let mut values_end = values.len() - scale_size;
-
-        if let QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) = self.scheme {
-            values_end -= 1; // zero-point offset is stored as i32 (same number of bytes as u32)
-        }
-
+        // Every u32 packs multiple values (e.g., four int8 values), the remaining values are the
+        // quantization parameters
+        let values_end = self
+            .num_elements
+            .div_ceil(self.scheme.q_type().num_packed());
         let qparams = values.split_off(values_end);
 
         (values, qparams)
     }
 
-    /// Dequantizes the data according to its quantization scheme.
-    pub fn dequantize(self) -> (Vec<f32>, QParams<f32, i8>) {
-        match self.scheme {
-            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => {
-                let (values, qparams) = self.into_vec_i8();
-                let strategy = AffineQuantization::<f32, i8, i32>::init(
-                    qparams.scale,
-                    qparams.offset.unwrap(),
-                );
-                (strategy.dequantize(&values), qparams)
-            }
-            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                let (values, qparams) = self.into_vec_i8();
-                let strategy = SymmetricQuantization::<f32, i8>::init(qparams.scale);
-                (strategy.dequantize(&values), qparams)
-            }
-        }
+    /// Dequantizes the data of a tensor with the given shape according to its quantization scheme.
+    pub fn dequantize(self, shape: &[usize]) -> (Vec<f32>, QParams<Vec<f32>, Vec<i8>>) {
+        let scheme = self.scheme;
+        let (values, qparams) = self.into_vec_i8();
+        let strategy = QuantizationStrategy::from_qparams(scheme, qparams.clone());
+
+        (strategy.dequantize(&values, shape), qparams)
     }
 }
 
@@ -185,6 +177,7 @@ unsafe fn reinterpret_vec<T, U>(mut input: Vec<T>) -> Vec<U> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quantization::{AffineQuantization, SymmetricQuantization};
     use alloc::vec;
 
     #[test]
@@ -200,7 +193,7 @@ mod tests {
 
         let (q_values, qparams) = q_bytes.into_vec_i8();
 
-        assert_eq!(qparams.scale, scale);
+        assert_eq!(qparams.scale, vec![scale]);
         assert_eq!(qparams.offset, None);
 
         assert_eq!(q_values, values);
@@ -219,8 +212,109 @@ mod tests {
 
         let (q_values, qparams) = q_bytes.into_vec_i8();
 
-        assert_eq!(qparams.scale, scale);
-        assert_eq!(qparams.offset, Some(offset));
+        assert_eq!(qparams.scale, vec![scale]);
+        assert_eq!(qparams.offset, Some(vec![offset]));
+
+        assert_eq!(q_values, values);
+    }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_per_channel_affine() {
+        let scales = vec![0.019607844, 0.03137255];
+        let offsets = vec![-128i8, -1];
+        let values = vec![-128i8, -77, -26, 25, 76, 127];
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerChannelAffineInt8(
+                vec![
+                    AffineQuantization::init(scales[0], offsets[0]),
+                    AffineQuantization::init(scales[1], offsets[1]),
+                ],
+                0,
+            ),
+        );
+
+        let (q_values, qparams) = q_bytes.into_vec_i8();
+
+        assert_eq!(qparams.scale, scales);
+        assert_eq!(qparams.offset, Some(offsets));
+
+        assert_eq!(q_values, values);
+    }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_per_block_symmetric() {
+        let scales = vec![0.03937008, 0.01, 0.5];
+        let values = vec![0i8, 25, 51, 76, 102, 127];
+
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerBlockSymmetricInt8(
+                scales
+                    .iter()
+                    .map(|scale| SymmetricQuantization::init(*scale))
+                    .collect(),
+                2,
+            ),
+        );
+
+        let (q_values, qparams) = q_bytes.into_vec_i8();
+
+        assert_eq!(qparams.scale, scales);
+        assert_eq!(qparams.offset, None);
+
+        assert_eq!(q_values, values);
+    }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_int4_affine() {
+        let scale = 0.33333334;
+        let offset = -8;
+        // Quantized [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]
+        let values = vec![-8i8, -5, -2, 1, 4, 7];
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerTensorAffineInt8(
+                AffineQuantization::init(scale, offset).with_q_type(QuantizationType::QInt4),
+            ),
+        );
+
+        // Six int4 values are packed in a single u32, followed by the offset and scale
+        assert_eq!(q_bytes.bytes.len(), 3 * size_of::<u32>());
+
+        let (q_values, qparams) = q_bytes.into_vec_i8();
+
+        assert_eq!(qparams.scale, vec![scale]);
+        assert_eq!(qparams.offset, Some(vec![offset]));
+
+        assert_eq!(q_values, values);
+    }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_uint2_per_channel() {
+        let scales = vec![0.1, 0.2];
+        let offsets = vec![0, 3];
+        let values = vec![0i8, 1, 2, 3, 3, 2, 1, 0, 0, 1, 2, 3, 3, 2, 1, 0, 3, 3];
+
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerChannelAffineInt8(
+                scales
+                    .iter()
+                    .zip(offsets.iter())
+                    .map(|(scale, offset)| {
+                        AffineQuantization::init(*scale, *offset)
+                            .with_q_type(QuantizationType::QUInt2)
+                    })
+                    .collect(),
+                0,
+            ),
+        );
+
+        let (q_values, qparams) = q_bytes.into_vec_i8();
+
+        assert_eq!(qparams.scale, scales);
+        assert_eq!(qparams.offset, Some(offsets));
 
         assert_eq!(q_values, values);
     }
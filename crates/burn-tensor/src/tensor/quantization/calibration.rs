@@ -1,6 +1,11 @@
 use crate::{backend::Backend, Tensor};
 
+use super::QuantizationScheme;
+
 /// The observed input calibration range.
+///
+/// The range contains one `[min, max]` value pair for each set of quantization parameters of the
+/// scheme (i.e., a single pair for per-tensor quantization).
 #[derive(Clone, Debug)]
 pub struct CalibrationRange<B: Backend> {
     /// Minimum observed value.
@@ -11,23 +16,34 @@ pub struct CalibrationRange<B: Backend> {
 
 /// Calibration method used to compute the quantization range mapping.
 pub trait Calibration {
-    /// Compute the input tensor range.
+    /// Compute the input tensor range for the given quantization scheme.
     fn compute_range<B: Backend, const D: usize>(
         &self,
         tensor: &Tensor<B, D>,
+        scheme: &QuantizationScheme,
     ) -> CalibrationRange<B>;
 }
 
-/// Computes the per-tensor quantization range mapping based on the min and max values.
+/// Computes the quantization range mapping based on the min and max values.
 pub struct MinMaxCalibration {}
 
 impl Calibration for MinMaxCalibration {
     fn compute_range<B: Backend, const D: usize>(
         &self,
         tensor: &Tensor<B, D>,
+        scheme: &QuantizationScheme,
     ) -> CalibrationRange<B> {
-        let min = tensor.clone().min();
-        let max = tensor.clone().max();
+        if scheme.is_per_tensor() {
+            let min = tensor.clone().min();
+            let max = tensor.clone().max();
+
+            return CalibrationRange { min, max };
+        }
+
+        // Compute the range of each group of values sharing the same quantization parameters
+        let groups = scheme.group_values(tensor.clone());
+        let min = groups.clone().min_dim(1).squeeze(1);
+        let max = groups.max_dim(1).squeeze(1);
 
         CalibrationRange { min, max }
     }
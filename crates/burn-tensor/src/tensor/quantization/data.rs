@@ -82,6 +82,52 @@ pub fn unpack_u32s_to_i8s(values: Vec<u32>, numel: usize) -> Vec<i8> {
     }
 }
 
+/// Pack sub-byte integer values into a sequence of unsigned 32-bit integers.
+///
+/// Each value only keeps its `bits` least significant bits, so a single `u32` packs `32 / bits`
+/// values (e.g., eight 4-bit values). The first value is stored in the least significant bits.
+pub fn pack_sub_bytes_to_u32s(values: &[i8], bits: u32) -> Vec<u32> {
+    let num_packed = (u32::BITS / bits) as usize;
+    let mask = (1u32 << bits) - 1;
+
+    values
+        .chunks(num_packed)
+        .map(|x| {
+            x.iter().enumerate().fold(0u32, |acc, (i, x)| {
+                acc | (*x as u32 & mask) << (i as u32 * bits)
+            })
+        })
+        .collect()
+}
+
+/// Unpack 32-bit unsigned integer values into a sequence of sub-byte integer values with the given
+/// number of bits.
+///
+/// Signed values are sign-extended to `i8`.
+pub fn unpack_u32s_to_sub_bytes(values: &[u32], numel: usize, bits: u32, signed: bool) -> Vec<i8> {
+    let num_packed = (u32::BITS / bits) as usize;
+    let mask = (1u32 << bits) - 1;
+    // Shift used to sign-extend the value from its most significant bit
+    let shift = 8 - bits;
+
+    values
+        .iter()
+        .enumerate()
+        .flat_map(|(i, packed)| {
+            // A single u32 could contain less packed values than its capacity...
+            let n = core::cmp::min(num_packed, numel.saturating_sub(i * num_packed));
+            (0..n).map(move |j| {
+                let value = ((packed >> (j as u32 * bits)) & mask) as u8;
+                if signed {
+                    ((value << shift) as i8) >> shift
+                } else {
+                    value as i8
+                }
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,4 +162,31 @@ mod tests {
 
         assert_eq!(unpacked, vec![55]);
     }
+
+    #[test]
+    fn should_pack_unpack_signed_int4() {
+        let values = vec![-8, 7, -1, 0, 3, -3, 5, -6, 1];
+        let packed = pack_sub_bytes_to_u32s(&values, 4);
+
+        assert_eq!(packed, vec![0xA5D3_0F78, 0x1]);
+        assert_eq!(unpack_u32s_to_sub_bytes(&packed, 9, 4, true), values);
+    }
+
+    #[test]
+    fn should_pack_unpack_unsigned_int4() {
+        let values = vec![0, 15, 8, 1];
+        let packed = pack_sub_bytes_to_u32s(&values, 4);
+
+        assert_eq!(packed, vec![0x18F0]);
+        assert_eq!(unpack_u32s_to_sub_bytes(&packed, 4, 4, false), values);
+    }
+
+    #[test]
+    fn should_pack_unpack_signed_int2() {
+        let values = vec![-2, -1, 0, 1, 1];
+        let packed = pack_sub_bytes_to_u32s(&values, 2);
+
+        assert_eq!(packed, vec![0b01_01_00_11_10]);
+        assert_eq!(unpack_u32s_to_sub_bytes(&packed, 5, 2, true), values);
+    }
 }
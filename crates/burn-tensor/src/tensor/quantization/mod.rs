@@ -1,6 +1,7 @@
 mod bytes;
 mod calibration;
 mod data;
+mod observer;
 mod parameters;
 mod primitive;
 mod scheme;
@@ -9,6 +10,7 @@ mod strategy;
 pub use bytes::*;
 pub use calibration::*;
 pub use data::*;
+pub use observer::*;
 pub use parameters::*;
 pub use primitive::*;
 pub use scheme::*;
@@ -0,0 +1,401 @@
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{backend::Backend, ElementConversion, Tensor};
+
+use super::CalibrationRange;
+
+/// Observes the values of tensors to compute a static calibration range.
+///
+/// Unlike [calibration](super::Calibration), which computes the range of a single tensor,
+/// observers accumulate statistics over multiple tensors (e.g., the activations of a module for
+/// each batch of a representative dataset). The observed range applies to the whole tensor.
+pub trait Observer {
+    /// Update the observed statistics with the values of the tensor.
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>);
+
+    /// Returns the `(min, max)` range computed from the observed statistics.
+    fn range(&self) -> (f32, f32);
+
+    /// Compute the calibration range from the observed statistics.
+    fn compute_range<B: Backend>(&self, device: &B::Device) -> CalibrationRange<B> {
+        let (min, max) = self.range();
+
+        CalibrationRange {
+            min: Tensor::from_floats([min], device),
+            max: Tensor::from_floats([max], device),
+        }
+    }
+}
+
+/// Observes the minimum and maximum values of all the tensors.
+#[derive(Clone, Debug, Default)]
+pub struct MinMaxObserver {
+    range: Option<(f32, f32)>,
+}
+
+impl MinMaxObserver {
+    /// Create a new min-max observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Observer for MinMaxObserver {
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let (min, max) = tensor_min_max(tensor);
+
+        self.range = Some(match self.range {
+            Some((min_prev, max_prev)) => (min_prev.min(min), max_prev.max(max)),
+            None => (min, max),
+        });
+    }
+
+    fn range(&self) -> (f32, f32) {
+        self.range.unwrap_or_default()
+    }
+}
+
+/// Observes the exponential moving average of the minimum and maximum values of the tensors.
+#[derive(Clone, Debug)]
+pub struct MovingAverageMinMaxObserver {
+    momentum: f32,
+    range: Option<(f32, f32)>,
+}
+
+impl MovingAverageMinMaxObserver {
+    /// Create a new moving average min-max observer with the given momentum used to update the
+    /// range.
+    pub fn new(momentum: f32) -> Self {
+        Self {
+            momentum,
+            range: None,
+        }
+    }
+}
+
+impl Observer for MovingAverageMinMaxObserver {
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let (min, max) = tensor_min_max(tensor);
+
+        self.range = Some(match self.range {
+            Some((min_prev, max_prev)) => (
+                min_prev + (min - min_prev) * self.momentum,
+                max_prev + (max - max_prev) * self.momentum,
+            ),
+            None => (min, max),
+        });
+    }
+
+    fn range(&self) -> (f32, f32) {
+        self.range.unwrap_or_default()
+    }
+}
+
+/// Observes the distribution of the values with a histogram, and clips the range to the given
+/// percentile to ignore outliers.
+#[derive(Clone, Debug)]
+pub struct PercentileObserver {
+    percentile: f32,
+    histogram: Histogram,
+}
+
+impl PercentileObserver {
+    /// Create a new percentile observer (e.g., `99.99`).
+    pub fn new(percentile: f32) -> Self {
+        assert!(
+            (0.0..=100.0).contains(&percentile),
+            "Percentile must be in range [0, 100], got {percentile}"
+        );
+
+        Self {
+            percentile,
+            histogram: Histogram::new(2048),
+        }
+    }
+}
+
+impl Observer for PercentileObserver {
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        self.histogram.update(&tensor_values(tensor));
+    }
+
+    fn range(&self) -> (f32, f32) {
+        let tail = (100.0 - self.percentile) / 100.0;
+        (
+            self.histogram.quantile(tail),
+            self.histogram.quantile(1.0 - tail),
+        )
+    }
+}
+
+/// Observes the distribution of the absolute values with a histogram, and selects the symmetric
+/// range that minimizes the KL divergence (i.e., relative entropy) between the distribution of the
+/// values and their quantized distribution.
+#[derive(Clone, Debug)]
+pub struct HistogramObserver {
+    num_quantized_bins: usize,
+    histogram: Histogram,
+    min: f32,
+}
+
+impl HistogramObserver {
+    /// Create a new histogram observer for the given number of quantized values on each side of
+    /// the range (e.g., 128 for 8-bit integers).
+    pub fn new(num_quantized_bins: usize) -> Self {
+        Self {
+            num_quantized_bins,
+            histogram: Histogram::new(2048),
+            min: 0.0,
+        }
+    }
+}
+
+impl Observer for HistogramObserver {
+    fn observe<B: Backend, const D: usize>(&mut self, tensor: &Tensor<B, D>) {
+        let values = tensor_values(tensor);
+        self.min = values.iter().fold(self.min, |acc, v| acc.min(*v));
+
+        let values = values.into_iter().map(|v| v.abs()).collect::<Vec<_>>();
+        // The histogram of the absolute values always starts at 0
+        self.histogram.update_with_range(&values, 0.0);
+    }
+
+    fn range(&self) -> (f32, f32) {
+        let threshold = self.histogram.entropy_threshold(self.num_quantized_bins);
+
+        // Non-negative values (e.g., after ReLU) don't need the negative side of the range
+        match self.min < 0.0 {
+            true => (-threshold, threshold),
+            false => (0.0, threshold),
+        }
+    }
+}
+
+/// Histogram with a fixed number of bins over a range that grows with the observed values.
+#[derive(Clone, Debug)]
+struct Histogram {
+    counts: Vec<f64>,
+    min: f32,
+    max: f32,
+}
+
+impl Histogram {
+    fn new(num_bins: usize) -> Self {
+        Self {
+            counts: vec![0.0; num_bins],
+            min: 0.0,
+            max: 0.0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.counts.iter().all(|count| *count == 0.0)
+    }
+
+    fn bin_width(&self) -> f32 {
+        (self.max - self.min) / self.counts.len() as f32
+    }
+
+    fn update(&mut self, values: &[f32]) {
+        let min = values.iter().fold(f32::MAX, |acc, v| acc.min(*v));
+        self.update_with_range(values, min);
+    }
+
+    fn update_with_range(&mut self, values: &[f32], min: f32) {
+        if values.is_empty() {
+            return;
+        }
+        let max = values.iter().fold(f32::MIN, |acc, v| acc.max(*v));
+
+        if self.is_empty() {
+            self.min = min;
+            self.max = max;
+        } else if min < self.min || max > self.max {
+            self.extend(min.min(self.min), max.max(self.max));
+        }
+
+        for value in values {
+            let index = self.bin_index(*value);
+            self.counts[index] += 1.0;
+        }
+    }
+
+    fn bin_index(&self, value: f32) -> usize {
+        let width = self.bin_width();
+        if width == 0.0 {
+            return 0;
+        }
+
+        let index = ((value - self.min) / width) as usize;
+        index.min(self.counts.len() - 1)
+    }
+
+    /// Redistribute the counts of each bin into the bins of the extended range.
+    fn extend(&mut self, min: f32, max: f32) {
+        let width = self.bin_width();
+        let num_bins = self.counts.len();
+        let counts = core::mem::replace(&mut self.counts, vec![0.0; num_bins]);
+        let min_prev = self.min;
+
+        self.min = min;
+        self.max = max;
+
+        for (i, count) in counts.into_iter().enumerate() {
+            let center = min_prev + (i as f32 + 0.5) * width;
+            let index = self.bin_index(center);
+            self.counts[index] += count;
+        }
+    }
+
+    /// Returns the value below which the given fraction of the observed values fall.
+    fn quantile(&self, fraction: f32) -> f32 {
+        let total = self.counts.iter().sum::<f64>();
+        let target = total * fraction as f64;
+        let width = self.bin_width();
+
+        let mut cumsum = 0.0;
+        for (i, count) in self.counts.iter().enumerate() {
+            if cumsum + count >= target && *count > 0.0 {
+                // Linear interpolation within the bin
+                let ratio = ((target - cumsum) / count) as f32;
+                return self.min + (i as f32 + ratio) * width;
+            }
+            cumsum += count;
+        }
+
+        self.max
+    }
+
+    /// Returns the threshold that minimizes the KL divergence between the observed distribution
+    /// clipped to the threshold and its quantized distribution with `num_quantized_bins` levels.
+    fn entropy_threshold(&self, num_quantized_bins: usize) -> f32 {
+        let num_bins = self.counts.len();
+        let width = self.bin_width();
+
+        if num_bins <= num_quantized_bins {
+            return self.max;
+        }
+
+        let mut best = (f64::MAX, num_bins);
+        for i in num_quantized_bins..=num_bins {
+            // Reference distribution, with the outliers clipped into the last bin
+            let mut reference = self.counts[..i].to_vec();
+            reference[i - 1] += self.counts[i..].iter().sum::<f64>();
+
+            let candidate = quantize_distribution(&self.counts[..i], num_quantized_bins);
+            let divergence = kl_divergence(&reference, &candidate);
+
+            if divergence < best.0 {
+                best = (divergence, i);
+            }
+        }
+
+        self.min + best.1 as f32 * width
+    }
+}
+
+/// Merges the bins into `num_quantized_bins` levels, and expands each level back to the non-empty
+/// bins it was merged from.
+fn quantize_distribution(counts: &[f64], num_quantized_bins: usize) -> Vec<f64> {
+    let num_merged_bins = counts.len() as f64 / num_quantized_bins as f64;
+    let mut output = vec![0.0; counts.len()];
+
+    for j in 0..num_quantized_bins {
+        let start = (j as f64 * num_merged_bins) as usize;
+        let end = match j == num_quantized_bins - 1 {
+            true => counts.len(),
+            false => ((j + 1) as f64 * num_merged_bins) as usize,
+        };
+
+        let bins = &counts[start..end];
+        let total = bins.iter().sum::<f64>();
+        let num_non_empty = bins.iter().filter(|count| **count > 0.0).count();
+
+        if num_non_empty > 0 {
+            let value = total / num_non_empty as f64;
+            for (k, count) in bins.iter().enumerate() {
+                if *count > 0.0 {
+                    output[start + k] = value;
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Computes the KL divergence between the (unnormalized) distributions `p` and `q`.
+fn kl_divergence(p: &[f64], q: &[f64]) -> f64 {
+    let p_sum = p.iter().sum::<f64>();
+    let q_sum = q.iter().sum::<f64>();
+
+    if p_sum == 0.0 || q_sum == 0.0 {
+        return f64::MAX;
+    }
+
+    p.iter()
+        .zip(q.iter())
+        .filter(|(p, _)| **p > 0.0)
+        .map(|(p, q)| {
+            let p = p / p_sum;
+            // Empty quantized bins for non-empty reference bins are heavily penalized
+            let q = match *q > 0.0 {
+                true => q / q_sum,
+                false => 1e-10,
+            };
+            p * (p / q).ln()
+        })
+        .sum()
+}
+
+fn tensor_min_max<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> (f32, f32) {
+    let min = tensor.clone().min().into_scalar().elem::<f32>();
+    let max = tensor.clone().max().into_scalar().elem::<f32>();
+
+    (min, max)
+}
+
+fn tensor_values<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> Vec<f32> {
+    tensor.to_data().iter::<f32>().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_should_redistribute_counts_when_range_grows() {
+        let mut histogram = Histogram::new(4);
+        histogram.update(&[0.0, 1.0, 2.0, 3.0]);
+        histogram.update(&[7.0]);
+
+        assert_eq!(histogram.min, 0.0);
+        assert_eq!(histogram.max, 7.0);
+        assert_eq!(histogram.counts, vec![2.0, 2.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn histogram_quantile() {
+        let mut histogram = Histogram::new(10);
+        histogram.update(&(0..100).map(|i| i as f32 * 0.1).collect::<Vec<_>>());
+
+        let median = histogram.quantile(0.5);
+        assert!((median - 4.95).abs() < 0.1, "{median}");
+    }
+
+    #[test]
+    fn entropy_threshold_should_clip_outliers() {
+        let mut histogram = Histogram::new(64);
+        let mut values = (0..1000)
+            .map(|i| (i % 100) as f32 * 0.01)
+            .collect::<Vec<_>>();
+        values.push(10.0);
+        histogram.update_with_range(&values, 0.0);
+
+        let threshold = histogram.entropy_threshold(8);
+        assert!(threshold < 2.0, "{threshold}");
+    }
+}
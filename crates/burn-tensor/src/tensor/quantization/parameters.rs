@@ -1,6 +1,9 @@
 use crate::{backend::Backend, Int, Tensor};
 
 /// The tensor quantization parameters.
+///
+/// The scale and offset tensors contain one value for each group of values that share the same
+/// quantization parameters (see [params_layout](super::QuantizationScheme::params_layout)).
 pub type QuantizationParameters<B> = QParams<Tensor<B, 1>, Tensor<B, 1, Int>>;
 
 /// The quantization tensor data parameters.
@@ -1,8 +1,9 @@
 #![allow(missing_docs)] // cube derive macros
 
+use alloc::vec;
 use serde::{Deserialize, Serialize};
 
-use crate::{backend::Backend, Tensor, TensorPrimitive};
+use crate::{backend::Backend, ops::FloatTensor, Shape, Tensor, TensorMetadata, TensorPrimitive};
 
 use super::{CalibrationRange, QuantizationParameters, QuantizationParametersPrimitive};
 
@@ -15,6 +16,62 @@ use cubecl::prelude::*;
 pub enum QuantizationType {
     /// 8-bit signed integer.
     QInt8,
+    /// 4-bit signed integer.
+    QInt4,
+    /// 4-bit unsigned integer.
+    QUInt4,
+    /// 2-bit signed integer.
+    QInt2,
+    /// 2-bit unsigned integer.
+    QUInt2,
+}
+
+impl QuantizationType {
+    /// Returns the number of bits used to represent a quantized value.
+    pub fn bits(&self) -> u32 {
+        match self {
+            QuantizationType::QInt8 => 8,
+            QuantizationType::QInt4 | QuantizationType::QUInt4 => 4,
+            QuantizationType::QInt2 | QuantizationType::QUInt2 => 2,
+        }
+    }
+
+    /// Returns true if the quantized values are signed.
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            QuantizationType::QInt8 | QuantizationType::QInt4 | QuantizationType::QInt2
+        )
+    }
+
+    /// Returns the number of quantized values packed into a single `u32`.
+    pub fn num_packed(&self) -> usize {
+        (u32::BITS / self.bits()) as usize
+    }
+
+    /// Returns the `[min, max]` range of the quantized values.
+    pub fn range(&self) -> (i32, i32) {
+        let bits = self.bits();
+        if self.is_signed() {
+            (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+        } else {
+            (0, (1 << bits) - 1)
+        }
+    }
+
+    /// Returns the `[-max, max]` range of the quantized values for symmetric quantization.
+    ///
+    /// # Panics
+    ///
+    /// If the quantized type is unsigned.
+    pub fn symmetric_range(&self) -> (i32, i32) {
+        assert!(
+            self.is_signed(),
+            "Symmetric quantization is only valid for signed integers."
+        );
+        let (_, b) = self.range();
+        (-b, b)
+    }
 }
 
 /// Quantization scheme.
@@ -25,10 +82,39 @@ pub enum QuantizationScheme {
     PerTensorAffine(QuantizationType),
     /// Per-tensor symmetric quantization.
     PerTensorSymmetric(QuantizationType),
-    // /// Per-channel affine/asymmetric quantization.
-    // PerChannelAffine,
-    // /// Per-channel symmetric quantization.
-    // PerChannelSymmetric,
+    /// Per-channel affine/asymmetric quantization along the given axis.
+    PerChannelAffine(QuantizationType, usize),
+    /// Per-channel symmetric quantization along the given axis.
+    PerChannelSymmetric(QuantizationType, usize),
+    /// Block-wise affine/asymmetric quantization with the given block size.
+    ///
+    /// Blocks are made of contiguous elements in row-major order, so the number of elements
+    /// of the tensor must be a multiple of the block size.
+    PerBlockAffine(QuantizationType, usize),
+    /// Block-wise symmetric quantization with the given block size.
+    ///
+    /// Blocks are made of contiguous elements in row-major order, so the number of elements
+    /// of the tensor must be a multiple of the block size.
+    PerBlockSymmetric(QuantizationType, usize),
+}
+
+/// Describes how the elements of a tensor map to their quantization parameters.
+///
+/// The element at the flat (row-major) index `i` uses the quantization parameters at index
+/// `(i / stride) % num_params`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QParamsLayout {
+    /// The number of contiguous elements sharing the same quantization parameters.
+    pub stride: usize,
+    /// The number of quantization parameters.
+    pub num_params: usize,
+}
+
+impl QParamsLayout {
+    /// Returns the index of the quantization parameters for the element at the given flat index.
+    pub fn param_index(&self, index: usize) -> usize {
+        (index / self.stride) % self.num_params
+    }
 }
 
 #[cfg(feature = "cubecl")]
@@ -43,49 +129,218 @@ impl cubecl::frontend::Init for QuantizationScheme {
 }
 
 impl QuantizationScheme {
+    /// Returns the quantized data type.
+    pub const fn q_type(&self) -> QuantizationType {
+        match self {
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelAffine(dtype, _)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _)
+            | QuantizationScheme::PerBlockAffine(dtype, _)
+            | QuantizationScheme::PerBlockSymmetric(dtype, _) => *dtype,
+        }
+    }
+
+    /// Returns true if the scheme uses a zero-point offset (i.e., affine range mapping).
+    pub fn has_offset(&self) -> bool {
+        matches!(
+            self,
+            QuantizationScheme::PerTensorAffine(_)
+                | QuantizationScheme::PerChannelAffine(..)
+                | QuantizationScheme::PerBlockAffine(..)
+        )
+    }
+
+    /// Returns true if a single set of quantization parameters is used for the whole tensor.
+    pub fn is_per_tensor(&self) -> bool {
+        matches!(
+            self,
+            QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_)
+        )
+    }
+
+    /// Returns the per-tensor scheme with the same quantized data type and range mapping.
+    pub fn to_per_tensor(&self) -> Self {
+        if self.has_offset() {
+            QuantizationScheme::PerTensorAffine(self.q_type())
+        } else {
+            QuantizationScheme::PerTensorSymmetric(self.q_type())
+        }
+    }
+
+    /// Returns true if a tensor of the given shape can be quantized with this scheme.
+    ///
+    /// Per-channel schemes require the channel axis to exist, and block-wise schemes require
+    /// the number of elements to be a multiple of the block size.
+    pub fn is_compatible(&self, shape: &[usize]) -> bool {
+        match self {
+            QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+                true
+            }
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => *axis < shape.len(),
+            QuantizationScheme::PerBlockAffine(_, block_size)
+            | QuantizationScheme::PerBlockSymmetric(_, block_size) => {
+                *block_size > 0 && shape.iter().product::<usize>() % block_size == 0
+            }
+        }
+    }
+
+    /// Returns the layout of the quantization parameters for a tensor of the given shape.
+    ///
+    /// # Panics
+    ///
+    /// If the channel axis is out of bounds, or if the number of elements is not a multiple of
+    /// the block size.
+    pub fn params_layout(&self, shape: &[usize]) -> QParamsLayout {
+        assert!(
+            self.is_compatible(shape),
+            "Quantization scheme {self:?} is not compatible with shape {shape:?}"
+        );
+
+        let num_elements = shape.iter().product::<usize>();
+        match self {
+            QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+                QParamsLayout {
+                    stride: num_elements.max(1),
+                    num_params: 1,
+                }
+            }
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => QParamsLayout {
+                stride: shape[axis + 1..].iter().product::<usize>().max(1),
+                num_params: shape[*axis].max(1),
+            },
+            QuantizationScheme::PerBlockAffine(_, block_size)
+            | QuantizationScheme::PerBlockSymmetric(_, block_size) => QParamsLayout {
+                stride: *block_size,
+                num_params: (num_elements / block_size).max(1),
+            },
+        }
+    }
+
+    /// Reshapes the tensor into a `[num_params, group_size]` matrix, where each row contains
+    /// the values that share the same quantization parameters.
+    pub fn group_values<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, 2> {
+        Tensor::from_primitive(TensorPrimitive::Float(
+            self.group_values_primitive::<B>(tensor.into_primitive().tensor()),
+        ))
+    }
+
+    /// Reshapes the tensor primitive into a `[num_params, group_size]` matrix, where each row contains
+    /// the values that share the same quantization parameters.
+    pub fn group_values_primitive<B: Backend>(&self, tensor: FloatTensor<B>) -> FloatTensor<B> {
+        let shape = tensor.shape();
+        let layout = self.params_layout(&shape.dims);
+        let num_elements = shape.num_elements();
+
+        let tensor = match self {
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis)
+                if *axis != 0 =>
+            {
+                // Move the channels to the first dimension so each row holds a single channel
+                B::float_swap_dims(tensor, 0, *axis)
+            }
+            _ => tensor,
+        };
+
+        B::float_reshape(
+            tensor,
+            Shape::new([layout.num_params, num_elements / layout.num_params]),
+        )
+    }
+
+    /// Reshapes the values of the quantization parameters (one for each group of values) so they can
+    /// be broadcast against a tensor of the given shape.
+    pub fn expand_params_primitive<B: Backend>(
+        &self,
+        params: FloatTensor<B>,
+        shape: &Shape,
+    ) -> FloatTensor<B> {
+        let layout = self.params_layout(&shape.dims);
+        let mut dims = vec![1; shape.num_dims()];
+
+        match self {
+            QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+                B::float_reshape(params, Shape::from(dims))
+            }
+            QuantizationScheme::PerChannelAffine(_, axis)
+            | QuantizationScheme::PerChannelSymmetric(_, axis) => {
+                dims[*axis] = layout.num_params;
+                B::float_reshape(params, Shape::from(dims))
+            }
+            QuantizationScheme::PerBlockAffine(..) | QuantizationScheme::PerBlockSymmetric(..) => {
+                // Repeat the parameters of each block for its contiguous values
+                let params = B::float_reshape(params, Shape::new([layout.num_params, 1]));
+                let params =
+                    B::float_expand(params, Shape::new([layout.num_params, layout.stride]));
+                B::float_reshape(params, shape.clone())
+            }
+        }
+    }
+
+    /// Compute the `(min, max)` range of the tensor values for each set of quantization parameters.
+    pub(crate) fn compute_range_primitive<B: Backend>(
+        &self,
+        tensor: FloatTensor<B>,
+    ) -> (FloatTensor<B>, FloatTensor<B>) {
+        if self.is_per_tensor() {
+            return (B::float_min(tensor.clone()), B::float_max(tensor));
+        }
+
+        let groups = self.group_values_primitive::<B>(tensor);
+        let shape = Shape::new([groups.shape().dims[0]]);
+        let min = B::float_reshape(B::float_min_dim(groups.clone(), 1), shape.clone());
+        let max = B::float_reshape(B::float_max_dim(groups, 1), shape);
+
+        (min, max)
+    }
+
     /// Compute the quantization parameters.
+    ///
+    /// The calibration range must contain one `[min, max]` value pair for each set of quantization
+    /// parameters (see [params_layout](Self::params_layout)).
     pub fn compute_q_params<B: Backend>(
         &self,
         range: CalibrationRange<B>,
     ) -> QuantizationParameters<B> {
         match self {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let a = i8::MIN as i32;
-                    let b = i8::MAX as i32;
-
-                    // We extend the `[min, max]` interval to ensure that it contains 0.
-                    // Otherwise, we would not meet the requirement that 0 be an exactly
-                    // representable value (zero-point).
-                    let zero = Tensor::zeros_like(&range.min);
-                    let min = range.min.min_pair(zero);
-                    let zero = Tensor::zeros_like(&range.max);
-                    let max = range.max.max_pair(zero);
-
-                    // If scale is 0 (most likely due to a tensor full of zeros), we arbitrarily adjust the
-                    // scale to 0.1 to avoid division by zero.
-                    let scale = max.sub(min.clone()).div_scalar(b - a);
-                    let scale = scale.clone().mask_fill(scale.equal_elem(0.), 0.1);
-                    let offset = Some(-(min.div(scale.clone()).sub_scalar(a)).int());
-                    QuantizationParameters { scale, offset }
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let b = i8::MAX as i32;
-                    let a = -b;
-
-                    // Compute scale to convert an input value in range `[-alpha, alpha]`
-                    let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
-
-                    QuantizationParameters {
-                        scale: values_range.div_scalar(b - a),
-                        offset: None,
-                    }
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerChannelAffine(dtype, _)
+            | QuantizationScheme::PerBlockAffine(dtype, _) => {
+                // Quantized range `[a, b]`
+                let (a, b) = dtype.range();
+
+                // We extend the `[min, max]` interval to ensure that it contains 0.
+                // Otherwise, we would not meet the requirement that 0 be an exactly
+                // representable value (zero-point).
+                let zero = Tensor::zeros_like(&range.min);
+                let min = range.min.min_pair(zero);
+                let zero = Tensor::zeros_like(&range.max);
+                let max = range.max.max_pair(zero);
+
+                // If scale is 0 (most likely due to a tensor full of zeros), we arbitrarily adjust the
+                // scale to 0.1 to avoid division by zero.
+                let scale = max.sub(min.clone()).div_scalar(b - a);
+                let scale = scale.clone().mask_fill(scale.equal_elem(0.), 0.1);
+                let offset = Some(-(min.div(scale.clone()).sub_scalar(a)).int());
+                QuantizationParameters { scale, offset }
+            }
+            QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _)
+            | QuantizationScheme::PerBlockSymmetric(dtype, _) => {
+                // Quantized range `[a, b]`
+                let (a, b) = dtype.symmetric_range();
+
+                // Compute scale to convert an input value in range `[-alpha, alpha]`
+                let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
+
+                QuantizationParameters {
+                    scale: values_range.div_scalar(b - a),
+                    offset: None,
                 }
-            },
+            }
         }
     }
 
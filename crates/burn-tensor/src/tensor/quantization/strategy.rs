@@ -8,36 +8,243 @@ use burn_common::{iter_slice_par, run_par};
 use num_traits::{Float, PrimInt};
 use serde::{Deserialize, Serialize};
 
-use super::{QuantizationScheme, QuantizationType};
+use super::{QParams, QParamsLayout, QuantizationScheme, QuantizationType};
 
 /// Quantization strategy.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The quantized values are always represented as `i8`, which the `Int8` suffix of the variants
+/// refers to, but the actual range of the values depends on the quantized data type of the
+/// strategy (e.g., `[-8, 7]` for [QInt4](QuantizationType::QInt4)).
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QuantizationStrategy {
-    /// Per-tensor `int8` affine/asymmetric quantization.
+    /// Per-tensor affine/asymmetric quantization to values stored as `i8`.
     PerTensorAffineInt8(AffineQuantization<f32, i8, i32>),
-    /// Per-tensor `int8` symmetric quantization.
+    /// Per-tensor symmetric quantization to values stored as `i8`.
     PerTensorSymmetricInt8(SymmetricQuantization<f32, i8>),
+    /// Per-channel affine/asymmetric quantization along the given axis, to values stored as `i8`.
+    PerChannelAffineInt8(Vec<AffineQuantization<f32, i8, i32>>, usize),
+    /// Per-channel symmetric quantization along the given axis, to values stored as `i8`.
+    PerChannelSymmetricInt8(Vec<SymmetricQuantization<f32, i8>>, usize),
+    /// Block-wise affine/asymmetric quantization with the given block size, to values stored as `i8`.
+    PerBlockAffineInt8(Vec<AffineQuantization<f32, i8, i32>>, usize),
+    /// Block-wise symmetric quantization with the given block size, to values stored as `i8`.
+    PerBlockSymmetricInt8(Vec<SymmetricQuantization<f32, i8>>, usize),
 }
 
 impl QuantizationStrategy {
+    /// Creates the quantization strategy for the given scheme from its quantization parameters.
+    ///
+    /// # Panics
+    ///
+    /// If the scheme requires a zero-point offset and none is provided.
+    pub fn from_qparams(scheme: QuantizationScheme, qparams: QParams<Vec<f32>, Vec<i8>>) -> Self {
+        fn affine(
+            qparams: QParams<Vec<f32>, Vec<i8>>,
+            q_type: QuantizationType,
+        ) -> Vec<AffineQuantization<f32, i8, i32>> {
+            let offset = qparams
+                .offset
+                .expect("Affine quantization requires a zero-point offset");
+            qparams
+                .scale
+                .into_iter()
+                .zip(offset)
+                .map(|(scale, offset)| AffineQuantization::init(scale, offset).with_q_type(q_type))
+                .collect()
+        }
+        fn symmetric(
+            qparams: QParams<Vec<f32>, Vec<i8>>,
+            q_type: QuantizationType,
+        ) -> Vec<SymmetricQuantization<f32, i8>> {
+            qparams
+                .scale
+                .into_iter()
+                .map(|scale| SymmetricQuantization::init(scale).with_q_type(q_type))
+                .collect()
+        }
+
+        let q_type = scheme.q_type();
+        match scheme {
+            QuantizationScheme::PerTensorAffine(_) => {
+                QuantizationStrategy::PerTensorAffineInt8(affine(qparams, q_type)[0])
+            }
+            QuantizationScheme::PerTensorSymmetric(_) => {
+                QuantizationStrategy::PerTensorSymmetricInt8(symmetric(qparams, q_type)[0])
+            }
+            QuantizationScheme::PerChannelAffine(_, axis) => {
+                QuantizationStrategy::PerChannelAffineInt8(affine(qparams, q_type), axis)
+            }
+            QuantizationScheme::PerChannelSymmetric(_, axis) => {
+                QuantizationStrategy::PerChannelSymmetricInt8(symmetric(qparams, q_type), axis)
+            }
+            QuantizationScheme::PerBlockAffine(_, block_size) => {
+                QuantizationStrategy::PerBlockAffineInt8(affine(qparams, q_type), block_size)
+            }
+            QuantizationScheme::PerBlockSymmetric(_, block_size) => {
+                QuantizationStrategy::PerBlockSymmetricInt8(symmetric(qparams, q_type), block_size)
+            }
+        }
+    }
+
+    /// Returns the quantized data type.
+    ///
+    /// # Panics
+    ///
+    /// If a per-channel or block-wise strategy holds no quantization parameters.
+    pub fn q_type(&self) -> QuantizationType {
+        const EMPTY: &str = "Per-channel and block-wise strategies require quantization parameters";
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(q) => q.q_type,
+            QuantizationStrategy::PerTensorSymmetricInt8(q) => q.q_type,
+            QuantizationStrategy::PerChannelAffineInt8(q, _)
+            | QuantizationStrategy::PerBlockAffineInt8(q, _) => q.first().expect(EMPTY).q_type,
+            QuantizationStrategy::PerChannelSymmetricInt8(q, _)
+            | QuantizationStrategy::PerBlockSymmetricInt8(q, _) => q.first().expect(EMPTY).q_type,
+        }
+    }
+
     /// Returns the corresponding quantization scheme.
+    ///
+    /// # Panics
+    ///
+    /// If a per-channel or block-wise strategy holds no quantization parameters.
     pub fn scheme(&self) -> QuantizationScheme {
+        let q_type = self.q_type();
         match self {
             QuantizationStrategy::PerTensorAffineInt8(_) => {
-                QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
+                QuantizationScheme::PerTensorAffine(q_type)
             }
             QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8)
+                QuantizationScheme::PerTensorSymmetric(q_type)
+            }
+            QuantizationStrategy::PerChannelAffineInt8(_, axis) => {
+                QuantizationScheme::PerChannelAffine(q_type, *axis)
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(_, axis) => {
+                QuantizationScheme::PerChannelSymmetric(q_type, *axis)
+            }
+            QuantizationStrategy::PerBlockAffineInt8(_, block_size) => {
+                QuantizationScheme::PerBlockAffine(q_type, *block_size)
+            }
+            QuantizationStrategy::PerBlockSymmetricInt8(_, block_size) => {
+                QuantizationScheme::PerBlockSymmetric(q_type, *block_size)
             }
         }
     }
+
+    /// Returns the quantization parameters, with one scale (and offset) for each group of values.
+    pub fn qparams(&self) -> QParams<Vec<f32>, Vec<i8>> {
+        fn affine(strategies: &[AffineQuantization<f32, i8, i32>]) -> QParams<Vec<f32>, Vec<i8>> {
+            QParams {
+                scale: strategies.iter().map(|q| q.scale).collect(),
+                offset: Some(strategies.iter().map(|q| q.offset).collect()),
+            }
+        }
+        fn symmetric(strategies: &[SymmetricQuantization<f32, i8>]) -> QParams<Vec<f32>, Vec<i8>> {
+            QParams {
+                scale: strategies.iter().map(|q| q.scale).collect(),
+                offset: None,
+            }
+        }
+
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(q) => affine(core::slice::from_ref(q)),
+            QuantizationStrategy::PerTensorSymmetricInt8(q) => symmetric(core::slice::from_ref(q)),
+            QuantizationStrategy::PerChannelAffineInt8(q, _)
+            | QuantizationStrategy::PerBlockAffineInt8(q, _) => affine(q),
+            QuantizationStrategy::PerChannelSymmetricInt8(q, _)
+            | QuantizationStrategy::PerBlockSymmetricInt8(q, _) => symmetric(q),
+        }
+    }
+
+    /// Convert the values of a tensor with the given shape to a lower precision data type.
+    pub fn quantize(&self, values: &[f32], shape: &[usize]) -> Vec<i8> {
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(q) => q.quantize(values),
+            QuantizationStrategy::PerTensorSymmetricInt8(q) => q.quantize(values),
+            QuantizationStrategy::PerChannelAffineInt8(q, _)
+            | QuantizationStrategy::PerBlockAffineInt8(q, _) => {
+                quantize_groups(values, q, self.scheme().params_layout(shape))
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(q, _)
+            | QuantizationStrategy::PerBlockSymmetricInt8(q, _) => {
+                quantize_groups(values, q, self.scheme().params_layout(shape))
+            }
+        }
+    }
+
+    /// Convert the values of a tensor with the given shape back to a higher precision data type.
+    pub fn dequantize(&self, values: &[i8], shape: &[usize]) -> Vec<f32> {
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(q) => q.dequantize(values),
+            QuantizationStrategy::PerTensorSymmetricInt8(q) => q.dequantize(values),
+            QuantizationStrategy::PerChannelAffineInt8(q, _)
+            | QuantizationStrategy::PerBlockAffineInt8(q, _) => {
+                dequantize_groups(values, q, self.scheme().params_layout(shape))
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(q, _)
+            | QuantizationStrategy::PerBlockSymmetricInt8(q, _) => {
+                dequantize_groups(values, q, self.scheme().params_layout(shape))
+            }
+        }
+    }
+}
+
+fn quantize_groups<E, Q, S>(values: &[E], strategies: &[S], layout: QParamsLayout) -> Vec<Q>
+where
+    E: Float + Send + Sync,
+    Q: PrimInt + Send + Sync,
+    S: Quantization<E, Q>,
+{
+    assert_eq!(
+        strategies.len(),
+        layout.num_params,
+        "Expected {} quantization parameters, got {}",
+        layout.num_params,
+        strategies.len()
+    );
+    // Each chunk of `stride` contiguous values shares the same quantization parameters
+    values
+        .chunks(layout.stride)
+        .enumerate()
+        .flat_map(|(i, chunk)| strategies[i % layout.num_params].quantize(chunk))
+        .collect()
+}
+
+fn dequantize_groups<E, Q, S>(values: &[Q], strategies: &[S], layout: QParamsLayout) -> Vec<E>
+where
+    E: Float + Send + Sync,
+    Q: PrimInt + Send + Sync,
+    S: Quantization<E, Q>,
+{
+    assert_eq!(
+        strategies.len(),
+        layout.num_params,
+        "Expected {} quantization parameters, got {}",
+        layout.num_params,
+        strategies.len()
+    );
+    values
+        .chunks(layout.stride)
+        .enumerate()
+        .flat_map(|(i, chunk)| strategies[i % layout.num_params].dequantize(chunk))
+        .collect()
 }
 
 /// Quantization scheme to convert elements of a higher precision data type `E` to a lower precision
 /// data type `Q` and vice-versa.
 pub trait Quantization<E: Float + Send + Sync, Q: PrimInt + Send + Sync> {
-    /// Create a new quantization scheme for an input range `[alpha, beta]`.
-    fn new(alpha: E, beta: E) -> Self;
+    /// Create a new quantization scheme for an input range `[alpha, beta]`, mapped to the
+    /// [QInt8](QuantizationType::QInt8) range.
+    fn new(alpha: E, beta: E) -> Self
+    where
+        Self: Sized,
+    {
+        Self::new_with_type(alpha, beta, QuantizationType::QInt8)
+    }
+    /// Create a new quantization scheme for an input range `[alpha, beta]`, mapped to the range
+    /// of the given quantized data type.
+    fn new_with_type(alpha: E, beta: E, q_type: QuantizationType) -> Self;
     /// Convert the values to a lower precision data type.
     fn quantize(&self, values: &[E]) -> Vec<Q>;
     /// Convert the values back to a higher precision data type.
@@ -53,6 +260,8 @@ pub struct AffineQuantization<E: Float + Send + Sync, Q: PrimInt + Send + Sync,
     pub scale: E,
     /// The zero-point offset.
     pub offset: Q,
+    /// The quantized data type, which determines the range of the quantized values.
+    pub q_type: QuantizationType,
     /// Accumulation type.
     _a: PhantomData<A>,
 }
@@ -66,24 +275,43 @@ fn valid_scale<E: Float>(mut scale: E) -> E {
     scale
 }
 
+/// Returns the `[a, b]` range of the quantized data type, restricted to the values representable by `Q`.
+fn q_range<E: Float, Q: PrimInt>(range: (i32, i32)) -> (E, E) {
+    let a = E::from(range.0)
+        .unwrap()
+        .max(E::from(Q::min_value()).unwrap());
+    let b = E::from(range.1)
+        .unwrap()
+        .min(E::from(Q::max_value()).unwrap());
+    (a, b)
+}
+
 impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt> AffineQuantization<E, Q, A> {
     /// Initialize an affine quantization scheme with the given parameters.
+    ///
+    /// The quantized data type defaults to [QInt8](QuantizationType::QInt8).
     pub fn init(scale: E, offset: Q) -> Self {
         Self {
             scale: valid_scale(scale),
             offset,
+            q_type: QuantizationType::QInt8,
             _a: PhantomData,
         }
     }
+
+    /// Set the quantized data type.
+    pub fn with_q_type(mut self, q_type: QuantizationType) -> Self {
+        self.q_type = q_type;
+        self
+    }
 }
 
 impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt + Send + Sync> Quantization<E, Q>
     for AffineQuantization<E, Q, A>
 {
-    fn new(alpha: E, beta: E) -> Self {
+    fn new_with_type(alpha: E, beta: E, q_type: QuantizationType) -> Self {
         // Q range `[a, b]`
-        let a = E::from(Q::min_value()).unwrap();
-        let b = E::from(Q::max_value()).unwrap();
+        let (a, b) = q_range::<E, Q>(q_type.range());
 
         // We extend the `[alpha, beta]` interval to ensure that it contains 0.
         // Otherwise, we would not meet the requirement that 0 be an exactly
@@ -97,14 +325,14 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt + Send + Sync>
         Self {
             scale,
             offset: Q::from(z).unwrap(),
+            q_type,
             _a: PhantomData,
         }
     }
 
     fn quantize(&self, values: &[E]) -> Vec<Q> {
         // Quantized range `[a, b]`
-        let a = E::from(Q::min_value()).unwrap();
-        let b = E::from(Q::max_value()).unwrap();
+        let (a, b) = q_range::<E, Q>(self.q_type.range());
 
         // x_q = clamp(round(x / scale + offset), a, b)
         let z = E::from(self.offset).unwrap();
@@ -138,46 +366,56 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt + Send + Sync>
 pub struct SymmetricQuantization<E: Float + Send + Sync, Q: PrimInt + Send + Sync> {
     /// The scaling factor.
     pub scale: E,
+    /// The quantized data type, which determines the range of the quantized values.
+    pub q_type: QuantizationType,
     /// The quantized type.
     _q: PhantomData<Q>,
 }
 
 impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> SymmetricQuantization<E, Q> {
     /// Initialize a symmetric quantization scheme with the given parameters.
+    ///
+    /// The quantized data type defaults to [QInt8](QuantizationType::QInt8).
     pub fn init(scale: E) -> Self {
         Self {
             scale: valid_scale(scale),
+            q_type: QuantizationType::QInt8,
             _q: PhantomData,
         }
     }
+
+    /// Set the quantized data type.
+    pub fn with_q_type(mut self, q_type: QuantizationType) -> Self {
+        self.q_type = q_type;
+        self
+    }
 }
 
 impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> Quantization<E, Q>
     for SymmetricQuantization<E, Q>
 {
-    fn new(alpha: E, beta: E) -> Self {
+    fn new_with_type(alpha: E, beta: E, q_type: QuantizationType) -> Self {
         assert!(
             !Q::min_value().is_zero(),
             "Symmetric quantization is only valid for signed integers."
         );
 
         // Quantized range `[a, b]`
-        let b = E::from(Q::max_value()).unwrap();
-        let a = b.neg();
+        let (a, b) = q_range::<E, Q>(q_type.symmetric_range());
 
         // Compute scale to convert a floating point value in range `[-alpha, alpha]` to the quantized range
         let alpha = alpha.abs().max(beta.abs());
         let scale = valid_scale((alpha + alpha) / (b - a));
         Self {
             scale,
+            q_type,
             _q: PhantomData,
         }
     }
 
     fn quantize(&self, values: &[E]) -> Vec<Q> {
         // Quantized range [a, b]
-        let b = E::from(Q::max_value()).unwrap();
-        let a = b.neg();
+        let (a, b) = q_range::<E, Q>(self.q_type.symmetric_range());
 
         // x_q = clamp(round(x / scale), a, b)
         values
@@ -226,6 +464,7 @@ impl<E: Float + Send + Sync, Q: PrimInt + Hash + Send + Sync, A: PrimInt> Hash
         let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
         bits.hash(state);
         self.offset.hash(state);
+        self.q_type.hash(state);
     }
 }
 
@@ -233,7 +472,7 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt> PartialEq
     for AffineQuantization<E, Q, A>
 {
     fn eq(&self, other: &Self) -> bool {
-        self.scale == other.scale && self.offset == other.offset
+        self.scale == other.scale && self.offset == other.offset && self.q_type == other.q_type
     }
 }
 
@@ -247,12 +486,13 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> Hash for SymmetricQuantiz
         // Hash raw bits.
         let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
         bits.hash(state);
+        self.q_type.hash(state);
     }
 }
 
 impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> PartialEq for SymmetricQuantization<E, Q> {
     fn eq(&self, other: &Self) -> bool {
-        self.scale == other.scale
+        self.scale == other.scale && self.q_type == other.q_type
     }
 }
 
@@ -279,6 +519,62 @@ mod tests {
         assert_eq!(d, expected_d);
     }
 
+    #[test]
+    fn test_int4_affine_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-8, -4, 3, 6];
+        let expected_d = vec![-1.6866667, -1.0733334, 0.0, 0.46];
+
+        let affine =
+            AffineQuantization::<f32, i8, i32>::new_with_type(-1.8, 0.5, QuantizationType::QInt4);
+        assert_eq!(affine.offset, 3);
+
+        let q = affine.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = affine.dequantize(&expected_q);
+
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_int2_symmetric_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-1, -1, 0, 0];
+
+        let symmetric =
+            SymmetricQuantization::<f32, i8>::new_with_type(-1.8, 0.5, QuantizationType::QInt2);
+        assert_eq!(symmetric.scale, 1.8);
+
+        let q: Vec<i8> = symmetric.quantize(&x);
+        assert_eq!(q, expected_q);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_uint4_symmetric_quantization_should_panic() {
+        let _symmetric =
+            SymmetricQuantization::<f32, i8>::new_with_type(-1.8, 0.5, QuantizationType::QUInt4);
+    }
+
+    #[test]
+    fn test_per_channel_strategy_q_type() {
+        let strategy = QuantizationStrategy::PerChannelSymmetricInt8(
+            vec![SymmetricQuantization::init(0.5).with_q_type(QuantizationType::QInt4)],
+            0,
+        );
+
+        assert_eq!(strategy.q_type(), QuantizationType::QInt4);
+    }
+
+    #[test]
+    #[should_panic = "require quantization parameters"]
+    fn test_empty_per_channel_strategy_q_type_should_panic() {
+        let strategy = QuantizationStrategy::PerChannelAffineInt8(vec![], 0);
+
+        let _q_type = strategy.q_type();
+    }
+
     #[test]
     fn test_affine_should_ensure_zero_point() {
         let x: [f32; 6] = [2.0, 1.0, 2.0, 3.0, 4.0, 5.0];
@@ -313,4 +609,50 @@ mod tests {
 
         assert_eq!(d, expected_d);
     }
+
+    #[test]
+    fn test_int8_per_channel_symmetric_quantization() {
+        // Channels along axis 1 of a [2, 2] tensor
+        let x: [f32; 4] = [-1.27, 0.5, 0.254, -0.1];
+        let expected_q = vec![-127, 127, 25, -25];
+
+        let strategy = QuantizationStrategy::PerChannelSymmetricInt8(
+            vec![
+                SymmetricQuantization::new(-1.27, 0.254),
+                SymmetricQuantization::new(-0.1, 0.5),
+            ],
+            1,
+        );
+
+        let q = strategy.quantize(&x, &[2, 2]);
+        assert_eq!(q, expected_q);
+
+        let d = strategy.dequantize(&q, &[2, 2]);
+        for (d, x) in d.iter().zip(x) {
+            assert!((d - x).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_int8_per_block_affine_quantization() {
+        // The second block has an exact scale of 1/128, and so an exact zero-point offset
+        let x: [f32; 4] = [0.0, 1.0, -1.9921875, 0.0];
+        let expected_q = vec![-128, 127, -128, 127];
+
+        let strategy = QuantizationStrategy::PerBlockAffineInt8(
+            vec![
+                AffineQuantization::new(0.0, 1.0),
+                AffineQuantization::new(-1.9921875, 0.0),
+            ],
+            2,
+        );
+
+        let q = strategy.quantize(&x, &[4]);
+        assert_eq!(q, expected_q);
+
+        let d = strategy.dequantize(&q, &[4]);
+        for (d, x) in d.iter().zip(x) {
+            assert!((d - x).abs() < 1e-6);
+        }
+    }
 }
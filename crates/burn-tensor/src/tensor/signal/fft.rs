@@ -0,0 +1,265 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::{Tensor, TensorPrimitive};
+
+/// Transform of the complex tensor given as a pair of real and imaginary parts.
+fn transform<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+    inverse: bool,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    assert!(
+        dim < D,
+        "FFT: dimension {dim} is out of bounds for rank {D}"
+    );
+    assert_eq!(
+        real.shape(),
+        imag.shape(),
+        "FFT: the real and imaginary parts must have the same shape"
+    );
+
+    let shape = real.shape();
+    let mut complex_shape = shape.clone();
+    complex_shape.dims.push(1);
+
+    // The backend operates on a single tensor whose last dimension holds both parts.
+    let real = B::float_reshape(real.into_primitive().tensor(), complex_shape.clone());
+    let imag = B::float_reshape(imag.into_primitive().tensor(), complex_shape.clone());
+    let complex = B::float_fft(B::float_cat(vec![real, imag], D), dim, inverse);
+
+    let mut ranges = complex_shape
+        .dims
+        .iter()
+        .map(|dim| 0..*dim)
+        .collect::<Vec<_>>();
+    ranges[D] = 0..1;
+    let real = B::float_slice(complex.clone(), &ranges);
+    ranges[D] = 1..2;
+    let imag = B::float_slice(complex, &ranges);
+
+    let part = |tensor| {
+        Tensor::from_primitive(TensorPrimitive::Float(B::float_reshape(
+            tensor,
+            shape.clone(),
+        )))
+    };
+
+    (part(real), part(imag))
+}
+
+/// Computes the one dimensional discrete Fourier transform of a complex tensor.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the input.
+/// * `imag` - The imaginary part of the input.
+/// * `dim` - The dimension along which to compute the transform.
+///
+/// # Returns
+///
+/// A tuple `(real, imag)` with the real and imaginary parts of the transform, which is not
+/// normalized.
+pub fn fft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    transform(real, imag, dim, false)
+}
+
+/// Computes the one dimensional inverse discrete Fourier transform of a complex tensor.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the input.
+/// * `imag` - The imaginary part of the input.
+/// * `dim` - The dimension along which to compute the transform.
+///
+/// # Returns
+///
+/// A tuple `(real, imag)` with the real and imaginary parts of the inverse transform, which is
+/// normalized by the size of `dim`.
+pub fn ifft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    transform(real, imag, dim, true)
+}
+
+/// Computes the one dimensional discrete Fourier transform of a real tensor.
+///
+/// Since the spectrum of a real signal is Hermitian symmetric, only the `n / 2 + 1` non-negative
+/// frequencies are returned.
+///
+/// # Arguments
+///
+/// * `signal` - The real input.
+/// * `dim` - The dimension along which to compute the transform.
+///
+/// # Returns
+///
+/// A tuple `(real, imag)` with the real and imaginary parts of the transform, where `dim` has a
+/// size of `n / 2 + 1`.
+pub fn rfft<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let size = signal.dims()[dim];
+    let imag = signal.zeros_like();
+    let (real, imag) = fft(signal, imag, dim);
+
+    (
+        real.narrow(dim, 0, size / 2 + 1),
+        imag.narrow(dim, 0, size / 2 + 1),
+    )
+}
+
+/// Computes the inverse of [rfft], going from the non-negative frequencies of a Hermitian
+/// spectrum back to a real signal.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the non-negative frequencies.
+/// * `imag` - The imaginary part of the non-negative frequencies.
+/// * `dim` - The dimension along which to compute the transform.
+/// * `size` - The size of the output along `dim`, defaults to `2 * (m - 1)` where `m` is the
+///   number of frequencies. The frequencies are truncated or zero-padded to `size / 2 + 1`.
+///
+/// # Returns
+///
+/// The real signal, normalized by `size`.
+pub fn irfft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+    size: Option<usize>,
+) -> Tensor<B, D> {
+    let frequencies = real.dims()[dim];
+    let size = size.unwrap_or(2 * frequencies.saturating_sub(1));
+    assert!(size > 0, "IRFFT: the output size must be greater than zero");
+
+    let half = size / 2 + 1;
+    let (real, imag) = if frequencies >= half {
+        (real.narrow(dim, 0, half), imag.narrow(dim, 0, half))
+    } else {
+        (
+            zero_pad(real, dim, half - frequencies),
+            zero_pad(imag, dim, half - frequencies),
+        )
+    };
+
+    // Rebuild the negative frequencies from the conjugate of the positive ones.
+    let (real, imag) = if size > half {
+        let axis = [dim as isize];
+        let mirror_real = real.clone().narrow(dim, 1, size - half).flip(axis);
+        let mirror_imag = imag.clone().narrow(dim, 1, size - half).flip(axis).neg();
+
+        (
+            Tensor::cat(vec![real, mirror_real], dim),
+            Tensor::cat(vec![imag, mirror_imag], dim),
+        )
+    } else {
+        (real, imag)
+    };
+
+    ifft(real, imag, dim).0
+}
+
+fn zero_pad<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    dim: usize,
+    size: usize,
+) -> Tensor<B, D> {
+    let mut shape = tensor.dims();
+    shape[dim] = size;
+    let zeros = Tensor::zeros(shape, &tensor.device());
+
+    Tensor::cat(vec![tensor, zeros], dim)
+}
+
+/// Computes the two dimensional discrete Fourier transform of a complex tensor.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the input.
+/// * `imag` - The imaginary part of the input.
+/// * `dims` - The two dimensions along which to compute the transform.
+///
+/// # Returns
+///
+/// A tuple `(real, imag)` with the real and imaginary parts of the transform.
+pub fn fft2<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (real, imag) = fft(real, imag, dims[1]);
+    fft(real, imag, dims[0])
+}
+
+/// Computes the two dimensional inverse discrete Fourier transform of a complex tensor.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the input.
+/// * `imag` - The imaginary part of the input.
+/// * `dims` - The two dimensions along which to compute the transform.
+///
+/// # Returns
+///
+/// A tuple `(real, imag)` with the real and imaginary parts of the inverse transform.
+pub fn ifft2<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (real, imag) = ifft(real, imag, dims[1]);
+    ifft(real, imag, dims[0])
+}
+
+/// Computes the two dimensional discrete Fourier transform of a real tensor.
+///
+/// Only the non-negative frequencies of the last of the two dimensions are returned.
+///
+/// # Arguments
+///
+/// * `signal` - The real input.
+/// * `dims` - The two dimensions along which to compute the transform.
+///
+/// # Returns
+///
+/// A tuple `(real, imag)` with the real and imaginary parts of the transform, where `dims[1]` has
+/// a size of `n / 2 + 1`.
+pub fn rfft2<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (real, imag) = rfft(signal, dims[1]);
+    fft(real, imag, dims[0])
+}
+
+/// Computes the inverse of [rfft2].
+///
+/// # Arguments
+///
+/// * `real` - The real part of the input.
+/// * `imag` - The imaginary part of the input.
+/// * `dims` - The two dimensions along which to compute the transform.
+/// * `size` - The size of the output along `dims[1]`, see [irfft].
+///
+/// # Returns
+///
+/// The real signal.
+pub fn irfft2<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dims: [usize; 2],
+    size: Option<usize>,
+) -> Tensor<B, D> {
+    let (real, imag) = ifft(real, imag, dims[0]);
+    irfft(real, imag, dims[1], size)
+}
@@ -0,0 +1,7 @@
+mod fft;
+mod stft;
+mod window;
+
+pub use fft::*;
+pub use stft::*;
+pub use window::*;
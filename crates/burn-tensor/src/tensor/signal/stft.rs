@@ -0,0 +1,201 @@
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::{Int, Tensor, TensorData};
+
+use super::{irfft, rfft};
+
+/// Options of the [short-time Fourier transform](stft) and its [inverse](istft).
+#[derive(new, Debug, Clone)]
+pub struct StftOptions {
+    /// The size of each frame, and of the transform computed on it.
+    pub n_fft: usize,
+    /// The number of samples between the start of two consecutive frames.
+    pub hop_length: usize,
+    /// Whether the signal is padded with its reflection by `n_fft / 2` on both sides, so that
+    /// frame `t` is centered on sample `t * hop_length`.
+    pub center: bool,
+}
+
+impl StftOptions {
+    fn padding(&self) -> usize {
+        if self.center {
+            self.n_fft / 2
+        } else {
+            0
+        }
+    }
+
+    fn num_frames(&self, padded_length: usize) -> usize {
+        assert!(
+            padded_length >= self.n_fft,
+            "STFT: the signal is shorter than a frame ({padded_length} < {})",
+            self.n_fft
+        );
+        1 + (padded_length - self.n_fft) / self.hop_length
+    }
+
+    /// Index of every sample of every frame in the padded signal, flattened.
+    fn frame_indices(&self, num_frames: usize) -> Vec<i64> {
+        (0..num_frames)
+            .flat_map(|frame| {
+                let start = frame * self.hop_length;
+                (start..start + self.n_fft).map(|index| index as i64)
+            })
+            .collect()
+    }
+
+    fn check(&self) {
+        assert!(self.n_fft > 0, "STFT: n_fft must be greater than zero");
+        assert!(
+            self.hop_length > 0,
+            "STFT: hop_length must be greater than zero"
+        );
+    }
+}
+
+fn frame_window<B: Backend>(
+    window: Option<Tensor<B, 1>>,
+    n_fft: usize,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    match window {
+        Some(window) => {
+            assert_eq!(
+                window.dims()[0],
+                n_fft,
+                "STFT: the window must have n_fft samples"
+            );
+            window
+        }
+        None => Tensor::ones([n_fft], device),
+    }
+}
+
+/// Computes the short-time Fourier transform of signals.
+///
+/// # Arguments
+///
+/// * `signal` - The real signals of shape `[batch_size, length]`.
+/// * `window` - The window of shape `[n_fft]` applied to every frame, rectangular when `None`.
+/// * `options` - The framing options.
+///
+/// # Returns
+///
+/// A tuple `(real, imag)` with the real and imaginary parts of the spectrogram, of shape
+/// `[batch_size, num_frames, n_fft / 2 + 1]`.
+///
+/// # Panics
+///
+/// If the signal is centered and has no more than `n_fft / 2` samples, since the reflection
+/// padding requires more samples than the padding size.
+pub fn stft<B: Backend>(
+    signal: Tensor<B, 2>,
+    window: Option<Tensor<B, 1>>,
+    options: StftOptions,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    options.check();
+    let device = signal.device();
+    let [batch_size, length] = signal.dims();
+    let padding = options.padding();
+    assert!(
+        padding < length,
+        "STFT: a centered signal must be longer than n_fft / 2 ({length} <= {padding})"
+    );
+    let padded_length = length + 2 * padding;
+    let num_frames = options.num_frames(padded_length);
+
+    // Reflect padding and framing are a single selection of samples from the original signal.
+    let indices = options
+        .frame_indices(num_frames)
+        .into_iter()
+        .map(|index| reflect(index - padding as i64, length))
+        .collect::<Vec<_>>();
+    let num_indices = indices.len();
+    let indices = Tensor::<B, 1, Int>::from_ints(TensorData::new(indices, [num_indices]), &device);
+
+    let frames = signal
+        .select(1, indices)
+        .reshape([batch_size, num_frames, options.n_fft]);
+    let window = frame_window(window, options.n_fft, &device);
+    let frames = frames.mul(window.unsqueeze());
+
+    rfft(frames, 2)
+}
+
+/// Maps an index of the reflect-padded signal to the original signal.
+fn reflect(index: i64, length: usize) -> i64 {
+    let last = length as i64 - 1;
+    let index = index.abs();
+
+    if index > last {
+        2 * last - index
+    } else {
+        index
+    }
+}
+
+/// Computes the inverse short-time Fourier transform, reconstructing signals from their
+/// spectrogram with a windowed overlap-add.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the spectrogram, of shape `[batch_size, num_frames, n_fft / 2 + 1]`.
+/// * `imag` - The imaginary part of the spectrogram, with the same shape.
+/// * `window` - The window used by the forward transform, rectangular when `None`.
+/// * `options` - The framing options used by the forward transform.
+/// * `length` - The length of the output signals, defaults to the longest reconstructed length.
+///
+/// # Returns
+///
+/// The signals of shape `[batch_size, length]`.
+pub fn istft<B: Backend>(
+    real: Tensor<B, 3>,
+    imag: Tensor<B, 3>,
+    window: Option<Tensor<B, 1>>,
+    options: StftOptions,
+    length: Option<usize>,
+) -> Tensor<B, 2> {
+    options.check();
+    let device = real.device();
+    let [batch_size, num_frames, _] = real.dims();
+    let padding = options.padding();
+    let padded_length = (num_frames - 1) * options.hop_length + options.n_fft;
+
+    let window = frame_window(window, options.n_fft, &device);
+    let frames = irfft(real, imag, 2, Some(options.n_fft)).mul(window.clone().unsqueeze());
+
+    let indices = options.frame_indices(num_frames);
+    let num_indices = indices.len();
+    let indices = Tensor::<B, 1, Int>::from_ints(TensorData::new(indices, [num_indices]), &device)
+        .unsqueeze::<2>();
+
+    // Overlap-add the frames, and the squared window to normalize the result.
+    let signal = Tensor::<B, 2>::zeros([batch_size, padded_length], &device).scatter(
+        1,
+        indices.clone().expand([batch_size, num_indices]),
+        frames.reshape([batch_size, num_indices]),
+    );
+    let envelope = Tensor::<B, 2>::zeros([1, padded_length], &device).scatter(
+        1,
+        indices,
+        window
+            .powi_scalar(2)
+            .unsqueeze::<2>()
+            .repeat_dim(1, num_frames),
+    );
+    // Samples not covered by the window can't be recovered and are left to zero.
+    let is_zero = envelope.clone().lower_equal_elem(1e-11).float();
+    let signal = signal.div(envelope.add(is_zero));
+
+    let length = length.unwrap_or(padded_length - 2 * padding);
+    let end = usize::min(padding + length, padded_length);
+    let signal = signal.slice([0..batch_size, padding..end]);
+
+    if end - padding < length {
+        let zeros = Tensor::zeros([batch_size, length - (end - padding)], &device);
+        Tensor::cat(alloc::vec![signal, zeros], 1)
+    } else {
+        signal
+    }
+}
@@ -0,0 +1,86 @@
+use core::f64::consts::PI;
+
+use crate::backend::Backend;
+use crate::{Int, Tensor};
+
+/// Computes `sum_i coefficients[i] * cos(2 * pi * i * n / denominator)` for every sample `n`.
+fn cosine_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    coefficients: &[f64],
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    if size <= 1 {
+        return Tensor::ones([size], device);
+    }
+
+    let denominator = if periodic { size } else { size - 1 };
+    let samples = Tensor::<B, 1, Int>::arange(0..size as i64, device)
+        .float()
+        .mul_scalar(2.0 * PI / denominator as f64);
+
+    coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, coefficient)| {
+            samples
+                .clone()
+                .mul_scalar(i as f64)
+                .cos()
+                .mul_scalar(*coefficient)
+        })
+        .reduce(|acc, term| acc.add(term))
+        .unwrap()
+}
+
+/// Creates a Hann window.
+///
+/// # Arguments
+///
+/// * `size` - The number of samples.
+/// * `periodic` - Whether the window is periodic, as used for spectral analysis, or symmetric, as
+///   used for filter design.
+/// * `device` - The device on which to create the window.
+///
+/// # Returns
+///
+/// The window of shape `[size]`.
+pub fn hann_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.5, -0.5], device)
+}
+
+/// Creates a Hamming window.
+///
+/// # Arguments
+///
+/// * `size` - The number of samples.
+/// * `periodic` - Whether the window is periodic, as used for spectral analysis, or symmetric, as
+///   used for filter design.
+/// * `device` - The device on which to create the window.
+///
+/// # Returns
+///
+/// The window of shape `[size]`.
+pub fn hamming_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.54, -0.46], device)
+}
+
+/// Creates a Blackman window.
+///
+/// # Arguments
+///
+/// * `size` - The number of samples.
+/// * `periodic` - Whether the window is periodic, as used for spectral analysis, or symmetric, as
+///   used for filter design.
+/// * `device` - The device on which to create the window.
+///
+/// # Returns
+///
+/// The window of shape `[size]`.
+pub fn blackman_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.42, -0.5, 0.08], device)
+}
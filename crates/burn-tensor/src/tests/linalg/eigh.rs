@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(linalg_eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn test_eigh_d2() {
+        let tensor = TestTensor::<2>::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.5858, 2.0, 3.4142]]), 3);
+        vectors
+            .clone()
+            .transpose()
+            .matmul(vectors.clone())
+            .into_data()
+            .assert_approx_eq(&TestTensor::<2>::eye(3, &vectors.device()).into_data(), 3);
+        (vectors.clone() * values)
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn test_eigh_d3_batched() {
+        let tensor = TestTensor::<3>::from([[[2.0, 1.0], [1.0, 2.0]], [[3.0, 0.0], [0.0, -1.0]]]);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[1.0, 3.0]], [[-1.0, 3.0]]]), 3);
+        (vectors.clone() * values)
+            .matmul(vectors.swap_dims(1, 2))
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
@@ -0,0 +1,6 @@
+mod cholesky;
+mod eigh;
+mod norm;
+mod qr;
+mod solve;
+mod svd;
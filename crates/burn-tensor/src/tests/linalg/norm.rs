@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(linalg_norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::linalg::{self, MatrixNorm, VectorNorm};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_vector_norm() {
+        let tensor = TestTensor::<2>::from([[3.0, -4.0], [0.0, 2.0]]);
+
+        let norm = |norm| linalg::vector_norm(tensor.clone(), norm, 1).into_data();
+
+        norm(VectorNorm::L0).assert_approx_eq(&TensorData::from([[2.0], [1.0]]), 3);
+        norm(VectorNorm::L1).assert_approx_eq(&TensorData::from([[7.0], [2.0]]), 3);
+        norm(VectorNorm::L2).assert_approx_eq(&TensorData::from([[5.0], [2.0]]), 3);
+        norm(VectorNorm::LInf).assert_approx_eq(&TensorData::from([[4.0], [2.0]]), 3);
+        norm(VectorNorm::LNegInf).assert_approx_eq(&TensorData::from([[3.0], [0.0]]), 3);
+        norm(VectorNorm::Lp(3.0)).assert_approx_eq(&TensorData::from([[4.4979], [2.0]]), 3);
+    }
+
+    #[test]
+    fn test_matrix_norm() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0], [3.0, 4.0]]);
+
+        let norm = |norm| linalg::matrix_norm(tensor.clone(), norm).into_data();
+
+        norm(MatrixNorm::Frobenius).assert_approx_eq(&TensorData::from([[5.4772]]), 3);
+        norm(MatrixNorm::Nuclear).assert_approx_eq(&TensorData::from([[7.0711]]), 3);
+        norm(MatrixNorm::Spectral).assert_approx_eq(&TensorData::from([[5.1167]]), 3);
+        norm(MatrixNorm::L1).assert_approx_eq(&TensorData::from([[6.0]]), 3);
+        norm(MatrixNorm::LInf).assert_approx_eq(&TensorData::from([[7.0]]), 3);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(linalg_qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn test_qr_square() {
+        let tensor = TestTensor::<2>::from([[2.0, -1.0, 0.0], [1.0, 3.0, 1.0], [2.0, 0.0, 4.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        r.clone()
+            .into_data()
+            .assert_approx_eq(&r.clone().triu(0).into_data(), 3);
+        q.clone()
+            .transpose()
+            .matmul(q.clone())
+            .into_data()
+            .assert_approx_eq(&TestTensor::<2>::eye(3, &q.device()).into_data(), 3);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn test_qr_known_values() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0], [4.0, 2.0]]);
+
+        let (q, r) = linalg::qr(tensor);
+
+        q.into_data()
+            .assert_approx_eq(&TensorData::from([[0.6, -0.8], [0.8, 0.6]]), 3);
+        r.into_data()
+            .assert_approx_eq(&TensorData::from([[5.0, 2.2], [0.0, 0.4]]), 3);
+    }
+
+    #[test]
+    fn test_qr_shapes() {
+        let tall = TestTensor::<3>::from([
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 9.0]],
+            [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [2.0, -1.0]],
+        ]);
+        let (q, r) = linalg::qr(tall.clone());
+        assert_eq!(q.dims(), [2, 4, 2]);
+        assert_eq!(r.dims(), [2, 2, 2]);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tall.into_data(), 3);
+
+        let wide = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let (q, r) = linalg::qr(wide.clone());
+        assert_eq!(q.dims(), [2, 2]);
+        assert_eq!(r.dims(), [2, 3]);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&wide.into_data(), 3);
+    }
+}
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(linalg_solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn test_solve_d2() {
+        let a = TestTensor::<2>::from([[3.0, 1.0], [1.0, 2.0]]);
+        let b = TestTensor::<2>::from([[9.0, 1.0], [8.0, 2.0]]);
+
+        let output = linalg::solve(a, b);
+        let expected = TensorData::from([[2.0, 0.0], [3.0, 1.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_inverse_d2() {
+        let tensor = TestTensor::<2>::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::inverse(tensor);
+        let expected = TensorData::from([[0.6, -0.7], [-0.2, 0.4]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_inverse_requires_pivoting() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 0.0], [0.0, 0.0, 2.0], [4.0, 0.0, 0.0]]);
+
+        let output = linalg::inverse(tensor);
+        let expected = TensorData::from([[0.0, 0.0, 0.25], [1.0, 0.0, 0.0], [0.0, 0.5, 0.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_det_d3_batched() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]],
+            [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 2.0]],
+        ]);
+
+        let output = linalg::det(tensor);
+        let expected = TensorData::from([[[-3.0]], [[-2.0]]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_slogdet_d2() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+
+        sign.into_data()
+            .assert_approx_eq(&TensorData::from([[-1.0]]), 3);
+        logabsdet
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0986]]), 3);
+    }
+}
@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(linalg_svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn test_svd_square() {
+        let tensor = TestTensor::<2>::from([[3.0, 0.0], [4.0, 5.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[6.7082, 2.2361]]), 3);
+        u.clone()
+            .transpose()
+            .matmul(u.clone())
+            .into_data()
+            .assert_approx_eq(&TestTensor::<2>::eye(2, &u.device()).into_data(), 3);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn test_svd_wide() {
+        let tensor = TestTensor::<2>::from([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(vh.dims(), [2, 3]);
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[5.0, 3.0]]), 3);
+        vh.clone()
+            .matmul(vh.clone().transpose())
+            .into_data()
+            .assert_approx_eq(&TestTensor::<2>::eye(2, &vh.device()).into_data(), 3);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
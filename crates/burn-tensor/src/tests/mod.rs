@@ -1,9 +1,11 @@
 mod activation;
 mod clone_invariance;
+mod linalg;
 mod module;
 mod ops;
 mod primitive;
 mod quantization;
+mod signal;
 mod stats;
 
 pub use cubecl::prelude::{Float, Int, Numeric};
@@ -78,6 +80,14 @@ macro_rules! testgen_all {
 #[macro_export]
 macro_rules! testgen_quantization {
     () => {
+        burn_tensor::testgen_quantization!(int8);
+
+        // test block-wise and sub-byte quantization
+        burn_tensor::testgen_quantize_block!();
+        burn_tensor::testgen_quantize_sub_byte!();
+    };
+    // For backends that only support per-tensor and per-channel int8 quantization
+    (int8) => {
         // Quantized tensor utilities
         pub mod qtensor {
             use core::marker::PhantomData;
@@ -116,6 +126,7 @@ macro_rules! testgen_quantization {
 
         // test quantization
         burn_tensor::testgen_calibration!();
+        burn_tensor::testgen_observer!();
         burn_tensor::testgen_scheme!();
         burn_tensor::testgen_quantize!();
 
@@ -203,6 +214,8 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_attention!();
 
         // test ops
         burn_tensor::testgen_gather_scatter!();
@@ -231,6 +244,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_map_comparison!();
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_maxmin!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_neg!();
@@ -268,17 +282,32 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_cartesian_grid!();
         burn_tensor::testgen_nan!();
         burn_tensor::testgen_round!();
+        burn_tensor::testgen_fake_quantize!();
         burn_tensor::testgen_floor!();
         burn_tensor::testgen_ceil!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_split!();
         burn_tensor::testgen_prod!();
+        burn_tensor::testgen_cumulative!();
 
         // test stats
         burn_tensor::testgen_var!();
         burn_tensor::testgen_cov!();
         burn_tensor::testgen_eye!();
 
+        // test linalg
+        burn_tensor::testgen_linalg_cholesky!();
+        burn_tensor::testgen_linalg_eigh!();
+        burn_tensor::testgen_linalg_norm!();
+        burn_tensor::testgen_linalg_qr!();
+        burn_tensor::testgen_linalg_solve!();
+        burn_tensor::testgen_linalg_svd!();
+
+        // test signal
+        burn_tensor::testgen_signal_fft!();
+        burn_tensor::testgen_signal_stft!();
+        burn_tensor::testgen_signal_window!();
+
         // test padding
         burn_tensor::testgen_padding!();
     };
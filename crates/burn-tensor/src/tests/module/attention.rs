@@ -0,0 +1,276 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::{attention::AttentionDropout, AttentionOptions};
+    use burn_tensor::{activation, backend::Backend, Tensor, TensorData};
+
+    #[test]
+    fn test_attention_matches_reference() {
+        let test = AttentionTestCase::new(2, 2, 2, 5, 7, 4);
+        let options = AttentionOptions::default();
+
+        let output = test.attention(None, None, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&test.reference(None, 2.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_scale() {
+        let test = AttentionTestCase::new(1, 2, 2, 3, 3, 4);
+        let options = AttentionOptions::new(Some(0.25), false, 0.0);
+
+        let output = test.attention(None, None, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&test.reference(None, 4.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_causal_aligned_with_last_keys() {
+        let test = AttentionTestCase::new(2, 2, 2, 3, 5, 4);
+        let options = AttentionOptions::new(None, true, 0.0);
+
+        let output = test.attention(None, None, options);
+
+        // The first query attends to the first 3 keys.
+        let mask = TestTensorBool::<2>::tril_mask([3, 5], 2, &Default::default())
+            .unsqueeze::<4>()
+            .expand([2, 2, 3, 5]);
+        output
+            .into_data()
+            .assert_approx_eq(&test.reference(Some(mask), 2.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_padding_mask() {
+        let device = Default::default();
+        let test = AttentionTestCase::new(2, 2, 2, 4, 4, 4);
+        let padding_mask = TestTensorBool::<2>::from_data(
+            [[false, false, false, true], [false, false, true, true]],
+            &device,
+        );
+
+        let output = test.attention(
+            Some(padding_mask.clone()),
+            None,
+            AttentionOptions::default(),
+        );
+
+        let mask = padding_mask.reshape([2, 1, 1, 4]).expand([2, 2, 4, 4]);
+        output
+            .into_data()
+            .assert_approx_eq(&test.reference(Some(mask), 2.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_attn_mask() {
+        let device = Default::default();
+        let test = AttentionTestCase::new(1, 2, 2, 2, 3, 4);
+        let attn_mask =
+            TestTensorBool::<3>::from_data([[[false, true, false], [true, false, false]]], &device);
+
+        let output = test.attention(None, Some(attn_mask.clone()), AttentionOptions::default());
+
+        let mask = attn_mask.reshape([1, 1, 2, 3]).expand([1, 2, 2, 3]);
+        output
+            .into_data()
+            .assert_approx_eq(&test.reference(Some(mask), 2.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_grouped_query() {
+        let test = AttentionTestCase::new(2, 4, 2, 3, 5, 4);
+
+        let output = test.attention(None, None, AttentionOptions::default());
+
+        output
+            .into_data()
+            .assert_approx_eq(&test.reference(None, 2.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_multi_query() {
+        let test = AttentionTestCase::new(1, 3, 1, 4, 4, 4);
+
+        let output = test.attention(None, None, AttentionOptions::new(None, true, 0.0));
+
+        let mask = TestTensorBool::<2>::tril_mask([4, 4], 0, &Default::default())
+            .unsqueeze::<4>()
+            .expand([1, 3, 4, 4]);
+        output
+            .into_data()
+            .assert_approx_eq(&test.reference(Some(mask), 2.0).into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_fully_masked_query_is_zero() {
+        let device = Default::default();
+        let test = AttentionTestCase::new(1, 1, 1, 2, 2, 4);
+        let attn_mask = TestTensorBool::<3>::from_data([[[true, true], [false, true]]], &device);
+
+        let output = test.attention(None, Some(attn_mask), AttentionOptions::default());
+
+        let values = test.value.clone().slice([0..1, 0..1, 0..1, 0..4]);
+        let expected = Tensor::cat(vec![values.zeros_like(), values], 2);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_dropout_matches_reference() {
+        // More keys than the tiles of the kernels.
+        let test = AttentionTestCase::new(2, 4, 2, 5, 37, 4);
+        let mut options = AttentionOptions::new(None, true, 0.3);
+        options.dropout_seed = Some(7);
+
+        let output = test.attention(None, None, options);
+
+        let mask = TestTensorBool::<2>::tril_mask([5, 37], 32, &Default::default())
+            .unsqueeze::<4>()
+            .expand([2, 4, 5, 37]);
+        let factors = dropout_factors(&options, [2, 4, 5, 37]);
+        output.into_data().assert_approx_eq(
+            &test
+                .reference_with_factors(Some(mask), 2.0, Some(factors))
+                .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_attention_dropout_draws_seed_when_unset() {
+        let test = AttentionTestCase::new(1, 2, 2, 8, 8, 4);
+        let options = AttentionOptions::new(None, false, 0.5);
+
+        let output_1 = test.attention(None, None, options);
+        let output_2 = test.attention(None, None, options);
+
+        assert_ne!(output_1.into_data(), output_2.into_data());
+    }
+
+    #[test]
+    fn test_attention_dropout_seed_follows_backend_seed() {
+        let test = AttentionTestCase::new(1, 2, 2, 8, 8, 4);
+        let options = AttentionOptions::new(None, false, 0.5);
+
+        TestBackend::seed(42);
+        let output_1 = test.attention(None, None, options);
+        TestBackend::seed(42);
+        let output_2 = test.attention(None, None, options);
+
+        output_1.into_data().assert_eq(&output_2.into_data(), true);
+    }
+
+    struct AttentionTestCase {
+        query: TestTensor<4>,
+        key: TestTensor<4>,
+        value: TestTensor<4>,
+    }
+
+    impl AttentionTestCase {
+        fn new(
+            batch_size: usize,
+            num_heads: usize,
+            num_kv_heads: usize,
+            seq_length_q: usize,
+            seq_length_k: usize,
+            head_dim: usize,
+        ) -> Self {
+            Self {
+                query: sequence([batch_size, num_heads, seq_length_q, head_dim], 0.37),
+                key: sequence([batch_size, num_kv_heads, seq_length_k, head_dim], 0.53),
+                value: sequence([batch_size, num_kv_heads, seq_length_k, head_dim], 0.71),
+            }
+        }
+
+        fn attention(
+            &self,
+            padding_mask: Option<TestTensorBool<2>>,
+            attn_mask: Option<TestTensorBool<3>>,
+            options: AttentionOptions,
+        ) -> TestTensor<4> {
+            scaled_dot_product_attention(
+                self.query.clone(),
+                self.key.clone(),
+                self.value.clone(),
+                padding_mask,
+                attn_mask,
+                options,
+            )
+        }
+
+        /// The attention computed with the weights, the masked keys being `true`.
+        fn reference(&self, mask: Option<TestTensorBool<4>>, divisor: f64) -> TestTensor<4> {
+            self.reference_with_factors(mask, divisor, None)
+        }
+
+        /// The attention computed with the weights multiplied by the dropout factors.
+        fn reference_with_factors(
+            &self,
+            mask: Option<TestTensorBool<4>>,
+            divisor: f64,
+            factors: Option<TestTensor<4>>,
+        ) -> TestTensor<4> {
+            let [_, num_heads, _, _] = self.query.dims();
+            let key = repeat_heads(self.key.clone(), num_heads);
+            let value = repeat_heads(self.value.clone(), num_heads);
+
+            let mut scores = self
+                .query
+                .clone()
+                .matmul(key.transpose())
+                .div_scalar(divisor);
+            if let Some(mask) = mask {
+                scores = scores.mask_fill(mask, -1.0e9);
+            }
+
+            let mut weights = activation::softmax(scores, 3);
+            if let Some(factors) = factors {
+                weights = weights.mul(factors);
+            }
+
+            weights.matmul(value)
+        }
+    }
+
+    /// The factors of the dropped weights, with the rows numbered across the batch and the heads.
+    fn dropout_factors(options: &AttentionOptions, shape: [usize; 4]) -> TestTensor<4> {
+        let dropout = AttentionDropout::new(options).unwrap();
+        let [batch_size, num_heads, seq_length_q, seq_length_k] = shape;
+        let factors = (0..batch_size * num_heads * seq_length_q)
+            .flat_map(|row| {
+                (0..seq_length_k).map(move |col| match dropout.keep(row, col) {
+                    true => dropout.scale as f32,
+                    false => 0.0,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        TestTensor::from_data(TensorData::new(factors, shape), &Default::default())
+    }
+
+    fn repeat_heads(tensor: TestTensor<4>, num_heads: usize) -> TestTensor<4> {
+        let [batch_size, num_kv_heads, seq_length, dim] = tensor.dims();
+        let num_groups = num_heads / num_kv_heads;
+
+        tensor
+            .unsqueeze_dim::<5>(2)
+            .expand([batch_size, num_kv_heads, num_groups, seq_length, dim])
+            .reshape([batch_size, num_heads, seq_length, dim])
+    }
+
+    fn sequence(shape: [usize; 4], factor: f32) -> TestTensor<4> {
+        let num_elements = shape.iter().product::<usize>() as i64;
+
+        TestTensorInt::<1>::arange(0..num_elements, &Default::default())
+            .float()
+            .mul_scalar(factor)
+            .sin()
+            .reshape(shape)
+    }
+}
@@ -0,0 +1,139 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, grid_sample_2d};
+    use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_align_corners() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Zeros,
+            align_corners: true,
+        };
+
+        test.assert_output(TensorData::from([[[[0.0, 4.0, 8.0], [3.0, 2.5, 2.4]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Border,
+            align_corners: false,
+        };
+
+        test.assert_output(TensorData::from([[[[0.0, 4.0, 8.0], [2.5, 5.0, 3.0]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_reflection() {
+        let test = GridSampleTestCase {
+            mode: InterpolateMode::Nearest,
+            padding_mode: GridSamplePaddingMode::Reflection,
+            align_corners: false,
+        };
+
+        test.assert_output(TensorData::from([[[[0.0, 4.0, 8.0], [2.0, 5.0, 3.0]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_rounds_half_to_even() {
+        let device = Default::default();
+        let x = input();
+        let grid = TestTensor::<4>::from_data([[[[-0.5, -1.0], [0.5, -1.0]]]], &device);
+        let options =
+            GridSampleOptions::new(InterpolateMode::Nearest, GridSamplePaddingMode::Zeros, true);
+
+        let output = grid_sample_2d(x, grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[0.0, 2.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bicubic() {
+        let device = Default::default();
+        let grid = TestTensor::<4>::from_data([[[[0.0, 0.0], [0.5, 0.0]]]], &device);
+
+        let options =
+            GridSampleOptions::new(InterpolateMode::Bicubic, GridSamplePaddingMode::Zeros, true);
+        let output = grid_sample_2d(input(), grid.clone(), options);
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[4.0, 5.0625]]]]), 3);
+
+        let options = GridSampleOptions::new(
+            InterpolateMode::Bicubic,
+            GridSamplePaddingMode::Border,
+            true,
+        );
+        let output = grid_sample_2d(input(), grid, options);
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[4.0, 4.59375]]]]), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_identity() {
+        let device = Default::default();
+        let theta = TestTensor::<3>::from_data([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]], &device);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 3], false);
+
+        let third = 2.0 / 3.0;
+        grid.into_data().assert_approx_eq(
+            &TensorData::from([[
+                [[-third, -0.5], [0.0, -0.5], [third, -0.5]],
+                [[-third, 0.5], [0.0, 0.5], [third, 0.5]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_transform() {
+        let device = Default::default();
+        let theta = TestTensor::<3>::from_data([[[0.0, 1.0, 0.0], [1.0, 0.0, 0.5]]], &device);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 2], true);
+
+        grid.into_data().assert_approx_eq(
+            &TensorData::from([[[[-1.0, -0.5], [-1.0, 1.5]], [[1.0, -0.5], [1.0, 1.5]]]]),
+            3,
+        );
+    }
+
+    struct GridSampleTestCase {
+        mode: InterpolateMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    }
+
+    impl GridSampleTestCase {
+        fn assert_output(self, expected: TensorData) {
+            let device = Default::default();
+            // Locations inside, on the border and outside of the input.
+            let grid = TestTensor::<4>::from_data(
+                [[
+                    [[-1.0, -1.0], [0.0, 0.0], [1.0, 1.0]],
+                    [[0.5, -0.5], [1.5, 0.0], [-1.2, 0.0]],
+                ]],
+                &device,
+            );
+            let options = GridSampleOptions::new(self.mode, self.padding_mode, self.align_corners);
+
+            let output = grid_sample_2d(input(), grid, options);
+
+            output.into_data().assert_approx_eq(&expected, 3);
+        }
+    }
+
+    fn input() -> TestTensor<4> {
+        TestTensorInt::<1>::arange(0..9, &Default::default())
+            .float()
+            .reshape([1, 1, 3, 3])
+    }
+}
@@ -1,5 +1,6 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod bicubic_interpolate;
@@ -12,6 +13,7 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod deform_conv2d;
 mod forward;
+mod grid_sample;
 mod maxpool1d;
 mod maxpool2d;
 mod nearest_interpolate;
@@ -0,0 +1,212 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_cumsum_float() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cumsum_first_dim() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0]]), false);
+    }
+
+    #[test]
+    fn test_cumsum_exclusive() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum_with_options(1, true, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0, 1.0], [0.0, 3.0, 7.0]]), false);
+    }
+
+    #[test]
+    fn test_cumsum_reverse() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum_with_options(1, false, true);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[3.0, 3.0, 2.0], [12.0, 9.0, 5.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cumsum_exclusive_reverse() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum_with_options(1, true, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 2.0, 0.0], [9.0, 5.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn test_cumsum_int() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        let output = tensor.cumsum_with_options(1, true, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 1], [0, 3, 7]]), false);
+    }
+
+    #[test]
+    fn test_cumprod_float() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+
+        let output = tensor.cumprod(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, -2.0, -6.0], [5.0, 45.0, 270.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cumprod_exclusive_reverse() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+
+        let output = tensor.cumprod_with_options(1, true, true);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[-6.0, 3.0, 1.0], [54.0, 6.0, 1.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cumprod_int() {
+        let tensor = TestTensorInt::<2>::from([[1, -2, 3], [5, 9, 6]]);
+
+        let output = tensor.cumprod_with_options(0, false, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5, -18, 18], [5, 9, 6]]), false);
+    }
+
+    #[test]
+    fn test_cummax_float() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+
+        let output = tensor.cummax(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0, 3.0], [5.0, 9.0, 9.0]]), false);
+    }
+
+    #[test]
+    fn test_cummax_reverse() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+
+        let output = tensor.cummax_reverse(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 3.0, 3.0], [9.0, 9.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn test_cummax_int() {
+        let tensor = TestTensorInt::<2>::from([[1, -2, 3], [5, 9, 6]]);
+
+        let output = tensor.cummax(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, -2, 3], [5, 9, 6]]), false);
+    }
+
+    #[test]
+    fn test_cummax_propagates_nan() {
+        let tensor = TestTensor::<2>::from([[1.0, f32::NAN, 3.0], [f32::NAN, 9.0, 6.0]]);
+
+        let output = tensor.cummax(1);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[1.0, f32::NAN, f32::NAN], [f32::NAN, f32::NAN, f32::NAN]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_cummin_float() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+
+        let output = tensor.cummin(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, -2.0, -2.0], [5.0, 5.0, 5.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cummin_reverse() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+
+        let output = tensor.cummin_reverse(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[-2.0, -2.0, 3.0], [5.0, 6.0, 6.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cummin_propagates_nan() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, f32::NAN], [5.0, f32::NAN, 6.0]]);
+
+        let output = tensor.cummin_reverse(1);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[f32::NAN, f32::NAN, f32::NAN], [f32::NAN, f32::NAN, 6.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_cummin_int() {
+        let tensor = TestTensorInt::<2>::from([[1, -2, 3], [5, 9, 6]]);
+
+        let output = tensor.cummin_reverse(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-2, -2, 3], [5, 6, 6]]), false);
+    }
+
+    #[test]
+    fn test_cumsum_3d_middle_dim() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+
+        let output = tensor.cumsum(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[1.0, 2.0], [4.0, 6.0]], [[5.0, 6.0], [12.0, 14.0]]]),
+            false,
+        );
+    }
+}
@@ -0,0 +1,175 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_einsum_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[4.0, 7.0, 5.0], [2.0, 3.0, 5.0]], &device);
+
+        let output = TestTensor::<2>::einsum("ij,jk->ik", vec![lhs.into(), rhs.into()]);
+        let expected =
+            TensorData::from([[18.0, 28.0, 40.0], [14.0, 23.0, 25.0], [14.0, 22.0, 30.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_einsum_attention_scores() {
+        let device = Default::default();
+        let query = TestTensor::<4>::from_floats(
+            [[
+                [[1.0, 2.0], [0.0, 1.0], [3.0, -1.0]],
+                [[2.0, 0.0], [1.0, 1.0], [0.5, 2.0]],
+            ]],
+            &device,
+        );
+        let key = TestTensor::<4>::from_floats(
+            [[[[1.0, 0.0], [2.0, 1.0]], [[-1.0, 1.0], [0.0, 3.0]]]],
+            &device,
+        );
+
+        let output = TestTensor::<4>::einsum(
+            "bhqd,bhkd->bhqk",
+            vec![query.clone().into(), key.clone().into()],
+        );
+        let expected = query.matmul(key.swap_dims(2, 3));
+
+        assert_eq!(output.dims(), [1, 2, 3, 2]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_einsum_transpose() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = TestTensor::<2>::einsum("ij->ji", vec![tensor.into()]);
+        let expected = TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_einsum_sum_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = TestTensor::<1>::einsum("ij->j", vec![tensor.into()]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([5.0, 7.0, 9.0]), 3);
+    }
+
+    #[test]
+    fn test_einsum_trace_and_diagonal() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let trace = TestTensor::<1>::einsum("ii->", vec![tensor.clone().into()]);
+        let diagonal = TestTensor::<1>::einsum("ii->i", vec![tensor.into()]);
+
+        trace
+            .into_data()
+            .assert_approx_eq(&TensorData::from([15.0]), 3);
+        diagonal
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 5.0, 9.0]), 3);
+    }
+
+    #[test]
+    fn test_einsum_implicit_output() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[5.0, 6.0], [7.0, 8.0]]);
+
+        // Without an explicit output, all repeated subscripts are summed.
+        let dot = TestTensor::<1>::einsum("ij,ij", vec![lhs.clone().into(), rhs.clone().into()]);
+        let matmul = TestTensor::<2>::einsum("ij,jk", vec![lhs.into(), rhs.into()]);
+
+        dot.into_data()
+            .assert_approx_eq(&TensorData::from([70.0]), 3);
+        matmul
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[19.0, 22.0], [43.0, 50.0]]), 3);
+    }
+
+    #[test]
+    fn test_einsum_outer_product() {
+        let lhs = TestTensor::<1>::from([1.0, 2.0]);
+        let rhs = TestTensor::<1>::from([3.0, 4.0, 5.0]);
+
+        let output = TestTensor::<2>::einsum("i,j->ij", vec![lhs.into(), rhs.into()]);
+        let expected = TensorData::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_einsum_mixed_ranks() {
+        let matrix = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let vector = TestTensor::<1>::from([1.0, 0.0, -1.0]);
+
+        let output = TestTensor::<1>::einsum("ij,j->i", vec![matrix.into(), vector.into()]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-2.0, -2.0]), 3);
+    }
+
+    #[test]
+    fn test_einsum_multiple_operands() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let b = TestTensor::<2>::from_floats([[1.0, 0.0, 2.0, 1.0], [0.0, 1.0, 1.0, 3.0]], &device);
+        let c = TestTensor::<2>::from_floats([[1.0], [2.0], [0.5], [-1.0]], &device);
+
+        let output = TestTensor::<2>::einsum(
+            "ij,jk,kl->il",
+            vec![a.clone().into(), b.clone().into(), c.clone().into()],
+        );
+        let expected = a.matmul(b).matmul(c);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_einsum_ellipsis_broadcast() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [1.0, 0.0]]],
+            &device,
+        );
+        let rhs = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let output = TestTensor::<3>::einsum(
+            "...ij,jk->...ik",
+            vec![lhs.clone().into(), rhs.clone().into()],
+        );
+        let expected = lhs.matmul(rhs.unsqueeze::<3>());
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_einsum_should_panic_when_sizes_mismatch() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let _output = TestTensor::<2>::einsum("ij,jk->ik", vec![lhs.into(), rhs.into()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_einsum_should_panic_when_output_rank_mismatch() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output = TestTensor::<1>::einsum("ij->ij", vec![tensor.into()]);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::quantization::{QuantizationParameters, QuantizationScheme, QuantizationType};
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_fake_quantize_affine_int8() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.009_019_608], &device),
+            offset: Some(Tensor::from_ints([72], &device)),
+        };
+
+        let output = tensor.fake_quantize(&scheme, qparams);
+
+        // Quantized values [-128, -39, 72, 127]
+        let expected = TensorData::from([-1.803_921_6, -1.001_176_5, 0.0, 0.496_078_44]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fake_quantize_symmetric_int8() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.014_173_228], &device),
+            offset: None,
+        };
+
+        let output = tensor.fake_quantize(&scheme, qparams);
+
+        // Quantized values [-127, -71, 0, 35]
+        let expected = TensorData::from([-1.8, -1.006_299_2, 0.0, 0.496_063]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fake_quantize_per_channel_clamped() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::<2>::from_floats([[-7., 0., 3., 7.], [3.5, -3.5, 1., 0.6]], &device);
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt4, 0);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([1.0, 0.25], &device),
+            offset: None,
+        };
+
+        let output = tensor.fake_quantize(&scheme, qparams);
+
+        // Values of the second channel are clamped to the int4 range [-7, 7]
+        let expected = TensorData::from([[-7., 0., 3., 7.], [1.75, -1.75, 1., 0.5]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
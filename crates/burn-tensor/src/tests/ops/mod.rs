@@ -18,10 +18,13 @@ mod clamp;
 mod close;
 mod cos;
 mod create_like;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
+mod fake_quantize;
 mod flatten;
 mod flip;
 mod floor;
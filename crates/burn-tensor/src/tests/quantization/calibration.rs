@@ -2,7 +2,7 @@
 mod tests {
     use super::*;
     use burn_tensor::{
-        quantization::{Calibration, MinMaxCalibration, QuantizationType},
+        quantization::{Calibration, MinMaxCalibration, QuantizationScheme, QuantizationType},
         Tensor, TensorData,
     };
 
@@ -11,7 +11,9 @@ mod tests {
         let tensor = TestTensor::<1>::from_floats([-1.8, -1.0, 0.0, 0.5], &Default::default());
         let calibration = MinMaxCalibration {};
 
-        let range = calibration.compute_range(&tensor);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+
+        let range = calibration.compute_range(&tensor, &scheme);
 
         range
             .min
@@ -22,4 +24,46 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([0.5]), false);
     }
+
+    #[test]
+    fn min_max_calibration_range_per_channel() {
+        let tensor = TestTensor::<2>::from_floats(
+            [[-1.8, -1.0, 0.0], [0.5, 2.0, -3.0]],
+            &Default::default(),
+        );
+        let calibration = MinMaxCalibration {};
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 1);
+
+        let range = calibration.compute_range(&tensor, &scheme);
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([-1.8, -1.0, -3.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([0.5, 2.0, 0.0]), false);
+    }
+
+    #[test]
+    fn min_max_calibration_range_per_block() {
+        let tensor = TestTensor::<2>::from_floats(
+            [[-1.8, -1.0, 0.0, 0.5], [2.0, -3.0, 1.0, 4.0]],
+            &Default::default(),
+        );
+        let calibration = MinMaxCalibration {};
+        let scheme = QuantizationScheme::PerBlockAffine(QuantizationType::QInt8, 2);
+
+        let range = calibration.compute_range(&tensor, &scheme);
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([-1.8, 0.0, -3.0, 1.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0, 0.5, 2.0, 4.0]), false);
+    }
 }
@@ -1,3 +1,4 @@
 mod calibration;
+mod observer;
 mod ops;
 mod scheme;
@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(observer)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        quantization::{
+            HistogramObserver, MinMaxObserver, MovingAverageMinMaxObserver, Observer,
+            PercentileObserver,
+        },
+        TensorData,
+    };
+
+    #[test]
+    fn min_max_observer_range() {
+        let device = Default::default();
+        let mut observer = MinMaxObserver::new();
+
+        observer.observe(&TestTensor::<1>::from_floats(
+            [-1.8, -1.0, 0.0, 0.5],
+            &device,
+        ));
+        observer.observe(&TestTensor::<2>::from_floats([[-0.5, 2.5]], &device));
+
+        let range = observer.compute_range::<TestBackend>(&device);
+
+        range
+            .min
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.8]), 3);
+        range
+            .max
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.5]), 3);
+    }
+
+    #[test]
+    fn moving_average_min_max_observer_range() {
+        let device = Default::default();
+        let mut observer = MovingAverageMinMaxObserver::new(0.5);
+
+        observer.observe(&TestTensor::<1>::from_floats([-2.0, 4.0], &device));
+        observer.observe(&TestTensor::<1>::from_floats([-4.0, 8.0], &device));
+
+        assert_eq!(observer.range(), (-3.0, 6.0));
+    }
+
+    #[test]
+    fn percentile_observer_should_ignore_outliers() {
+        let device = Default::default();
+        let mut observer = PercentileObserver::new(99.0);
+        let values = (0..1000)
+            .map(|i| (i % 100) as f32 * 0.01)
+            .collect::<Vec<_>>();
+
+        observer.observe(&TestTensor::<1>::from_floats(values.as_slice(), &device));
+        observer.observe(&TestTensor::<1>::from_floats([-100.0, 100.0], &device));
+
+        let (min, max) = observer.range();
+        assert!(min > -0.1 && min < 0.1, "{min}");
+        assert!(max > 0.9 && max < 1.1, "{max}");
+    }
+
+    #[test]
+    fn histogram_observer_should_clip_outliers() {
+        let device = Default::default();
+        let mut observer = HistogramObserver::new(128);
+        let values = (1..10000)
+            .map(|i| (i as f32 / 10000.0).ln())
+            .collect::<Vec<_>>();
+
+        observer.observe(&TestTensor::<1>::from_floats(values.as_slice(), &device));
+        observer.observe(&TestTensor::<1>::from_floats([100.0], &device));
+
+        // The range is clipped close to the largest absolute value of the distribution (~9.2)
+        let (min, max) = observer.range();
+        assert_eq!(min, -max);
+        assert!(max > 8.0 && max < 10.0, "{max}");
+    }
+}
@@ -29,6 +29,8 @@ mod permute;
 mod powf;
 mod powf_scalar;
 mod quantize;
+mod quantize_block;
+mod quantize_sub_byte;
 mod recip;
 mod remainder;
 mod repeat_dim;
@@ -8,7 +8,7 @@ mod tests {
     };
     use burn_tensor::{DType, Tensor, TensorData};
 
-    fn get_q_params(data: TensorData) -> QParams<f32, i8> {
+    fn get_q_params(data: TensorData) -> QParams<Vec<f32>, Vec<i8>> {
         let num_elements = data.num_elements();
         let scheme = if let DType::QFloat(scheme) = data.dtype {
             scheme
@@ -119,4 +119,63 @@ mod tests {
 
         x_q.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_quantize_per_channel_symmetric_int8() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[-1.27, 0.5], [0.254, -0.1]], &device);
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 1);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.01, 0.003_937_008], &device),
+            offset: None,
+        };
+
+        let x_q = tensor.quantize(&scheme, qparams).into_data();
+
+        let expected = TensorData::quantized(
+            vec![-127i8, 127, 25, -25],
+            [2, 2],
+            QuantizationStrategy::PerChannelSymmetricInt8(
+                vec![
+                    SymmetricQuantization::init(0.01),
+                    SymmetricQuantization::init(0.003_937_008),
+                ],
+                1,
+            ),
+        );
+
+        // Values equality
+        x_q.assert_eq(&expected, true);
+
+        // Quantization parameters check
+        let qparams = get_q_params(x_q);
+        let expected = get_q_params(expected);
+        assert_eq!(qparams.scale, expected.scale);
+        assert_eq!(qparams.offset, expected.offset);
+    }
+
+    #[test]
+    fn should_support_quantize_dynamic_per_channel_int8() {
+        let device = Default::default();
+        // NOTE: we use fully representable values since different backend implementations could differ slightly
+        // due to rounding discrepancies
+        let tensor = TestTensor::<2>::from_floats([[5., 0.], [4., -10.]], &device);
+        let scheme = QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, 0);
+
+        let x_q = tensor.quantize_dynamic(&scheme);
+
+        let expected = TensorData::quantized(
+            vec![127i8, -128, 127, -128],
+            [2, 2],
+            QuantizationStrategy::PerChannelAffineInt8(
+                vec![
+                    AffineQuantization::init(0.019_607_844, -128),
+                    AffineQuantization::init(0.054_901_96, 54),
+                ],
+                0,
+            ),
+        );
+
+        x_q.into_data().assert_eq(&expected, false);
+    }
 }
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(quantize_block)]
+mod tests {
+    use super::*;
+    use burn_tensor::quantization::{
+        AffineQuantization, QuantizationScheme, QuantizationStrategy, QuantizationType,
+    };
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_dequantize_per_block() {
+        let device = Default::default();
+        let data = TensorData::quantized(
+            vec![-128i8, 127, -128, 127],
+            [2, 2],
+            QuantizationStrategy::PerBlockAffineInt8(
+                vec![
+                    AffineQuantization::init(0.003_921_569, -128),
+                    AffineQuantization::init(0.007_843_138, 127),
+                ],
+                2,
+            ),
+        );
+        let x_q = TestTensor::<2>::from_data(data, &device);
+
+        let x = x_q.dequantize();
+
+        x.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 1.0], [-2.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_quantize_dynamic_per_block_int8() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([5., 0., 4., -10.], &device);
+        let scheme = QuantizationScheme::PerBlockAffine(QuantizationType::QInt8, 2);
+
+        let x_q = tensor.quantize_dynamic(&scheme);
+
+        let expected = TensorData::quantized(
+            vec![127i8, -128, 127, -128],
+            [4],
+            QuantizationStrategy::PerBlockAffineInt8(
+                vec![
+                    AffineQuantization::init(0.019_607_844, -128),
+                    AffineQuantization::init(0.054_901_96, 54),
+                ],
+                2,
+            ),
+        );
+
+        x_q.into_data().assert_eq(&expected, false);
+    }
+}
@@ -0,0 +1,70 @@
+#[burn_tensor_testgen::testgen(quantize_sub_byte)]
+mod tests {
+    use super::*;
+    use burn_tensor::quantization::{
+        AffineQuantization, QuantizationScheme, QuantizationStrategy, QuantizationType,
+        SymmetricQuantization,
+    };
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_quantize_dynamic_uint4() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([5., 0., 4., -10.], &device);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt4);
+
+        let x_q = tensor.quantize_dynamic(&scheme);
+
+        let expected = TensorData::quantized(
+            vec![15i8, 10, 14, 0],
+            [4],
+            QuantizationStrategy::PerTensorAffineInt8(
+                AffineQuantization::init(1.0, 10).with_q_type(QuantizationType::QUInt4),
+            ),
+        );
+
+        x_q.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_quantize_dynamic_per_channel_int4() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::<2>::from_floats([[-7., 0., 3., 7.], [3.5, -3.5, 1., 0.5]], &device);
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt4, 0);
+
+        let x_q = tensor.quantize_dynamic(&scheme);
+
+        let expected = TensorData::quantized(
+            vec![-7i8, 0, 3, 7, 7, -7, 2, 1],
+            [2, 4],
+            QuantizationStrategy::PerChannelSymmetricInt8(
+                vec![
+                    SymmetricQuantization::init(1.0).with_q_type(QuantizationType::QInt4),
+                    SymmetricQuantization::init(0.5).with_q_type(QuantizationType::QInt4),
+                ],
+                0,
+            ),
+        );
+
+        x_q.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_dequantize_int2() {
+        let device = Default::default();
+        let data = TensorData::quantized(
+            vec![-2i8, -1, 0, 1, 1],
+            [5],
+            QuantizationStrategy::PerTensorAffineInt8(
+                AffineQuantization::init(0.5, 0).with_q_type(QuantizationType::QInt2),
+            ),
+        );
+        let x_q = TestTensor::<1>::from_data(data, &device);
+
+        let x = x_q.dequantize();
+
+        x.into_data()
+            .assert_approx_eq(&TensorData::from([-1.0, -0.5, 0.0, 0.5, 0.5]), 4);
+    }
+}
@@ -45,4 +45,119 @@ mod tests {
             .assert_approx_eq(&TensorData::from([0.014_173_228]), 8);
         assert!(qparams.offset.is_none());
     }
+
+    #[test]
+    fn per_channel_affine_int8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, 0);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8, 1.0], &device),
+            max: TestTensor::<1>::from_floats([0.5, 5.0], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.009_019_608, 0.019_607_844]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([71, -128]), false);
+    }
+
+    #[test]
+    fn per_block_symmetric_int8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerBlockSymmetric(QuantizationType::QInt8, 32);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8, -0.5], &device),
+            max: TestTensor::<1>::from_floats([0.5, 1.8], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.014_173_228, 0.014_173_228]), 8);
+        assert!(qparams.offset.is_none());
+    }
+
+    #[test]
+    fn per_tensor_affine_int4() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt4);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8], &device),
+            max: TestTensor::<1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.153_333_33]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([3]), false);
+    }
+
+    #[test]
+    fn per_tensor_affine_uint4() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt4);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8], &device),
+            max: TestTensor::<1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.153_333_33]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([11]), false);
+    }
+
+    #[test]
+    fn per_tensor_symmetric_int4() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8], &device),
+            max: TestTensor::<1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.257_142_84]), 8);
+        assert!(qparams.offset.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn per_tensor_symmetric_uint4_should_panic() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QUInt4);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8], &device),
+            max: TestTensor::<1>::from_floats([0.5], &device),
+        };
+
+        let _qparams = scheme.compute_q_params(range);
+    }
 }
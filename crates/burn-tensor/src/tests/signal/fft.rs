@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(signal_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+
+    #[test]
+    fn test_fft_power_of_two() {
+        let real = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0]);
+        let imag = TestTensor::<1>::zeros([4], &real.device());
+
+        let (real, imag) = signal::fft(real, imag, 0);
+
+        real.into_data()
+            .assert_approx_eq(&TensorData::from([10.0, -2.0, -2.0, -2.0]), 3);
+        imag.into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 2.0, 0.0, -2.0]), 3);
+    }
+
+    #[test]
+    fn test_fft_any_size() {
+        let real = TestTensor::<2>::from([[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]);
+        let imag = TestTensor::<2>::from([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+
+        let (real, imag) = signal::fft(real, imag, 1);
+
+        real.into_data()
+            .assert_approx_eq(&TensorData::from([[6.0, -1.5, -1.5], [0.0, 0.0, 0.0]]), 3);
+        imag.into_data().assert_approx_eq(
+            &TensorData::from([[0.0, 0.866, -0.866], [1.0, 1.0, 1.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_fft_ifft_roundtrip() {
+        let real = TestTensor::<2>::from([[1.0, -2.0], [0.5, 3.0], [4.0, 1.0]]);
+        let imag = TestTensor::<2>::from([[0.0, 1.0], [-1.0, 2.0], [0.5, 0.0]]);
+
+        let (out_real, out_imag) = signal::fft(real.clone(), imag.clone(), 0);
+        let (out_real, out_imag) = signal::ifft(out_real, out_imag, 0);
+
+        out_real.into_data().assert_approx_eq(&real.into_data(), 3);
+        out_imag.into_data().assert_approx_eq(&imag.into_data(), 3);
+    }
+
+    #[test]
+    fn test_rfft_irfft_roundtrip() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0], [2.0, -1.0, 0.0, 1.0, 3.0]]);
+
+        let (real, imag) = signal::rfft(tensor.clone(), 1);
+        assert_eq!(real.dims(), [2, 3]);
+        assert_eq!(imag.dims(), [2, 3]);
+
+        let output = signal::irfft(real, imag, 1, Some(5));
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn test_fft2() {
+        let real = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let imag = TestTensor::<2>::zeros([2, 2], &real.device());
+
+        let (real, imag) = signal::fft2(real, imag, [0, 1]);
+
+        real.into_data()
+            .assert_approx_eq(&TensorData::from([[10.0, -2.0], [-4.0, 0.0]]), 3);
+        imag.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn test_rfft2_irfft2_roundtrip() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0, 0.0], [3.0, 4.0, -1.0]]]);
+
+        let (real, imag) = signal::rfft2(tensor.clone(), [1, 2]);
+        let output = signal::irfft2(real, imag, [1, 2], Some(3));
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
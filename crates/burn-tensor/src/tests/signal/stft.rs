@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(signal_stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+
+    #[test]
+    fn test_stft_rectangular_window() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]]);
+
+        let (real, imag) = signal::stft(tensor, None, signal::StftOptions::new(4, 2, false));
+
+        real.into_data().assert_approx_eq(
+            &TensorData::from([[[10.0, -2.0, -2.0], [18.0, -2.0, -2.0], [26.0, -2.0, -2.0]]]),
+            3,
+        );
+        imag.into_data().assert_approx_eq(
+            &TensorData::from([[[0.0, 2.0, 0.0], [0.0, 2.0, 0.0], [0.0, 2.0, 0.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_stft_center_shape() {
+        let tensor = TestTensor::<2>::zeros([2, 10], &Default::default());
+
+        let (real, imag) = signal::stft(tensor, None, signal::StftOptions::new(4, 2, true));
+
+        assert_eq!(real.dims(), [2, 6, 3]);
+        assert_eq!(imag.dims(), [2, 6, 3]);
+    }
+
+    #[test]
+    fn test_stft_center_shortest_signal() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0]]);
+
+        // The signal [1, 2, 3] is reflect padded to [3, 2, 1, 2, 3, 2, 1]
+        let (real, _imag) = signal::stft(tensor, None, signal::StftOptions::new(4, 2, true));
+
+        real.into_data()
+            .assert_approx_eq(&TensorData::from([[[8.0, 2.0, 0.0], [8.0, -2.0, 0.0]]]), 3);
+    }
+
+    #[test]
+    #[should_panic = "longer than n_fft / 2"]
+    fn test_stft_center_short_signal_should_panic() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0]]);
+
+        let _output = signal::stft(tensor, None, signal::StftOptions::new(4, 2, true));
+    }
+
+    #[test]
+    fn test_stft_istft_roundtrip() {
+        let tensor = TestTensor::<2>::from([
+            [1.0, 2.0, -1.0, 0.5, 3.0, 0.0, -2.0, 1.0],
+            [0.0, 1.0, 4.0, -3.0, 2.0, 2.5, 1.0, -1.0],
+        ]);
+        let window = signal::hann_window::<TestBackend>(4, true, &tensor.device());
+        let options = signal::StftOptions::new(4, 1, true);
+
+        let (real, imag) = signal::stft(tensor.clone(), Some(window.clone()), options.clone());
+        let output = signal::istft(real, imag, Some(window), options, Some(8));
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(signal_window)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+
+    #[test]
+    fn test_hann_window() {
+        let device = Default::default();
+
+        let periodic = signal::hann_window::<TestBackend>(4, true, &device);
+        let symmetric = signal::hann_window::<TestBackend>(5, false, &device);
+
+        periodic
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.5, 1.0, 0.5]), 3);
+        symmetric
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.5, 1.0, 0.5, 0.0]), 3);
+    }
+
+    #[test]
+    fn test_hamming_window() {
+        let device = Default::default();
+
+        let window = signal::hamming_window::<TestBackend>(4, true, &device);
+
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.08, 0.54, 1.0, 0.54]), 3);
+    }
+
+    #[test]
+    fn test_blackman_window() {
+        let device = Default::default();
+
+        let window = signal::blackman_window::<TestBackend>(4, true, &device);
+
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.34, 1.0, 0.34]), 3);
+    }
+}
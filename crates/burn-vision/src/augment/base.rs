@@ -0,0 +1,130 @@
+use alloc::{boxed::Box, vec::Vec};
+use burn_tensor::{backend::Backend, Bool, Shape, Tensor, TensorData};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{warp_affine, VisionOps, WarpOptions};
+
+/// An augmentation of a batch of images [batch_size, channels, height, width], running on the
+/// device of the images.
+///
+/// The random parameters of each image are drawn from its own generator, so that an image is
+/// augmented the same way whatever the batch it belongs to.
+pub trait Augmentation<B: Backend>: Send + Sync {
+    /// Augments the images, drawing the parameters of each image from the generator at its
+    /// index in the batch.
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4>;
+}
+
+/// Creates the generator of each item of a batch from a seed and the index of the item in the
+/// dataset.
+pub fn item_rngs(seed: u64, items: impl IntoIterator<Item = usize>) -> Vec<StdRng> {
+    items
+        .into_iter()
+        .map(|item| StdRng::seed_from_u64(mix_seed(seed, item as u64)))
+        .collect()
+}
+
+/// Mixes the seed with the index of the item with the finalizer of `splitmix64`, so that
+/// neighboring items get unrelated streams.
+fn mix_seed(seed: u64, item: u64) -> u64 {
+    let mut z = seed ^ item.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Applies a sequence of augmentations in order.
+pub struct Compose<B: Backend> {
+    augmentations: Vec<Box<dyn Augmentation<B>>>,
+}
+
+impl<B: Backend> Compose<B> {
+    /// Creates an empty sequence, leaving the images unchanged.
+    pub fn new() -> Self {
+        Self {
+            augmentations: Vec::new(),
+        }
+    }
+
+    /// Appends an augmentation to the sequence.
+    pub fn with(mut self, augmentation: impl Augmentation<B> + 'static) -> Self {
+        self.augmentations.push(Box::new(augmentation));
+        self
+    }
+}
+
+impl<B: Backend> Default for Compose<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> Augmentation<B> for Compose<B> {
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4> {
+        self.augmentations
+            .iter()
+            .fold(images, |images, augmentation| {
+                augmentation.apply(images, rngs)
+            })
+    }
+}
+
+/// Creates a tensor [batch_size, 1, 1, 1] with a value per item, broadcasting over the images.
+pub(crate) fn item_values<B: Backend>(values: Vec<f32>, device: &B::Device) -> Tensor<B, 4> {
+    let shape = Shape::new([values.len(), 1, 1, 1]);
+    Tensor::from_data(
+        TensorData::new(values, shape).convert::<B::FloatElem>(),
+        device,
+    )
+}
+
+/// Keeps the augmented images of the selected items and the original images of the others.
+pub(crate) fn select_items<B: Backend>(
+    images: Tensor<B, 4>,
+    augmented: Tensor<B, 4>,
+    selected: &[bool],
+) -> Tensor<B, 4> {
+    if selected.iter().all(|&selected| selected) {
+        return augmented;
+    }
+    if !selected.contains(&true) {
+        return images;
+    }
+
+    let shape = images.dims();
+    let mask = TensorData::new(selected.to_vec(), Shape::new([selected.len(), 1, 1, 1]));
+    let mask = Tensor::<B, 4, Bool>::from_data(mask, &images.device()).expand(shape);
+
+    images.mask_where(mask, augmented)
+}
+
+/// Warps each image with its affine transform `[a, b, c, d, e, f]`, mapping the location
+/// `(x, y)` of the image to `(a * x + b * y + c, d * x + e * y + f)` in the output.
+pub(crate) fn warp_items<B: Backend + VisionOps<B>>(
+    images: Tensor<B, 4>,
+    matrices: Vec<[f32; 6]>,
+    output_size: [usize; 2],
+    options: WarpOptions,
+) -> Tensor<B, 4> {
+    let batch_size = matrices.len();
+    let data = matrices.into_iter().flatten().collect::<Vec<_>>();
+    let data = TensorData::new(data, Shape::new([batch_size, 2, 3]));
+    let matrices = Tensor::from_data(data.convert::<B::FloatElem>(), &images.device());
+
+    warp_affine(images, matrices, output_size, options)
+}
+
+/// The affine transform rotating the image by `angle` degrees around its center.
+pub(crate) fn rotation([height, width]: [usize; 2], angle: f32) -> [f32; 6] {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let (cx, cy) = ((width as f32 - 1.0) / 2.0, (height as f32 - 1.0) / 2.0);
+
+    [
+        cos,
+        -sin,
+        cx - cos * cx + sin * cy,
+        sin,
+        cos,
+        cy - sin * cx - cos * cy,
+    ]
+}
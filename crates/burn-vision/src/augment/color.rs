@@ -0,0 +1,136 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Tensor};
+use rand::{rngs::StdRng, Rng};
+
+use super::base::{item_values, Augmentation};
+use crate::{color_convert, ColorConversion, VisionOps};
+
+/// Randomly changes the brightness, contrast, saturation and hue of each image, like
+/// `torchvision.transforms.ColorJitter`.
+///
+/// Images are RGB or grayscale with values in `[0, 1]`. The adjustments are applied in a fixed
+/// order, and the saturation and the hue are left unchanged for grayscale images.
+#[derive(derive_new::new, Clone, Debug)]
+pub struct ColorJitter {
+    /// The brightness factor is drawn in `[max(0, 1 - brightness), 1 + brightness]`.
+    pub brightness: f32,
+    /// The contrast factor is drawn in `[max(0, 1 - contrast), 1 + contrast]`.
+    pub contrast: f32,
+    /// The saturation factor is drawn in `[max(0, 1 - saturation), 1 + saturation]`.
+    pub saturation: f32,
+    /// The hue shift is drawn in `[-hue, hue]`, as a fraction of a full turn up to `0.5`.
+    pub hue: f32,
+}
+
+impl<B: Backend + VisionOps<B>> Augmentation<B> for ColorJitter {
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4> {
+        assert!(
+            (0.0..=0.5).contains(&self.hue),
+            "The hue jitter must be in [0, 0.5], got {}",
+            self.hue
+        );
+
+        let device = images.device();
+        let mut factors = vec![Vec::with_capacity(rngs.len()); 4];
+        for rng in rngs.iter_mut() {
+            factors[0].push(factor(rng, self.brightness));
+            factors[1].push(factor(rng, self.contrast));
+            factors[2].push(factor(rng, self.saturation));
+            factors[3].push(rng.random_range(-self.hue..=self.hue));
+        }
+        let [brightness, contrast, saturation, hue] = factors.try_into().unwrap();
+
+        let mut images = images;
+        if self.brightness > 0.0 {
+            images = adjust_brightness(images, item_values(brightness, &device));
+        }
+        if self.contrast > 0.0 {
+            images = adjust_contrast(images, item_values(contrast, &device));
+        }
+        if self.saturation > 0.0 {
+            images = adjust_saturation(images, item_values(saturation, &device));
+        }
+        if self.hue > 0.0 {
+            images = adjust_hue(images, item_values(hue, &device));
+        }
+
+        images
+    }
+}
+
+fn factor(rng: &mut StdRng, jitter: f32) -> f32 {
+    rng.random_range((1.0 - jitter).max(0.0)..=1.0 + jitter)
+}
+
+/// Interpolates between the two images, extrapolating for a factor above one.
+pub(crate) fn blend<B: Backend>(
+    images: Tensor<B, 4>,
+    other: Tensor<B, 4>,
+    factor: Tensor<B, 4>,
+) -> Tensor<B, 4> {
+    (other.clone() + (images - other) * factor).clamp(0.0, 1.0)
+}
+
+pub(crate) fn grayscale<B: Backend + VisionOps<B>>(images: Tensor<B, 4>) -> Tensor<B, 4> {
+    match images.dims()[1] {
+        3 => color_convert(images, ColorConversion::RgbToGray),
+        _ => images,
+    }
+}
+
+pub(crate) fn adjust_brightness<B: Backend>(
+    images: Tensor<B, 4>,
+    factor: Tensor<B, 4>,
+) -> Tensor<B, 4> {
+    (images * factor).clamp(0.0, 1.0)
+}
+
+/// Blends each image with the mean of its luma.
+pub(crate) fn adjust_contrast<B: Backend + VisionOps<B>>(
+    images: Tensor<B, 4>,
+    factor: Tensor<B, 4>,
+) -> Tensor<B, 4> {
+    let mean = grayscale(images.clone())
+        .mean_dim(1)
+        .mean_dim(2)
+        .mean_dim(3);
+    let mean = mean.expand(images.dims());
+
+    blend(images, mean, factor)
+}
+
+/// Blends each RGB image with its grayscale version.
+pub(crate) fn adjust_saturation<B: Backend + VisionOps<B>>(
+    images: Tensor<B, 4>,
+    factor: Tensor<B, 4>,
+) -> Tensor<B, 4> {
+    if images.dims()[1] != 3 {
+        return images;
+    }
+
+    let gray = grayscale(images.clone()).expand(images.dims());
+    blend(images, gray, factor)
+}
+
+/// Rotates the hue of each RGB image by a fraction of a full turn.
+pub(crate) fn adjust_hue<B: Backend + VisionOps<B>>(
+    images: Tensor<B, 4>,
+    shift: Tensor<B, 4>,
+) -> Tensor<B, 4> {
+    if images.dims()[1] != 3 {
+        return images;
+    }
+
+    let hsv = color_convert(images, ColorConversion::RgbToHsv);
+    let [hue, saturation, value] = hsv.chunk(3, 1).try_into().unwrap();
+    // The shifted hue stays positive, so that the remainder wraps it in [0, 360).
+    let hue = (hue + shift.mul_scalar(360.0))
+        .add_scalar(360.0)
+        .remainder_scalar(360.0);
+
+    color_convert(
+        Tensor::cat(vec![hue, saturation, value], 1),
+        ColorConversion::HsvToRgb,
+    )
+}
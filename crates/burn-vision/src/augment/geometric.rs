@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, ops::InterpolateMode, Tensor};
+use rand::{rngs::StdRng, Rng};
+
+use super::base::{rotation, select_items, warp_items, Augmentation};
+use crate::{BorderType, VisionOps, WarpOptions};
+
+/// Crops a random region of each image and resizes it to a fixed size, like
+/// `torchvision.transforms.RandomResizedCrop`.
+#[derive(derive_new::new, Clone, Debug)]
+pub struct RandomResizedCrop {
+    /// The size of the output, as `[height, width]`.
+    pub size: [usize; 2],
+    /// The range of the area of the region, relative to the area of the image.
+    #[new(value = "(0.08, 1.0)")]
+    pub scale: (f32, f32),
+    /// The range of the aspect ratio of the region, as its width over its height.
+    #[new(value = "(3.0 / 4.0, 4.0 / 3.0)")]
+    pub ratio: (f32, f32),
+}
+
+impl RandomResizedCrop {
+    /// Samples a region `(top, left, height, width)` of an image, falling back to the largest
+    /// centered region within the aspect ratio range after ten attempts.
+    fn sample_region(&self, rng: &mut StdRng, [height, width]: [usize; 2]) -> [f32; 4] {
+        let area = (height * width) as f32;
+        let (log_min, log_max) = (self.ratio.0.ln(), self.ratio.1.ln());
+
+        for _ in 0..10 {
+            let target_area = area * rng.random_range(self.scale.0..=self.scale.1);
+            let ratio = rng.random_range(log_min..=log_max).exp();
+            let crop_width = (target_area * ratio).sqrt().round() as usize;
+            let crop_height = (target_area / ratio).sqrt().round() as usize;
+
+            if (1..=width).contains(&crop_width) && (1..=height).contains(&crop_height) {
+                let top = rng.random_range(0..=height - crop_height);
+                let left = rng.random_range(0..=width - crop_width);
+                return [
+                    top as f32,
+                    left as f32,
+                    crop_height as f32,
+                    crop_width as f32,
+                ];
+            }
+        }
+
+        let image_ratio = width as f32 / height as f32;
+        let (crop_height, crop_width) = if image_ratio < self.ratio.0 {
+            ((width as f32 / self.ratio.0).round(), width as f32)
+        } else if image_ratio > self.ratio.1 {
+            (height as f32, (height as f32 * self.ratio.1).round())
+        } else {
+            (height as f32, width as f32)
+        };
+
+        [
+            ((height as f32 - crop_height) / 2.0).floor(),
+            ((width as f32 - crop_width) / 2.0).floor(),
+            crop_height,
+            crop_width,
+        ]
+    }
+}
+
+impl<B: Backend + VisionOps<B>> Augmentation<B> for RandomResizedCrop {
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4> {
+        let [_, _, height, width] = images.dims();
+        let [out_height, out_width] = self.size;
+
+        let matrices = rngs
+            .iter_mut()
+            .map(|rng| {
+                let [top, left, crop_height, crop_width] = self.sample_region(rng, [height, width]);
+                // Maps the region to the output, aligning the centers of the corner pixels.
+                let (scale_y, scale_x) = (
+                    out_height as f32 / crop_height,
+                    out_width as f32 / crop_width,
+                );
+                let offset_y = top + 0.5 / scale_y - 0.5;
+                let offset_x = left + 0.5 / scale_x - 0.5;
+
+                [
+                    scale_x,
+                    0.0,
+                    -offset_x * scale_x,
+                    0.0,
+                    scale_y,
+                    -offset_y * scale_y,
+                ]
+            })
+            .collect();
+
+        let options = WarpOptions::new(InterpolateMode::Bilinear, BorderType::Replicate);
+        warp_items(images, matrices, self.size, options)
+    }
+}
+
+/// Flips each image horizontally with a probability.
+#[derive(derive_new::new, Clone, Debug)]
+pub struct RandomHorizontalFlip {
+    /// The probability of flipping an image.
+    #[new(value = "0.5")]
+    pub probability: f64,
+}
+
+impl<B: Backend> Augmentation<B> for RandomHorizontalFlip {
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4> {
+        flip_items(images, rngs, self.probability, 3)
+    }
+}
+
+/// Flips each image vertically with a probability.
+#[derive(derive_new::new, Clone, Debug)]
+pub struct RandomVerticalFlip {
+    /// The probability of flipping an image.
+    #[new(value = "0.5")]
+    pub probability: f64,
+}
+
+impl<B: Backend> Augmentation<B> for RandomVerticalFlip {
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4> {
+        flip_items(images, rngs, self.probability, 2)
+    }
+}
+
+fn flip_items<B: Backend>(
+    images: Tensor<B, 4>,
+    rngs: &mut [StdRng],
+    probability: f64,
+    dim: isize,
+) -> Tensor<B, 4> {
+    let selected = rngs
+        .iter_mut()
+        .map(|rng| rng.random_bool(probability))
+        .collect::<Vec<_>>();
+
+    if !selected.contains(&true) {
+        return images;
+    }
+
+    let flipped = images.clone().flip([dim]);
+    select_items(images, flipped, &selected)
+}
+
+/// Rotates each image around its center by a random angle.
+#[derive(derive_new::new, Clone, Debug)]
+pub struct RandomRotation {
+    /// The maximum angle in degrees, the angle being drawn in `[-degrees, degrees]`.
+    pub degrees: f32,
+    /// The value of the pixels rotated from outside of the image.
+    #[new(value = "0.0")]
+    pub fill: f32,
+    /// The interpolation used to sample the image.
+    #[new(value = "InterpolateMode::Bilinear")]
+    pub mode: InterpolateMode,
+}
+
+impl<B: Backend + VisionOps<B>> Augmentation<B> for RandomRotation {
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4> {
+        let [_, _, height, width] = images.dims();
+        let matrices = rngs
+            .iter_mut()
+            .map(|rng| {
+                let angle = rng.random_range(-self.degrees..=self.degrees);
+                rotation([height, width], angle)
+            })
+            .collect();
+
+        let options = WarpOptions::new(self.mode.clone(), BorderType::Constant(self.fill));
+        warp_items(images, matrices, [height, width], options)
+    }
+}
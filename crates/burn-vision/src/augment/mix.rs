@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Bool, Shape, Tensor, TensorData};
+use rand::{rngs::StdRng, Rng};
+use rand_distr::{Beta, Distribution};
+
+use super::base::item_values;
+
+/// An augmentation mixing the images of a batch [batch_size, channels, height, width] together
+/// with their targets [batch_size, num_classes], like one-hot or smoothed labels.
+///
+/// Each image is mixed with the image at the mirrored index of the batch, so that the result
+/// only depends on the generators of the items and the order of the batch.
+pub trait MixAugmentation<B: Backend>: Send + Sync {
+    /// Mixes the images and their targets, drawing the parameters of each image from the
+    /// generator at its index in the batch.
+    fn apply(
+        &self,
+        images: Tensor<B, 4>,
+        targets: Tensor<B, 2>,
+        rngs: &mut [StdRng],
+    ) -> (Tensor<B, 4>, Tensor<B, 2>);
+}
+
+/// Blends each image and its target with another item of the batch, with a weight drawn from a
+/// `Beta(alpha, alpha)` distribution.
+///
+/// [mixup: Beyond Empirical Risk Minimization](https://arxiv.org/abs/1710.09412)
+#[derive(derive_new::new, Clone, Debug)]
+pub struct Mixup {
+    /// The concentration of the distribution of the weights.
+    pub alpha: f32,
+}
+
+impl<B: Backend> MixAugmentation<B> for Mixup {
+    fn apply(
+        &self,
+        images: Tensor<B, 4>,
+        targets: Tensor<B, 2>,
+        rngs: &mut [StdRng],
+    ) -> (Tensor<B, 4>, Tensor<B, 2>) {
+        let device = images.device();
+        let lambdas = sample_lambdas(rngs, self.alpha);
+        let weights = item_values::<B>(lambdas.clone(), &device);
+
+        let partners = images.clone().flip([0]);
+        let images = partners.clone() + (images - partners) * weights;
+
+        (images, mix_targets(targets, lambdas))
+    }
+}
+
+/// Replaces a random box of each image with the same box of another item of the batch, mixing
+/// the targets with the proportion of the area that is kept.
+///
+/// The size of the box is drawn so that the kept area follows a `Beta(alpha, alpha)`
+/// distribution, before it is clipped to the image.
+///
+/// [CutMix: Regularization Strategy to Train Strong Classifiers with Localizable
+/// Features](https://arxiv.org/abs/1905.04899)
+#[derive(derive_new::new, Clone, Debug)]
+pub struct CutMix {
+    /// The concentration of the distribution of the kept area.
+    pub alpha: f32,
+}
+
+impl<B: Backend> MixAugmentation<B> for CutMix {
+    fn apply(
+        &self,
+        images: Tensor<B, 4>,
+        targets: Tensor<B, 2>,
+        rngs: &mut [StdRng],
+    ) -> (Tensor<B, 4>, Tensor<B, 2>) {
+        let shape = images.dims();
+        let [batch_size, _, height, width] = shape;
+        let lambdas = sample_lambdas(rngs, self.alpha);
+
+        let mut mask = Vec::with_capacity(batch_size * height * width);
+        let mut kept = Vec::with_capacity(batch_size);
+        for (rng, lambda) in rngs.iter_mut().zip(lambdas) {
+            let cut_ratio = (1.0 - lambda).sqrt();
+            let (center_y, center_x) = (
+                rng.random_range(0..height) as f32,
+                rng.random_range(0..width) as f32,
+            );
+            let bounds = |center: f32, size: usize| {
+                let half = (size as f32 * cut_ratio / 2.0).floor();
+                let low = (center - half).clamp(0.0, size as f32) as usize;
+                let high = (center + half).clamp(0.0, size as f32) as usize;
+                low..high
+            };
+            let (rows, cols) = (bounds(center_y, height), bounds(center_x, width));
+
+            mask.extend(
+                (0..height * width)
+                    .map(|i| rows.contains(&(i / width)) && cols.contains(&(i % width))),
+            );
+            kept.push(1.0 - (rows.len() * cols.len()) as f32 / (height * width) as f32);
+        }
+
+        let mask = TensorData::new(mask, Shape::new([batch_size, 1, height, width]));
+        let mask = Tensor::<B, 4, Bool>::from_data(mask, &images.device()).expand(shape);
+        let images = images.clone().mask_where(mask, images.flip([0]));
+
+        (images, mix_targets(targets, kept))
+    }
+}
+
+/// Draws the weight of each item from a `Beta(alpha, alpha)` distribution.
+fn sample_lambdas(rngs: &mut [StdRng], alpha: f32) -> Vec<f32> {
+    assert!(alpha > 0.0, "Alpha must be positive, got {alpha}");
+    let beta = Beta::new(alpha, alpha).unwrap();
+
+    rngs.iter_mut().map(|rng| beta.sample(rng)).collect()
+}
+
+/// Mixes each target with the target of its partner, weighting the target of the item by its
+/// weight.
+fn mix_targets<B: Backend>(targets: Tensor<B, 2>, weights: Vec<f32>) -> Tensor<B, 2> {
+    let num_items = weights.len();
+    let weights = TensorData::new(weights, Shape::new([num_items, 1]));
+    let weights = Tensor::<B, 2>::from_data(weights.convert::<B::FloatElem>(), &targets.device());
+
+    let partners = targets.clone().flip([0]);
+    partners.clone() + (targets - partners) * weights
+}
@@ -0,0 +1,21 @@
+//! Data augmentation of batches of images, running on the device of the batch.
+//!
+//! Augmentations are applied to the batched tensor, typically at the end of a
+//! `Batcher` once the images are on the training device. The random parameters of each image are
+//! drawn from its own generator, created with [item_rngs] from a seed and the index of the item
+//! in the dataset, so that runs are reproducible whatever the batching and the number of workers.
+//!
+//! Augmentations are chained with [Compose], while [Mixup] and [CutMix] also mix the targets
+//! and implement [MixAugmentation].
+
+mod base;
+mod color;
+mod geometric;
+mod mix;
+mod rand_augment;
+
+pub use base::{item_rngs, Augmentation, Compose};
+pub use color::ColorJitter;
+pub use geometric::{RandomHorizontalFlip, RandomResizedCrop, RandomRotation, RandomVerticalFlip};
+pub use mix::{CutMix, MixAugmentation, Mixup};
+pub use rand_augment::RandAugment;
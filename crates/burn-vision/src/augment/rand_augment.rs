@@ -0,0 +1,203 @@
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, ops::InterpolateMode, Tensor};
+use rand::{rngs::StdRng, Rng};
+
+use super::{
+    base::{item_values, rotation, select_items, warp_items, Augmentation},
+    color::{adjust_brightness, adjust_contrast, adjust_saturation, blend},
+};
+use crate::{filter2d, BorderType, VisionOps, WarpOptions};
+
+/// Applies a number of operations drawn for each image, with a shared magnitude, like
+/// `torchvision.transforms.RandAugment`.
+///
+/// Images are RGB or grayscale with values in `[0, 1]`. The histogram equalization of the
+/// original operation set is not included.
+#[derive(derive_new::new, Clone, Debug)]
+pub struct RandAugment {
+    /// The number of operations applied to each image.
+    #[new(value = "2")]
+    pub num_ops: usize,
+    /// The magnitude of the operations, as a bin of `[0, num_magnitude_bins)`.
+    #[new(value = "9")]
+    pub magnitude: usize,
+    /// The number of magnitude bins.
+    #[new(value = "31")]
+    pub num_magnitude_bins: usize,
+    /// The value of the pixels moved from outside of the image by geometric operations.
+    #[new(value = "0.0")]
+    pub fill: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operation {
+    Identity,
+    ShearX,
+    ShearY,
+    TranslateX,
+    TranslateY,
+    Rotate,
+    Brightness,
+    Color,
+    Contrast,
+    Sharpness,
+    Posterize,
+    Solarize,
+    AutoContrast,
+}
+
+const OPERATIONS: [Operation; 13] = [
+    Operation::Identity,
+    Operation::ShearX,
+    Operation::ShearY,
+    Operation::TranslateX,
+    Operation::TranslateY,
+    Operation::Rotate,
+    Operation::Brightness,
+    Operation::Color,
+    Operation::Contrast,
+    Operation::Sharpness,
+    Operation::Posterize,
+    Operation::Solarize,
+    Operation::AutoContrast,
+];
+
+impl Operation {
+    /// The magnitude of the operation at a fraction of its range, for an image [height, width].
+    fn magnitude(&self, fraction: f32, [height, width]: [usize; 2]) -> f32 {
+        match self {
+            Operation::ShearX | Operation::ShearY => 0.3 * fraction,
+            Operation::TranslateX => 150.0 / 331.0 * width as f32 * fraction,
+            Operation::TranslateY => 150.0 / 331.0 * height as f32 * fraction,
+            Operation::Rotate => 30.0 * fraction,
+            Operation::Brightness
+            | Operation::Color
+            | Operation::Contrast
+            | Operation::Sharpness => 0.9 * fraction,
+            Operation::Posterize => 8.0 - (4.0 * fraction).round(),
+            Operation::Solarize => 1.0 - fraction,
+            Operation::Identity | Operation::AutoContrast => 0.0,
+        }
+    }
+
+    /// Whether the direction of the operation is drawn for each image.
+    fn is_signed(&self) -> bool {
+        !matches!(
+            self,
+            Operation::Identity
+                | Operation::Posterize
+                | Operation::Solarize
+                | Operation::AutoContrast
+        )
+    }
+
+    fn apply<B: Backend + VisionOps<B>>(
+        &self,
+        images: Tensor<B, 4>,
+        magnitudes: Vec<f32>,
+        fill: f32,
+    ) -> Tensor<B, 4> {
+        let [_, _, height, width] = images.dims();
+        let device = images.device();
+        let affine = |matrix: &dyn Fn(f32) -> [f32; 6]| {
+            let matrices = magnitudes.iter().map(|&m| matrix(m)).collect();
+            let options = WarpOptions::new(InterpolateMode::Nearest, BorderType::Constant(fill));
+            warp_items(images.clone(), matrices, [height, width], options)
+        };
+        let factors = || item_values::<B>(magnitudes.iter().map(|m| 1.0 + m).collect(), &device);
+
+        match self {
+            Operation::Identity => images,
+            Operation::ShearX => affine(&|m| [1.0, m, 0.0, 0.0, 1.0, 0.0]),
+            Operation::ShearY => affine(&|m| [1.0, 0.0, 0.0, m, 1.0, 0.0]),
+            Operation::TranslateX => affine(&|m| [1.0, 0.0, m, 0.0, 1.0, 0.0]),
+            Operation::TranslateY => affine(&|m| [1.0, 0.0, 0.0, 0.0, 1.0, m]),
+            Operation::Rotate => affine(&|angle| rotation([height, width], angle)),
+            Operation::Brightness => adjust_brightness(images, factors()),
+            Operation::Color => adjust_saturation(images, factors()),
+            Operation::Contrast => adjust_contrast(images, factors()),
+            Operation::Sharpness => {
+                let kernel = Tensor::<B, 2>::from_floats(
+                    [[1.0, 1.0, 1.0], [1.0, 5.0, 1.0], [1.0, 1.0, 1.0]],
+                    &device,
+                )
+                .div_scalar(13.0);
+                let smooth = filter2d(images.clone(), kernel, BorderType::Replicate);
+                blend(images, smooth, factors())
+            }
+            Operation::Posterize => {
+                // Keeps the most significant bits of the 8-bit values.
+                let step = 2.0f32.powf(8.0 - magnitudes[0]);
+                images
+                    .mul_scalar(255.0)
+                    .floor()
+                    .div_scalar(step)
+                    .floor()
+                    .mul_scalar(step / 255.0)
+            }
+            Operation::Solarize => {
+                let mask = images.clone().greater_equal_elem(magnitudes[0]);
+                let inverted = images.clone().neg().add_scalar(1.0);
+                images.mask_where(mask, inverted)
+            }
+            Operation::AutoContrast => {
+                let shape = images.dims();
+                let min = images.clone().min_dim(3).min_dim(2);
+                let max = images.clone().max_dim(3).max_dim(2);
+                // Uniform channels are left unchanged.
+                let uniform = max.clone().equal(min.clone());
+                let min = min.mask_fill(uniform.clone(), 0.0);
+                let range = (max - min.clone()).mask_fill(uniform, 1.0);
+
+                ((images - min.expand(shape)) / range.expand(shape)).clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+impl<B: Backend + VisionOps<B>> Augmentation<B> for RandAugment {
+    fn apply(&self, images: Tensor<B, 4>, rngs: &mut [StdRng]) -> Tensor<B, 4> {
+        let [_, _, height, width] = images.dims();
+        let fraction = match self.num_magnitude_bins {
+            0 | 1 => 0.0,
+            bins => self.magnitude as f32 / (bins - 1) as f32,
+        };
+        let mut images = images;
+
+        for _ in 0..self.num_ops {
+            let choices = rngs
+                .iter_mut()
+                .map(|rng| {
+                    let operation = rng.random_range(0..OPERATIONS.len());
+                    let sign = if rng.random_bool(0.5) { -1.0 } else { 1.0 };
+                    (operation, sign)
+                })
+                .collect::<Vec<_>>();
+
+            // Each operation runs on the whole batch, keeping the images that drew it.
+            for (index, operation) in OPERATIONS.iter().enumerate() {
+                let selected = choices
+                    .iter()
+                    .map(|&(choice, _)| choice == index)
+                    .collect::<Vec<_>>();
+                if *operation == Operation::Identity || !selected.contains(&true) {
+                    continue;
+                }
+
+                let magnitude = operation.magnitude(fraction, [height, width]);
+                let magnitudes = choices
+                    .iter()
+                    .map(|&(_, sign)| match operation.is_signed() {
+                        true => sign * magnitude,
+                        false => magnitude,
+                    })
+                    .collect();
+
+                let augmented = operation.apply(images.clone(), magnitudes, self.fill);
+                images = select_items(images, augmented, &selected);
+            }
+        }
+
+        images
+    }
+}
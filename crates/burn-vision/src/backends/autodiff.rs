@@ -0,0 +1,189 @@
+use burn_autodiff::{
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    ops::{Backward, Ops, OpsKind},
+    Autodiff, NodeID,
+};
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    ops::{BoolTensor, FloatTensor, IntTensor},
+};
+
+use crate::{
+    ops::filter2d_composed, BorderType, CannyOptions, ColorConversion, ConnectedStatsOptions,
+    ConnectedStatsPrimitive, Connectivity, NmsOptions, RoiAlignOptions, RoiPoolOptions, VisionOps,
+    WarpOptions,
+};
+
+impl<B: Backend + VisionOps<B>, C: CheckpointStrategy> VisionOps<Self> for Autodiff<B, C> {
+    fn connected_components(img: BoolTensor<Self>, connectivity: Connectivity) -> IntTensor<Self> {
+        B::connected_components(img, connectivity)
+    }
+
+    fn connected_components_with_stats(
+        img: BoolTensor<Self>,
+        connectivity: Connectivity,
+        opts: ConnectedStatsOptions,
+    ) -> (IntTensor<Self>, ConnectedStatsPrimitive<Self>) {
+        let (labels, stats) = B::connected_components_with_stats(img, connectivity, opts);
+        let stats = ConnectedStatsPrimitive {
+            area: stats.area,
+            left: stats.left,
+            top: stats.top,
+            right: stats.right,
+            bottom: stats.bottom,
+            max_label: stats.max_label,
+        };
+        (labels, stats)
+    }
+
+    fn nms(
+        boxes: FloatTensor<Self>,
+        scores: FloatTensor<Self>,
+        options: NmsOptions,
+    ) -> IntTensor<Self> {
+        B::nms(boxes.primitive, scores.primitive, options)
+    }
+
+    fn roi_align(
+        x: FloatTensor<Self>,
+        rois: FloatTensor<Self>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct RoiAlign;
+
+        impl<B: Backend + VisionOps<B>> Backward<B, 1> for RoiAlign {
+            type State = (NodeID, FloatTensor<B>, RoiAlignOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, rois, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_x {
+                    let x_grad = B::roi_align_backward(x, rois, grad, options);
+                    grads.register::<B>(node.id, x_grad);
+                }
+            }
+        }
+
+        // The regions are not differentiable, only the gradient of the features is computed.
+        match RoiAlign
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::roi_align(x.primitive, rois.primitive.clone(), options);
+                prep.finish((x_state, rois.primitive, options), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::roi_align(x.primitive, rois.primitive, options))
+            }
+        }
+    }
+
+    fn roi_pool(
+        x: FloatTensor<Self>,
+        rois: FloatTensor<Self>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct RoiPool;
+
+        impl<B: Backend + VisionOps<B>> Backward<B, 1> for RoiPool {
+            type State = (NodeID, FloatTensor<B>, RoiPoolOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, rois, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_x {
+                    let x_grad = B::roi_pool_backward(x, rois, grad, options);
+                    grads.register::<B>(node.id, x_grad);
+                }
+            }
+        }
+
+        // The regions are not differentiable, only the gradient of the features is computed.
+        match RoiPool
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::roi_pool(x.primitive, rois.primitive.clone(), options);
+                prep.finish((x_state, rois.primitive, options), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::roi_pool(x.primitive, rois.primitive, options))
+            }
+        }
+    }
+
+    // Morphology, the median blur, color conversions and warps are computed by the inner backend,
+    // without tracking gradients.
+    fn erode(x: FloatTensor<Self>, kernel: BoolTensor<Self>) -> FloatTensor<Self> {
+        Self::from_inner(B::erode(x.primitive, kernel))
+    }
+
+    fn dilate(x: FloatTensor<Self>, kernel: BoolTensor<Self>) -> FloatTensor<Self> {
+        Self::from_inner(B::dilate(x.primitive, kernel))
+    }
+
+    fn filter2d(
+        x: FloatTensor<Self>,
+        kernel: FloatTensor<Self>,
+        border: BorderType,
+    ) -> FloatTensor<Self> {
+        filter2d_composed::<Self>(x, kernel, border)
+    }
+
+    fn median_blur(
+        x: FloatTensor<Self>,
+        kernel_size: usize,
+        border: BorderType,
+    ) -> FloatTensor<Self> {
+        Self::from_inner(B::median_blur(x.primitive, kernel_size, border))
+    }
+
+    fn canny(x: FloatTensor<Self>, options: CannyOptions) -> BoolTensor<Self> {
+        B::canny(x.primitive, options)
+    }
+
+    fn color_convert(x: FloatTensor<Self>, conversion: ColorConversion) -> FloatTensor<Self> {
+        Self::from_inner(B::color_convert(x.primitive, conversion))
+    }
+
+    fn warp_perspective(
+        x: FloatTensor<Self>,
+        matrix: FloatTensor<Self>,
+        output_size: [usize; 2],
+        options: WarpOptions,
+    ) -> FloatTensor<Self> {
+        Self::from_inner(B::warp_perspective(
+            x.primitive,
+            matrix.primitive,
+            output_size,
+            options,
+        ))
+    }
+}
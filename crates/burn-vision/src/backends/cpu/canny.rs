@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+};
+
+use super::{
+    filter::correlate,
+    image::{read_float, write_bool},
+};
+use crate::{BorderType, CannyOptions};
+
+/// The 3x3 Sobel operators for the derivatives along the width and along the height.
+pub(crate) const SOBEL_X: [f32; 9] = [-1.0, 0.0, 1.0, -2.0, 0.0, 2.0, -1.0, 0.0, 1.0];
+pub(crate) const SOBEL_Y: [f32; 9] = [-1.0, -2.0, -1.0, 0.0, 0.0, 0.0, 1.0, 2.0, 1.0];
+
+/// Tangents of 22.5 and 67.5 degrees, delimiting the quantized directions of the gradient.
+const TAN_22_5: f32 = 0.414_213_57;
+const TAN_67_5: f32 = 2.414_213_6;
+
+const WEAK: u8 = 1;
+const STRONG: u8 = 2;
+
+pub fn canny<B: Backend>(x: FloatTensor<B>, options: CannyOptions) -> BoolTensor<B> {
+    let (x, shape @ [_, _, height, width], device) = read_float::<B, 4>(x);
+
+    let output = x
+        .chunks_exact(height * width)
+        .flat_map(|plane| {
+            let size = [height, width];
+            let gx = correlate(plane, size, &SOBEL_X, [3, 3], BorderType::Replicate);
+            let gy = correlate(plane, size, &SOBEL_Y, [3, 3], BorderType::Replicate);
+
+            hysteresis(suppress_non_maximum(&gx, &gy, size, &options), size)
+        })
+        .collect();
+
+    write_bool::<B, 4>(output, shape, &device)
+}
+
+/// Keeps the pixels whose gradient magnitude is a maximum along the direction of the gradient,
+/// labeling them as weak or strong edges with the thresholds.
+fn suppress_non_maximum(
+    gx: &[f32],
+    gy: &[f32],
+    [height, width]: [usize; 2],
+    options: &CannyOptions,
+) -> Vec<u8> {
+    let magnitude = gx
+        .iter()
+        .zip(gy)
+        .map(|(gx, gy)| match options.l2_gradient {
+            true => (gx * gx + gy * gy).sqrt(),
+            false => gx.abs() + gy.abs(),
+        })
+        .collect::<Vec<_>>();
+    let magnitude_at =
+        |y: i64, x: i64| match (0..height as i64).contains(&y) && (0..width as i64).contains(&x) {
+            true => magnitude[y as usize * width + x as usize],
+            false => 0.0,
+        };
+
+    (0..height * width)
+        .map(|i| {
+            let current = magnitude[i];
+            if current <= options.low_threshold {
+                return 0;
+            }
+
+            let (y, x) = ((i / width) as i64, (i % width) as i64);
+            let (abs_x, abs_y) = (gx[i].abs(), gy[i].abs());
+            let ((dy, dx), (dy2, dx2)) = if abs_y <= abs_x * TAN_22_5 {
+                ((0, -1), (0, 1))
+            } else if abs_y >= abs_x * TAN_67_5 {
+                ((-1, 0), (1, 0))
+            } else if gx[i] * gy[i] > 0.0 {
+                ((-1, -1), (1, 1))
+            } else {
+                ((-1, 1), (1, -1))
+            };
+
+            // Ties are broken towards the second neighbor, so that plateaus keep a single edge.
+            let is_maximum =
+                current > magnitude_at(y + dy, x + dx) && current >= magnitude_at(y + dy2, x + dx2);
+
+            match (is_maximum, current > options.high_threshold) {
+                (false, _) => 0,
+                (true, false) => WEAK,
+                (true, true) => STRONG,
+            }
+        })
+        .collect()
+}
+
+/// Promotes the weak edges 8-connected to a strong edge - returns the mask of the strong edges.
+fn hysteresis(mut labels: Vec<u8>, [height, width]: [usize; 2]) -> Vec<bool> {
+    let mut stack = (0..labels.len())
+        .filter(|&i| labels[i] == STRONG)
+        .collect::<Vec<_>>();
+
+    while let Some(i) = stack.pop() {
+        let (y, x) = (i / width, i % width);
+
+        for ny in y.saturating_sub(1)..(y + 2).min(height) {
+            for nx in x.saturating_sub(1)..(x + 2).min(width) {
+                let neighbor = ny * width + nx;
+
+                if labels[neighbor] == WEAK {
+                    labels[neighbor] = STRONG;
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    labels.into_iter().map(|label| label == STRONG).collect()
+}
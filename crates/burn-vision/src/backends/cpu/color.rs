@@ -0,0 +1,103 @@
+use alloc::vec;
+use burn_tensor::{backend::Backend, ops::FloatTensor};
+
+use super::image::{read_float, write_float};
+use crate::ColorConversion;
+
+/// The ITU-R BT.601 weights of the red, green and blue channels in the luma.
+const LUMA: [f32; 3] = [0.299, 0.587, 0.114];
+
+pub fn color_convert<B: Backend>(x: FloatTensor<B>, conversion: ColorConversion) -> FloatTensor<B> {
+    let (x, [batch_size, channels, height, width], device) = read_float::<B, 4>(x);
+    let (in_channels, out_channels) = conversion.channels();
+    assert_eq!(
+        channels, in_channels,
+        "{conversion:?} expects {in_channels} input channels, got {channels}"
+    );
+
+    let plane = height * width;
+    let mut output = vec![0.0; batch_size * out_channels * plane];
+
+    for b in 0..batch_size {
+        let input = &x[b * in_channels * plane..];
+        let output = &mut output[b * out_channels * plane..];
+
+        for i in 0..plane {
+            let mut pixel = [0.0; 3];
+            for (c, value) in pixel.iter_mut().take(in_channels).enumerate() {
+                *value = input[c * plane + i];
+            }
+
+            let pixel = convert(pixel, conversion);
+            for (c, value) in pixel.iter().take(out_channels).enumerate() {
+                output[c * plane + i] = *value;
+            }
+        }
+    }
+
+    write_float::<B, 4>(output, [batch_size, out_channels, height, width], &device)
+}
+
+/// Converts the channels of a pixel, the channels unused by a color space being ignored.
+fn convert([a, b, c]: [f32; 3], conversion: ColorConversion) -> [f32; 3] {
+    match conversion {
+        ColorConversion::RgbToGray => [luma(a, b, c), 0.0, 0.0],
+        ColorConversion::GrayToRgb => [a, a, a],
+        ColorConversion::RgbToHsv => rgb_to_hsv(a, b, c),
+        ColorConversion::HsvToRgb => hsv_to_rgb(a, b, c),
+        ColorConversion::RgbToYuv => {
+            let y = luma(a, b, c);
+            [y, 0.492 * (c - y) + 0.5, 0.877 * (a - y) + 0.5]
+        }
+        ColorConversion::YuvToRgb => {
+            let (u, v) = (b - 0.5, c - 0.5);
+            [a + 1.140 * v, a - 0.395 * u - 0.581 * v, a + 2.032 * u]
+        }
+    }
+}
+
+fn luma(r: f32, g: f32, b: f32) -> f32 {
+    LUMA[0] * r + LUMA[1] * g + LUMA[2] * b
+}
+
+fn rgb_to_hsv(r: f32, g: f32, b: f32) -> [f32; 3] {
+    let value = r.max(g).max(b);
+    let delta = value - r.min(g).min(b);
+    let saturation = match value > 0.0 {
+        true => delta / value,
+        false => 0.0,
+    };
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if value == r {
+        60.0 * (g - b) / delta
+    } else if value == g {
+        120.0 + 60.0 * (b - r) / delta
+    } else {
+        240.0 + 60.0 * (r - g) / delta
+    };
+    let hue = match hue < 0.0 {
+        true => hue + 360.0,
+        false => hue,
+    };
+
+    [hue, saturation, value]
+}
+
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> [f32; 3] {
+    let sector = (hue / 60.0).rem_euclid(6.0);
+    let fraction = sector - sector.floor();
+    let p = value * (1.0 - saturation);
+    let q = value * (1.0 - saturation * fraction);
+    let t = value * (1.0 - saturation * (1.0 - fraction));
+
+    match sector as u32 {
+        0 => [value, t, p],
+        1 => [q, value, p],
+        2 => [p, value, t],
+        3 => [p, q, value],
+        4 => [t, p, value],
+        _ => [value, p, q],
+    }
+}
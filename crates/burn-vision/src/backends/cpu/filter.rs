@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, ops::FloatTensor};
+
+use super::image::{pixel, read_float, write_float};
+use crate::BorderType;
+
+pub fn filter2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel: FloatTensor<B>,
+    border: BorderType,
+) -> FloatTensor<B> {
+    let (x, shape @ [_, _, height, width], device) = read_float::<B, 4>(x);
+    let (kernel, [kernel_height, kernel_width], _) = read_float::<B, 2>(kernel);
+
+    let output = x
+        .chunks_exact(height * width)
+        .flat_map(|plane| {
+            correlate(
+                plane,
+                [height, width],
+                &kernel,
+                [kernel_height, kernel_width],
+                border,
+            )
+        })
+        .collect();
+
+    write_float::<B, 4>(output, shape, &device)
+}
+
+pub fn median_blur<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: usize,
+    border: BorderType,
+) -> FloatTensor<B> {
+    let (x, shape @ [_, _, height, width], device) = read_float::<B, 4>(x);
+    let anchor = (kernel_size / 2) as i64;
+    let mut window = Vec::with_capacity(kernel_size * kernel_size);
+    let mut output = Vec::with_capacity(x.len());
+
+    for plane in x.chunks_exact(height * width) {
+        for i in 0..height * width {
+            let (y, x) = ((i / width) as i64, (i % width) as i64);
+
+            window.clear();
+            for dy in 0..kernel_size as i64 {
+                for dx in 0..kernel_size as i64 {
+                    let (y, x) = (y + dy - anchor, x + dx - anchor);
+                    window.push(pixel(plane, y, x, [height, width], border));
+                }
+            }
+
+            let middle = window.len() / 2;
+            let (_, median, _) = window.select_nth_unstable_by(middle, f32::total_cmp);
+            output.push(*median);
+        }
+    }
+
+    write_float::<B, 4>(output, shape, &device)
+}
+
+/// Correlates a plane of the image [height, width] with a kernel anchored at its center.
+pub(crate) fn correlate(
+    plane: &[f32],
+    [height, width]: [usize; 2],
+    kernel: &[f32],
+    [kernel_height, kernel_width]: [usize; 2],
+    border: BorderType,
+) -> Vec<f32> {
+    let (anchor_y, anchor_x) = ((kernel_height / 2) as i64, (kernel_width / 2) as i64);
+
+    (0..height * width)
+        .map(|i| {
+            let (y, x) = ((i / width) as i64, (i % width) as i64);
+
+            kernel
+                .iter()
+                .enumerate()
+                .map(|(k, weight)| {
+                    let y = y + (k / kernel_width) as i64 - anchor_y;
+                    let x = x + (k % kernel_width) as i64 - anchor_x;
+                    weight * pixel(plane, y, x, [height, width], border)
+                })
+                .sum()
+        })
+        .collect()
+}
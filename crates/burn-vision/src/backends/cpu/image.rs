@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+    Bool, Shape, Tensor, TensorData, TensorPrimitive,
+};
+
+use crate::BorderType;
+
+/// Reads a float tensor, returning its data, its shape and its device.
+pub(crate) fn read_float<B: Backend, const D: usize>(
+    tensor: FloatTensor<B>,
+) -> (Vec<f32>, [usize; D], B::Device) {
+    let tensor = Tensor::<B, D>::from_primitive(TensorPrimitive::Float(tensor));
+    let device = tensor.device();
+    let shape = tensor.dims();
+    let data = tensor.into_data().convert::<f32>().to_vec::<f32>().unwrap();
+
+    (data, shape, device)
+}
+
+/// Reads a bool tensor, returning its data and its shape.
+pub(crate) fn read_bool<B: Backend, const D: usize>(
+    tensor: BoolTensor<B>,
+) -> (Vec<bool>, [usize; D]) {
+    let tensor = Tensor::<B, D, Bool>::from_primitive(tensor);
+    let shape = tensor.dims();
+    let data = tensor
+        .into_data()
+        .convert::<u8>()
+        .to_vec::<u8>()
+        .unwrap()
+        .into_iter()
+        .map(|value| value != 0)
+        .collect();
+
+    (data, shape)
+}
+
+pub(crate) fn write_float<B: Backend, const D: usize>(
+    data: Vec<f32>,
+    shape: [usize; D],
+    device: &B::Device,
+) -> FloatTensor<B> {
+    let data = TensorData::new(data, Shape::new(shape)).convert::<B::FloatElem>();
+    Tensor::<B, D>::from_data(data, device)
+        .into_primitive()
+        .tensor()
+}
+
+pub(crate) fn write_bool<B: Backend, const D: usize>(
+    data: Vec<bool>,
+    shape: [usize; D],
+    device: &B::Device,
+) -> BoolTensor<B> {
+    let data = TensorData::new(data, Shape::new(shape));
+    Tensor::<B, D, Bool>::from_data(data, device).into_primitive()
+}
+
+/// Maps a coordinate outside of a dimension of the image to the pixel extrapolating it, like
+/// `cv::borderInterpolate` - returns `None` for a constant border.
+pub(crate) fn border_index(coord: i64, size: usize, border: BorderType) -> Option<usize> {
+    let size = size as i64;
+
+    if (0..size).contains(&coord) {
+        return Some(coord as usize);
+    }
+
+    let coord = match border {
+        BorderType::Constant(_) => return None,
+        BorderType::Replicate => coord.clamp(0, size - 1),
+        _ if size == 1 => 0,
+        BorderType::Reflect | BorderType::Reflect101 => {
+            // Reflections of the image alternate over a period of twice its size.
+            let (period, shift) = match border {
+                BorderType::Reflect => (2 * size, 0),
+                _ => (2 * size - 2, 1),
+            };
+            let coord = coord.rem_euclid(period);
+
+            match coord < size {
+                true => coord,
+                false => period - coord - 1 + shift,
+            }
+        }
+    };
+
+    Some(coord as usize)
+}
+
+/// The value of a plane of the image [height, width] at a location, extrapolated with the border.
+pub(crate) fn pixel(
+    plane: &[f32],
+    y: i64,
+    x: i64,
+    [height, width]: [usize; 2],
+    border: BorderType,
+) -> f32 {
+    match (
+        border_index(y, height, border),
+        border_index(x, width, border),
+    ) {
+        (Some(y), Some(x)) => plane[y * width + x],
+        _ => match border {
+            BorderType::Constant(value) => value,
+            _ => unreachable!(),
+        },
+    }
+}
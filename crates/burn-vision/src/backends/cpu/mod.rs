@@ -1,4 +1,20 @@
+mod canny;
+mod color;
 mod connected_components;
+mod filter;
+mod image;
+mod morphology;
+mod nms;
 mod ops;
+mod roi;
+mod warp;
 
+pub use canny::*;
+pub use color::*;
 pub use connected_components::*;
+pub use filter::*;
+pub(crate) use image::border_index;
+pub use morphology::*;
+pub use nms::*;
+pub use roi::*;
+pub use warp::*;
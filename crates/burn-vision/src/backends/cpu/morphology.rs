@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+};
+
+use super::image::{read_bool, read_float, write_float};
+
+pub fn erode<B: Backend>(x: FloatTensor<B>, kernel: BoolTensor<B>) -> FloatTensor<B> {
+    morphology::<B>(x, kernel, f32::min)
+}
+
+pub fn dilate<B: Backend>(x: FloatTensor<B>, kernel: BoolTensor<B>) -> FloatTensor<B> {
+    morphology::<B>(x, kernel, f32::max)
+}
+
+/// Reduces the neighborhood of each pixel selected by the structuring element with `op`.
+fn morphology<B: Backend>(
+    x: FloatTensor<B>,
+    kernel: BoolTensor<B>,
+    op: fn(f32, f32) -> f32,
+) -> FloatTensor<B> {
+    let (x, shape @ [_, _, height, width], device) = read_float::<B, 4>(x);
+    let (kernel, [kernel_height, kernel_width]) = read_bool::<B, 2>(kernel);
+    let (anchor_y, anchor_x) = ((kernel_height / 2) as i64, (kernel_width / 2) as i64);
+
+    let offsets = (0..kernel_height * kernel_width)
+        .filter(|&i| kernel[i])
+        .map(|i| {
+            let dy = (i / kernel_width) as i64 - anchor_y;
+            let dx = (i % kernel_width) as i64 - anchor_x;
+            (dy, dx)
+        })
+        .collect::<Vec<_>>();
+
+    let output = x
+        .chunks_exact(height * width)
+        .flat_map(|plane| {
+            let offsets = &offsets;
+            (0..height * width).map(move |i| {
+                let (y, x) = ((i / width) as i64, (i % width) as i64);
+
+                offsets
+                    .iter()
+                    .map(|(dy, dx)| (y + dy, x + dx))
+                    .filter(|&(y, x)| {
+                        (0..height as i64).contains(&y) && (0..width as i64).contains(&x)
+                    })
+                    .map(|(y, x)| plane[y as usize * width + x as usize])
+                    .reduce(op)
+                    // Pixels without any neighbor in the image are left unchanged.
+                    .unwrap_or(plane[i])
+            })
+        })
+        .collect();
+
+    write_float::<B, 4>(output, shape, &device)
+}
@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Int, Shape, Tensor, TensorData, TensorPrimitive,
+};
+
+use crate::NmsOptions;
+
+pub fn nms<B: Backend>(
+    boxes: FloatTensor<B>,
+    scores: FloatTensor<B>,
+    options: NmsOptions,
+) -> IntTensor<B> {
+    let boxes = Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(boxes));
+    let device = boxes.device();
+    let boxes = boxes.into_data().convert::<f32>().to_vec::<f32>().unwrap();
+    let scores = Tensor::<B, 1>::from_primitive(TensorPrimitive::Float(scores))
+        .into_data()
+        .convert::<f32>()
+        .to_vec::<f32>()
+        .unwrap();
+
+    let order = sort_candidates(&scores, options.score_threshold);
+    let overlaps = |i: usize, j: usize| {
+        let (i, j) = (order[i] * 4, order[j] * 4);
+        iou(&boxes[i..i + 4], &boxes[j..j + 4]) > options.iou_threshold
+    };
+    let kept = select_boxes(order.len(), overlaps, options.max_output_boxes)
+        .into_iter()
+        .map(|i| order[i])
+        .collect();
+
+    indices_tensor::<B>(kept, &device)
+}
+
+/// Indices of the boxes with a score greater than the threshold, sorted by decreasing score.
+///
+/// Boxes with the same score keep their order, so that the result is deterministic.
+pub(crate) fn sort_candidates(scores: &[f32], score_threshold: f32) -> Vec<usize> {
+    let mut order = (0..scores.len())
+        .filter(|&i| scores[i] > score_threshold)
+        .collect::<Vec<_>>();
+    order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+    order
+}
+
+/// Greedily selects the candidates, sorted by decreasing score, that don't overlap a previously
+/// selected candidate - returns the positions of the selected candidates.
+///
+/// `overlaps` tells whether two candidates given by their positions overlap.
+pub(crate) fn select_boxes(
+    num_candidates: usize,
+    overlaps: impl Fn(usize, usize) -> bool,
+    max_output_boxes: Option<usize>,
+) -> Vec<usize> {
+    let max_output_boxes = max_output_boxes.unwrap_or(num_candidates);
+    let mut kept = Vec::new();
+
+    for candidate in 0..num_candidates {
+        if kept.len() >= max_output_boxes {
+            break;
+        }
+        if !kept.iter().any(|&selected| overlaps(selected, candidate)) {
+            kept.push(candidate);
+        }
+    }
+
+    kept
+}
+
+pub(crate) fn indices_tensor<B: Backend>(indices: Vec<usize>, device: &B::Device) -> IntTensor<B> {
+    let num_indices = indices.len();
+    let indices = indices.into_iter().map(|i| i as i64).collect::<Vec<_>>();
+    let data = TensorData::new(indices, Shape::new([num_indices])).convert::<B::IntElem>();
+
+    Tensor::<B, 1, Int>::from_data(data, device).into_primitive()
+}
+
+/// Intersection over union of two boxes in the `Xyxy` format.
+fn iou(a: &[f32], b: &[f32]) -> f32 {
+    let area = |b: &[f32]| (b[2] - b[0]).max(0.0) * (b[3] - b[1]).max(0.0);
+    let width = (a[2].min(b[2]) - a[0].max(b[0])).max(0.0);
+    let height = (a[3].min(b[3]) - a[1].max(b[1])).max(0.0);
+    let intersection = width * height;
+    let union = area(a) + area(b) - intersection;
+
+    if union > 0.0 {
+        intersection / union
+    } else {
+        0.0
+    }
+}
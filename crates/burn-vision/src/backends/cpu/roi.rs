@@ -0,0 +1,252 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, ops::FloatTensor, TensorMetadata};
+
+use super::image::{read_float, write_float};
+use crate::{RoiAlignOptions, RoiPoolOptions};
+
+pub fn roi_align<B: Backend>(
+    x: FloatTensor<B>,
+    rois: FloatTensor<B>,
+    options: RoiAlignOptions,
+) -> FloatTensor<B> {
+    let (x, [_, channels, height, width], device) = read_float::<B, 4>(x);
+    let (rois, [num_rois, _], _) = read_float::<B, 2>(rois);
+    let [pooled_height, pooled_width] = options.output_size;
+    let mut output = vec![0.0; num_rois * channels * pooled_height * pooled_width];
+
+    for (k, roi) in rois.chunks_exact(5).enumerate() {
+        let batch = roi[0] as usize;
+
+        for c in 0..channels {
+            let input = &x[(batch * channels + c) * height * width..][..height * width];
+            let output = &mut output[(k * channels + c) * pooled_height * pooled_width..];
+
+            for_each_align_sample(roi, [height, width], &options, |bin, taps, count| {
+                output[bin] += taps
+                    .iter()
+                    .map(|&(index, weight)| input[index] * weight)
+                    .sum::<f32>()
+                    / count;
+            });
+        }
+    }
+
+    write_float::<B, 4>(
+        output,
+        [num_rois, channels, pooled_height, pooled_width],
+        &device,
+    )
+}
+
+pub fn roi_align_backward<B: Backend>(
+    x: FloatTensor<B>,
+    rois: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    options: RoiAlignOptions,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let device = B::float_device(&x);
+    let (rois, _, _) = read_float::<B, 2>(rois);
+    let (output_grad, _, _) = read_float::<B, 4>(output_grad);
+    let [pooled_height, pooled_width] = options.output_size;
+    let mut x_grad = vec![0.0; batch_size * channels * height * width];
+
+    for (k, roi) in rois.chunks_exact(5).enumerate() {
+        let batch = roi[0] as usize;
+
+        for c in 0..channels {
+            let x_grad = &mut x_grad[(batch * channels + c) * height * width..][..height * width];
+            let grad = &output_grad[(k * channels + c) * pooled_height * pooled_width..];
+
+            for_each_align_sample(roi, [height, width], &options, |bin, taps, count| {
+                for &(index, weight) in taps.iter() {
+                    x_grad[index] += grad[bin] * weight / count;
+                }
+            });
+        }
+    }
+
+    write_float::<B, 4>(x_grad, [batch_size, channels, height, width], &device)
+}
+
+pub fn roi_pool<B: Backend>(
+    x: FloatTensor<B>,
+    rois: FloatTensor<B>,
+    options: RoiPoolOptions,
+) -> FloatTensor<B> {
+    let (x, [_, channels, height, width], device) = read_float::<B, 4>(x);
+    let (rois, [num_rois, _], _) = read_float::<B, 2>(rois);
+    let [pooled_height, pooled_width] = options.output_size;
+    let mut output = Vec::with_capacity(num_rois * channels * pooled_height * pooled_width);
+
+    for roi in rois.chunks_exact(5) {
+        let batch = roi[0] as usize;
+
+        for c in 0..channels {
+            let input = &x[(batch * channels + c) * height * width..][..height * width];
+            output.extend(
+                pool_argmax(input, roi, [height, width], &options)
+                    .map(|argmax| argmax.map(|index| input[index]).unwrap_or(0.0)),
+            );
+        }
+    }
+
+    write_float::<B, 4>(
+        output,
+        [num_rois, channels, pooled_height, pooled_width],
+        &device,
+    )
+}
+
+pub fn roi_pool_backward<B: Backend>(
+    x: FloatTensor<B>,
+    rois: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    options: RoiPoolOptions,
+) -> FloatTensor<B> {
+    let (x, [batch_size, channels, height, width], device) = read_float::<B, 4>(x);
+    let (rois, _, _) = read_float::<B, 2>(rois);
+    let (output_grad, _, _) = read_float::<B, 4>(output_grad);
+    let [pooled_height, pooled_width] = options.output_size;
+    let mut x_grad = vec![0.0; batch_size * channels * height * width];
+
+    for (k, roi) in rois.chunks_exact(5).enumerate() {
+        let batch = roi[0] as usize;
+
+        for c in 0..channels {
+            let offset = (batch * channels + c) * height * width;
+            let input = &x[offset..][..height * width];
+            let grad = &output_grad[(k * channels + c) * pooled_height * pooled_width..];
+
+            for (bin, argmax) in pool_argmax(input, roi, [height, width], &options).enumerate() {
+                if let Some(index) = argmax {
+                    x_grad[offset + index] += grad[bin];
+                }
+            }
+        }
+    }
+
+    write_float::<B, 4>(x_grad, [batch_size, channels, height, width], &device)
+}
+
+/// Calls `f` with the output bin, the bilinear taps and the number of samples of the bin for
+/// every sampling point of the region.
+fn for_each_align_sample(
+    roi: &[f32],
+    [height, width]: [usize; 2],
+    options: &RoiAlignOptions,
+    mut f: impl FnMut(usize, &[(usize, f32)], f32),
+) {
+    let [pooled_height, pooled_width] = options.output_size;
+    let offset = if options.aligned { 0.5 } else { 0.0 };
+    let start_x = roi[1] * options.spatial_scale - offset;
+    let start_y = roi[2] * options.spatial_scale - offset;
+    let mut roi_width = roi[3] * options.spatial_scale - offset - start_x;
+    let mut roi_height = roi[4] * options.spatial_scale - offset - start_y;
+
+    // Misaligned regions are forced to be at least one pixel wide.
+    if !options.aligned {
+        roi_width = roi_width.max(1.0);
+        roi_height = roi_height.max(1.0);
+    }
+
+    let bin_height = roi_height / pooled_height as f32;
+    let bin_width = roi_width / pooled_width as f32;
+    let grid_height = match options.sampling_ratio {
+        0 => bin_height.ceil() as usize,
+        ratio => ratio,
+    };
+    let grid_width = match options.sampling_ratio {
+        0 => bin_width.ceil() as usize,
+        ratio => ratio,
+    };
+    let count = (grid_height * grid_width).max(1) as f32;
+
+    for ph in 0..pooled_height {
+        for pw in 0..pooled_width {
+            for iy in 0..grid_height {
+                let y = start_y
+                    + ph as f32 * bin_height
+                    + (iy as f32 + 0.5) * bin_height / grid_height as f32;
+
+                for ix in 0..grid_width {
+                    let x = start_x
+                        + pw as f32 * bin_width
+                        + (ix as f32 + 0.5) * bin_width / grid_width as f32;
+
+                    if let Some(taps) = bilinear_taps(y, x, [height, width]) {
+                        f(ph * pooled_width + pw, &taps, count);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The flat indices and the weights of the neighbors of a sampling point, `None` when the point
+/// is more than one pixel outside of the input.
+fn bilinear_taps(y: f32, x: f32, [height, width]: [usize; 2]) -> Option<[(usize, f32); 4]> {
+    if y < -1.0 || y > height as f32 || x < -1.0 || x > width as f32 {
+        return None;
+    }
+
+    let neighbors = |coord: f32, size: usize| {
+        let coord = coord.max(0.0);
+        let low = coord as usize;
+
+        if low >= size - 1 {
+            (size - 1, size - 1, 0.0)
+        } else {
+            (low, low + 1, coord - low as f32)
+        }
+    };
+    let (y_low, y_high, ly) = neighbors(y, height);
+    let (x_low, x_high, lx) = neighbors(x, width);
+    let (hy, hx) = (1.0 - ly, 1.0 - lx);
+
+    Some([
+        (y_low * width + x_low, hy * hx),
+        (y_low * width + x_high, hy * lx),
+        (y_high * width + x_low, ly * hx),
+        (y_high * width + x_high, ly * lx),
+    ])
+}
+
+/// The flat index of the maximum of each output bin of the region, `None` when the bin is empty.
+fn pool_argmax<'a>(
+    input: &'a [f32],
+    roi: &[f32],
+    [height, width]: [usize; 2],
+    options: &RoiPoolOptions,
+) -> impl Iterator<Item = Option<usize>> + 'a {
+    let [pooled_height, pooled_width] = options.output_size;
+    let start_x = (roi[1] * options.spatial_scale).round() as i64;
+    let start_y = (roi[2] * options.spatial_scale).round() as i64;
+    let end_x = (roi[3] * options.spatial_scale).round() as i64;
+    let end_y = (roi[4] * options.spatial_scale).round() as i64;
+
+    // Misaligned regions are forced to be at least one pixel wide.
+    let bin_height = (end_y - start_y + 1).max(1) as f32 / pooled_height as f32;
+    let bin_width = (end_x - start_x + 1).max(1) as f32 / pooled_width as f32;
+    let bounds = |start: i64, bin: f32, index: usize, size: usize| {
+        let low = (index as f32 * bin).floor() as i64 + start;
+        let high = ((index + 1) as f32 * bin).ceil() as i64 + start;
+        (
+            low.clamp(0, size as i64) as usize,
+            high.clamp(0, size as i64) as usize,
+        )
+    };
+
+    (0..pooled_height * pooled_width).map(move |bin| {
+        let (h_start, h_end) = bounds(start_y, bin_height, bin / pooled_width, height);
+        let (w_start, w_end) = bounds(start_x, bin_width, bin % pooled_width, width);
+
+        (h_start..h_end)
+            .flat_map(|h| (w_start..w_end).map(move |w| h * width + w))
+            .fold(None, |argmax: Option<usize>, index| match argmax {
+                Some(max) if input[max] >= input[index] => Some(max),
+                _ => Some(index),
+            })
+    })
+}
@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{FloatTensor, InterpolateMode},
+};
+
+use super::image::{pixel, read_float, write_float};
+use crate::{BorderType, WarpOptions};
+
+/// The coefficient of the bicubic convolution, matching `opencv`.
+const CUBIC_A: f32 = -0.75;
+
+pub fn warp_perspective<B: Backend>(
+    x: FloatTensor<B>,
+    matrix: FloatTensor<B>,
+    [out_height, out_width]: [usize; 2],
+    options: WarpOptions,
+) -> FloatTensor<B> {
+    let (x, [batch_size, channels, height, width], device) = read_float::<B, 4>(x);
+    let (matrix, _, _) = read_float::<B, 3>(matrix);
+    let mut output = Vec::with_capacity(batch_size * channels * out_height * out_width);
+
+    for (b, matrix) in matrix.chunks_exact(9).enumerate() {
+        let inverse = invert(matrix);
+        let locations = (0..out_height * out_width)
+            .map(|i| {
+                let (v, u) = ((i / out_width) as f32, (i % out_width) as f32);
+                let x = inverse[0] * u + inverse[1] * v + inverse[2];
+                let y = inverse[3] * u + inverse[4] * v + inverse[5];
+                let w = inverse[6] * u + inverse[7] * v + inverse[8];
+                // Points at infinity are mapped to the origin, like `opencv`.
+                let w = if w != 0.0 { 1.0 / w } else { 0.0 };
+
+                (y * w, x * w)
+            })
+            .collect::<Vec<_>>();
+
+        for c in 0..channels {
+            let plane = &x[(b * channels + c) * height * width..][..height * width];
+            output.extend(locations.iter().map(|&(y, x)| {
+                sample(plane, y, x, [height, width], &options.mode, options.border)
+            }));
+        }
+    }
+
+    write_float::<B, 4>(
+        output,
+        [batch_size, channels, out_height, out_width],
+        &device,
+    )
+}
+
+/// Inverts a 3x3 matrix with its adjugate, a singular matrix being mapped to zeros.
+fn invert(m: &[f32]) -> [f32; 9] {
+    let adjugate = [
+        m[4] * m[8] - m[5] * m[7],
+        m[2] * m[7] - m[1] * m[8],
+        m[1] * m[5] - m[2] * m[4],
+        m[5] * m[6] - m[3] * m[8],
+        m[0] * m[8] - m[2] * m[6],
+        m[2] * m[3] - m[0] * m[5],
+        m[3] * m[7] - m[4] * m[6],
+        m[1] * m[6] - m[0] * m[7],
+        m[0] * m[4] - m[1] * m[3],
+    ];
+    let det = m[0] * adjugate[0] + m[1] * adjugate[3] + m[2] * adjugate[6];
+    let scale = if det != 0.0 { 1.0 / det } else { 0.0 };
+
+    adjugate.map(|value| value * scale)
+}
+
+/// Interpolates a plane of the image [height, width] at a location.
+fn sample(
+    plane: &[f32],
+    y: f32,
+    x: f32,
+    size: [usize; 2],
+    mode: &InterpolateMode,
+    border: BorderType,
+) -> f32 {
+    let (y0, x0) = (y.floor(), x.floor());
+    let (ty, tx) = (y - y0, x - x0);
+    let (y0, x0) = (y0 as i64, x0 as i64);
+
+    match mode {
+        InterpolateMode::Nearest => pixel(plane, y.round() as i64, x.round() as i64, size, border),
+        InterpolateMode::Bilinear => {
+            let row = |y: i64| {
+                pixel(plane, y, x0, size, border) * (1.0 - tx)
+                    + pixel(plane, y, x0 + 1, size, border) * tx
+            };
+            row(y0) * (1.0 - ty) + row(y0 + 1) * ty
+        }
+        InterpolateMode::Bicubic => {
+            let (wy, wx) = (cubic_weights(ty), cubic_weights(tx));
+            (0..4)
+                .map(|i| {
+                    let y = y0 + i as i64 - 1;
+                    let row = (0..4)
+                        .map(|j| wx[j] * pixel(plane, y, x0 + j as i64 - 1, size, border))
+                        .sum::<f32>();
+                    wy[i] * row
+                })
+                .sum()
+        }
+    }
+}
+
+/// The weights of the four taps around a location at a fraction `t` of its pixel.
+fn cubic_weights(t: f32) -> [f32; 4] {
+    let a = CUBIC_A;
+    let w0 = ((a * (t + 1.0) - 5.0 * a) * (t + 1.0) + 8.0 * a) * (t + 1.0) - 4.0 * a;
+    let w1 = ((a + 2.0) * t - (a + 3.0)) * t * t + 1.0;
+    let w2 = ((a + 2.0) * (1.0 - t) - (a + 3.0)) * (1.0 - t) * (1.0 - t) + 1.0;
+
+    [w0, w1, w2, 1.0 - w0 - w1 - w2]
+}
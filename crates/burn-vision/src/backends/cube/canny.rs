@@ -0,0 +1,204 @@
+use burn_cubecl::{
+    ops::{
+        into_data_sync,
+        numeric::{empty_device, zeros_device},
+    },
+    tensor::CubeTensor,
+    BoolElement, CubeBackend, CubeRuntime, FloatElement, IntElement,
+};
+use burn_tensor::{
+    ops::{FloatTensorOps, IntTensorOps},
+    ElementConversion, Shape, TensorData,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::filter::filter2d;
+use crate::{backends::cpu, BorderType, CannyOptions};
+
+/// Each unit labels one pixel as a strong edge (2), a weak edge (1) or not an edge (0), keeping
+/// only the maxima of the gradient magnitude along the direction of the gradient.
+#[cube(launch)]
+fn non_maximum_kernel<F: Float, I: Int>(
+    gx: &Tensor<F>,
+    gy: &Tensor<F>,
+    labels: &mut Tensor<I>,
+    low_threshold: f32,
+    high_threshold: f32,
+    #[comptime] l2_gradient: bool,
+) {
+    if ABSOLUTE_POS >= labels.len() {
+        terminate!();
+    }
+
+    let y = ABSOLUTE_POS / labels.stride(2) % labels.shape(2);
+    let x = ABSOLUTE_POS / labels.stride(3) % labels.shape(3);
+    let plane = ABSOLUTE_POS - y * labels.stride(2) - x * labels.stride(3);
+
+    let grad_x = f32::cast_from(gx[ABSOLUTE_POS]);
+    let grad_y = f32::cast_from(gy[ABSOLUTE_POS]);
+    let current = magnitude(grad_x, grad_y, l2_gradient);
+    let mut label = 0u32;
+
+    if current > low_threshold {
+        let abs_x = f32::abs(grad_x);
+        let abs_y = f32::abs(grad_y);
+        let mut dy = 0i32;
+        let mut dx = -1i32;
+        let mut dy2 = 0i32;
+        let mut dx2 = 1i32;
+
+        // Quantizes the direction with the tangents of 22.5 and 67.5 degrees.
+        if abs_y > abs_x * 0.414_213_57 {
+            dy = -1;
+            dy2 = 1;
+            if abs_y >= abs_x * 2.414_213_6 {
+                dx = 0;
+                dx2 = 0;
+            } else if grad_x * grad_y > 0.0 {
+                dx = -1;
+                dx2 = 1;
+            } else {
+                dx = 1;
+                dx2 = -1;
+            }
+        }
+
+        let y = i32::cast_from(y);
+        let x = i32::cast_from(x);
+        let neighbor = neighbor_magnitude(gx, gy, plane, y + dy, x + dx, l2_gradient);
+        let neighbor2 = neighbor_magnitude(gx, gy, plane, y + dy2, x + dx2, l2_gradient);
+
+        // Ties are broken towards the second neighbor, so that plateaus keep a single edge.
+        if current > neighbor && current >= neighbor2 {
+            label = 1;
+            if current > high_threshold {
+                label = 2;
+            }
+        }
+    }
+
+    labels[ABSOLUTE_POS] = I::cast_from(label);
+}
+
+#[cube]
+fn magnitude(grad_x: f32, grad_y: f32, #[comptime] l2_gradient: bool) -> f32 {
+    let mut value = f32::abs(grad_x) + f32::abs(grad_y);
+    if comptime![l2_gradient] {
+        value = f32::sqrt(grad_x * grad_x + grad_y * grad_y);
+    }
+    value
+}
+
+/// The gradient magnitude of a neighbor of the plane starting at `plane`, zero outside of the
+/// image.
+#[cube]
+fn neighbor_magnitude<F: Float>(
+    gx: &Tensor<F>,
+    gy: &Tensor<F>,
+    plane: u32,
+    y: i32,
+    x: i32,
+    #[comptime] l2_gradient: bool,
+) -> f32 {
+    let mut value = 0.0f32;
+
+    if y >= 0 && x >= 0 && y < i32::cast_from(gx.shape(2)) && x < i32::cast_from(gx.shape(3)) {
+        let index = plane + u32::cast_from(y) * gx.stride(2) + u32::cast_from(x) * gx.stride(3);
+        value = magnitude(
+            f32::cast_from(gx[index]),
+            f32::cast_from(gy[index]),
+            l2_gradient,
+        );
+    }
+
+    value
+}
+
+/// Each unit promotes one weak edge 8-connected to a strong edge, flagging the change.
+#[cube(launch)]
+fn hysteresis_kernel<I: Int>(labels: &mut Tensor<I>, changed: &mut Tensor<I>) {
+    if ABSOLUTE_POS >= labels.len() {
+        terminate!();
+    }
+
+    if u32::cast_from(labels[ABSOLUTE_POS]) == 1 {
+        let height = labels.shape(2);
+        let width = labels.shape(3);
+        let y = ABSOLUTE_POS / labels.stride(2) % height;
+        let x = ABSOLUTE_POS / labels.stride(3) % width;
+        let plane = ABSOLUTE_POS - y * labels.stride(2) - x * labels.stride(3);
+        let mut strong = false;
+
+        // Neighbors are shifted by one to stay unsigned.
+        for sy in y..y + 3 {
+            for sx in x..x + 3 {
+                if sy >= 1 && sy <= height && sx >= 1 && sx <= width {
+                    let index = plane + (sy - 1) * labels.stride(2) + (sx - 1) * labels.stride(3);
+                    if u32::cast_from(labels[index]) == 2 {
+                        strong = true;
+                    }
+                }
+            }
+        }
+
+        if strong {
+            labels[ABSOLUTE_POS] = I::cast_from(2u32);
+            changed[0] = I::cast_from(1u32);
+        }
+    }
+}
+
+pub(crate) fn canny<R: CubeRuntime, F: FloatElement, I: IntElement, BT: BoolElement>(
+    x: CubeTensor<R>,
+    options: CannyOptions,
+) -> CubeTensor<R> {
+    let sobel = |data: [f32; 9]| {
+        let data = TensorData::new(data.to_vec(), Shape::new([3, 3])).convert::<F>();
+        CubeBackend::<R, F, I, BT>::float_from_data(data, &x.device)
+    };
+    let gx = filter2d::<R, F>(x.clone(), sobel(cpu::SOBEL_X), BorderType::Replicate);
+    let gy = filter2d::<R, F>(x.clone(), sobel(cpu::SOBEL_Y), BorderType::Replicate);
+
+    let labels = empty_device::<R, I>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let num_elems = labels.shape.num_elements();
+    if num_elems == 0 {
+        return CubeBackend::<R, F, I, BT>::int_equal_elem(labels, 2.elem());
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    non_maximum_kernel::launch::<F, I, R>(
+        &x.client,
+        cube_count.clone(),
+        cube_dim,
+        gx.as_tensor_arg::<F>(1),
+        gy.as_tensor_arg::<F>(1),
+        labels.as_tensor_arg::<I>(1),
+        ScalarArg::new(options.low_threshold),
+        ScalarArg::new(options.high_threshold),
+        options.l2_gradient,
+    );
+
+    // Strong edges grow by one pixel per pass, until no weak edge is connected to them.
+    loop {
+        let changed = zeros_device::<R, I>(x.client.clone(), x.device.clone(), Shape::new([1]));
+
+        hysteresis_kernel::launch::<I, R>(
+            &x.client,
+            cube_count.clone(),
+            cube_dim,
+            labels.as_tensor_arg::<I>(1),
+            changed.as_tensor_arg::<I>(1),
+        );
+
+        let changed = into_data_sync::<R, I>(changed)
+            .convert::<i64>()
+            .to_vec::<i64>();
+        if changed.unwrap()[0] == 0 {
+            break;
+        }
+    }
+
+    CubeBackend::<R, F, I, BT>::int_equal_elem(labels, 2.elem())
+}
@@ -0,0 +1,175 @@
+use burn_cubecl::{ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime, FloatElement};
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::ColorConversion;
+
+const RGB_TO_GRAY: u32 = 0;
+const GRAY_TO_RGB: u32 = 1;
+const RGB_TO_HSV: u32 = 2;
+const HSV_TO_RGB: u32 = 3;
+const RGB_TO_YUV: u32 = 4;
+const YUV_TO_RGB: u32 = 5;
+
+/// Each unit converts all the channels of one pixel.
+#[cube(launch)]
+fn color_convert_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] conversion: u32,
+) {
+    let plane = output.shape(2) * output.shape(3);
+    if ABSOLUTE_POS >= output.shape(0) * plane {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / plane;
+    let y = ABSOLUTE_POS % plane / output.shape(3);
+    let x = ABSOLUTE_POS % output.shape(3);
+    let input_offset = batch * input.stride(0) + y * input.stride(2) + x * input.stride(3);
+    let output_offset = batch * output.stride(0) + y * output.stride(2) + x * output.stride(3);
+
+    let a = f32::cast_from(input[input_offset]);
+    let mut b = a;
+    let mut c = a;
+    if comptime![conversion != GRAY_TO_RGB] {
+        b = f32::cast_from(input[input_offset + input.stride(1)]);
+        c = f32::cast_from(input[input_offset + 2 * input.stride(1)]);
+    }
+
+    let mut first = a;
+    let mut second = b;
+    let mut third = c;
+
+    if comptime![conversion == RGB_TO_GRAY] {
+        first = luma(a, b, c);
+    }
+    if comptime![conversion == RGB_TO_HSV] {
+        let value = f32::max(f32::max(a, b), c);
+        let delta = value - f32::min(f32::min(a, b), c);
+        let mut hue = 0.0f32;
+        let mut saturation = 0.0f32;
+
+        if value > 0.0 {
+            saturation = delta / value;
+        }
+        if delta != 0.0 {
+            if value == a {
+                hue = 60.0 * (b - c) / delta;
+            } else if value == b {
+                hue = 120.0 + 60.0 * (c - a) / delta;
+            } else {
+                hue = 240.0 + 60.0 * (a - b) / delta;
+            }
+        }
+        if hue < 0.0 {
+            hue += 360.0;
+        }
+
+        first = hue;
+        second = saturation;
+        third = value;
+    }
+    if comptime![conversion == HSV_TO_RGB] {
+        let saturation = b;
+        let value = c;
+        let sector = a / 60.0;
+        let sector = sector - 6.0 * f32::floor(sector / 6.0);
+        let fraction = sector - f32::floor(sector);
+        let p = value * (1.0 - saturation);
+        let q = value * (1.0 - saturation * fraction);
+        let t = value * (1.0 - saturation * (1.0 - fraction));
+        let sector = u32::cast_from(f32::floor(sector));
+
+        first = value;
+        second = p;
+        third = q;
+        if sector == 0 {
+            second = t;
+            third = p;
+        } else if sector == 1 {
+            first = q;
+            second = value;
+            third = p;
+        } else if sector == 2 {
+            first = p;
+            second = value;
+            third = t;
+        } else if sector == 3 {
+            first = p;
+            second = q;
+            third = value;
+        } else if sector == 4 {
+            first = t;
+            second = p;
+            third = value;
+        }
+    }
+    if comptime![conversion == RGB_TO_YUV] {
+        let luma = luma(a, b, c);
+        first = luma;
+        second = 0.492 * (c - luma) + 0.5;
+        third = 0.877 * (a - luma) + 0.5;
+    }
+    if comptime![conversion == YUV_TO_RGB] {
+        let u = b - 0.5;
+        let v = c - 0.5;
+        first = a + 1.140 * v;
+        second = a - 0.395 * u - 0.581 * v;
+        third = a + 2.032 * u;
+    }
+
+    output[output_offset] = F::cast_from(first);
+    if comptime![conversion != RGB_TO_GRAY] {
+        output[output_offset + output.stride(1)] = F::cast_from(second);
+        output[output_offset + 2 * output.stride(1)] = F::cast_from(third);
+    }
+}
+
+/// The luma of a color, with the ITU-R BT.601 weights.
+#[cube]
+fn luma(r: f32, g: f32, b: f32) -> f32 {
+    0.299 * r + 0.587 * g + 0.114 * b
+}
+
+pub(crate) fn color_convert<R: CubeRuntime, F: FloatElement>(
+    x: CubeTensor<R>,
+    conversion: ColorConversion,
+) -> CubeTensor<R> {
+    let [batch_size, channels, height, width] = x.shape.dims();
+    let (in_channels, out_channels) = conversion.channels();
+    assert_eq!(
+        channels, in_channels,
+        "{conversion:?} expects {in_channels} input channels, got {channels}"
+    );
+
+    let shape_out = Shape::new([batch_size, out_channels, height, width]);
+    let output = empty_device::<R, F>(x.client.clone(), x.device.clone(), shape_out);
+
+    let num_pixels = batch_size * height * width;
+    if num_pixels == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_pixels, cube_dim);
+    let conversion = match conversion {
+        ColorConversion::RgbToGray => RGB_TO_GRAY,
+        ColorConversion::GrayToRgb => GRAY_TO_RGB,
+        ColorConversion::RgbToHsv => RGB_TO_HSV,
+        ColorConversion::HsvToRgb => HSV_TO_RGB,
+        ColorConversion::RgbToYuv => RGB_TO_YUV,
+        ColorConversion::YuvToRgb => YUV_TO_RGB,
+    };
+
+    color_convert_kernel::launch::<F, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<F>(1),
+        output.as_tensor_arg::<F>(1),
+        conversion,
+    );
+
+    output
+}
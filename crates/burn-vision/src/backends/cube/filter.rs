@@ -0,0 +1,155 @@
+use burn_cubecl::{ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime, FloatElement};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::image::{border_args, read_pixel};
+use crate::BorderType;
+
+/// Each unit computes one output pixel as the correlation of its neighborhood with the kernel.
+#[cube(launch)]
+fn filter2d_kernel<F: Float>(
+    input: &Tensor<F>,
+    kernel: &Tensor<F>,
+    output: &mut Tensor<F>,
+    border_value: f32,
+    #[comptime] border: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let offset = batch * input.stride(0) + channel * input.stride(1);
+    let kernel_height = kernel.shape(0);
+    let kernel_width = kernel.shape(1);
+    let anchor_y = i32::cast_from(kernel_height / 2);
+    let anchor_x = i32::cast_from(kernel_width / 2);
+    let mut value = 0.0f32;
+
+    for ky in 0..kernel_height {
+        for kx in 0..kernel_width {
+            let weight = f32::cast_from(kernel[ky * kernel.stride(0) + kx * kernel.stride(1)]);
+            let sy = i32::cast_from(y + ky) - anchor_y;
+            let sx = i32::cast_from(x + kx) - anchor_x;
+
+            value += weight * read_pixel(input, offset, sy, sx, border_value, border);
+        }
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(value);
+}
+
+/// Each unit computes one output pixel as the median of its neighborhood, found by ranking every
+/// value of the neighborhood.
+#[cube(launch)]
+fn median_blur_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    kernel_size: u32,
+    border_value: f32,
+    #[comptime] border: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let offset = batch * input.stride(0) + channel * input.stride(1);
+    let anchor = i32::cast_from(kernel_size / 2);
+    let window = kernel_size * kernel_size;
+    let middle = window / 2;
+    let mut median = 0.0f32;
+
+    for i in 0..window {
+        let sy = i32::cast_from(y + i / kernel_size) - anchor;
+        let sx = i32::cast_from(x + i % kernel_size) - anchor;
+        let current = read_pixel(input, offset, sy, sx, border_value, border);
+        let mut less = 0u32;
+        let mut equal = 0u32;
+
+        for j in 0..window {
+            let sy = i32::cast_from(y + j / kernel_size) - anchor;
+            let sx = i32::cast_from(x + j % kernel_size) - anchor;
+            let other = read_pixel(input, offset, sy, sx, border_value, border);
+
+            if other < current {
+                less += 1;
+            } else if other == current {
+                equal += 1;
+            }
+        }
+
+        if less <= middle && middle < less + equal {
+            median = current;
+        }
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(median);
+}
+
+pub(crate) fn filter2d<R: CubeRuntime, F: FloatElement>(
+    x: CubeTensor<R>,
+    kernel: CubeTensor<R>,
+    border: BorderType,
+) -> CubeTensor<R> {
+    let output = empty_device::<R, F>(x.client.clone(), x.device.clone(), x.shape.clone());
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+    let (border, border_value) = border_args(border);
+
+    filter2d_kernel::launch::<F, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<F>(1),
+        kernel.as_tensor_arg::<F>(1),
+        output.as_tensor_arg::<F>(1),
+        ScalarArg::new(border_value),
+        border,
+    );
+
+    output
+}
+
+pub(crate) fn median_blur<R: CubeRuntime, F: FloatElement>(
+    x: CubeTensor<R>,
+    kernel_size: usize,
+    border: BorderType,
+) -> CubeTensor<R> {
+    let output = empty_device::<R, F>(x.client.clone(), x.device.clone(), x.shape.clone());
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+    let (border, border_value) = border_args(border);
+
+    median_blur_kernel::launch::<F, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<F>(1),
+        output.as_tensor_arg::<F>(1),
+        ScalarArg::new(kernel_size as u32),
+        ScalarArg::new(border_value),
+        border,
+    );
+
+    output
+}
@@ -0,0 +1,88 @@
+use cubecl::prelude::*;
+
+use crate::BorderType;
+
+pub(crate) const BORDER_CONSTANT: u32 = 0;
+pub(crate) const BORDER_REPLICATE: u32 = 1;
+pub(crate) const BORDER_REFLECT: u32 = 2;
+pub(crate) const BORDER_REFLECT_101: u32 = 3;
+
+/// Splits the border into its comptime kind and its constant value.
+pub(crate) fn border_args(border: BorderType) -> (u32, f32) {
+    match border {
+        BorderType::Constant(value) => (BORDER_CONSTANT, value),
+        BorderType::Replicate => (BORDER_REPLICATE, 0.0),
+        BorderType::Reflect => (BORDER_REFLECT, 0.0),
+        BorderType::Reflect101 => (BORDER_REFLECT_101, 0.0),
+    }
+}
+
+/// Maps a coordinate outside of a dimension of the image to the pixel extrapolating it, `-1`
+/// for a constant border.
+#[cube]
+pub(crate) fn border_index(coord: i32, size: u32, #[comptime] border: u32) -> i32 {
+    let size = i32::cast_from(size);
+    let mut index = coord;
+
+    if coord < 0 || coord >= size {
+        if comptime![border == BORDER_CONSTANT] {
+            index = -1;
+        }
+        if comptime![border == BORDER_REPLICATE] {
+            index = i32::max(i32::min(coord, size - 1), 0);
+        }
+        if comptime![border == BORDER_REFLECT || border == BORDER_REFLECT_101] {
+            index = reflect(coord, size, comptime![border == BORDER_REFLECT_101]);
+        }
+    }
+
+    index
+}
+
+/// Mirrors a coordinate outside of a dimension of the image, including the edge pixels or not.
+#[cube]
+fn reflect(coord: i32, size: i32, #[comptime] exclude_edge: bool) -> i32 {
+    let mut index = 0;
+
+    if size > 1 {
+        // Reflections of the image alternate over a period of twice its size.
+        let mut period = 2 * size;
+        let mut shift = 0;
+        if comptime![exclude_edge] {
+            period = 2 * size - 2;
+            shift = 1;
+        }
+
+        let coord = (coord % period + period) % period;
+        index = coord;
+        if coord >= size {
+            index = period - coord - 1 + shift;
+        }
+    }
+
+    index
+}
+
+/// The value of the pixel of the image [batch_size, channels, height, width] at a location of the
+/// plane starting at `offset`, extrapolated with the border.
+#[cube]
+pub(crate) fn read_pixel<F: Float>(
+    input: &Tensor<F>,
+    offset: u32,
+    y: i32,
+    x: i32,
+    border_value: f32,
+    #[comptime] border: u32,
+) -> f32 {
+    let y = border_index(y, input.shape(2), border);
+    let x = border_index(x, input.shape(3), border);
+    let mut value = border_value;
+
+    if y >= 0 && x >= 0 {
+        let index =
+            offset + u32::cast_from(y) * input.stride(2) + u32::cast_from(x) * input.stride(3);
+        value = f32::cast_from(input[index]);
+    }
+
+    value
+}
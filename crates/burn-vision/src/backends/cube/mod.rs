@@ -1,2 +1,10 @@
+mod canny;
+mod color;
 mod connected_components;
+mod filter;
+mod image;
+mod morphology;
+mod nms;
 mod ops;
+mod roi;
+mod warp;
@@ -0,0 +1,91 @@
+use burn_cubecl::{
+    ops::numeric::empty_device, tensor::CubeTensor, BoolElement, CubeRuntime, FloatElement,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Each unit computes one output pixel as the minimum, or the maximum when dilating, of its
+/// neighborhood selected by the structuring element. Pixels outside of the image are ignored.
+#[cube(launch)]
+fn morphology_kernel<F: Float, BT: CubePrimitive>(
+    input: &Tensor<F>,
+    kernel: &Tensor<BT>,
+    output: &mut Tensor<F>,
+    #[comptime] dilate: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let height = i32::cast_from(input.shape(2));
+    let width = i32::cast_from(input.shape(3));
+    let offset = batch * input.stride(0) + channel * input.stride(1);
+    let kernel_height = kernel.shape(0);
+    let kernel_width = kernel.shape(1);
+    let anchor_y = i32::cast_from(kernel_height / 2);
+    let anchor_x = i32::cast_from(kernel_width / 2);
+
+    // Pixels without any neighbor in the image are left unchanged.
+    let mut value = f32::cast_from(input[offset + y * input.stride(2) + x * input.stride(3)]);
+    let mut found = false;
+
+    for ky in 0..kernel_height {
+        for kx in 0..kernel_width {
+            let selected = bool::cast_from(kernel[ky * kernel.stride(0) + kx * kernel.stride(1)]);
+            let sy = i32::cast_from(y + ky) - anchor_y;
+            let sx = i32::cast_from(x + kx) - anchor_x;
+
+            if selected && sy >= 0 && sy < height && sx >= 0 && sx < width {
+                let index = offset
+                    + u32::cast_from(sy) * input.stride(2)
+                    + u32::cast_from(sx) * input.stride(3);
+                let current = f32::cast_from(input[index]);
+
+                if found {
+                    if comptime![dilate] {
+                        value = f32::max(value, current);
+                    } else {
+                        value = f32::min(value, current);
+                    }
+                } else {
+                    value = current;
+                    found = true;
+                }
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(value);
+}
+
+pub(crate) fn morphology<R: CubeRuntime, F: FloatElement, BT: BoolElement>(
+    x: CubeTensor<R>,
+    kernel: CubeTensor<R>,
+    dilate: bool,
+) -> CubeTensor<R> {
+    let output = empty_device::<R, F>(x.client.clone(), x.device.clone(), x.shape.clone());
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    morphology_kernel::launch::<F, BT, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<F>(1),
+        kernel.as_tensor_arg::<BT>(1),
+        output.as_tensor_arg::<F>(1),
+        dilate,
+    );
+
+    output
+}
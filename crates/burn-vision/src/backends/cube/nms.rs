@@ -0,0 +1,138 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_cubecl::{
+    ops::{into_data_sync, numeric::empty_device},
+    tensor::CubeTensor,
+    BoolElement, CubeBackend, CubeRuntime, FloatElement, IntElement,
+};
+use burn_tensor::{Int, Shape, Tensor, TensorPrimitive};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{backends::cpu, NmsOptions};
+
+type Cube<R, F, I, BT> = CubeBackend<R, F, I, BT>;
+
+/// The number of candidates whose suppression is packed in each word of the mask.
+const BLOCK_SIZE: usize = 32;
+
+/// Each unit computes the suppression mask of one candidate against a block of candidates: the
+/// bit `b` is set when the candidate `block * 32 + b`, with a lower score, overlaps the
+/// candidate.
+#[cube(launch)]
+fn nms_mask_kernel<F: Float>(boxes: &Tensor<F>, mask: &mut Tensor<u32>, iou_threshold: f32) {
+    if ABSOLUTE_POS >= mask.len() {
+        terminate!();
+    }
+
+    let num_candidates = boxes.shape(0);
+    let num_blocks = mask.shape(1);
+    let candidate = ABSOLUTE_POS / num_blocks;
+    let block = ABSOLUTE_POS % num_blocks;
+
+    let x1 = coordinate(boxes, candidate, 0);
+    let y1 = coordinate(boxes, candidate, 1);
+    let x2 = coordinate(boxes, candidate, 2);
+    let y2 = coordinate(boxes, candidate, 3);
+    let area = f32::max(x2 - x1, 0.0) * f32::max(y2 - y1, 0.0);
+    let mut bits = 0u32;
+
+    for b in 0..32u32 {
+        let other = block * 32 + b;
+        if other > candidate && other < num_candidates {
+            let ox1 = coordinate(boxes, other, 0);
+            let oy1 = coordinate(boxes, other, 1);
+            let ox2 = coordinate(boxes, other, 2);
+            let oy2 = coordinate(boxes, other, 3);
+            let other_area = f32::max(ox2 - ox1, 0.0) * f32::max(oy2 - oy1, 0.0);
+            let width = f32::max(f32::min(x2, ox2) - f32::max(x1, ox1), 0.0);
+            let height = f32::max(f32::min(y2, oy2) - f32::max(y1, oy1), 0.0);
+            let intersection = width * height;
+            let union = area + other_area - intersection;
+
+            if union > 0.0 && intersection / union > iou_threshold {
+                bits |= 1u32 << b;
+            }
+        }
+    }
+
+    mask[ABSOLUTE_POS] = bits;
+}
+
+#[cube]
+fn coordinate<F: Float>(boxes: &Tensor<F>, index: u32, component: u32) -> f32 {
+    f32::cast_from(boxes[index * boxes.stride(0) + component * boxes.stride(1)])
+}
+
+/// Non-maximum suppression computing on the device a bit mask of the candidates suppressed by
+/// each candidate, so that only the greedy selection runs on the host.
+///
+/// The mask of `n` candidates takes `n * n / 8` bytes.
+pub(crate) fn nms<R, F, I, BT>(
+    boxes: CubeTensor<R>,
+    scores: CubeTensor<R>,
+    options: NmsOptions,
+) -> CubeTensor<R>
+where
+    R: CubeRuntime,
+    F: FloatElement,
+    I: IntElement,
+    BT: BoolElement,
+{
+    let boxes = Tensor::<Cube<R, F, I, BT>, 2>::from_primitive(TensorPrimitive::Float(boxes));
+    let device = boxes.device();
+    let scores = Tensor::<Cube<R, F, I, BT>, 1>::from_primitive(TensorPrimitive::Float(scores))
+        .into_data()
+        .convert::<f32>()
+        .to_vec::<f32>()
+        .unwrap();
+
+    let order = cpu::sort_candidates(&scores, options.score_threshold);
+    let num_candidates = order.len();
+    if num_candidates == 0 {
+        return cpu::indices_tensor::<Cube<R, F, I, BT>>(order, &device);
+    }
+
+    let indices = cpu::indices_tensor::<Cube<R, F, I, BT>>(order.clone(), &device);
+    let indices = Tensor::<Cube<R, F, I, BT>, 1, Int>::from_primitive(indices);
+    let candidates = boxes.select(0, indices).into_primitive().tensor();
+
+    let num_blocks = num_candidates.div_ceil(BLOCK_SIZE);
+    let mask = empty_device::<R, u32>(
+        candidates.client.clone(),
+        candidates.device.clone(),
+        Shape::new([num_candidates, num_blocks]),
+    );
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_candidates * num_blocks, cube_dim);
+
+    nms_mask_kernel::launch::<F, R>(
+        &candidates.client,
+        cube_count,
+        cube_dim,
+        candidates.as_tensor_arg::<F>(1),
+        mask.as_tensor_arg::<u32>(1),
+        ScalarArg::new(options.iou_threshold),
+    );
+
+    let mask = into_data_sync::<R, u32>(mask).to_vec::<u32>().unwrap();
+    let max_output_boxes = options.max_output_boxes.unwrap_or(num_candidates);
+    let mut suppressed = vec![0u32; num_blocks];
+    let mut kept = Vec::new();
+
+    for candidate in 0..num_candidates {
+        if kept.len() >= max_output_boxes {
+            break;
+        }
+        if suppressed[candidate / BLOCK_SIZE] & (1 << (candidate % BLOCK_SIZE)) != 0 {
+            continue;
+        }
+
+        kept.push(order[candidate]);
+        let row = &mask[candidate * num_blocks..(candidate + 1) * num_blocks];
+        for (suppressed, bits) in suppressed.iter_mut().zip(row) {
+            *suppressed |= bits;
+        }
+    }
+
+    cpu::indices_tensor::<Cube<R, F, I, BT>>(kept, &device)
+}
@@ -1,5 +1,7 @@
 use crate::{
-    backends::cpu, ConnectedStatsOptions, ConnectedStatsPrimitive, Connectivity, VisionOps,
+    backends::cpu, BorderType, CannyOptions, ColorConversion, ConnectedStatsOptions,
+    ConnectedStatsPrimitive, Connectivity, NmsOptions, RoiAlignOptions, RoiPoolOptions, VisionOps,
+    WarpOptions,
 };
 use burn_cubecl::{BoolElement, CubeBackend, CubeRuntime, FloatElement, IntElement};
 #[cfg(feature = "fusion")]
@@ -7,11 +9,13 @@ use burn_fusion::{client::FusionClient, stream::Operation, Fusion, FusionBackend
 #[cfg(feature = "fusion")]
 use burn_ir::{CustomOpIr, HandleContainer, OperationIr};
 use burn_tensor::{
-    ops::{BoolTensor, IntTensor},
-    Element,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+    DType, Element, Int, Tensor,
 };
 
-use super::connected_components::hardware_accelerated;
+use super::{
+    canny, color, connected_components::hardware_accelerated, filter, morphology, nms, roi, warp,
+};
 
 impl<R, F, I, BT> VisionOps<Self> for CubeBackend<R, F, I, BT>
 where
@@ -39,6 +43,71 @@ where
             cpu::connected_components_with_stats::<Self>(img, connectivity, opts)
         })
     }
+
+    fn nms(
+        boxes: FloatTensor<Self>,
+        scores: FloatTensor<Self>,
+        options: NmsOptions,
+    ) -> IntTensor<Self> {
+        nms::nms::<R, F, I, BT>(boxes, scores, options)
+    }
+
+    fn roi_align(
+        x: FloatTensor<Self>,
+        rois: FloatTensor<Self>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<Self> {
+        roi::roi_align::<R, F>(x, rois, options)
+    }
+
+    fn roi_pool(
+        x: FloatTensor<Self>,
+        rois: FloatTensor<Self>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<Self> {
+        roi::roi_pool::<R, F>(x, rois, options)
+    }
+
+    fn erode(x: FloatTensor<Self>, kernel: BoolTensor<Self>) -> FloatTensor<Self> {
+        morphology::morphology::<R, F, BT>(x, kernel, false)
+    }
+
+    fn dilate(x: FloatTensor<Self>, kernel: BoolTensor<Self>) -> FloatTensor<Self> {
+        morphology::morphology::<R, F, BT>(x, kernel, true)
+    }
+
+    fn filter2d(
+        x: FloatTensor<Self>,
+        kernel: FloatTensor<Self>,
+        border: BorderType,
+    ) -> FloatTensor<Self> {
+        filter::filter2d::<R, F>(x, kernel, border)
+    }
+
+    fn median_blur(
+        x: FloatTensor<Self>,
+        kernel_size: usize,
+        border: BorderType,
+    ) -> FloatTensor<Self> {
+        filter::median_blur::<R, F>(x, kernel_size, border)
+    }
+
+    fn canny(x: FloatTensor<Self>, options: CannyOptions) -> BoolTensor<Self> {
+        canny::canny::<R, F, I, BT>(x, options)
+    }
+
+    fn color_convert(x: FloatTensor<Self>, conversion: ColorConversion) -> FloatTensor<Self> {
+        color::color_convert::<R, F>(x, conversion)
+    }
+
+    fn warp_perspective(
+        x: FloatTensor<Self>,
+        matrix: FloatTensor<Self>,
+        output_size: [usize; 2],
+        options: WarpOptions,
+    ) -> FloatTensor<Self> {
+        warp::warp_perspective::<R, F>(x, matrix, output_size, options)
+    }
 }
 
 #[cfg(feature = "fusion")]
@@ -160,4 +229,394 @@ impl<B: FusionBackend + VisionOps<B>> VisionOps<Self> for Fusion<B> {
         };
         (out, stats)
     }
+
+    fn nms(
+        boxes: FloatTensor<Self>,
+        scores: FloatTensor<Self>,
+        options: NmsOptions,
+    ) -> IntTensor<Self> {
+        // The number of selected boxes is only known once the inner backend has run, so the
+        // selection isn't registered as a lazy operation.
+        let client = boxes.client.clone();
+        let device = client.device().clone();
+        let boxes = client.resolve_tensor_float::<B>(boxes);
+        let scores = client.resolve_tensor_float::<B>(scores);
+        let kept = Tensor::<B, 1, Int>::from_primitive(B::nms(boxes, scores, options)).into_data();
+
+        Tensor::<Self, 1, Int>::from_data(kept, &device).into_primitive()
+    }
+
+    fn roi_align(
+        x: FloatTensor<Self>,
+        rois: FloatTensor<Self>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<Self> {
+        let num_rois = rois.shape[0];
+        let channels = x.shape[1];
+        let [pooled_height, pooled_width] = options.output_size;
+        let client = x.client.clone();
+
+        #[derive(derive_new::new)]
+        struct RoiAlign<B> {
+            desc: CustomOpIr,
+            options: RoiAlignOptions,
+            _b: core::marker::PhantomData<B>,
+        }
+
+        impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for RoiAlign<B1> {
+            fn execute(
+                self: Box<Self>,
+                handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+            ) {
+                let ([x, rois], [out]) = self.desc.consume();
+                let x = handles.get_float_tensor::<B1>(&x);
+                let rois = handles.get_float_tensor::<B1>(&rois);
+                let output = B1::roi_align(x, rois, self.options);
+
+                handles.register_float_tensor::<B1>(&out.id, output);
+            }
+        }
+
+        let streams = vec![x.stream, rois.stream];
+        let out = client.tensor_uninitialized(
+            vec![num_rois, channels, pooled_height, pooled_width],
+            x.dtype,
+        );
+
+        let desc = CustomOpIr::new(
+            "roi_align",
+            &[x.into_ir(), rois.into_ir()],
+            &[out.to_ir_out()],
+        );
+        client.register(
+            streams,
+            OperationIr::Custom(desc.clone()),
+            RoiAlign::<B>::new(desc, options),
+        );
+
+        out
+    }
+
+    fn roi_pool(
+        x: FloatTensor<Self>,
+        rois: FloatTensor<Self>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<Self> {
+        let num_rois = rois.shape[0];
+        let channels = x.shape[1];
+        let [pooled_height, pooled_width] = options.output_size;
+        let client = x.client.clone();
+
+        #[derive(derive_new::new)]
+        struct RoiPool<B> {
+            desc: CustomOpIr,
+            options: RoiPoolOptions,
+            _b: core::marker::PhantomData<B>,
+        }
+
+        impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for RoiPool<B1> {
+            fn execute(
+                self: Box<Self>,
+                handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+            ) {
+                let ([x, rois], [out]) = self.desc.consume();
+                let x = handles.get_float_tensor::<B1>(&x);
+                let rois = handles.get_float_tensor::<B1>(&rois);
+                let output = B1::roi_pool(x, rois, self.options);
+
+                handles.register_float_tensor::<B1>(&out.id, output);
+            }
+        }
+
+        let streams = vec![x.stream, rois.stream];
+        let out = client.tensor_uninitialized(
+            vec![num_rois, channels, pooled_height, pooled_width],
+            x.dtype,
+        );
+
+        let desc = CustomOpIr::new(
+            "roi_pool",
+            &[x.into_ir(), rois.into_ir()],
+            &[out.to_ir_out()],
+        );
+        client.register(
+            streams,
+            OperationIr::Custom(desc.clone()),
+            RoiPool::<B>::new(desc, options),
+        );
+
+        out
+    }
+
+    fn erode(x: FloatTensor<Self>, kernel: BoolTensor<Self>) -> FloatTensor<Self> {
+        fusion_morphology::<B>(x, kernel, false)
+    }
+
+    fn dilate(x: FloatTensor<Self>, kernel: BoolTensor<Self>) -> FloatTensor<Self> {
+        fusion_morphology::<B>(x, kernel, true)
+    }
+
+    fn filter2d(
+        x: FloatTensor<Self>,
+        kernel: FloatTensor<Self>,
+        border: BorderType,
+    ) -> FloatTensor<Self> {
+        let client = x.client.clone();
+
+        #[derive(derive_new::new)]
+        struct Filter2d<B> {
+            desc: CustomOpIr,
+            border: BorderType,
+            _b: core::marker::PhantomData<B>,
+        }
+
+        impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for Filter2d<B1> {
+            fn execute(
+                self: Box<Self>,
+                handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+            ) {
+                let ([x, kernel], [out]) = self.desc.consume();
+                let x = handles.get_float_tensor::<B1>(&x);
+                let kernel = handles.get_float_tensor::<B1>(&kernel);
+                let output = B1::filter2d(x, kernel, self.border);
+
+                handles.register_float_tensor::<B1>(&out.id, output);
+            }
+        }
+
+        let streams = vec![x.stream, kernel.stream];
+        let out = client.tensor_uninitialized(x.shape.clone(), x.dtype);
+
+        let desc = CustomOpIr::new(
+            "filter2d",
+            &[x.into_ir(), kernel.into_ir()],
+            &[out.to_ir_out()],
+        );
+        client.register(
+            streams,
+            OperationIr::Custom(desc.clone()),
+            Filter2d::<B>::new(desc, border),
+        );
+
+        out
+    }
+
+    fn median_blur(
+        x: FloatTensor<Self>,
+        kernel_size: usize,
+        border: BorderType,
+    ) -> FloatTensor<Self> {
+        let client = x.client.clone();
+
+        #[derive(derive_new::new)]
+        struct MedianBlur<B> {
+            desc: CustomOpIr,
+            kernel_size: usize,
+            border: BorderType,
+            _b: core::marker::PhantomData<B>,
+        }
+
+        impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for MedianBlur<B1> {
+            fn execute(
+                self: Box<Self>,
+                handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+            ) {
+                let ([x], [out]) = self.desc.consume();
+                let x = handles.get_float_tensor::<B1>(&x);
+                let output = B1::median_blur(x, self.kernel_size, self.border);
+
+                handles.register_float_tensor::<B1>(&out.id, output);
+            }
+        }
+
+        let stream = x.stream;
+        let out = client.tensor_uninitialized(x.shape.clone(), x.dtype);
+
+        let desc = CustomOpIr::new("median_blur", &[x.into_ir()], &[out.to_ir_out()]);
+        client.register(
+            vec![stream],
+            OperationIr::Custom(desc.clone()),
+            MedianBlur::<B>::new(desc, kernel_size, border),
+        );
+
+        out
+    }
+
+    fn canny(x: FloatTensor<Self>, options: CannyOptions) -> BoolTensor<Self> {
+        let client = x.client.clone();
+
+        #[derive(derive_new::new)]
+        struct Canny<B> {
+            desc: CustomOpIr,
+            options: CannyOptions,
+            _b: core::marker::PhantomData<B>,
+        }
+
+        impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for Canny<B1> {
+            fn execute(
+                self: Box<Self>,
+                handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+            ) {
+                let ([x], [edges]) = self.desc.consume();
+                let x = handles.get_float_tensor::<B1>(&x);
+                let output = B1::canny(x, self.options);
+
+                handles.register_bool_tensor::<B1>(&edges.id, output);
+            }
+        }
+
+        let stream = x.stream;
+        let out = client.tensor_uninitialized(x.shape.clone(), DType::Bool);
+
+        let desc = CustomOpIr::new("canny", &[x.into_ir()], &[out.to_ir_out()]);
+        client.register(
+            vec![stream],
+            OperationIr::Custom(desc.clone()),
+            Canny::<B>::new(desc, options),
+        );
+
+        out
+    }
+
+    fn color_convert(x: FloatTensor<Self>, conversion: ColorConversion) -> FloatTensor<Self> {
+        let batch_size = x.shape[0];
+        let height = x.shape[2];
+        let width = x.shape[3];
+        let (_, out_channels) = conversion.channels();
+        let client = x.client.clone();
+
+        #[derive(derive_new::new)]
+        struct ColorConvert<B> {
+            desc: CustomOpIr,
+            conversion: ColorConversion,
+            _b: core::marker::PhantomData<B>,
+        }
+
+        impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for ColorConvert<B1> {
+            fn execute(
+                self: Box<Self>,
+                handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+            ) {
+                let ([x], [out]) = self.desc.consume();
+                let x = handles.get_float_tensor::<B1>(&x);
+                let output = B1::color_convert(x, self.conversion);
+
+                handles.register_float_tensor::<B1>(&out.id, output);
+            }
+        }
+
+        let stream = x.stream;
+        let out =
+            client.tensor_uninitialized(vec![batch_size, out_channels, height, width], x.dtype);
+
+        let desc = CustomOpIr::new("color_convert", &[x.into_ir()], &[out.to_ir_out()]);
+        client.register(
+            vec![stream],
+            OperationIr::Custom(desc.clone()),
+            ColorConvert::<B>::new(desc, conversion),
+        );
+
+        out
+    }
+
+    fn warp_perspective(
+        x: FloatTensor<Self>,
+        matrix: FloatTensor<Self>,
+        output_size: [usize; 2],
+        options: WarpOptions,
+    ) -> FloatTensor<Self> {
+        let batch_size = x.shape[0];
+        let channels = x.shape[1];
+        let [out_height, out_width] = output_size;
+        let client = x.client.clone();
+
+        #[derive(derive_new::new)]
+        struct WarpPerspective<B> {
+            desc: CustomOpIr,
+            output_size: [usize; 2],
+            options: WarpOptions,
+            _b: core::marker::PhantomData<B>,
+        }
+
+        impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for WarpPerspective<B1> {
+            fn execute(
+                self: Box<Self>,
+                handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+            ) {
+                let ([x, matrix], [out]) = self.desc.consume();
+                let x = handles.get_float_tensor::<B1>(&x);
+                let matrix = handles.get_float_tensor::<B1>(&matrix);
+                let output = B1::warp_perspective(x, matrix, self.output_size, self.options);
+
+                handles.register_float_tensor::<B1>(&out.id, output);
+            }
+        }
+
+        let streams = vec![x.stream, matrix.stream];
+        let out =
+            client.tensor_uninitialized(vec![batch_size, channels, out_height, out_width], x.dtype);
+
+        let desc = CustomOpIr::new(
+            "warp_perspective",
+            &[x.into_ir(), matrix.into_ir()],
+            &[out.to_ir_out()],
+        );
+        client.register(
+            streams,
+            OperationIr::Custom(desc.clone()),
+            WarpPerspective::<B>::new(desc, output_size, options),
+        );
+
+        out
+    }
+}
+
+/// Registers an erosion, or a dilation when `dilate` is true, as a custom operation.
+#[cfg(feature = "fusion")]
+fn fusion_morphology<B: FusionBackend + VisionOps<B>>(
+    x: FloatTensor<Fusion<B>>,
+    kernel: BoolTensor<Fusion<B>>,
+    dilate: bool,
+) -> FloatTensor<Fusion<B>> {
+    let client = x.client.clone();
+
+    #[derive(derive_new::new)]
+    struct Morphology<B> {
+        desc: CustomOpIr,
+        dilate: bool,
+        _b: core::marker::PhantomData<B>,
+    }
+
+    impl<B1: FusionBackend + VisionOps<B1>> Operation<B1::FusionRuntime> for Morphology<B1> {
+        fn execute(
+            self: Box<Self>,
+            handles: &mut HandleContainer<<B1::FusionRuntime as FusionRuntime>::FusionHandle>,
+        ) {
+            let ([x, kernel], [out]) = self.desc.consume();
+            let x = handles.get_float_tensor::<B1>(&x);
+            let kernel = handles.get_bool_tensor::<B1>(&kernel);
+            let output = match self.dilate {
+                true => B1::dilate(x, kernel),
+                false => B1::erode(x, kernel),
+            };
+
+            handles.register_float_tensor::<B1>(&out.id, output);
+        }
+    }
+
+    let streams = vec![x.stream, kernel.stream];
+    let out = client.tensor_uninitialized(x.shape.clone(), x.dtype);
+    let name = match dilate {
+        true => "dilate",
+        false => "erode",
+    };
+
+    let desc = CustomOpIr::new(name, &[x.into_ir(), kernel.into_ir()], &[out.to_ir_out()]);
+    client.register(
+        streams,
+        OperationIr::Custom(desc.clone()),
+        Morphology::<B>::new(desc, dilate),
+    );
+
+    out
 }
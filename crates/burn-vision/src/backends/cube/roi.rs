@@ -0,0 +1,244 @@
+use burn_cubecl::{ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime, FloatElement};
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{RoiAlignOptions, RoiPoolOptions};
+
+/// Each unit computes one output element by averaging the samples of its bin.
+#[cube(launch)]
+fn roi_align_kernel<F: Float>(
+    input: &Tensor<F>,
+    rois: &Tensor<F>,
+    output: &mut Tensor<F>,
+    spatial_scale: f32,
+    sampling_ratio: u32,
+    #[comptime] aligned: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let k = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let ph = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let pw = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let roi = k * rois.stride(0);
+    let batch = f32::cast_from(rois[roi]) as u32;
+    let mut pixel_offset = 0.0f32;
+    if comptime![aligned] {
+        pixel_offset = 0.5;
+    }
+
+    let start_x = f32::cast_from(rois[roi + rois.stride(1)]) * spatial_scale - pixel_offset;
+    let start_y = f32::cast_from(rois[roi + 2 * rois.stride(1)]) * spatial_scale - pixel_offset;
+    let mut roi_width =
+        f32::cast_from(rois[roi + 3 * rois.stride(1)]) * spatial_scale - pixel_offset - start_x;
+    let mut roi_height =
+        f32::cast_from(rois[roi + 4 * rois.stride(1)]) * spatial_scale - pixel_offset - start_y;
+
+    // Misaligned regions are forced to be at least one pixel wide.
+    if comptime![!aligned] {
+        roi_width = f32::max(roi_width, 1.0);
+        roi_height = f32::max(roi_height, 1.0);
+    }
+
+    let bin_height = roi_height / f32::cast_from(output.shape(2));
+    let bin_width = roi_width / f32::cast_from(output.shape(3));
+    let mut grid_height = sampling_ratio;
+    let mut grid_width = sampling_ratio;
+    if sampling_ratio == 0 {
+        grid_height = f32::ceil(bin_height) as u32;
+        grid_width = f32::ceil(bin_width) as u32;
+    }
+    let count = f32::max(f32::cast_from(grid_height * grid_width), 1.0);
+
+    let offset = batch * input.stride(0) + channel * input.stride(1);
+    let mut value = 0.0f32;
+
+    for iy in 0..grid_height {
+        let y = start_y
+            + f32::cast_from(ph) * bin_height
+            + (f32::cast_from(iy) + 0.5) * bin_height / f32::cast_from(grid_height);
+
+        for ix in 0..grid_width {
+            let x = start_x
+                + f32::cast_from(pw) * bin_width
+                + (f32::cast_from(ix) + 0.5) * bin_width / f32::cast_from(grid_width);
+
+            value += bilinear(input, offset, y, x);
+        }
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(value / count);
+}
+
+/// The bilinear interpolation of the input at a sampling point, zero when the point is more than
+/// one pixel outside of the input.
+#[cube]
+fn bilinear<F: Float>(input: &Tensor<F>, offset: u32, y: f32, x: f32) -> f32 {
+    let height = input.shape(2);
+    let width = input.shape(3);
+    let mut value = 0.0f32;
+
+    if y >= -1.0 && y <= f32::cast_from(height) && x >= -1.0 && x <= f32::cast_from(width) {
+        let y = f32::max(y, 0.0);
+        let x = f32::max(x, 0.0);
+
+        let mut y_low = y as u32;
+        let mut y_high = y_low + 1;
+        let mut ly = y - f32::cast_from(y_low);
+        if y_low >= height - 1 {
+            y_low = height - 1;
+            y_high = height - 1;
+            ly = 0.0;
+        }
+
+        let mut x_low = x as u32;
+        let mut x_high = x_low + 1;
+        let mut lx = x - f32::cast_from(x_low);
+        if x_low >= width - 1 {
+            x_low = width - 1;
+            x_high = width - 1;
+            lx = 0.0;
+        }
+
+        let hy = 1.0 - ly;
+        let hx = 1.0 - lx;
+        let row_low = offset + y_low * input.stride(2);
+        let row_high = offset + y_high * input.stride(2);
+
+        value = hy * hx * f32::cast_from(input[row_low + x_low * input.stride(3)])
+            + hy * lx * f32::cast_from(input[row_low + x_high * input.stride(3)])
+            + ly * hx * f32::cast_from(input[row_high + x_low * input.stride(3)])
+            + ly * lx * f32::cast_from(input[row_high + x_high * input.stride(3)]);
+    }
+
+    value
+}
+
+/// Each unit computes one output element as the maximum of its bin, zero when the bin is empty.
+#[cube(launch)]
+fn roi_pool_kernel<F: Float>(
+    input: &Tensor<F>,
+    rois: &Tensor<F>,
+    output: &mut Tensor<F>,
+    spatial_scale: f32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let k = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let ph = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let pw = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let height = f32::cast_from(input.shape(2));
+    let width = f32::cast_from(input.shape(3));
+
+    let roi = k * rois.stride(0);
+    let batch = f32::cast_from(rois[roi]) as u32;
+    let start_x = f32::round(f32::cast_from(rois[roi + rois.stride(1)]) * spatial_scale);
+    let start_y = f32::round(f32::cast_from(rois[roi + 2 * rois.stride(1)]) * spatial_scale);
+    let end_x = f32::round(f32::cast_from(rois[roi + 3 * rois.stride(1)]) * spatial_scale);
+    let end_y = f32::round(f32::cast_from(rois[roi + 4 * rois.stride(1)]) * spatial_scale);
+
+    // Misaligned regions are forced to be at least one pixel wide.
+    let bin_height = f32::max(end_y - start_y + 1.0, 1.0) / f32::cast_from(output.shape(2));
+    let bin_width = f32::max(end_x - start_x + 1.0, 1.0) / f32::cast_from(output.shape(3));
+
+    let ph = f32::cast_from(ph);
+    let pw = f32::cast_from(pw);
+    let h_start = f32::clamp(f32::floor(ph * bin_height) + start_y, 0.0, height) as u32;
+    let h_end = f32::clamp(f32::ceil((ph + 1.0) * bin_height) + start_y, 0.0, height) as u32;
+    let w_start = f32::clamp(f32::floor(pw * bin_width) + start_x, 0.0, width) as u32;
+    let w_end = f32::clamp(f32::ceil((pw + 1.0) * bin_width) + start_x, 0.0, width) as u32;
+
+    let offset = batch * input.stride(0) + channel * input.stride(1);
+    let mut value = 0.0f32;
+    let mut empty = true;
+
+    for h in h_start..h_end {
+        for w in w_start..w_end {
+            let current = f32::cast_from(input[offset + h * input.stride(2) + w * input.stride(3)]);
+
+            if empty || current > value {
+                value = current;
+                empty = false;
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(value);
+}
+
+pub(crate) fn roi_align<R: CubeRuntime, F: FloatElement>(
+    x: CubeTensor<R>,
+    rois: CubeTensor<R>,
+    options: RoiAlignOptions,
+) -> CubeTensor<R> {
+    let output = empty_output::<R, F>(&x, &rois, options.output_size);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    roi_align_kernel::launch::<F, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<F>(1),
+        rois.as_tensor_arg::<F>(1),
+        output.as_tensor_arg::<F>(1),
+        ScalarArg::new(options.spatial_scale),
+        ScalarArg::new(options.sampling_ratio as u32),
+        options.aligned,
+    );
+
+    output
+}
+
+pub(crate) fn roi_pool<R: CubeRuntime, F: FloatElement>(
+    x: CubeTensor<R>,
+    rois: CubeTensor<R>,
+    options: RoiPoolOptions,
+) -> CubeTensor<R> {
+    let output = empty_output::<R, F>(&x, &rois, options.output_size);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    roi_pool_kernel::launch::<F, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<F>(1),
+        rois.as_tensor_arg::<F>(1),
+        output.as_tensor_arg::<F>(1),
+        ScalarArg::new(options.spatial_scale),
+    );
+
+    output
+}
+
+fn empty_output<R: CubeRuntime, F: FloatElement>(
+    x: &CubeTensor<R>,
+    rois: &CubeTensor<R>,
+    [pooled_height, pooled_width]: [usize; 2],
+) -> CubeTensor<R> {
+    let [_, channels, _, _] = x.shape.dims();
+    let [num_rois, _] = rois.shape.dims();
+    let shape_out = Shape::new([num_rois, channels, pooled_height, pooled_width]);
+
+    empty_device::<R, F>(x.client.clone(), x.device.clone(), shape_out)
+}
@@ -0,0 +1,226 @@
+use burn_cubecl::{ops::numeric::empty_device, tensor::CubeTensor, CubeRuntime, FloatElement};
+use burn_tensor::{ops::InterpolateMode, Shape};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::image::{border_args, read_pixel};
+use crate::WarpOptions;
+
+const NEAREST: u32 = 0;
+const BILINEAR: u32 = 1;
+const BICUBIC: u32 = 2;
+
+/// Each unit computes one output pixel by sampling the input at the inverse transform of its
+/// location.
+#[cube(launch)]
+fn warp_perspective_kernel<F: Float>(
+    input: &Tensor<F>,
+    matrix: &Tensor<F>,
+    output: &mut Tensor<F>,
+    border_value: f32,
+    #[comptime] mode: u32,
+    #[comptime] border: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let v = f32::cast_from(ABSOLUTE_POS / output.stride(2) % output.shape(2));
+    let u = f32::cast_from(ABSOLUTE_POS / output.stride(3) % output.shape(3));
+
+    let m = batch * matrix.stride(0);
+    let row = matrix.stride(1);
+    let col = matrix.stride(2);
+    let m0 = f32::cast_from(matrix[m]);
+    let m1 = f32::cast_from(matrix[m + col]);
+    let m2 = f32::cast_from(matrix[m + 2 * col]);
+    let m3 = f32::cast_from(matrix[m + row]);
+    let m4 = f32::cast_from(matrix[m + row + col]);
+    let m5 = f32::cast_from(matrix[m + row + 2 * col]);
+    let m6 = f32::cast_from(matrix[m + 2 * row]);
+    let m7 = f32::cast_from(matrix[m + 2 * row + col]);
+    let m8 = f32::cast_from(matrix[m + 2 * row + 2 * col]);
+
+    // The inverse of the transform from its adjugate, a singular matrix being mapped to zeros.
+    let a0 = m4 * m8 - m5 * m7;
+    let a1 = m2 * m7 - m1 * m8;
+    let a2 = m1 * m5 - m2 * m4;
+    let a3 = m5 * m6 - m3 * m8;
+    let a4 = m0 * m8 - m2 * m6;
+    let a5 = m2 * m3 - m0 * m5;
+    let a6 = m3 * m7 - m4 * m6;
+    let a7 = m1 * m6 - m0 * m7;
+    let a8 = m0 * m4 - m1 * m3;
+    let det = m0 * a0 + m1 * a3 + m2 * a6;
+    let mut scale = 0.0f32;
+    if det != 0.0 {
+        scale = 1.0 / det;
+    }
+
+    // Points at infinity are mapped to the origin, like `opencv`.
+    let w = (a6 * u + a7 * v + a8) * scale;
+    let mut w_inv = 0.0f32;
+    if w != 0.0 {
+        w_inv = 1.0 / w;
+    }
+    let x = (a0 * u + a1 * v + a2) * scale * w_inv;
+    let y = (a3 * u + a4 * v + a5) * scale * w_inv;
+
+    let offset = batch * input.stride(0) + channel * input.stride(1);
+    let y0 = f32::floor(y);
+    let x0 = f32::floor(x);
+    let ty = y - y0;
+    let tx = x - x0;
+    let y0 = i32::cast_from(y0);
+    let x0 = i32::cast_from(x0);
+    let mut value = 0.0f32;
+
+    if comptime![mode == NEAREST] {
+        let y = i32::cast_from(f32::round(y));
+        let x = i32::cast_from(f32::round(x));
+        value = read_pixel(input, offset, y, x, border_value, border);
+    }
+    if comptime![mode == BILINEAR] {
+        let top = read_pixel(input, offset, y0, x0, border_value, border) * (1.0 - tx)
+            + read_pixel(input, offset, y0, x0 + 1, border_value, border) * tx;
+        let bottom = read_pixel(input, offset, y0 + 1, x0, border_value, border) * (1.0 - tx)
+            + read_pixel(input, offset, y0 + 1, x0 + 1, border_value, border) * tx;
+        value = top * (1.0 - ty) + bottom * ty;
+    }
+    if comptime![mode == BICUBIC] {
+        let wy0 = cubic_weight_outer(ty + 1.0);
+        let wy1 = cubic_weight_inner(ty);
+        let wy2 = cubic_weight_inner(1.0 - ty);
+        let wx0 = cubic_weight_outer(tx + 1.0);
+        let wx1 = cubic_weight_inner(tx);
+        let wx2 = cubic_weight_inner(1.0 - tx);
+        let wx3 = 1.0 - wx0 - wx1 - wx2;
+
+        value =
+            wy0 * cubic_row(
+                input,
+                offset,
+                y0 - 1,
+                x0,
+                wx0,
+                wx1,
+                wx2,
+                wx3,
+                border_value,
+                border,
+            ) + wy1
+                * cubic_row(
+                    input,
+                    offset,
+                    y0,
+                    x0,
+                    wx0,
+                    wx1,
+                    wx2,
+                    wx3,
+                    border_value,
+                    border,
+                )
+                + wy2
+                    * cubic_row(
+                        input,
+                        offset,
+                        y0 + 1,
+                        x0,
+                        wx0,
+                        wx1,
+                        wx2,
+                        wx3,
+                        border_value,
+                        border,
+                    )
+                + (1.0 - wy0 - wy1 - wy2)
+                    * cubic_row(
+                        input,
+                        offset,
+                        y0 + 2,
+                        x0,
+                        wx0,
+                        wx1,
+                        wx2,
+                        wx3,
+                        border_value,
+                        border,
+                    );
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(value);
+}
+
+/// The bicubic convolution weight of a tap at a distance in `[0, 1]`, with `A = -0.75`.
+#[cube]
+fn cubic_weight_inner(t: f32) -> f32 {
+    (1.25 * t - 2.25) * t * t + 1.0
+}
+
+/// The bicubic convolution weight of a tap at a distance in `[1, 2]`, with `A = -0.75`.
+#[cube]
+fn cubic_weight_outer(t: f32) -> f32 {
+    ((-0.75 * t + 3.75) * t - 6.0) * t + 3.0
+}
+
+/// Interpolates the four taps of a row around `x0`.
+#[allow(clippy::too_many_arguments)]
+#[cube]
+fn cubic_row<F: Float>(
+    input: &Tensor<F>,
+    offset: u32,
+    y: i32,
+    x0: i32,
+    w0: f32,
+    w1: f32,
+    w2: f32,
+    w3: f32,
+    border_value: f32,
+    #[comptime] border: u32,
+) -> f32 {
+    w0 * read_pixel(input, offset, y, x0 - 1, border_value, border)
+        + w1 * read_pixel(input, offset, y, x0, border_value, border)
+        + w2 * read_pixel(input, offset, y, x0 + 1, border_value, border)
+        + w3 * read_pixel(input, offset, y, x0 + 2, border_value, border)
+}
+
+pub(crate) fn warp_perspective<R: CubeRuntime, F: FloatElement>(
+    x: CubeTensor<R>,
+    matrix: CubeTensor<R>,
+    [out_height, out_width]: [usize; 2],
+    options: WarpOptions,
+) -> CubeTensor<R> {
+    let [batch_size, channels, _, _] = x.shape.dims();
+    let shape_out = Shape::new([batch_size, channels, out_height, out_width]);
+    let output = empty_device::<R, F>(x.client.clone(), x.device.clone(), shape_out);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+    let (border, border_value) = border_args(options.border);
+    let mode = match options.mode {
+        InterpolateMode::Nearest => NEAREST,
+        InterpolateMode::Bilinear => BILINEAR,
+        InterpolateMode::Bicubic => BICUBIC,
+    };
+
+    warp_perspective_kernel::launch::<F, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<F>(1),
+        matrix.as_tensor_arg::<F>(1),
+        output.as_tensor_arg::<F>(1),
+        ScalarArg::new(border_value),
+        mode,
+        border,
+    );
+
+    output
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "autodiff")]
+mod autodiff;
 pub(crate) mod cpu;
 #[cfg(feature = "cubecl-backend")]
 mod cube;
@@ -6,12 +6,37 @@
 //! Currently implemented are:
 //! - `connected_components`
 //! - `connected_components_with_stats`
+//! - `nms`
+//! - `batched_nms`
+//! - `roi_align`
+//! - `roi_pool`
+//! - `box_area`
+//! - `box_iou`
+//! - `generalized_box_iou`
+//! - `box_convert`
+//! - `erode`, `dilate`, `open` and `close`
+//! - `filter2d`
+//! - `box_filter`
+//! - `gaussian_blur`
+//! - `median_blur`
+//! - `sobel`
+//! - `canny`
+//! - `color_convert`
+//! - `warp_affine`
+//! - `warp_perspective`
+//!
+//! Detection operations follow the conventions of `torchvision.ops`.
+//!
+//! # Augmentation
+//! The [augment] module provides data augmentations of batches of images, running on the
+//! device of the batch.
 //!
 
 #![warn(missing_docs)]
 
 extern crate alloc;
 
+pub mod augment;
 /// Backend implementations for JIT and CPU
 pub mod backends;
 mod ops;
@@ -1,10 +1,12 @@
 use crate::backends::cpu;
 use burn_tensor::{
     backend::Backend,
-    ops::{BoolTensor, IntTensor},
+    ops::{BoolTensor, FloatTensor, IntTensor, InterpolateMode},
     Int, Tensor,
 };
 
+use super::{boxes, filters, warp};
+
 /// Connected components connectivity
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Connectivity {
@@ -102,6 +104,144 @@ impl ConnectedStatsOptions {
     }
 }
 
+/// Format of the boxes used by the detection operations, along the last dimension of size 4.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoxFormat {
+    /// Corners of the box, as `(x1, y1, x2, y2)`.
+    Xyxy,
+    /// Top left corner and size of the box, as `(x, y, width, height)`.
+    Xywh,
+    /// Center and size of the box, as `(cx, cy, width, height)`.
+    Cxcywh,
+}
+
+/// Options for [non-maximum suppression](VisionOps::nms).
+#[derive(derive_new::new, Clone, Copy, Debug, PartialEq)]
+pub struct NmsOptions {
+    /// Boxes overlapping a kept box with an IoU strictly greater than this threshold are discarded.
+    pub iou_threshold: f32,
+    /// Boxes with a score lower than or equal to this threshold are discarded.
+    pub score_threshold: f32,
+    /// Maximum number of boxes to keep, all of them when `None`.
+    pub max_output_boxes: Option<usize>,
+}
+
+impl Default for NmsOptions {
+    fn default() -> Self {
+        Self {
+            iou_threshold: 0.5,
+            score_threshold: f32::NEG_INFINITY,
+            max_output_boxes: None,
+        }
+    }
+}
+
+/// Options for [RoI align](VisionOps::roi_align).
+#[derive(derive_new::new, Clone, Copy, Debug, PartialEq)]
+pub struct RoiAlignOptions {
+    /// Size of the output of each region, as `[height, width]`.
+    pub output_size: [usize; 2],
+    /// Scale mapping the coordinates of the regions to the coordinates of the input.
+    pub spatial_scale: f32,
+    /// Number of sampling points along each dimension of an output bin, adaptive to the size of
+    /// the region when `0`.
+    pub sampling_ratio: usize,
+    /// If true, the coordinates of the regions are shifted by half a pixel so that they refer to
+    /// the corner points of the pixels, matching the `half_pixel` mode of ONNX.
+    pub aligned: bool,
+}
+
+/// Options for [RoI pooling](VisionOps::roi_pool).
+#[derive(derive_new::new, Clone, Copy, Debug, PartialEq)]
+pub struct RoiPoolOptions {
+    /// Size of the output of each region, as `[height, width]`.
+    pub output_size: [usize; 2],
+    /// Scale mapping the coordinates of the regions to the coordinates of the input.
+    pub spatial_scale: f32,
+}
+
+/// Extrapolation of the pixels outside of the image, following the `opencv` border types.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BorderType {
+    /// Pixels outside of the image have a constant value: `iiiiii|abcdefgh|iiiiiii`
+    Constant(f32),
+    /// The edge pixels are repeated: `aaaaaa|abcdefgh|hhhhhhh`
+    Replicate,
+    /// The image is mirrored, including the edge pixels: `fedcba|abcdefgh|hgfedcb`
+    Reflect,
+    /// The image is mirrored, excluding the edge pixels: `gfedcb|abcdefgh|gfedcba`
+    Reflect101,
+}
+
+impl Default for BorderType {
+    fn default() -> Self {
+        Self::Reflect101
+    }
+}
+
+/// Shape of a [structuring element](crate::structuring_element) used by morphology operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KernelShape {
+    /// Rectangle filled with ones
+    Rect,
+    /// Cross along the middle row and column
+    Cross,
+    /// Ellipse inscribed in the rectangle
+    Ellipse,
+}
+
+/// Color space conversion performed by [color_convert](VisionOps::color_convert).
+///
+/// Color channels are expected in `[0, 1]`, except for the hue which is in degrees in `[0, 360)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorConversion {
+    /// RGB to single channel luma, with the ITU-R BT.601 weights
+    RgbToGray,
+    /// Single channel gray to RGB, repeating the channel
+    GrayToRgb,
+    /// RGB to hue, saturation and value
+    RgbToHsv,
+    /// Hue, saturation and value to RGB
+    HsvToRgb,
+    /// RGB to luma and chroma, with the chroma centered on `0.5`
+    RgbToYuv,
+    /// Luma and chroma to RGB
+    YuvToRgb,
+}
+
+impl ColorConversion {
+    /// Number of channels of the input and of the output of the conversion.
+    pub fn channels(&self) -> (usize, usize) {
+        match self {
+            ColorConversion::RgbToGray => (3, 1),
+            ColorConversion::GrayToRgb => (1, 3),
+            _ => (3, 3),
+        }
+    }
+}
+
+/// Options for [Canny edge detection](VisionOps::canny).
+#[derive(derive_new::new, Clone, Copy, Debug, PartialEq)]
+pub struct CannyOptions {
+    /// Pixels with a gradient magnitude above this threshold are edges if they are connected to
+    /// a strong edge.
+    pub low_threshold: f32,
+    /// Pixels with a gradient magnitude above this threshold are strong edges.
+    pub high_threshold: f32,
+    /// Whether the magnitude of the gradient uses the L2 norm instead of the L1 norm.
+    pub l2_gradient: bool,
+}
+
+/// Options for [perspective](VisionOps::warp_perspective) and [affine](VisionOps::warp_affine)
+/// warps.
+#[derive(derive_new::new, Clone, Debug)]
+pub struct WarpOptions {
+    /// Interpolation used to sample the input.
+    pub mode: InterpolateMode,
+    /// Extrapolation of the input for the locations outside of the image.
+    pub border: BorderType,
+}
+
 /// Vision operations, implemented by each backend
 pub trait VisionOps<B: Backend> {
     /// Computes the connected components labeled image of boolean image with 4 or 8 way
@@ -124,4 +264,240 @@ pub trait VisionOps<B: Backend> {
     ) -> (IntTensor<B>, ConnectedStatsPrimitive<B>) {
         cpu::connected_components_with_stats(img, connectivity, opts)
     }
+
+    /// Performs non-maximum suppression, greedily keeping the boxes with the highest scores and
+    /// discarding the ones overlapping a kept box - returns the indices of the kept boxes sorted
+    /// by decreasing score.
+    ///
+    /// `boxes` - The boxes in the `Xyxy` format [num_boxes, 4]
+    /// `scores` - The score of each box [num_boxes]
+    fn nms(boxes: FloatTensor<B>, scores: FloatTensor<B>, options: NmsOptions) -> IntTensor<B> {
+        cpu::nms::<B>(boxes, scores, options)
+    }
+
+    /// Performs non-maximum suppression independently for each category, so that boxes of
+    /// different categories never suppress each other - returns the indices of the kept boxes
+    /// sorted by decreasing score.
+    ///
+    /// `boxes` - The boxes in the `Xyxy` format [num_boxes, 4]
+    /// `scores` - The score of each box [num_boxes]
+    /// `idxs` - The category of each box [num_boxes]
+    fn batched_nms(
+        boxes: FloatTensor<B>,
+        scores: FloatTensor<B>,
+        idxs: IntTensor<B>,
+        options: NmsOptions,
+    ) -> IntTensor<B> {
+        let boxes = boxes::offset_by_category::<B>(boxes, idxs);
+        Self::nms(boxes, scores, options)
+    }
+
+    /// Pools the features of each region of interest to a fixed size, by averaging bilinearly
+    /// interpolated samples of each output bin.
+    ///
+    /// `x` - The features [batch_size, channels, height, width]
+    /// `rois` - The regions as `(batch_index, x1, y1, x2, y2)` [num_rois, 5]
+    ///
+    /// Returns the pooled features [num_rois, channels, output_height, output_width]
+    fn roi_align(
+        x: FloatTensor<B>,
+        rois: FloatTensor<B>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<B> {
+        cpu::roi_align::<B>(x, rois, options)
+    }
+
+    /// Backward pass of [roi_align](VisionOps::roi_align) - returns the gradient of the features.
+    fn roi_align_backward(
+        x: FloatTensor<B>,
+        rois: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<B> {
+        cpu::roi_align_backward::<B>(x, rois, output_grad, options)
+    }
+
+    /// Pools the features of each region of interest to a fixed size, by taking the maximum of
+    /// each output bin.
+    ///
+    /// `x` - The features [batch_size, channels, height, width]
+    /// `rois` - The regions as `(batch_index, x1, y1, x2, y2)` [num_rois, 5]
+    ///
+    /// Returns the pooled features [num_rois, channels, output_height, output_width]
+    fn roi_pool(
+        x: FloatTensor<B>,
+        rois: FloatTensor<B>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<B> {
+        cpu::roi_pool::<B>(x, rois, options)
+    }
+
+    /// Backward pass of [roi_pool](VisionOps::roi_pool) - returns the gradient of the features.
+    fn roi_pool_backward(
+        x: FloatTensor<B>,
+        rois: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<B> {
+        cpu::roi_pool_backward::<B>(x, rois, output_grad, options)
+    }
+
+    /// Computes the area of each box.
+    ///
+    /// `boxes` - The boxes in the `Xyxy` format [num_boxes, 4]
+    fn box_area(boxes: FloatTensor<B>) -> FloatTensor<B> {
+        boxes::box_area::<B>(boxes)
+    }
+
+    /// Computes the intersection over union of each pair of boxes.
+    ///
+    /// `boxes1` - The boxes in the `Xyxy` format [n, 4]
+    /// `boxes2` - The boxes in the `Xyxy` format [m, 4]
+    ///
+    /// Returns the IoU matrix [n, m]
+    fn box_iou(boxes1: FloatTensor<B>, boxes2: FloatTensor<B>) -> FloatTensor<B> {
+        boxes::box_iou::<B>(boxes1, boxes2)
+    }
+
+    /// Computes the generalized intersection over union of each pair of boxes, which also
+    /// penalizes the area of the smallest box enclosing both boxes that they don't cover.
+    ///
+    /// `boxes1` - The boxes in the `Xyxy` format [n, 4]
+    /// `boxes2` - The boxes in the `Xyxy` format [m, 4]
+    ///
+    /// Returns the generalized IoU matrix [n, m]
+    fn generalized_box_iou(boxes1: FloatTensor<B>, boxes2: FloatTensor<B>) -> FloatTensor<B> {
+        boxes::generalized_box_iou::<B>(boxes1, boxes2)
+    }
+
+    /// Converts the boxes [num_boxes, 4] from a format to another.
+    fn box_convert(
+        boxes: FloatTensor<B>,
+        in_format: BoxFormat,
+        out_format: BoxFormat,
+    ) -> FloatTensor<B> {
+        boxes::box_convert::<B>(boxes, in_format, out_format)
+    }
+
+    /// Erodes the image with a structuring element, computing the minimum of the neighborhood
+    /// of each pixel. Pixels outside of the image are ignored.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `kernel` - The structuring element [kernel_height, kernel_width], anchored at its center
+    fn erode(x: FloatTensor<B>, kernel: BoolTensor<B>) -> FloatTensor<B> {
+        cpu::erode::<B>(x, kernel)
+    }
+
+    /// Dilates the image with a structuring element, computing the maximum of the neighborhood
+    /// of each pixel. Pixels outside of the image are ignored.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `kernel` - The structuring element [kernel_height, kernel_width], anchored at its center
+    fn dilate(x: FloatTensor<B>, kernel: BoolTensor<B>) -> FloatTensor<B> {
+        cpu::dilate::<B>(x, kernel)
+    }
+
+    /// Correlates each channel of the image with a kernel.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `kernel` - The kernel [kernel_height, kernel_width], anchored at its center
+    fn filter2d(x: FloatTensor<B>, kernel: FloatTensor<B>, border: BorderType) -> FloatTensor<B> {
+        cpu::filter2d::<B>(x, kernel, border)
+    }
+
+    /// Blurs the image with the sum of the neighborhood of each pixel, divided by the area of the
+    /// neighborhood when `normalize` is true.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `kernel_size` - The size of the neighborhood, as `[height, width]`
+    fn box_filter(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 2],
+        normalize: bool,
+        border: BorderType,
+    ) -> FloatTensor<B> {
+        let kernel = filters::box_kernel::<B>(kernel_size, normalize, &B::float_device(&x));
+        Self::filter2d(x, kernel, border)
+    }
+
+    /// Blurs the image with a gaussian kernel.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `kernel_size` - The size of the kernel, as `[height, width]`
+    /// `sigma` - The standard deviation along each dimension, as `[sigma_y, sigma_x]`, computed
+    ///   from the kernel size when not positive
+    fn gaussian_blur(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 2],
+        sigma: [f32; 2],
+        border: BorderType,
+    ) -> FloatTensor<B> {
+        let kernel = filters::gaussian_kernel::<B>(kernel_size, sigma, &B::float_device(&x));
+        Self::filter2d(x, kernel, border)
+    }
+
+    /// Replaces each pixel with the median of its square neighborhood.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `kernel_size` - The side of the neighborhood
+    fn median_blur(x: FloatTensor<B>, kernel_size: usize, border: BorderType) -> FloatTensor<B> {
+        cpu::median_blur::<B>(x, kernel_size, border)
+    }
+
+    /// Computes the derivative of the image with the 3x3 Sobel operator.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `dx` - The order of the derivative along the width, up to 2
+    /// `dy` - The order of the derivative along the height, up to 2
+    fn sobel(x: FloatTensor<B>, dx: usize, dy: usize, border: BorderType) -> FloatTensor<B> {
+        let kernel = filters::sobel_kernel::<B>(dx, dy, &B::float_device(&x));
+        Self::filter2d(x, kernel, border)
+    }
+
+    /// Finds the edges of each channel of the image with the Canny algorithm, using the 3x3
+    /// Sobel operator with replicated borders - returns the edge mask.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    fn canny(x: FloatTensor<B>, options: CannyOptions) -> BoolTensor<B> {
+        cpu::canny::<B>(x, options)
+    }
+
+    /// Converts the image from a color space to another.
+    ///
+    /// `x` - The image [batch_size, channels, height, width], with the input channels of the
+    ///   conversion
+    fn color_convert(x: FloatTensor<B>, conversion: ColorConversion) -> FloatTensor<B> {
+        cpu::color_convert::<B>(x, conversion)
+    }
+
+    /// Applies a perspective transform to the image, so that each output pixel `p` is sampled at
+    /// `inverse(matrix) * p` in the input.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `matrix` - The transform of each image [batch_size, 3, 3]
+    /// `output_size` - The size of the output, as `[height, width]`
+    fn warp_perspective(
+        x: FloatTensor<B>,
+        matrix: FloatTensor<B>,
+        output_size: [usize; 2],
+        options: WarpOptions,
+    ) -> FloatTensor<B> {
+        cpu::warp_perspective::<B>(x, matrix, output_size, options)
+    }
+
+    /// Applies an affine transform to the image, so that each output pixel `p` is sampled at
+    /// `inverse(matrix) * p` in the input.
+    ///
+    /// `x` - The image [batch_size, channels, height, width]
+    /// `matrix` - The transform of each image [batch_size, 2, 3]
+    /// `output_size` - The size of the output, as `[height, width]`
+    fn warp_affine(
+        x: FloatTensor<B>,
+        matrix: FloatTensor<B>,
+        output_size: [usize; 2],
+        options: WarpOptions,
+    ) -> FloatTensor<B> {
+        let matrix = warp::affine_to_perspective::<B>(matrix);
+        Self::warp_perspective(x, matrix, output_size, options)
+    }
 }
@@ -0,0 +1,151 @@
+use alloc::vec;
+use burn_tensor::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Int, Tensor, TensorPrimitive,
+};
+
+use crate::BoxFormat;
+
+/// Splits the boxes [num_boxes, 4] into their four components [num_boxes, 1].
+fn components<B: Backend>(boxes: Tensor<B, 2>) -> [Tensor<B, 2>; 4] {
+    let [a, b, c, d] = boxes.chunk(4, 1).try_into().unwrap();
+    [a, b, c, d]
+}
+
+fn area<B: Backend>(boxes: Tensor<B, 2>) -> Tensor<B, 1> {
+    let [x1, y1, x2, y2] = components(boxes);
+    ((x2 - x1) * (y2 - y1)).squeeze(1)
+}
+
+/// Computes the pairwise area of the intersection and of the union of the boxes [n, 4] and
+/// [m, 4], both of shape [n, m].
+fn intersection_union<B: Backend>(
+    boxes1: Tensor<B, 2>,
+    boxes2: Tensor<B, 2>,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let [n, _] = boxes1.dims();
+    let [m, _] = boxes2.dims();
+    let area1 = area(boxes1.clone()).reshape([n, 1]);
+    let area2 = area(boxes2.clone()).reshape([1, m]);
+
+    let boxes1 = boxes1.unsqueeze_dim::<3>(1).expand([n, m, 4]);
+    let boxes2 = boxes2.unsqueeze_dim::<3>(0).expand([n, m, 4]);
+    let top_left = boxes1
+        .clone()
+        .narrow(2, 0, 2)
+        .max_pair(boxes2.clone().narrow(2, 0, 2));
+    let bottom_right = boxes1.narrow(2, 2, 2).min_pair(boxes2.narrow(2, 2, 2));
+
+    let size = (bottom_right - top_left).clamp_min(0.0);
+    let intersection = (size.clone().narrow(2, 0, 1) * size.narrow(2, 1, 1)).squeeze(2);
+    let union = area1 + area2 - intersection.clone();
+
+    (intersection, union)
+}
+
+pub(crate) fn box_area<B: Backend>(boxes: FloatTensor<B>) -> FloatTensor<B> {
+    area(Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(
+        boxes,
+    )))
+    .into_primitive()
+    .tensor()
+}
+
+pub(crate) fn box_iou<B: Backend>(
+    boxes1: FloatTensor<B>,
+    boxes2: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let (intersection, union) = intersection_union(
+        Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(boxes1)),
+        Tensor::from_primitive(TensorPrimitive::Float(boxes2)),
+    );
+
+    (intersection / union).into_primitive().tensor()
+}
+
+pub(crate) fn generalized_box_iou<B: Backend>(
+    boxes1: FloatTensor<B>,
+    boxes2: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let boxes1 = Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(boxes1));
+    let boxes2 = Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(boxes2));
+    let [n, _] = boxes1.dims();
+    let [m, _] = boxes2.dims();
+    let (intersection, union) = intersection_union(boxes1.clone(), boxes2.clone());
+    let iou = intersection / union.clone();
+
+    // Smallest box enclosing both boxes.
+    let boxes1 = boxes1.unsqueeze_dim::<3>(1).expand([n, m, 4]);
+    let boxes2 = boxes2.unsqueeze_dim::<3>(0).expand([n, m, 4]);
+    let top_left = boxes1
+        .clone()
+        .narrow(2, 0, 2)
+        .min_pair(boxes2.clone().narrow(2, 0, 2));
+    let bottom_right = boxes1.narrow(2, 2, 2).max_pair(boxes2.narrow(2, 2, 2));
+    let size = (bottom_right - top_left).clamp_min(0.0);
+    let enclosing = (size.clone().narrow(2, 0, 1) * size.narrow(2, 1, 1)).squeeze::<2>(2);
+
+    (iou - (enclosing.clone() - union) / enclosing)
+        .into_primitive()
+        .tensor()
+}
+
+pub(crate) fn box_convert<B: Backend>(
+    boxes: FloatTensor<B>,
+    in_format: BoxFormat,
+    out_format: BoxFormat,
+) -> FloatTensor<B> {
+    if in_format == out_format {
+        return boxes;
+    }
+
+    let [a, b, c, d] = components(Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(
+        boxes,
+    )));
+    let [x1, y1, x2, y2] = match in_format {
+        BoxFormat::Xyxy => [a, b, c, d],
+        BoxFormat::Xywh => [a.clone(), b.clone(), a + c, b + d],
+        BoxFormat::Cxcywh => {
+            let (half_width, half_height) = (c.div_scalar(2.0), d.div_scalar(2.0));
+            [
+                a.clone() - half_width.clone(),
+                b.clone() - half_height.clone(),
+                a + half_width,
+                b + half_height,
+            ]
+        }
+    };
+
+    let components = match out_format {
+        BoxFormat::Xyxy => vec![x1, y1, x2, y2],
+        BoxFormat::Xywh => vec![x1.clone(), y1.clone(), x2 - x1, y2 - y1],
+        BoxFormat::Cxcywh => vec![
+            (x1.clone() + x2.clone()).div_scalar(2.0),
+            (y1.clone() + y2.clone()).div_scalar(2.0),
+            x2 - x1,
+            y2 - y1,
+        ],
+    };
+
+    Tensor::cat(components, 1).into_primitive().tensor()
+}
+
+/// Offsets the boxes of each category so that boxes of different categories never overlap.
+pub(crate) fn offset_by_category<B: Backend>(
+    boxes: FloatTensor<B>,
+    idxs: IntTensor<B>,
+) -> FloatTensor<B> {
+    let boxes = Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(boxes));
+    let idxs = Tensor::<B, 1, Int>::from_primitive(idxs);
+    let [num_boxes, _] = boxes.dims();
+
+    if num_boxes == 0 {
+        return boxes.into_primitive().tensor();
+    }
+
+    let offset = boxes.clone().max().add_scalar(1.0).reshape([1, 1]);
+    let offsets = idxs.float().reshape([num_boxes, 1]) * offset;
+
+    (boxes + offsets).into_primitive().tensor()
+}
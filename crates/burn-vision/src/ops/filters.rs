@@ -0,0 +1,164 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    backend::Backend,
+    module::conv2d,
+    ops::{BoolTensor, ConvOptions, FloatTensor},
+    Bool, Int, Shape, Tensor, TensorData, TensorPrimitive,
+};
+
+use crate::{backends::cpu::border_index, BorderType, KernelShape};
+
+/// Coefficients of the 3x3 Sobel operator along one dimension, for each order of derivative.
+const SOBEL: [[f32; 3]; 3] = [[1.0, 2.0, 1.0], [-1.0, 0.0, 1.0], [1.0, -2.0, 1.0]];
+
+pub(crate) fn box_kernel<B: Backend>(
+    [height, width]: [usize; 2],
+    normalize: bool,
+    device: &B::Device,
+) -> FloatTensor<B> {
+    let value = match normalize {
+        true => 1.0 / (height * width) as f32,
+        false => 1.0,
+    };
+
+    kernel::<B>(alloc::vec![value; height * width], [height, width], device)
+}
+
+pub(crate) fn gaussian_kernel<B: Backend>(
+    [height, width]: [usize; 2],
+    [sigma_y, sigma_x]: [f32; 2],
+    device: &B::Device,
+) -> FloatTensor<B> {
+    let weights_y = gaussian_weights(height, sigma_y);
+    let weights_x = gaussian_weights(width, sigma_x);
+
+    kernel::<B>(outer(&weights_y, &weights_x), [height, width], device)
+}
+
+pub(crate) fn sobel_kernel<B: Backend>(dx: usize, dy: usize, device: &B::Device) -> FloatTensor<B> {
+    assert!(
+        dx <= 2 && dy <= 2 && dx + dy > 0,
+        "The 3x3 Sobel operator supports derivatives of order 1 or 2, got dx={dx} and dy={dy}"
+    );
+
+    kernel::<B>(outer(&SOBEL[dy], &SOBEL[dx]), [3, 3], device)
+}
+
+/// Correlates each channel of the image with the kernel using only tensor operations, so that
+/// backends tracking gradients can differentiate it with respect to both the image and the kernel.
+pub(crate) fn filter2d_composed<B: Backend>(
+    x: FloatTensor<B>,
+    kernel: FloatTensor<B>,
+    border: BorderType,
+) -> FloatTensor<B> {
+    let x = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(x));
+    let kernel = Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(kernel));
+    let [batch_size, channels, height, width] = x.dims();
+    let [kernel_height, kernel_width] = kernel.dims();
+    let (top, left) = (kernel_height / 2, kernel_width / 2);
+    let (bottom, right) = (kernel_height - 1 - top, kernel_width - 1 - left);
+
+    let x = x.reshape([batch_size * channels, 1, height, width]);
+    let padded = match border {
+        BorderType::Constant(value) => x.pad((left, right, top, bottom), value),
+        _ => {
+            let device = x.device();
+            let rows = border_indices::<B>(height, top, bottom, border, &device);
+            let columns = border_indices::<B>(width, left, right, border, &device);
+            x.select(2, rows).select(3, columns)
+        }
+    };
+
+    let weight = kernel.reshape([1, 1, kernel_height, kernel_width]);
+    let output = conv2d(
+        padded,
+        weight,
+        None,
+        ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+    );
+
+    output
+        .reshape([batch_size, channels, height, width])
+        .into_primitive()
+        .tensor()
+}
+
+/// The indices of a dimension of the image padded with `before` and `after` extrapolated pixels.
+fn border_indices<B: Backend>(
+    size: usize,
+    before: usize,
+    after: usize,
+    border: BorderType,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let indices = (0..size + before + after)
+        .map(|i| border_index(i as i64 - before as i64, size, border).unwrap() as i64)
+        .collect::<Vec<_>>();
+
+    let data = TensorData::new(indices, Shape::new([size + before + after]));
+    Tensor::from_data(data.convert::<B::IntElem>(), device)
+}
+
+/// Creates a structuring element like `cv::getStructuringElement`, anchored at its center.
+pub(crate) fn structuring_element<B: Backend>(
+    shape: KernelShape,
+    [height, width]: [usize; 2],
+    device: &B::Device,
+) -> BoolTensor<B> {
+    let (anchor_y, anchor_x) = (height / 2, width / 2);
+    let mut data = alloc::vec![false; height * width];
+
+    for y in 0..height {
+        let (start, end) = match shape {
+            KernelShape::Rect => (0, width),
+            KernelShape::Cross if y == anchor_y => (0, width),
+            KernelShape::Cross => (anchor_x, anchor_x + 1),
+            KernelShape::Ellipse => {
+                let radius_y = anchor_y as f32;
+                let dy = y as f32 - radius_y;
+                // A single row has no height to scale the width of the ellipse.
+                let ratio = match radius_y > 0.0 {
+                    true => ((radius_y * radius_y - dy * dy) / (radius_y * radius_y)).max(0.0),
+                    false => 0.0,
+                };
+                let dx = (anchor_x as f32 * ratio.sqrt()).round() as usize;
+                (anchor_x.saturating_sub(dx), (anchor_x + dx + 1).min(width))
+            }
+        };
+
+        data[y * width + start..y * width + end].fill(true);
+    }
+
+    let data = TensorData::new(data, Shape::new([height, width]));
+    Tensor::<B, 2, Bool>::from_data(data, device).into_primitive()
+}
+
+/// Weights of a normalized gaussian kernel, with the default standard deviation of `opencv` when
+/// `sigma` is not positive.
+fn gaussian_weights(size: usize, sigma: f32) -> Vec<f32> {
+    let sigma = match sigma > 0.0 {
+        true => sigma,
+        false => 0.3 * ((size as f32 - 1.0) * 0.5 - 1.0) + 0.8,
+    };
+    let center = (size as f32 - 1.0) / 2.0;
+    let weights = (0..size)
+        .map(|i| (-(i as f32 - center).powi(2) / (2.0 * sigma * sigma)).exp())
+        .collect::<Vec<_>>();
+    let sum = weights.iter().sum::<f32>();
+
+    weights.into_iter().map(|w| w / sum).collect()
+}
+
+fn outer(column: &[f32], row: &[f32]) -> Vec<f32> {
+    column
+        .iter()
+        .flat_map(|a| row.iter().map(move |b| a * b))
+        .collect()
+}
+
+fn kernel<B: Backend>(data: Vec<f32>, shape: [usize; 2], device: &B::Device) -> FloatTensor<B> {
+    let data = TensorData::new(data, Shape::new(shape)).convert::<B::FloatElem>();
+    Tensor::<B, 2>::from_data(data, device)
+        .into_primitive()
+        .tensor()
+}
@@ -1,3 +1,7 @@
 mod base;
+mod boxes;
+mod filters;
+mod warp;
 
 pub use base::*;
+pub(crate) use filters::{filter2d_composed, structuring_element};
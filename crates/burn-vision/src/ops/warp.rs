@@ -0,0 +1,14 @@
+use burn_tensor::{backend::Backend, ops::FloatTensor, Tensor, TensorPrimitive};
+
+/// Completes the affine matrices [batch_size, 2, 3] with the row `[0, 0, 1]`.
+pub(crate) fn affine_to_perspective<B: Backend>(matrix: FloatTensor<B>) -> FloatTensor<B> {
+    let matrix = Tensor::<B, 3>::from_primitive(TensorPrimitive::Float(matrix));
+    let [batch_size, _, _] = matrix.dims();
+    let last_row = Tensor::<B, 1>::from_floats([0.0, 0.0, 1.0], &matrix.device())
+        .reshape([1, 1, 3])
+        .expand([batch_size, 1, 3]);
+
+    Tensor::cat(alloc::vec![matrix, last_row], 1)
+        .into_primitive()
+        .tensor()
+}
@@ -1,6 +1,10 @@
-use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+use burn_tensor::{backend::Backend, Bool, Int, Tensor, TensorPrimitive};
 
-use crate::{ConnectedStats, ConnectedStatsOptions, Connectivity, VisionOps};
+use crate::{
+    ops::structuring_element as element, BorderType, BoxFormat, CannyOptions, ColorConversion,
+    ConnectedStats, ConnectedStatsOptions, Connectivity, KernelShape, NmsOptions, RoiAlignOptions,
+    RoiPoolOptions, VisionOps, WarpOptions,
+};
 
 /// Connected components tensor extensions
 pub trait ConnectedComponents<B: Backend> {
@@ -37,3 +41,331 @@ impl<B: Backend + VisionOps<B>> ConnectedComponents<B> for Tensor<B, 3, Bool> {
         (Tensor::from_primitive(labels), stats.into())
     }
 }
+
+/// Performs non-maximum suppression, greedily keeping the boxes with the highest scores and
+/// discarding the ones overlapping a kept box - returns the indices of the kept boxes sorted by
+/// decreasing score.
+///
+/// `boxes` - The boxes in the `Xyxy` format [num_boxes, 4]
+/// `scores` - The score of each box [num_boxes]
+pub fn nms<B: Backend + VisionOps<B>>(
+    boxes: Tensor<B, 2>,
+    scores: Tensor<B, 1>,
+    options: NmsOptions,
+) -> Tensor<B, 1, Int> {
+    Tensor::from_primitive(B::nms(
+        boxes.into_primitive().tensor(),
+        scores.into_primitive().tensor(),
+        options,
+    ))
+}
+
+/// Performs non-maximum suppression independently for each category, so that boxes of different
+/// categories never suppress each other - returns the indices of the kept boxes sorted by
+/// decreasing score.
+///
+/// `boxes` - The boxes in the `Xyxy` format [num_boxes, 4]
+/// `scores` - The score of each box [num_boxes]
+/// `idxs` - The category of each box [num_boxes]
+pub fn batched_nms<B: Backend + VisionOps<B>>(
+    boxes: Tensor<B, 2>,
+    scores: Tensor<B, 1>,
+    idxs: Tensor<B, 1, Int>,
+    options: NmsOptions,
+) -> Tensor<B, 1, Int> {
+    Tensor::from_primitive(B::batched_nms(
+        boxes.into_primitive().tensor(),
+        scores.into_primitive().tensor(),
+        idxs.into_primitive(),
+        options,
+    ))
+}
+
+/// Pools the features of each region of interest to a fixed size, by averaging bilinearly
+/// interpolated samples of each output bin.
+///
+/// `x` - The features [batch_size, channels, height, width]
+/// `rois` - The regions as `(batch_index, x1, y1, x2, y2)` [num_rois, 5]
+///
+/// Returns the pooled features [num_rois, channels, output_height, output_width]
+pub fn roi_align<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    rois: Tensor<B, 2>,
+    options: RoiAlignOptions,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::roi_align(
+        x.into_primitive().tensor(),
+        rois.into_primitive().tensor(),
+        options,
+    )))
+}
+
+/// Pools the features of each region of interest to a fixed size, by taking the maximum of each
+/// output bin.
+///
+/// `x` - The features [batch_size, channels, height, width]
+/// `rois` - The regions as `(batch_index, x1, y1, x2, y2)` [num_rois, 5]
+///
+/// Returns the pooled features [num_rois, channels, output_height, output_width]
+pub fn roi_pool<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    rois: Tensor<B, 2>,
+    options: RoiPoolOptions,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::roi_pool(
+        x.into_primitive().tensor(),
+        rois.into_primitive().tensor(),
+        options,
+    )))
+}
+
+/// Computes the area of each box in the `Xyxy` format [num_boxes, 4].
+pub fn box_area<B: Backend + VisionOps<B>>(boxes: Tensor<B, 2>) -> Tensor<B, 1> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::box_area(
+        boxes.into_primitive().tensor(),
+    )))
+}
+
+/// Computes the intersection over union of each pair of boxes in the `Xyxy` format [n, 4] and
+/// [m, 4] - returns the IoU matrix [n, m].
+pub fn box_iou<B: Backend + VisionOps<B>>(
+    boxes1: Tensor<B, 2>,
+    boxes2: Tensor<B, 2>,
+) -> Tensor<B, 2> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::box_iou(
+        boxes1.into_primitive().tensor(),
+        boxes2.into_primitive().tensor(),
+    )))
+}
+
+/// Computes the generalized intersection over union of each pair of boxes in the `Xyxy` format
+/// [n, 4] and [m, 4] - returns the generalized IoU matrix [n, m].
+pub fn generalized_box_iou<B: Backend + VisionOps<B>>(
+    boxes1: Tensor<B, 2>,
+    boxes2: Tensor<B, 2>,
+) -> Tensor<B, 2> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::generalized_box_iou(
+        boxes1.into_primitive().tensor(),
+        boxes2.into_primitive().tensor(),
+    )))
+}
+
+/// Converts the boxes [num_boxes, 4] from a format to another.
+pub fn box_convert<B: Backend + VisionOps<B>>(
+    boxes: Tensor<B, 2>,
+    in_format: BoxFormat,
+    out_format: BoxFormat,
+) -> Tensor<B, 2> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::box_convert(
+        boxes.into_primitive().tensor(),
+        in_format,
+        out_format,
+    )))
+}
+
+/// Creates a structuring element for morphology operations, like `cv::getStructuringElement`.
+///
+/// `size` - The size of the element, as `[height, width]`
+pub fn structuring_element<B: Backend>(
+    shape: KernelShape,
+    size: [usize; 2],
+    device: &B::Device,
+) -> Tensor<B, 2, Bool> {
+    Tensor::from_primitive(element::<B>(shape, size, device))
+}
+
+/// Morphology tensor extensions, for grayscale images [batch_size, channels, height, width] and
+/// for binary images.
+///
+/// The structuring element [kernel_height, kernel_width] is anchored at its center, and pixels
+/// outside of the image are ignored.
+pub trait Morphology<B: Backend>: Sized {
+    /// Erodes the image, computing the minimum of the neighborhood of each pixel.
+    fn erode(self, kernel: Tensor<B, 2, Bool>) -> Self;
+
+    /// Dilates the image, computing the maximum of the neighborhood of each pixel.
+    fn dilate(self, kernel: Tensor<B, 2, Bool>) -> Self;
+
+    /// Opens the image, eroding then dilating it to remove small bright regions.
+    fn open(self, kernel: Tensor<B, 2, Bool>) -> Self {
+        self.erode(kernel.clone()).dilate(kernel)
+    }
+
+    /// Closes the image, dilating then eroding it to fill small dark regions.
+    fn close(self, kernel: Tensor<B, 2, Bool>) -> Self {
+        self.dilate(kernel.clone()).erode(kernel)
+    }
+}
+
+impl<B: Backend + VisionOps<B>> Morphology<B> for Tensor<B, 4> {
+    fn erode(self, kernel: Tensor<B, 2, Bool>) -> Self {
+        Tensor::from_primitive(TensorPrimitive::Float(B::erode(
+            self.into_primitive().tensor(),
+            kernel.into_primitive(),
+        )))
+    }
+
+    fn dilate(self, kernel: Tensor<B, 2, Bool>) -> Self {
+        Tensor::from_primitive(TensorPrimitive::Float(B::dilate(
+            self.into_primitive().tensor(),
+            kernel.into_primitive(),
+        )))
+    }
+}
+
+impl<B: Backend + VisionOps<B>> Morphology<B> for Tensor<B, 4, Bool> {
+    fn erode(self, kernel: Tensor<B, 2, Bool>) -> Self {
+        self.float().erode(kernel).greater_elem(0.5)
+    }
+
+    fn dilate(self, kernel: Tensor<B, 2, Bool>) -> Self {
+        self.float().dilate(kernel).greater_elem(0.5)
+    }
+}
+
+/// Correlates each channel of the image [batch_size, channels, height, width] with a kernel
+/// [kernel_height, kernel_width] anchored at its center, like `cv::filter2D`.
+pub fn filter2d<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    kernel: Tensor<B, 2>,
+    border: BorderType,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::filter2d(
+        x.into_primitive().tensor(),
+        kernel.into_primitive().tensor(),
+        border,
+    )))
+}
+
+/// Blurs the image [batch_size, channels, height, width] with the sum of the neighborhood of
+/// each pixel, divided by the area of the neighborhood when `normalize` is true.
+///
+/// `kernel_size` - The size of the neighborhood, as `[height, width]`
+pub fn box_filter<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    kernel_size: [usize; 2],
+    normalize: bool,
+    border: BorderType,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::box_filter(
+        x.into_primitive().tensor(),
+        kernel_size,
+        normalize,
+        border,
+    )))
+}
+
+/// Blurs the image [batch_size, channels, height, width] with a gaussian kernel.
+///
+/// `kernel_size` - The odd size of the kernel, as `[height, width]`
+/// `sigma` - The standard deviation along each dimension, as `[sigma_y, sigma_x]`, computed from
+///   the kernel size when not positive
+pub fn gaussian_blur<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    kernel_size: [usize; 2],
+    sigma: [f32; 2],
+    border: BorderType,
+) -> Tensor<B, 4> {
+    assert!(
+        kernel_size.iter().all(|size| size % 2 == 1),
+        "The size of the gaussian kernel must be odd, got {kernel_size:?}"
+    );
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::gaussian_blur(
+        x.into_primitive().tensor(),
+        kernel_size,
+        sigma,
+        border,
+    )))
+}
+
+/// Replaces each pixel of the image [batch_size, channels, height, width] with the median of its
+/// square neighborhood of odd side `kernel_size`.
+pub fn median_blur<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    kernel_size: usize,
+    border: BorderType,
+) -> Tensor<B, 4> {
+    assert!(
+        kernel_size % 2 == 1,
+        "The size of the median kernel must be odd, got {kernel_size}"
+    );
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::median_blur(
+        x.into_primitive().tensor(),
+        kernel_size,
+        border,
+    )))
+}
+
+/// Computes the derivative of order `dx` along the width and `dy` along the height of the image
+/// [batch_size, channels, height, width], with the 3x3 Sobel operator.
+pub fn sobel<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    dx: usize,
+    dy: usize,
+    border: BorderType,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::sobel(
+        x.into_primitive().tensor(),
+        dx,
+        dy,
+        border,
+    )))
+}
+
+/// Finds the edges of each channel of the image [batch_size, channels, height, width] with the
+/// Canny algorithm - returns the edge mask.
+pub fn canny<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    options: CannyOptions,
+) -> Tensor<B, 4, Bool> {
+    Tensor::from_primitive(B::canny(x.into_primitive().tensor(), options))
+}
+
+/// Converts the image [batch_size, channels, height, width] from a color space to another.
+pub fn color_convert<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    conversion: ColorConversion,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::color_convert(
+        x.into_primitive().tensor(),
+        conversion,
+    )))
+}
+
+/// Applies a perspective transform [batch_size, 3, 3] to the image
+/// [batch_size, channels, height, width], like `cv::warpPerspective`.
+///
+/// `output_size` - The size of the output, as `[height, width]`
+pub fn warp_perspective<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    matrix: Tensor<B, 3>,
+    output_size: [usize; 2],
+    options: WarpOptions,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::warp_perspective(
+        x.into_primitive().tensor(),
+        matrix.into_primitive().tensor(),
+        output_size,
+        options,
+    )))
+}
+
+/// Applies an affine transform [batch_size, 2, 3] to the image
+/// [batch_size, channels, height, width], like `cv::warpAffine`.
+///
+/// `output_size` - The size of the output, as `[height, width]`
+pub fn warp_affine<B: Backend + VisionOps<B>>(
+    x: Tensor<B, 4>,
+    matrix: Tensor<B, 3>,
+    output_size: [usize; 2],
+    options: WarpOptions,
+) -> Tensor<B, 4> {
+    Tensor::from_primitive(TensorPrimitive::Float(B::warp_affine(
+        x.into_primitive().tensor(),
+        matrix.into_primitive().tensor(),
+        output_size,
+        options,
+    )))
+}
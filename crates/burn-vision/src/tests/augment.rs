@@ -0,0 +1,119 @@
+#[burn_tensor_testgen::testgen(augment)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ElementConversion, TensorData};
+    use burn_vision::augment::{
+        item_rngs, Augmentation, ColorJitter, Compose, CutMix, MixAugmentation, Mixup, RandAugment,
+        RandomHorizontalFlip, RandomResizedCrop, RandomRotation,
+    };
+
+    fn images() -> TestTensor<4> {
+        TestTensorInt::<1>::arange(0..96, &Default::default())
+            .float()
+            .div_scalar(96.0)
+            .reshape([2, 3, 4, 4])
+    }
+
+    fn targets() -> TestTensor<2> {
+        TestTensor::<2>::from([[1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    #[test]
+    fn should_flip_all_images_with_probability_one() {
+        let mut rngs = item_rngs(0, 0..2);
+
+        let output = RandomHorizontalFlip { probability: 1.0 }.apply(images(), &mut rngs);
+
+        output
+            .into_data()
+            .assert_eq(&images().flip([3]).into_data(), false);
+    }
+
+    #[test]
+    fn should_keep_images_with_identity_parameters() {
+        let mut rngs = item_rngs(0, 0..2);
+        let augmentation = Compose::new()
+            .with(RandomHorizontalFlip { probability: 0.0 })
+            .with(RandomResizedCrop {
+                size: [4, 4],
+                scale: (1.0, 1.0),
+                ratio: (1.0, 1.0),
+            })
+            .with(RandomRotation::new(0.0))
+            .with(ColorJitter::new(0.0, 0.0, 0.0, 0.0));
+
+        let output = augmentation.apply(images(), &mut rngs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&images().into_data(), 3);
+    }
+
+    #[test]
+    fn should_augment_items_independently_of_the_batch() {
+        let augmentation = Compose::new()
+            .with(RandomResizedCrop::new([3, 3]))
+            .with(ColorJitter::new(0.4, 0.4, 0.4, 0.1))
+            .with(RandAugment::new());
+
+        let batch = augmentation.apply(images(), &mut item_rngs(42, [5, 9]));
+        let single = augmentation.apply(images().narrow(0, 1, 1), &mut item_rngs(42, [9]));
+
+        batch
+            .narrow(0, 1, 1)
+            .into_data()
+            .assert_approx_eq(&single.into_data(), 3);
+    }
+
+    #[test]
+    fn should_keep_shape_and_range_with_rand_augment() {
+        let mut rngs = item_rngs(7, 0..2);
+
+        let output = RandAugment {
+            num_ops: 3,
+            magnitude: 20,
+            ..RandAugment::new()
+        }
+        .apply(images(), &mut rngs);
+
+        assert_eq!(output.dims(), [2, 3, 4, 4]);
+        assert!(output.clone().min().into_scalar().elem::<f32>() >= 0.0);
+        assert!(output.max().into_scalar().elem::<f32>() <= 1.0);
+    }
+
+    #[test]
+    fn should_mix_targets_with_mixup() {
+        let mut rngs = item_rngs(3, 0..2);
+
+        let (output, mixed) = Mixup::new(0.4).apply(images(), targets(), &mut rngs);
+
+        assert_eq!(output.dims(), [2, 3, 4, 4]);
+        mixed
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0], [1.0]]), 3);
+    }
+
+    #[test]
+    fn should_mix_targets_with_the_kept_area_with_cutmix() {
+        let mut rngs = item_rngs(3, 0..2);
+        let images = images();
+
+        let (output, mixed) = CutMix::new(1.0).apply(images.clone(), targets(), &mut rngs);
+
+        // The weight of the target of each image is the proportion of its pixels that are kept.
+        let kept = output
+            .equal(images)
+            .float()
+            .mean_dim(3)
+            .mean_dim(2)
+            .mean_dim(1)
+            .reshape([2, 1]);
+        let weights = (mixed.clone() * targets()).sum_dim(1);
+        weights.into_data().assert_approx_eq(&kept.into_data(), 3);
+        mixed
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0], [1.0]]), 3);
+    }
+}
@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(boxes)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{box_area, box_convert, box_iou, generalized_box_iou, BoxFormat};
+
+    #[test]
+    fn should_compute_box_area() {
+        let boxes = TestTensor::<2>::from([[0.0, 0.0, 10.0, 10.0], [1.0, 2.0, 4.0, 6.0]]);
+
+        let output = box_area(boxes);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([100.0, 12.0]), 3);
+    }
+
+    #[test]
+    fn should_compute_box_iou() {
+        let boxes1 = TestTensor::<2>::from([[0.0, 0.0, 10.0, 10.0]]);
+        let boxes2 = TestTensor::<2>::from([
+            [0.0, 0.0, 10.0, 10.0],
+            [5.0, 5.0, 15.0, 15.0],
+            [20.0, 20.0, 30.0, 30.0],
+        ]);
+
+        let output = box_iou(boxes1, boxes2);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.142857, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_generalized_box_iou() {
+        let boxes1 = TestTensor::<2>::from([[0.0, 0.0, 10.0, 10.0]]);
+        let boxes2 = TestTensor::<2>::from([
+            [0.0, 0.0, 10.0, 10.0],
+            [5.0, 5.0, 15.0, 15.0],
+            [20.0, 20.0, 30.0, 30.0],
+        ]);
+
+        let output = generalized_box_iou(boxes1, boxes2);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, -0.079365, -0.777778]]), 3);
+    }
+
+    #[test]
+    fn should_convert_box_formats() {
+        let boxes = TestTensor::<2>::from([[1.0, 2.0, 5.0, 8.0]]);
+
+        let xywh = box_convert(boxes.clone(), BoxFormat::Xyxy, BoxFormat::Xywh);
+        let cxcywh = box_convert(xywh.clone(), BoxFormat::Xywh, BoxFormat::Cxcywh);
+        let xyxy = box_convert(cxcywh.clone(), BoxFormat::Cxcywh, BoxFormat::Xyxy);
+
+        xywh.into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 2.0, 4.0, 6.0]]), 3);
+        cxcywh
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0, 5.0, 4.0, 6.0]]), 3);
+        xyxy.into_data().assert_approx_eq(&boxes.into_data(), 3);
+    }
+}
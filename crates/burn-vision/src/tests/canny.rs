@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(canny)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{canny, CannyOptions};
+
+    fn step() -> TestTensor<4> {
+        TestTensor::<4>::from([[[[0.0, 0.0, 0.0, 1.0, 1.0, 1.0]; 4]]])
+    }
+
+    #[test]
+    fn should_keep_single_edge_along_step() {
+        let output = canny(step(), CannyOptions::new(1.0, 2.0, false));
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[[false, false, true, false, false, false]; 4]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_use_l2_gradient() {
+        let output = canny(step(), CannyOptions::new(1.0, 3.5, true));
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[[false, false, true, false, false, false]; 4]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_discard_weak_edges_without_strong_edge() {
+        let output = canny(step(), CannyOptions::new(1.0, 10.0, false));
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[[false; 6]; 4]]]), false);
+    }
+
+    #[test]
+    fn should_keep_weak_edges_connected_to_strong_edge() {
+        // The step is stronger on the first row, making its edges strong.
+        let x = TestTensor::<4>::from([[[
+            [0.0, 0.0, 0.0, 4.0, 4.0, 4.0],
+            [0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+        ]]]);
+
+        let output = canny(x, CannyOptions::new(1.0, 12.0, false));
+
+        // The weak edges of the lower rows are kept through the strong edges of the first rows.
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [false, false, false, true, true, true],
+                [false, false, false, true, false, false],
+                [false, false, true, false, false, false],
+                [false, false, true, false, false, false],
+                [false, false, true, false, false, false],
+                [false, false, true, false, false, false],
+            ]]]),
+            false,
+        );
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(color)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{color_convert, ColorConversion};
+
+    /// Red, green, blue and gray pixels [1, 3, 1, 4].
+    fn colors() -> TestTensor<4> {
+        TestTensor::<4>::from([[
+            [[1.0, 0.0, 0.0, 0.5]],
+            [[0.0, 1.0, 0.0, 0.5]],
+            [[0.0, 0.0, 1.0, 0.5]],
+        ]])
+    }
+
+    #[test]
+    fn should_convert_rgb_to_gray() {
+        let output = color_convert(colors(), ColorConversion::RgbToGray);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[0.299, 0.587, 0.114, 0.5]]]]), 3);
+    }
+
+    #[test]
+    fn should_convert_gray_to_rgb() {
+        let x = TestTensor::<4>::from([[[[0.25, 0.75]]]]);
+
+        let output = color_convert(x, ColorConversion::GrayToRgb);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[[0.25, 0.75]], [[0.25, 0.75]], [[0.25, 0.75]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_convert_rgb_to_hsv_and_back() {
+        let hsv = color_convert(colors(), ColorConversion::RgbToHsv);
+        let rgb = color_convert(hsv.clone(), ColorConversion::HsvToRgb);
+
+        hsv.into_data().assert_approx_eq(
+            &TensorData::from([[
+                [[0.0, 120.0, 240.0, 0.0]],
+                [[1.0, 1.0, 1.0, 0.0]],
+                [[1.0, 1.0, 1.0, 0.5]],
+            ]]),
+            3,
+        );
+        rgb.into_data().assert_approx_eq(&colors().into_data(), 3);
+    }
+
+    #[test]
+    fn should_convert_rgb_to_yuv_and_back() {
+        let x = TestTensor::<4>::from([[[[0.2]], [[0.6]], [[0.4]]]]);
+
+        let yuv = color_convert(x.clone(), ColorConversion::RgbToYuv);
+        let rgb = color_convert(yuv.clone(), ColorConversion::YuvToRgb);
+
+        yuv.into_data().assert_approx_eq(
+            &TensorData::from([[[[0.4576]], [[0.47166]], [[0.27408]]]]),
+            3,
+        );
+        rgb.into_data().assert_approx_eq(&x.into_data(), 3);
+    }
+}
@@ -0,0 +1,112 @@
+#[burn_tensor_testgen::testgen(filter)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{box_filter, filter2d, gaussian_blur, median_blur, sobel, BorderType};
+
+    #[test]
+    fn should_extrapolate_borders() {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0]]]]);
+        // Picks the right neighbor of each pixel.
+        let kernel = TestTensor::<2>::from([[0.0, 0.0, 1.0]]);
+
+        let constant = filter2d(x.clone(), kernel.clone(), BorderType::Constant(-1.0));
+        let replicate = filter2d(x.clone(), kernel.clone(), BorderType::Replicate);
+        let reflect = filter2d(x.clone(), kernel.clone(), BorderType::Reflect);
+        let reflect_101 = filter2d(x, kernel, BorderType::Reflect101);
+
+        constant
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[2.0, 3.0, -1.0]]]]), 3);
+        replicate
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[2.0, 3.0, 3.0]]]]), 3);
+        reflect
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[2.0, 3.0, 3.0]]]]), 3);
+        reflect_101
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[2.0, 3.0, 2.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_average_neighborhood_with_box_filter() {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]]);
+
+        let normalized = box_filter(x.clone(), [3, 3], true, BorderType::Constant(0.0));
+        let sum = box_filter(x, [1, 3], false, BorderType::Constant(0.0));
+
+        normalized.into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [12.0 / 9.0, 21.0 / 9.0, 16.0 / 9.0],
+                [27.0 / 9.0, 45.0 / 9.0, 33.0 / 9.0],
+                [24.0 / 9.0, 39.0 / 9.0, 28.0 / 9.0],
+            ]]]),
+            3,
+        );
+        sum.into_data().assert_approx_eq(
+            &TensorData::from([[[[3.0, 6.0, 5.0], [9.0, 15.0, 11.0], [15.0, 24.0, 17.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_blur_impulse_with_gaussian_weights() {
+        let x = TestTensor::<4>::from([[[[0.0, 0.0, 1.0, 0.0, 0.0]]]]);
+
+        // The default standard deviation of a kernel of size 3 is 0.8.
+        let output = gaussian_blur(x, [1, 3], [0.0, 0.0], BorderType::default());
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[[0.0, 0.23899, 0.52201, 0.23899, 0.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_remove_outlier_with_median_blur() {
+        let x = TestTensor::<4>::from([[[[1.0, 1.0, 1.0], [1.0, 9.0, 1.0], [1.0, 1.0, 1.0]]]]);
+
+        let output = median_blur(x, 3, BorderType::default());
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[1.0; 3]; 3]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_derivative_with_sobel() {
+        let x = TestTensor::<4>::from([[[[0.0, 1.0, 2.0, 3.0]; 3]]]);
+
+        let dx = sobel(x.clone(), 1, 0, BorderType::Replicate);
+        let dy = sobel(x, 0, 1, BorderType::Replicate);
+
+        dx.into_data()
+            .assert_approx_eq(&TensorData::from([[[[4.0, 8.0, 8.0, 4.0]; 3]]]), 3);
+        dy.into_data()
+            .assert_approx_eq(&TensorData::from([[[[0.0; 4]; 3]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_filter_gradient() {
+        let device = Default::default();
+        let x = burn_tensor::Tensor::<TestAutodiffBackend, 4>::ones([1, 1, 3, 3], &device)
+            .require_grad();
+        let kernel =
+            burn_tensor::Tensor::<TestAutodiffBackend, 2>::ones([3, 3], &device).require_grad();
+
+        let output = filter2d(x.clone(), kernel.clone(), BorderType::Constant(0.0));
+        let grads = output.sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+        let kernel_grad = kernel.grad(&grads).unwrap();
+
+        // Each pixel contributes to the outputs whose neighborhood contains it.
+        let expected = [[4.0, 6.0, 4.0], [6.0, 9.0, 6.0], [4.0, 6.0, 4.0]];
+        x_grad
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[expected]]), 3);
+        kernel_grad
+            .into_data()
+            .assert_approx_eq(&TensorData::from(expected), 3);
+    }
+}
@@ -1,10 +1,23 @@
+mod augment;
+mod boxes;
+mod canny;
+mod color;
 mod connected_components;
+mod filter;
+mod morphology;
+mod nms;
+mod roi_align;
+mod roi_pool;
+mod warp;
 
 #[macro_export]
 macro_rules! testgen_all {
     () => {
         use burn_tensor::{Bool, Float, Int};
 
+        pub type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+
+        pub type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
         pub type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, Int>;
         pub type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, Bool>;
 
@@ -13,7 +26,17 @@ macro_rules! testgen_all {
 
             pub type IntType = <TestBackend as burn_tensor::backend::Backend>::IntElem;
 
+            burn_vision::testgen_augment!();
+            burn_vision::testgen_boxes!();
+            burn_vision::testgen_canny!();
+            burn_vision::testgen_color!();
             burn_vision::testgen_connected_components!();
+            burn_vision::testgen_filter!();
+            burn_vision::testgen_morphology!();
+            burn_vision::testgen_nms!();
+            burn_vision::testgen_roi_align!();
+            burn_vision::testgen_roi_pool!();
+            burn_vision::testgen_warp!();
         }
     };
 }
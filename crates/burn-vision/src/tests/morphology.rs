@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(morphology)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{structuring_element, KernelShape, Morphology};
+
+    #[test]
+    fn should_create_structuring_elements() {
+        let device = Default::default();
+
+        let ellipse = structuring_element::<TestBackend>(KernelShape::Ellipse, [5, 5], &device);
+        let cross = structuring_element::<TestBackend>(KernelShape::Cross, [3, 3], &device);
+
+        ellipse.into_data().assert_eq(
+            &TensorData::from([
+                [false, false, true, false, false],
+                [true, true, true, true, true],
+                [true, true, true, true, true],
+                [true, true, true, true, true],
+                [false, false, true, false, false],
+            ]),
+            false,
+        );
+        cross.into_data().assert_eq(
+            &TensorData::from([
+                [false, true, false],
+                [true, true, true],
+                [false, true, false],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_erode_to_the_minimum_of_the_neighborhood() {
+        let device = Default::default();
+        let x = TestTensor::<4>::from([[[
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0],
+            [0.0, 1.0, 2.0, 1.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+        ]]]);
+        let kernel = structuring_element::<TestBackend>(KernelShape::Rect, [3, 3], &device);
+
+        let output = x.erode(kernel);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_dilate_to_the_maximum_of_the_neighborhood() {
+        let device = Default::default();
+        let x = TestTensor::<4>::from([[[
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 3.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]]]);
+        let kernel = structuring_element::<TestBackend>(KernelShape::Cross, [3, 3], &device);
+
+        let output = x.dilate(kernel);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.0, 3.0, 0.0, 0.0],
+                [3.0, 3.0, 3.0, 0.0],
+                [0.0, 3.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0, 1.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_open_binary_image() {
+        let device = Default::default();
+        let x = TestTensorBool::<4>::from([[[
+            [false, false, false, false, false, false],
+            [false, true, true, true, false, false],
+            [false, true, true, true, false, false],
+            [false, true, true, true, false, false],
+            [false, false, false, false, false, false],
+            [false, false, false, false, false, true],
+        ]]]);
+        let kernel = structuring_element::<TestBackend>(KernelShape::Rect, [3, 3], &device);
+
+        let output = x.open(kernel);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[
+                [false, false, false, false, false, false],
+                [false, true, true, true, false, false],
+                [false, true, true, true, false, false],
+                [false, true, true, true, false, false],
+                [false, false, false, false, false, false],
+                [false, false, false, false, false, false],
+            ]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_close_binary_image() {
+        let device = Default::default();
+        let x = TestTensorBool::<4>::from([[[
+            [true, true, true, true, true],
+            [true, true, true, true, true],
+            [true, true, false, true, true],
+            [true, true, true, true, true],
+            [true, true, true, true, true],
+        ]]]);
+        let kernel = structuring_element::<TestBackend>(KernelShape::Rect, [3, 3], &device);
+
+        let output = x.close(kernel);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[[true; 5]; 5]]]), false);
+    }
+}
@@ -0,0 +1,99 @@
+#[burn_tensor_testgen::testgen(nms)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{batched_nms, nms, NmsOptions};
+
+    fn boxes() -> TestTensor<2> {
+        TestTensor::from([
+            [0.0, 0.0, 10.0, 10.0],
+            [1.0, 1.0, 11.0, 11.0],
+            [20.0, 20.0, 30.0, 30.0],
+            [0.0, 0.0, 10.0, 5.0],
+        ])
+    }
+
+    fn scores() -> TestTensor<1> {
+        TestTensor::from([0.9, 0.8, 0.7, 0.95])
+    }
+
+    #[test]
+    fn should_suppress_overlapping_boxes() {
+        let output = nms(
+            boxes(),
+            scores(),
+            NmsOptions::new(0.5, f32::NEG_INFINITY, None),
+        );
+
+        // The IoU of the first and last boxes is exactly the threshold, so both are kept.
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 0, 2]), false);
+    }
+
+    #[test]
+    fn should_discard_boxes_below_score_threshold() {
+        let output = nms(boxes(), scores(), NmsOptions::new(0.5, 0.75, None));
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 0]), false);
+    }
+
+    #[test]
+    fn should_limit_output_boxes() {
+        let output = nms(
+            boxes(),
+            scores(),
+            NmsOptions::new(0.3, f32::NEG_INFINITY, Some(2)),
+        );
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 2]), false);
+    }
+
+    #[test]
+    fn should_suppress_boxes_across_mask_blocks() {
+        // Each box overlaps the next one with an IoU of 1/3, and has a higher score.
+        let num_boxes = 70;
+        let boxes = (0..num_boxes)
+            .flat_map(|i| [i as f32 * 5.0, 0.0, i as f32 * 5.0 + 10.0, 10.0])
+            .collect::<Vec<_>>();
+        let scores = (0..num_boxes)
+            .map(|i| 1.0 - i as f32 / num_boxes as f32)
+            .collect::<Vec<_>>();
+        let boxes =
+            TestTensor::<2>::from_data(TensorData::new(boxes, [num_boxes, 4]), &Default::default());
+        let scores =
+            TestTensor::<1>::from_data(TensorData::new(scores, [num_boxes]), &Default::default());
+
+        let output = nms(boxes, scores, NmsOptions::new(0.3, f32::NEG_INFINITY, None));
+
+        let expected = (0..num_boxes as i64).step_by(2).collect::<Vec<_>>();
+        output
+            .into_data()
+            .assert_eq(&TensorData::new(expected, [num_boxes / 2]), false);
+    }
+
+    #[test]
+    fn should_support_empty_boxes() {
+        let boxes = TestTensor::<2>::empty([0, 4], &Default::default());
+        let scores = TestTensor::<1>::empty([0], &Default::default());
+
+        let output = nms(boxes, scores, NmsOptions::default());
+
+        assert_eq!(output.dims(), [0]);
+    }
+
+    #[test]
+    fn should_not_suppress_boxes_of_other_categories() {
+        let idxs = TestTensorInt::<1>::from([0, 1, 0, 0]);
+
+        let output = batched_nms(boxes(), scores(), idxs, NmsOptions::default());
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 0, 1, 2]), false);
+    }
+}
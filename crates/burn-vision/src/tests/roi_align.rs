@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(roi_align)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{roi_align, RoiAlignOptions};
+
+    /// Features whose value is `4 * y + x`, so that bilinear samples are exact.
+    fn features(batch_size: usize) -> TestTensor<4> {
+        TestTensorInt::<1>::arange(0..16 * batch_size as i64, &Default::default())
+            .float()
+            .reshape([batch_size, 1, 4, 4])
+    }
+
+    #[test]
+    fn should_average_samples_of_each_bin() {
+        let rois = TestTensor::<2>::from([[0.0, 0.0, 0.0, 2.0, 2.0]]);
+
+        let output = roi_align(
+            features(1),
+            rois,
+            RoiAlignOptions::new([2, 2], 1.0, 2, false),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[2.5, 3.5], [6.5, 7.5]]]]), 3);
+    }
+
+    #[test]
+    fn should_support_aligned_regions() {
+        let rois = TestTensor::<2>::from([[0.0, 0.0, 0.0, 2.0, 2.0]]);
+
+        let output = roi_align(
+            features(1),
+            rois,
+            RoiAlignOptions::new([2, 2], 1.0, 2, true),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[0.625, 1.5], [4.125, 5.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_support_adaptive_sampling_and_batch_index() {
+        let rois = TestTensor::<2>::from([[1.0, 2.0, 2.0, 6.0, 6.0]]);
+
+        let output = roi_align(
+            features(2),
+            rois,
+            RoiAlignOptions::new([1, 1], 0.5, 0, false),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[26.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_features_gradient() {
+        let device = Default::default();
+        let x = burn_tensor::Tensor::<TestAutodiffBackend, 4>::zeros([1, 1, 4, 4], &device)
+            .require_grad();
+        let rois = burn_tensor::Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[0.0, 0.0, 0.0, 2.0, 2.0]],
+            &device,
+        );
+
+        let output = roi_align(x.clone(), rois, RoiAlignOptions::new([1, 1], 1.0, 2, false));
+        let grads = output.sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        x_grad.into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.0625, 0.125, 0.0625, 0.0],
+                [0.125, 0.25, 0.125, 0.0],
+                [0.0625, 0.125, 0.0625, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ]]]),
+            3,
+        );
+    }
+}
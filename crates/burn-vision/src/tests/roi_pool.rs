@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(roi_pool)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_vision::{roi_pool, RoiPoolOptions};
+
+    fn features() -> TestTensor<4> {
+        TestTensorInt::<1>::arange(0..16, &Default::default())
+            .float()
+            .reshape([1, 1, 4, 4])
+    }
+
+    #[test]
+    fn should_take_maximum_of_each_bin() {
+        let rois = TestTensor::<2>::from([[0.0, 0.0, 0.0, 3.0, 3.0]]);
+
+        let output = roi_pool(features(), rois, RoiPoolOptions::new([2, 2], 1.0));
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[5.0, 7.0], [13.0, 15.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_scale_regions() {
+        let rois = TestTensor::<2>::from([[0.0, 0.0, 0.0, 2.0, 2.0]]);
+
+        let output = roi_pool(features(), rois, RoiPoolOptions::new([2, 2], 0.5));
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[0.0, 1.0], [4.0, 5.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_features_gradient() {
+        let device = Default::default();
+        let x = burn_tensor::Tensor::<TestAutodiffBackend, 4>::from_data(
+            features().into_data(),
+            &device,
+        )
+        .require_grad();
+        let rois = burn_tensor::Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[0.0, 0.0, 0.0, 3.0, 3.0]],
+            &device,
+        );
+
+        let output = roi_pool(x.clone(), rois, RoiPoolOptions::new([2, 2], 1.0));
+        let grads = output.sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        x_grad.into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 1.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 1.0],
+            ]]]),
+            3,
+        );
+    }
+}
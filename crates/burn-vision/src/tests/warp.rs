@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(warp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::InterpolateMode, TensorData};
+    use burn_vision::{warp_affine, warp_perspective, BorderType, WarpOptions};
+
+    fn image() -> TestTensor<4> {
+        TestTensorInt::<1>::arange(0..16, &Default::default())
+            .float()
+            .reshape([1, 1, 4, 4])
+    }
+
+    #[test]
+    fn should_keep_image_with_identity() {
+        let matrix = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]]);
+        let options = WarpOptions::new(InterpolateMode::Bicubic, BorderType::Reflect101);
+
+        let output = warp_perspective(image(), matrix, [4, 4], options);
+
+        output.into_data().assert_approx_eq(&image().into_data(), 3);
+    }
+
+    #[test]
+    fn should_translate_with_affine_transform() {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let matrix = TestTensor::<3>::from([[[1.0, 0.0, 1.0], [0.0, 1.0, 0.0]]]);
+        let options = WarpOptions::new(InterpolateMode::Bilinear, BorderType::Constant(0.0));
+
+        let output = warp_affine(x, matrix, [2, 3], options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[0.0, 1.0, 2.0], [0.0, 4.0, 5.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_interpolate_between_pixels() {
+        let x = TestTensor::<4>::from([[[[0.0, 2.0], [4.0, 6.0]]]]);
+        // Shifts the image by half a pixel along both dimensions.
+        let matrix = TestTensor::<3>::from([[[1.0, 0.0, -0.5], [0.0, 1.0, -0.5]]]);
+        let options = WarpOptions::new(InterpolateMode::Bilinear, BorderType::Replicate);
+
+        let output = warp_affine(x, matrix, [2, 2], options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[3.0, 4.0], [5.0, 6.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_divide_by_homogeneous_coordinate() {
+        // Both matrices scale the image down by two.
+        let matrix = TestTensor::<3>::from([
+            [[0.5, 0.0, 0.0], [0.0, 0.5, 0.0], [0.0, 0.0, 1.0]],
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]],
+        ]);
+        let x = TestTensor::cat(vec![image(), image()], 0);
+        let options = WarpOptions::new(InterpolateMode::Nearest, BorderType::default());
+
+        let output = warp_perspective(x, matrix, [2, 2], options);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[[0.0, 2.0], [8.0, 10.0]]], [[[0.0, 2.0], [8.0, 10.0]]]]),
+            3,
+        );
+    }
+}
@@ -25,9 +25,12 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::ConstantOfShape => constant_of_shape_update_output(node),
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
+        NodeType::ConvInteger => quantized_update_outputs(node, ElementType::Int32),
         NodeType::Cos => same_as_input(node),
+        NodeType::DequantizeLinear => quantized_update_outputs(node, ElementType::Float32),
         NodeType::Div => same_as_input_broadcast(node),
         NodeType::Dropout => same_as_input(node),
+        NodeType::Einsum => einsum_update_outputs(node),
         NodeType::Equal => elementwise_comparison_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
@@ -39,7 +42,10 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::GatherElements => same_as_input(node),
         NodeType::Greater => elementwise_comparison_outputs(node),
         NodeType::GreaterOrEqual => elementwise_comparison_outputs(node),
+        NodeType::GridSample => same_as_input(node),
+        NodeType::GRU => recurrent_update_outputs(node),
         NodeType::HardSigmoid => same_as_input(node),
+        NodeType::If => if_update_outputs(node),
         NodeType::GlobalAveragePool => same_as_input(node),
         NodeType::ConvTranspose1d => conv_transpose1d_update_outputs(node),
         NodeType::ConvTranspose2d => conv_transpose2d_update_outputs(node),
@@ -49,8 +55,11 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::LessOrEqual => elementwise_comparison_outputs(node),
         NodeType::Linear => linear_update_outputs(node),
         NodeType::Log => same_as_input(node),
+        NodeType::Loop => loop_update_outputs(node),
+        NodeType::LSTM => recurrent_update_outputs(node),
         NodeType::LogSoftmax => same_as_input(node),
         NodeType::MatMul => matmul_update_outputs(node),
+        NodeType::MatMulInteger => quantized_matmul_update_outputs(node, 1, ElementType::Int32),
         NodeType::Max => same_as_input_broadcast(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
@@ -62,6 +71,10 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Pad => same_as_input(node),
         NodeType::PRelu => same_as_input_broadcast(node),
         NodeType::Pow => same_as_input_broadcast(node),
+        NodeType::QLinearConv => quantized_update_outputs(node, ElementType::Float32),
+        NodeType::QLinearMatMul => quantized_matmul_update_outputs(node, 3, ElementType::Float32),
+        NodeType::QuantizeLinear => quantized_update_outputs(node, ElementType::Float32),
+        NodeType::RNN => recurrent_update_outputs(node),
         NodeType::RandomNormal => random_update_output(node),
         NodeType::RandomNormalLike => random_like_update_output(node),
         NodeType::RandomUniform => random_update_output(node),
@@ -76,6 +89,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Relu => same_as_input(node),
         NodeType::Reshape => reshape_update_outputs(node),
         NodeType::Resize => same_as_input(node),
+        NodeType::Scan => scan_update_outputs(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
         NodeType::Sign => same_as_input(node),
@@ -537,6 +551,106 @@ fn top_k_update_output(node: &mut Node) {
     });
 }
 
+/// Infers the output types of the recurrent nodes (LSTM, GRU and RNN).
+///
+/// With the default layout, the output sequence `Y` is `[seq_length, num_directions, batch_size,
+/// hidden_size]` and the final states `Y_h` and `Y_c` are `[num_directions, batch_size,
+/// hidden_size]`.
+fn recurrent_update_outputs(node: &mut Node) {
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("{:?}: invalid input type", node.node_type),
+    };
+
+    for (i, output) in node.outputs.iter_mut().enumerate() {
+        output.ty = ArgType::Tensor(TensorType {
+            elem_type: elem_type.clone(),
+            dim: if i == 0 { 4 } else { 3 },
+            shape: None, // shape is tracked and calculated at runtime
+        });
+    }
+}
+
+/// Infers the output types of the If node from the outputs of the `then_branch` subgraph.
+fn if_update_outputs(node: &mut Node) {
+    let then_branch = match node.attrs.get("then_branch") {
+        Some(AttributeValue::Graph(graph)) => graph,
+        _ => panic!("If: then_branch subgraph is required"),
+    };
+
+    for (output, branch_output) in node.outputs.iter_mut().zip(then_branch.outputs.iter()) {
+        output.ty = branch_output.ty.clone();
+    }
+}
+
+/// Infers the output types of the Loop node from the outputs of the `body` subgraph.
+///
+/// The body outputs are the condition, the loop carried values and the scan outputs. The node
+/// outputs are the final loop carried values followed by the scan outputs, which are stacked along
+/// a new first dimension.
+fn loop_update_outputs(node: &mut Node) {
+    let body = match node.attrs.get("body") {
+        Some(AttributeValue::Graph(graph)) => graph,
+        _ => panic!("Loop: body subgraph is required"),
+    };
+    // The body inputs are the iteration number, the condition and the loop carried values
+    let num_carried = body.inputs.len() - 2;
+
+    for (i, (output, body_output)) in node
+        .outputs
+        .iter_mut()
+        .zip(body.outputs.iter().skip(1))
+        .enumerate()
+    {
+        output.ty = if i < num_carried {
+            body_output.ty.clone()
+        } else {
+            stacked_type(&body_output.ty)
+        };
+    }
+}
+
+/// Infers the output types of the Scan node from the outputs of the `body` subgraph.
+///
+/// The body outputs are the state variables followed by the scan outputs, which are stacked along
+/// a new dimension.
+fn scan_update_outputs(node: &mut Node) {
+    let body = match node.attrs.get("body") {
+        Some(AttributeValue::Graph(graph)) => graph,
+        _ => panic!("Scan: body subgraph is required"),
+    };
+    let num_scan_inputs = match node.attrs.get("num_scan_inputs") {
+        Some(AttributeValue::Int64(num_scan_inputs)) => *num_scan_inputs as usize,
+        _ => panic!("Scan: num_scan_inputs attribute is required"),
+    };
+    let num_states = body.inputs.len() - num_scan_inputs;
+
+    for (i, (output, body_output)) in node.outputs.iter_mut().zip(body.outputs.iter()).enumerate() {
+        output.ty = if i < num_states {
+            body_output.ty.clone()
+        } else {
+            stacked_type(&body_output.ty)
+        };
+    }
+}
+
+/// The type of the values of each iteration stacked along a new dimension.
+fn stacked_type(ty: &ArgType) -> ArgType {
+    match ty {
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorType {
+            elem_type: tensor.elem_type.clone(),
+            dim: tensor.dim + 1,
+            shape: None, // shape is tracked and calculated at runtime
+        }),
+        ArgType::Scalar(elem_type) => ArgType::Tensor(TensorType {
+            elem_type: elem_type.clone(),
+            dim: 1,
+            shape: None,
+        }),
+        _ => panic!("Only tensor and scalar values can be stacked, got {:?}", ty),
+    }
+}
+
 /// Temporary pass-through stub for dimension inference so that we can export the IR model.
 fn temporary_pass_through_stub(node: &mut Node) {
     log::warn!("Must implement dimension inference for {:?}", node);
@@ -683,17 +797,9 @@ fn matmul_update_outputs(node: &mut Node) {
     // NOTE: matmul only supported for float tensors
     match (node.inputs[0].ty.clone(), node.inputs[1].ty.clone()) {
         (ArgType::Tensor(a), ArgType::Tensor(b)) => {
-            // With broadcasting support, output dim has to be computed based on the inputs
-            let mut out_dim = max(a.dim, b.dim);
-
-            // Matrix-vector or vector-matrix product
-            if (a.dim >= 2 && b.dim == 1) || (a.dim == 1 && b.dim >= 2) {
-                out_dim -= 1;
-            }
-
             node.outputs[0].ty = ArgType::Tensor(TensorType {
                 elem_type: a.elem_type.clone(),
-                dim: out_dim,
+                dim: matmul_output_dim(a.dim, b.dim),
                 shape: a.shape.clone(),
             });
         }
@@ -701,6 +807,106 @@ fn matmul_update_outputs(node: &mut Node) {
     }
 }
 
+/// Infers the rank of the output of an einsum from its equation.
+fn einsum_update_outputs(node: &mut Node) {
+    let equation = match node.attrs.get("equation") {
+        Some(equation) => equation.clone().into_string(),
+        None => panic!("Einsum: equation attribute not found"),
+    };
+    let equation = equation.replace(' ', "");
+
+    let inputs = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.clone(),
+            _ => panic!("Einsum: only tensor inputs are valid"),
+        })
+        .collect::<Vec<_>>();
+    let (terms, output) = match equation.split_once("->") {
+        Some((terms, output)) => (terms, Some(output)),
+        None => (equation.as_str(), None),
+    };
+
+    // The number of dimensions covered by the ellipsis is the largest among the inputs.
+    let terms = terms.split(',').collect::<Vec<_>>();
+    let num_labels = |term: &str| term.chars().filter(char::is_ascii_alphabetic).count();
+    let broadcast_dim = terms
+        .iter()
+        .zip(&inputs)
+        .filter(|(term, _)| term.contains("..."))
+        .map(|(term, input)| input.dim - num_labels(term))
+        .max()
+        .unwrap_or(0);
+
+    let dim = match output {
+        Some(output) if output.contains("...") => num_labels(output) + broadcast_dim,
+        Some(output) => num_labels(output),
+        None => {
+            // Implicit output: the labels appearing exactly once.
+            let labels = terms.concat();
+            let num_unique = labels
+                .chars()
+                .filter(|label| label.is_ascii_alphabetic())
+                .filter(|label| labels.matches(*label).count() == 1)
+                .count();
+            num_unique + broadcast_dim
+        }
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: inputs[0].elem_type.clone(),
+        // Burn tensors have at least one dimension, a scalar output has a shape of [1].
+        dim: dim.max(1),
+        shape: None,
+    });
+}
+
+/// Returns the rank of the output of a matrix product between tensors of the given ranks.
+fn matmul_output_dim(lhs_dim: usize, rhs_dim: usize) -> usize {
+    // With broadcasting support, output dim has to be computed based on the inputs
+    let out_dim = max(lhs_dim, rhs_dim);
+
+    // Matrix-vector or vector-matrix product
+    if (lhs_dim >= 2 && rhs_dim == 1) || (lhs_dim == 1 && rhs_dim >= 2) {
+        out_dim - 1
+    } else {
+        out_dim
+    }
+}
+
+/// Infers the output of a quantized operator, which has the rank of its first input.
+///
+/// Quantized tensors are float tensors in Burn, so the quantized operators output float tensors
+/// while the integer operators (e.g., ConvInteger) output int32 tensors.
+fn quantized_update_outputs(node: &mut Node, elem_type: ElementType) {
+    let dim = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("{:?}: only tensor input is valid", node.node_type),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim,
+        shape: None,
+    });
+}
+
+/// Infers the output of a quantized matrix product (QLinearMatMul or MatMulInteger) between the
+/// first input and the input at the given index.
+fn quantized_matmul_update_outputs(node: &mut Node, rhs: usize, elem_type: ElementType) {
+    let dim = match (&node.inputs[0].ty, &node.inputs[rhs].ty) {
+        (ArgType::Tensor(a), ArgType::Tensor(b)) => matmul_output_dim(a.dim, b.dim),
+        _ => panic!("{:?}: only tensor inputs are valid", node.node_type),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim,
+        shape: None,
+    });
+}
+
 fn range_update_outputs(node: &mut Node) {
     if node.inputs.len() != 3 {
         panic!("Range: expected 3 inputs, found {}", node.inputs.len());
@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// Error that can occur when parsing an ONNX file.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The file can't be read.
+    Io(std::io::Error),
+
+    /// The file isn't a valid ONNX protobuf message.
+    Protobuf(protobuf::Error),
+
+    /// The graph can't be converted to the intermediate representation.
+    InvalidGraph(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Unable to open file: {err}"),
+            Self::Protobuf(err) => write!(f, "Unable to parse ONNX file: {err}"),
+            Self::InvalidGraph(message) => write!(f, "Invalid ONNX graph: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Protobuf(err) => Some(err),
+            Self::InvalidGraph(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ParseError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<protobuf::Error> for ParseError {
+    fn from(err: protobuf::Error) -> Self {
+        Self::Protobuf(err)
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     fs::File,
+    panic::{self, AssertUnwindSafe},
     path::Path,
 };
 
@@ -8,24 +9,34 @@ use crate::node_remap::remap_node_type;
 
 use super::{
     coalesce::coalesce,
+    error::ParseError,
     ir::{Data, OnnxGraph, TensorType},
     proto_conversion::convert_node_proto,
-    protos::{ModelProto, NodeProto, TensorProto, ValueInfoProto},
+    protos::{
+        attribute_proto::AttributeType, GraphProto, ModelProto, NodeProto, TensorProto,
+        ValueInfoProto,
+    },
 };
 
 use super::dim_inference::dim_inference;
-use super::ir::{ArgType, Argument, Node, NodeType};
+use super::ir::{ArgType, Argument, AttributeValue, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 13] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 19] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::ConvInteger,
+    NodeType::DequantizeLinear,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::MatMulInteger,
     NodeType::OneHot,
+    NodeType::QLinearConv,
+    NodeType::QLinearMatMul,
+    NodeType::QuantizeLinear,
     NodeType::Reshape,
     NodeType::Resize,
     NodeType::Unsqueeze,
@@ -53,6 +64,8 @@ pub struct GraphData {
     input_name_map: HashMap<String, IOEntry>,
     /// Maps the updated input name to the original input name. Required to check if the input is an initializer
     input_key_map: HashMap<String, String>,
+    /// The values of the enclosing graphs, referenced by their original name. Only set for subgraphs
+    outer_scope: Option<HashMap<String, Argument>>,
 }
 
 impl GraphData {
@@ -100,9 +113,38 @@ impl GraphData {
             processed_nodes: Vec::new(),
             input_name_map,
             input_key_map,
+            outer_scope: None,
         }
     }
 
+    /// Create the graph data of a subgraph (e.g., the body of a `Loop` node).
+    ///
+    /// The inputs are prefixed with the name of the subgraph to keep them unique, and the values of
+    /// the enclosing graphs can be referenced from the outer scope.
+    pub(crate) fn new_subgraph(
+        graph: &GraphProto,
+        name: &str,
+        outer_scope: HashMap<String, Argument>,
+    ) -> Self {
+        let mut graph_data = Self::new(&graph.input, &[], &graph.initializer);
+
+        for (i, input) in graph_data.inputs.iter_mut().enumerate() {
+            let in_name = format!("{}_input{}", name, i + 1);
+            let key = graph_data.input_key_map.remove(&input.name).unwrap();
+            graph_data.input_key_map.insert(in_name.clone(), key);
+            input.name = in_name;
+        }
+
+        // The outputs of a subgraph may not be typed, their types are inferred from the nodes
+        graph_data.outputs = graph
+            .output
+            .iter()
+            .map(|x| Argument::new(x.name.clone()))
+            .collect();
+        graph_data.outer_scope = Some(outer_scope);
+        graph_data
+    }
+
     /// Get the value of an input from the original input name. Used during proto conversion
     pub(crate) fn init_in(&self, proto_str: &str) -> Argument {
         match self.input_name_map.get(proto_str) {
@@ -111,6 +153,12 @@ impl GraphData {
                 //need to confirm) then we could pop the initializer from the map
                 if let Some(init_arg) = self.initializers.get(proto_str) {
                     init_arg.clone()
+                } else if let Some(outer_arg) = self
+                    .outer_scope
+                    .as_ref()
+                    .and_then(|outer_scope| outer_scope.get(proto_str))
+                {
+                    outer_arg.clone()
                 } else {
                     log::warn!(
                         "Input {} not found, should only happen when peeking",
@@ -162,18 +210,49 @@ impl GraphData {
 
     /// Consumes the graph data and returns the processed nodes, filtered inputs and outputs
     fn consume(mut self) -> (Vec<Node>, Vec<Argument>, Vec<Argument>) {
-        self.inputs.retain(|x| x.passed);
+        let Some(outer_scope) = self.outer_scope.take() else {
+            self.inputs.retain(|x| x.passed);
+            let outputs = self
+                .outputs
+                .into_iter()
+                .filter_map(|x| match self.input_name_map.get(&x.name) {
+                    Some(IOEntry::Node(i, j)) => Some(self.processed_nodes[*i].outputs[*j].clone()),
+                    _ => None,
+                })
+                .collect();
+            return (self.processed_nodes, self.inputs, outputs);
+        };
+
+        // The inputs of a subgraph are defined by its parent node, so they are all kept, and its
+        // outputs can directly be inputs or values of the enclosing graphs
         let outputs = self
             .outputs
-            .into_iter()
-            .filter_map(|x| match self.input_name_map.get(&x.name) {
-                Some(IOEntry::Node(i, j)) => Some(self.processed_nodes[*i].outputs[*j].clone()),
-                _ => None,
+            .iter()
+            .map(|x| match self.input_name_map.get(&x.name) {
+                Some(IOEntry::In(i)) => self.inputs[*i].clone(),
+                Some(IOEntry::Node(i, j)) => self.processed_nodes[*i].outputs[*j].clone(),
+                None => outer_scope
+                    .get(&x.name)
+                    .cloned()
+                    .unwrap_or_else(|| panic!("Subgraph output {} not found", x.name)),
             })
             .collect();
         (self.processed_nodes, self.inputs, outputs)
     }
 
+    /// Returns the values that can be referenced by a subgraph, i.e. the values of this graph and of
+    /// the enclosing graphs, mapped from their original name.
+    fn scope(&self) -> HashMap<String, Argument> {
+        let mut scope = self.outer_scope.clone().unwrap_or_default();
+        scope.extend(self.initializers.clone());
+        scope.extend(
+            self.input_name_map
+                .keys()
+                .map(|name| (name.clone(), self.init_in(name))),
+        );
+        scope
+    }
+
     /// Used to get the output of the graph by name. Only used to remap unsqueeze nodes
     pub fn get_graph_output(&self, name: &str) -> Option<&Argument> {
         self.outputs.iter().find(|x| x.name == name)
@@ -203,19 +282,24 @@ impl OnnxGraphBuilder {
     pub(crate) fn build(mut self, model_proto: &ModelProto) -> OnnxGraph {
         self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
 
-        let mut graph_data = GraphData::new(
+        let graph_data = GraphData::new(
             &model_proto.graph.input,
             &model_proto.graph.output,
             &model_proto.graph.initializer,
         );
 
-        let mut node_iter = model_proto.graph.node.iter().peekable();
+        self.build_graph(&model_proto.graph.node, graph_data)
+    }
+
+    fn build_graph(&mut self, nodes: &[NodeProto], mut graph_data: GraphData) -> OnnxGraph {
+        let mut node_iter = nodes.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
             let mut node = convert_node_proto(node_proto, &graph_data);
 
             remap_node_type(&mut node);
             self.handle_node_renaming(&mut node);
+            self.handle_subgraphs(&mut node, node_proto, &graph_data);
             coalesce(&mut node, &mut node_iter, &graph_data);
             self.handle_identity(&mut node, &graph_data);
             self.check_constants(&mut node, &graph_data);
@@ -228,7 +312,13 @@ impl OnnxGraphBuilder {
             graph_data.add_node(node);
         }
 
-        let (mut processed_nodes, inputs, outputs) = graph_data.consume();
+        let (mut processed_nodes, inputs, mut outputs) = graph_data.consume();
+        // The outputs forwarded by identity nodes refer to the identity input instead
+        outputs.iter_mut().for_each(|x| {
+            if let Some(identity_idx) = self.identity_idx.get(&x.name) {
+                x.clone_from(&processed_nodes[*identity_idx].inputs[0]);
+            }
+        });
         // Remove the graph inputs/output that are not used by any node
         let mut i = 0;
         processed_nodes.retain(|_| {
@@ -261,6 +351,66 @@ impl OnnxGraphBuilder {
         node.name.clone_from(&new_name);
     }
 
+    /// Convert the graph attributes of the node (e.g., the branches of an `If` node) to subgraphs.
+    ///
+    /// The values of the enclosing graphs used by a subgraph are appended to the node inputs, so
+    /// that they are considered as used by the node.
+    fn handle_subgraphs(
+        &mut self,
+        node: &mut Node,
+        node_proto: &NodeProto,
+        graph_data: &GraphData,
+    ) {
+        let graph_attrs = node_proto
+            .attribute
+            .iter()
+            .filter(|attr| attr.type_.unwrap() == AttributeType::GRAPH);
+
+        for attr in graph_attrs {
+            log::debug!("converting subgraph {} of node {}", &attr.name, &node.name);
+            let mut scope = graph_data.scope();
+            // Values forwarded by identity nodes refer to the identity input instead
+            scope.values_mut().for_each(|arg| {
+                if let Some(identity_idx) = self.identity_idx.get(&arg.name) {
+                    arg.clone_from(&graph_data.processed_nodes[*identity_idx].inputs[0]);
+                }
+            });
+            let outer_names = scope
+                .values()
+                .filter(|arg| arg.value.is_none())
+                .map(|arg| arg.name.clone())
+                .collect::<HashSet<_>>();
+
+            // Node names are unique across subgraphs since they are used as field names
+            let mut builder = OnnxGraphBuilder {
+                constants_types: self.constants_types.clone(),
+                node_name_counter: std::mem::take(&mut self.node_name_counter),
+                ..Default::default()
+            };
+            let subgraph_data =
+                GraphData::new_subgraph(&attr.g, &format!("{}_{}", node.name, attr.name), scope);
+            let subgraph = builder.build_graph(&attr.g.node, subgraph_data);
+            self.node_name_counter = builder.node_name_counter;
+
+            let captures = subgraph
+                .nodes
+                .iter()
+                .flat_map(|node| node.inputs.iter())
+                .chain(subgraph.outputs.iter())
+                .filter(|arg| outer_names.contains(&arg.name))
+                .cloned()
+                .collect::<Vec<_>>();
+            for capture in captures {
+                if !node.inputs.iter().any(|input| input.name == capture.name) {
+                    node.inputs.push(capture);
+                }
+            }
+
+            node.attrs
+                .insert(attr.name.clone(), AttributeValue::Graph(subgraph));
+        }
+    }
+
     fn check_constants(&mut self, node: &mut Node, graph_data: &GraphData) {
         if node.node_type == NodeType::Constant
             || (node.node_type == NodeType::Identity && node.inputs[0].value.is_some())
@@ -347,10 +497,7 @@ impl OnnxGraphBuilder {
 pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
     log::info!("Parsing ONNX file: {}", onnx_path.display());
 
-    // Open the file
-    let mut file = File::open(onnx_path).expect("Unable to open file");
-    let onnx_model: ModelProto =
-        Message::parse_from_reader(&mut file).expect("Unable to parse ONNX file");
+    let onnx_model = read_model(onnx_path).unwrap_or_else(|err| panic!("{err}"));
 
     // ONNX nodes must be topologically sorted per spec:
     // https://github.com/onnx/onnx/blob/main/docs/IR.md#graphs
@@ -358,6 +505,67 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
         onnx_model.graph.node.is_top_sorted(),
         "Nodes are not topologically sorted"
     );
+    let graph = convert_model(&onnx_model);
+
+    log::info!("Finished parsing ONNX file: {}", onnx_path.display());
+
+    graph
+}
+
+/// Open an onnx file and convert it to a Graph (intermediate representation), returning an
+/// error instead of panicking when the file is invalid.
+///
+/// This should be used to load untrusted files, e.g. in a serving process.
+///
+/// # Arguments
+///
+/// * `onnx_path` - Path to the onnx file
+///
+/// # Returns
+///
+/// * `OnnxGraph` - The graph representation of the onnx file, or the reason it can't be parsed
+///
+/// # Notes
+///
+/// The failures of the conversion of the nodes are caught, but are still reported by the panic
+/// hook.
+pub fn try_parse_onnx(onnx_path: &Path) -> Result<OnnxGraph, ParseError> {
+    log::info!("Parsing ONNX file: {}", onnx_path.display());
+
+    let onnx_model = read_model(onnx_path)?;
+
+    // ONNX nodes must be topologically sorted per spec:
+    // https://github.com/onnx/onnx/blob/main/docs/IR.md#graphs
+    if !onnx_model.graph.node.is_top_sorted() {
+        return Err(ParseError::InvalidGraph(
+            "Nodes are not topologically sorted".to_string(),
+        ));
+    }
+
+    // The conversion of the nodes asserts the validity of their attributes and inputs.
+    let graph = panic::catch_unwind(AssertUnwindSafe(|| convert_model(&onnx_model))).map_err(
+        |payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Unknown error".to_string());
+            ParseError::InvalidGraph(message)
+        },
+    )?;
+
+    log::info!("Finished parsing ONNX file: {}", onnx_path.display());
+
+    Ok(graph)
+}
+
+fn read_model(onnx_path: &Path) -> Result<ModelProto, ParseError> {
+    let mut file = File::open(onnx_path)?;
+    let onnx_model: ModelProto = Message::parse_from_reader(&mut file)?;
+    Ok(onnx_model)
+}
+
+fn convert_model(onnx_model: &ModelProto) -> OnnxGraph {
     log::debug!("Number of nodes: {:?}", onnx_model.graph.node.len());
     log::debug!("Number of inputs: {:?}", onnx_model.graph.input.len());
 
@@ -368,11 +576,7 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
 
     log::debug!("Number of outputs: {:?}", onnx_model.graph.output.len());
     let builder = OnnxGraphBuilder::default();
-    let graph = builder.build(&onnx_model);
-
-    log::info!("Finished parsing ONNX file: {}", onnx_path.display());
-
-    graph
+    builder.build(onnx_model)
 }
 
 /// Remap the unsqueeze node to a reshape node, Should only be called after
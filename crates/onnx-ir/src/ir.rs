@@ -87,6 +87,7 @@ pub enum AttributeValue {
     Strings(Vec<String>),
     Tensor(Tensor),
     Tensors(Vec<Tensor>),
+    Graph(OnnxGraph),
 }
 
 pub type Attributes = HashMap<String, AttributeValue>;
@@ -96,6 +97,8 @@ pub type Attributes = HashMap<String, AttributeValue>;
 pub enum ElementType {
     Float32,
     Float64,
+    Int8,
+    Uint8,
     Int32,
     Int64,
     String,
@@ -757,6 +760,14 @@ impl AttributeValue {
             panic!("Expected Tensors, got {:?}", self);
         }
     }
+
+    pub fn into_graph(self) -> OnnxGraph {
+        if let AttributeValue::Graph(elem) = self {
+            elem
+        } else {
+            panic!("Expected Graph, got {:?}", self);
+        }
+    }
 }
 
 /// Convert AttributeValue to an Argument
@@ -1,5 +1,6 @@
 mod coalesce;
 mod dim_inference;
+mod error;
 mod from_onnx;
 pub mod ir;
 mod node_remap;
@@ -7,6 +8,7 @@ mod proto_conversion;
 mod protos;
 pub mod util;
 
+pub use error::ParseError;
 pub use from_onnx::convert_constant_value;
-pub use from_onnx::parse_onnx;
+pub use from_onnx::{parse_onnx, try_parse_onnx};
 pub use ir::OnnxGraph;
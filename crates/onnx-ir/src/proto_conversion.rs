@@ -39,6 +39,28 @@ impl TryFrom<TensorProto> for Tensor {
                 // TODO : Add support for int16 by converting to int32
                 todo!("Add support for int16");
             }
+            // 8-bit integers are widened to int32, the element type keeping the original type
+            DataType::INT8 => (
+                ElementType::Int8,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(
+                        cast_slice::<u8, i8>(&tensor.raw_data[..])
+                            .iter()
+                            .map(|x| *x as i32)
+                            .collect(),
+                    )
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
+            DataType::UINT8 => (
+                ElementType::Uint8,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i32).collect())
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
             DataType::INT32 => (
                 ElementType::Int32,
                 // Convert the raw data to a vector of ints
@@ -107,6 +129,8 @@ impl TryFrom<&type_proto::Tensor> for Tensor {
     fn try_from(tensor: &type_proto::Tensor) -> Result<Tensor, Self::Error> {
         let elem_type = match DataType::from_i32(tensor.elem_type).unwrap() {
             DataType::FLOAT => ElementType::Float32,
+            DataType::INT8 => ElementType::Int8,
+            DataType::UINT8 => ElementType::Uint8,
             DataType::INT32 => ElementType::Int32,
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
@@ -151,8 +175,8 @@ impl TryFrom<AttributeProto> for AttributeValue {
             // warning: tensor can be empty TODO: check if it is empty
             AttributeType::TENSOR => AttributeValue::Tensor(Tensor::try_from(attr.t.unwrap())?),
 
-            // Graph is not supported for now
-            // AttributeType::GRAPH => AttributeValue::Graph(attr.g),
+            // Graphs depend on the values of the enclosing graph and are converted by the
+            // graph builder
             AttributeType::FLOATS => AttributeValue::Float32s(attr.floats),
             AttributeType::INTS => AttributeValue::Int64s(attr.ints),
             AttributeType::STRINGS => AttributeValue::Strings(to_string_vec(attr.strings)),
@@ -175,6 +199,10 @@ impl TryFrom<AttributeProto> for AttributeValue {
 pub fn convert_vec_attrs_proto(attrs: Vec<AttributeProto>) -> Attributes {
     let mut result = Attributes::new();
     for attr in attrs {
+        if attr.type_.unwrap() == AttributeType::GRAPH {
+            // Converted by the graph builder
+            continue;
+        }
         result.insert(attr.name.clone(), AttributeValue::try_from(attr).unwrap());
     }
     result
@@ -233,6 +261,8 @@ impl TryFrom<ValueInfoProto> for Argument {
 
         let elem_type = match DataType::from_i32(tensor_proto.elem_type).unwrap() {
             DataType::FLOAT => ElementType::Float32,
+            DataType::INT8 => ElementType::Int8,
+            DataType::UINT8 => ElementType::Uint8,
             DataType::INT32 => ElementType::Int32,
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,